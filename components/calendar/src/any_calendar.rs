@@ -7,9 +7,9 @@
 use crate::cal::hijri::HijriSimulatedLocation;
 use crate::cal::iso::IsoDateInner;
 use crate::cal::{
-    Buddhist, Chinese, Coptic, Dangi, Ethiopian, EthiopianEraStyle, Gregorian, Hebrew,
-    HijriSimulated, HijriTabular, HijriTabularEpoch, HijriTabularLeapYears, HijriUmmAlQura, Indian,
-    Iso, Japanese, JapaneseExtended, Persian, Roc,
+    BikramSambat, Buddhist, Chinese, Coptic, Dangi, Ethiopian, EthiopianEraStyle, Gregorian,
+    Hebrew, HijriSimulated, HijriTabular, HijriTabularEpoch, HijriTabularLeapYears, HijriUmmAlQura,
+    Indian, Iso, Japanese, JapaneseExtended, Persian, Roc, Vietnamese,
 };
 use crate::error::DateError;
 use crate::types::YearInfo;
@@ -76,6 +76,8 @@ define_preferences!(
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub enum AnyCalendar {
+    /// A [`BikramSambat`] calendar
+    BikramSambat(BikramSambat),
     /// A [`Buddhist`] calendar
     Buddhist(Buddhist),
     /// A [`Chinese`] calendar
@@ -108,6 +110,8 @@ pub enum AnyCalendar {
     Persian(Persian),
     /// A [`Roc`] calendar
     Roc(Roc),
+    /// A [`Vietnamese`] calendar
+    Vietnamese(Vietnamese),
 }
 
 // TODO(#3469): Decide on the best way to implement Ord.
@@ -115,6 +119,8 @@ pub enum AnyCalendar {
 #[derive(Clone, PartialEq, Eq, Debug, Copy)]
 #[non_exhaustive]
 pub enum AnyDateInner {
+    /// A date for a [`BikramSambat`] calendar
+    BikramSambat(<BikramSambat as Calendar>::DateInner),
     /// A date for a [`Buddhist`] calendar
     Buddhist(<Buddhist as Calendar>::DateInner),
     /// A date for a [`Chinese`] calendar
@@ -151,11 +157,17 @@ pub enum AnyDateInner {
     Persian(<Persian as Calendar>::DateInner),
     /// A date for a [`Roc`] calendar
     Roc(<Roc as Calendar>::DateInner),
+    /// A date for a [`Vietnamese`] calendar
+    Vietnamese(<Vietnamese as Calendar>::DateInner),
 }
 
 macro_rules! match_cal_and_date {
     (match ($cal:ident, $date:ident): ($cal_matched:ident, $date_matched:ident) => $e:expr) => {
         match ($cal, $date) {
+            (
+                &Self::BikramSambat(ref $cal_matched),
+                &AnyDateInner::BikramSambat(ref $date_matched),
+            ) => $e,
             (&Self::Buddhist(ref $cal_matched), &AnyDateInner::Buddhist(ref $date_matched)) => $e,
             (&Self::Chinese(ref $cal_matched), &AnyDateInner::Chinese(ref $date_matched)) => $e,
             (&Self::Coptic(ref $cal_matched), &AnyDateInner::Coptic(ref $date_matched)) => $e,
@@ -184,6 +196,9 @@ macro_rules! match_cal_and_date {
             ) => $e,
             (&Self::Persian(ref $cal_matched), &AnyDateInner::Persian(ref $date_matched)) => $e,
             (&Self::Roc(ref $cal_matched), &AnyDateInner::Roc(ref $date_matched)) => $e,
+            (&Self::Vietnamese(ref $cal_matched), &AnyDateInner::Vietnamese(ref $date_matched)) => {
+                $e
+            }
             _ => panic!(
                 "Found AnyCalendar with mixed calendar type {:?} and date type {:?}!",
                 $cal.kind().debug_name(),
@@ -196,6 +211,7 @@ macro_rules! match_cal_and_date {
 macro_rules! match_cal {
     (match $cal:ident: ($cal_matched:ident) => $e:expr) => {
         match $cal {
+            &Self::BikramSambat(ref $cal_matched) => AnyDateInner::BikramSambat($e),
             &Self::Buddhist(ref $cal_matched) => AnyDateInner::Buddhist($e),
             &Self::Chinese(ref $cal_matched) => AnyDateInner::Chinese($e),
             &Self::Coptic(ref $cal_matched) => AnyDateInner::Coptic($e),
@@ -214,6 +230,7 @@ macro_rules! match_cal {
             &Self::JapaneseExtended(ref $cal_matched) => AnyDateInner::JapaneseExtended($e),
             &Self::Persian(ref $cal_matched) => AnyDateInner::Persian($e),
             &Self::Roc(ref $cal_matched) => AnyDateInner::Roc($e),
+            &Self::Vietnamese(ref $cal_matched) => AnyDateInner::Vietnamese($e),
         }
     };
 }
@@ -263,6 +280,9 @@ impl Calendar for AnyCalendar {
 
     fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
         match (self, date) {
+            (Self::BikramSambat(c), AnyDateInner::BikramSambat(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
             (Self::Buddhist(c), AnyDateInner::Buddhist(ref mut d)) => {
                 c.offset_date(d, offset.cast_unit())
             }
@@ -310,6 +330,9 @@ impl Calendar for AnyCalendar {
                 c.offset_date(d, offset.cast_unit())
             }
             (Self::Roc(c), AnyDateInner::Roc(ref mut d)) => c.offset_date(d, offset.cast_unit()),
+            (Self::Vietnamese(c), AnyDateInner::Vietnamese(ref mut d)) => {
+                c.offset_date(d, offset.cast_unit())
+            }
             // This is only reached from misuse of from_raw, a semi-internal api
             #[expect(clippy::panic)]
             (_, d) => panic!(
@@ -329,6 +352,14 @@ impl Calendar for AnyCalendar {
         smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self> {
         match (self, calendar2, date1, date2) {
+            (
+                Self::BikramSambat(c1),
+                Self::BikramSambat(c2),
+                AnyDateInner::BikramSambat(d1),
+                AnyDateInner::BikramSambat(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             (
                 Self::Buddhist(c1),
                 Self::Buddhist(c2),
@@ -454,6 +485,14 @@ impl Calendar for AnyCalendar {
             (Self::Roc(c1), Self::Roc(c2), AnyDateInner::Roc(d1), AnyDateInner::Roc(d2)) => c1
                 .until(d1, d2, c2, largest_unit, smallest_unit)
                 .cast_unit(),
+            (
+                Self::Vietnamese(c1),
+                Self::Vietnamese(c2),
+                AnyDateInner::Vietnamese(d1),
+                AnyDateInner::Vietnamese(d2),
+            ) => c1
+                .until(d1, d2, c2, largest_unit, smallest_unit)
+                .cast_unit(),
             _ => {
                 // attempt to convert
                 let iso = calendar2.to_iso(date2);
@@ -499,6 +538,7 @@ impl Calendar for AnyCalendar {
 
     fn debug_name(&self) -> &'static str {
         match self.kind() {
+            AnyCalendarKind::BikramSambat => "AnyCalendar (Bikram Sambat)",
             AnyCalendarKind::Buddhist => "AnyCalendar (Buddhist)",
             AnyCalendarKind::Chinese => "AnyCalendar (Chinese)",
             AnyCalendarKind::Coptic => "AnyCalendar (Coptic)",
@@ -521,11 +561,36 @@ impl Calendar for AnyCalendar {
             AnyCalendarKind::JapaneseExtended => "AnyCalendar (Japanese, historical era data)",
             AnyCalendarKind::Persian => "AnyCalendar (Persian)",
             AnyCalendarKind::Roc => "AnyCalendar (Roc)",
+            AnyCalendarKind::Vietnamese => "AnyCalendar (Vietnamese)",
+        }
+    }
+
+    fn constrain_leap_month_code(&self, month_code: types::MonthCode) -> Option<types::MonthCode> {
+        match self {
+            Self::BikramSambat(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Buddhist(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Chinese(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Coptic(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Dangi(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Ethiopian(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Gregorian(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Hebrew(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Indian(ref c) => c.constrain_leap_month_code(month_code),
+            Self::HijriSimulated(ref c) => c.constrain_leap_month_code(month_code),
+            Self::HijriTabular(ref c) => c.constrain_leap_month_code(month_code),
+            Self::HijriUmmAlQura(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Iso(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Japanese(ref c) => c.constrain_leap_month_code(month_code),
+            Self::JapaneseExtended(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Persian(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Roc(ref c) => c.constrain_leap_month_code(month_code),
+            Self::Vietnamese(ref c) => c.constrain_leap_month_code(month_code),
         }
     }
 
     fn calendar_algorithm(&self) -> Option<CalendarAlgorithm> {
         match self {
+            Self::BikramSambat(ref c) => c.calendar_algorithm(),
             Self::Buddhist(ref c) => c.calendar_algorithm(),
             Self::Chinese(ref c) => c.calendar_algorithm(),
             Self::Coptic(ref c) => c.calendar_algorithm(),
@@ -542,6 +607,7 @@ impl Calendar for AnyCalendar {
             Self::JapaneseExtended(ref c) => c.calendar_algorithm(),
             Self::Persian(ref c) => c.calendar_algorithm(),
             Self::Roc(ref c) => c.calendar_algorithm(),
+            Self::Vietnamese(ref c) => c.calendar_algorithm(),
         }
     }
 }
@@ -555,6 +621,7 @@ impl AnyCalendar {
     #[cfg(feature = "compiled_data")]
     pub const fn new(kind: AnyCalendarKind) -> Self {
         match kind {
+            AnyCalendarKind::BikramSambat => AnyCalendar::BikramSambat(BikramSambat),
             AnyCalendarKind::Buddhist => AnyCalendar::Buddhist(Buddhist),
             AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese::new()),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
@@ -591,6 +658,7 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => AnyCalendar::Vietnamese(Vietnamese::new()),
         }
     }
 
@@ -604,6 +672,7 @@ impl AnyCalendar {
         P: BufferProvider + ?Sized,
     {
         Ok(match kind {
+            AnyCalendarKind::BikramSambat => AnyCalendar::BikramSambat(BikramSambat),
             AnyCalendarKind::Buddhist => AnyCalendar::Buddhist(Buddhist),
             AnyCalendarKind::Chinese => {
                 AnyCalendar::Chinese(Chinese::try_new_with_buffer_provider(provider)?)
@@ -646,6 +715,9 @@ impl AnyCalendar {
             ),
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => {
+                AnyCalendar::Vietnamese(Vietnamese::try_new_with_buffer_provider(provider)?)
+            }
         })
     }
 
@@ -656,10 +728,12 @@ impl AnyCalendar {
             + DataProvider<crate::provider::CalendarJapaneseExtendedV1>
             + DataProvider<crate::provider::CalendarChineseV1>
             + DataProvider<crate::provider::CalendarDangiV1>
+            + DataProvider<crate::provider::CalendarVietnameseV1>
             + DataProvider<crate::provider::CalendarHijriSimulatedMeccaV1>
             + ?Sized,
     {
         Ok(match kind {
+            AnyCalendarKind::BikramSambat => AnyCalendar::BikramSambat(BikramSambat),
             AnyCalendarKind::Buddhist => AnyCalendar::Buddhist(Buddhist),
             AnyCalendarKind::Chinese => AnyCalendar::Chinese(Chinese::try_new_unstable(provider)?),
             AnyCalendarKind::Coptic => AnyCalendar::Coptic(Coptic),
@@ -698,12 +772,16 @@ impl AnyCalendar {
             }
             AnyCalendarKind::Persian => AnyCalendar::Persian(Persian),
             AnyCalendarKind::Roc => AnyCalendar::Roc(Roc),
+            AnyCalendarKind::Vietnamese => {
+                AnyCalendar::Vietnamese(Vietnamese::try_new_unstable(provider)?)
+            }
         })
     }
 
     /// The [`AnyCalendarKind`] corresponding to the calendar this contains
     pub fn kind(&self) -> AnyCalendarKind {
         match *self {
+            Self::BikramSambat(_) => AnyCalendarKind::BikramSambat,
             Self::Buddhist(_) => AnyCalendarKind::Buddhist,
             Self::Chinese(_) => AnyCalendarKind::Chinese,
             Self::Coptic(_) => AnyCalendarKind::Coptic,
@@ -720,6 +798,7 @@ impl AnyCalendar {
             Self::JapaneseExtended(_) => AnyCalendarKind::JapaneseExtended,
             Self::Persian(_) => AnyCalendarKind::Persian,
             Self::Roc(_) => AnyCalendarKind::Roc,
+            Self::Vietnamese(_) => AnyCalendarKind::Vietnamese,
         }
     }
 }
@@ -741,6 +820,7 @@ impl<C: AsCalendar<Calendar = AnyCalendar>> Date<C> {
 impl AnyDateInner {
     fn kind(&self) -> AnyCalendarKind {
         match *self {
+            AnyDateInner::BikramSambat(_) => AnyCalendarKind::BikramSambat,
             AnyDateInner::Buddhist(_) => AnyCalendarKind::Buddhist,
             AnyDateInner::Chinese(_) => AnyCalendarKind::Chinese,
             AnyDateInner::Coptic(_) => AnyCalendarKind::Coptic,
@@ -766,6 +846,7 @@ impl AnyDateInner {
             AnyDateInner::JapaneseExtended(_) => AnyCalendarKind::JapaneseExtended,
             AnyDateInner::Persian(_) => AnyCalendarKind::Persian,
             AnyDateInner::Roc(_) => AnyCalendarKind::Roc,
+            AnyDateInner::Vietnamese(_) => AnyCalendarKind::Vietnamese,
         }
     }
 }
@@ -774,6 +855,8 @@ impl AnyDateInner {
 #[non_exhaustive]
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
 pub enum AnyCalendarKind {
+    /// The kind of a [`BikramSambat`] calendar
+    BikramSambat,
     /// The kind of a [`Buddhist`] calendar
     Buddhist,
     /// The kind of a [`Chinese`] calendar
@@ -810,6 +893,8 @@ pub enum AnyCalendarKind {
     Persian,
     /// The kind of a [`Roc`] calendar
     Roc,
+    /// The kind of a [`Vietnamese`] calendar
+    Vietnamese,
 }
 
 impl AnyCalendarKind {
@@ -833,6 +918,7 @@ impl AnyCalendarKind {
 
     fn debug_name(self) -> &'static str {
         match self {
+            AnyCalendarKind::BikramSambat => BikramSambat.debug_name(),
             AnyCalendarKind::Buddhist => Buddhist.debug_name(),
             AnyCalendarKind::Chinese => Chinese::DEBUG_NAME,
             AnyCalendarKind::Coptic => Coptic.debug_name(),
@@ -859,6 +945,7 @@ impl AnyCalendarKind {
             AnyCalendarKind::JapaneseExtended => JapaneseExtended::DEBUG_NAME,
             AnyCalendarKind::Persian => Persian.debug_name(),
             AnyCalendarKind::Roc => Roc.debug_name(),
+            AnyCalendarKind::Vietnamese => Vietnamese::DEBUG_NAME,
         }
     }
 }
@@ -868,6 +955,7 @@ impl TryFrom<CalendarAlgorithm> for AnyCalendarKind {
     fn try_from(v: CalendarAlgorithm) -> Result<Self, Self::Error> {
         use CalendarAlgorithm::*;
         match v {
+            Bikram => Ok(AnyCalendarKind::BikramSambat),
             Buddhist => Ok(AnyCalendarKind::Buddhist),
             Chinese => Ok(AnyCalendarKind::Chinese),
             Coptic => Ok(AnyCalendarKind::Coptic),
@@ -890,6 +978,7 @@ impl TryFrom<CalendarAlgorithm> for AnyCalendarKind {
             Japanese => Ok(AnyCalendarKind::Japanese),
             Persian => Ok(AnyCalendarKind::Persian),
             Roc => Ok(AnyCalendarKind::Roc),
+            Vietnam => Ok(AnyCalendarKind::Vietnamese),
             _ => {
                 debug_assert!(false, "unknown calendar algorithm {v:?}");
                 Err(())
@@ -954,6 +1043,43 @@ impl IntoAnyCalendar for AnyCalendar {
     }
 }
 
+impl IntoAnyCalendar for BikramSambat {
+    #[inline]
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::BikramSambat(BikramSambat)
+    }
+    #[inline]
+    fn kind(&self) -> AnyCalendarKind {
+        AnyCalendarKind::BikramSambat
+    }
+    #[inline]
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+        if let AnyCalendar::BikramSambat(cal) = any {
+            Ok(cal)
+        } else {
+            Err(any)
+        }
+    }
+    #[inline]
+    fn from_any_ref(any: &AnyCalendar) -> Option<&Self> {
+        if let AnyCalendar::BikramSambat(cal) = any {
+            Some(cal)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::BikramSambat(*d)
+    }
+}
+
+impl From<BikramSambat> for AnyCalendar {
+    fn from(value: BikramSambat) -> AnyCalendar {
+        value.to_any()
+    }
+}
+
 impl IntoAnyCalendar for Buddhist {
     #[inline]
     fn to_any(self) -> AnyCalendar {
@@ -1560,6 +1686,43 @@ impl From<Roc> for AnyCalendar {
     }
 }
 
+impl IntoAnyCalendar for Vietnamese {
+    #[inline]
+    fn to_any(self) -> AnyCalendar {
+        AnyCalendar::Vietnamese(self)
+    }
+    #[inline]
+    fn kind(&self) -> AnyCalendarKind {
+        AnyCalendarKind::Vietnamese
+    }
+    #[inline]
+    fn from_any(any: AnyCalendar) -> Result<Self, AnyCalendar> {
+        if let AnyCalendar::Vietnamese(cal) = any {
+            Ok(cal)
+        } else {
+            Err(any)
+        }
+    }
+    #[inline]
+    fn from_any_ref(any: &AnyCalendar) -> Option<&Self> {
+        if let AnyCalendar::Vietnamese(cal) = any {
+            Some(cal)
+        } else {
+            None
+        }
+    }
+    #[inline]
+    fn date_to_any(&self, d: &Self::DateInner) -> AnyDateInner {
+        AnyDateInner::Vietnamese(*d)
+    }
+}
+
+impl From<Vietnamese> for AnyCalendar {
+    fn from(value: Vietnamese) -> AnyCalendar {
+        value.to_any()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tinystr::tinystr;
@@ -1641,6 +1804,7 @@ mod tests {
 
     #[test]
     fn test_any_construction() {
+        let bikram_sambat = AnyCalendar::new(AnyCalendarKind::BikramSambat);
         let buddhist = AnyCalendar::new(AnyCalendarKind::Buddhist);
         let chinese = AnyCalendar::new(AnyCalendarKind::Chinese);
         let coptic = AnyCalendar::new(AnyCalendarKind::Coptic);
@@ -1659,6 +1823,8 @@ mod tests {
         let japanext = AnyCalendar::new(AnyCalendarKind::JapaneseExtended);
         let persian = AnyCalendar::new(AnyCalendarKind::Persian);
         let roc = AnyCalendar::new(AnyCalendarKind::Roc);
+        let vietnamese = AnyCalendar::new(AnyCalendarKind::Vietnamese);
+        let bikram_sambat = Ref(&bikram_sambat);
         let buddhist = Ref(&buddhist);
         let chinese = Ref(&chinese);
         let coptic = Ref(&coptic);
@@ -1676,6 +1842,7 @@ mod tests {
         let japanext = Ref(&japanext);
         let persian = Ref(&persian);
         let roc = Ref(&roc);
+        let vietnamese = Ref(&vietnamese);
 
         single_test_roundtrip(buddhist, Some(("be", Some(0))), 100, "M03", 1);
         single_test_roundtrip(buddhist, None, 2000, "M03", 1);
@@ -1829,6 +1996,30 @@ mod tests {
             DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M00L"))),
         );
 
+        single_test_roundtrip(vietnamese, None, 400, "M02", 5);
+        single_test_roundtrip(vietnamese, None, 2024, "M08", 29);
+        single_test_roundtrip(vietnamese, None, -1300, "M11", 12);
+        single_test_error(
+            vietnamese,
+            None,
+            2024,
+            "M13",
+            1,
+            DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M13"))),
+        );
+
+        single_test_roundtrip(bikram_sambat, Some(("bs", Some(0))), 2081, "M02", 1);
+        single_test_roundtrip(bikram_sambat, None, 2000, "M12", 30);
+        single_test_roundtrip(bikram_sambat, Some(("bs", Some(0))), 1950, "M06", 15);
+        single_test_error(
+            bikram_sambat,
+            Some(("bs", Some(0))),
+            2081,
+            "M13",
+            1,
+            DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M13"))),
+        );
+
         single_test_roundtrip(japanese, Some(("reiwa", None)), 3, "M03", 1);
         single_test_roundtrip(japanese, Some(("heisei", None)), 6, "M12", 1);
         single_test_roundtrip(japanese, Some(("meiji", None)), 10, "M03", 1);
@@ -1989,4 +2180,32 @@ mod tests {
             DateError::UnknownMonthCode(MonthCode(tinystr!(4, "M9"))),
         );
     }
+
+    #[test]
+    #[cfg(feature = "ixdtf")]
+    fn test_vietnamese_and_bikram_sambat_annotations() {
+        use icu_locale_core::locale;
+
+        let vietnamese = AnyCalendar::new(AnyCalendarKind::new(locale!("vi-u-ca-vietnam").into()));
+        assert_eq!(vietnamese.kind(), AnyCalendarKind::Vietnamese);
+        let date = Date::try_from_str("2025-01-29[u-ca=vietnam]", Ref(&vietnamese)).unwrap();
+        assert_eq!(date.month().standard_code, MonthCode(tinystr!(4, "M01")));
+        assert_eq!(date.day_of_month().0, 1);
+
+        let bikram_sambat =
+            AnyCalendar::new(AnyCalendarKind::new(locale!("ne-u-ca-bikram").into()));
+        assert_eq!(bikram_sambat.kind(), AnyCalendarKind::BikramSambat);
+        let date = Date::try_from_str("2024-05-14[u-ca=bikram]", Ref(&bikram_sambat)).unwrap();
+        assert_eq!(date.extended_year(), 2081);
+        assert_eq!(date.month().standard_code, MonthCode(tinystr!(4, "M02")));
+        assert_eq!(date.day_of_month().0, 1);
+
+        assert!(matches!(
+            Date::try_from_str("2024-05-14[u-ca=vietnam]", Ref(&bikram_sambat)),
+            Err(crate::ParseError::MismatchedCalendar(
+                CalendarAlgorithm::Bikram,
+                CalendarAlgorithm::Vietnam
+            ))
+        ));
+    }
 }
@@ -0,0 +1,365 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Bikram Sambat calendar.
+//!
+//! ```rust
+//! use icu::calendar::{cal::BikramSambat, Date};
+//!
+//! let date_iso = Date::try_new_iso(2024, 4, 13)
+//!     .expect("Failed to initialize ISO Date instance.");
+//! let date_bikram_sambat = Date::new_from_iso(date_iso, BikramSambat);
+//!
+//! assert_eq!(date_bikram_sambat.era_year().year, 2081);
+//! assert_eq!(date_bikram_sambat.month().ordinal, 1);
+//! assert_eq!(date_bikram_sambat.day_of_month().0, 1);
+//! ```
+
+use crate::cal::iso::{Iso, IsoDateInner};
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic, PrecomputedDataSource};
+use crate::error::DateError;
+use crate::{types, Calendar, Date, DateDuration, DateDurationUnit, RangeError};
+use calendrical_calculations::bikram_sambat;
+use calendrical_calculations::rata_die::RataDie;
+use tinystr::tinystr;
+
+/// The [Bikram Sambat Calendar](https://en.wikipedia.org/wiki/Vikram_Samvat)
+///
+/// The Bikram Sambat (or Vikram Samvat) calendar is the official calendar of Nepal. It is a
+/// sidereal solar calendar with twelve months, each of which starts on the day on which the sun
+/// enters the next sign of the sidereal zodiac, as observed in Nepal. This makes months between
+/// 29 and 32 days long. Years start in mid-April, with Bikram Sambat 2081 starting on April 13, 2024.
+///
+/// For the years 2000 to 2081 BS (1943 to 2025 CE), this implementation uses the month lengths
+/// published by Nepal's calendar committee. Outside of this range, it calculates the positions of
+/// the sun astronomically, using the Lahiri ayanamsa, which may differ from the published calendar
+/// by one day when the sun enters a sign close to midnight.
+///
+/// This type can be used with [`Date`] to represent dates in this calendar. CLDR has no month or
+/// era names for this calendar, so ICU4X does not support formatting its dates.
+///
+/// # Era codes
+///
+/// This calendar uses a single era code: `bs`, with Bikram Sambat 1 starting in 57 BCE.
+/// Dates before this era use negative years.
+///
+/// # Month codes
+///
+/// This calendar supports 12 solar month codes (`"M01" - "M12"`), with `"M01"` being Baisakh.
+#[derive(Copy, Clone, Debug, Hash, Default, Eq, PartialEq, PartialOrd, Ord)]
+#[allow(clippy::exhaustive_structs)] // this type is stable
+pub struct BikramSambat;
+
+/// The inner date type used for representing [`Date`]s of [`BikramSambat`]. See [`Date`] and [`BikramSambat`] for more details.
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+pub struct BikramSambatDateInner(ArithmeticDate<BikramSambat>);
+
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct BikramSambatYearInfo {
+    value: i32,
+    new_year: RataDie,
+    /// The length of month `n` minus 29, in bits `2n - 2` and `2n - 1`
+    month_lengths: u32,
+}
+
+impl From<BikramSambatYearInfo> for i32 {
+    fn from(value: BikramSambatYearInfo) -> Self {
+        value.value
+    }
+}
+
+impl BikramSambatYearInfo {
+    fn compute(year: i32) -> Self {
+        let starts = bikram_sambat::month_starts(year);
+        let [new_year, ..] = starts;
+        let month_lengths = starts
+            .iter()
+            .zip(starts.iter().skip(1))
+            .enumerate()
+            .map(|(i, (&start, &next))| ((next - start - 29).clamp(0, 3) as u32) << (2 * i))
+            .fold(0, |acc, bits| acc | bits);
+        Self {
+            value: year,
+            new_year,
+            month_lengths,
+        }
+    }
+
+    fn month_len(self, month: u8) -> u8 {
+        if (1..=12).contains(&month) {
+            29 + ((self.month_lengths >> (2 * (month - 1))) & 3) as u8
+        } else {
+            0
+        }
+    }
+
+    fn days_before_month(self, month: u8) -> u16 {
+        (1..month).map(|m| u16::from(self.month_len(m))).sum()
+    }
+
+    /// The month and day of a 1-indexed day of the year
+    fn md_from_day_of_year(self, mut day_of_year: u16) -> (u8, u8) {
+        let mut month = 1;
+        while month < 12 && day_of_year > u16::from(self.month_len(month)) {
+            day_of_year -= u16::from(self.month_len(month));
+            month += 1;
+        }
+        (month, day_of_year as u8)
+    }
+}
+
+impl CalendarArithmetic for BikramSambat {
+    type YearInfo = BikramSambatYearInfo;
+
+    fn days_in_provided_month(info: BikramSambatYearInfo, month: u8) -> u8 {
+        info.month_len(month)
+    }
+
+    fn months_in_provided_year(_: BikramSambatYearInfo) -> u8 {
+        12
+    }
+
+    fn provided_year_is_leap(info: BikramSambatYearInfo) -> bool {
+        Self::days_in_provided_year(info) > 365
+    }
+
+    fn last_month_day_in_provided_year(info: BikramSambatYearInfo) -> (u8, u8) {
+        (12, info.month_len(12))
+    }
+
+    fn days_in_provided_year(info: BikramSambatYearInfo) -> u16 {
+        info.days_before_month(13)
+    }
+}
+
+impl PrecomputedDataSource<BikramSambatYearInfo> for () {
+    fn load_or_compute_info(&self, year: i32) -> BikramSambatYearInfo {
+        BikramSambatYearInfo::compute(year)
+    }
+}
+
+impl crate::cal::scaffold::UnstableSealed for BikramSambat {}
+impl Calendar for BikramSambat {
+    type DateInner = BikramSambatDateInner;
+    type Year = types::EraYear;
+    fn from_codes(
+        &self,
+        era: Option<&str>,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateError> {
+        let year = match era {
+            Some("bs") | None => year,
+            Some(_) => return Err(DateError::UnknownEra),
+        };
+        let year = BikramSambatYearInfo::compute(year);
+        let month = match month_code.parsed() {
+            Some((month, false)) if (1..=12).contains(&month) => month,
+            _ => return Err(DateError::UnknownMonthCode(month_code)),
+        };
+        ArithmeticDate::new_from_ordinals(year, month, day)
+            .map(BikramSambatDateInner)
+            .map_err(Into::into)
+    }
+
+    fn from_rata_die(&self, rd: RataDie) -> Self::DateInner {
+        let year = BikramSambatYearInfo::compute(bikram_sambat::bikram_sambat_year_from_fixed(rd));
+        let day_of_year = (rd - year.new_year + 1).clamp(1, 366) as u16;
+        let (month, day) = year.md_from_day_of_year(day_of_year);
+        BikramSambatDateInner(ArithmeticDate::new_unchecked(year, month, day))
+    }
+
+    fn to_rata_die(&self, date: &Self::DateInner) -> RataDie {
+        date.0.year.new_year
+            + i64::from(date.0.year.days_before_month(date.0.month))
+            + i64::from(date.0.day)
+            - 1
+    }
+
+    fn from_iso(&self, iso: IsoDateInner) -> BikramSambatDateInner {
+        self.from_rata_die(Iso.to_rata_die(&iso))
+    }
+
+    fn to_iso(&self, date: &Self::DateInner) -> IsoDateInner {
+        Iso.from_rata_die(self.to_rata_die(date))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: DateDuration<Self>) {
+        date.0.offset_date(offset, &());
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        _largest_unit: DateDurationUnit,
+        _smallest_unit: DateDurationUnit,
+    ) -> DateDuration<Self> {
+        date1.0.until(date2.0, _largest_unit, _smallest_unit)
+    }
+
+    fn year_info(&self, date: &Self::DateInner) -> Self::Year {
+        types::EraYear {
+            era_index: Some(0),
+            era: tinystr!(16, "bs"),
+            year: self.extended_year(date),
+            ambiguity: types::YearAmbiguity::CenturyRequired,
+        }
+    }
+
+    fn extended_year(&self, date: &Self::DateInner) -> i32 {
+        date.0.extended_year()
+    }
+
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        Self::provided_year_is_leap(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> types::MonthInfo {
+        date.0.month()
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year(&self, date: &Self::DateInner) -> types::DayOfYear {
+        date.0.day_of_year()
+    }
+
+    fn debug_name(&self) -> &'static str {
+        "Bikram Sambat"
+    }
+
+    fn calendar_algorithm(&self) -> Option<crate::preferences::CalendarAlgorithm> {
+        Some(crate::preferences::CalendarAlgorithm::Bikram)
+    }
+}
+
+impl BikramSambat {
+    /// Construct a new Bikram Sambat Calendar
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Date<BikramSambat> {
+    /// Construct new Bikram Sambat Date, with year provided in the Bikram Sambat era.
+    ///
+    /// ```rust
+    /// use icu::calendar::Date;
+    ///
+    /// let date_bikram_sambat = Date::try_new_bikram_sambat(2081, 2, 1)
+    ///     .expect("Failed to initialize Bikram Sambat Date instance.");
+    ///
+    /// assert_eq!(date_bikram_sambat.era_year().year, 2081);
+    /// assert_eq!(date_bikram_sambat.month().ordinal, 2);
+    /// assert_eq!(date_bikram_sambat.day_of_month().0, 1);
+    /// assert_eq!(
+    ///     date_bikram_sambat.to_iso(),
+    ///     Date::try_new_iso(2024, 5, 14).unwrap()
+    /// );
+    /// ```
+    pub fn try_new_bikram_sambat(
+        year: i32,
+        month: u8,
+        day: u8,
+    ) -> Result<Date<BikramSambat>, RangeError> {
+        ArithmeticDate::new_from_ordinals(BikramSambatYearInfo::compute(year), month, day)
+            .map(BikramSambatDateInner)
+            .map(|inner| Date::from_raw(inner, BikramSambat))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_year() {
+        for (year, (iso_year, iso_month, iso_day)) in [
+            (2075, (2018, 4, 14)),
+            (2077, (2020, 4, 13)),
+            (2081, (2024, 4, 13)),
+            (2082, (2025, 4, 14)),
+        ] {
+            let iso = Date::try_new_iso(iso_year, iso_month, iso_day).unwrap();
+            let new_year = Date::try_new_bikram_sambat(year, 1, 1).unwrap();
+            assert_eq!(new_year.to_iso(), iso);
+            assert_eq!(iso.to_calendar(BikramSambat), new_year);
+
+            let new_years_eve = Date::from_rata_die(iso.to_rata_die() - 1, BikramSambat);
+            assert_eq!(new_years_eve.era_year().year, year - 1);
+            assert_eq!(new_years_eve.month().ordinal, 12);
+            assert_eq!(
+                new_years_eve.day_of_month().0,
+                new_years_eve.days_in_month()
+            );
+            assert_eq!(new_years_eve.day_of_year().0, new_years_eve.days_in_year());
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        // 2070 BS to 2090 BS
+        let start = Date::try_new_iso(2013, 4, 1).unwrap().to_rata_die();
+        for offset in 0..(20 * 366) {
+            let rd = start + offset;
+            let date = Date::from_rata_die(rd, BikramSambat);
+            assert_eq!(date.to_rata_die(), rd, "{date:?}");
+            let year = date.era_year().year;
+            assert_eq!(
+                Date::try_new_bikram_sambat(year, date.month().ordinal, date.day_of_month().0),
+                Ok(date)
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_codes() {
+        let date = Date::try_new_from_codes(
+            Some("bs"),
+            2081,
+            types::MonthCode(tinystr!(4, "M10")),
+            1,
+            BikramSambat,
+        )
+        .unwrap();
+        assert_eq!(date.to_iso(), Date::try_new_iso(2025, 1, 14).unwrap());
+        assert!(matches!(
+            Date::try_new_from_codes(
+                Some("bs"),
+                2081,
+                types::MonthCode(tinystr!(4, "M10L")),
+                1,
+                BikramSambat,
+            ),
+            Err(DateError::UnknownMonthCode(_))
+        ));
+        assert!(matches!(
+            Date::try_new_from_codes(
+                Some("bs"),
+                2081,
+                types::MonthCode(tinystr!(4, "M10")),
+                33,
+                BikramSambat,
+            ),
+            Err(DateError::Range { .. })
+        ));
+    }
+}
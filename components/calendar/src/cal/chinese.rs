@@ -282,6 +282,13 @@ impl Calendar for Chinese {
         Some(crate::preferences::CalendarAlgorithm::Chinese)
     }
 
+    fn constrain_leap_month_code(&self, month_code: types::MonthCode) -> Option<types::MonthCode> {
+        month_code
+            .parsed()
+            .filter(|&(number, is_leap)| is_leap && (1..=12).contains(&number))
+            .and_then(|_| month_code.get_normal_if_leap())
+    }
+
     fn months_in_year(&self, date: &Self::DateInner) -> u8 {
         date.0.months_in_year()
     }
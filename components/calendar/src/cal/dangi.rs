@@ -267,6 +267,13 @@ impl Calendar for Dangi {
     fn calendar_algorithm(&self) -> Option<crate::preferences::CalendarAlgorithm> {
         Some(crate::preferences::CalendarAlgorithm::Dangi)
     }
+
+    fn constrain_leap_month_code(&self, month_code: types::MonthCode) -> Option<types::MonthCode> {
+        month_code
+            .parsed()
+            .filter(|&(number, is_leap)| is_leap && (1..=12).contains(&number))
+            .and_then(|_| month_code.get_normal_if_leap())
+    }
 }
 
 impl<A: AsCalendar<Calendar = Dangi>> Date<A> {
@@ -336,6 +336,11 @@ impl Calendar for Hebrew {
     fn calendar_algorithm(&self) -> Option<crate::preferences::CalendarAlgorithm> {
         Some(crate::preferences::CalendarAlgorithm::Hebrew)
     }
+
+    fn constrain_leap_month_code(&self, month_code: types::MonthCode) -> Option<types::MonthCode> {
+        // Adar I only exists in leap years, Adar is used in common years
+        (month_code.0 == "M05L").then(|| types::MonthCode(tinystr!(4, "M06")))
+    }
 }
 
 impl Date<Hebrew> {
@@ -3,6 +3,7 @@
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
 //! Types for individual calendars
+pub(crate) mod bikram_sambat;
 pub(crate) mod buddhist;
 pub(crate) mod chinese;
 pub(crate) mod chinese_based;
@@ -18,7 +19,9 @@ pub(crate) mod japanese;
 pub(crate) mod julian;
 pub(crate) mod persian;
 pub(crate) mod roc;
+pub(crate) mod vietnamese;
 
+pub use bikram_sambat::BikramSambat;
 pub use buddhist::Buddhist;
 pub use chinese::Chinese;
 pub use coptic::Coptic;
@@ -35,6 +38,7 @@ pub use japanese::{Japanese, JapaneseExtended};
 pub use julian::Julian;
 pub use persian::Persian;
 pub use roc::Roc;
+pub use vietnamese::Vietnamese;
 
 pub use crate::any_calendar::{AnyCalendar, AnyCalendarKind};
 
@@ -0,0 +1,406 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! This module contains types and implementations for the Vietnamese calendar.
+//!
+//! ```rust
+//! use icu::calendar::cal::Vietnamese;
+//! use icu::calendar::Date;
+//!
+//! let vietnamese = Vietnamese::new();
+//! let vietnamese_date =
+//!     Date::try_new_vietnamese_with_calendar(2023, 6, 6, vietnamese)
+//!         .expect("Failed to initialize Vietnamese Date instance.");
+//!
+//! assert_eq!(vietnamese_date.cyclic_year().related_iso, 2023);
+//! assert_eq!(vietnamese_date.cyclic_year().year, 40);
+//! assert_eq!(vietnamese_date.month().ordinal, 6);
+//! assert_eq!(vietnamese_date.day_of_month().0, 6);
+//! ```
+
+use crate::cal::chinese_based::{ChineseBasedPrecomputedData, ChineseBasedWithDataLoading};
+use crate::cal::iso::{Iso, IsoDateInner};
+use crate::calendar_arithmetic::PrecomputedDataSource;
+use crate::calendar_arithmetic::{ArithmeticDate, CalendarArithmetic};
+use crate::error::DateError;
+use crate::provider::chinese_based::CalendarVietnameseV1;
+use crate::types::CyclicYear;
+use crate::AsCalendar;
+use crate::{types, Calendar, Date};
+use calendrical_calculations::chinese_based;
+use calendrical_calculations::rata_die::RataDie;
+use core::cmp::Ordering;
+use icu_provider::prelude::*;
+
+/// The [Vietnamese Calendar](https://en.wikipedia.org/wiki/Vietnamese_calendar)
+///
+/// The Vietnamese calendar is a lunisolar calendar used traditionally in Vietnam. It is used
+/// today to track important cultural events and holidays like Tết (Vietnamese lunar new year).
+/// It uses the same rules as the Chinese lunar calendar (see [`Chinese`](super::Chinese)),
+/// except that since 1968 observations are based in Vietnam (UTC+7) rather than China (UTC+8).
+/// This can cause some differences; for example, Tết 1985 was on January 21, while the
+/// Chinese new year was on February 20, as the calendars assigned a different leap month.
+///
+/// This calendar is currently in a preview state: formatting for this calendar is not
+/// going to be perfect.
+///
+/// ```rust
+/// use icu::calendar::cal::{Chinese, Vietnamese};
+/// use icu::calendar::Date;
+/// use tinystr::tinystr;
+///
+/// let iso = Date::try_new_iso(1985, 1, 21).unwrap();
+/// let vietnamese = iso.to_calendar(Vietnamese::new());
+/// let chinese = iso.to_calendar(Chinese::new());
+///
+/// assert_eq!(vietnamese.cyclic_year().related_iso, 1985);
+/// assert_eq!(vietnamese.month().standard_code.0, tinystr!(4, "M01"));
+/// assert_eq!(vietnamese.day_of_month().0, 1);
+///
+/// assert_eq!(chinese.cyclic_year().related_iso, 1984);
+/// assert_eq!(chinese.month().standard_code.0, tinystr!(4, "M12"));
+/// ```
+/// # Era codes
+///
+/// This calendar does not use era codes.
+///
+/// # Month codes
+///
+/// This calendar is a lunisolar calendar. It supports regular month codes `"M01" - "M12"` as well
+/// as leap month codes `"M01L" - "M12L"`.
+#[derive(Clone, Debug, Default)]
+pub struct Vietnamese {
+    data: Option<DataPayload<CalendarVietnameseV1>>,
+}
+
+/// The inner date type used for representing [`Date`]s of [`Vietnamese`]. See [`Date`] and [`Vietnamese`] for more detail.
+#[derive(Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub struct VietnameseDateInner(ArithmeticDate<Vietnamese>);
+
+// we want these impls without the `C: Copy/Clone` bounds
+impl Copy for VietnameseDateInner {}
+impl Clone for VietnameseDateInner {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+// These impls just make custom derives on types containing C
+// work. They're basically no-ops
+impl PartialEq for Vietnamese {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+impl Eq for Vietnamese {}
+#[expect(clippy::non_canonical_partial_ord_impl)] // this is intentional
+impl PartialOrd for Vietnamese {
+    fn partial_cmp(&self, _: &Self) -> Option<Ordering> {
+        Some(Ordering::Equal)
+    }
+}
+
+impl Ord for Vietnamese {
+    fn cmp(&self, _: &Self) -> Ordering {
+        Ordering::Equal
+    }
+}
+
+impl Vietnamese {
+    /// Creates a new [`Vietnamese`] with some precomputed calendrical calculations.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            data: Some(DataPayload::from_static_ref(
+                crate::provider::Baked::SINGLETON_CALENDAR_VIETNAMESE_V1,
+            )),
+        }
+    }
+
+    icu_provider::gen_buffer_data_constructors!(() -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+    ]);
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D: DataProvider<CalendarVietnameseV1> + ?Sized>(
+        provider: &D,
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            data: Some(provider.load(Default::default())?.payload),
+        })
+    }
+
+    /// Construct a new [`Vietnamese`] without any precomputed calendrical calculations.
+    pub fn new_always_calculating() -> Self {
+        Vietnamese { data: None }
+    }
+
+    pub(crate) const DEBUG_NAME: &'static str = "Vietnamese";
+}
+
+impl crate::cal::scaffold::UnstableSealed for Vietnamese {}
+impl Calendar for Vietnamese {
+    type DateInner = VietnameseDateInner;
+    type Year = CyclicYear;
+
+    fn from_codes(
+        &self,
+        era: Option<&str>,
+        year: i32,
+        month_code: crate::types::MonthCode,
+        day: u8,
+    ) -> Result<Self::DateInner, DateError> {
+        match era {
+            None => {}
+            _ => return Err(DateError::UnknownEra),
+        }
+
+        let year = self.get_precomputed_data().load_or_compute_info(year);
+
+        let Some(month) = year.parse_month_code(month_code) else {
+            return Err(DateError::UnknownMonthCode(month_code));
+        };
+
+        year.validate_md(month, day)?;
+
+        Ok(VietnameseDateInner(ArithmeticDate::new_unchecked(
+            year, month, day,
+        )))
+    }
+
+    fn from_rata_die(&self, rd: RataDie) -> Self::DateInner {
+        let iso = Iso.from_rata_die(rd);
+        let y = self
+            .get_precomputed_data()
+            .load_or_compute_info_for_rd(rd, iso.0);
+        let (m, d) = y.md_from_rd(rd);
+        VietnameseDateInner(ArithmeticDate::new_unchecked(y, m, d))
+    }
+
+    fn to_rata_die(&self, date: &Self::DateInner) -> RataDie {
+        date.0.year.rd_from_md(date.0.month, date.0.day)
+    }
+
+    fn from_iso(&self, iso: IsoDateInner) -> Self::DateInner {
+        let rd = Iso.to_rata_die(&iso);
+        let y = self
+            .get_precomputed_data()
+            .load_or_compute_info_for_rd(rd, iso.0);
+        let (m, d) = y.md_from_rd(rd);
+        VietnameseDateInner(ArithmeticDate::new_unchecked(y, m, d))
+    }
+
+    fn to_iso(&self, date: &Self::DateInner) -> IsoDateInner {
+        Iso.from_rata_die(self.to_rata_die(date))
+    }
+
+    fn months_in_year(&self, date: &Self::DateInner) -> u8 {
+        date.0.months_in_year()
+    }
+
+    fn days_in_year(&self, date: &Self::DateInner) -> u16 {
+        date.0.days_in_year()
+    }
+
+    fn days_in_month(&self, date: &Self::DateInner) -> u8 {
+        date.0.days_in_month()
+    }
+
+    fn offset_date(&self, date: &mut Self::DateInner, offset: crate::DateDuration<Self>) {
+        date.0.offset_date(offset, &self.get_precomputed_data());
+    }
+
+    fn until(
+        &self,
+        date1: &Self::DateInner,
+        date2: &Self::DateInner,
+        _calendar2: &Self,
+        largest_unit: crate::DateDurationUnit,
+        smallest_unit: crate::DateDurationUnit,
+    ) -> crate::DateDuration<Self> {
+        date1.0.until(date2.0, largest_unit, smallest_unit)
+    }
+
+    fn debug_name(&self) -> &'static str {
+        Self::DEBUG_NAME
+    }
+
+    fn year_info(&self, date: &Self::DateInner) -> Self::Year {
+        let year = date.0.year;
+        CyclicYear {
+            year: (year.related_iso as i64 - 4).rem_euclid(60) as u8 + 1,
+            related_iso: year.related_iso,
+        }
+    }
+
+    fn extended_year(&self, date: &Self::DateInner) -> i32 {
+        chinese_based::extended_from_iso::<chinese_based::Vietnamese>(date.0.year.related_iso)
+    }
+
+    fn is_in_leap_year(&self, date: &Self::DateInner) -> bool {
+        Self::provided_year_is_leap(date.0.year)
+    }
+
+    fn month(&self, date: &Self::DateInner) -> crate::types::MonthInfo {
+        date.0.year.month(date.0.month)
+    }
+
+    fn day_of_month(&self, date: &Self::DateInner) -> crate::types::DayOfMonth {
+        date.0.day_of_month()
+    }
+
+    fn day_of_year(&self, date: &Self::DateInner) -> crate::types::DayOfYear {
+        types::DayOfYear(date.0.year.day_of_year(date.0.month, date.0.day))
+    }
+
+    fn calendar_algorithm(&self) -> Option<crate::preferences::CalendarAlgorithm> {
+        Some(crate::preferences::CalendarAlgorithm::Vietnam)
+    }
+
+    fn constrain_leap_month_code(&self, month_code: types::MonthCode) -> Option<types::MonthCode> {
+        month_code
+            .parsed()
+            .filter(|&(number, is_leap)| is_leap && (1..=12).contains(&number))
+            .and_then(|_| month_code.get_normal_if_leap())
+    }
+}
+
+impl<A: AsCalendar<Calendar = Vietnamese>> Date<A> {
+    /// Construct a new Vietnamese date from a `year`, `month`, and `day`.
+    /// `year` represents the [ISO](crate::Iso) year that roughly matches the Vietnamese year;
+    /// `month` represents the month of the year ordinally (ex. if it is a leap year, the last month will be 13, not 12);
+    /// `day` indicates day of month.
+    ///
+    /// ```rust
+    /// use icu::calendar::cal::Vietnamese;
+    /// use icu::calendar::Date;
+    ///
+    /// let vietnamese = Vietnamese::new();
+    ///
+    /// let date_vietnamese =
+    ///     Date::try_new_vietnamese_with_calendar(2023, 6, 18, vietnamese)
+    ///         .expect("Failed to initialize Vietnamese Date instance.");
+    ///
+    /// assert_eq!(date_vietnamese.cyclic_year().related_iso, 2023);
+    /// assert_eq!(date_vietnamese.cyclic_year().year, 40);
+    /// assert_eq!(date_vietnamese.month().ordinal, 6);
+    /// assert_eq!(date_vietnamese.day_of_month().0, 18);
+    /// ```
+    pub fn try_new_vietnamese_with_calendar(
+        related_iso_year: i32,
+        month: u8,
+        day: u8,
+        calendar: A,
+    ) -> Result<Date<A>, DateError> {
+        let year = calendar
+            .as_calendar()
+            .get_precomputed_data()
+            .load_or_compute_info(related_iso_year);
+        year.validate_md(month, day)?;
+        Ok(Date::from_raw(
+            VietnameseDateInner(ArithmeticDate::new_unchecked(year, month, day)),
+            calendar,
+        ))
+    }
+}
+
+impl ChineseBasedWithDataLoading for Vietnamese {
+    type CB = calendrical_calculations::chinese_based::Vietnamese;
+    fn get_precomputed_data(&self) -> ChineseBasedPrecomputedData<Self::CB> {
+        ChineseBasedPrecomputedData::new(self.data.as_ref().map(|d| d.get()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+    use crate::cal::Chinese;
+    use calendrical_calculations::rata_die::RataDie;
+
+    /// Run a test twice, with two calendars
+    fn do_twice(
+        vietnamese_calculating: &Vietnamese,
+        vietnamese_cached: &Vietnamese,
+        test: impl Fn(crate::Ref<Vietnamese>, &'static str),
+    ) {
+        test(crate::Ref(vietnamese_calculating), "calculating");
+        test(crate::Ref(vietnamese_cached), "cached");
+    }
+
+    #[test]
+    fn test_same_as_chinese_before_1968() {
+        let chinese = Chinese::new_always_calculating();
+        let vietnamese = Vietnamese::new_always_calculating();
+        for year in 1900..1968 {
+            let iso = Date::try_new_iso(year, 2, 20).unwrap();
+            let chinese = iso.to_calendar(crate::Ref(&chinese));
+            let vietnamese = iso.to_calendar(crate::Ref(&vietnamese));
+            assert_eq!(chinese.cyclic_year(), vietnamese.cyclic_year(), "{iso:?}");
+            assert_eq!(chinese.month(), vietnamese.month(), "{iso:?}");
+            assert_eq!(chinese.day_of_month(), vietnamese.day_of_month(), "{iso:?}");
+        }
+    }
+
+    #[test]
+    fn test_tet() {
+        let vietnamese_calculating = Vietnamese::new_always_calculating();
+        let vietnamese_cached = Vietnamese::new();
+
+        for (year, month, day) in [(1968, 1, 29), (1985, 1, 21), (2007, 2, 17), (2024, 2, 10)] {
+            let iso = Date::try_new_iso(year, month, day).unwrap();
+            do_twice(
+                &vietnamese_calculating,
+                &vietnamese_cached,
+                |vietnamese, calendar_type| {
+                    let tet = iso.to_calendar(vietnamese);
+                    assert_eq!(
+                        tet.cyclic_year().related_iso,
+                        year,
+                        "[{calendar_type}] {iso:?}"
+                    );
+                    assert_eq!(tet.month().ordinal, 1, "[{calendar_type}] {iso:?}");
+                    assert_eq!(tet.day_of_month().0, 1, "[{calendar_type}] {iso:?}");
+                },
+            );
+        }
+    }
+
+    #[test]
+    fn test_iso_to_vietnamese_roundtrip() {
+        let mut rd = -1963020;
+        let max_rd = 1963020;
+        let mut iters = 0;
+        let max_iters = 560;
+        let vietnamese_calculating = Vietnamese::new_always_calculating();
+        let vietnamese_cached = Vietnamese::new();
+        while rd < max_rd && iters < max_iters {
+            let rata_die = RataDie::new(rd);
+            let iso = Date::from_rata_die(rata_die, Iso);
+            do_twice(
+                &vietnamese_calculating,
+                &vietnamese_cached,
+                |vietnamese, calendar_type| {
+                    let vietnamese = iso.to_calendar(vietnamese);
+                    let result = vietnamese.to_calendar(Iso);
+                    assert_eq!(
+                        iso, result,
+                        "[{calendar_type}] Failed roundtrip ISO -> Vietnamese -> ISO for RD: {rd}"
+                    );
+                },
+            );
+
+            rd += 7043;
+            iters += 1;
+        }
+    }
+}
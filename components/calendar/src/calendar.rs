@@ -94,6 +94,16 @@ pub trait Calendar: crate::cal::scaffold::UnstableSealed {
         smallest_unit: DateDurationUnit,
     ) -> DateDuration<Self>;
 
+    #[doc(hidden)] // unstable
+    /// The month code that the leap month `month_code` is replaced with in years that
+    /// do not contain it, when constraining out-of-range fields.
+    ///
+    /// Returns `None` if `month_code` is not a leap month of this calendar.
+    fn constrain_leap_month_code(&self, month_code: types::MonthCode) -> Option<types::MonthCode> {
+        let _ = month_code;
+        None
+    }
+
     /// Returns the [`CalendarAlgorithm`](crate::preferences::CalendarAlgorithm) that is required to match
     /// when parsing into this calendar.
     ///
@@ -171,22 +171,20 @@ impl<C: CalendarArithmetic> ArithmeticDate<C> {
     }
 
     #[inline]
-    fn offset_months(
-        &mut self,
-        mut month_offset: i32,
-        data: &impl PrecomputedDataSource<C::YearInfo>,
-    ) {
-        while month_offset != 0 {
-            let year_months = C::months_in_provided_year(self.year);
-            if self.month as i32 + month_offset > year_months as i32 {
+    fn offset_months(&mut self, month_offset: i32, data: &impl PrecomputedDataSource<C::YearInfo>) {
+        // The ordinal month in the year of `self.year`, which may be outside of that year
+        let mut month = self.month as i32 + month_offset;
+        loop {
+            let year_months = C::months_in_provided_year(self.year) as i32;
+            if month > year_months {
                 self.year = data.load_or_compute_info(self.year.into() + 1);
-                month_offset -= year_months as i32;
-            } else if self.month as i32 + month_offset < 1 {
+                month -= year_months;
+            } else if month < 1 {
                 self.year = data.load_or_compute_info(self.year.into() - 1);
-                month_offset += C::months_in_provided_year(self.year) as i32;
+                month += C::months_in_provided_year(self.year) as i32;
             } else {
-                self.month = (self.month as i32 + month_offset) as u8;
-                month_offset = 0
+                self.month = month as u8;
+                break;
             }
         }
     }
@@ -5,6 +5,7 @@
 use crate::any_calendar::{AnyCalendar, IntoAnyCalendar};
 use crate::calendar_arithmetic::CalendarArithmetic;
 use crate::error::DateError;
+use crate::options::{DateAddOptions, DateDifferenceOptions, DateFromFieldsOptions, Overflow};
 use crate::types::{CyclicYear, EraYear, IsoWeekOfYear};
 use crate::week::{RelativeUnit, WeekCalculator, WeekOf};
 use crate::{types, Calendar, DateDuration, DateDurationUnit, Iso};
@@ -13,6 +14,7 @@ use alloc::rc::Rc;
 #[cfg(feature = "alloc")]
 use alloc::sync::Arc;
 use calendrical_calculations::rata_die::RataDie;
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops::Deref;
 
@@ -134,6 +136,74 @@ impl<A: AsCalendar> Date<A> {
         Ok(Date { inner, calendar })
     }
 
+    /// Construct a date from era/month codes and fields, handling fields that do not exist
+    /// in the given year according to `options`
+    ///
+    /// With [`Overflow::Constrain`], a day past the end of the month is replaced by the last
+    /// day of the month, and a leap month that does not exist in the given year is replaced
+    /// by the corresponding month of that year. With [`Overflow::Reject`], this behaves like
+    /// [`Self::try_new_from_codes()`].
+    ///
+    /// This follows the Temporal
+    /// [`CalendarDateFromFields`](https://tc39.es/proposal-temporal/#sec-temporal-calendardatefromfields)
+    /// operation. The year is `extended_year` if no era is provided.
+    ///
+    /// ```rust
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::calendar::options::{DateFromFieldsOptions, Overflow};
+    /// use icu::calendar::types::MonthCode;
+    /// use icu::calendar::Date;
+    /// use tinystr::tinystr;
+    ///
+    /// // Adar I only exists in leap years
+    /// let adar_i = MonthCode(tinystr!(4, "M05L"));
+    ///
+    /// let date = Date::try_new_from_codes_with_options(
+    ///     None,
+    ///     5785,
+    ///     adar_i,
+    ///     30,
+    ///     Default::default(),
+    ///     Hebrew,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(date.month().standard_code.0, "M06");
+    /// assert_eq!(date.day_of_month().0, 29);
+    ///
+    /// let mut options = DateFromFieldsOptions::default();
+    /// options.overflow = Overflow::Reject;
+    /// assert!(Date::try_new_from_codes_with_options(None, 5785, adar_i, 30, options, Hebrew)
+    ///     .is_err());
+    /// ```
+    pub fn try_new_from_codes_with_options(
+        era: Option<&str>,
+        year: i32,
+        month_code: types::MonthCode,
+        day: u8,
+        options: DateFromFieldsOptions,
+        calendar: A,
+    ) -> Result<Self, DateError> {
+        let cal = calendar.as_calendar();
+        let inner = match cal.from_codes(era, year, month_code, day) {
+            Ok(inner) => inner,
+            Err(e) if options.overflow == Overflow::Reject || day == 0 => return Err(e),
+            Err(_) => {
+                let mut inner = cal.from_codes(era, year, month_code, 1).or_else(|e| {
+                    match (e, cal.constrain_leap_month_code(month_code)) {
+                        (DateError::UnknownMonthCode(_), Some(constrained)) => {
+                            cal.from_codes(era, year, constrained, 1)
+                        }
+                        _ => Err(e),
+                    }
+                })?;
+                let day = day.min(cal.days_in_month(&inner));
+                cal.offset_date(&mut inner, DateDuration::new(0, 0, 0, i32::from(day) - 1));
+                inner
+            }
+        };
+        Ok(Date { inner, calendar })
+    }
+
     /// Construct a date from a [`RataDie`] and some calendar representation
     #[inline]
     pub fn from_rata_die(rd: RataDie, calendar: A) -> Self {
@@ -227,6 +297,66 @@ impl<A: AsCalendar> Date<A> {
         )
     }
 
+    /// Add a `duration` to this date, mutating it, and handling days and months that do not
+    /// exist in the resulting year according to `options`
+    ///
+    /// The years are added first, keeping the month code, then the months, and then the weeks
+    /// and days. Only the day and month reached after adding the years and months are subject
+    /// to `options`. This follows the Temporal
+    /// [`CalendarDateAdd`](https://tc39.es/proposal-temporal/#sec-temporal-calendardateadd)
+    /// operation.
+    ///
+    /// If this returns an error, the date is left unchanged.
+    #[doc(hidden)] // unstable
+    pub fn try_add_with_options(
+        &mut self,
+        duration: DateDuration<A::Calendar>,
+        options: DateAddOptions,
+    ) -> Result<(), DateError> {
+        self.inner = add(
+            self.calendar.as_calendar(),
+            &self.inner,
+            duration,
+            options.overflow,
+        )?;
+        Ok(())
+    }
+
+    /// Add a `duration` to this date, returning the new one, and handling days and months that
+    /// do not exist in the resulting year according to `options`
+    ///
+    /// See [`Self::try_add_with_options()`].
+    #[doc(hidden)] // unstable
+    pub fn try_added_with_options(
+        mut self,
+        duration: DateDuration<A::Calendar>,
+        options: DateAddOptions,
+    ) -> Result<Self, DateError> {
+        self.try_add_with_options(duration, options)?;
+        Ok(self)
+    }
+
+    /// Calculating the duration between `other - self`, such that adding it to `self` with
+    /// [`Overflow::Constrain`] results in `other`
+    ///
+    /// The duration uses no units larger than [`DateDifferenceOptions::largest_unit`]. This
+    /// follows the Temporal
+    /// [`CalendarDateUntil`](https://tc39.es/proposal-temporal/#sec-temporal-calendardateuntil)
+    /// operation.
+    #[doc(hidden)] // unstable
+    pub fn until_with_options<B: AsCalendar<Calendar = A::Calendar>>(
+        &self,
+        other: &Date<B>,
+        options: DateDifferenceOptions,
+    ) -> DateDuration<A::Calendar> {
+        until(
+            self.calendar.as_calendar(),
+            &self.inner,
+            &other.inner,
+            options.largest_unit.unwrap_or(DateDurationUnit::Days),
+        )
+    }
+
     /// The calendar-specific year-info.
     ///
     /// This returns an enum, see [`Date::era_year()`] and [`Date::cyclic_year()`] which are available
@@ -303,6 +433,189 @@ impl<A: AsCalendar> Date<A> {
     }
 }
 
+/// Moves `date` to the first day of its year.
+pub(crate) fn start_of_year<C: Calendar>(cal: &C, date: &mut C::DateInner) {
+    let month = i32::from(cal.month(date).ordinal);
+    let day = i32::from(cal.day_of_month(date).0);
+    cal.offset_date(date, DateDuration::new(0, 1 - month, 0, 1 - day));
+}
+
+/// Returns the first day of the month with code `month_code` in the year that starts
+/// with `year_start`.
+pub(crate) fn month_with_code<C: Calendar>(
+    cal: &C,
+    year_start: &C::DateInner,
+    month_code: types::MonthCode,
+) -> Option<C::DateInner> {
+    let mut month = *year_start;
+    for _ in 0..cal.months_in_year(year_start) {
+        if cal.month(&month).standard_code == month_code {
+            return Some(month);
+        }
+        cal.offset_date(&mut month, DateDuration::new(0, 1, 0, 0));
+    }
+    None
+}
+
+/// Adds `years` and `months` to `date`, returning the first day of the resulting month.
+///
+/// The years are added keeping the month code, which is constrained or rejected according to
+/// `overflow` if it does not exist in the resulting year.
+fn add_years_and_months<C: Calendar>(
+    cal: &C,
+    date: &C::DateInner,
+    years: i32,
+    months: i32,
+    overflow: Overflow,
+) -> Result<C::DateInner, DateError> {
+    let mut result = *date;
+    if years == 0 {
+        let day = i32::from(cal.day_of_month(date).0);
+        cal.offset_date(&mut result, DateDuration::new(0, months, 0, 1 - day));
+        return Ok(result);
+    }
+
+    start_of_year(cal, &mut result);
+    cal.offset_date(&mut result, DateDuration::new(years, 0, 0, 0));
+
+    let find_month = |month_code| month_with_code(cal, &result, month_code);
+    let month_code = cal.month(date).standard_code;
+    result = find_month(month_code)
+        .or_else(|| match overflow {
+            Overflow::Constrain => cal
+                .constrain_leap_month_code(month_code)
+                .and_then(find_month),
+            Overflow::Reject => None,
+        })
+        .ok_or(DateError::UnknownMonthCode(month_code))?;
+
+    cal.offset_date(&mut result, DateDuration::new(0, months, 0, 0));
+    Ok(result)
+}
+
+/// Implementation of [`Date::try_add_with_options()`].
+fn add<C: Calendar>(
+    cal: &C,
+    date: &C::DateInner,
+    duration: DateDuration<C>,
+    overflow: Overflow,
+) -> Result<C::DateInner, DateError> {
+    let mut result = add_years_and_months(cal, date, duration.years, duration.months, overflow)?;
+
+    let day = cal.day_of_month(date).0;
+    let days_in_month = cal.days_in_month(&result);
+    let day = if day <= days_in_month {
+        day
+    } else if overflow == Overflow::Constrain {
+        days_in_month
+    } else {
+        return Err(DateError::Range {
+            field: "day",
+            value: i32::from(day),
+            min: 1,
+            max: i32::from(days_in_month),
+        });
+    };
+
+    cal.offset_date(
+        &mut result,
+        DateDuration::new(0, 0, duration.weeks, duration.days + i32::from(day) - 1),
+    );
+    Ok(result)
+}
+
+/// Whether adding `years` and `months` to `date`, without adjusting its day to the length
+/// of the resulting month, goes past `target` in the direction of `sign`.
+///
+/// This is the Temporal `NonISODateSurpasses` operation without weeks and days.
+fn surpasses<C: Calendar>(
+    cal: &C,
+    date: &C::DateInner,
+    target: &C::DateInner,
+    sign: i32,
+    years: i32,
+    months: i32,
+) -> bool {
+    let target_year = cal.extended_year(target);
+    let target_month = cal.month(target);
+    let day = cal.day_of_month(date).0;
+    let target_day = cal.day_of_month(target).0;
+
+    let compare = |year: i32, month: Ordering| {
+        if year != target_year {
+            sign * (year - target_year) > 0
+        } else if month != Ordering::Equal {
+            sign * (month as i32) > 0
+        } else {
+            sign * (i32::from(day) - i32::from(target_day)) > 0
+        }
+    };
+
+    let month_code = cal.month(date).standard_code;
+    let year = cal.extended_year(date) + years;
+    if compare(year, month_code.0.cmp(&target_month.standard_code.0)) {
+        return true;
+    }
+
+    let Ok(months_added) = add_years_and_months(cal, date, years, months, Overflow::Constrain)
+    else {
+        debug_assert!(false, "constraining a month code failed");
+        return true;
+    };
+    compare(
+        cal.extended_year(&months_added),
+        cal.month(&months_added).ordinal.cmp(&target_month.ordinal),
+    )
+}
+
+/// Implementation of [`Date::until_with_options()`].
+fn until<C: Calendar>(
+    cal: &C,
+    date: &C::DateInner,
+    other: &C::DateInner,
+    largest_unit: DateDurationUnit,
+) -> DateDuration<C> {
+    let sign = match cal.to_rata_die(other).cmp(&cal.to_rata_die(date)) {
+        Ordering::Greater => 1,
+        Ordering::Less => -1,
+        Ordering::Equal => return DateDuration::default(),
+    };
+
+    let mut years = 0;
+    if largest_unit == DateDurationUnit::Years {
+        // Adding one more year than the difference of the years always surpasses `other`
+        years = cal.extended_year(other) - cal.extended_year(date);
+        while years != 0 && surpasses(cal, date, other, sign, years, 0) {
+            years -= sign;
+        }
+    }
+
+    let mut months = 0;
+    if matches!(
+        largest_unit,
+        DateDurationUnit::Years | DateDurationUnit::Months
+    ) {
+        while !surpasses(cal, date, other, sign, years, months + sign) {
+            months += sign;
+        }
+    }
+
+    let intermediate = add(
+        cal,
+        date,
+        DateDuration::new(years, months, 0, 0),
+        Overflow::Constrain,
+    )
+    .unwrap_or(*date);
+    let days = (cal.to_rata_die(other) - cal.to_rata_die(&intermediate)) as i32;
+
+    if largest_unit == DateDurationUnit::Weeks {
+        DateDuration::new(years, months, days / 7, days % 7)
+    } else {
+        DateDuration::new(years, months, 0, days)
+    }
+}
+
 impl<A: AsCalendar<Calendar = C>, C: Calendar<Year = EraYear>> Date<A> {
     /// Returns information about the era for calendars using eras.
     pub fn era_year(&self) -> EraYear {
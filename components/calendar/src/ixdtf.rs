@@ -4,7 +4,7 @@
 
 use core::str::FromStr;
 
-use crate::{AsCalendar, Calendar, Date, Iso, RangeError};
+use crate::{AsCalendar, Calendar, Date, Iso, MonthDay, RangeError, YearMonth};
 use icu_locale_core::preferences::extensions::unicode::keywords::CalendarAlgorithm;
 use ixdtf::encoding::Utf8;
 use ixdtf::parsers::IxdtfParser;
@@ -100,22 +100,161 @@ impl<A: AsCalendar> Date<A> {
         let date_record = ixdtf_record.date.ok_or(ParseError::MissingFields)?;
         let iso = Date::try_new_iso(date_record.year, date_record.month, date_record.day)?;
 
-        if let Some(ixdtf_calendar) = ixdtf_record.calendar {
+        if let Some(parsed_calendar) = parsed_calendar(ixdtf_record) {
             if let Some(expected_calendar) = calendar.as_calendar().calendar_algorithm() {
-                if let Some(parsed_calendar) =
-                    icu_locale_core::extensions::unicode::Value::try_from_utf8(ixdtf_calendar)
-                        .ok()
-                        .and_then(|v| CalendarAlgorithm::try_from(&v).ok())
-                {
-                    if parsed_calendar != expected_calendar {
-                        return Err(ParseError::MismatchedCalendar(
-                            expected_calendar,
-                            parsed_calendar,
-                        ));
-                    }
+                if parsed_calendar != expected_calendar {
+                    return Err(ParseError::MismatchedCalendar(
+                        expected_calendar,
+                        parsed_calendar,
+                    ));
                 }
             }
         }
         Ok(iso.to_calendar(calendar))
     }
 }
+
+/// The calendar of the `u-ca` annotation, if any
+fn parsed_calendar(ixdtf_record: &IxdtfParseRecord<'_, Utf8>) -> Option<CalendarAlgorithm> {
+    icu_locale_core::extensions::unicode::Value::try_from_utf8(ixdtf_record.calendar?)
+        .ok()
+        .and_then(|v| CalendarAlgorithm::try_from(&v).ok())
+}
+
+/// Converts the ISO date of a string without a year or day into the given calendar.
+///
+/// Such strings are only valid with the ISO calendar, so this returns an error if they are
+/// annotated with a different calendar, or if the month of the ISO date is not also a month
+/// of the given calendar.
+fn try_iso_month_to_calendar<A: AsCalendar>(
+    ixdtf_record: &IxdtfParseRecord<'_, Utf8>,
+    iso: Date<Iso>,
+    calendar: A,
+) -> Result<Date<A>, ParseError> {
+    if let Some(parsed_calendar) = parsed_calendar(ixdtf_record) {
+        if parsed_calendar != CalendarAlgorithm::Iso8601 {
+            return Err(ParseError::MismatchedCalendar(
+                CalendarAlgorithm::Iso8601,
+                parsed_calendar,
+            ));
+        }
+    }
+    let date = iso.to_calendar(calendar);
+    if date.day_of_month() != iso.day_of_month() || date.days_in_month() != iso.days_in_month() {
+        return Err(ParseError::MissingFields);
+    }
+    Ok(date)
+}
+
+impl FromStr for YearMonth<Iso> {
+    type Err = ParseError;
+    fn from_str(rfc_9557_str: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(rfc_9557_str, Iso)
+    }
+}
+
+impl<A: AsCalendar> YearMonth<A> {
+    /// Creates a [`YearMonth`] in the given calendar from an RFC 9557 string.
+    ///
+    /// The string is either a date, of which the year and month in the given calendar are
+    /// used, or an ISO year and month, such as `2024-07`. The latter is only accepted if
+    /// the ISO month is also a month of the given calendar.
+    ///
+    /// Returns an error if the string has a calendar annotation that does not
+    /// match the calendar argument, unless the argument is [`Iso`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::calendar::{Gregorian, YearMonth};
+    ///
+    /// let year_month = YearMonth::try_from_str("2024-07", Gregorian).unwrap();
+    /// assert_eq!(year_month.month().ordinal, 7);
+    ///
+    /// let year_month =
+    ///     YearMonth::try_from_str("2024-02-10[u-ca=hebrew]", Hebrew).unwrap();
+    /// assert_eq!(year_month.month().standard_code.0, "M05L");
+    ///
+    /// // Hebrew months are not ISO months
+    /// let _ = YearMonth::try_from_str("2024-02", Hebrew).unwrap_err();
+    /// ```
+    pub fn try_from_str(rfc_9557_str: &str, calendar: A) -> Result<Self, ParseError> {
+        Self::try_from_utf8(rfc_9557_str.as_bytes(), calendar)
+    }
+
+    /// Creates a [`YearMonth`] in the given calendar from an RFC 9557 string.
+    ///
+    /// See [`Self::try_from_str()`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    pub fn try_from_utf8(rfc_9557_str: &[u8], calendar: A) -> Result<Self, ParseError> {
+        if let Ok(ixdtf_record) = IxdtfParser::from_utf8(rfc_9557_str).parse() {
+            return Date::try_from_ixdtf_record(&ixdtf_record, calendar).map(Self::from_date);
+        }
+        let ixdtf_record = IxdtfParser::from_utf8(rfc_9557_str).parse_year_month()?;
+        let date_record = ixdtf_record.date.ok_or(ParseError::MissingFields)?;
+        let iso = Date::try_new_iso(date_record.year, date_record.month, 1)?;
+        try_iso_month_to_calendar(&ixdtf_record, iso, calendar).map(Self::from_date)
+    }
+}
+
+impl FromStr for MonthDay<Iso> {
+    type Err = ParseError;
+    fn from_str(rfc_9557_str: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(rfc_9557_str, Iso)
+    }
+}
+
+impl<A: AsCalendar> MonthDay<A> {
+    /// Creates a [`MonthDay`] in the given calendar from an RFC 9557 string.
+    ///
+    /// The string is either a date, of which the month and day in the given calendar are
+    /// used, or an ISO month and day, such as `--07-17`. The latter is only accepted if
+    /// the ISO month is also a month of the given calendar.
+    ///
+    /// Returns an error if the string has a calendar annotation that does not
+    /// match the calendar argument, unless the argument is [`Iso`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::calendar::{Gregorian, MonthDay};
+    ///
+    /// let month_day = MonthDay::try_from_str("--02-29", Gregorian).unwrap();
+    /// assert_eq!(month_day.month_code().0, "M02");
+    /// assert_eq!(month_day.day_of_month().0, 29);
+    ///
+    /// let month_day =
+    ///     MonthDay::try_from_str("2024-02-24[u-ca=hebrew]", Hebrew).unwrap();
+    /// assert_eq!(month_day.month_code().0, "M05L");
+    /// assert_eq!(month_day.day_of_month().0, 15);
+    ///
+    /// // Hebrew months are not ISO months
+    /// let _ = MonthDay::try_from_str("--02-24", Hebrew).unwrap_err();
+    /// ```
+    pub fn try_from_str(rfc_9557_str: &str, calendar: A) -> Result<Self, ParseError> {
+        Self::try_from_utf8(rfc_9557_str.as_bytes(), calendar)
+    }
+
+    /// Creates a [`MonthDay`] in the given calendar from an RFC 9557 string.
+    ///
+    /// See [`Self::try_from_str()`].
+    ///
+    /// ✨ *Enabled with the `ixdtf` Cargo feature.*
+    pub fn try_from_utf8(rfc_9557_str: &[u8], calendar: A) -> Result<Self, ParseError> {
+        if let Ok(ixdtf_record) = IxdtfParser::from_utf8(rfc_9557_str).parse() {
+            return Date::try_from_ixdtf_record(&ixdtf_record, calendar).map(Self::from_date);
+        }
+        let ixdtf_record = IxdtfParser::from_utf8(rfc_9557_str).parse_month_day()?;
+        let date_record = ixdtf_record.date.ok_or(ParseError::MissingFields)?;
+        // A leap year, so that February 29 can be parsed
+        let iso = Date::try_new_iso(1972, date_record.month, date_record.day)?;
+        try_iso_month_to_calendar(&ixdtf_record, iso, calendar).map(Self::from_date)
+    }
+}
@@ -94,10 +94,13 @@ extern crate alloc;
 
 // Make sure inherent docs go first
 mod date;
+mod month_day;
+mod year_month;
 
 // Public modules
 mod any_calendar;
 pub mod cal;
+pub mod options;
 pub mod provider;
 pub mod types;
 pub mod week;
@@ -118,6 +121,8 @@ pub use duration::{DateDuration, DateDurationUnit};
 pub use error::{DateError, RangeError};
 #[cfg(feature = "ixdtf")]
 pub use ixdtf::ParseError;
+pub use month_day::MonthDay;
+pub use year_month::YearMonth;
 
 // Reexports
 #[doc(no_inline)]
@@ -0,0 +1,405 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::date::{month_with_code, start_of_year};
+use crate::error::DateError;
+use crate::options::{DateFromFieldsOptions, Overflow};
+use crate::{types, AsCalendar, Calendar, Date, DateDuration};
+use calendrical_calculations::iso::fixed_from_iso;
+use calendrical_calculations::rata_die::RataDie;
+use core::fmt;
+
+/// The ISO year in or before which the reference year of a [`MonthDay`] is preferably chosen.
+///
+/// This is a leap year, so that February 29 in ISO-like calendars has a reference year.
+const REFERENCE_ISO_YEAR: i32 = 1972;
+
+/// The earliest ISO year that is searched for a reference year.
+const EARLIEST_REFERENCE_ISO_YEAR: i32 = 1900;
+
+/// The latest ISO year that is searched for a reference year.
+const LATEST_REFERENCE_ISO_YEAR: i32 = 2050;
+
+/// A month and day in a given calendar, such as a birthday.
+///
+/// This corresponds to the Temporal
+/// [`PlainMonthDay`](https://tc39.es/proposal-temporal/#sec-temporal-plainmonthday-objects)
+/// type. It is represented by a date in a reference year, which is the latest ISO year
+/// on or before 1972 in which the month and day exist in the calendar. If there is no such
+/// year after 1900, the earliest such year after 1972 is used instead.
+///
+/// # Example
+///
+/// ```rust
+/// use icu::calendar::cal::Hebrew;
+/// use icu::calendar::types::MonthCode;
+/// use icu::calendar::{Date, MonthDay};
+/// use tinystr::tinystr;
+///
+/// // Adar I 30 only exists in leap years
+/// let adar_i = MonthCode(tinystr!(4, "M05L"));
+/// let month_day = MonthDay::try_new_from_codes(adar_i, 30, Hebrew).unwrap();
+///
+/// assert_eq!(month_day.month_code(), adar_i);
+/// assert_eq!(month_day.day_of_month().0, 30);
+/// assert_eq!(
+///     month_day.reference_date().to_iso(),
+///     Date::try_new_iso(1970, 3, 8).unwrap()
+/// );
+///
+/// // In a common year, the birthday is in Adar
+/// let date = month_day
+///     .try_into_date(None, 5785, Default::default())
+///     .unwrap();
+/// assert_eq!(date.month().standard_code.0, "M06");
+/// assert_eq!(date.day_of_month().0, 29);
+/// ```
+pub struct MonthDay<A: AsCalendar> {
+    date: Date<A>,
+}
+
+impl<A: AsCalendar> MonthDay<A> {
+    /// Construct a month and day from a month code and day, and some calendar representation
+    ///
+    /// Returns an error if the month and day do not exist in any reference year.
+    #[inline]
+    pub fn try_new_from_codes(
+        month_code: types::MonthCode,
+        day: u8,
+        calendar: A,
+    ) -> Result<Self, DateError> {
+        let options = DateFromFieldsOptions {
+            overflow: Overflow::Reject,
+        };
+        Self::try_new_from_codes_with_options(month_code, day, options, calendar)
+    }
+
+    /// Construct a month and day from a month code and day, handling fields that do not
+    /// exist in any reference year according to `options`
+    ///
+    /// With [`Overflow::Constrain`], the day is clamped to the largest day the month has in any
+    /// reference year, and a leap month that does not exist in any reference year is replaced
+    /// like in [`Date::try_new_from_codes_with_options()`].
+    pub fn try_new_from_codes_with_options(
+        month_code: types::MonthCode,
+        day: u8,
+        options: DateFromFieldsOptions,
+        calendar: A,
+    ) -> Result<Self, DateError> {
+        let cal = calendar.as_calendar();
+        let day_error = |max| DateError::Range {
+            field: "day",
+            value: i32::from(day),
+            min: 1,
+            max: i32::from(max),
+        };
+        if day == 0 {
+            return Err(day_error(reference_max_days(cal, month_code)));
+        }
+
+        let inner = match reference_date(cal, month_code, day) {
+            Some(inner) => inner,
+            None if options.overflow == Overflow::Reject => {
+                return Err(match reference_max_days(cal, month_code) {
+                    0 => DateError::UnknownMonthCode(month_code),
+                    max => day_error(max),
+                })
+            }
+            None => [Some(month_code), cal.constrain_leap_month_code(month_code)]
+                .into_iter()
+                .flatten()
+                .find_map(|month_code| match reference_max_days(cal, month_code) {
+                    0 => None,
+                    max => reference_date(cal, month_code, day.min(max)),
+                })
+                .ok_or(DateError::UnknownMonthCode(month_code))?,
+        };
+        Ok(Self {
+            date: Date::from_raw(inner, calendar),
+        })
+    }
+
+    /// Construct the month and day of a [`Date`]
+    pub fn from_date(date: Date<A>) -> Self {
+        let cal = date.calendar.as_calendar();
+        let inner = reference_date(cal, date.month().standard_code, date.day_of_month().0)
+            // The month and day exist in the year of `date`, which is outside of the
+            // searched range
+            .unwrap_or(date.inner);
+        Self {
+            date: Date::from_raw(inner, date.calendar),
+        }
+    }
+
+    /// Returns the date with this month and day in the given year, handling a day or month that
+    /// does not exist in the year according to `options`
+    ///
+    /// The year is `extended_year` if no era is provided
+    pub fn try_into_date(
+        self,
+        era: Option<&str>,
+        year: i32,
+        options: DateFromFieldsOptions,
+    ) -> Result<Date<A>, DateError> {
+        Date::try_new_from_codes_with_options(
+            era,
+            year,
+            self.month_code(),
+            self.day_of_month().0,
+            options,
+            self.date.calendar,
+        )
+    }
+
+    /// The date in the reference year
+    #[inline]
+    pub fn reference_date(&self) -> &Date<A> {
+        &self.date
+    }
+
+    /// The month code of this month and day
+    #[inline]
+    pub fn month_code(&self) -> types::MonthCode {
+        self.date.month().standard_code
+    }
+
+    /// The calendar-specific month represented by this month and day
+    ///
+    /// The [ordinal](types::MonthInfo::ordinal) is that of the month in the reference year,
+    /// use [`Self::month_code()`] to identify the month.
+    #[inline]
+    pub fn month(&self) -> types::MonthInfo {
+        self.date.month()
+    }
+
+    /// The day of the month
+    #[inline]
+    pub fn day_of_month(&self) -> types::DayOfMonth {
+        self.date.day_of_month()
+    }
+
+    /// Get a reference to the contained calendar
+    #[inline]
+    pub fn calendar(&self) -> &A::Calendar {
+        self.date.calendar()
+    }
+}
+
+/// Iterates over the months with the given code in the searched reference years, latest
+/// first from the year containing the end of [`REFERENCE_ISO_YEAR`], followed by the ones
+/// from that year onwards, earliest first.
+///
+/// The months are paired with whether they are from the first part of the search.
+fn reference_months<C: Calendar>(
+    cal: &C,
+    month_code: types::MonthCode,
+) -> impl Iterator<Item = (C::DateInner, bool)> + '_ {
+    let mut year_start = cal.from_rata_die(last_reference_day());
+    start_of_year(cal, &mut year_start);
+    let earliest = fixed_from_iso(EARLIEST_REFERENCE_ISO_YEAR, 1, 1);
+    let latest = fixed_from_iso(LATEST_REFERENCE_ISO_YEAR, 12, 31);
+
+    let years = move |step| {
+        core::iter::successors(Some(year_start), move |year_start| {
+            let mut next = *year_start;
+            cal.offset_date(&mut next, DateDuration::new(step, 0, 0, 0));
+            Some(next)
+        })
+    };
+    let before = years(-1)
+        .take_while(move |year_start| cal.to_rata_die(year_start) >= earliest)
+        .filter_map(move |year_start| month_with_code(cal, &year_start, month_code))
+        .map(|month| (month, true));
+    let after = years(1)
+        .take_while(move |year_start| cal.to_rata_die(year_start) <= latest)
+        .filter_map(move |year_start| month_with_code(cal, &year_start, month_code))
+        .map(|month| (month, false));
+    before.chain(after)
+}
+
+/// The last day of [`REFERENCE_ISO_YEAR`].
+fn last_reference_day() -> RataDie {
+    fixed_from_iso(REFERENCE_ISO_YEAR, 12, 31)
+}
+
+/// The date with the given month code and day in the reference year.
+fn reference_date<C: Calendar>(
+    cal: &C,
+    month_code: types::MonthCode,
+    day: u8,
+) -> Option<C::DateInner> {
+    reference_months(cal, month_code).find_map(|(mut date, before)| {
+        if day > cal.days_in_month(&date) {
+            return None;
+        }
+        cal.offset_date(&mut date, DateDuration::new(0, 0, 0, i32::from(day) - 1));
+        (before == (cal.to_rata_die(&date) <= last_reference_day())).then_some(date)
+    })
+}
+
+/// The largest number of days of the month with the given code in the searched reference
+/// years, or 0 if the month does not exist in any of them.
+fn reference_max_days<C: Calendar>(cal: &C, month_code: types::MonthCode) -> u8 {
+    reference_months(cal, month_code)
+        .map(|(month, _)| cal.days_in_month(&month))
+        .max()
+        .unwrap_or(0)
+}
+
+impl<C, A, B> PartialEq<MonthDay<B>> for MonthDay<A>
+where
+    C: Calendar,
+    A: AsCalendar<Calendar = C>,
+    B: AsCalendar<Calendar = C>,
+{
+    fn eq(&self, other: &MonthDay<B>) -> bool {
+        self.date == other.date
+    }
+}
+
+impl<A: AsCalendar> Eq for MonthDay<A> {}
+
+impl<A: AsCalendar> fmt::Debug for MonthDay<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let month_code = self.month_code().0;
+        let day = self.day_of_month().0;
+        let calendar = self.date.calendar.as_calendar().debug_name();
+        write!(f, "MonthDay({month_code}-{day}, for calendar {calendar})")
+    }
+}
+
+impl<A: AsCalendar + Clone> Clone for MonthDay<A> {
+    fn clone(&self) -> Self {
+        Self {
+            date: self.date.clone(),
+        }
+    }
+}
+
+impl<A> Copy for MonthDay<A> where A: AsCalendar + Copy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cal::{Chinese, Hebrew};
+    use crate::types::MonthCode;
+    use crate::{AnyCalendar, AnyCalendarKind, Gregorian, Iso, Ref};
+
+    fn code(s: &str) -> MonthCode {
+        MonthCode(s.parse().unwrap())
+    }
+
+    fn reference<A: AsCalendar>(month_day: MonthDay<A>) -> Date<crate::Iso> {
+        month_day.reference_date().to_iso()
+    }
+
+    #[test]
+    fn test_reference_year() {
+        let month_day = MonthDay::try_new_from_codes(code("M02"), 29, Gregorian).unwrap();
+        assert_eq!(
+            reference(month_day),
+            Date::try_new_iso(1972, 2, 29).unwrap()
+        );
+        let month_day = MonthDay::try_new_from_codes(code("M12"), 31, Iso).unwrap();
+        assert_eq!(
+            reference(month_day),
+            Date::try_new_iso(1972, 12, 31).unwrap()
+        );
+
+        // 5733 is a leap year, but its Adar I is in 1973
+        let month_day = MonthDay::try_new_from_codes(code("M05L"), 1, Hebrew).unwrap();
+        assert_eq!(reference(month_day), Date::try_new_iso(1970, 2, 7).unwrap());
+
+        // The Chinese year that starts in 1972 ends in 1973
+        let chinese = Chinese::new();
+        let month_day = MonthDay::try_new_from_codes(code("M12"), 1, Ref(&chinese)).unwrap();
+        assert!(month_day.reference_date().to_iso() <= Date::try_new_iso(1972, 12, 31).unwrap());
+        assert_eq!(month_day.month_code(), code("M12"));
+        assert_eq!(month_day.day_of_month().0, 1);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let constrain = DateFromFieldsOptions::default();
+        let reject = DateFromFieldsOptions {
+            overflow: Overflow::Reject,
+        };
+
+        assert_eq!(
+            MonthDay::try_new_from_codes_with_options(code("M02"), 30, constrain, Gregorian),
+            MonthDay::try_new_from_codes(code("M02"), 29, Gregorian)
+        );
+        assert!(matches!(
+            MonthDay::try_new_from_codes_with_options(code("M02"), 30, reject, Gregorian),
+            Err(DateError::Range { max: 29, .. })
+        ));
+        for options in [constrain, reject] {
+            assert!(matches!(
+                MonthDay::try_new_from_codes_with_options(code("M13"), 1, options, Gregorian),
+                Err(DateError::UnknownMonthCode(_))
+            ));
+            assert!(matches!(
+                MonthDay::try_new_from_codes_with_options(code("M01"), 0, options, Gregorian),
+                Err(DateError::Range { .. })
+            ));
+        }
+
+        // There is no twelfth leap month in the reference years
+        let chinese = Chinese::new();
+        assert!(matches!(
+            MonthDay::try_new_from_codes_with_options(code("M12L"), 1, reject, Ref(&chinese)),
+            Err(DateError::UnknownMonthCode(_))
+        ));
+        let month_day =
+            MonthDay::try_new_from_codes_with_options(code("M12L"), 1, constrain, Ref(&chinese))
+                .unwrap();
+        assert_eq!(month_day.month_code(), code("M12"));
+    }
+
+    #[test]
+    fn test_from_date() {
+        let dates = [
+            Date::try_new_iso(2020, 2, 29).unwrap(),
+            Date::try_new_iso(2023, 4, 20).unwrap(),
+            Date::try_new_iso(2024, 3, 10).unwrap(),
+            Date::try_new_iso(2025, 8, 1).unwrap(),
+        ];
+        for kind in [
+            AnyCalendarKind::BikramSambat,
+            AnyCalendarKind::Chinese,
+            AnyCalendarKind::Dangi,
+            AnyCalendarKind::Gregorian,
+            AnyCalendarKind::Hebrew,
+            AnyCalendarKind::HijriUmmAlQura,
+            AnyCalendarKind::Japanese,
+            AnyCalendarKind::Persian,
+            AnyCalendarKind::Vietnamese,
+        ] {
+            let calendar = AnyCalendar::new(kind);
+            for date in dates {
+                let date = date.to_calendar(Ref(&calendar));
+                let month_day = MonthDay::from_date(date);
+                assert_eq!(month_day.month_code(), date.month().standard_code);
+                assert_eq!(month_day.day_of_month(), date.day_of_month());
+                assert_eq!(
+                    MonthDay::try_new_from_codes(
+                        month_day.month_code(),
+                        month_day.day_of_month().0,
+                        Ref(&calendar)
+                    ),
+                    Ok(month_day)
+                );
+                let date_roundtrip = month_day
+                    .try_into_date(None, date.extended_year(), Default::default())
+                    .unwrap();
+                if !matches!(
+                    kind,
+                    AnyCalendarKind::Chinese | AnyCalendarKind::Dangi | AnyCalendarKind::Vietnamese
+                ) {
+                    assert_eq!(date_roundtrip, date);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,83 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for constructing dates and performing date arithmetic.
+//!
+//! These follow the options of the same names in the
+//! [Temporal specification](https://tc39.es/proposal-temporal/).
+
+use crate::DateDurationUnit;
+
+/// How to handle date fields that are out of range for a given year or month.
+///
+/// This corresponds to the Temporal `overflow` option.
+///
+/// # Example
+///
+/// ```
+/// use icu::calendar::options::{DateFromFieldsOptions, Overflow};
+/// use icu::calendar::types::MonthCode;
+/// use icu::calendar::{Date, DateError, Gregorian};
+/// use tinystr::tinystr;
+///
+/// let month_code = MonthCode(tinystr!(4, "M02"));
+///
+/// let mut options = DateFromFieldsOptions::default();
+/// options.overflow = Overflow::Constrain;
+/// let date =
+///     Date::try_new_from_codes_with_options(None, 2025, month_code, 31, options, Gregorian)
+///         .unwrap();
+/// assert_eq!(date.day_of_month().0, 28);
+///
+/// options.overflow = Overflow::Reject;
+/// assert!(matches!(
+///     Date::try_new_from_codes_with_options(None, 2025, month_code, 31, options, Gregorian),
+///     Err(DateError::Range { field: "day", .. })
+/// ));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Overflow {
+    /// Clamp the day to the last day of the month, and replace a leap month that
+    /// does not exist in the year by the month the calendar considers closest.
+    ///
+    /// For example, January 31 plus one month is the last day of February, and
+    /// Adar I of a Hebrew leap year plus one year is Adar of the following common year.
+    #[default]
+    Constrain,
+    /// Return an error if the day or month does not exist.
+    Reject,
+}
+
+/// Options for [`Date::try_new_from_codes_with_options()`](crate::Date::try_new_from_codes_with_options).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct DateFromFieldsOptions {
+    /// How to handle a day or month that does not exist in the given year.
+    ///
+    /// Defaults to [`Overflow::Constrain`].
+    pub overflow: Overflow,
+}
+
+/// Options for [`Date::try_add_with_options()`](crate::Date::try_add_with_options).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+#[doc(hidden)] // unstable
+pub struct DateAddOptions {
+    /// How to handle a day or month that does not exist in the resulting year.
+    ///
+    /// Defaults to [`Overflow::Constrain`].
+    pub overflow: Overflow,
+}
+
+/// Options for [`Date::until_with_options()`](crate::Date::until_with_options).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+#[doc(hidden)] // unstable
+pub struct DateDifferenceOptions {
+    /// The largest unit of the resulting duration.
+    ///
+    /// Defaults to [`DateDurationUnit::Days`].
+    pub largest_unit: Option<DateDurationUnit>,
+}
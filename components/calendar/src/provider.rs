@@ -17,7 +17,7 @@
 
 pub mod chinese_based;
 pub mod hijri;
-pub use chinese_based::{CalendarChineseV1, CalendarDangiV1};
+pub use chinese_based::{CalendarChineseV1, CalendarDangiV1, CalendarVietnameseV1};
 pub use hijri::CalendarHijriSimulatedMeccaV1;
 
 use crate::types::Weekday;
@@ -52,6 +52,7 @@ const _: () = {
     impl_calendar_japanese_modern_v1!(Baked);
     impl_calendar_japanese_extended_v1!(Baked);
     impl_calendar_week_v1!(Baked);
+    impl_calendar_vietnamese_v1!(Baked);
 };
 
 icu_provider::data_marker!(
@@ -89,6 +90,7 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     CalendarJapaneseModernV1::INFO,
     CalendarJapaneseExtendedV1::INFO,
     CalendarWeekV1::INFO,
+    CalendarVietnameseV1::INFO,
 ];
 
 /// The date at which an era started
@@ -30,6 +30,13 @@ icu_provider::data_marker!(
     ChineseBasedCache<'static>,
     is_singleton = true
 );
+icu_provider::data_marker!(
+    /// Precomputed data for the Vietnamese calendar
+    CalendarVietnameseV1,
+    "calendar/vietnamese/v1",
+    ChineseBasedCache<'static>,
+    is_singleton = true
+);
 
 /// Cached/precompiled data for a certain range of years for a chinese-based
 /// calendar. Avoids the need to perform lunar calendar arithmetic for most calendrical
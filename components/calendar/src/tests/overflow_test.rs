@@ -0,0 +1,372 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conformance tests for the Temporal `overflow` and `largestUnit` options.
+//!
+//! Expected values are the results of the corresponding `Temporal.PlainDate` operations.
+
+use crate::cal::{Chinese, Hebrew};
+use crate::options::*;
+use crate::types::{MonthCode, YearInfo};
+use crate::*;
+use tinystr::TinyAsciiStr;
+
+fn constrain() -> DateAddOptions {
+    DateAddOptions::default()
+}
+
+fn reject() -> DateAddOptions {
+    DateAddOptions {
+        overflow: Overflow::Reject,
+    }
+}
+
+fn largest(unit: DateDurationUnit) -> DateDifferenceOptions {
+    DateDifferenceOptions {
+        largest_unit: Some(unit),
+    }
+}
+
+fn code(s: &str) -> MonthCode {
+    MonthCode(s.parse().unwrap())
+}
+
+fn iso(y: i32, m: u8, d: u8) -> Date<Iso> {
+    Date::try_new_iso(y, m, d).unwrap()
+}
+
+/// The era, year, month code and day, as accepted by [`Date::try_new_from_codes()`]
+fn codes<A: AsCalendar>(date: &Date<A>) -> (Option<TinyAsciiStr<16>>, i32, MonthCode, u8) {
+    let (era, year) = match date.year() {
+        YearInfo::Era(year) => (Some(year.era), year.year),
+        YearInfo::Cyclic(year) => (None, year.related_iso),
+    };
+    (era, year, date.month().standard_code, date.day_of_month().0)
+}
+
+fn ymd<A: AsCalendar>(date: &Date<A>) -> (i32, MonthCode, u8) {
+    let (_, year, month_code, day) = codes(date);
+    (year, month_code, day)
+}
+
+fn duration<C: Calendar>(d: DateDuration<C>) -> (i32, i32, i32, i32) {
+    (d.years, d.months, d.weeks, d.days)
+}
+
+#[test]
+fn test_from_codes_iso() {
+    let mut options = DateFromFieldsOptions::default();
+
+    let date = Date::try_new_from_codes_with_options(None, 2023, code("M02"), 31, options, Iso);
+    assert_eq!(date, Ok(iso(2023, 2, 28)));
+    let date = Date::try_new_from_codes_with_options(None, 2024, code("M02"), 30, options, Iso);
+    assert_eq!(date, Ok(iso(2024, 2, 29)));
+    let date = Date::try_new_from_codes_with_options(None, 2024, code("M04"), 255, options, Iso);
+    assert_eq!(date, Ok(iso(2024, 4, 30)));
+
+    // Fields that never exist are rejected in both modes
+    for overflow in [Overflow::Constrain, Overflow::Reject] {
+        options.overflow = overflow;
+        assert!(matches!(
+            Date::try_new_from_codes_with_options(None, 2024, code("M13"), 1, options, Iso),
+            Err(DateError::UnknownMonthCode(_))
+        ));
+        assert!(matches!(
+            Date::try_new_from_codes_with_options(None, 2024, code("M02L"), 1, options, Iso),
+            Err(DateError::UnknownMonthCode(_))
+        ));
+        assert!(matches!(
+            Date::try_new_from_codes_with_options(None, 2024, code("M01"), 0, options, Iso),
+            Err(DateError::Range { field: "day", .. })
+        ));
+    }
+
+    options.overflow = Overflow::Reject;
+    assert!(matches!(
+        Date::try_new_from_codes_with_options(None, 2023, code("M02"), 29, options, Iso),
+        Err(DateError::Range { field: "day", .. })
+    ));
+    let date = Date::try_new_from_codes_with_options(None, 2024, code("M02"), 29, options, Iso);
+    assert_eq!(date, Ok(iso(2024, 2, 29)));
+}
+
+#[test]
+fn test_from_codes_lunisolar() {
+    let mut options = DateFromFieldsOptions::default();
+
+    // 5784 is a leap year, 5785 is not
+    let date = Date::try_new_from_codes_with_options(None, 5785, code("M05L"), 15, options, Hebrew)
+        .unwrap();
+    assert_eq!(ymd(&date), (5785, code("M06"), 15));
+    let date = Date::try_new_from_codes_with_options(None, 5784, code("M05L"), 15, options, Hebrew)
+        .unwrap();
+    assert_eq!(ymd(&date), (5784, code("M05L"), 15));
+    assert!(matches!(
+        Date::try_new_from_codes_with_options(None, 5785, code("M03L"), 1, options, Hebrew),
+        Err(DateError::UnknownMonthCode(_))
+    ));
+
+    // 2023 has a leap month after the second month, 2024 has none
+    let chinese = Chinese::new();
+    let date =
+        Date::try_new_from_codes_with_options(None, 2024, code("M02L"), 1, options, Ref(&chinese))
+            .unwrap();
+    assert_eq!(ymd(&date), (2024, code("M02"), 1));
+    let date =
+        Date::try_new_from_codes_with_options(None, 2023, code("M02L"), 30, options, Ref(&chinese))
+            .unwrap();
+    assert_eq!(date.month().standard_code, code("M02L"));
+    assert_eq!(date.day_of_month().0, date.days_in_month());
+
+    options.overflow = Overflow::Reject;
+    assert!(matches!(
+        Date::try_new_from_codes_with_options(None, 5785, code("M05L"), 1, options, Hebrew),
+        Err(DateError::UnknownMonthCode(_))
+    ));
+    assert!(matches!(
+        Date::try_new_from_codes_with_options(None, 2024, code("M02L"), 1, options, Ref(&chinese)),
+        Err(DateError::UnknownMonthCode(_))
+    ));
+}
+
+#[test]
+fn test_add_iso() {
+    let cases = [
+        // (date, (years, months, weeks, days), constrained result, rejected)
+        ((2024, 1, 31), (0, 1, 0, 0), (2024, 2, 29), true),
+        ((2023, 1, 31), (0, 1, 0, 0), (2023, 2, 28), true),
+        ((2024, 3, 31), (0, -1, 0, 0), (2024, 2, 29), true),
+        ((2024, 2, 29), (1, 0, 0, 0), (2025, 2, 28), true),
+        ((2024, 2, 29), (4, 0, 0, 0), (2028, 2, 29), false),
+        ((2024, 2, 29), (-1, 0, 0, 0), (2023, 2, 28), true),
+        ((2024, 1, 31), (0, 1, 0, 1), (2024, 3, 1), true),
+        ((2024, 1, 31), (1, 1, 0, 0), (2025, 2, 28), true),
+        ((2024, 1, 31), (0, 2, 0, 0), (2024, 3, 31), false),
+        ((2024, 1, 31), (0, 13, 0, 0), (2025, 2, 28), true),
+        ((2024, 1, 31), (0, -11, 0, 0), (2023, 2, 28), true),
+        ((2024, 1, 31), (0, 0, 4, 1), (2024, 2, 29), false),
+        ((2024, 12, 31), (0, 0, 0, 1), (2025, 1, 1), false),
+        ((2024, 8, 31), (1, 1, 1, 1), (2025, 10, 8), true),
+        ((2020, 2, 29), (0, 0, 0, -366), (2019, 2, 28), false),
+    ];
+    for (date, (y, m, w, d), expected, rejected) in cases {
+        let date = iso(date.0, date.1, date.2);
+        let duration = DateDuration::new(y, m, w, d);
+        let expected = iso(expected.0, expected.1, expected.2);
+        assert_eq!(
+            date.try_added_with_options(duration, constrain()),
+            Ok(expected),
+            "{date:?} + {duration:?}"
+        );
+        let result = date.try_added_with_options(duration, reject());
+        if rejected {
+            assert!(
+                matches!(result, Err(DateError::Range { field: "day", .. })),
+                "{date:?} + {duration:?}"
+            );
+        } else {
+            assert_eq!(result, Ok(expected), "{date:?} + {duration:?}");
+        }
+    }
+
+    // A failed addition leaves the date unchanged
+    let mut date = iso(2024, 1, 31);
+    assert!(date
+        .try_add_with_options(DateDuration::new(0, 1, 0, 0), reject())
+        .is_err());
+    assert_eq!(date, iso(2024, 1, 31));
+}
+
+#[test]
+fn test_add_lunisolar() {
+    // Adar I 5784 plus one year is Adar 5785
+    let date = Date::try_new_from_codes(None, 5784, code("M05L"), 30, Hebrew).unwrap();
+    let result = date
+        .clone()
+        .try_added_with_options(DateDuration::new(1, 0, 0, 0), constrain())
+        .unwrap();
+    assert_eq!(ymd(&result), (5785, code("M06"), 29));
+    assert!(matches!(
+        date.clone()
+            .try_added_with_options(DateDuration::new(1, 0, 0, 0), reject()),
+        Err(DateError::UnknownMonthCode(_))
+    ));
+    let result = date
+        .clone()
+        .try_added_with_options(DateDuration::new(3, 0, 0, 0), reject())
+        .unwrap();
+    assert_eq!(ymd(&result), (5787, code("M05L"), 30));
+
+    // Adar (II) keeps its month code
+    let date = Date::try_new_from_codes(None, 5784, code("M06"), 1, Hebrew).unwrap();
+    let result = date
+        .clone()
+        .try_added_with_options(DateDuration::new(1, 0, 0, 0), reject())
+        .unwrap();
+    assert_eq!(ymd(&result), (5785, code("M06"), 1));
+
+    // Months are counted by ordinal, including leap months
+    let date = Date::try_new_from_codes(None, 5784, code("M05"), 1, Hebrew).unwrap();
+    let result = date
+        .clone()
+        .try_added_with_options(DateDuration::new(0, 2, 0, 0), reject())
+        .unwrap();
+    assert_eq!(ymd(&result), (5784, code("M06"), 1));
+
+    let chinese = Chinese::new();
+    let date = Date::try_new_from_codes(None, 2023, code("M02L"), 1, Ref(&chinese)).unwrap();
+    let result = date
+        .try_added_with_options(DateDuration::new(1, 0, 0, 0), constrain())
+        .unwrap();
+    assert_eq!(ymd(&result), (2024, code("M02"), 1));
+    assert!(matches!(
+        date.try_added_with_options(DateDuration::new(1, 0, 0, 0), reject()),
+        Err(DateError::UnknownMonthCode(_))
+    ));
+    let result = date
+        .try_added_with_options(DateDuration::new(0, -1, 0, 0), reject())
+        .unwrap();
+    assert_eq!(ymd(&result), (2023, code("M02"), 1));
+    let result = date
+        .try_added_with_options(DateDuration::new(0, 1, 0, 0), reject())
+        .unwrap();
+    assert_eq!(ymd(&result), (2023, code("M03"), 1));
+}
+
+#[test]
+fn test_until_iso() {
+    use DateDurationUnit::*;
+    let cases = [
+        // (from, to, largest unit, (years, months, weeks, days))
+        ((2024, 1, 31), (2024, 2, 29), Months, (0, 0, 0, 29)),
+        ((2024, 1, 31), (2024, 3, 1), Months, (0, 1, 0, 1)),
+        ((2024, 1, 31), (2024, 3, 31), Months, (0, 2, 0, 0)),
+        ((2024, 3, 31), (2024, 2, 29), Months, (0, -1, 0, 0)),
+        ((2024, 3, 31), (2024, 2, 28), Months, (0, -1, 0, -1)),
+        ((2020, 2, 29), (2021, 2, 28), Years, (0, 11, 0, 30)),
+        ((2020, 2, 29), (2021, 3, 1), Years, (1, 0, 0, 1)),
+        ((2021, 2, 28), (2020, 2, 29), Years, (0, -11, 0, -28)),
+        ((1992, 9, 2), (2022, 1, 30), Years, (29, 4, 0, 28)),
+        ((2022, 1, 30), (1992, 9, 2), Years, (-29, -4, 0, -28)),
+        ((1992, 9, 2), (2022, 1, 30), Months, (0, 352, 0, 28)),
+        ((1992, 9, 2), (2022, 1, 30), Weeks, (0, 0, 1534, 4)),
+        ((1992, 9, 2), (2022, 1, 30), Days, (0, 0, 0, 10742)),
+        ((2024, 5, 5), (2024, 5, 5), Years, (0, 0, 0, 0)),
+    ];
+    for (from, to, unit, expected) in cases {
+        let from = iso(from.0, from.1, from.2);
+        let to = iso(to.0, to.1, to.2);
+        let result = from.until_with_options(&to, largest(unit));
+        assert_eq!(
+            duration(result),
+            expected,
+            "{from:?} until {to:?} ({unit:?})"
+        );
+    }
+
+    // Days is the default largest unit
+    let result = iso(2024, 1, 1).until_with_options(&iso(2025, 1, 1), Default::default());
+    assert_eq!(duration(result), (0, 0, 0, 366));
+}
+
+#[test]
+fn test_until_lunisolar() {
+    use DateDurationUnit::*;
+
+    let from = Date::try_new_from_codes(None, 5784, code("M05L"), 30, Hebrew).unwrap();
+    let to = Date::try_new_from_codes(None, 5785, code("M06"), 29, Hebrew).unwrap();
+    assert_eq!(
+        duration(from.until_with_options(&to, largest(Years))),
+        (0, 12, 0, 29)
+    );
+    let to = Date::try_new_from_codes(None, 5785, code("M07"), 1, Hebrew).unwrap();
+    assert_eq!(
+        duration(from.until_with_options(&to, largest(Years))),
+        (1, 0, 0, 1)
+    );
+    assert_eq!(
+        duration(from.until_with_options(&to, largest(Months))),
+        (0, 13, 0, 1)
+    );
+}
+
+/// Checks that `until` followed by `add` round-trips in every calendar
+#[test]
+fn test_until_add_roundtrip() {
+    use DateDurationUnit::*;
+    let kinds = [
+        AnyCalendarKind::BikramSambat,
+        AnyCalendarKind::Buddhist,
+        AnyCalendarKind::Chinese,
+        AnyCalendarKind::Coptic,
+        AnyCalendarKind::Dangi,
+        AnyCalendarKind::Ethiopian,
+        AnyCalendarKind::EthiopianAmeteAlem,
+        AnyCalendarKind::Gregorian,
+        AnyCalendarKind::Hebrew,
+        AnyCalendarKind::Indian,
+        AnyCalendarKind::HijriTabularTypeIIFriday,
+        AnyCalendarKind::HijriSimulatedMecca,
+        AnyCalendarKind::HijriTabularTypeIIThursday,
+        AnyCalendarKind::HijriUmmAlQura,
+        AnyCalendarKind::Iso,
+        AnyCalendarKind::Japanese,
+        AnyCalendarKind::JapaneseExtended,
+        AnyCalendarKind::Persian,
+        AnyCalendarKind::Roc,
+        AnyCalendarKind::Vietnamese,
+    ];
+    let dates = [
+        iso(2020, 2, 29),
+        iso(2021, 1, 31),
+        iso(2023, 3, 22),
+        iso(2023, 5, 19),
+        iso(2024, 1, 1),
+        iso(2024, 3, 10),
+        iso(2024, 12, 31),
+        iso(2025, 7, 24),
+    ];
+    for kind in kinds {
+        let calendar = AnyCalendar::new(kind);
+        for from in dates {
+            let from = from.to_calendar(Ref(&calendar));
+            for to in dates {
+                let to = to.to_calendar(Ref(&calendar));
+                for unit in [Years, Months, Weeks, Days] {
+                    let duration = from.until_with_options(&to, largest(unit));
+                    assert_eq!(
+                        from.try_added_with_options(duration, constrain()),
+                        Ok(to),
+                        "{from:?} + {duration:?} ({unit:?})"
+                    );
+                }
+            }
+            // Adding a month or a year keeps the date valid, and keeps the month code
+            // unless it does not exist in the resulting year
+            for (years, months) in [(1, 0), (-1, 0), (0, 1), (0, -1), (2, 3)] {
+                let result = from
+                    .try_added_with_options(DateDuration::new(years, months, 0, 0), constrain())
+                    .unwrap();
+                assert!(
+                    result.day_of_month().0 <= from.day_of_month().0,
+                    "{result:?}"
+                );
+                if months == 0 {
+                    assert_eq!(result.extended_year(), from.extended_year() + years);
+                }
+                let (era, year, month_code, day) = codes(&result);
+                assert_eq!(
+                    Date::try_new_from_codes(
+                        era.as_ref().map(TinyAsciiStr::as_str),
+                        year,
+                        month_code,
+                        day,
+                        Ref(&calendar)
+                    ),
+                    Ok(result)
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::error::DateError;
+use crate::options::{DateFromFieldsOptions, Overflow};
+use crate::{types, AsCalendar, Calendar, Date, DateDuration};
+use core::fmt;
+
+/// A year and month in a given calendar, such as a billing cycle.
+///
+/// This corresponds to the Temporal
+/// [`PlainYearMonth`](https://tc39.es/proposal-temporal/#sec-temporal-plainyearmonth-objects)
+/// type. It is represented by the first day of the month, which is its reference date.
+///
+/// # Example
+///
+/// ```rust
+/// use icu::calendar::cal::Hebrew;
+/// use icu::calendar::types::MonthCode;
+/// use icu::calendar::{Date, YearMonth};
+/// use tinystr::tinystr;
+///
+/// let adar_i = MonthCode(tinystr!(4, "M05L"));
+/// let year_month =
+///     YearMonth::try_new_from_codes(None, 5784, adar_i, Hebrew).unwrap();
+///
+/// assert_eq!(year_month.month().standard_code, adar_i);
+/// assert_eq!(year_month.days_in_month(), 30);
+/// assert_eq!(
+///     year_month.reference_date().to_iso(),
+///     Date::try_new_iso(2024, 2, 10).unwrap()
+/// );
+///
+/// let date = year_month.try_into_date(15, Default::default()).unwrap();
+/// assert_eq!(date.to_iso(), Date::try_new_iso(2024, 2, 24).unwrap());
+/// ```
+pub struct YearMonth<A: AsCalendar> {
+    date: Date<A>,
+}
+
+impl<A: AsCalendar> YearMonth<A> {
+    /// Construct a year and month from era/month codes and fields, and some calendar representation
+    ///
+    /// The year is `extended_year` if no era is provided
+    #[inline]
+    pub fn try_new_from_codes(
+        era: Option<&str>,
+        year: i32,
+        month_code: types::MonthCode,
+        calendar: A,
+    ) -> Result<Self, DateError> {
+        Date::try_new_from_codes(era, year, month_code, 1, calendar).map(|date| Self { date })
+    }
+
+    /// Construct a year and month from era/month codes and fields, handling a leap month that
+    /// does not exist in the given year according to `options`
+    ///
+    /// See [`Date::try_new_from_codes_with_options()`].
+    #[inline]
+    pub fn try_new_from_codes_with_options(
+        era: Option<&str>,
+        year: i32,
+        month_code: types::MonthCode,
+        options: DateFromFieldsOptions,
+        calendar: A,
+    ) -> Result<Self, DateError> {
+        Date::try_new_from_codes_with_options(era, year, month_code, 1, options, calendar)
+            .map(|date| Self { date })
+    }
+
+    /// Construct the year and month of a [`Date`]
+    pub fn from_date(mut date: Date<A>) -> Self {
+        let day = date.day_of_month().0;
+        date.calendar.as_calendar().offset_date(
+            &mut date.inner,
+            DateDuration::new(0, 0, 0, 1 - i32::from(day)),
+        );
+        Self { date }
+    }
+
+    /// Returns the date with the given day in this year and month, handling a day that does not
+    /// exist in the month according to `options`
+    pub fn try_into_date(
+        self,
+        day: u8,
+        options: DateFromFieldsOptions,
+    ) -> Result<Date<A>, DateError> {
+        let days_in_month = self.days_in_month();
+        let day = if (1..=days_in_month).contains(&day) {
+            day
+        } else if day > 0 && options.overflow == Overflow::Constrain {
+            days_in_month
+        } else {
+            return Err(DateError::Range {
+                field: "day",
+                value: i32::from(day),
+                min: 1,
+                max: i32::from(days_in_month),
+            });
+        };
+        let mut date = self.date;
+        date.calendar.as_calendar().offset_date(
+            &mut date.inner,
+            DateDuration::new(0, 0, 0, i32::from(day) - 1),
+        );
+        Ok(date)
+    }
+
+    /// The first day of this month
+    #[inline]
+    pub fn reference_date(&self) -> &Date<A> {
+        &self.date
+    }
+
+    /// The calendar-specific year-info.
+    ///
+    /// See [`Date::year()`].
+    #[inline]
+    pub fn year(&self) -> types::YearInfo {
+        self.date.year()
+    }
+
+    /// The "extended year".
+    ///
+    /// See [`Date::extended_year()`].
+    #[inline]
+    pub fn extended_year(&self) -> i32 {
+        self.date.extended_year()
+    }
+
+    /// Returns whether the year is a leap year for this calendar
+    #[inline]
+    pub fn is_in_leap_year(&self) -> bool {
+        self.date.is_in_leap_year()
+    }
+
+    /// The calendar-specific month represented by this year and month
+    #[inline]
+    pub fn month(&self) -> types::MonthInfo {
+        self.date.month()
+    }
+
+    /// The number of months in the year
+    #[inline]
+    pub fn months_in_year(&self) -> u8 {
+        self.date.months_in_year()
+    }
+
+    /// The number of days in the year
+    #[inline]
+    pub fn days_in_year(&self) -> u16 {
+        self.date.days_in_year()
+    }
+
+    /// The number of days in the month
+    #[inline]
+    pub fn days_in_month(&self) -> u8 {
+        self.date.days_in_month()
+    }
+
+    /// Get a reference to the contained calendar
+    #[inline]
+    pub fn calendar(&self) -> &A::Calendar {
+        self.date.calendar()
+    }
+}
+
+impl<A: AsCalendar> From<Date<A>> for YearMonth<A> {
+    fn from(date: Date<A>) -> Self {
+        Self::from_date(date)
+    }
+}
+
+impl<C, A, B> PartialEq<YearMonth<B>> for YearMonth<A>
+where
+    C: Calendar,
+    A: AsCalendar<Calendar = C>,
+    B: AsCalendar<Calendar = C>,
+{
+    fn eq(&self, other: &YearMonth<B>) -> bool {
+        self.date == other.date
+    }
+}
+
+impl<A: AsCalendar> Eq for YearMonth<A> {}
+
+impl<A: AsCalendar> fmt::Debug for YearMonth<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let month = self.month().ordinal;
+        let year = self.year();
+        let calendar = self.date.calendar.as_calendar().debug_name();
+        match year {
+            types::YearInfo::Era(types::EraYear { year, era, .. }) => {
+                write!(
+                    f,
+                    "YearMonth({year}-{month}, {era} era, for calendar {calendar})"
+                )
+            }
+            types::YearInfo::Cyclic(types::CyclicYear { year, related_iso }) => {
+                write!(
+                    f,
+                    "YearMonth({year}-{month}, ISO year {related_iso}, for calendar {calendar})"
+                )
+            }
+        }
+    }
+}
+
+impl<A: AsCalendar + Clone> Clone for YearMonth<A> {
+    fn clone(&self) -> Self {
+        Self {
+            date: self.date.clone(),
+        }
+    }
+}
+
+impl<A> Copy for YearMonth<A> where A: AsCalendar + Copy {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cal::Hebrew;
+    use crate::types::MonthCode;
+    use crate::Iso;
+
+    fn code(s: &str) -> MonthCode {
+        MonthCode(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_year_month() {
+        let year_month = YearMonth::from(Date::try_new_iso(2024, 2, 17).unwrap());
+        assert_eq!(
+            year_month,
+            YearMonth::try_new_from_codes(None, 2024, code("M02"), Iso).unwrap()
+        );
+        assert_eq!(
+            *year_month.reference_date(),
+            Date::try_new_iso(2024, 2, 1).unwrap()
+        );
+        assert_eq!(
+            year_month.try_into_date(31, Default::default()),
+            Ok(Date::try_new_iso(2024, 2, 29).unwrap())
+        );
+        let reject = DateFromFieldsOptions {
+            overflow: Overflow::Reject,
+        };
+        assert!(matches!(
+            year_month.try_into_date(31, reject),
+            Err(DateError::Range { max: 29, .. })
+        ));
+        assert!(year_month.try_into_date(0, Default::default()).is_err());
+
+        // Adar I does not exist in 5785
+        assert!(matches!(
+            YearMonth::try_new_from_codes(None, 5785, code("M05L"), Hebrew),
+            Err(DateError::UnknownMonthCode(_))
+        ));
+        let year_month = YearMonth::try_new_from_codes_with_options(
+            None,
+            5785,
+            code("M05L"),
+            Default::default(),
+            Hebrew,
+        )
+        .unwrap();
+        assert_eq!(year_month.month().standard_code, code("M06"));
+    }
+
+    #[test]
+    #[cfg(feature = "ixdtf")]
+    fn test_from_str_calendar_annotation() {
+        use crate::{cal::Gregorian, ParseError};
+        use icu_locale_core::preferences::extensions::unicode::keywords::CalendarAlgorithm;
+
+        let year_month = YearMonth::try_from_str("2024-07[u-ca=iso8601]", Gregorian).unwrap();
+        assert_eq!(year_month.month().standard_code, code("M07"));
+
+        // Strings without a day are only valid in the ISO calendar
+        assert!(matches!(
+            YearMonth::try_from_str("2024-07[u-ca=gregory]", Gregorian),
+            Err(ParseError::MismatchedCalendar(
+                CalendarAlgorithm::Iso8601,
+                CalendarAlgorithm::Gregory
+            ))
+        ));
+        assert!(matches!(
+            crate::MonthDay::try_from_str("--07-17[u-ca=hebrew]", Hebrew),
+            Err(ParseError::MismatchedCalendar(
+                CalendarAlgorithm::Iso8601,
+                CalendarAlgorithm::Hebrew
+            ))
+        ));
+    }
+}
@@ -0,0 +1,253 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Bucketing strings under the index labels of a locale.
+//!
+//! ✨ *Enabled with the `experimental` Cargo feature.*
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+//!
+//! An [`AlphabeticIndex`] assigns strings to the labeled buckets of an index like the
+//! "A B C … Z" of an English contact list, "あ か さ た な …" in Japanese, or "ㄱ ㄴ ㄷ …"
+//! in Korean. The labels are the index exemplar characters of the locale (see
+//! [`ExemplarCharacters::try_new_index`](icu_locale::exemplar_chars::ExemplarCharacters::try_new_index)),
+//! and a string belongs to the last label that is not greater than it according to
+//! the collation of the locale at primary strength.
+//!
+//! Strings that sort before the first label, such as digits and symbols, go into an
+//! underflow bucket, and strings in other scripts that sort after the last label go into
+//! an overflow bucket.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::collator::alphabetic_index::{AlphabeticIndex, Bucket};
+//! use icu::locale::locale;
+//!
+//! let index = AlphabeticIndex::try_new(locale!("sv").into()).unwrap();
+//!
+//! assert_eq!(index.bucket_for("Anders"), Bucket::Label("A"));
+//! assert_eq!(index.bucket_for("ängel"), Bucket::Label("Ä"));
+//! assert_eq!(index.bucket_for("Zorn"), Bucket::Label("Z"));
+//! assert_eq!(index.bucket_for("42"), Bucket::Underflow);
+//! assert_eq!(index.bucket_for("Ωμέγα"), Bucket::Overflow);
+//!
+//! // Grouping strings by bucket
+//! let mut groups = vec![Vec::new(); index.buckets().count()];
+//! for name in ["Östen", "Åke", "Anna", "Örjan", "Bo"] {
+//!     groups[index.bucket_index(name)].push(name);
+//! }
+//! let groups = index
+//!     .buckets()
+//!     .zip(groups)
+//!     .filter(|(_, names)| !names.is_empty())
+//!     .map(|(bucket, names)| (bucket.label(), names))
+//!     .collect::<Vec<_>>();
+//! assert_eq!(
+//!     groups,
+//!     [
+//!         ("A", vec!["Anna"]),
+//!         ("B", vec!["Bo"]),
+//!         ("Å", vec!["Åke"]),
+//!         ("Ö", vec!["Östen", "Örjan"])
+//!     ]
+//! );
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use icu_locale::exemplar_chars::{ExemplarCharacters, ExemplarCharactersBorrowed};
+use icu_locale::provider::LocaleExemplarCharactersIndexV1;
+use icu_normalizer::provider::{NormalizerNfdDataV1, NormalizerNfdTablesV1};
+use icu_properties::props::Script;
+use icu_properties::provider::PropertyEnumScriptV1;
+use icu_properties::{CodePointMapData, CodePointMapDataBorrowed};
+use icu_provider::prelude::*;
+
+use crate::options::{CollatorOptions, Strength};
+use crate::provider::*;
+use crate::{Collator, CollatorPreferences};
+
+/// The label of the underflow and overflow buckets
+const FLOW_LABEL: &str = "\u{2026}";
+
+/// A bucket of an [`AlphabeticIndex`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Bucket<'a> {
+    /// The bucket for strings that sort before the first label.
+    Underflow,
+    /// The bucket for strings that sort between this label and the next one.
+    Label(&'a str),
+    /// The bucket for strings in other scripts that sort after the last label.
+    Overflow,
+}
+
+impl<'a> Bucket<'a> {
+    /// The text to display for the bucket.
+    ///
+    /// This is "…" for the underflow and overflow buckets.
+    pub fn label(self) -> &'a str {
+        match self {
+            Bucket::Label(label) => label,
+            Bucket::Underflow | Bucket::Overflow => FLOW_LABEL,
+        }
+    }
+}
+
+/// Assigns strings to the buckets of a locale-specific alphabetic index.
+///
+/// See the [module-level docs](self) for details.
+#[derive(Debug)]
+pub struct AlphabeticIndex {
+    collator: Collator,
+    labels: Vec<String>,
+    scripts: CodePointMapData<Script>,
+}
+
+impl AlphabeticIndex {
+    /// Creates an index for the given locale from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(prefs: CollatorPreferences) -> Result<Self, DataError> {
+        let collator = Collator::try_new(prefs, Self::options())?.static_to_owned();
+        let exemplars = ExemplarCharacters::try_new_index(
+            &LocaleExemplarCharactersIndexV1::make_locale(prefs.locale_preferences),
+        )?;
+        Ok(Self::new_internal(
+            collator,
+            exemplars,
+            CodePointMapData::<Script>::new().static_to_owned(),
+        ))
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: CollatorPreferences) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(provider: &D, prefs: CollatorPreferences) -> Result<Self, DataError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<LocaleExemplarCharactersIndexV1>
+            + DataProvider<PropertyEnumScriptV1>
+            + ?Sized,
+    {
+        let collator = Collator::try_new_unstable(provider, prefs, Self::options())?;
+        let exemplars = ExemplarCharacters::try_new_index_unstable(
+            provider,
+            &LocaleExemplarCharactersIndexV1::make_locale(prefs.locale_preferences),
+        )?;
+        Ok(Self::new_internal(
+            collator,
+            exemplars.as_borrowed(),
+            CodePointMapData::<Script>::try_new_unstable(provider)?,
+        ))
+    }
+
+    fn options() -> CollatorOptions {
+        let mut options = CollatorOptions::default();
+        options.strength = Some(Strength::Primary);
+        options
+    }
+
+    fn new_internal(
+        collator: Collator,
+        exemplars: ExemplarCharactersBorrowed,
+        scripts: CodePointMapData<Script>,
+    ) -> Self {
+        let mut labels = exemplars
+            .code_points()
+            .iter_chars()
+            .map(String::from)
+            .chain(exemplars.strings().iter().map(String::from))
+            .collect::<Vec<_>>();
+        let borrowed = collator.as_borrowed();
+        labels.sort_by(|a, b| borrowed.compare(a, b));
+        // Labels that are equal at primary strength would have empty buckets.
+        labels.dedup_by(|a, b| borrowed.compare(a, b) == Ordering::Equal);
+        Self {
+            collator,
+            labels,
+            scripts,
+        }
+    }
+
+    /// Returns the buckets of the index in order, starting with the underflow
+    /// bucket and ending with the overflow bucket.
+    pub fn buckets(&self) -> impl Iterator<Item = Bucket<'_>> + '_ {
+        core::iter::once(Bucket::Underflow)
+            .chain(self.labels.iter().map(|label| Bucket::Label(label)))
+            .chain(core::iter::once(Bucket::Overflow))
+    }
+
+    /// Returns the bucket for `s`.
+    pub fn bucket_for(&self, s: &str) -> Bucket<'_> {
+        let index = self.bucket_index(s);
+        if index == 0 {
+            Bucket::Underflow
+        } else if let Some(label) = self.labels.get(index - 1) {
+            Bucket::Label(label)
+        } else {
+            Bucket::Overflow
+        }
+    }
+
+    /// Returns the position of the bucket for `s` in [`Self::buckets`].
+    pub fn bucket_index(&self, s: &str) -> usize {
+        let collator = self.collator.as_borrowed();
+        let count = self
+            .labels
+            .partition_point(|label| collator.compare(label, s) != Ordering::Greater);
+        if count == self.labels.len() {
+            let scripts = self.scripts.as_borrowed();
+            let last_script = self
+                .labels
+                .last()
+                .and_then(|label| script_of(scripts, label));
+            if let (Some(script), Some(last_script)) = (script_of(scripts, s), last_script) {
+                if script != last_script {
+                    return count + 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+/// The script of the first character of `s` that has a specific script,
+/// treating Hiragana and Katakana as one.
+fn script_of(scripts: CodePointMapDataBorrowed<Script>, s: &str) -> Option<Script> {
+    s.chars()
+        .map(|c| scripts.get(c))
+        .find(|&script| script != Script::Common && script != Script::Inherited)
+        .map(|script| {
+            if script == Script::Hiragana {
+                Script::Katakana
+            } else {
+                script
+            }
+        })
+}
@@ -12,6 +12,8 @@
 use alloc::collections::VecDeque;
 use alloc::vec::Vec;
 
+#[cfg(feature = "experimental")]
+use crate::elements::ccc_from_trie_value;
 use crate::elements::CharacterAndClassAndTrieValue;
 use crate::elements::CollationElement32;
 use crate::elements::Tag;
@@ -42,6 +44,8 @@ use crate::provider::CollationRootV1;
 use crate::provider::CollationSpecialPrimariesV1;
 use crate::provider::CollationSpecialPrimariesValidated;
 use crate::provider::CollationTailoringV1;
+#[cfg(feature = "experimental")]
+use crate::tailoring::CollationTailoring;
 use core::array;
 use core::cmp::Ordering;
 use core::convert::{Infallible, TryFrom};
@@ -51,6 +55,8 @@ use icu_normalizer::provider::NormalizerNfdDataV1;
 use icu_normalizer::provider::NormalizerNfdTablesV1;
 use icu_normalizer::DecomposingNormalizerBorrowed;
 use icu_normalizer::Decomposition;
+#[cfg(feature = "experimental")]
+use icu_properties::props::CanonicalCombiningClass;
 use icu_provider::marker::ErasedMarker;
 use icu_provider::prelude::*;
 use smallvec::SmallVec;
@@ -355,6 +361,8 @@ struct LocaleSpecificDataHolder {
     reordering: Option<DataPayload<CollationReorderingV1>>,
     merged_options: CollatorOptionsBitField,
     lithuanian_dot_above: bool,
+    resolved_locale: DataLocale,
+    resolved_collation_type: CollationType,
 }
 
 icu_locale_core::preferences::define_preferences!(
@@ -433,10 +441,16 @@ impl LocaleSpecificDataHolder {
             ..Default::default()
         };
 
-        let metadata_payload: DataPayload<crate::provider::CollationMetadataV1> = provider
-            .load(req)
-            .or_else(|_| provider.load(fallback_req))?
-            .payload;
+        let (metadata_response, resolved_collation_type) = match provider.load(req) {
+            Ok(response) => (
+                response,
+                prefs.collation_type.unwrap_or(CollationType::Standard),
+            ),
+            Err(_) => (provider.load(fallback_req)?, CollationType::Standard),
+        };
+        let resolved_locale = metadata_response.metadata.locale.unwrap_or(data_locale);
+        let metadata_payload: DataPayload<crate::provider::CollationMetadataV1> =
+            metadata_response.payload;
 
         let metadata = metadata_payload.get();
 
@@ -515,6 +529,8 @@ impl LocaleSpecificDataHolder {
             merged_options,
             reordering,
             lithuanian_dot_above: metadata.lithuanian_dot_above(),
+            resolved_locale,
+            resolved_collation_type,
         })
     }
 }
@@ -532,6 +548,8 @@ pub struct Collator {
     decompositions: DataPayload<NormalizerNfdDataV1>,
     tables: DataPayload<NormalizerNfdTablesV1>,
     lithuanian_dot_above: bool,
+    resolved_locale: DataLocale,
+    resolved_collation_type: CollationType,
 }
 
 impl Collator {
@@ -548,6 +566,8 @@ impl Collator {
             decompositions: self.decompositions.get(),
             tables: self.tables.get(),
             lithuanian_dot_above: self.lithuanian_dot_above,
+            resolved_locale: self.resolved_locale,
+            resolved_collation_type: self.resolved_collation_type,
         }
     }
 
@@ -600,6 +620,79 @@ impl Collator {
         )
     }
 
+    /// Creates a [`Collator`] for a [`CollationTailoring`] built from rules at run time,
+    /// with the root collation from compiled data.
+    ///
+    /// The settings in the rules are the defaults for the options that `options` leaves unset.
+    ///
+    /// ✨ *Enabled with the `compiled_data` and `experimental` Cargo features.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(all(feature = "compiled_data", feature = "experimental"))]
+    pub fn try_new_with_tailoring(
+        tailoring: CollationTailoring,
+        options: CollatorOptions,
+    ) -> Result<Self, DataError> {
+        Ok(CollatorBorrowed::try_new(Default::default(), options)?
+            .static_to_owned()
+            .with_tailoring(tailoring, options))
+    }
+
+    #[cfg(feature = "experimental")]
+    icu_provider::gen_buffer_data_constructors!(
+        (tailoring: CollationTailoring, options: CollatorOptions) -> error: DataError,
+        functions: [
+            try_new_with_tailoring: skip,
+            try_new_with_tailoring_with_buffer_provider,
+            try_new_with_tailoring_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_tailoring)]
+    #[cfg(feature = "experimental")]
+    pub fn try_new_with_tailoring_unstable<D>(
+        provider: &D,
+        tailoring: CollationTailoring,
+        options: CollatorOptions,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<CollationSpecialPrimariesV1>
+            + DataProvider<CollationRootV1>
+            + DataProvider<CollationTailoringV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationMetadataV1>
+            + DataProvider<CollationReorderingV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        Ok(
+            Self::try_new_unstable(provider, Default::default(), options)?
+                .with_tailoring(tailoring, options),
+        )
+    }
+
+    /// Replaces the root-locale data with the given tailoring.
+    #[cfg(feature = "experimental")]
+    fn with_tailoring(mut self, tailoring: CollationTailoring, options: CollatorOptions) -> Self {
+        let (data, diacritics, _, defaults) = tailoring.into_parts();
+        let mut merged_options = CollatorOptionsBitField::from(options);
+        merged_options.set_defaults(defaults);
+        self.tailoring = Some(DataPayload::from_owned(data));
+        self.diacritics = DataPayload::from_owned(diacritics);
+        self.options = merged_options;
+        self.reordering = None;
+        self.lithuanian_dot_above = false;
+        self
+    }
+
     #[expect(clippy::too_many_arguments)]
     fn try_new_unstable_internal<D>(
         provider: &D,
@@ -673,6 +766,8 @@ impl Collator {
             decompositions,
             tables,
             lithuanian_dot_above: locale_dependent.lithuanian_dot_above,
+            resolved_locale: locale_dependent.resolved_locale,
+            resolved_collation_type: locale_dependent.resolved_collation_type,
         })
     }
 }
@@ -705,7 +800,7 @@ macro_rules! compare {
 
 /// Compares strings according to culturally-relevant ordering,
 /// borrowed version.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct CollatorBorrowed<'a> {
     special_primaries: &'a CollationSpecialPrimariesValidated<'a>,
     root: &'a CollationData<'a>,
@@ -717,6 +812,8 @@ pub struct CollatorBorrowed<'a> {
     decompositions: &'a DecompositionData<'a>,
     tables: &'a DecompositionTables<'a>,
     lithuanian_dot_above: bool,
+    resolved_locale: DataLocale,
+    resolved_collation_type: CollationType,
 }
 
 impl CollatorBorrowed<'static> {
@@ -781,6 +878,8 @@ impl CollatorBorrowed<'static> {
             decompositions,
             tables,
             lithuanian_dot_above: locale_dependent.lithuanian_dot_above,
+            resolved_locale: locale_dependent.resolved_locale,
+            resolved_collation_type: locale_dependent.resolved_collation_type,
         })
     }
 
@@ -810,6 +909,8 @@ impl CollatorBorrowed<'static> {
             decompositions: DataPayload::from_static_ref(self.decompositions),
             tables: DataPayload::from_static_ref(self.tables),
             lithuanian_dot_above: self.lithuanian_dot_above,
+            resolved_locale: self.resolved_locale,
+            resolved_collation_type: self.resolved_collation_type,
         }
     }
 }
@@ -841,6 +942,57 @@ impl CollatorBorrowed<'_> {
         self.options.into()
     }
 
+    /// The preferences that this collator resolved to.
+    ///
+    /// The locale is the locale of the collation data that was loaded, after locale fallback,
+    /// as reported by the data provider in [`DataResponseMetadata::locale`], or the requested
+    /// locale if the provider does not report it. Since data providers deduplicate data that
+    /// is inherited from a parent locale, this is the least specific locale with the same
+    /// data, which might be `und`.
+    ///
+    /// The collation type is the requested one if there is data for it, and
+    /// [`CollationType::Standard`] otherwise. The case first and numeric preferences
+    /// are the same as in [`Self::resolved_options`].
+    ///
+    /// A collator for a tailoring built from rules at run time resolves to `und` and
+    /// [`CollationType::Standard`].
+    ///
+    /// [`DataResponseMetadata::locale`]: icu_provider::DataResponseMetadata::locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::collator::preferences::{CollationCaseFirst, CollationType};
+    /// use icu::collator::Collator;
+    /// use icu::locale::{locale, Locale};
+    ///
+    /// let collator =
+    ///     Collator::try_new(locale!("de-AT-u-co-phonebk").into(), Default::default())
+    ///         .unwrap();
+    ///
+    /// let resolved = collator.resolved_preferences();
+    /// assert_eq!(Locale::from(resolved.locale_preferences), locale!("de"));
+    /// assert_eq!(resolved.collation_type, Some(CollationType::Phonebk));
+    /// assert_eq!(resolved.case_first, Some(CollationCaseFirst::False));
+    ///
+    /// // There is no data for the "phonebk" collation in French
+    /// let collator =
+    ///     Collator::try_new(locale!("fr-u-co-phonebk").into(), Default::default())
+    ///         .unwrap();
+    ///
+    /// let resolved = collator.resolved_preferences();
+    /// assert_eq!(resolved.collation_type, Some(CollationType::Standard));
+    /// ```
+    pub fn resolved_preferences(&self) -> CollatorPreferences {
+        let options = self.resolved_options();
+        CollatorPreferences {
+            locale_preferences: self.resolved_locale.into(),
+            collation_type: Some(self.resolved_collation_type),
+            case_first: Some(options.case_first),
+            numeric_ordering: Some(options.numeric),
+        }
+    }
+
     compare!(
         /// Compare guaranteed well-formed UTF-8 slices.
         ,
@@ -953,6 +1105,72 @@ impl CollatorBorrowed<'_> {
         }
     }
 
+    /// Calls `f` with the bits of each collation element of `s` that is not completely
+    /// ignorable, until `f` returns `false`.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn for_each_collation_element(&self, s: &str, mut f: impl FnMut(u64) -> bool) {
+        let mut iter = collation_elements!(
+            self,
+            s.chars(),
+            self.tailoring_or_root(),
+            self.numeric_primary()
+        );
+        iter.init();
+        loop {
+            let ce = iter.next();
+            if ce == NO_CE {
+                return;
+            }
+            let bits = (u64::from(ce.primary()) << 32) | u64::from(ce.non_primary().bits());
+            if bits != 0 && !f(bits) {
+                return;
+            }
+        }
+    }
+
+    /// Calls `f` with the weights of each collation element of `s` up to the strength
+    /// of the collator, until `f` returns `false`.
+    ///
+    /// Collation elements that are ignorable at the strength of the collator are skipped,
+    /// as are variable collation elements and the primary ignorables after them if
+    /// variables are shifted. The quaternary and identical levels are not considered.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn for_each_search_key(&self, s: &str, mut f: impl FnMut(u64) -> bool) {
+        let variable_top = self.variable_top();
+        let strength = self.options.strength();
+        let tertiary_mask = self.options.tertiary_mask().unwrap_or_default();
+        let mut after_variable = false;
+        self.for_each_collation_element(s, |bits| {
+            let p = (bits >> 32) as u32;
+            if p < variable_top && p > MERGE_SEPARATOR_PRIMARY {
+                after_variable = true;
+                return true;
+            }
+            if p != 0 {
+                after_variable = false;
+            } else if after_variable {
+                return true;
+            }
+            let non_primary = NonPrimary::new(bits as u32);
+            let mut key = u64::from(p) << 32;
+            if strength >= Strength::Secondary {
+                key |= u64::from(non_primary.secondary()) << 16;
+            }
+            if strength >= Strength::Tertiary {
+                key |= u64::from(non_primary.tertiary_case_quarternary(tertiary_mask));
+            }
+            key == 0 || f(key)
+        });
+    }
+
+    /// Whether `c` can start a collation unit, i.e. whether its canonical
+    /// combining class is zero.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn is_starter(&self, c: char) -> bool {
+        ccc_from_trie_value(self.decompositions.trie.get(c))
+            == CanonicalCombiningClass::NotReordered
+    }
+
     /// The implementation of the comparison operation.
     ///
     /// `head_chars` is an iterator _backward_ over the identical
@@ -144,7 +144,7 @@ fn decomposition_starts_with_non_starter(trie_value: u32) -> bool {
 /// Extracts a canonical combining class (possibly zero) from a trie value.
 ///
 /// See components/normalizer/trie-value-format.md
-fn ccc_from_trie_value(trie_value: u32) -> CanonicalCombiningClass {
+pub(crate) fn ccc_from_trie_value(trie_value: u32) -> CanonicalCombiningClass {
     if trie_value_has_ccc(trie_value) {
         CanonicalCombiningClass::from_icu4c_value(trie_value as u8)
     } else {
@@ -193,11 +193,11 @@ const UNASSIGNED_IMPLICIT_BYTE: u8 = 0xFE;
 // const CONTRACT_SINGLE_CP_NO_MATCH: u32 = 0x100;
 
 /// Set if the first character of every contraction suffix has lccc!=0.
-const CONTRACT_NEXT_CCC: u32 = 0x200;
+pub(crate) const CONTRACT_NEXT_CCC: u32 = 0x200;
 /// Set if any contraction suffix ends with lccc!=0.
-const CONTRACT_TRAILING_CCC: u32 = 0x400;
+pub(crate) const CONTRACT_TRAILING_CCC: u32 = 0x400;
 /// Set if at least one contraction suffix contains a starter
-const CONTRACT_HAS_STARTER: u32 = 0x800;
+pub(crate) const CONTRACT_HAS_STARTER: u32 = 0x800;
 
 // const NO_CE32: CollationElement32 = CollationElement32::default();
 // constants named NO_CE* : End of input. Only used in runtime code, not stored in data.
@@ -320,8 +320,14 @@ pub mod docs;
 // name of that struct without coordination.
 mod elements;
 
+#[cfg(feature = "experimental")]
+pub mod alphabetic_index;
 pub mod options;
 pub mod provider;
+#[cfg(feature = "experimental")]
+pub mod search;
+#[cfg(feature = "experimental")]
+pub mod tailoring;
 
 pub use comparison::Collator;
 pub use comparison::CollatorBorrowed;
@@ -149,7 +149,7 @@ const SINGLE_U32: &ZeroSlice<u32> =
 const SINGLE_U64: &ZeroSlice<u64> =
     zeroslice!(u64; <u64 as AsULE>::ULE::from_unsigned; [FFFD_CE_VALUE]);
 
-fn data_ce_to_primary(data_ce: u64, c: char) -> u32 {
+pub(crate) fn data_ce_to_primary(data_ce: u64, c: char) -> u32 {
     // Collation::getThreeBytePrimaryForOffsetData
     let p = (data_ce >> 32) as u32; // three-byte primary pppppp00
     let lower32 = data_ce as u32 as i32; // base code point b & step s: bbbbbbss (bit 7: isCompressible)
@@ -455,15 +455,15 @@ icu_provider::data_struct!(
 );
 
 impl CollationMetadata {
-    const MAX_VARIABLE_MASK: u32 = 0b11;
-    const TAILORED_MASK: u32 = 1 << 3;
-    const TAILORED_DIACRITICS_MASK: u32 = 1 << 4;
-    const REORDERING_MASK: u32 = 1 << 5;
-    const LITHUANIAN_DOT_ABOVE_MASK: u32 = 1 << 6;
-    const BACWARD_SECOND_LEVEL_MASK: u32 = 1 << 7;
-    const ALTERNATE_SHIFTED_MASK: u32 = 1 << 8;
-    const CASE_FIRST_MASK: u32 = 1 << 9;
-    const UPPER_FIRST_MASK: u32 = 1 << 10;
+    pub(crate) const MAX_VARIABLE_MASK: u32 = 0b11;
+    pub(crate) const TAILORED_MASK: u32 = 1 << 3;
+    pub(crate) const TAILORED_DIACRITICS_MASK: u32 = 1 << 4;
+    pub(crate) const REORDERING_MASK: u32 = 1 << 5;
+    pub(crate) const LITHUANIAN_DOT_ABOVE_MASK: u32 = 1 << 6;
+    pub(crate) const BACWARD_SECOND_LEVEL_MASK: u32 = 1 << 7;
+    pub(crate) const ALTERNATE_SHIFTED_MASK: u32 = 1 << 8;
+    pub(crate) const CASE_FIRST_MASK: u32 = 1 << 9;
+    pub(crate) const UPPER_FIRST_MASK: u32 = 1 << 10;
 
     #[inline(always)]
     pub(crate) fn max_variable(self) -> MaxVariable {
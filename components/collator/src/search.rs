@@ -0,0 +1,214 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Searching for strings in text by collation equivalence.
+//!
+//! ✨ *Enabled with the `experimental` Cargo feature.*
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+//!
+//! A [`StringSearch`] finds the substrings of a text that are equal to a pattern
+//! under the [`Strength`](crate::options::Strength) and
+//! [`AlternateHandling`](crate::options::AlternateHandling) of a collator, e.g. to
+//! implement an accent- and case-insensitive "find in page".
+//!
+//! Matches are compared as whole collation units: a match never starts or ends in the
+//! middle of a contraction, an expansion, or a combining character sequence.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::collator::options::{CollatorOptions, Strength};
+//! use icu::collator::search::StringSearch;
+//! use icu::collator::Collator;
+//!
+//! let mut options = CollatorOptions::default();
+//! options.strength = Some(Strength::Primary);
+//! let collator = Collator::try_new(Default::default(), options).unwrap();
+//!
+//! let search = StringSearch::new(collator, "resume");
+//! let text = "Attach your Résumé, or resume later.";
+//! let matches: Vec<_> = search.find_iter(text).map(|r| &text[r]).collect();
+//! assert_eq!(matches, ["Résumé", "resume"]);
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Range;
+
+use crate::CollatorBorrowed;
+
+/// Finds the occurrences of a pattern in text using collation.
+///
+/// See the [module-level docs](self) for details.
+#[derive(Debug, Clone)]
+pub struct StringSearch<'a> {
+    collator: CollatorBorrowed<'a>,
+    pattern: String,
+    keys: Vec<u64>,
+}
+
+impl<'a> StringSearch<'a> {
+    /// Creates a search for `pattern` that matches according to the options of `collator`.
+    ///
+    /// A pattern that is ignorable at the strength of the collator, such as the
+    /// empty string, matches nowhere.
+    pub fn new(collator: CollatorBorrowed<'a>, pattern: &str) -> Self {
+        let mut keys = Vec::new();
+        collator.for_each_search_key(pattern, |key| {
+            keys.push(key);
+            true
+        });
+        Self {
+            collator,
+            pattern: String::from(pattern),
+            keys,
+        }
+    }
+
+    /// Returns the byte range of the first match in `text`, if any.
+    pub fn find(&self, text: &str) -> Option<Range<usize>> {
+        self.find_iter(text).next()
+    }
+
+    /// Returns an iterator over the byte ranges of the non-overlapping matches in `text`.
+    pub fn find_iter<'s, 't>(&'s self, text: &'t str) -> Matches<'s, 'a, 't> {
+        Matches {
+            search: self,
+            text,
+            pos: 0,
+        }
+    }
+
+    /// Returns the match that starts at `start`, if any.
+    fn match_at(&self, text: &str, start: usize) -> Option<Range<usize>> {
+        let rest = text.get(start..)?;
+        let first = rest.chars().next()?;
+        if !self.collator.is_starter(first) {
+            return None;
+        }
+        // Leave out ignorable characters, like spaces with shifted variables.
+        let mut ignorable = true;
+        self.collator
+            .for_each_search_key(first.encode_utf8(&mut [0; 4]), |_| {
+                ignorable = false;
+                false
+            });
+        if ignorable {
+            return None;
+        }
+
+        // Quick check that the text continues like the pattern
+        let mut matched = 0;
+        self.collator.for_each_search_key(rest, |key| {
+            if self.keys.get(matched) == Some(&key) {
+                matched += 1;
+                matched < self.keys.len()
+            } else {
+                false
+            }
+        });
+        if matched < self.keys.len() {
+            return None;
+        }
+
+        // Find the shortest substring that is equal to the pattern.
+        let mut end = start;
+        for c in rest.chars() {
+            end += c.len_utf8();
+            if text
+                .get(end..)
+                .and_then(|after| after.chars().next())
+                .is_some_and(|c| !self.collator.is_starter(c))
+            {
+                continue;
+            }
+            let candidate = text.get(start..end)?;
+            let mut keys = Vec::new();
+            self.collator.for_each_search_key(candidate, |key| {
+                keys.push(key);
+                keys.len() <= self.keys.len()
+            });
+            if keys.len() > self.keys.len() {
+                return None;
+            }
+            if keys == self.keys
+                && self.collator.compare(&self.pattern, candidate) == Ordering::Equal
+                && self.is_in_context(text, start..end)
+            {
+                return Some(start..end);
+            }
+        }
+        None
+    }
+
+    /// Whether the collation elements of `text[range]` are the same on their own and
+    /// within `text`, i.e. whether no contraction or context-sensitive mapping crosses
+    /// the boundaries of the range.
+    fn is_in_context(&self, text: &str, range: Range<usize>) -> bool {
+        // Start at the starter before the range to see contractions that include its start.
+        let context_start = text
+            .get(..range.start)
+            .unwrap_or_default()
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| self.collator.is_starter(c))
+            .map_or(range.start, |(i, _)| i);
+        let ces = |s: &str| {
+            let mut ces = Vec::new();
+            self.collator.for_each_collation_element(s, |ce| {
+                ces.push(ce);
+                true
+            });
+            ces
+        };
+        let mut expected = ces(text.get(context_start..range.start).unwrap_or_default());
+        expected.extend(ces(text.get(range).unwrap_or_default()));
+        let mut actual = Vec::with_capacity(expected.len());
+        self.collator.for_each_collation_element(
+            text.get(context_start..).unwrap_or_default(),
+            |ce| {
+                actual.push(ce);
+                actual.len() < expected.len()
+            },
+        );
+        actual == expected
+    }
+}
+
+/// An iterator over the matches of a [`StringSearch`] in a text.
+///
+/// This is returned by [`StringSearch::find_iter`].
+#[derive(Debug)]
+pub struct Matches<'s, 'a, 't> {
+    search: &'s StringSearch<'a>,
+    text: &'t str,
+    pos: usize,
+}
+
+impl Iterator for Matches<'_, '_, '_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.search.keys.is_empty() {
+            return None;
+        }
+        while let Some(c) = self
+            .text
+            .get(self.pos..)
+            .and_then(|rest| rest.chars().next())
+        {
+            if let Some(range) = self.search.match_at(self.text, self.pos) {
+                self.pos = range.end;
+                return Some(range);
+            }
+            self.pos += c.len_utf8();
+        }
+        None
+    }
+}
@@ -0,0 +1,826 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The algorithms in this file are adapted from `CollationBuilder` and
+// `CollationWeights` in ICU4C and, therefore, are subject to the ICU
+// license as described in LICENSE.
+
+//! Turns parsed rules into collation elements for the tailored strings.
+//!
+//! Like `CollationBuilder` in ICU4C, this keeps an ordered list of nodes
+//! for each root primary weight. Root nodes stand for the root collation
+//! elements that the rules reset to, and tailored nodes stand for the
+//! relations. Once all rules have been processed, weights are allocated
+//! for the tailored nodes in the gaps between the root weights.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use zerovec::ule::AsULE;
+
+use super::parser::{Level, Rule};
+use super::{RootData, TailoringError};
+use crate::elements::{CollationElement32, Tag, CASE_MASK, TERTIARY_MASK};
+use crate::provider::{data_ce_to_primary, CollationSpecialPrimariesValidated};
+
+const COMMON_WEIGHT16: u32 = 0x0500;
+/// Tailored secondary and tertiary weights before the common weight
+/// are allocated between this and the common weight.
+const BELOW_COMMON_WEIGHT16: u32 = 0x0400;
+/// Tailored secondary weights after the common weight are allocated
+/// after all weights with this lead byte, which is the last lead byte
+/// that sort key compression uses for runs of common weights.
+const SECONDARY_AFTER_COMMON_LOWER: u32 = 0x45FF;
+/// The lowest root secondary weight above the common weight
+/// for collation elements with a primary weight.
+const SECONDARY_AFTER_COMMON_UPPER: u32 = 0x7000;
+const TERTIARY_AFTER_COMMON_LOWER: u32 = 0x05FF;
+/// The lowest root tertiary weight above the common weight.
+const TERTIARY_AFTER_COMMON_UPPER: u32 = 0x1000;
+
+const LOWER_CASE: u16 = 0;
+const MIXED_CASE: u16 = 0x4000;
+
+/// A collation element during the build: either from the root
+/// or for a tailored node, whose weights are not known yet.
+#[derive(Copy, Clone, Debug)]
+enum Ce {
+    Root(u64),
+    Node(usize),
+}
+
+#[derive(Debug)]
+struct Node {
+    /// The root primary whose list this node is in
+    group: u32,
+    level: Level,
+    /// The weight at `level`: 32 bits for primaries, 16 for the others
+    weight: u32,
+    tailored: bool,
+    /// Case bits for tailored nodes
+    case: u16,
+    /// Rule offset for errors
+    offset: usize,
+}
+
+impl Node {
+    /// Whether this is a root node that makes a common weight explicit
+    /// because there are weights below it.
+    fn is_explicit_common(&self) -> bool {
+        !self.tailored && self.level != Level::Primary && self.weight == COMMON_WEIGHT16
+    }
+}
+
+/// Where the next relation goes.
+#[derive(Copy, Clone, Debug)]
+enum Position {
+    /// After the node and the nodes weaker than the relation
+    After(usize),
+    /// Immediately before the node
+    Before(usize),
+}
+
+pub(super) struct Builder<'a> {
+    root: &'a RootData<'a>,
+    nodes: Vec<Node>,
+    /// Node lists keyed by root primary
+    lists: BTreeMap<u32, Vec<usize>>,
+    /// The collation elements of each tailored string, and the offset of its rule
+    mappings: BTreeMap<String, (Vec<Ce>, usize)>,
+    /// All primary weights of the root collation, sorted
+    root_primaries: Vec<u32>,
+}
+
+impl<'a> Builder<'a> {
+    pub(super) fn new(root: &'a RootData<'a>) -> Self {
+        Self {
+            root,
+            nodes: Vec::new(),
+            lists: BTreeMap::new(),
+            mappings: BTreeMap::new(),
+            root_primaries: root_primaries(root),
+        }
+    }
+
+    /// Processes the rules and returns the collation elements of
+    /// each tailored string, with the offset of its rule.
+    pub(super) fn build(
+        mut self,
+        rules: Vec<Rule>,
+    ) -> Result<BTreeMap<String, (Vec<u64>, usize)>, TailoringError> {
+        let mut ces = Vec::new();
+        let mut position = Position::After(0);
+        let mut before = None;
+        for rule in rules {
+            match rule {
+                Rule::Reset {
+                    offset,
+                    before: reset_before,
+                    string,
+                } => {
+                    ces = self.ces(&string);
+                    let node = match ces.last() {
+                        Some(Ce::Root(ce)) => self.root_node(*ce),
+                        Some(Ce::Node(node)) => *node,
+                        // Resetting to a completely ignorable string
+                        None => return Err(TailoringError::Unsupported(offset)),
+                    };
+                    position = match reset_before {
+                        None => Position::After(node),
+                        Some(level) => self.before(node, level, offset)?,
+                    };
+                    before = reset_before;
+                }
+                Rule::Relation {
+                    offset,
+                    level,
+                    string,
+                    extension,
+                } => {
+                    if level != Level::Identical {
+                        if before.is_some_and(|b| b != level) {
+                            // The relation after `&[before n]` must have strength n.
+                            return Err(TailoringError::Syntax(offset));
+                        }
+                        let case = self.case_bits(&string);
+                        let node = self.insert(position, level, case, offset);
+                        ces.pop();
+                        ces.push(Ce::Node(node));
+                        position = Position::After(node);
+                        before = None;
+                    } else if before.is_some() {
+                        return Err(TailoringError::Syntax(offset));
+                    }
+                    let mut mapping = ces.clone();
+                    if !extension.is_empty() {
+                        mapping.extend(self.ces(&extension));
+                    }
+                    self.mappings.insert(string, (mapping, offset));
+                }
+            }
+        }
+
+        let node_ces = self.allocate_weights()?;
+        Ok(self
+            .mappings
+            .into_iter()
+            .map(|(string, (ces, offset))| {
+                let ces = ces
+                    .into_iter()
+                    .map(|ce| match ce {
+                        Ce::Root(ce) => ce,
+                        Ce::Node(node) => node_ces.get(node).copied().unwrap_or_default(),
+                    })
+                    .collect();
+                (string, (ces, offset))
+            })
+            .collect())
+    }
+
+    /// The collation elements of a string, using the tailored strings
+    /// so far and the root collation for the rest.
+    fn ces(&self, s: &str) -> Vec<Ce> {
+        let mut ces = Vec::new();
+        let mut untailored_start = 0;
+        let mut i = 0;
+        while i < s.len() {
+            let tailored = s
+                .get(i..)
+                .unwrap_or_default()
+                .char_indices()
+                .skip(1)
+                .map(|(len, _)| i + len)
+                .chain(core::iter::once(s.len()))
+                .collect::<Vec<_>>()
+                .into_iter()
+                .rev()
+                .find_map(|end| {
+                    let key = s.get(i..end)?;
+                    self.mappings.get(key).map(|(mapping, _)| (end, mapping))
+                })
+                .filter(|_| {
+                    s.get(i..)
+                        .and_then(|rest| rest.chars().next())
+                        .is_some_and(|c| self.root.ccc(c).to_icu4c_value() == 0)
+                });
+            if let Some((end, mapping)) = tailored {
+                ces.extend(
+                    self.root
+                        .ces(s.get(untailored_start..i).unwrap_or_default())
+                        .into_iter()
+                        .map(Ce::Root),
+                );
+                ces.extend_from_slice(mapping);
+                i = end;
+                untailored_start = end;
+            } else {
+                i += s
+                    .get(i..)
+                    .and_then(|rest| rest.chars().next())
+                    .map_or(1, char::len_utf8);
+            }
+        }
+        ces.extend(
+            self.root
+                .ces(s.get(untailored_start..).unwrap_or_default())
+                .into_iter()
+                .map(Ce::Root),
+        );
+        ces
+    }
+
+    /// The case bits for a tailored string, from its root collation elements.
+    fn case_bits(&self, s: &str) -> u16 {
+        let mut cases = self
+            .root
+            .ces(s)
+            .into_iter()
+            .filter(|ce| (ce >> 32) != 0)
+            .map(|ce| (ce as u16) & CASE_MASK);
+        let Some(first) = cases.next() else {
+            return LOWER_CASE;
+        };
+        if cases.all(|case| case == first) {
+            first
+        } else {
+            MIXED_CASE
+        }
+    }
+
+    fn index(&self, node: usize) -> (u32, usize) {
+        let group = self.nodes.get(node).map(|n| n.group).unwrap_or_default();
+        let index = self
+            .lists
+            .get(&group)
+            .and_then(|list| list.iter().position(|&n| n == node))
+            .unwrap_or_default();
+        (group, index)
+    }
+
+    fn node(&self, group: u32, index: usize) -> Option<&Node> {
+        let node = *self.lists.get(&group)?.get(index)?;
+        self.nodes.get(node)
+    }
+
+    fn insert_at(&mut self, group: u32, index: usize, node: Node) -> usize {
+        let id = self.nodes.len();
+        self.nodes.push(node);
+        let list = self.lists.entry(group).or_default();
+        list.insert(index.min(list.len()), id);
+        id
+    }
+
+    /// The node at the head of the list for a root primary.
+    fn head(&mut self, primary: u32) -> usize {
+        if let Some(&head) = self.lists.get(&primary).and_then(|list| list.first()) {
+            return head;
+        }
+        self.insert_at(
+            primary,
+            0,
+            Node {
+                group: primary,
+                level: Level::Primary,
+                weight: primary,
+                tailored: false,
+                case: 0,
+                offset: 0,
+            },
+        )
+    }
+
+    /// The index range of the nodes below the one at `index` down to `level`.
+    fn children(&self, group: u32, index: usize, level: Level) -> core::ops::Range<usize> {
+        let start = index + 1;
+        let mut end = start;
+        while self.node(group, end).is_some_and(|n| n.level >= level) {
+            end += 1;
+        }
+        start..end
+    }
+
+    fn explicit_common(&self, parent: usize, level: Level) -> Option<usize> {
+        let (group, index) = self.index(parent);
+        let list = self.lists.get(&group)?;
+        self.children(group, index, level)
+            .filter_map(|i| list.get(i).copied())
+            .find(|&n| {
+                self.nodes
+                    .get(n)
+                    .is_some_and(|n| n.level == level && n.is_explicit_common())
+            })
+    }
+
+    /// Makes the common weight at `level` below `parent` explicit.
+    fn ensure_explicit_common(&mut self, parent: usize, level: Level) -> usize {
+        if let Some(common) = self.explicit_common(parent, level) {
+            return common;
+        }
+        // All existing nodes at this level are above the implicit common weight.
+        let (group, index) = self.index(parent);
+        self.insert_at(
+            group,
+            index + 1,
+            Node {
+                group,
+                level,
+                weight: COMMON_WEIGHT16,
+                tailored: false,
+                case: 0,
+                offset: 0,
+            },
+        )
+    }
+
+    /// Finds or inserts the root node for a secondary or tertiary weight below `parent`.
+    fn find_or_insert_weak(&mut self, parent: usize, weight: u32, level: Level) -> usize {
+        if weight == COMMON_WEIGHT16 {
+            return self.explicit_common(parent, level).unwrap_or(parent);
+        }
+        if weight < COMMON_WEIGHT16 {
+            self.ensure_explicit_common(parent, level);
+        }
+        let (group, index) = self.index(parent);
+        let range = self.children(group, index, level);
+        let mut insert_at = range.end;
+        for i in range {
+            let Some(&id) = self.lists.get(&group).and_then(|list| list.get(i)) else {
+                break;
+            };
+            let Some(node) = self.nodes.get(id) else {
+                break;
+            };
+            if node.level == level && !node.tailored {
+                if node.weight == weight {
+                    return id;
+                }
+                if node.weight > weight {
+                    insert_at = i;
+                    break;
+                }
+            }
+        }
+        self.insert_at(
+            group,
+            insert_at,
+            Node {
+                group,
+                level,
+                weight,
+                tailored: false,
+                case: 0,
+                offset: 0,
+            },
+        )
+    }
+
+    /// Finds or inserts the node for a root collation element.
+    fn root_node(&mut self, ce: u64) -> usize {
+        let primary = (ce >> 32) as u32;
+        let secondary = ((ce >> 16) & 0xFFFF) as u32;
+        let tertiary = u32::from((ce as u16) & TERTIARY_MASK);
+        let head = self.head(primary);
+        let secondary = self.find_or_insert_weak(head, secondary, Level::Secondary);
+        self.find_or_insert_weak(secondary, tertiary, Level::Tertiary)
+    }
+
+    /// The position for a relation after `&[before n]`.
+    fn before(
+        &mut self,
+        node: usize,
+        level: Level,
+        offset: usize,
+    ) -> Result<Position, TailoringError> {
+        let (group, index) = self.index(node);
+        match level {
+            Level::Primary => {
+                let primary_index = (0..=index)
+                    .rev()
+                    .find(|&i| {
+                        self.node(group, i)
+                            .is_some_and(|n| n.level == Level::Primary)
+                    })
+                    .unwrap_or_default();
+                if primary_index > 0 {
+                    return Ok(Position::Before(
+                        self.lists
+                            .get(&group)
+                            .and_then(|list| list.get(primary_index))
+                            .copied()
+                            .unwrap_or_default(),
+                    ));
+                }
+                // Go to the end of the list for the previous root primary.
+                let previous_root = self
+                    .root_primaries
+                    .partition_point(|&p| p < group)
+                    .checked_sub(1)
+                    .and_then(|i| self.root_primaries.get(i))
+                    .copied();
+                let previous_list = self.lists.range(..group).next_back().map(|(&p, _)| p);
+                let Some(previous) = previous_root.max(previous_list).filter(|&p| p != 0) else {
+                    return Err(TailoringError::Unsupported(offset));
+                };
+                let head = self.head(previous);
+                let last = self
+                    .lists
+                    .get(&previous)
+                    .and_then(|list| list.last())
+                    .copied()
+                    .unwrap_or(head);
+                Ok(Position::After(last))
+            }
+            Level::Secondary => {
+                let parent_index = (0..=index)
+                    .rev()
+                    .find(|&i| {
+                        self.node(group, i)
+                            .is_some_and(|n| n.level <= Level::Secondary)
+                    })
+                    .unwrap_or_default();
+                let parent = self
+                    .lists
+                    .get(&group)
+                    .and_then(|list| list.get(parent_index))
+                    .copied()
+                    .unwrap_or(node);
+                if self
+                    .nodes
+                    .get(parent)
+                    .is_some_and(|n| n.level == Level::Secondary)
+                {
+                    Ok(Position::Before(parent))
+                } else {
+                    Ok(Position::Before(
+                        self.ensure_explicit_common(parent, Level::Secondary),
+                    ))
+                }
+            }
+            _ => match self.nodes.get(node).map(|n| n.level) {
+                Some(Level::Primary) => {
+                    let parent = self.explicit_common(node, Level::Secondary).unwrap_or(node);
+                    Ok(Position::Before(
+                        self.ensure_explicit_common(parent, Level::Tertiary),
+                    ))
+                }
+                Some(Level::Secondary) => Ok(Position::Before(
+                    self.ensure_explicit_common(node, Level::Tertiary),
+                )),
+                _ => Ok(Position::Before(node)),
+            },
+        }
+    }
+
+    /// Inserts a tailored node for a relation.
+    fn insert(&mut self, position: Position, level: Level, case: u16, offset: usize) -> usize {
+        let (group, index) = match position {
+            Position::Before(node) => self.index(node),
+            Position::After(node) => {
+                let (group, index) = self.index(node);
+                let mut current = self
+                    .nodes
+                    .get(node)
+                    .map(|n| n.level)
+                    .unwrap_or(Level::Primary);
+                let mut index = index + 1;
+                // Nodes that make the common weights of `node` explicit stand for `node` itself.
+                while let Some(n) = self.node(group, index) {
+                    if n.is_explicit_common() && n.level > current && n.level <= level {
+                        current = n.level;
+                        index += 1;
+                    } else {
+                        break;
+                    }
+                }
+                while self.node(group, index).is_some_and(|n| n.level > level) {
+                    index += 1;
+                }
+                (group, index)
+            }
+        };
+        self.insert_at(
+            group,
+            index,
+            Node {
+                group,
+                level,
+                weight: 0,
+                tailored: true,
+                case,
+                offset,
+            },
+        )
+    }
+
+    /// Assigns weights to the tailored nodes and returns the collation
+    /// element for each node.
+    fn allocate_weights(&mut self) -> Result<Vec<u64>, TailoringError> {
+        let lists = core::mem::take(&mut self.lists);
+        for (&group, list) in &lists {
+            // Primary weights
+            let primaries = list
+                .iter()
+                .copied()
+                .filter(|&n| {
+                    self.nodes
+                        .get(n)
+                        .is_some_and(|n| n.tailored && n.level == Level::Primary)
+                })
+                .collect::<Vec<_>>();
+            if let Some(&first) = primaries.first() {
+                let offset = self.nodes.get(first).map(|n| n.offset).unwrap_or_default();
+                if group == 0 {
+                    // Primary relations after ignorables
+                    return Err(TailoringError::Unsupported(offset));
+                }
+                let upper = self
+                    .root_primaries
+                    .get(self.root_primaries.partition_point(|&p| p <= group))
+                    .copied()
+                    .unwrap_or(u32::MAX);
+                let weights = WeightKind::Primary
+                    .allocate(group, upper, primaries.len())
+                    .ok_or(TailoringError::WeightsExhausted(offset))?;
+                for (n, w) in primaries.into_iter().zip(weights) {
+                    if let Some(node) = self.nodes.get_mut(n) {
+                        node.weight = w;
+                    }
+                }
+            }
+
+            // Secondary and tertiary weights
+            for (index, &parent) in list.iter().enumerate() {
+                let Some(parent_level) = self.nodes.get(parent).map(|n| n.level) else {
+                    continue;
+                };
+                if parent_level == Level::Primary {
+                    self.allocate_children(list, index, Level::Secondary)?;
+                }
+                if parent_level <= Level::Secondary {
+                    self.allocate_children(list, index, Level::Tertiary)?;
+                }
+            }
+        }
+
+        let mut ces = alloc::vec![0; self.nodes.len()];
+        for (&group, list) in &lists {
+            let mut primary = group;
+            let mut secondary = COMMON_WEIGHT16;
+            for &n in list {
+                let Some(node) = self.nodes.get(n) else {
+                    continue;
+                };
+                let tertiary = match node.level {
+                    Level::Primary => {
+                        primary = node.weight;
+                        secondary = COMMON_WEIGHT16;
+                        COMMON_WEIGHT16
+                    }
+                    Level::Secondary => {
+                        secondary = node.weight;
+                        COMMON_WEIGHT16
+                    }
+                    _ => node.weight,
+                };
+                if node.tailored {
+                    if let Some(ce) = ces.get_mut(n) {
+                        *ce = (u64::from(primary) << 32)
+                            | (u64::from(secondary) << 16)
+                            | u64::from(node.case)
+                            | u64::from(tertiary);
+                    }
+                }
+            }
+        }
+        self.lists = lists;
+        Ok(ces)
+    }
+
+    /// Allocates weights for the tailored children at `level` of the
+    /// node at `index`, in the gaps between the root weights.
+    fn allocate_children(
+        &mut self,
+        list: &[usize],
+        index: usize,
+        level: Level,
+    ) -> Result<(), TailoringError> {
+        let mut end = index + 1;
+        while list
+            .get(end)
+            .and_then(|&n| self.nodes.get(n))
+            .is_some_and(|n| n.level >= level)
+        {
+            end += 1;
+        }
+        let children = list
+            .get(index + 1..end)
+            .unwrap_or_default()
+            .iter()
+            .copied()
+            .filter(|&n| self.nodes.get(n).is_some_and(|n| n.level == level))
+            .collect::<Vec<_>>();
+        let has_explicit_common = children
+            .iter()
+            .any(|&n| self.nodes.get(n).is_some_and(Node::is_explicit_common));
+        let mut lower = if has_explicit_common {
+            BELOW_COMMON_WEIGHT16
+        } else {
+            COMMON_WEIGHT16
+        };
+        let mut run = Vec::new();
+        for n in children.into_iter().map(Some).chain(core::iter::once(None)) {
+            let root_weight = match n.and_then(|n| self.nodes.get(n)) {
+                Some(node) if node.tailored => {
+                    run.extend(n);
+                    continue;
+                }
+                Some(node) => Some(node.weight),
+                None => None,
+            };
+            if let Some(&first) = run.first() {
+                let (kind, after_common_lower, after_common_upper) = if level == Level::Secondary {
+                    (
+                        WeightKind::Secondary,
+                        SECONDARY_AFTER_COMMON_LOWER,
+                        SECONDARY_AFTER_COMMON_UPPER,
+                    )
+                } else {
+                    (
+                        WeightKind::Tertiary,
+                        TERTIARY_AFTER_COMMON_LOWER,
+                        TERTIARY_AFTER_COMMON_UPPER,
+                    )
+                };
+                let (low, high) = if lower == COMMON_WEIGHT16 {
+                    let high = root_weight
+                        .unwrap_or(after_common_upper)
+                        .min(after_common_upper);
+                    if high > after_common_lower {
+                        (after_common_lower, high)
+                    } else {
+                        (lower, root_weight.unwrap_or(after_common_upper))
+                    }
+                } else {
+                    (lower, root_weight.unwrap_or((lower & 0xFF00) + 0x100))
+                };
+                let offset = self.nodes.get(first).map(|n| n.offset).unwrap_or_default();
+                let weights = kind
+                    .allocate(low, high, run.len())
+                    .ok_or(TailoringError::WeightsExhausted(offset))?;
+                for (n, w) in run.drain(..).zip(weights) {
+                    if let Some(node) = self.nodes.get_mut(n) {
+                        node.weight = w;
+                    }
+                }
+            }
+            if let Some(w) = root_weight {
+                lower = w;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The kinds of weights, with their byte constraints.
+#[derive(Copy, Clone, Debug)]
+enum WeightKind {
+    Primary,
+    Secondary,
+    Tertiary,
+}
+
+impl WeightKind {
+    /// The number of bytes in a weight of this kind.
+    fn width(self) -> u32 {
+        match self {
+            WeightKind::Primary => 4,
+            _ => 2,
+        }
+    }
+
+    /// The shift for byte `i` (1-based) of a left-aligned weight.
+    fn shift(self, i: u32) -> u32 {
+        8 * (self.width() - i)
+    }
+
+    /// The inclusive range of values for byte `i` (1-based) of `weight`.
+    fn byte_range(self, i: u32, weight: u32) -> (u32, u32) {
+        match (self, i) {
+            (WeightKind::Tertiary, 1) => (0, 0x3F),
+            (WeightKind::Tertiary, _) => (2, 0x3F),
+            (_, 1) => (0, 0xFF),
+            (WeightKind::Primary, 2)
+                if CollationSpecialPrimariesValidated::HARDCODED_FALLBACK
+                    .is_compressible((weight >> 24) as u8) =>
+            {
+                // Reserve the bytes for primary compression.
+                (4, 0xFE)
+            }
+            _ => (2, 0xFF),
+        }
+    }
+
+    /// The mask for the bytes after the first `len` bytes.
+    fn tail_mask(self, len: u32) -> u32 {
+        if len >= self.width() {
+            0
+        } else {
+            (1u32 << self.shift(len)) - 1
+        }
+    }
+
+    /// The smallest weight of `len` bytes that is greater than
+    /// `weight` truncated to `len` bytes.
+    fn increment(self, weight: u32, len: u32) -> Option<u32> {
+        let mut weight = weight & !self.tail_mask(len);
+        for i in (1..=len).rev() {
+            let shift = self.shift(i);
+            let byte = (weight >> shift) & 0xFF;
+            let (min, max) = self.byte_range(i, weight);
+            weight &= !(0xFF << shift);
+            if byte < max {
+                return Some(weight | ((byte + 1).max(min) << shift));
+            }
+            weight |= min << shift;
+        }
+        None
+    }
+
+    /// Allocates `count` weights between `lower` and `upper` (both exclusive),
+    /// preferring shorter weights. A weight must not be a prefix of `upper`.
+    fn allocate(self, lower: u32, upper: u32, count: usize) -> Option<Vec<u32>> {
+        'lengths: for len in 1..=self.width() {
+            let mut weights = Vec::with_capacity(count);
+            let mut weight = lower;
+            for _ in 0..count {
+                match self.increment(weight, len) {
+                    Some(w) if (w | self.tail_mask(len)) < upper => {
+                        weights.push(w);
+                        weight = w;
+                    }
+                    _ => continue 'lengths,
+                }
+            }
+            return Some(weights);
+        }
+        None
+    }
+}
+
+/// Collects all primary weights of the root collation.
+fn root_primaries(root: &RootData) -> Vec<u32> {
+    fn push_ce32(primaries: &mut Vec<u32>, ce32: u32) {
+        let low_byte = ce32 & 0xFF;
+        if low_byte < 0xC0 {
+            primaries.push(ce32 & 0xFFFF0000);
+        } else if CollationElement32::new(ce32).tag_checked() == Some(Tag::LongPrimary) {
+            primaries.push(ce32 & 0xFFFFFF00);
+        }
+    }
+
+    let data = root.root;
+    let mut primaries = Vec::new();
+    for range in data.trie.iter_ranges() {
+        let ce32 = CollationElement32::new(range.value);
+        match ce32.tag_checked() {
+            Some(Tag::Offset) => {
+                if let Some(data_ce) = data.ces.get(ce32.index()) {
+                    primaries.extend(
+                        range
+                            .range
+                            .filter_map(char::from_u32)
+                            .map(|c| data_ce_to_primary(data_ce, c)),
+                    );
+                }
+            }
+            Some(Tag::Contraction) | Some(Tag::Prefix) => {
+                if let Some(contexts) = data
+                    .contexts
+                    .get_subslice(ce32.index()..data.contexts.len())
+                {
+                    if let (Some(hi), Some(lo)) = (contexts.get(0), contexts.get(1)) {
+                        push_ce32(&mut primaries, (u32::from(hi) << 16) | u32::from(lo));
+                    }
+                    for (_, value) in super::data::decode_char16trie(
+                        contexts.get_subslice(2..contexts.len()).unwrap_or_default(),
+                    ) {
+                        push_ce32(&mut primaries, value as u32);
+                    }
+                }
+            }
+            _ => push_ce32(&mut primaries, range.value),
+        }
+    }
+    for ce32 in data.ce32s.iter() {
+        push_ce32(&mut primaries, ce32);
+    }
+    primaries.extend(data.ces.iter().map(|ce| (ce >> 32) as u32));
+    for ce32 in root.jamo.iter() {
+        push_ce32(&mut primaries, u32::from_unaligned(*ce32));
+    }
+    primaries.retain(|&p| p != 0);
+    primaries.sort_unstable();
+    primaries.dedup();
+    primaries
+}
@@ -0,0 +1,618 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The data formats written by this file are those of `CollationDataBuilder`,
+// `UCharsTrieBuilder`, and `UCPTrie` in ICU4C and, therefore, this code is
+// subject to the ICU license as described in LICENSE.
+
+//! Encodes the collation elements of the tailored strings as `CollationData`.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_collections::codepointtrie::{CodePointTrie, CodePointTrieHeader, TrieType};
+use icu_properties::props::CanonicalCombiningClass;
+use zerovec::{ZeroSlice, ZeroVec};
+
+use super::{RootData, TailoringError};
+use crate::elements::{
+    CollationElement32, Tag, CONTRACT_HAS_STARTER, CONTRACT_NEXT_CCC, CONTRACT_TRAILING_CCC,
+    FALLBACK_CE32, FFFD_CE32_VALUE, OPTIMIZED_DIACRITICS_MAX_COUNT,
+};
+use crate::provider::{CollationData, CollationDiacritics};
+
+const COMBINING_DIACRITICS_BASE: u32 = 0x0300;
+
+const LONG_PRIMARY_CE32_LOW_BYTE: u32 = 0xC1;
+const LONG_SECONDARY_CE32_LOW_BYTE: u32 = 0xC2;
+const EXPANSION_CE32_LOW_BYTE: u32 = 0xC6;
+const CONTRACTION_CE32_LOW_BYTE: u32 = 0xC9;
+const MAX_EXPANSION_LENGTH: usize = 31;
+const MAX_INDEX: usize = (1 << 19) - 1;
+
+/// The collation data for the tailored strings, the possibly-shortened
+/// diacritics table, and whether the table was shortened.
+pub(super) type Tailored = (CollationData<'static>, CollationDiacritics<'static>, bool);
+
+/// A character with the mappings for it alone and for the contractions starting with it.
+#[derive(Default)]
+struct Mappings {
+    single: Option<Vec<u64>>,
+    suffixes: BTreeMap<Vec<u16>, Vec<u64>>,
+}
+
+pub(super) fn build(
+    root: &RootData,
+    mappings: &BTreeMap<String, (Vec<u64>, usize)>,
+) -> Result<Tailored, TailoringError> {
+    let mut by_first = BTreeMap::<char, Mappings>::new();
+    let mut offsets = BTreeMap::<char, usize>::new();
+    for (string, (ces, offset)) in mappings {
+        let mut chars = string.chars();
+        let Some(first) = chars.next() else {
+            continue;
+        };
+        if ('\u{1100}'..='\u{11FF}').contains(&first) {
+            // Conjoining jamo always use the root collation.
+            return Err(TailoringError::Unsupported(*offset));
+        }
+        offsets.entry(first).or_insert(*offset);
+        let entry = by_first.entry(first).or_default();
+        if chars.as_str().is_empty() {
+            entry.single = Some(ces.clone());
+        } else {
+            entry
+                .suffixes
+                .insert(chars.as_str().encode_utf16().collect(), ces.clone());
+        }
+    }
+
+    let mut ces = Vec::new();
+    let mut contexts = Vec::new();
+    let mut values = BTreeMap::<u32, u32>::new();
+    for (c, mut mappings) in by_first {
+        let offset = offsets.get(&c).copied().unwrap_or_default();
+        // Keep the contractions of the root collation.
+        let root_ce32 = root.root.ce32_for_char(c);
+        if root_ce32.tag_checked() == Some(Tag::Contraction) {
+            let trie = root
+                .root
+                .contexts
+                .get_subslice(root_ce32.index() + 2..root.root.contexts.len())
+                .unwrap_or_default();
+            for (suffix, _) in decode_char16trie(trie) {
+                let mut string = String::from(c);
+                string.extend(char::decode_utf16(suffix.iter().copied()).flatten());
+                mappings
+                    .suffixes
+                    .entry(suffix)
+                    .or_insert_with(|| root.ces(&string));
+            }
+        }
+
+        let ce32 = if mappings.suffixes.is_empty() {
+            encode(&mappings.single.unwrap_or_default(), &mut ces)
+        } else {
+            let single = match mappings.single {
+                Some(single) => single,
+                None => root.ces(c.encode_utf8(&mut [0; 4])),
+            };
+            let default = encode(&single, &mut ces).ok_or(TailoringError::Unsupported(offset))?;
+            let mut flags = CONTRACT_NEXT_CCC;
+            let mut entries = Vec::new();
+            for (suffix, suffix_ces) in mappings.suffixes {
+                let cccs = char::decode_utf16(suffix.iter().copied())
+                    .flatten()
+                    .map(|c| root.ccc(c))
+                    .collect::<Vec<_>>();
+                if cccs.first() == Some(&CanonicalCombiningClass::NotReordered) {
+                    flags &= !CONTRACT_NEXT_CCC;
+                }
+                if cccs
+                    .last()
+                    .is_some_and(|&ccc| ccc != CanonicalCombiningClass::NotReordered)
+                {
+                    flags |= CONTRACT_TRAILING_CCC;
+                }
+                if cccs.contains(&CanonicalCombiningClass::NotReordered) {
+                    flags |= CONTRACT_HAS_STARTER;
+                }
+                let value =
+                    encode(&suffix_ces, &mut ces).ok_or(TailoringError::Unsupported(offset))?;
+                entries.push((suffix, value as i32));
+            }
+            let index = contexts.len();
+            if index > MAX_INDEX {
+                return Err(TailoringError::Unsupported(offset));
+            }
+            contexts.push((default >> 16) as u16);
+            contexts.push(default as u16);
+            contexts.extend(write_char16trie(&entries, 0));
+            Some(((index as u32) << 13) | flags | CONTRACTION_CE32_LOW_BYTE)
+        };
+        values.insert(
+            u32::from(c),
+            ce32.ok_or(TailoringError::Unsupported(offset))?,
+        );
+    }
+
+    // The diacritics table is used for the characters in it without looking
+    // at the trie, so it ends before the first tailored diacritic and the
+    // trie gets the root weights of the untailored ones after that.
+    let tailored_diacritics = values
+        .range(COMBINING_DIACRITICS_BASE..)
+        .next()
+        .map(|(&c, _)| (c - COMBINING_DIACRITICS_BASE) as usize)
+        .filter(|&i| i < OPTIMIZED_DIACRITICS_MAX_COUNT);
+    let secondaries = match tailored_diacritics {
+        Some(len) => {
+            for (i, secondary) in root.diacritics.iter().enumerate().skip(len) {
+                values
+                    .entry(COMBINING_DIACRITICS_BASE + i as u32)
+                    .or_insert(
+                        (u32::from(secondary) << 16) | 0x0500 | LONG_SECONDARY_CE32_LOW_BYTE,
+                    );
+            }
+            root.diacritics.iter().take(len).collect()
+        }
+        None => root.diacritics.iter().collect(),
+    };
+
+    let trie = build_trie(&values).ok_or(TailoringError::Unsupported(0))?;
+    Ok((
+        CollationData {
+            trie,
+            ces: ZeroVec::alloc_from_slice(&ces),
+            ce32s: ZeroVec::new(),
+            contexts: ZeroVec::alloc_from_slice(&contexts),
+        },
+        CollationDiacritics { secondaries },
+        tailored_diacritics.is_some(),
+    ))
+}
+
+/// Encodes collation elements as a CE32, appending to `ces` for expansions.
+fn encode(ce_list: &[u64], ces: &mut Vec<u64>) -> Option<u32> {
+    if let [ce] = ce_list {
+        let primary = (ce >> 32) as u32;
+        let secondary = ((ce >> 16) & 0xFFFF) as u32;
+        let tertiary = (ce & 0xFFFF) as u32;
+        if primary & 0xFFFF == 0 && secondary & 0xFF == 0 && tertiary & 0xFF == 0 {
+            // Simple CE32; the case bits cannot both be set, so this is not special.
+            return Some(primary | secondary | (tertiary >> 8));
+        }
+        if secondary == 0x0500 && tertiary == 0x0500 && primary & 0xFF == 0 {
+            return Some(primary | LONG_PRIMARY_CE32_LOW_BYTE);
+        }
+        if primary == 0 && tertiary & 0xFF == 0 {
+            return Some((secondary << 16) | tertiary | LONG_SECONDARY_CE32_LOW_BYTE);
+        }
+    }
+    if ce_list.is_empty() {
+        // Completely ignorable
+        return Some(0);
+    }
+    let index = ces.len();
+    if ce_list.len() > MAX_EXPANSION_LENGTH || index > MAX_INDEX {
+        return None;
+    }
+    ces.extend_from_slice(ce_list);
+    Some(((index as u32) << 13) | ((ce_list.len() as u32) << 8) | EXPANSION_CE32_LOW_BYTE)
+}
+
+/// Builds a fast-type `CodePointTrie` mapping the given code points to their
+/// values and everything else to `FALLBACK_CE32`.
+fn build_trie(values: &BTreeMap<u32, u32>) -> Option<CodePointTrie<'static, u32>> {
+    const NULL: u32 = 0xC0;
+    debug_assert_eq!(CollationElement32::new(NULL), FALLBACK_CE32);
+
+    // The data starts with the null block.
+    let mut data = Vec::from([NULL; 64]);
+    let mut blocks = BTreeMap::<Vec<u32>, u16>::new();
+    let mut block = |start: u32, len: u32, data: &mut Vec<u32>| -> Option<u16> {
+        if values.range(start..start + len).next().is_none() {
+            return Some(0);
+        }
+        let block = (start..start + len)
+            .map(|c| values.get(&c).copied().unwrap_or(NULL))
+            .collect::<Vec<_>>();
+        if let Some(&offset) = blocks.get(&block) {
+            return Some(offset);
+        }
+        let offset = u16::try_from(data.len()).ok()?;
+        data.extend_from_slice(&block);
+        blocks.insert(block, offset);
+        Some(offset)
+    };
+
+    let mut index = Vec::new();
+    for start in (0..0x10000).step_by(64) {
+        index.push(block(start, 64, &mut data)?);
+    }
+
+    let high_start = match values.keys().next_back() {
+        Some(&max) if max >= 0x10000 => ((max >> 14) + 1) << 14,
+        _ => 0x10000,
+    };
+    let mut index3_null_offset = 0x7FFF;
+    if high_start > 0x10000 {
+        // Supplementary code points: index-1, index-2, and index-3 blocks of 32
+        // entries for 16K, 512, and 16 code points.
+        let index1_start = index.len();
+        let index1_len = ((high_start - 0x10000) >> 14) as usize;
+        index.resize(index1_start + index1_len, 0);
+        let index2_null_offset = u16::try_from(index.len()).ok()?;
+        index3_null_offset = index2_null_offset + 32;
+        index.extend([index3_null_offset; 32]);
+        index.extend([0; 32]);
+        for i1 in 0..index1_len {
+            let start1 = 0x10000 + ((i1 as u32) << 14);
+            let index2 = if values.range(start1..start1 + 0x4000).next().is_none() {
+                index2_null_offset
+            } else {
+                let index2 = index.len();
+                index.resize(index2 + 32, index3_null_offset);
+                for i2 in 0..32 {
+                    let start2 = start1 + (i2 << 9);
+                    if values.range(start2..start2 + 0x200).next().is_some() {
+                        let index3 = u16::try_from(index.len()).ok()?;
+                        for i3 in 0..32 {
+                            let offset = block(start2 + (i3 << 4), 16, &mut data)?;
+                            index.push(offset);
+                        }
+                        *index.get_mut(index2 + i2 as usize)? = index3;
+                    }
+                }
+                u16::try_from(index2).ok()?
+            };
+            *index.get_mut(index1_start + i1)? = index2;
+        }
+        if index.len() >= 0x8000 {
+            return None;
+        }
+    }
+
+    // The high value and the error value
+    data.push(NULL);
+    data.push(FFFD_CE32_VALUE);
+
+    CodePointTrie::try_new(
+        CodePointTrieHeader {
+            high_start,
+            shifted12_high_start: (high_start >> 12) as u16,
+            index3_null_offset,
+            data_null_offset: 0,
+            null_value: NULL,
+            trie_type: TrieType::Fast,
+        },
+        ZeroVec::alloc_from_slice(&index),
+        ZeroVec::alloc_from_slice(&data),
+    )
+    .ok()
+}
+
+// Constants for the `Char16Trie` format; see `icu_collections::char16trie`.
+const MIN_LINEAR_MATCH: u16 = 0x30;
+const MAX_LINEAR_MATCH_LENGTH: usize = 0x10;
+const MIN_VALUE_LEAD: u16 = MIN_LINEAR_MATCH + MAX_LINEAR_MATCH_LENGTH as u16;
+const NODE_TYPE_MASK: u16 = MIN_VALUE_LEAD - 1;
+const VALUE_IS_FINAL: u16 = 0x8000;
+const MAX_ONE_UNIT_VALUE: u32 = 0x3fff;
+const MIN_TWO_UNIT_VALUE_LEAD: u16 = 0x4000;
+const THREE_UNIT_VALUE_LEAD: u16 = 0x7fff;
+const MAX_TWO_UNIT_VALUE: u32 = ((THREE_UNIT_VALUE_LEAD - MIN_TWO_UNIT_VALUE_LEAD) as u32) << 16;
+const MAX_ONE_UNIT_NODE_VALUE: u32 = 0xff;
+const MIN_TWO_UNIT_NODE_VALUE_LEAD: u16 =
+    MIN_VALUE_LEAD + ((MAX_ONE_UNIT_NODE_VALUE + 1) << 6) as u16;
+const THREE_UNIT_NODE_VALUE_LEAD: u16 = 0x7fc0;
+const MAX_TWO_UNIT_NODE_VALUE: u32 =
+    ((THREE_UNIT_NODE_VALUE_LEAD - MIN_TWO_UNIT_NODE_VALUE_LEAD) as u32) << 10;
+const MAX_BRANCH_LINEAR_SUB_NODE_LENGTH: usize = 5;
+const MAX_ONE_UNIT_DELTA: u32 = 0xfbff;
+const MIN_TWO_UNIT_DELTA_LEAD: u16 = MAX_ONE_UNIT_DELTA as u16 + 1;
+const THREE_UNIT_DELTA_LEAD: u16 = 0xffff;
+const MAX_TWO_UNIT_DELTA: u32 = ((THREE_UNIT_DELTA_LEAD - MIN_TWO_UNIT_DELTA_LEAD) as u32) << 16;
+
+/// Encodes a value or a jump delta, without the final-value bit.
+fn write_value(value: i32, out: &mut Vec<u16>) {
+    let v = value as u32;
+    if v <= MAX_ONE_UNIT_VALUE {
+        out.push(v as u16);
+    } else if v < MAX_TWO_UNIT_VALUE {
+        out.push(MIN_TWO_UNIT_VALUE_LEAD + (v >> 16) as u16);
+        out.push(v as u16);
+    } else {
+        out.push(THREE_UNIT_VALUE_LEAD);
+        out.push((v >> 16) as u16);
+        out.push(v as u16);
+    }
+}
+
+fn write_final_value(value: i32) -> Vec<u16> {
+    let mut out = Vec::new();
+    write_value(value, &mut out);
+    if let Some(lead) = out.first_mut() {
+        *lead |= VALUE_IS_FINAL;
+    }
+    out
+}
+
+/// Writes the trie for the given sorted, unique keys below `depth`.
+/// The keys must all be longer than `depth`, except possibly the first one.
+fn write_char16trie(entries: &[(Vec<u16>, i32)], depth: usize) -> Vec<u16> {
+    let (value, rest) = match entries.split_first() {
+        Some(((key, value), rest)) if key.len() == depth => (Some(*value), rest),
+        _ => (None, entries),
+    };
+    if rest.is_empty() {
+        return write_final_value(value.unwrap_or_default());
+    }
+
+    let unit_at = |entry: &(Vec<u16>, i32), i: usize| entry.0.get(i).copied();
+    let mut body = Vec::new();
+    let first_unit = rest.first().and_then(|e| unit_at(e, depth));
+    if rest.iter().all(|e| unit_at(e, depth) == first_unit) {
+        // Linear match for the units that all keys share
+        let mut len = 1;
+        while len < MAX_LINEAR_MATCH_LENGTH
+            && rest.iter().all(|e| e.0.len() > depth + len)
+            && rest.iter().all(|e| {
+                unit_at(e, depth + len) == rest.first().and_then(|f| unit_at(f, depth + len))
+            })
+        {
+            len += 1;
+        }
+        body.push(MIN_LINEAR_MATCH + len as u16 - 1);
+        body.extend(
+            rest.first()
+                .and_then(|e| e.0.get(depth..depth + len))
+                .unwrap_or_default(),
+        );
+        body.extend(write_char16trie(rest, depth + len));
+    } else {
+        let mut groups = Vec::<&[(Vec<u16>, i32)]>::new();
+        let mut start = 0;
+        for i in 1..=rest.len() {
+            if i == rest.len()
+                || rest.get(i).and_then(|e| unit_at(e, depth))
+                    != rest.get(start).and_then(|e| unit_at(e, depth))
+            {
+                groups.extend(rest.get(start..i));
+                start = i;
+            }
+        }
+        let count = groups.len() - 1;
+        if count < MIN_LINEAR_MATCH as usize {
+            body.push(count as u16);
+        } else {
+            body.push(0);
+            body.push(count as u16);
+        }
+        body.extend(write_branch(&groups, depth));
+    }
+
+    if let Some(value) = value {
+        // Put the value into the lead unit of the node.
+        let v = value as u32;
+        let node_type = body.first().copied().unwrap_or_default() & NODE_TYPE_MASK;
+        let mut out = Vec::new();
+        if v <= MAX_ONE_UNIT_NODE_VALUE {
+            out.push((((v + 1) << 6) as u16) | node_type);
+        } else if v < MAX_TWO_UNIT_NODE_VALUE {
+            out.push((MIN_TWO_UNIT_NODE_VALUE_LEAD + ((v >> 10) as u16 & 0x7fc0)) | node_type);
+            out.push(v as u16);
+        } else {
+            out.push(THREE_UNIT_NODE_VALUE_LEAD | node_type);
+            out.push((v >> 16) as u16);
+            out.push(v as u16);
+        }
+        out.extend(body.get(1..).unwrap_or_default());
+        out
+    } else {
+        body
+    }
+}
+
+/// Writes the branch sub-node for the groups of keys that differ in the unit at `depth`.
+fn write_branch(groups: &[&[(Vec<u16>, i32)]], depth: usize) -> Vec<u16> {
+    let unit = |group: &&[(Vec<u16>, i32)]| {
+        group
+            .first()
+            .and_then(|e| e.0.get(depth))
+            .copied()
+            .unwrap_or_default()
+    };
+    if groups.len() > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        // Split: units less than the middle one, and the rest
+        let (less, rest) = groups.split_at(groups.len() / 2);
+        let rest_nodes = write_branch(rest, depth);
+        let less_nodes = write_branch(less, depth);
+        let mut out = Vec::new();
+        out.push(rest.first().map(unit).unwrap_or_default());
+        let delta = rest_nodes.len() as u32;
+        if delta <= MAX_ONE_UNIT_DELTA {
+            out.push(delta as u16);
+        } else if delta < MAX_TWO_UNIT_DELTA {
+            out.push(MIN_TWO_UNIT_DELTA_LEAD + (delta >> 16) as u16);
+            out.push(delta as u16);
+        } else {
+            out.push(THREE_UNIT_DELTA_LEAD);
+            out.push((delta >> 16) as u16);
+            out.push(delta as u16);
+        }
+        out.extend(rest_nodes);
+        out.extend(less_nodes);
+        return out;
+    }
+
+    // A linear list of units, each followed by a final value or by the
+    // delta to its node, except that the node of the last one follows it.
+    let Some((last, init)) = groups.split_last() else {
+        return Vec::new();
+    };
+    let nodes = init
+        .iter()
+        .map(|group| match group {
+            [(key, value)] if key.len() == depth + 1 => (true, write_final_value(*value)),
+            _ => (false, write_char16trie(group, depth + 1)),
+        })
+        .collect::<Vec<_>>();
+    let last_node = write_char16trie(last, depth + 1);
+
+    // The deltas depend on the lengths of the entries after them, so go backwards.
+    let mut entries = Vec::<Vec<u16>>::new();
+    let mut after = 1 + last_node.len();
+    let mut jumped_nodes = 0;
+    for (group, (is_final, node)) in init.iter().zip(&nodes).rev() {
+        let mut entry = Vec::from([unit(group)]);
+        if *is_final {
+            entry.extend_from_slice(node);
+        } else {
+            // Nodes of the earlier entries come after the nodes of the later ones.
+            let nodes_before = nodes
+                .iter()
+                .filter(|(is_final, _)| !is_final)
+                .map(|(_, n)| n.len())
+                .sum::<usize>()
+                - jumped_nodes
+                - node.len();
+            write_value((after + nodes_before) as i32, &mut entry);
+            jumped_nodes += node.len();
+        }
+        after += entry.len();
+        entries.push(entry);
+    }
+    let mut out = entries.into_iter().rev().flatten().collect::<Vec<_>>();
+    out.push(unit(last));
+    out.extend(last_node);
+    for (is_final, node) in nodes {
+        if !is_final {
+            out.extend(node);
+        }
+    }
+    out
+}
+
+/// Lists the keys and values in a `Char16Trie`.
+pub(super) fn decode_char16trie(trie: &ZeroSlice<u16>) -> Vec<(Vec<u16>, i32)> {
+    let mut out = Vec::new();
+    decode_node(trie, 0, &mut Vec::new(), &mut out);
+    out
+}
+
+fn read_value(trie: &ZeroSlice<u16>, pos: usize, lead: u16) -> i32 {
+    let unit = |i| i32::from(trie.get(i).unwrap_or_default());
+    if lead < MIN_TWO_UNIT_VALUE_LEAD {
+        i32::from(lead)
+    } else if lead < THREE_UNIT_VALUE_LEAD {
+        ((i32::from(lead - MIN_TWO_UNIT_VALUE_LEAD)) << 16) | unit(pos)
+    } else {
+        (unit(pos) << 16) | unit(pos + 1)
+    }
+}
+
+fn value_length(lead: u16) -> usize {
+    if lead < MIN_TWO_UNIT_VALUE_LEAD {
+        0
+    } else if lead < THREE_UNIT_VALUE_LEAD {
+        1
+    } else {
+        2
+    }
+}
+
+fn decode_node(
+    trie: &ZeroSlice<u16>,
+    mut pos: usize,
+    key: &mut Vec<u16>,
+    out: &mut Vec<(Vec<u16>, i32)>,
+) {
+    let unit = |i: usize| trie.get(i).unwrap_or_default();
+    let mut node = unit(pos);
+    pos += 1;
+    if node >= MIN_VALUE_LEAD {
+        if node & VALUE_IS_FINAL != 0 {
+            out.push((key.clone(), read_value(trie, pos, node & !VALUE_IS_FINAL)));
+            return;
+        }
+        let value = if node < MIN_TWO_UNIT_NODE_VALUE_LEAD {
+            i32::from((node >> 6) - 1)
+        } else if node < THREE_UNIT_NODE_VALUE_LEAD {
+            pos += 1;
+            (i32::from((node & 0x7fc0) - MIN_TWO_UNIT_NODE_VALUE_LEAD) << 10)
+                | i32::from(unit(pos - 1))
+        } else {
+            pos += 2;
+            (i32::from(unit(pos - 2)) << 16) | i32::from(unit(pos - 1))
+        };
+        out.push((key.clone(), value));
+        node &= NODE_TYPE_MASK;
+    }
+    if node < MIN_LINEAR_MATCH {
+        let len = if node == 0 {
+            pos += 1;
+            usize::from(unit(pos - 1)) + 1
+        } else {
+            usize::from(node) + 1
+        };
+        decode_branch(trie, pos, len, key, out);
+    } else {
+        let len = usize::from(node - MIN_LINEAR_MATCH) + 1;
+        let depth = key.len();
+        key.extend((pos..pos + len).map(unit));
+        decode_node(trie, pos + len, key, out);
+        key.truncate(depth);
+    }
+}
+
+fn decode_branch(
+    trie: &ZeroSlice<u16>,
+    mut pos: usize,
+    mut len: usize,
+    key: &mut Vec<u16>,
+    out: &mut Vec<(Vec<u16>, i32)>,
+) {
+    let unit = |i: usize| trie.get(i).unwrap_or_default();
+    while len > MAX_BRANCH_LINEAR_SUB_NODE_LENGTH {
+        // Skip the split unit, then decode the "less" half at the delta.
+        pos += 1;
+        let lead = unit(pos);
+        let (delta, width) = if lead < MIN_TWO_UNIT_DELTA_LEAD {
+            (usize::from(lead), 1)
+        } else if lead < THREE_UNIT_DELTA_LEAD {
+            (
+                (usize::from(lead - MIN_TWO_UNIT_DELTA_LEAD) << 16) | usize::from(unit(pos + 1)),
+                2,
+            )
+        } else {
+            (
+                (usize::from(unit(pos + 1)) << 16) | usize::from(unit(pos + 2)),
+                3,
+            )
+        };
+        decode_branch(trie, pos + width + delta, len / 2, key, out);
+        pos += width;
+        len -= len / 2;
+    }
+    for _ in 1..len {
+        key.push(unit(pos));
+        let lead = unit(pos + 1);
+        let value_pos = pos + 2;
+        let next = value_pos + value_length(lead & !VALUE_IS_FINAL);
+        if lead & VALUE_IS_FINAL != 0 {
+            out.push((
+                key.clone(),
+                read_value(trie, value_pos, lead & !VALUE_IS_FINAL),
+            ));
+        } else {
+            let delta = read_value(trie, value_pos, lead) as usize;
+            decode_node(trie, next + delta, key, out);
+        }
+        key.pop();
+        pos = next;
+    }
+    key.push(unit(pos));
+    decode_node(trie, pos + 1, key, out);
+    key.pop();
+}
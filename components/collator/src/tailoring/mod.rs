@@ -0,0 +1,346 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Various collation-related algorithms and constants in this module are
+// adapted from ICU4C and, therefore, are subject to the ICU license as
+// described in LICENSE.
+
+//! Building collation tailorings at run time from rule strings.
+//!
+//! ✨ *Enabled with the `experimental` Cargo feature.*
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+//!
+//! The prebuilt tailorings that [`Collator::try_new`](crate::Collator::try_new) loads
+//! are compiled from CLDR collation rules ahead of time. [`CollationTailoring`]
+//! compiles rules in the [ICU collation rule syntax] into the same data structures
+//! on top of the root collation, so that a custom sort order can be used without
+//! regenerating data.
+//!
+//! Supported syntax:
+//!
+//! * Resets (`&a`) including `[before 1]`, `[before 2]`, and `[before 3]`
+//! * Primary, secondary, tertiary, and identical relations (`<`, `<<`, `<<<`, `=`)
+//!   as well as the legacy `;` and `,`
+//! * Starred relations with ranges (`&a <* b-dx`)
+//! * Expansions (`&a < b / c`) and contractions (`&c < ch`)
+//! * Quoting (`'-'`) and escapes (`\u00E6`, `\x{1F600}`)
+//! * The settings `strength`, `alternate`, `backwards 2`, `caseFirst`, `caseLevel`,
+//!   `numericOrdering`, and `maxVariable`
+//!
+//! Not supported: context before a relation string (`|`), quaternary relations,
+//! special reset positions like `[first regular]`, `[reorder ...]`, `[import ...]`,
+//! and `[suppressContractions ...]`. Such rules fail with [`TailoringError::Unsupported`].
+//!
+//! # Examples
+//!
+//! ```
+//! use core::cmp::Ordering;
+//! use icu::collator::options::CollatorOptions;
+//! use icu::collator::tailoring::CollationTailoring;
+//! use icu::collator::Collator;
+//!
+//! let tailoring = CollationTailoring::try_from_rules("&a < æ <<< Æ").unwrap();
+//! let collator =
+//!     Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+//! let collator = collator.as_borrowed();
+//!
+//! assert_eq!(collator.compare("æ", "b"), Ordering::Less);
+//! assert_eq!(collator.compare("aa", "æ"), Ordering::Less);
+//! assert_eq!(collator.compare("æ", "Æ"), Ordering::Less);
+//! assert_eq!(collator.compare("Æ", "b"), Ordering::Less);
+//! ```
+//!
+//! [ICU collation rule syntax]: https://unicode-org.github.io/icu/userguide/collation/customization/
+
+mod builder;
+mod data;
+mod parser;
+
+use alloc::vec::Vec;
+use displaydoc::Display;
+use icu_normalizer::provider::{
+    DecompositionData, DecompositionTables, NormalizerNfdDataV1, NormalizerNfdTablesV1,
+};
+use icu_normalizer::DecomposingNormalizerBorrowed;
+use icu_properties::props::CanonicalCombiningClass;
+use icu_provider::prelude::*;
+use zerovec::ule::AsULE;
+use zerovec::ZeroSlice;
+
+use crate::elements::{
+    ccc_from_trie_value, CollationElements, JAMO_COUNT, NO_CE, OPTIMIZED_DIACRITICS_MAX_COUNT,
+};
+use crate::options::{AlternateHandling, CollatorOptionsBitField};
+use crate::preferences::CollationCaseFirst;
+use crate::provider::{
+    CollationData, CollationDiacritics, CollationDiacriticsV1, CollationJamoV1, CollationMetadata,
+    CollationRootV1,
+};
+
+/// An error from building a [`CollationTailoring`].
+///
+/// The offsets are byte offsets into the rule string.
+#[derive(Debug, Copy, Clone, PartialEq, Display)]
+#[non_exhaustive]
+pub enum TailoringError {
+    /// The rules are not well-formed.
+    #[displaydoc("Syntax error in collation rules at offset {0}")]
+    Syntax(usize),
+    /// The rules use syntax or settings that are not supported.
+    #[displaydoc("Unsupported collation rule at offset {0}")]
+    Unsupported(usize),
+    /// There is not enough room for the weights that the rules require.
+    #[displaydoc("No room for the tailored weights of the collation rule at offset {0}")]
+    WeightsExhausted(usize),
+    /// The root collation data could not be loaded.
+    #[displaydoc("{0}")]
+    Data(DataError),
+}
+
+impl core::error::Error for TailoringError {}
+
+impl From<DataError> for TailoringError {
+    fn from(e: DataError) -> Self {
+        TailoringError::Data(e)
+    }
+}
+
+/// A collation tailoring compiled from ICU collation rules.
+///
+/// Use [`Collator::try_new_with_tailoring`](crate::Collator::try_new_with_tailoring)
+/// to compare strings with it. See the [module-level docs](self) for the supported syntax.
+#[derive(Debug)]
+pub struct CollationTailoring {
+    data: CollationData<'static>,
+    diacritics: CollationDiacritics<'static>,
+    metadata: CollationMetadata,
+    defaults: CollatorOptionsBitField,
+}
+
+impl CollationTailoring {
+    /// Compiles the given rules on top of the root collation from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_from_rules(rules: &str) -> Result<Self, TailoringError> {
+        let diacritics: DataPayload<CollationDiacriticsV1> =
+            crate::provider::Baked.load(Default::default())?.payload;
+        Self::try_from_rules_internal(
+            rules,
+            crate::provider::Baked::SINGLETON_COLLATION_ROOT_V1,
+            &crate::provider::Baked::SINGLETON_COLLATION_JAMO_V1.ce32s,
+            diacritics.get(),
+            icu_normalizer::provider::Baked::SINGLETON_NORMALIZER_NFD_DATA_V1,
+            icu_normalizer::provider::Baked::SINGLETON_NORMALIZER_NFD_TABLES_V1,
+        )
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (rules: &str) -> error: TailoringError,
+        functions: [
+            try_from_rules: skip,
+            try_from_rules_with_buffer_provider,
+            try_from_rules_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_from_rules)]
+    pub fn try_from_rules_unstable<D>(provider: &D, rules: &str) -> Result<Self, TailoringError>
+    where
+        D: DataProvider<CollationRootV1>
+            + DataProvider<CollationJamoV1>
+            + DataProvider<CollationDiacriticsV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        let root: DataPayload<CollationRootV1> = provider.load(Default::default())?.payload;
+        let jamo: DataPayload<CollationJamoV1> = provider.load(Default::default())?.payload;
+        let diacritics: DataPayload<CollationDiacriticsV1> =
+            provider.load(Default::default())?.payload;
+        let decompositions: DataPayload<NormalizerNfdDataV1> =
+            provider.load(Default::default())?.payload;
+        let tables: DataPayload<NormalizerNfdTablesV1> = provider.load(Default::default())?.payload;
+        Self::try_from_rules_internal(
+            rules,
+            root.get(),
+            &jamo.get().ce32s,
+            diacritics.get(),
+            decompositions.get(),
+            tables.get(),
+        )
+    }
+
+    fn try_from_rules_internal(
+        rules: &str,
+        root: &CollationData,
+        jamo: &ZeroSlice<u32>,
+        diacritics: &CollationDiacritics,
+        decompositions: &DecompositionData,
+        tables: &DecompositionTables,
+    ) -> Result<Self, TailoringError> {
+        let Ok(jamo) = <&[<u32 as AsULE>::ULE; JAMO_COUNT]>::try_from(jamo.as_ule_slice()) else {
+            return Err(DataError::custom("invalid")
+                .with_marker(CollationJamoV1::INFO)
+                .into());
+        };
+        if diacritics.secondaries.len() != OPTIMIZED_DIACRITICS_MAX_COUNT {
+            return Err(DataError::custom("invalid")
+                .with_marker(CollationDiacriticsV1::INFO)
+                .into());
+        }
+        let root = RootData {
+            root,
+            jamo,
+            diacritics: &diacritics.secondaries,
+            decompositions,
+            tables,
+        };
+
+        let mut parser = parser::Parser::new(
+            rules,
+            DecomposingNormalizerBorrowed::new_with_data(decompositions, tables),
+        );
+        parser.parse()?;
+        let settings = parser.settings;
+
+        let mappings = builder::Builder::new(&root).build(parser.items)?;
+        let (data, diacritics, tailored_diacritics) = data::build(&root, &mappings)?;
+
+        let mut bits = CollationMetadata::TAILORED_MASK;
+        if tailored_diacritics {
+            bits |= CollationMetadata::TAILORED_DIACRITICS_MASK;
+        }
+        if settings.alternate == Some(AlternateHandling::Shifted) {
+            bits |= CollationMetadata::ALTERNATE_SHIFTED_MASK;
+        }
+        if settings.backward_second_level == Some(true) {
+            bits |= CollationMetadata::BACWARD_SECOND_LEVEL_MASK;
+        }
+        match settings.case_first {
+            Some(CollationCaseFirst::Upper) => {
+                bits |= CollationMetadata::CASE_FIRST_MASK | CollationMetadata::UPPER_FIRST_MASK
+            }
+            Some(CollationCaseFirst::Lower) => bits |= CollationMetadata::CASE_FIRST_MASK,
+            _ => {}
+        }
+        bits |= settings
+            .max_variable
+            .unwrap_or(crate::options::MaxVariable::Punctuation) as u32
+            & CollationMetadata::MAX_VARIABLE_MASK;
+
+        let mut defaults = CollatorOptionsBitField::default();
+        // Unset options are left alone so that they keep the usual defaults.
+        if settings.strength.is_some() {
+            defaults.set_strength(settings.strength);
+        }
+        if settings.alternate.is_some() {
+            defaults.set_alternate_handling(settings.alternate);
+        }
+        if settings.backward_second_level.is_some() {
+            defaults.set_backward_second_level(settings.backward_second_level);
+        }
+        if settings.case_first.is_some() {
+            defaults.set_case_first(settings.case_first);
+        }
+        if settings.case_level.is_some() {
+            defaults.set_case_level(settings.case_level);
+        }
+        if settings.numeric.is_some() {
+            defaults.set_numeric(settings.numeric);
+        }
+        if settings.max_variable.is_some() {
+            defaults.set_max_variable(settings.max_variable);
+        }
+
+        Ok(CollationTailoring {
+            data,
+            diacritics,
+            metadata: CollationMetadata { bits },
+            defaults,
+        })
+    }
+
+    /// The main collation data of the tailoring.
+    ///
+    /// Code points that are not tailored map to the fallback value, which
+    /// means that the root collation applies to them.
+    pub fn data(&self) -> &CollationData<'static> {
+        &self.data
+    }
+
+    /// The secondary weights for the start of the Combining Diacritics block.
+    ///
+    /// If the rules tailor any of these characters, the table is shortened
+    /// and the tailored characters and the ones after them are in [`Self::data`].
+    pub fn diacritics(&self) -> &CollationDiacritics<'static> {
+        &self.diacritics
+    }
+
+    /// The metadata bits describing the tailoring and its settings.
+    pub fn metadata(&self) -> CollationMetadata {
+        self.metadata
+    }
+
+    pub(crate) fn into_parts(
+        self,
+    ) -> (
+        CollationData<'static>,
+        CollationDiacritics<'static>,
+        CollationMetadata,
+        CollatorOptionsBitField,
+    ) {
+        (self.data, self.diacritics, self.metadata, self.defaults)
+    }
+}
+
+/// The root collation data that a tailoring is built on.
+struct RootData<'a> {
+    root: &'a CollationData<'a>,
+    jamo: &'a [<u32 as AsULE>::ULE; JAMO_COUNT],
+    diacritics: &'a ZeroSlice<u16>,
+    decompositions: &'a DecompositionData<'a>,
+    tables: &'a DecompositionTables<'a>,
+}
+
+impl RootData<'_> {
+    /// The root collation elements of a string, not including
+    /// completely ignorable ones.
+    fn ces(&self, s: &str) -> Vec<u64> {
+        let mut iter = CollationElements::new(
+            s.chars(),
+            self.root,
+            self.root,
+            self.jamo,
+            self.diacritics,
+            self.decompositions,
+            self.tables,
+            None,
+            false,
+        );
+        iter.init();
+        let mut ces = Vec::new();
+        loop {
+            let ce = iter.next();
+            if ce == NO_CE {
+                return ces;
+            }
+            let bits = (u64::from(ce.primary()) << 32) | u64::from(ce.non_primary().bits());
+            if bits != 0 {
+                ces.push(bits);
+            }
+        }
+    }
+
+    fn ccc(&self, c: char) -> CanonicalCombiningClass {
+        ccc_from_trie_value(self.decompositions.trie.get32(u32::from(c)))
+    }
+}
@@ -0,0 +1,473 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// The rule syntax handled in this file follows `CollationRuleParser` in ICU4C
+// and, therefore, the code is subject to the ICU license as described in LICENSE.
+
+//! Parser for the ICU collation rule syntax.
+//!
+//! See <https://unicode-org.github.io/icu/userguide/collation/customization/>
+//! for the syntax.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_normalizer::DecomposingNormalizerBorrowed;
+
+use super::TailoringError;
+use crate::options::{AlternateHandling, MaxVariable, Strength};
+use crate::preferences::CollationCaseFirst;
+
+/// The strength of a relation.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub(super) enum Level {
+    Primary,
+    Secondary,
+    Tertiary,
+    Identical,
+}
+
+/// A single reset or relation, with its strings in NFD.
+#[derive(Debug)]
+pub(super) enum Rule {
+    /// `&string` or `&[before n]string`
+    Reset {
+        offset: usize,
+        before: Option<Level>,
+        string: String,
+    },
+    /// `< string`, `<< string / extension`, etc.
+    Relation {
+        offset: usize,
+        level: Level,
+        string: String,
+        extension: String,
+    },
+}
+
+/// Settings given in square brackets. `None` means that the rules
+/// did not mention the setting.
+#[derive(Debug, Default)]
+pub(super) struct Settings {
+    pub(super) strength: Option<Strength>,
+    pub(super) alternate: Option<AlternateHandling>,
+    pub(super) backward_second_level: Option<bool>,
+    pub(super) case_first: Option<CollationCaseFirst>,
+    pub(super) case_level: Option<bool>,
+    pub(super) numeric: Option<bool>,
+    pub(super) max_variable: Option<MaxVariable>,
+}
+
+pub(super) struct Parser<'a> {
+    rules: &'a str,
+    pos: usize,
+    nfd: DecomposingNormalizerBorrowed<'a>,
+    pub(super) items: Vec<Rule>,
+    pub(super) settings: Settings,
+}
+
+fn is_white_space(c: char) -> bool {
+    // Pattern_White_Space
+    matches!(
+        c,
+        '\t' | '\n'
+            | '\u{000B}'
+            | '\u{000C}'
+            | '\r'
+            | ' '
+            | '\u{0085}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{2028}'
+            | '\u{2029}'
+    )
+}
+
+fn is_syntax_char(c: char) -> bool {
+    // ASCII punctuation and symbols
+    c.is_ascii_punctuation()
+}
+
+impl<'a> Parser<'a> {
+    pub(super) fn new(rules: &'a str, nfd: DecomposingNormalizerBorrowed<'a>) -> Self {
+        Self {
+            rules,
+            pos: 0,
+            nfd,
+            items: Vec::new(),
+            settings: Settings::default(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rules.get(self.pos..).and_then(|s| s.chars().next())
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn syntax_error(&self) -> TailoringError {
+        TailoringError::Syntax(self.pos)
+    }
+
+    /// Skips white space and `#` comments.
+    fn skip_white_space(&mut self) {
+        while let Some(c) = self.peek() {
+            if is_white_space(c) {
+                self.bump();
+            } else if c == '#' {
+                while let Some(c) = self.bump() {
+                    if c == '\n' || c == '\r' || c == '\u{0085}' || c == '\u{2028}' {
+                        break;
+                    }
+                }
+            } else {
+                break;
+            }
+        }
+    }
+
+    pub(super) fn parse(&mut self) -> Result<(), TailoringError> {
+        loop {
+            self.skip_white_space();
+            let Some(c) = self.peek() else {
+                return Ok(());
+            };
+            match c {
+                '&' => self.parse_reset()?,
+                '<' | '=' | ';' | ',' => {
+                    if self.items.is_empty() {
+                        // A relation must follow a reset.
+                        return Err(self.syntax_error());
+                    }
+                    self.parse_relation()?
+                }
+                '[' => self.parse_setting()?,
+                '@' => {
+                    // Legacy syntax for `[backwards 2]`
+                    self.bump();
+                    self.settings.backward_second_level = Some(true);
+                }
+                '!' => {
+                    // Legacy syntax for Thai/Lao prevowel reordering,
+                    // which is built into normalization-based collation.
+                    self.bump();
+                }
+                _ => return Err(self.syntax_error()),
+            }
+        }
+    }
+
+    fn parse_reset(&mut self) -> Result<(), TailoringError> {
+        let offset = self.pos;
+        self.bump(); // '&'
+        self.skip_white_space();
+        let mut before = None;
+        if self.peek() == Some('[') {
+            let option_offset = self.pos;
+            let option = self.parse_bracketed()?;
+            before = Some(match option.as_str() {
+                "before 1" => Level::Primary,
+                "before 2" => Level::Secondary,
+                "before 3" => Level::Tertiary,
+                // Special reset positions such as `[first tertiary ignorable]`
+                _ => return Err(TailoringError::Unsupported(option_offset)),
+            });
+            self.skip_white_space();
+            if self.peek() == Some('[') {
+                return Err(TailoringError::Unsupported(self.pos));
+            }
+        }
+        let string = self.parse_string()?;
+        if string.is_empty() {
+            return Err(self.syntax_error());
+        }
+        self.items.push(Rule::Reset {
+            offset,
+            before,
+            string,
+        });
+        Ok(())
+    }
+
+    fn parse_relation(&mut self) -> Result<(), TailoringError> {
+        let offset = self.pos;
+        let level = match self.bump() {
+            Some('<') => {
+                let mut count = 1;
+                while self.peek() == Some('<') {
+                    self.bump();
+                    count += 1;
+                }
+                match count {
+                    1 => Level::Primary,
+                    2 => Level::Secondary,
+                    3 => Level::Tertiary,
+                    // Quaternary relations
+                    _ => return Err(TailoringError::Unsupported(offset)),
+                }
+            }
+            Some(';') => Level::Secondary,
+            Some(',') => Level::Tertiary,
+            _ => Level::Identical,
+        };
+        if self.peek() == Some('*') {
+            self.bump();
+            return self.parse_starred(offset, level);
+        }
+        let string = self.parse_string()?;
+        if string.is_empty() {
+            return Err(self.syntax_error());
+        }
+        self.skip_white_space();
+        if self.peek() == Some('|') {
+            // Context before the relation string
+            return Err(TailoringError::Unsupported(self.pos));
+        }
+        let mut extension = String::new();
+        if self.peek() == Some('/') {
+            self.bump();
+            extension = self.parse_string()?;
+            if extension.is_empty() {
+                return Err(self.syntax_error());
+            }
+        }
+        self.items.push(Rule::Relation {
+            offset,
+            level,
+            string,
+            extension,
+        });
+        Ok(())
+    }
+
+    /// Parses the characters of a starred relation like `<*a-dx`,
+    /// which is shorthand for `<a<b<c<d<x`.
+    fn parse_starred(&mut self, offset: usize, level: Level) -> Result<(), TailoringError> {
+        let raw = self.parse_raw_string()?;
+        if raw.is_empty() {
+            return Err(self.syntax_error());
+        }
+        let mut prev = None;
+        for c in raw.chars() {
+            self.push_starred(offset, level, c);
+            prev = Some(c);
+        }
+        loop {
+            self.skip_white_space();
+            if self.peek() != Some('-') {
+                return Ok(());
+            }
+            self.bump();
+            let Some(start) = prev else {
+                return Err(self.syntax_error());
+            };
+            let raw = self.parse_raw_string()?;
+            let mut chars = raw.chars();
+            let Some(end) = chars.next() else {
+                return Err(self.syntax_error());
+            };
+            if end < start {
+                return Err(self.syntax_error());
+            }
+            for c in (u32::from(start) + 1..=u32::from(end)).filter_map(char::from_u32) {
+                self.push_starred(offset, level, c);
+            }
+            prev = Some(end);
+            for c in chars {
+                self.push_starred(offset, level, c);
+                prev = Some(c);
+            }
+        }
+    }
+
+    fn push_starred(&mut self, offset: usize, level: Level, c: char) {
+        let mut buf = [0u8; 4];
+        let string = self.nfd.normalize(c.encode_utf8(&mut buf)).into_owned();
+        self.items.push(Rule::Relation {
+            offset,
+            level,
+            string,
+            extension: String::new(),
+        });
+    }
+
+    /// Parses a string and normalizes it to NFD.
+    fn parse_string(&mut self) -> Result<String, TailoringError> {
+        let raw = self.parse_raw_string()?;
+        Ok(self.nfd.normalize(&raw).into_owned())
+    }
+
+    /// Parses a string up to the next white space or unquoted syntax character.
+    fn parse_raw_string(&mut self) -> Result<String, TailoringError> {
+        self.skip_white_space();
+        let mut s = String::new();
+        while let Some(c) = self.peek() {
+            if c == '\'' {
+                self.bump();
+                if self.peek() == Some('\'') {
+                    // `''` is an apostrophe
+                    self.bump();
+                    s.push('\'');
+                    continue;
+                }
+                loop {
+                    match self.bump() {
+                        None => return Err(self.syntax_error()),
+                        Some('\'') => {
+                            if self.peek() == Some('\'') {
+                                self.bump();
+                                s.push('\'');
+                            } else {
+                                break;
+                            }
+                        }
+                        Some(c) => s.push(c),
+                    }
+                }
+            } else if c == '\\' {
+                self.bump();
+                s.push(self.parse_escape()?);
+            } else if is_syntax_char(c) || is_white_space(c) {
+                break;
+            } else {
+                self.bump();
+                s.push(c);
+            }
+        }
+        Ok(s)
+    }
+
+    /// Parses the part of an escape after the backslash.
+    fn parse_escape(&mut self) -> Result<char, TailoringError> {
+        let offset = self.pos;
+        let c = self.bump().ok_or(TailoringError::Syntax(offset))?;
+        let value = match c {
+            'u' => self.parse_hex(4, 4)?,
+            'U' => self.parse_hex(8, 8)?,
+            'x' if self.peek() == Some('{') => {
+                self.bump();
+                let v = self.parse_hex(1, 8)?;
+                if self.bump() != Some('}') {
+                    return Err(TailoringError::Syntax(offset));
+                }
+                v
+            }
+            'x' => self.parse_hex(1, 2)?,
+            c => return Ok(c),
+        };
+        char::from_u32(value).ok_or(TailoringError::Syntax(offset))
+    }
+
+    fn parse_hex(&mut self, min: usize, max: usize) -> Result<u32, TailoringError> {
+        let offset = self.pos;
+        let mut value = 0u32;
+        let mut count = 0;
+        while count < max {
+            let Some(digit) = self.peek().and_then(|c| c.to_digit(16)) else {
+                break;
+            };
+            self.bump();
+            value = (value << 4) | digit;
+            count += 1;
+        }
+        if count < min {
+            return Err(TailoringError::Syntax(offset));
+        }
+        Ok(value)
+    }
+
+    /// Returns the contents of `[...]` with white space runs collapsed.
+    fn parse_bracketed(&mut self) -> Result<String, TailoringError> {
+        let offset = self.pos;
+        self.bump(); // '['
+        let mut s = String::new();
+        loop {
+            match self.bump() {
+                None => return Err(TailoringError::Syntax(offset)),
+                Some(']') => break,
+                Some('[') => {
+                    // Nested sets, e.g. in `[suppressContractions [...]]`
+                    return Err(TailoringError::Unsupported(offset));
+                }
+                Some(c) if is_white_space(c) => {
+                    if !s.is_empty() && !s.ends_with(' ') {
+                        s.push(' ');
+                    }
+                }
+                Some(c) => s.push(c),
+            }
+        }
+        if s.ends_with(' ') {
+            s.pop();
+        }
+        Ok(s)
+    }
+
+    fn parse_setting(&mut self) -> Result<(), TailoringError> {
+        let offset = self.pos;
+        let setting = self.parse_bracketed()?;
+        let (key, value) = setting.split_once(' ').unwrap_or((setting.as_str(), ""));
+        let settings = &mut self.settings;
+        let on_off = |value: &str| match value {
+            "on" => Ok(true),
+            "off" => Ok(false),
+            _ => Err(TailoringError::Syntax(offset)),
+        };
+        match key {
+            "strength" => {
+                settings.strength = Some(match value {
+                    "1" => Strength::Primary,
+                    "2" => Strength::Secondary,
+                    "3" => Strength::Tertiary,
+                    "4" => Strength::Quaternary,
+                    "I" => Strength::Identical,
+                    _ => return Err(TailoringError::Syntax(offset)),
+                })
+            }
+            "alternate" => {
+                settings.alternate = Some(match value {
+                    "non-ignorable" => AlternateHandling::NonIgnorable,
+                    "shifted" => AlternateHandling::Shifted,
+                    _ => return Err(TailoringError::Syntax(offset)),
+                })
+            }
+            "backwards" => {
+                if value != "2" {
+                    return Err(TailoringError::Syntax(offset));
+                }
+                settings.backward_second_level = Some(true);
+            }
+            "caseFirst" => {
+                settings.case_first = Some(match value {
+                    "off" => CollationCaseFirst::False,
+                    "lower" => CollationCaseFirst::Lower,
+                    "upper" => CollationCaseFirst::Upper,
+                    _ => return Err(TailoringError::Syntax(offset)),
+                })
+            }
+            "caseLevel" => settings.case_level = Some(on_off(value)?),
+            "numericOrdering" => settings.numeric = Some(on_off(value)?),
+            "maxVariable" => {
+                settings.max_variable = Some(match value {
+                    "space" => MaxVariable::Space,
+                    "punct" => MaxVariable::Punctuation,
+                    "symbol" => MaxVariable::Symbol,
+                    "currency" => MaxVariable::Currency,
+                    _ => return Err(TailoringError::Syntax(offset)),
+                })
+            }
+            // Normalization is always on, and the Hiragana setting
+            // is deprecated and ignored by ICU4C, too.
+            "normalization" | "hiraganaQ" => {
+                on_off(value)?;
+            }
+            // `reorder`, `import`, `suppressContractions`, ...
+            _ => return Err(TailoringError::Unsupported(offset)),
+        }
+        Ok(())
+    }
+}
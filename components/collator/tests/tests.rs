@@ -844,6 +844,44 @@ fn test_reordering_owned() {
     }
 }
 
+#[test]
+fn test_resolved_preferences() {
+    // There's no explicit fi-FI data.
+    let collator = Collator::try_new(locale!("fi-FI").into(), Default::default()).unwrap();
+    let resolved = collator.resolved_preferences();
+    assert_eq!(Locale::from(resolved.locale_preferences), locale!("fi"));
+    assert_eq!(resolved.collation_type, Some(CollationType::Standard));
+    assert_eq!(resolved.case_first, Some(CollationCaseFirst::False));
+    assert_eq!(
+        resolved.numeric_ordering,
+        Some(CollationNumericOrdering::False)
+    );
+
+    // Danish sorts upper case first by default
+    let owned = Collator::try_new_unstable(
+        &TestingProvider,
+        locale!("da-u-kn").into(),
+        Default::default(),
+    )
+    .unwrap();
+    let resolved = owned.as_borrowed().resolved_preferences();
+    assert_eq!(Locale::from(resolved.locale_preferences), locale!("da"));
+    assert_eq!(resolved.case_first, Some(CollationCaseFirst::Upper));
+    assert_eq!(
+        resolved.numeric_ordering,
+        Some(CollationNumericOrdering::True)
+    );
+
+    let collator = Collator::try_new(locale!("zh-u-co-stroke").into(), Default::default()).unwrap();
+    let resolved = collator.resolved_preferences();
+    assert_eq!(resolved.collation_type, Some(CollationType::Stroke));
+
+    let collator = Collator::try_new(locale!("en-u-co-stroke").into(), Default::default()).unwrap();
+    let resolved = collator.resolved_preferences();
+    assert_eq!(Locale::from(resolved.locale_preferences), locale!("und"));
+    assert_eq!(resolved.collation_type, Some(CollationType::Standard));
+}
+
 #[test]
 fn test_vi() {
     {
@@ -2062,6 +2100,319 @@ fn test_latin1_utf16_fi() {
     );
 }
 
+#[cfg(feature = "experimental")]
+#[test]
+fn test_tailoring_from_rules() {
+    use icu_collator::tailoring::CollationTailoring;
+
+    let tailoring = CollationTailoring::try_from_rules("&a < æ <<< Æ").unwrap();
+    let collator = Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("a", "æ"), Ordering::Less);
+    assert_eq!(collator.compare("az", "æ"), Ordering::Less);
+    assert_eq!(collator.compare("æ", "Æ"), Ordering::Less);
+    assert_eq!(collator.compare("Æ", "b"), Ordering::Less);
+    assert_eq!(collator.compare("æb", "Æa"), Ordering::Greater);
+    assert_eq!(collator.compare("x", "y"), Ordering::Less);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_tailoring_from_rules_unstable() {
+    use icu_collator::tailoring::CollationTailoring;
+
+    let tailoring =
+        CollationTailoring::try_from_rules_unstable(&TestingProvider, "&n < ñ <<< Ñ").unwrap();
+    let collator = Collator::try_new_with_tailoring_unstable(
+        &TestingProvider,
+        tailoring,
+        CollatorOptions::default(),
+    )
+    .unwrap();
+    let collator = collator.as_borrowed();
+    let spanish = Collator::try_new(locale!("es").into(), CollatorOptions::default()).unwrap();
+    let words = ["nz", "ñ", "Ñ", "ña", "o", "n", "Nz", "ñz"];
+    for left in words {
+        for right in words {
+            assert_eq!(
+                collator.compare(left, right),
+                spanish.compare(left, right),
+                "{left} vs {right}"
+            );
+        }
+    }
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_tailoring_contractions() {
+    use icu_collator::tailoring::CollationTailoring;
+
+    let tailoring = CollationTailoring::try_from_rules("&c < ch <<< cH <<< Ch <<< CH").unwrap();
+    let collator = Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("cz", "ch"), Ordering::Less);
+    assert_eq!(collator.compare("ch", "d"), Ordering::Less);
+    assert_eq!(collator.compare("ch", "CH"), Ordering::Less);
+    assert_eq!(collator.compare("chz", "ci"), Ordering::Greater);
+    assert_eq!(collator.compare("c", "ch"), Ordering::Less);
+
+    // The contractions of the root collation are kept.
+    let tailoring = CollationTailoring::try_from_rules("&k < l·x").unwrap();
+    let collator = Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+    let root = Collator::try_new(Default::default(), CollatorOptions::default()).unwrap();
+    assert_eq!(
+        collator.as_borrowed().compare("l·", "l"),
+        root.compare("l·", "l")
+    );
+    assert_eq!(collator.as_borrowed().compare("l·x", "l"), Ordering::Less);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_tailoring_before() {
+    use icu_collator::tailoring::CollationTailoring;
+
+    let tailoring = CollationTailoring::try_from_rules("&[before 1]b < x").unwrap();
+    let collator = Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("az", "x"), Ordering::Less);
+    assert_eq!(collator.compare("x", "b"), Ordering::Less);
+
+    let tailoring = CollationTailoring::try_from_rules("&[before 3]a <<< x").unwrap();
+    let collator = Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+    assert_eq!(collator.as_borrowed().compare("x", "a"), Ordering::Less);
+    assert_eq!(collator.as_borrowed().compare("xb", "ab"), Ordering::Less);
+    assert_eq!(
+        collator.as_borrowed().compare("xb", "aa"),
+        Ordering::Greater
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_tailoring_diacritics() {
+    use icu_collator::tailoring::CollationTailoring;
+
+    let tailoring = CollationTailoring::try_from_rules("&\\u0301 << \\u0300").unwrap();
+    let collator = Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+    let collator = collator.as_borrowed();
+    assert_eq!(collator.compare("à", "á"), Ordering::Greater);
+    assert_eq!(collator.compare("à", "â"), Ordering::Less);
+    assert_eq!(collator.compare("ä", "â"), Ordering::Greater);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_tailoring_settings() {
+    use icu_collator::tailoring::CollationTailoring;
+
+    let tailoring = CollationTailoring::try_from_rules("[strength 1][caseFirst upper]").unwrap();
+    let collator = Collator::try_new_with_tailoring(tailoring, CollatorOptions::default()).unwrap();
+    assert_eq!(collator.as_borrowed().compare("a", "A"), Ordering::Equal);
+    assert_eq!(collator.as_borrowed().compare("a", "b"), Ordering::Less);
+
+    // Explicit options take precedence over the settings in the rules.
+    let tailoring = CollationTailoring::try_from_rules("[strength 1][caseFirst upper]").unwrap();
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Tertiary);
+    let collator = Collator::try_new_with_tailoring(tailoring, options).unwrap();
+    assert_eq!(collator.as_borrowed().compare("A", "a"), Ordering::Less);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_tailoring_errors() {
+    use icu_collator::tailoring::{CollationTailoring, TailoringError};
+
+    assert_eq!(
+        CollationTailoring::try_from_rules("&a <").unwrap_err(),
+        TailoringError::Syntax(4)
+    );
+    assert_eq!(
+        CollationTailoring::try_from_rules("a < b").unwrap_err(),
+        TailoringError::Syntax(0)
+    );
+    assert_eq!(
+        CollationTailoring::try_from_rules("&a <<<< b").unwrap_err(),
+        TailoringError::Unsupported(3)
+    );
+    assert_eq!(
+        CollationTailoring::try_from_rules("&[first regular] < b").unwrap_err(),
+        TailoringError::Unsupported(1)
+    );
+    assert_eq!(
+        CollationTailoring::try_from_rules("&a < b | c").unwrap_err(),
+        TailoringError::Unsupported(7)
+    );
+}
+
+#[cfg(feature = "experimental")]
+fn search_matches<'t>(
+    prefs: CollatorPreferences,
+    options: CollatorOptions,
+    pattern: &str,
+    text: &'t str,
+) -> Vec<&'t str> {
+    let collator = Collator::try_new(prefs, options).unwrap();
+    let search = icu_collator::search::StringSearch::new(collator, pattern);
+    search.find_iter(text).map(|range| &text[range]).collect()
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_search_strength() {
+    let text = "Résumé, RESUME, resume, résumés";
+    let mut options = CollatorOptions::default();
+
+    options.strength = Some(Strength::Primary);
+    assert_eq!(
+        search_matches(Default::default(), options, "resume", text),
+        ["Résumé", "RESUME", "resume", "résumé"]
+    );
+
+    options.strength = Some(Strength::Secondary);
+    assert_eq!(
+        search_matches(Default::default(), options, "resume", text),
+        ["RESUME", "resume"]
+    );
+
+    options.strength = Some(Strength::Tertiary);
+    assert_eq!(
+        search_matches(Default::default(), options, "resume", text),
+        ["resume"]
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_search_combining_sequences() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    // A match includes the combining marks after it.
+    assert_eq!(
+        search_matches(Default::default(), options, "a", "a\u{301}bá"),
+        ["a\u{301}", "á"]
+    );
+    // Expansions are matched as a whole.
+    assert_eq!(
+        search_matches(Default::default(), options, "ss", "Straße strasse"),
+        ["ß", "ss"]
+    );
+    assert_eq!(
+        search_matches(Default::default(), options, "s", "Straße"),
+        ["S"]
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_search_contractions() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    // In traditional Spanish, "ch" is a letter of its own.
+    assert_eq!(
+        search_matches(locale!("es-u-co-trad").into(), options, "c", "chico cosa"),
+        ["c", "c"]
+    );
+    assert_eq!(
+        search_matches(locale!("es-u-co-trad").into(), options, "h", "chico hola"),
+        ["h"]
+    );
+    assert_eq!(
+        search_matches(Default::default(), options, "h", "chico hola"),
+        ["h", "h"]
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_search_alternate_shifted() {
+    let mut options = CollatorOptions::default();
+    options.strength = Some(Strength::Primary);
+    assert_eq!(
+        search_matches(Default::default(), options, "deluxe", "de-luxe deluxe"),
+        ["deluxe"]
+    );
+    options.alternate_handling = Some(AlternateHandling::Shifted);
+    assert_eq!(
+        search_matches(
+            Default::default(),
+            options,
+            "deluxe",
+            "de-luxe deluxe de luxe"
+        ),
+        ["de-luxe", "deluxe", "de luxe"]
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_search_empty_pattern() {
+    let collator = Collator::try_new(Default::default(), Default::default()).unwrap();
+    let search = icu_collator::search::StringSearch::new(collator, "");
+    assert_eq!(search.find("abc"), None);
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_alphabetic_index_labels() {
+    use icu_collator::alphabetic_index::AlphabeticIndex;
+
+    let labels = |locale: Locale| {
+        let index = AlphabeticIndex::try_new(locale.into()).unwrap();
+        index
+            .buckets()
+            .map(|bucket| bucket.label())
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    assert_eq!(
+        labels(locale!("en")),
+        "… A B C D E F G H I J K L M N O P Q R S T U V W X Y Z …"
+    );
+    assert_eq!(labels(locale!("ja")), "… あ か さ た な は ま や ら わ …");
+    assert_eq!(
+        labels(locale!("ko")),
+        "… ㄱ ㄴ ㄷ ㄹ ㅁ ㅂ ㅅ ㅇ ㅈ ㅊ ㅋ ㅌ ㅍ ㅎ …"
+    );
+    // Multi-character labels sort by the collation of the locale.
+    assert_eq!(
+        labels(locale!("cs")),
+        "… A B C Č D E F G H CH I J K L M N O P Q R Ř S Š T U V W X Y Z Ž …"
+    );
+}
+
+#[cfg(feature = "experimental")]
+#[test]
+fn test_alphabetic_index_buckets() {
+    use icu_collator::alphabetic_index::{AlphabeticIndex, Bucket};
+
+    let index = AlphabeticIndex::try_new(locale!("en").into()).unwrap();
+    assert_eq!(index.bucket_for("apple"), Bucket::Label("A"));
+    assert_eq!(index.bucket_for("Ábel"), Bucket::Label("A"));
+    assert_eq!(index.bucket_for("Zebra"), Bucket::Label("Z"));
+    assert_eq!(index.bucket_for(""), Bucket::Underflow);
+    assert_eq!(index.bucket_for("123"), Bucket::Underflow);
+    assert_eq!(index.bucket_for("Привет"), Bucket::Overflow);
+    assert_eq!(index.bucket_index("apple"), 1);
+    assert_eq!(index.bucket_index("Привет"), 27);
+
+    let index = AlphabeticIndex::try_new(locale!("ja").into()).unwrap();
+    assert_eq!(index.bucket_for("かきく"), Bucket::Label("か"));
+    assert_eq!(index.bucket_for("カタカナ"), Bucket::Label("か"));
+    assert_eq!(index.bucket_for("ン"), Bucket::Label("わ"));
+
+    let index = AlphabeticIndex::try_new(locale!("ko").into()).unwrap();
+    assert_eq!(index.bucket_for("가나다"), Bucket::Label("ㄱ"));
+    assert_eq!(index.bucket_for("힣"), Bucket::Label("ㅎ"));
+
+    let index = AlphabeticIndex::try_new(locale!("cs").into()).unwrap();
+    assert_eq!(index.bucket_for("chata"), Bucket::Label("CH"));
+    assert_eq!(index.bucket_for("cesta"), Bucket::Label("C"));
+    assert_eq!(index.bucket_for("hrad"), Bucket::Label("H"));
+}
+
 // TODO: Test languages that map to the root.
 // The languages that map to root without script reordering are:
 // ca (at least for now)
@@ -106,6 +106,7 @@ where
     P: DataProvider<icu_calendar::provider::CalendarJapaneseModernV1>
         + DataProvider<icu_calendar::provider::CalendarChineseV1>
         + DataProvider<icu_calendar::provider::CalendarDangiV1>
+        + DataProvider<icu_calendar::provider::CalendarVietnameseV1>
         + ?Sized,
 {
     #[inline]
@@ -1049,6 +1049,28 @@ impl_date_marker!(
 );
 
 impl_date_marker!(
+    /// A [`MonthDay`](crate::input::MonthDay), such as a birthday, can be formatted
+    /// in its own calendar:
+    ///
+    /// ```
+    /// use icu::calendar::cal::Hebrew;
+    /// use icu::datetime::fieldsets::MD;
+    /// use icu::datetime::input::MonthDay;
+    /// use icu::datetime::FixedCalendarDateTimeFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = FixedCalendarDateTimeFormatter::<Hebrew, _>::try_new(
+    ///     locale!("en").into(),
+    ///     MD::medium(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let month_day =
+    ///     MonthDay::try_from_str("2024-02-24[u-ca=hebrew]", Hebrew).unwrap();
+    ///
+    /// assert_writeable_eq!(formatter.format(&month_day), "15 Adar I");
+    /// ```
     MD,
     MDT,
     description = "month and day",
@@ -1136,6 +1158,28 @@ impl_calendar_period_marker!(
 );
 
 impl_calendar_period_marker!(
+    /// A [`YearMonth`](crate::input::YearMonth), such as a billing cycle, can be formatted
+    /// in its own calendar:
+    ///
+    /// ```
+    /// use icu::datetime::fieldsets::YM;
+    /// use icu::datetime::input::YearMonth;
+    /// use icu::datetime::DateTimeFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter =
+    ///     DateTimeFormatter::try_new(locale!("en").into(), YM::medium())
+    ///         .unwrap();
+    ///
+    /// let year_month =
+    ///     YearMonth::try_from_str("2024-07", formatter.calendar()).unwrap();
+    ///
+    /// assert_writeable_eq!(
+    ///     formatter.format_same_calendar(&year_month).unwrap(),
+    ///     "Jul 2024"
+    /// );
+    /// ```
     YM,
     description = "year and month",
     sample_length = medium,
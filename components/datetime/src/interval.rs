@@ -0,0 +1,863 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of date/time intervals, such as "Jan 3 – 7, 2025".
+
+use crate::error::DateTimeFormatterLoadError;
+use crate::external_loaders::*;
+use crate::fieldsets::enums::CompositeFieldSet;
+use crate::format::datetime::try_write_pattern_items;
+use crate::format::DateTimeInputUnchecked;
+use crate::neo::{DateTimeFormatter, DateTimeFormatterPreferences, FormattedDateTime};
+use crate::parts;
+use crate::provider::fields::{self, Field, FieldSymbol};
+use crate::provider::interval::*;
+use crate::provider::pattern::{runtime, PatternItem};
+use crate::raw::neo::{DateTimeZonePatternDataBorrowed, PatternComponent};
+use crate::scaffold::*;
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Range;
+use icu_calendar::AnyCalendarKind;
+use icu_provider::marker::ErasedMarker;
+use icu_provider::prelude::zerofrom::ZeroFrom;
+use icu_provider::prelude::*;
+use writeable::{adapters::WithPart, impl_display_with_writeable, Part, Writeable};
+
+/// A field of a date or time, as relevant to interval formatting.
+///
+/// The order of the variants is from the greatest to the smallest difference.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum IntervalField {
+    Era,
+    Year,
+    Month,
+    Day,
+    Weekday,
+    DayPeriod,
+    Hour,
+    Minute,
+    Second,
+}
+
+impl IntervalField {
+    fn from_symbol(symbol: FieldSymbol) -> Option<Self> {
+        Some(match symbol {
+            FieldSymbol::Era => Self::Era,
+            FieldSymbol::Year(fields::Year::Calendar) => Self::Year,
+            FieldSymbol::Month(_) => Self::Month,
+            FieldSymbol::Day(fields::Day::DayOfMonth) => Self::Day,
+            FieldSymbol::Weekday(_) => Self::Weekday,
+            FieldSymbol::DayPeriod(_) => Self::DayPeriod,
+            FieldSymbol::Hour(_) => Self::Hour,
+            FieldSymbol::Minute => Self::Minute,
+            FieldSymbol::Second(fields::Second::Second) | FieldSymbol::DecimalSecond(_) => {
+                Self::Second
+            }
+            _ => return None,
+        })
+    }
+
+    /// The CLDR symbol used for this field as the greatest difference.
+    fn difference_symbol(self, is_12_hour: bool) -> Option<char> {
+        Some(match self {
+            Self::Era => 'G',
+            Self::Year => 'y',
+            Self::Month => 'M',
+            Self::Day => 'd',
+            Self::DayPeriod => 'a',
+            Self::Hour if is_12_hour => 'h',
+            Self::Hour => 'H',
+            Self::Minute => 'm',
+            Self::Second => 's',
+            Self::Weekday => return None,
+        })
+    }
+
+    fn is_date(self) -> bool {
+        self <= Self::Weekday
+    }
+}
+
+/// The fields displayed by a pattern or requested by a skeleton.
+///
+/// Day periods are not part of a signature: CLDR interval skeletons leave them
+/// out, since they are implied by the hour cycle.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+struct Signature {
+    era: bool,
+    year: bool,
+    /// `Some(true)` for a month name, `Some(false)` for a month number
+    month: Option<bool>,
+    day: bool,
+    weekday: bool,
+    /// `Some(true)` for a 12-hour clock, `Some(false)` for a 24-hour clock
+    hour: Option<bool>,
+    minute: bool,
+    second: bool,
+    /// Whether there are fields that interval formatting does not support
+    unsupported: bool,
+}
+
+impl Signature {
+    fn add(&mut self, symbol: FieldSymbol, len: usize) {
+        match IntervalField::from_symbol(symbol) {
+            Some(IntervalField::Era) => self.era = true,
+            Some(IntervalField::Year) => self.year = true,
+            Some(IntervalField::Month) => self.month = Some(len >= 3),
+            Some(IntervalField::Day) => self.day = true,
+            Some(IntervalField::Weekday) => self.weekday = true,
+            Some(IntervalField::DayPeriod) => (),
+            Some(IntervalField::Hour) => {
+                self.hour = Some(matches!(
+                    symbol,
+                    FieldSymbol::Hour(fields::Hour::H11 | fields::Hour::H12)
+                ))
+            }
+            Some(IntervalField::Minute) => self.minute = true,
+            Some(IntervalField::Second) => self.second = true,
+            None => self.unsupported = true,
+        }
+    }
+
+    fn from_fields(fields: impl Iterator<Item = Field>) -> Self {
+        let mut signature = Self::default();
+        for field in fields {
+            signature.add(field.symbol, field.length.to_len());
+        }
+        signature
+    }
+
+    /// Parses a skeleton string such as `yMMMd`.
+    fn from_skeleton(skeleton: &str) -> Self {
+        let mut signature = Self::default();
+        let mut chars = skeleton.chars().peekable();
+        while let Some(ch) = chars.next() {
+            let mut len = 1;
+            while chars.next_if_eq(&ch).is_some() {
+                len += 1;
+            }
+            match FieldSymbol::try_from(ch) {
+                Ok(symbol) => signature.add(symbol, len),
+                Err(_) => signature.unsupported = true,
+            }
+        }
+        signature
+    }
+
+    /// The smallest field that is displayed.
+    fn smallest_field(self) -> Option<IntervalField> {
+        [
+            (self.second, IntervalField::Second),
+            (self.minute, IntervalField::Minute),
+            (self.hour.is_some(), IntervalField::Hour),
+            (self.day, IntervalField::Day),
+            (self.month.is_some(), IntervalField::Month),
+            (self.year, IntervalField::Year),
+            (self.era, IntervalField::Era),
+        ]
+        .into_iter()
+        .find_map(|(present, field)| present.then_some(field))
+    }
+}
+
+impl DateTimeInputUnchecked {
+    /// Returns the greatest field that differs between `self` and `other`.
+    fn greatest_difference(&self, other: &Self) -> Option<IntervalField> {
+        if self.year != other.year {
+            let era = |input: &Self| input.year.and_then(|y| y.era()).map(|e| e.era);
+            return Some(if era(self) != era(other) {
+                IntervalField::Era
+            } else {
+                IntervalField::Year
+            });
+        }
+        if self.month != other.month {
+            return Some(IntervalField::Month);
+        }
+        if self.day_of_month != other.day_of_month {
+            return Some(IntervalField::Day);
+        }
+        if self.hour != other.hour {
+            let is_pm = |input: &Self| input.hour.map(|h| h.number() >= 12);
+            return Some(if is_pm(self) != is_pm(other) {
+                IntervalField::DayPeriod
+            } else {
+                IntervalField::Hour
+            });
+        }
+        if self.minute != other.minute {
+            return Some(IntervalField::Minute);
+        }
+        if self.second != other.second || self.subsecond != other.subsecond {
+            return Some(IntervalField::Second);
+        }
+        None
+    }
+
+    fn has_same_zone(&self, other: &Self) -> bool {
+        self.zone_id == other.zone_id
+            && self.zone_offset == other.zone_offset
+            && self.zone_variant == other.zone_variant
+    }
+}
+
+pub(crate) type ErasedIntervalPatternsV1 = ErasedMarker<IntervalPatterns<'static>>;
+
+/// [`DateTimeIntervalFormatter`] formats a range between two dates and/or times
+/// using the CLDR interval formats, like "Jan 3 – 7, 2025" or "10:00 – 11:30 AM".
+///
+/// The parts of the interval that are equal for both ends are only displayed once.
+/// Which parts these are is determined by the greatest field that differs between
+/// the two inputs. If no suitable interval pattern exists, the start and the end are
+/// formatted in full and joined with the locale's fallback pattern.
+///
+/// # Examples
+///
+/// ```
+/// use icu::datetime::fieldsets::YMD;
+/// use icu::datetime::input::Date;
+/// use icu::datetime::DateTimeIntervalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter =
+///     DateTimeIntervalFormatter::try_new(locale!("en").into(), YMD::medium())
+///         .unwrap();
+///
+/// let start = Date::try_new_gregorian(2025, 1, 3).unwrap();
+/// let end = Date::try_new_gregorian(2025, 1, 7).unwrap();
+/// assert_writeable_eq!(
+///     formatter.format(&start, &end),
+///     "Jan 3\u{2009}–\u{2009}7, 2025"
+/// );
+///
+/// let end = Date::try_new_gregorian(2025, 2, 7).unwrap();
+/// assert_writeable_eq!(
+///     formatter.format(&start, &end),
+///     "Jan 3\u{2009}–\u{2009}Feb 7, 2025"
+/// );
+/// ```
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the `experimental` Cargo feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Debug, Clone)]
+pub struct DateTimeIntervalFormatter<FSet: DateTimeNamesMarker> {
+    formatter: DateTimeFormatter<FSet>,
+    patterns: DataPayload<ErasedIntervalPatternsV1>,
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateDataMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+    FSet: GetField<CompositeFieldSet>,
+{
+    /// Creates a new [`DateTimeIntervalFormatter`] from compiled data.
+    ///
+    /// The calendar is selected in the same way as in [`DateTimeFormatter::try_new`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: DateTimeFormatterPreferences,
+        field_set_with_options: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        crate::provider::Baked: AllAnyCalendarFormattingDataMarkers<FSet>,
+    {
+        Self::try_new_internal(
+            &crate::provider::Baked,
+            &ExternalLoaderCompiledData,
+            prefs,
+            field_set_with_options.get_field(),
+        )
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DateTimeFormatterPreferences, field_set_with_options: FSet) -> error: DateTimeFormatterLoadError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        prefs: DateTimeFormatterPreferences,
+        field_set_with_options: FSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: ?Sized
+            + AllAnyCalendarFormattingDataMarkers<FSet>
+            + AllAnyCalendarExternalDataMarkers
+            + DataProvider<DatetimePatternsIntervalGregorianV1>
+            + DataProvider<DatetimePatternsIntervalGenericV1>,
+    {
+        Self::try_new_internal(
+            provider,
+            &ExternalLoaderUnstable(provider),
+            prefs,
+            field_set_with_options.get_field(),
+        )
+    }
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateDataMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+{
+    fn try_new_internal<P, L>(
+        provider: &P,
+        loader: &L,
+        prefs: DateTimeFormatterPreferences,
+        field_set_with_options: CompositeFieldSet,
+    ) -> Result<Self, DateTimeFormatterLoadError>
+    where
+        P: ?Sized
+            + AllAnyCalendarFormattingDataMarkers<FSet>
+            + DataProvider<DatetimePatternsIntervalGregorianV1>
+            + DataProvider<DatetimePatternsIntervalGenericV1>,
+        L: DecimalFormatterLoader + FormattableAnyCalendarLoader,
+    {
+        let formatter =
+            DateTimeFormatter::try_new_internal(provider, loader, prefs, field_set_with_options)?;
+        let patterns = if formatter.calendar.any_calendar().kind() == AnyCalendarKind::Gregorian {
+            Self::load_patterns::<DatetimePatternsIntervalGregorianV1>(provider, prefs)
+        } else {
+            Self::load_patterns::<DatetimePatternsIntervalGenericV1>(provider, prefs)
+        }
+        .map_err(DateTimeFormatterLoadError::Data)?;
+        Ok(Self {
+            formatter,
+            patterns,
+        })
+    }
+
+    fn load_patterns<M>(
+        provider: &(impl DataProvider<M> + ?Sized),
+        prefs: DateTimeFormatterPreferences,
+    ) -> Result<DataPayload<ErasedIntervalPatternsV1>, DataError>
+    where
+        M: DataMarker<DataStruct = IntervalPatterns<'static>>,
+    {
+        let locale = M::make_locale(prefs.locale_preferences);
+        provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })
+            .map(|r| r.payload.cast())
+    }
+}
+
+impl<FSet: DateTimeMarkers> DateTimeIntervalFormatter<FSet>
+where
+    FSet::D: DateInputMarkers,
+    FSet::T: TimeMarkers,
+    FSet::Z: ZoneMarkers,
+{
+    /// Formats the interval between two datetimes after first converting them
+    /// to the formatter's calendar.
+    pub fn format<'a, I>(&'a self, start: &I, end: &I) -> FormattedDateTimeInterval<'a>
+    where
+        I: ?Sized + ConvertCalendar,
+        I::Converted<'a>: Sized + AllInputMarkers<FSet>,
+    {
+        let calendar = self.formatter.calendar.any_calendar();
+        let start = DateTimeInputUnchecked::extract_from_neo_input::<
+            FSet::D,
+            FSet::T,
+            FSet::Z,
+            I::Converted<'a>,
+        >(&start.to_calendar(calendar));
+        let end = DateTimeInputUnchecked::extract_from_neo_input::<
+            FSet::D,
+            FSet::T,
+            FSet::Z,
+            I::Converted<'a>,
+        >(&end.to_calendar(calendar));
+        let start = FormattedDateTime {
+            pattern: self.formatter.selection.select(&start),
+            input: start,
+            names: self.formatter.names.as_borrowed(),
+        };
+        let end = FormattedDateTime {
+            pattern: self.formatter.selection.select(&end),
+            input: end,
+            names: self.formatter.names.as_borrowed(),
+        };
+        let patterns = self.patterns.get();
+        let kind = resolve_interval(start.pattern, &start.input, &end.input, patterns);
+        FormattedDateTimeInterval {
+            start,
+            end,
+            kind,
+            fallback: &patterns.fallback,
+        }
+    }
+
+    /// Returns the underlying [`DateTimeFormatter`], which formats single datetimes
+    /// with the same field set.
+    pub fn as_formatter(&self) -> &DateTimeFormatter<FSet> {
+        &self.formatter
+    }
+}
+
+/// How a [`FormattedDateTimeInterval`] is rendered.
+#[derive(Debug)]
+enum IntervalKind {
+    /// The two inputs are equal in all displayed fields: format the start only.
+    Single,
+    /// Format the start and the end in full and join them with the fallback pattern.
+    Fallback,
+    /// Use an interval pattern.
+    Range {
+        /// The pattern items; items before `split` are written with the start input,
+        /// items from `split` on with the end input.
+        items: Vec<PatternItem>,
+        split: usize,
+        /// The items that only belong to the start.
+        start_range: Range<usize>,
+        /// The items that only belong to the end.
+        end_range: Range<usize>,
+    },
+}
+
+/// Chooses how to format the interval between `start` and `end`.
+fn resolve_interval(
+    pattern: DateTimeZonePatternDataBorrowed,
+    start: &DateTimeInputUnchecked,
+    end: &DateTimeInputUnchecked,
+    patterns: &IntervalPatterns,
+) -> IntervalKind {
+    let items = pattern
+        .iter_items_with_component()
+        .collect::<Vec<(PatternComponent, PatternItem)>>();
+    let has_fields = |component: PatternComponent| {
+        items
+            .iter()
+            .any(|(c, item)| *c == component && matches!(item, PatternItem::Field(_)))
+    };
+    if has_fields(PatternComponent::Zone) && !start.has_same_zone(end) {
+        return IntervalKind::Fallback;
+    }
+    let Some(difference) = start.greatest_difference(end) else {
+        return IntervalKind::Single;
+    };
+    let has_date = has_fields(PatternComponent::Date);
+    let has_time = has_fields(PatternComponent::Time);
+    // CLDR does not have interval patterns that span both dates and times.
+    let component = match (difference.is_date(), has_date, has_time) {
+        (true, true, true) => return IntervalKind::Fallback,
+        (true, true, false) => PatternComponent::Date,
+        (_, _, true) => PatternComponent::Time,
+        (_, _, false) => return IntervalKind::Single,
+    };
+    let Some(first) = items.iter().position(|(c, _)| *c == component) else {
+        return IntervalKind::Single;
+    };
+    let last = items
+        .iter()
+        .rposition(|(c, _)| *c == component)
+        .unwrap_or(first);
+    let component_fields = || {
+        items
+            .get(first..=last)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|(_, item)| match item {
+                PatternItem::Field(field) => Some(*field),
+                PatternItem::Literal(_) => None,
+            })
+    };
+    let signature = Signature::from_fields(component_fields());
+    if signature.unsupported {
+        return IntervalKind::Fallback;
+    }
+    match signature.smallest_field() {
+        Some(smallest) if smallest >= difference => (),
+        // The difference is not visible
+        _ => return IntervalKind::Single,
+    }
+    let Some(interval_pattern) = select_interval_pattern(patterns, signature, difference) else {
+        return IntervalKind::Fallback;
+    };
+
+    // Adjust the interval pattern to the symbols and lengths of the single pattern, so
+    // that it follows the same options and uses the names that are already loaded.
+    let mut interval_items = Vec::with_capacity(interval_pattern.items.len());
+    let mut seen = Vec::<IntervalField>::new();
+    let mut split = None;
+    for item in interval_pattern.items.iter() {
+        let item = match item {
+            PatternItem::Field(field) => {
+                let Some(category) = IntervalField::from_symbol(field.symbol) else {
+                    return IntervalKind::Fallback;
+                };
+                let Some(field) = component_fields()
+                    .find(|f| IntervalField::from_symbol(f.symbol) == Some(category))
+                else {
+                    return IntervalKind::Fallback;
+                };
+                if split.is_none() {
+                    if seen.contains(&category) {
+                        split = Some(interval_items.len());
+                    } else {
+                        seen.push(category);
+                    }
+                }
+                PatternItem::Field(field)
+            }
+            literal => literal,
+        };
+        interval_items.push(item);
+    }
+    let Some(split) = split else {
+        debug_assert!(false, "interval pattern without a repeated field");
+        return IntervalKind::Fallback;
+    };
+
+    let (first_half, second_half) = interval_items.split_at(split);
+    let categories = |items: &[PatternItem]| {
+        items
+            .iter()
+            .filter_map(|item| match item {
+                PatternItem::Field(field) => IntervalField::from_symbol(field.symbol),
+                PatternItem::Literal(_) => None,
+            })
+            .collect::<Vec<_>>()
+    };
+    let first_categories = categories(first_half);
+    let second_categories = categories(second_half);
+    // The range of fields in `items` whose categories are also in `other`
+    let repeated_range = |items: &[PatternItem], other: &[IntervalField], offset: usize| {
+        let is_repeated = |item: &PatternItem| match item {
+            PatternItem::Field(field) => IntervalField::from_symbol(field.symbol)
+                .is_some_and(|category| other.contains(&category)),
+            PatternItem::Literal(_) => false,
+        };
+        let start = items.iter().position(is_repeated).unwrap_or(0);
+        let end = items.iter().rposition(is_repeated).map_or(0, |i| i + 1);
+        offset + start..offset + end.max(start)
+    };
+    let start_range = repeated_range(first_half, &second_categories, first);
+    let end_range = repeated_range(second_half, &first_categories, first + split);
+
+    let mut all_items = Vec::with_capacity(items.len() + interval_items.len());
+    all_items.extend(items.iter().take(first).map(|(_, item)| *item));
+    all_items.extend(interval_items);
+    all_items.extend(items.iter().skip(last + 1).map(|(_, item)| *item));
+
+    IntervalKind::Range {
+        items: all_items,
+        split: first + split,
+        start_range,
+        end_range,
+    }
+}
+
+/// Finds the interval pattern for the skeleton that best matches `signature`.
+fn select_interval_pattern<'a>(
+    patterns: &'a IntervalPatterns,
+    signature: Signature,
+    difference: IntervalField,
+) -> Option<runtime::Pattern<'a>> {
+    let cursor = patterns
+        .patterns
+        .iter0()
+        .find(|cursor| Signature::from_skeleton(cursor.key0()) == signature)?;
+    let is_12_hour = signature.hour.unwrap_or_default();
+    // If there is no pattern for the greatest difference, for example if the year
+    // differs but only the month and day are displayed, use the next smaller field.
+    [
+        IntervalField::Era,
+        IntervalField::Year,
+        IntervalField::Month,
+        IntervalField::Day,
+        IntervalField::DayPeriod,
+        IntervalField::Hour,
+        IntervalField::Minute,
+        IntervalField::Second,
+    ]
+    .into_iter()
+    .filter(|field| *field >= difference)
+    .filter_map(|field| field.difference_symbol(is_12_hour))
+    .find_map(|symbol| cursor.get1(&symbol))
+    .map(runtime::Pattern::zero_from)
+}
+
+/// An intermediate type during a date/time interval formatting operation.
+///
+/// Not intended to be stored: convert to a string first.
+#[derive(Debug)]
+pub struct FormattedDateTimeInterval<'a> {
+    start: FormattedDateTime<'a>,
+    end: FormattedDateTime<'a>,
+    kind: IntervalKind,
+    fallback: &'a icu_pattern::DoublePlaceholderPattern,
+}
+
+impl Writeable for FormattedDateTimeInterval<'_> {
+    fn write_to_parts<S: writeable::PartsWrite + ?Sized>(
+        &self,
+        sink: &mut S,
+    ) -> Result<(), fmt::Error> {
+        let (items, split, start_range, end_range) = match &self.kind {
+            IntervalKind::Single => return self.start.write_to_parts(sink),
+            IntervalKind::Fallback => {
+                return self
+                    .fallback
+                    .interpolate((
+                        WithPart {
+                            part: parts::START_RANGE,
+                            writeable: &self.start,
+                        },
+                        WithPart {
+                            part: parts::END_RANGE,
+                            writeable: &self.end,
+                        },
+                    ))
+                    .write_to_parts(sink)
+            }
+            IntervalKind::Range {
+                items,
+                split,
+                start_range,
+                end_range,
+            } => (items, *split, start_range, end_range),
+        };
+        let metadata = self.start.pattern.metadata();
+        let names = &self.start.names;
+        let segments: [(Range<usize>, &DateTimeInputUnchecked, Option<Part>); 6] = [
+            (0..start_range.start, &self.start.input, None),
+            (
+                start_range.clone(),
+                &self.start.input,
+                Some(parts::START_RANGE),
+            ),
+            (start_range.end..split, &self.start.input, None),
+            (split..end_range.start, &self.end.input, None),
+            (end_range.clone(), &self.end.input, Some(parts::END_RANGE)),
+            (end_range.end..items.len(), &self.end.input, None),
+        ];
+        let mut result = Ok(());
+        for (range, input, part) in segments {
+            let items = items.get(range).unwrap_or_default();
+            let r = match part {
+                Some(part) => {
+                    let mut r = Ok(());
+                    sink.with_part(part, |w| {
+                        r = try_write_pattern_items(
+                            metadata,
+                            items.iter().copied(),
+                            input,
+                            names,
+                            names.decimal_formatter,
+                            w,
+                        )?;
+                        Ok(())
+                    })?;
+                    r
+                }
+                None => try_write_pattern_items(
+                    metadata,
+                    items.iter().copied(),
+                    input,
+                    names,
+                    names.decimal_formatter,
+                    sink,
+                )?,
+            };
+            result = result.and(r);
+        }
+        // As in FormattedDateTime, errors should only happen with invalid data.
+        if let Err(e) = result {
+            debug_assert!(
+                false,
+                "unexpected error in FormattedDateTimeInterval: {e:?}"
+            );
+        }
+        Ok(())
+    }
+}
+
+impl_display_with_writeable!(FormattedDateTimeInterval<'_>);
+
+#[cfg(all(test, feature = "compiled_data"))]
+mod tests {
+    use super::*;
+    use crate::fieldsets::{T, YMD};
+    use crate::input::{Date, DateTime, Time};
+    use crate::provider::Baked;
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+    use writeable::assert_writeable_parts_eq;
+
+    fn interval_formatter<FSet>(
+        prefs: DateTimeFormatterPreferences,
+        field_set: FSet,
+    ) -> DateTimeIntervalFormatter<FSet>
+    where
+        FSet: DateTimeMarkers + GetField<CompositeFieldSet>,
+        FSet::D: DateDataMarkers,
+        FSet::T: TimeMarkers,
+        FSet::Z: ZoneMarkers,
+        Baked: AllAnyCalendarFormattingDataMarkers<FSet>,
+    {
+        DateTimeIntervalFormatter::try_new(prefs, field_set).unwrap()
+    }
+
+    #[test]
+    fn test_date_intervals() {
+        let formatter = interval_formatter(locale!("en").into(), YMD::medium());
+        let date = |y, m, d| Date::try_new_gregorian(y, m, d).unwrap();
+
+        for (start, end, expected) in [
+            (
+                date(2025, 1, 3),
+                date(2025, 1, 7),
+                "Jan 3\u{2009}–\u{2009}7, 2025",
+            ),
+            (
+                date(2025, 1, 3),
+                date(2025, 2, 7),
+                "Jan 3\u{2009}–\u{2009}Feb 7, 2025",
+            ),
+            (
+                date(2024, 12, 30),
+                date(2025, 1, 3),
+                "Dec 30, 2024\u{2009}–\u{2009}Jan 3, 2025",
+            ),
+            (date(2025, 1, 3), date(2025, 1, 3), "Jan 3, 2025"),
+        ] {
+            assert_writeable_eq!(formatter.format(&start, &end), expected);
+        }
+
+        // Lengths of the single pattern are used
+        let formatter = interval_formatter(locale!("en").into(), YMD::long());
+        assert_writeable_eq!(
+            formatter.format(&date(2025, 1, 3), &date(2025, 1, 7)),
+            "January 3\u{2009}–\u{2009}7, 2025"
+        );
+    }
+
+    #[test]
+    fn test_other_calendar() {
+        let formatter = interval_formatter(locale!("en-u-ca-buddhist").into(), YMD::medium());
+        let start = Date::try_new_iso(2025, 1, 3).unwrap();
+        let end = Date::try_new_iso(2025, 1, 7).unwrap();
+        assert_writeable_eq!(
+            formatter.format(&start, &end),
+            "Jan 3\u{2009}–\u{2009}7, 2568 BE"
+        );
+    }
+
+    #[test]
+    fn test_time_intervals() {
+        let formatter = interval_formatter(locale!("en").into(), T::hm());
+        let time = |h, m| Time::try_new(h, m, 0, 0).unwrap();
+        for (start, end, expected) in [
+            (
+                time(10, 0),
+                time(11, 30),
+                "10:00\u{2009}–\u{2009}11:30\u{202f}AM",
+            ),
+            (
+                time(10, 0),
+                time(10, 30),
+                "10:00\u{2009}–\u{2009}10:30\u{202f}AM",
+            ),
+            (
+                time(10, 0),
+                time(13, 30),
+                "10:00\u{202f}AM\u{2009}–\u{2009}1:30\u{202f}PM",
+            ),
+            // Seconds are not displayed
+            (
+                time(10, 0),
+                Time::try_new(10, 0, 5, 0).unwrap(),
+                "10:00\u{202f}AM",
+            ),
+        ] {
+            assert_writeable_eq!(formatter.format(&start, &end), expected);
+        }
+
+        let formatter = interval_formatter(locale!("en-u-hc-h23").into(), T::hm());
+        assert_writeable_eq!(
+            formatter.format(&time(9, 0), &time(13, 30)),
+            "09:00\u{2009}–\u{2009}13:30"
+        );
+    }
+
+    #[test]
+    fn test_date_time_intervals() {
+        let formatter = interval_formatter(locale!("en").into(), YMD::medium().with_time_hm());
+        let datetime = |d, h| DateTime {
+            date: Date::try_new_gregorian(2025, 1, d).unwrap(),
+            time: Time::try_new(h, 0, 0, 0).unwrap(),
+        };
+        assert_writeable_eq!(
+            formatter.format(&datetime(3, 10), &datetime(3, 11)),
+            "Jan 3, 2025, 10:00\u{2009}–\u{2009}11:00\u{202f}AM"
+        );
+        assert_writeable_eq!(
+            formatter.format(&datetime(3, 10), &datetime(7, 11)),
+            "Jan 3, 2025, 10:00\u{202f}AM\u{2009}–\u{2009}Jan 7, 2025, 11:00\u{202f}AM"
+        );
+    }
+
+    #[test]
+    fn test_parts() {
+        let formatter = interval_formatter(locale!("en").into(), YMD::medium());
+        let start = Date::try_new_gregorian(2025, 1, 3).unwrap();
+        let end = Date::try_new_gregorian(2025, 1, 7).unwrap();
+        assert_writeable_parts_eq!(
+            formatter.format(&start, &end),
+            "Jan 3\u{2009}–\u{2009}7, 2025",
+            [
+                (0, 3, parts::MONTH),
+                (4, 5, icu_decimal::parts::INTEGER),
+                (4, 5, parts::DAY),
+                (4, 5, parts::START_RANGE),
+                (14, 15, icu_decimal::parts::INTEGER),
+                (14, 15, parts::DAY),
+                (14, 15, parts::END_RANGE),
+                (17, 21, icu_decimal::parts::INTEGER),
+                (17, 21, parts::YEAR),
+            ]
+        );
+
+        let end = Date::try_new_gregorian(2026, 1, 7).unwrap();
+        assert_writeable_parts_eq!(
+            formatter.format(&start, &end),
+            "Jan 3, 2025\u{2009}–\u{2009}Jan 7, 2026",
+            [
+                (0, 11, parts::START_RANGE),
+                (0, 3, parts::MONTH),
+                (4, 5, icu_decimal::parts::INTEGER),
+                (4, 5, parts::DAY),
+                (7, 11, icu_decimal::parts::INTEGER),
+                (7, 11, parts::YEAR),
+                (20, 31, parts::END_RANGE),
+                (20, 23, parts::MONTH),
+                (24, 25, icu_decimal::parts::INTEGER),
+                (24, 25, parts::DAY),
+                (27, 31, icu_decimal::parts::INTEGER),
+                (27, 31, parts::YEAR),
+            ]
+        );
+    }
+}
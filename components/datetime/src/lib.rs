@@ -129,6 +129,8 @@ mod error;
 mod external_loaders;
 pub mod fieldsets;
 mod format;
+#[cfg(feature = "experimental")]
+mod interval;
 mod neo;
 pub mod options;
 pub mod parts;
@@ -147,6 +149,9 @@ pub use neo::FixedCalendarDateTimeFormatter;
 pub use neo::FormattedDateTime;
 pub use neo::NoCalendarFormatter;
 
+#[cfg(feature = "experimental")]
+pub use interval::{DateTimeIntervalFormatter, FormattedDateTimeInterval};
+
 /// Locale preferences used by this crate
 pub mod preferences {
     /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
@@ -168,6 +173,8 @@ pub mod preferences {
 /// This module contains re-exports from the [`icu_calendar`] and [`icu_time`] crates.
 pub mod input {
     pub use icu_calendar::Date;
+    pub use icu_calendar::MonthDay;
+    pub use icu_calendar::YearMonth;
     pub use icu_time::zone::UtcOffset;
     pub use icu_time::DateTime;
     pub use icu_time::Time;
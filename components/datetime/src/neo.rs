@@ -22,7 +22,7 @@ use crate::size_test_macro::size_test;
 use crate::MismatchedCalendarError;
 use core::fmt;
 use core::marker::PhantomData;
-use icu_calendar::{preferences::CalendarPreferences, AnyCalendar, IntoAnyCalendar};
+use icu_calendar::{preferences::CalendarPreferences, AnyCalendar, Calendar, IntoAnyCalendar};
 use icu_decimal::DecimalFormatterPreferences;
 use icu_locale_core::preferences::{define_preferences, prefs_convert};
 use icu_provider::prelude::*;
@@ -59,21 +59,21 @@ define_preferences!(
         ///
         /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
         ///
-        /// To get the resolved numbering system, you can inspect the data provider.
-        /// See the [`icu_decimal::provider`] module for an example.
+        /// To get the resolved numbering system, use [`DateTimeFormatter::resolved_preferences()`].
         numbering_system: NumberingSystem,
         /// The user's preferred hour cycle.
         ///
         /// Corresponds to the `-u-hc` in Unicode Locale Identifier.
         ///
-        /// To get the resolved hour cycle, you can inspect the formatting pattern.
+        /// To get the resolved hour cycle, use [`DateTimeFormatter::resolved_preferences()`],
+        /// or inspect the formatting pattern.
         /// See [`DateTimePattern`](crate::pattern::DateTimePattern) for an example.
         hour_cycle: HourCycle,
         /// The user's preferred calendar system
         ///
         /// Corresponds to the `-u-ca` in Unicode Locale Identifier.
         ///
-        /// To get the resolved calendar system, use [`DateTimeFormatter::calendar_kind()`].
+        /// To get the resolved calendar system, use [`DateTimeFormatter::resolved_preferences()`].
         calendar_algorithm: CalendarAlgorithm
     }
 );
@@ -145,7 +145,7 @@ macro_rules! gen_buffer_constructors_with_external_loader {
     };
 }
 
-size_test!(FixedCalendarDateTimeFormatter<icu_calendar::Gregorian, crate::fieldsets::YMD>, typed_neo_year_month_day_formatter_size, 328);
+size_test!(FixedCalendarDateTimeFormatter<icu_calendar::Gregorian, crate::fieldsets::YMD>, typed_neo_year_month_day_formatter_size, 384);
 
 /// [`FixedCalendarDateTimeFormatter`] is a formatter capable of formatting dates and/or times from
 /// a calendar selected at compile time.
@@ -407,7 +407,7 @@ where
 size_test!(
     DateTimeFormatter<crate::fieldsets::YMD>,
     neo_year_month_day_formatter_size,
-    384
+    440
 );
 
 /// [`DateTimeFormatter`] is a formatter capable of formatting dates and/or times from
@@ -509,7 +509,7 @@ where
     FSet::T: TimeMarkers,
     FSet::Z: ZoneMarkers,
 {
-    fn try_new_internal<P, L>(
+    pub(crate) fn try_new_internal<P, L>(
         provider: &P,
         loader: &L,
         prefs: DateTimeFormatterPreferences,
@@ -1029,6 +1029,65 @@ impl<FSet: DateTimeMarkers> DateTimeFormatter<FSet> {
         icu_calendar::Ref(self.calendar.any_calendar())
     }
 
+    /// Returns the preferences that this formatter resolved to.
+    ///
+    /// - The locale is the locale of the date or time pattern data that was loaded, after
+    ///   locale fallback, as reported by the data provider in
+    ///   [`DataResponseMetadata::locale`], or the requested locale if the provider does not
+    ///   report it. Since data providers deduplicate data that is inherited from a parent
+    ///   locale, this is the least specific locale with the same data, which might be `und`.
+    ///   Formatters for time zones only load no pattern data and report the requested
+    ///   locale.
+    /// - The calendar algorithm is the one of [`Self::calendar`].
+    /// - The hour cycle is the one used for formatting hours, or `None` if the formatter
+    ///   does not format times.
+    /// - The numbering system is the one used for formatting numbers, or `None` if the
+    ///   formatter does not format any numbers.
+    ///
+    /// [`DataResponseMetadata::locale`]: icu_provider::DataResponseMetadata::locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::datetime::fieldsets::YMDT;
+    /// use icu::datetime::{DateTimeFormatter, DateTimeFormatterPreferences};
+    /// use icu::locale::preferences::extensions::unicode::keywords::{
+    ///     CalendarAlgorithm, HourCycle,
+    /// };
+    /// use icu::locale::subtags::subtag;
+    /// use icu::locale::{locale, Locale};
+    ///
+    /// let mut prefs: DateTimeFormatterPreferences =
+    ///     locale!("th-TH-u-nu-thai").into();
+    /// prefs.hour_cycle = Some(HourCycle::H23);
+    ///
+    /// let formatter = DateTimeFormatter::try_new(prefs, YMDT::medium()).unwrap();
+    ///
+    /// let resolved = formatter.resolved_preferences();
+    /// assert_eq!(Locale::from(resolved.locale_preferences), locale!("th"));
+    /// assert_eq!(
+    ///     resolved.calendar_algorithm,
+    ///     Some(CalendarAlgorithm::Buddhist)
+    /// );
+    /// assert_eq!(resolved.hour_cycle, Some(HourCycle::H23));
+    /// assert_eq!(resolved.numbering_system.as_deref(), Some(&subtag!("thai")));
+    /// ```
+    pub fn resolved_preferences(&self) -> DateTimeFormatterPreferences {
+        DateTimeFormatterPreferences {
+            locale_preferences: self.selection.resolved_locale().into(),
+            numbering_system: self
+                .names
+                .as_borrowed()
+                .decimal_formatter
+                .and_then(|formatter| formatter.resolved_preferences().numbering_system),
+            hour_cycle: self
+                .selection
+                .resolved_hour_cycle()
+                .map(|hour| hour.to_hour_cycle()),
+            calendar_algorithm: self.calendar.any_calendar().calendar_algorithm(),
+        }
+    }
+
     /// Gets a [`FieldSetBuilder`] corresponding to the fields and options configured in this
     /// formatter. The builder can be used to recreate the formatter.
     ///
@@ -1138,9 +1197,9 @@ pub type NoCalendarFormatter<FSet> = FixedCalendarDateTimeFormatter<(), FSet>;
 /// Not intended to be stored: convert to a string first.
 #[derive(Debug)]
 pub struct FormattedDateTime<'a> {
-    pattern: DateTimeZonePatternDataBorrowed<'a>,
-    input: DateTimeInputUnchecked,
-    names: RawDateTimeNamesBorrowed<'a>,
+    pub(crate) pattern: DateTimeZonePatternDataBorrowed<'a>,
+    pub(crate) input: DateTimeInputUnchecked,
+    pub(crate) names: RawDateTimeNamesBorrowed<'a>,
 }
 
 impl Writeable for FormattedDateTime<'_> {
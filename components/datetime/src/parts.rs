@@ -127,3 +127,19 @@ pub const TIME_ZONE_NAME: Part = Part {
     category: "datetime",
     value: "timeZoneName",
 };
+
+/// A [`Part`] used by [`FormattedDateTimeInterval`](super::FormattedDateTimeInterval)
+/// for the fields that only belong to the start of the interval.
+#[cfg(feature = "experimental")]
+pub const START_RANGE: Part = Part {
+    category: "datetime",
+    value: "startRange",
+};
+
+/// A [`Part`] used by [`FormattedDateTimeInterval`](super::FormattedDateTimeInterval)
+/// for the fields that only belong to the end of the interval.
+#[cfg(feature = "experimental")]
+pub const END_RANGE: Part = Part {
+    category: "datetime",
+    value: "endRange",
+};
@@ -12,6 +12,8 @@
 
 mod formatter;
 mod names;
+#[cfg(feature = "experimental")]
+mod parser;
 #[expect(clippy::module_inception)] // the file pattern.rs should contain DateTimePattern
 mod pattern;
 
@@ -32,6 +34,10 @@ pub(crate) use names::RawDateTimeNamesBorrowed;
 pub(crate) use names::TimeZoneDataPayloadsBorrowed;
 pub use names::WeekdayNameLength;
 pub use names::YearNameLength;
+#[cfg(feature = "experimental")]
+pub use parser::{
+    DateTimeParseError, DateTimeParseErrorKind, DateTimePatternParser, ParseMode, ParseOptions,
+};
 pub use pattern::DateTimePattern;
 use tinystr::TinyStr16;
 
@@ -7,6 +7,8 @@ use super::{
     GetNameForEraError, GetNameForMonthError, GetNameForWeekdayError, MonthPlaceholderValue,
     PatternLoadError, UnsupportedCalendarError,
 };
+#[cfg(feature = "experimental")]
+use super::{DateTimePatternParser, ParseOptions};
 use crate::error::ErrorField;
 use crate::fieldsets::enums::{CompositeDateTimeFieldSet, CompositeFieldSet};
 use crate::provider::fields::{self, FieldLength, FieldSymbol};
@@ -365,7 +367,7 @@ where
 size_test!(
     FixedCalendarDateTimeNames<icu_calendar::Gregorian>,
     typed_date_time_names_size,
-    328
+    360
 );
 
 /// A low-level type that formats datetime patterns with localized names.
@@ -2754,6 +2756,28 @@ impl<FSet: DateTimeNamesMarker> DateTimeNames<FSet> {
             calendar: self.calendar,
         }
     }
+
+    /// Associates this [`DateTimeNames`] with a pattern for parsing.
+    ///
+    /// The names needed by the pattern must already be loaded; parsing a field whose
+    /// names are missing fails with [`DateTimeParseErrorKind::NamesNotLoaded`].
+    ///
+    /// For an example, see [`DateTimePatternParser`].
+    ///
+    /// [`DateTimeParseErrorKind::NamesNotLoaded`]: crate::pattern::DateTimeParseErrorKind::NamesNotLoaded
+    #[cfg(feature = "experimental")]
+    pub fn parser_with_pattern<'l>(
+        &'l self,
+        pattern: &'l DateTimePattern,
+        options: ParseOptions,
+    ) -> DateTimePatternParser<'l> {
+        DateTimePatternParser::new(
+            pattern.as_borrowed(),
+            self.inner.inner.as_borrowed(),
+            self.calendar.any_calendar(),
+            options,
+        )
+    }
 }
 
 impl<FSet: DateTimeNamesMarker> RawDateTimeNames<FSet> {
@@ -3836,6 +3860,42 @@ pub(crate) struct TimeZoneDataPayloadsBorrowed<'a> {
 }
 
 impl<'data> RawDateTimeNamesBorrowed<'data> {
+    /// Returns the year names of the given length, if loaded.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn year_names_for_length(
+        &self,
+        length: YearNameLength,
+    ) -> Option<&'data YearNames<'data>> {
+        self.year_names.get_with_variables(length)
+    }
+
+    /// Returns the month names of the given length, if loaded.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn month_names_for_length(
+        &self,
+        length: MonthNameLength,
+    ) -> Option<&'data MonthNames<'data>> {
+        self.month_names.get_with_variables(length)
+    }
+
+    /// Returns the weekday names of the given length, if loaded.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn weekday_names_for_length(
+        &self,
+        length: WeekdayNameLength,
+    ) -> Option<&'data LinearNames<'data>> {
+        self.weekday_names.get_with_variables(length)
+    }
+
+    /// Returns the day period names of the given length, if loaded.
+    #[cfg(feature = "experimental")]
+    pub(crate) fn day_period_names_for_length(
+        &self,
+        length: DayPeriodNameLength,
+    ) -> Option<&'data LinearNames<'data>> {
+        self.dayperiod_names.get_with_variables(length)
+    }
+
     pub(crate) fn get_payloads(&self) -> TimeZoneDataPayloadsBorrowed<'data> {
         TimeZoneDataPayloadsBorrowed {
             essentials: self.zone_essentials.get_option(),
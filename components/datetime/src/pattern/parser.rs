@@ -0,0 +1,1624 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::names::RawDateTimeNamesBorrowed;
+use super::pattern::DateTimePatternBorrowed;
+use super::{DayPeriodNameLength, MonthNameLength, WeekdayNameLength, YearNameLength};
+use crate::error::ErrorField;
+use crate::provider::fields::{self, Field, FieldLength, FieldSymbol};
+use crate::provider::neo::{LinearNames, MonthNames, YearNames};
+use crate::provider::pattern::PatternItem;
+use crate::provider::time_zones::{ExemplarCities, Locations};
+use crate::unchecked::MissingInputFieldKind;
+use alloc::string::String;
+use alloc::vec::Vec;
+use fixed_decimal::Decimal;
+use icu_calendar::types::{MonthCode, Weekday};
+use icu_calendar::{AnyCalendar, AnyCalendarKind, Date, DateError, RangeError, Ref};
+use icu_decimal::DecimalFormatter;
+use icu_pattern::SinglePlaceholderPattern;
+use icu_time::zone::{models, TimeZoneInfo, UtcOffset};
+use icu_time::{DateTime, Time, TimeZone, ZonedDateTime};
+use tinystr::TinyAsciiStr;
+use writeable::Writeable;
+
+/// How strictly the input has to match the pattern.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the `experimental` Cargo feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum ParseMode {
+    /// The input must be exactly what the pattern would format to.
+    ///
+    /// Literals must match exactly, numeric fields must have at least as many digits as
+    /// the field length, names must match case-sensitively, and a weekday in the input
+    /// must agree with the date.
+    #[default]
+    Strict,
+    /// The input may deviate from the pattern in ways common for typed input.
+    ///
+    /// Whitespace literals match any amount of any whitespace, names and literals
+    /// match case-insensitively, numeric fields may have any number of digits, ASCII
+    /// digits are accepted in addition to the locale's digits, and a mismatching
+    /// weekday is ignored.
+    Lenient,
+}
+
+/// Options for [`DateTimePatternParser`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the `experimental` Cargo feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// How strictly the input has to match the pattern.
+    ///
+    /// Default is [`ParseMode::Strict`].
+    pub mode: Option<ParseMode>,
+    /// The first year of the 100-year window into which two-digit years (`yy`) are resolved,
+    /// in the calendar's year numbering.
+    ///
+    /// Default is the year containing ISO 1950-01-01 in the parser's calendar.
+    pub two_digit_year_start: Option<i32>,
+}
+
+impl ParseOptions {
+    /// Creates options with the given [`ParseMode`] and default values otherwise.
+    pub const fn with_mode(mode: ParseMode) -> Self {
+        Self {
+            mode: Some(mode),
+            two_digit_year_start: None,
+        }
+    }
+}
+
+/// The kind of a [`DateTimeParseError`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the `experimental` Cargo feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Debug, Copy, Clone, PartialEq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum DateTimeParseErrorKind {
+    /// The input does not contain a literal of the pattern.
+    #[displaydoc("Expected {0:?}")]
+    ExpectedLiteral(char),
+    /// The input does not contain a number where the pattern has a numeric field,
+    /// or the number has the wrong number of digits.
+    #[displaydoc("Expected a number for {0:?}")]
+    ExpectedNumber(ErrorField),
+    /// The input does not contain any of the names for a textual field.
+    #[displaydoc("Expected a name for {0:?}")]
+    UnknownName(ErrorField),
+    /// The names for a textual field have not been loaded.
+    ///
+    /// Load the names for the pattern, for example with
+    /// [`FixedCalendarDateTimeNames::load_for_pattern`](super::FixedCalendarDateTimeNames::load_for_pattern).
+    #[displaydoc("Names for {0:?} not loaded")]
+    NamesNotLoaded(ErrorField),
+    /// The pattern contains a field that cannot be parsed.
+    #[displaydoc("Parsing {0:?} is not supported")]
+    UnsupportedField(ErrorField),
+    /// The input continues after the end of the pattern.
+    #[displaydoc("Unexpected trailing input")]
+    TrailingInput,
+    /// The pattern lacks a field required for the requested output type.
+    #[displaydoc("Pattern has no value for {0:?}")]
+    MissingInputField(MissingInputFieldKind),
+    /// Two fields in the input disagree, for example the weekday does not
+    /// match the date.
+    #[displaydoc("The value for {0:?} is inconsistent with the other fields")]
+    InconsistentField(ErrorField),
+    /// The fields do not form a valid date in the calendar.
+    #[displaydoc("{0}")]
+    InvalidDate(DateError),
+    /// The fields do not form a valid time of day.
+    #[displaydoc("{0}")]
+    InvalidTime(RangeError),
+    /// The time zone fields do not form a valid offset.
+    #[displaydoc("Invalid offset")]
+    InvalidOffset,
+}
+
+/// Error returned by the parse functions of [`DateTimePatternParser`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the `experimental` Cargo feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+#[derive(Debug, Copy, Clone, PartialEq, displaydoc::Display)]
+#[displaydoc("{kind} at index {index}")]
+#[non_exhaustive]
+pub struct DateTimeParseError {
+    /// What went wrong.
+    pub kind: DateTimeParseErrorKind,
+    /// The UTF-8 index into the input at which the problem was found.
+    ///
+    /// For errors about a field, this is the start of the field in the input. For
+    /// [`DateTimeParseErrorKind::MissingInputField`], this is the length of the input.
+    pub index: usize,
+}
+
+impl core::error::Error for DateTimeParseError {}
+
+impl DateTimeParseError {
+    fn new(kind: DateTimeParseErrorKind, index: usize) -> Self {
+        Self { kind, index }
+    }
+}
+
+/// A parser for a specific [`DateTimePattern`].
+///
+/// The parser is the inverse of [`DateTimePatternFormatter`]: it uses the same pattern
+/// and the same names to read a date, time, or zoned datetime from a string.
+///
+/// Create one of these via [`DateTimeNames::parser_with_pattern`].
+///
+/// ❗ This type forgoes most internationalization functionality of the datetime crate.
+/// It assumes that the pattern is already localized for the customer's locale.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. It can be enabled with the `experimental` Cargo feature
+/// of the icu meta-crate. Use with caution.
+/// </div>
+///
+/// # Examples
+///
+/// Round-trip a string formatted by a [`DateTimeFormatter`]:
+///
+/// ```
+/// use icu::calendar::Date;
+/// use icu::datetime::fieldsets::YMD;
+/// use icu::datetime::pattern::{DateTimeNames, ParseMode, ParseOptions};
+/// use icu::datetime::DateTimeFormatter;
+/// use icu::locale::locale;
+/// use writeable::Writeable;
+///
+/// let prefs = locale!("en").into();
+/// let formatter = DateTimeFormatter::try_new(prefs, YMD::long()).unwrap();
+///
+/// let date = Date::try_new_iso(2025, 1, 3).unwrap();
+/// let formatted = formatter.format(&date);
+/// let pattern = formatted.pattern();
+/// let string = formatted.write_to_string().into_owned();
+/// assert_eq!(string, "January 3, 2025");
+///
+/// let names = DateTimeNames::from_formatter(prefs, formatter);
+/// let parser = names.parser_with_pattern(&pattern, Default::default());
+///
+/// assert_eq!(
+///     parser.parse_date(&string).unwrap().to_iso(),
+///     date.to_iso()
+/// );
+///
+/// // Strict mode rejects deviations from the pattern
+/// let err = parser.parse_date("january  3, 2025").unwrap_err();
+/// assert_eq!(err.index, 0);
+///
+/// // which lenient mode accepts
+/// let parser =
+///     names.parser_with_pattern(&pattern, ParseOptions::with_mode(ParseMode::Lenient));
+/// assert_eq!(
+///     parser.parse_date("january  3, 2025").unwrap().to_iso(),
+///     date.to_iso()
+/// );
+/// ```
+///
+/// [`DateTimePattern`]: super::DateTimePattern
+/// [`DateTimePatternFormatter`]: super::DateTimePatternFormatter
+/// [`DateTimeNames::parser_with_pattern`]: super::DateTimeNames::parser_with_pattern
+/// [`DateTimeFormatter`]: crate::DateTimeFormatter
+#[derive(Debug, Clone)]
+pub struct DateTimePatternParser<'a> {
+    pattern: DateTimePatternBorrowed<'a>,
+    names: RawDateTimeNamesBorrowed<'a>,
+    calendar: &'a AnyCalendar,
+    mode: ParseMode,
+    two_digit_year_start: Option<i32>,
+    digits: [char; 10],
+    decimal_separator: String,
+}
+
+/// The values read from the input, with the start index of the field they came from.
+#[derive(Debug, Default)]
+struct ParsedFields {
+    era: Option<(EraCode, usize)>,
+    year: Option<(i32, usize)>,
+    two_digit_year: bool,
+    cyclic_year: Option<(u8, usize)>,
+    related_iso: Option<(i32, usize)>,
+    month: Option<(ParsedMonth, usize)>,
+    day: Option<(u8, usize)>,
+    weekday: Option<(Weekday, Field, usize)>,
+    day_period: Option<(DayPeriodValue, usize)>,
+    hour: Option<(u8, fields::Hour, usize)>,
+    minute: Option<(u8, usize)>,
+    second: Option<(u8, usize)>,
+    nanosecond: Option<(u32, usize)>,
+    millis_in_day: Option<(u32, usize)>,
+    zone_id: Option<TimeZone>,
+    offset: Option<UtcOffset>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum EraCode {
+    Index(u8),
+    Code(TinyAsciiStr<16>),
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ParsedMonth {
+    Ordinal(u8),
+    FormattingCode(MonthCode),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DayPeriodValue {
+    Am,
+    Pm,
+    Noon,
+    Midnight,
+}
+
+impl<'a> DateTimePatternParser<'a> {
+    pub(crate) fn new(
+        pattern: DateTimePatternBorrowed<'a>,
+        names: RawDateTimeNamesBorrowed<'a>,
+        calendar: &'a AnyCalendar,
+        options: ParseOptions,
+    ) -> Self {
+        let (digits, decimal_separator) = digits_and_separator(names.decimal_formatter);
+        Self {
+            pattern,
+            names,
+            calendar,
+            mode: options.mode.unwrap_or_default(),
+            two_digit_year_start: options.two_digit_year_start,
+            digits,
+            decimal_separator,
+        }
+    }
+
+    /// Parses a date from the input.
+    ///
+    /// Time and time zone fields in the pattern are read but ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::datetime::fieldsets::YMD;
+    /// use icu::datetime::pattern::{DateTimeNames, DateTimePattern};
+    /// use icu::datetime::DateTimeFormatter;
+    /// use icu::locale::locale;
+    ///
+    /// // Re-use the month names loaded by a formatter
+    /// let prefs = locale!("fr").into();
+    /// let formatter = DateTimeFormatter::try_new(prefs, YMD::long()).unwrap();
+    /// let names = DateTimeNames::from_formatter(prefs, formatter);
+    ///
+    /// let pattern: DateTimePattern = "d MMMM y".parse().unwrap();
+    /// let parser = names.parser_with_pattern(&pattern, Default::default());
+    ///
+    /// assert_eq!(
+    ///     parser.parse_date("14 juillet 1789").unwrap().to_iso(),
+    ///     Date::try_new_iso(1789, 7, 14).unwrap()
+    /// );
+    /// ```
+    pub fn parse_date(&self, input: &str) -> Result<Date<AnyCalendar>, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        self.resolve_date(&fields, input.len())
+    }
+
+    /// Parses a time of day from the input.
+    ///
+    /// Date and time zone fields in the pattern are read but ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind};
+    /// use icu::datetime::fieldsets::enums::TimeFieldSet;
+    /// use icu::datetime::input::Time;
+    /// use icu::datetime::pattern::{
+    ///     DateTimeNames, DateTimePattern, DayPeriodNameLength, ParseMode, ParseOptions,
+    /// };
+    /// use icu::locale::locale;
+    ///
+    /// let mut names: DateTimeNames<TimeFieldSet> =
+    ///     DateTimeNames::try_new_with_calendar_without_number_formatting(
+    ///         locale!("en").into(),
+    ///         AnyCalendar::new(AnyCalendarKind::Gregorian),
+    ///     )
+    ///     .unwrap();
+    /// names
+    ///     .as_mut()
+    ///     .include_day_period_names(DayPeriodNameLength::Abbreviated)
+    ///     .unwrap();
+    ///
+    /// let pattern: DateTimePattern = "h:mm\u{202f}a".parse().unwrap();
+    /// let parser = names.parser_with_pattern(
+    ///     &pattern,
+    ///     ParseOptions::with_mode(ParseMode::Lenient),
+    /// );
+    ///
+    /// assert_eq!(
+    ///     parser.parse_time("3:45 pm").unwrap(),
+    ///     Time::try_new(15, 45, 0, 0).unwrap()
+    /// );
+    ///
+    /// // The position of the problem is reported
+    /// let err = parser.parse_time("3:x5 pm").unwrap_err();
+    /// assert_eq!(err.index, 2);
+    /// ```
+    pub fn parse_time(&self, input: &str) -> Result<Time, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        self.resolve_time(&fields, input.len())
+    }
+
+    /// Parses a date and time of day from the input.
+    ///
+    /// Time zone fields in the pattern are read but ignored.
+    pub fn parse_date_time(
+        &self,
+        input: &str,
+    ) -> Result<DateTime<AnyCalendar>, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        Ok(DateTime {
+            date: self.resolve_date(&fields, input.len())?,
+            time: self.resolve_time(&fields, input.len())?,
+        })
+    }
+
+    /// Parses a date, time of day, and time zone from the input.
+    ///
+    /// Time zones can be parsed from localized offsets (`O`), ISO-8601 offsets (`X`, `x`),
+    /// location formats (`VVVV`), exemplar cities (`VVV`), and time zone identifiers (`V`).
+    /// Non-location names (`z`, `v`) are only recognized when they fall back to a
+    /// localized offset, since a name may refer to many time zones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::{AnyCalendar, AnyCalendarKind, Date};
+    /// use icu::datetime::fieldsets::enums::CompositeFieldSet;
+    /// use icu::datetime::input::{Time, UtcOffset};
+    /// use icu::datetime::pattern::{DateTimeNames, DateTimePattern};
+    /// use icu::locale::locale;
+    ///
+    /// let mut names: DateTimeNames<CompositeFieldSet> =
+    ///     DateTimeNames::try_new_with_calendar_without_number_formatting(
+    ///         locale!("en").into(),
+    ///         AnyCalendar::new(AnyCalendarKind::Gregorian),
+    ///     )
+    ///     .unwrap();
+    /// names.as_mut().include_time_zone_essentials().unwrap();
+    ///
+    /// let pattern: DateTimePattern = "y-MM-dd HH:mm O".parse().unwrap();
+    /// let parser = names.parser_with_pattern(&pattern, Default::default());
+    ///
+    /// let zdt = parser.parse_zoned_date_time("2025-01-03 10:30 GMT-5").unwrap();
+    /// assert_eq!(zdt.date.to_iso(), Date::try_new_iso(2025, 1, 3).unwrap());
+    /// assert_eq!(zdt.time, Time::try_new(10, 30, 0, 0).unwrap());
+    /// assert_eq!(zdt.zone.offset(), Some(UtcOffset::try_from_str("-05").unwrap()));
+    /// ```
+    pub fn parse_zoned_date_time(
+        &self,
+        input: &str,
+    ) -> Result<ZonedDateTime<AnyCalendar, TimeZoneInfo<models::Base>>, DateTimeParseError> {
+        let fields = self.parse_fields(input)?;
+        let zone = match (fields.zone_id, fields.offset) {
+            (None, None) => {
+                return Err(DateTimeParseError::new(
+                    DateTimeParseErrorKind::MissingInputField(MissingInputFieldKind::TimeZoneId),
+                    input.len(),
+                ))
+            }
+            (id, offset) => id.unwrap_or(TimeZone::UNKNOWN).with_offset(offset),
+        };
+        Ok(ZonedDateTime {
+            date: self.resolve_date(&fields, input.len())?,
+            time: self.resolve_time(&fields, input.len())?,
+            zone,
+        })
+    }
+
+    fn is_lenient(&self) -> bool {
+        self.mode == ParseMode::Lenient
+    }
+
+    fn parse_fields(&self, input: &str) -> Result<ParsedFields, DateTimeParseError> {
+        let items: Vec<PatternItem> = self.pattern.0.items.iter().collect();
+        let mut fields = ParsedFields::default();
+        let mut pos = 0;
+        for (i, item) in items.iter().enumerate() {
+            match *item {
+                PatternItem::Literal(ch) => {
+                    if self.is_lenient() {
+                        pos = skip_whitespace(input, pos);
+                        if ch.is_whitespace() {
+                            continue;
+                        }
+                    }
+                    match input[pos..].chars().next() {
+                        Some(c) if c == ch || (self.is_lenient() && chars_eq_lenient(c, ch)) => {
+                            pos += c.len_utf8();
+                        }
+                        _ => {
+                            return Err(DateTimeParseError::new(
+                                DateTimeParseErrorKind::ExpectedLiteral(ch),
+                                pos,
+                            ))
+                        }
+                    }
+                }
+                PatternItem::Field(field) => {
+                    if self.is_lenient() {
+                        pos = skip_whitespace(input, pos);
+                    }
+                    // A numeric field directly followed by another numeric field, as in `HHmm`,
+                    // consumes exactly as many digits as its length.
+                    let abutting = matches!(
+                        items.get(i + 1),
+                        Some(PatternItem::Field(next)) if is_numeric(*next)
+                    );
+                    pos = self.parse_field(input, pos, field, abutting, &mut fields)?;
+                }
+            }
+        }
+        if self.is_lenient() {
+            pos = skip_whitespace(input, pos);
+        }
+        if pos < input.len() {
+            return Err(DateTimeParseError::new(
+                DateTimeParseErrorKind::TrailingInput,
+                pos,
+            ));
+        }
+        Ok(fields)
+    }
+
+    /// Parses a single field starting at `pos`, returning the position after it.
+    fn parse_field(
+        &self,
+        input: &str,
+        pos: usize,
+        field: Field,
+        abutting: bool,
+        fields: &mut ParsedFields,
+    ) -> Result<usize, DateTimeParseError> {
+        use fields::Second;
+        let error_field = ErrorField(field);
+        let err = |kind| DateTimeParseError::new(kind, pos);
+        let number = |max_digits: usize| {
+            self.read_number(input, pos, field, abutting, max_digits)
+                .ok_or(err(DateTimeParseErrorKind::ExpectedNumber(error_field)))
+        };
+        let narrow = |value: u32| {
+            u8::try_from(value)
+                .map_err(|_| err(DateTimeParseErrorKind::ExpectedNumber(error_field)))
+        };
+
+        Ok(match (field.symbol, field.length) {
+            (FieldSymbol::Era, l) => {
+                let length = YearNameLength::from_field_length(l)
+                    .ok_or(err(DateTimeParseErrorKind::UnsupportedField(error_field)))?;
+                let names = self
+                    .names
+                    .year_names_for_length(length)
+                    .ok_or(err(DateTimeParseErrorKind::NamesNotLoaded(error_field)))?;
+                let (era, len) = match names {
+                    YearNames::FixedEras(names) => self
+                        .match_longest(&input[pos..], names.iter().enumerate())
+                        .map(|(i, len)| (EraCode::Index(i as u8), len)),
+                    YearNames::VariableEras(map) => self
+                        .match_longest(&input[pos..], map.b().iter().enumerate())
+                        .and_then(|(i, len)| {
+                            let code = map.a().get(i)?.try_as_str().ok()?;
+                            Some((EraCode::Code(TinyAsciiStr::try_from_str(code).ok()?), len))
+                        }),
+                    YearNames::Cyclic(_) => None,
+                }
+                .ok_or(err(DateTimeParseErrorKind::UnknownName(error_field)))?;
+                fields.era = Some((era, pos));
+                pos + len
+            }
+            (FieldSymbol::Year(fields::Year::Calendar), l) => {
+                let max_digits = if l == FieldLength::Two && !self.is_lenient() {
+                    2
+                } else {
+                    9
+                };
+                let (value, end, digits) = number(max_digits)?;
+                fields.year = Some((value as i32, pos));
+                fields.two_digit_year = l == FieldLength::Two && digits == 2;
+                end
+            }
+            (FieldSymbol::Year(fields::Year::RelatedIso), _) => {
+                let (value, end, _) = number(9)?;
+                fields.related_iso = Some((value as i32, pos));
+                end
+            }
+            (FieldSymbol::Year(fields::Year::Cyclic), l) => {
+                let names = YearNameLength::from_field_length(l)
+                    .and_then(|length| self.names.year_names_for_length(length))
+                    .ok_or(err(DateTimeParseErrorKind::NamesNotLoaded(error_field)))?;
+                let YearNames::Cyclic(names) = names else {
+                    return Err(err(DateTimeParseErrorKind::UnknownName(error_field)));
+                };
+                let (i, len) = self
+                    .match_longest(&input[pos..], names.iter().enumerate())
+                    .ok_or(err(DateTimeParseErrorKind::UnknownName(error_field)))?;
+                fields.cyclic_year = Some((i as u8 + 1, pos));
+                pos + len
+            }
+            (FieldSymbol::Month(_), FieldLength::One | FieldLength::Two) => {
+                let (value, end, _) = number(2)?;
+                fields.month = Some((ParsedMonth::Ordinal(narrow(value)?), pos));
+                end
+            }
+            (FieldSymbol::Month(symbol), l) => {
+                let length = MonthNameLength::from_field(symbol, l)
+                    .ok_or(err(DateTimeParseErrorKind::UnsupportedField(error_field)))?;
+                let names = self
+                    .names
+                    .month_names_for_length(length)
+                    .ok_or(err(DateTimeParseErrorKind::NamesNotLoaded(error_field)))?;
+                let (month, end) = self
+                    .match_month(input, pos, field, names)
+                    .ok_or(err(DateTimeParseErrorKind::UnknownName(error_field)))?;
+                fields.month = Some((month, pos));
+                end
+            }
+            (FieldSymbol::Day(fields::Day::DayOfMonth), _) => {
+                let (value, end, _) = number(2)?;
+                fields.day = Some((narrow(value)?, pos));
+                end
+            }
+            (FieldSymbol::Weekday(symbol), l) => {
+                let length = WeekdayNameLength::from_field(symbol, l)
+                    .ok_or(err(DateTimeParseErrorKind::UnsupportedField(error_field)))?;
+                let names = self
+                    .names
+                    .weekday_names_for_length(length)
+                    .ok_or(err(DateTimeParseErrorKind::NamesNotLoaded(error_field)))?;
+                let (i, len) = self
+                    .match_longest(&input[pos..], names.names.iter().enumerate())
+                    .ok_or(err(DateTimeParseErrorKind::UnknownName(error_field)))?;
+                fields.weekday = Some((Weekday::from_days_since_sunday(i as isize), field, pos));
+                pos + len
+            }
+            (FieldSymbol::DayPeriod(symbol), l) => {
+                let length = DayPeriodNameLength::from_field(symbol, l)
+                    .ok_or(err(DateTimeParseErrorKind::UnsupportedField(error_field)))?;
+                let names = self
+                    .names
+                    .day_period_names_for_length(length)
+                    .ok_or(err(DateTimeParseErrorKind::NamesNotLoaded(error_field)))?;
+                let (value, len) = self
+                    .match_day_period(&input[pos..], symbol, names)
+                    .ok_or(err(DateTimeParseErrorKind::UnknownName(error_field)))?;
+                fields.day_period = Some((value, pos));
+                pos + len
+            }
+            (FieldSymbol::Hour(symbol), _) => {
+                let (value, end, _) = number(2)?;
+                fields.hour = Some((narrow(value)?, symbol, pos));
+                end
+            }
+            (FieldSymbol::Minute, _) => {
+                let (value, end, _) = number(2)?;
+                fields.minute = Some((narrow(value)?, pos));
+                end
+            }
+            (FieldSymbol::Second(Second::Second), _) => {
+                let (value, end, _) = number(2)?;
+                fields.second = Some((narrow(value)?, pos));
+                end
+            }
+            (FieldSymbol::Second(Second::MillisInDay), _) => {
+                let (value, end, _) = number(8)?;
+                fields.millis_in_day = Some((value, pos));
+                end
+            }
+            (FieldSymbol::DecimalSecond(decimal_second), _) => {
+                let (value, end, _) = self
+                    .read_number(input, pos, field, true, 2)
+                    .or_else(|| self.read_number(input, pos, field, false, 2))
+                    .ok_or(err(DateTimeParseErrorKind::ExpectedNumber(error_field)))?;
+                fields.second = Some((narrow(value)?, pos));
+                let end = input[end..]
+                    .starts_with(self.decimal_separator.as_str())
+                    .then_some(end + self.decimal_separator.len())
+                    .ok_or(err(DateTimeParseErrorKind::ExpectedNumber(error_field)))?;
+                let precision = decimal_second as usize;
+                let (nanos, end) = self
+                    .read_fraction(input, end, precision)
+                    .ok_or(err(DateTimeParseErrorKind::ExpectedNumber(error_field)))?;
+                fields.nanosecond = Some((nanos, pos));
+                end
+            }
+            (FieldSymbol::TimeZone(zone), l) => {
+                self.parse_zone(input, pos, field, zone, l, fields)?
+            }
+            _ => return Err(err(DateTimeParseErrorKind::UnsupportedField(error_field))),
+        })
+    }
+
+    fn parse_zone(
+        &self,
+        input: &str,
+        pos: usize,
+        field: Field,
+        zone: fields::TimeZone,
+        length: FieldLength,
+        fields: &mut ParsedFields,
+    ) -> Result<usize, DateTimeParseError> {
+        use fields::TimeZone::*;
+        let error_field = ErrorField(field);
+        let err = |kind| DateTimeParseError::new(kind, pos);
+        let payloads = self.names.get_payloads();
+        let rest = &input[pos..];
+
+        match (zone, length) {
+            (Location, FieldLength::One | FieldLength::Two) => {
+                let len = rest
+                    .find(|c: char| !c.is_ascii_alphanumeric())
+                    .unwrap_or(rest.len());
+                let id = icu_locale_core::subtags::Subtag::try_from_str(&rest[..len])
+                    .map_err(|_| err(DateTimeParseErrorKind::UnknownName(error_field)))?;
+                fields.zone_id = Some(TimeZone(id));
+                Ok(pos + len)
+            }
+            (Location, FieldLength::Three) => {
+                let (id, len) = [payloads.exemplars, payloads.exemplars_root]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|cities: &ExemplarCities| {
+                        self.match_longest_by_key(rest, cities.exemplars.iter())
+                    })
+                    .max_by_key(|&(_, len)| len)
+                    .ok_or(err(DateTimeParseErrorKind::UnknownName(error_field)))?;
+                fields.zone_id = Some(id);
+                Ok(pos + len)
+            }
+            (Location, _) => {
+                let location = payloads.locations.or(payloads.locations_root).and_then(
+                    |locations: &Locations| {
+                        let (prefix, suffix) = split_single(&locations.pattern_generic);
+                        let after_prefix = self.match_str(rest, &prefix)?;
+                        [payloads.locations, payloads.locations_root]
+                            .into_iter()
+                            .flatten()
+                            .filter_map(|l| {
+                                let (id, len) = self.match_longest_by_key(
+                                    &rest[after_prefix..],
+                                    l.locations.iter(),
+                                )?;
+                                let end = after_prefix + len;
+                                Some((id, end + self.match_str(&rest[end..], &suffix)?))
+                            })
+                            .max_by_key(|&(_, len)| len)
+                    },
+                );
+                match location {
+                    Some((id, len)) => {
+                        fields.zone_id = Some(id);
+                        Ok(pos + len)
+                    }
+                    None => {
+                        self.parse_localized_offset(input, pos, field, FieldLength::Four, fields)
+                    }
+                }
+            }
+            (LocalizedOffset, l) => self.parse_localized_offset(input, pos, field, l, fields),
+            (SpecificNonLocation, l) | (GenericNonLocation, l) => {
+                let l = if l == FieldLength::Four {
+                    FieldLength::Four
+                } else {
+                    FieldLength::One
+                };
+                self.parse_localized_offset(input, pos, field, l, fields)
+                    .map_err(|_| err(DateTimeParseErrorKind::UnsupportedField(error_field)))
+            }
+            (IsoWithZ | Iso, l) => {
+                if zone == IsoWithZ || self.is_lenient() {
+                    if let Some(z) = rest.strip_prefix('Z') {
+                        fields.offset = Some(UtcOffset::zero());
+                        return Ok(input.len() - z.len());
+                    }
+                }
+                let (offset, len) = parse_iso_offset(rest, l, self.is_lenient())
+                    .ok_or(err(DateTimeParseErrorKind::InvalidOffset))?;
+                fields.offset = Some(offset);
+                Ok(pos + len)
+            }
+        }
+    }
+
+    fn parse_localized_offset(
+        &self,
+        input: &str,
+        pos: usize,
+        field: Field,
+        length: FieldLength,
+        fields: &mut ParsedFields,
+    ) -> Result<usize, DateTimeParseError> {
+        let error_field = ErrorField(field);
+        let err = |kind| DateTimeParseError::new(kind, pos);
+        let essentials = self
+            .names
+            .get_payloads()
+            .essentials
+            .ok_or(err(DateTimeParseErrorKind::NamesNotLoaded(error_field)))?;
+        let rest = &input[pos..];
+
+        let (prefix, suffix) = split_single(&essentials.offset_pattern);
+        let with_sign = self.match_str(rest, &prefix).and_then(|start| {
+            let sign_char = rest[start..].chars().next()?;
+            let sign = match sign_char {
+                '+' => 1,
+                '-' | '\u{2212}' => -1,
+                _ => return None,
+            };
+            let (hours, mut end, hour_digits) =
+                self.read_digits(rest, start + sign_char.len_utf8(), 1, 2)?;
+            // `OOOO` always has two-digit hours and minutes
+            let long = length == FieldLength::Four && !self.is_lenient();
+            if long && hour_digits != 2 {
+                return None;
+            }
+            let mut seconds = hours as i32 * 3600;
+            let mut has_minutes = false;
+            for unit in [60, 1] {
+                let Some(separator_len) =
+                    self.match_str(&rest[end..], &essentials.offset_separator)
+                else {
+                    break;
+                };
+                let Some((value, after, _)) = self.read_digits(rest, end + separator_len, 2, 2)
+                else {
+                    break;
+                };
+                seconds += value as i32 * unit;
+                end = after;
+                has_minutes = true;
+            }
+            if long && !has_minutes {
+                return None;
+            }
+            let end = end + self.match_str(&rest[end..], &suffix)?;
+            Some((sign * seconds, end))
+        });
+
+        let (seconds, len) = match with_sign {
+            Some(v) => v,
+            None => (
+                0,
+                self.match_str(rest, &essentials.offset_zero)
+                    .ok_or(err(DateTimeParseErrorKind::InvalidOffset))?,
+            ),
+        };
+        fields.offset = Some(
+            UtcOffset::try_from_seconds(seconds)
+                .map_err(|_| err(DateTimeParseErrorKind::InvalidOffset))?,
+        );
+        Ok(pos + len)
+    }
+
+    fn resolve_date(
+        &self,
+        fields: &ParsedFields,
+        input_len: usize,
+    ) -> Result<Date<AnyCalendar>, DateTimeParseError> {
+        let missing = |kind| {
+            DateTimeParseError::new(DateTimeParseErrorKind::MissingInputField(kind), input_len)
+        };
+        let (month, month_index) = fields.month.ok_or(missing(MissingInputFieldKind::Month))?;
+        let (day, day_index) = fields
+            .day
+            .ok_or(missing(MissingInputFieldKind::DayOfMonth))?;
+
+        let era = match fields.era {
+            Some((EraCode::Code(code), _)) => Some(code),
+            Some((EraCode::Index(i), index)) => Some(
+                era_code_for_index(self.calendar.kind(), i)
+                    .and_then(|s| TinyAsciiStr::try_from_str(s).ok())
+                    .ok_or(DateTimeParseError::new(
+                        DateTimeParseErrorKind::InvalidDate(DateError::UnknownEra),
+                        index,
+                    ))?,
+            ),
+            None => None,
+        };
+
+        let (year, year_index) = match (fields.year, fields.related_iso) {
+            (_, Some(related_iso)) => related_iso,
+            (Some((year, index)), _) if fields.two_digit_year && era.is_none() => {
+                let start = self.two_digit_year_start.unwrap_or_else(|| {
+                    Date::try_new_iso(1950, 1, 1)
+                        .map(|d| {
+                            d.to_calendar(Ref(self.calendar))
+                                .year()
+                                .era_year_or_related_iso()
+                        })
+                        .unwrap_or(1950)
+                });
+                (start + (year - start).rem_euclid(100), index)
+            }
+            (Some(year), _) => year,
+            (None, None) => return Err(missing(MissingInputFieldKind::Year)),
+        };
+
+        let date = self
+            .resolve_month(era.as_ref().map(|e| e.as_str()), year, month, day)
+            .map_err(|e| {
+                let index = match e {
+                    DateError::UnknownEra => fields.era.map(|(_, i)| i).unwrap_or(year_index),
+                    DateError::UnknownMonthCode(_) => month_index,
+                    DateError::Range { field: "day", .. } => day_index,
+                    DateError::Range { field: "month", .. } => month_index,
+                    _ => year_index,
+                };
+                DateTimeParseError::new(DateTimeParseErrorKind::InvalidDate(e), index)
+            })?;
+
+        if let Some((cyclic, index)) = fields.cyclic_year {
+            if date.year().cyclic().is_some_and(|c| c.year != cyclic) && !self.is_lenient() {
+                return Err(DateTimeParseError::new(
+                    DateTimeParseErrorKind::InconsistentField(ErrorField(Field {
+                        symbol: FieldSymbol::Year(fields::Year::Cyclic),
+                        length: FieldLength::Three,
+                    })),
+                    index,
+                ));
+            }
+        }
+        if let Some((weekday, field, index)) = fields.weekday {
+            if date.day_of_week() != weekday && !self.is_lenient() {
+                return Err(DateTimeParseError::new(
+                    DateTimeParseErrorKind::InconsistentField(ErrorField(field)),
+                    index,
+                ));
+            }
+        }
+        Ok(date)
+    }
+
+    /// Finds the date for a month given either as an ordinal or as a formatting code.
+    ///
+    /// In calendars with leap months neither of them is necessarily the standard month code,
+    /// so this tries the candidate month codes until one matches.
+    fn resolve_month(
+        &self,
+        era: Option<&str>,
+        year: i32,
+        month: ParsedMonth,
+        day: u8,
+    ) -> Result<Date<AnyCalendar>, DateError> {
+        let new_date = |code| Date::try_new_from_codes(era, year, code, day, self.calendar.clone());
+        let first_of_month =
+            |code| Date::try_new_from_codes(era, year, code, 1, Ref(self.calendar));
+        let matches = |code: MonthCode| {
+            first_of_month(code).is_ok_and(|d| match month {
+                ParsedMonth::Ordinal(ordinal) => d.month().ordinal == ordinal,
+                ParsedMonth::FormattingCode(formatting_code) => {
+                    d.month().formatting_code == formatting_code
+                }
+            })
+        };
+        let direct = match month {
+            ParsedMonth::Ordinal(ordinal) => MonthCode::new_normal(ordinal),
+            ParsedMonth::FormattingCode(code) => Some(code),
+        };
+        if let Some(code) = direct.filter(|&c| matches(c)) {
+            return new_date(code);
+        }
+        for number in 1..=13 {
+            for leap in [false, true] {
+                if let Some(code) = month_code(number, leap).filter(|&c| matches(c)) {
+                    return new_date(code);
+                }
+            }
+        }
+        // Reports the appropriate error
+        new_date(direct.ok_or(DateError::Range {
+            field: "month",
+            value: 0,
+            min: 1,
+            max: 13,
+        })?)
+    }
+
+    fn resolve_time(
+        &self,
+        fields: &ParsedFields,
+        input_len: usize,
+    ) -> Result<Time, DateTimeParseError> {
+        if let Some((millis, index)) = fields.millis_in_day {
+            let time = Time::try_new(
+                (millis / 3_600_000) as u8,
+                (millis / 60_000 % 60) as u8,
+                (millis / 1000 % 60) as u8,
+                millis % 1000 * 1_000_000,
+            )
+            .map_err(|e| DateTimeParseError::new(DateTimeParseErrorKind::InvalidTime(e), index))?;
+            return Ok(time);
+        }
+        let (hour, symbol, hour_index) = fields.hour.ok_or(DateTimeParseError::new(
+            DateTimeParseErrorKind::MissingInputField(MissingInputFieldKind::Hour),
+            input_len,
+        ))?;
+        let invalid_hour = |max| {
+            DateTimeParseError::new(
+                DateTimeParseErrorKind::InvalidTime(RangeError {
+                    field: "hour",
+                    value: hour.into(),
+                    min: 0,
+                    max,
+                }),
+                hour_index,
+            )
+        };
+        let hour = match symbol {
+            fields::Hour::H23 => hour,
+            fields::Hour::H11 | fields::Hour::H12 => {
+                let max = if symbol == fields::Hour::H11 { 11 } else { 12 };
+                if hour > max || (symbol == fields::Hour::H12 && hour == 0 && !self.is_lenient()) {
+                    return Err(invalid_hour(max.into()));
+                }
+                let hour = hour % 12;
+                match fields.day_period.map(|(p, _)| p) {
+                    Some(DayPeriodValue::Pm | DayPeriodValue::Noon) => hour + 12,
+                    _ => hour,
+                }
+            }
+        };
+        let index_of = |f: Option<usize>| f.unwrap_or(hour_index);
+        Time::try_new(
+            hour,
+            fields.minute.map(|(m, _)| m).unwrap_or(0),
+            fields.second.map(|(s, _)| s).unwrap_or(0),
+            fields.nanosecond.map(|(n, _)| n).unwrap_or(0),
+        )
+        .map_err(|e| {
+            let index = match e.field {
+                "minute" => index_of(fields.minute.map(|(_, i)| i)),
+                "second" => index_of(fields.second.map(|(_, i)| i)),
+                "nanosecond" => index_of(fields.nanosecond.map(|(_, i)| i)),
+                _ => hour_index,
+            };
+            DateTimeParseError::new(DateTimeParseErrorKind::InvalidTime(e), index)
+        })
+    }
+
+    /// Reads a number for a numeric field, applying the digit count rules of the mode.
+    ///
+    /// Returns the value, the end position, and the number of digits.
+    fn read_number(
+        &self,
+        input: &str,
+        pos: usize,
+        field: Field,
+        abutting: bool,
+        max_digits: usize,
+    ) -> Option<(u32, usize, usize)> {
+        let len = field.length.to_len();
+        let (min, max) = match (self.mode, abutting) {
+            (_, true) => (len, len),
+            (ParseMode::Strict, false) => (len.min(max_digits), max_digits.max(len)),
+            (ParseMode::Lenient, false) => (1, max_digits.max(len)),
+        };
+        self.read_digits(input, pos, min, max)
+    }
+
+    fn read_digits(
+        &self,
+        input: &str,
+        pos: usize,
+        min: usize,
+        max: usize,
+    ) -> Option<(u32, usize, usize)> {
+        let mut value: u32 = 0;
+        let mut count = 0;
+        let mut end = pos;
+        for c in input[pos..].chars().take(max) {
+            let Some(digit) = self.digit_value(c) else {
+                break;
+            };
+            value = value.checked_mul(10)?.checked_add(digit)?;
+            count += 1;
+            end += c.len_utf8();
+        }
+        (count >= min && count > 0).then_some((value, end, count))
+    }
+
+    /// Reads the fractional part of a second as nanoseconds.
+    fn read_fraction(&self, input: &str, pos: usize, precision: usize) -> Option<(u32, usize)> {
+        let (min, max) = if self.is_lenient() {
+            (1, 9)
+        } else {
+            (precision, precision)
+        };
+        let (value, end, count) = self.read_digits(input, pos, min, max)?;
+        Some((value * 10u32.pow(9 - count as u32), end))
+    }
+
+    fn digit_value(&self, c: char) -> Option<u32> {
+        if let Some(d) = self.digits.iter().position(|&d| d == c) {
+            Some(d as u32)
+        } else if self.is_lenient() {
+            c.to_digit(10)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the length of `expected` in `input` if `input` starts with it.
+    fn match_str(&self, input: &str, expected: &str) -> Option<usize> {
+        if expected.is_empty() {
+            return Some(0);
+        }
+        if !self.is_lenient() {
+            return input.starts_with(expected).then_some(expected.len());
+        }
+        let mut input_chars = input.char_indices();
+        for e in expected.chars() {
+            let (_, c) = input_chars.next()?;
+            if !chars_eq_lenient(c, e) {
+                return None;
+            }
+        }
+        Some(input_chars.next().map(|(i, _)| i).unwrap_or(input.len()))
+    }
+
+    /// Finds the longest non-empty name that the input starts with.
+    fn match_longest<'n, K>(
+        &self,
+        input: &str,
+        names: impl Iterator<Item = (K, &'n str)>,
+    ) -> Option<(K, usize)> {
+        let mut best: Option<(K, usize)> = None;
+        for (key, name) in names {
+            if name.is_empty() {
+                continue;
+            }
+            if let Some(len) = self.match_str(input, name) {
+                if best.as_ref().is_none_or(|&(_, best_len)| len > best_len) {
+                    best = Some((key, len));
+                }
+            }
+        }
+        best
+    }
+
+    fn match_longest_by_key<'n>(
+        &self,
+        input: &str,
+        names: impl Iterator<Item = (&'n <TimeZone as zerovec::ule::AsULE>::ULE, &'n str)>,
+    ) -> Option<(TimeZone, usize)> {
+        self.match_longest(
+            input,
+            names.map(|(k, v)| (<TimeZone as zerovec::ule::AsULE>::from_unaligned(*k), v)),
+        )
+    }
+
+    fn match_month(
+        &self,
+        input: &str,
+        pos: usize,
+        field: Field,
+        names: &MonthNames,
+    ) -> Option<(ParsedMonth, usize)> {
+        let rest = &input[pos..];
+        match names {
+            MonthNames::Linear(names) => self
+                .match_longest(rest, names.iter().enumerate())
+                .and_then(|(i, len)| {
+                    Some((
+                        ParsedMonth::FormattingCode(MonthCode::new_normal(i as u8 + 1)?),
+                        pos + len,
+                    ))
+                }),
+            MonthNames::LeapLinear(names) => {
+                let num_months = names.len() / 2;
+                self.match_longest(rest, names.iter().enumerate())
+                    .and_then(|(i, len)| {
+                        let code = month_code((i % num_months) as u8 + 1, i >= num_months)?;
+                        Some((ParsedMonth::FormattingCode(code), pos + len))
+                    })
+            }
+            MonthNames::LeapNumeric(leap_pattern) => {
+                let (prefix, suffix) = split_single(leap_pattern);
+                let leap = self.match_str(rest, &prefix).and_then(|start| {
+                    let (value, end, _) = self.read_number(input, pos + start, field, false, 2)?;
+                    let end = end + self.match_str(&input[end..], &suffix)?;
+                    Some((month_code(u8::try_from(value).ok()?, true)?, end))
+                });
+                match leap {
+                    Some((code, end)) => Some((ParsedMonth::FormattingCode(code), end)),
+                    None => {
+                        let (value, end, _) = self.read_number(input, pos, field, false, 2)?;
+                        Some((ParsedMonth::Ordinal(u8::try_from(value).ok()?), end))
+                    }
+                }
+            }
+        }
+    }
+
+    fn match_day_period(
+        &self,
+        input: &str,
+        symbol: fields::DayPeriod,
+        names: &LinearNames,
+    ) -> Option<(DayPeriodValue, usize)> {
+        let mut candidates = Vec::with_capacity(4);
+        candidates.extend(names.am().map(|n| (DayPeriodValue::Am, n)));
+        candidates.extend(names.pm().map(|n| (DayPeriodValue::Pm, n)));
+        if symbol == fields::DayPeriod::NoonMidnight {
+            candidates.extend(names.noon().map(|n| (DayPeriodValue::Noon, n)));
+            candidates.extend(names.midnight().map(|n| (DayPeriodValue::Midnight, n)));
+        }
+        self.match_longest(input, candidates.into_iter())
+    }
+}
+
+fn is_numeric(field: Field) -> bool {
+    match field.symbol {
+        FieldSymbol::Year(fields::Year::Cyclic) => false,
+        FieldSymbol::Year(_)
+        | FieldSymbol::Day(_)
+        | FieldSymbol::Hour(_)
+        | FieldSymbol::Minute
+        | FieldSymbol::Second(_)
+        | FieldSymbol::DecimalSecond(_) => true,
+        FieldSymbol::Month(_) => matches!(field.length, FieldLength::One | FieldLength::Two),
+        _ => false,
+    }
+}
+
+fn skip_whitespace(input: &str, pos: usize) -> usize {
+    input.len() - input[pos..].trim_start().len()
+}
+
+fn chars_eq_lenient(a: char, b: char) -> bool {
+    a == b || (a.is_whitespace() && b.is_whitespace()) || a.to_lowercase().eq(b.to_lowercase())
+}
+
+fn month_code(number: u8, leap: bool) -> Option<MonthCode> {
+    let code = MonthCode::new_normal(number)?;
+    if !leap {
+        return Some(code);
+    }
+    let [m, tens, ones, _] = *code.0.all_bytes();
+    Some(MonthCode(
+        TinyAsciiStr::try_from_raw([m, tens, ones, b'L']).ok()?,
+    ))
+}
+
+/// The era codes of the calendars with fixed eras, in the order of their era indices.
+fn era_code_for_index(kind: AnyCalendarKind, index: u8) -> Option<&'static str> {
+    use AnyCalendarKind::*;
+    let codes: &[&str] = match kind {
+        Gregorian => &["bce", "ce"],
+        Roc => &["broc", "roc"],
+        Ethiopian | EthiopianAmeteAlem => &["aa", "am"],
+        HijriTabularTypeIIFriday
+        | HijriTabularTypeIIThursday
+        | HijriUmmAlQura
+        | HijriSimulatedMecca => &["ah", "bh"],
+        BikramSambat => &["bs"],
+        Buddhist => &["be"],
+        Coptic | Hebrew => &["am"],
+        Indian => &["shaka"],
+        Persian => &["ap"],
+        Iso => &["default"],
+        _ => &[],
+    };
+    codes.get(usize::from(index)).copied()
+}
+
+/// Splits a single-placeholder pattern into the literal text before and after the placeholder.
+fn split_single(pattern: &SinglePlaceholderPattern) -> (String, String) {
+    let mut prefix = String::new();
+    let mut suffix = String::new();
+    let mut seen_placeholder = false;
+    for item in pattern.iter() {
+        match item {
+            icu_pattern::PatternItem::Literal(s) if seen_placeholder => suffix.push_str(s),
+            icu_pattern::PatternItem::Literal(s) => prefix.push_str(s),
+            icu_pattern::PatternItem::Placeholder(_) => seen_placeholder = true,
+        }
+    }
+    (prefix, suffix)
+}
+
+/// Parses an ISO-8601 offset such as `+05`, `+0530`, or `+05:30`, without the `Z` form.
+fn parse_iso_offset(input: &str, length: FieldLength, lenient: bool) -> Option<(UtcOffset, usize)> {
+    let bytes = input.as_bytes();
+    let sign = match bytes.first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let two_digits = |i: usize| -> Option<i32> {
+        let (&tens, &ones) = (bytes.get(i)?, bytes.get(i + 1)?);
+        (tens.is_ascii_digit() && ones.is_ascii_digit())
+            .then(|| i32::from(tens - b'0') * 10 + i32::from(ones - b'0'))
+    };
+    let hours = two_digits(1)?;
+    let mut seconds = hours * 3600;
+    let mut end = 3;
+    // X/x: +05 or +0530; XX/xx: +0530; XXX/xxx: +05:30; XXXX/xxxx: +0530[45]; XXXXX/xxxxx: +05:30[:45]
+    let extended = matches!(length, FieldLength::Three | FieldLength::Five);
+    for unit in [60, 1] {
+        let sep = usize::from(bytes.get(end) == Some(&b':'));
+        if sep == 1 && !extended && !lenient {
+            break;
+        }
+        if sep == 0 && extended && !lenient {
+            break;
+        }
+        let Some(value) = two_digits(end + sep) else {
+            break;
+        };
+        seconds += value * unit;
+        end += sep + 2;
+    }
+    if !lenient && !matches!(length, FieldLength::One) && end == 3 {
+        return None;
+    }
+    Some((UtcOffset::try_from_seconds(sign * seconds).ok()?, end))
+}
+
+/// Computes the digits 0 through 9 and the decimal separator used by the decimal formatter,
+/// falling back to ASCII as formatting does.
+fn digits_and_separator(decimal_formatter: Option<&DecimalFormatter>) -> ([char; 10], String) {
+    let mut digits = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+    let Some(formatter) = decimal_formatter else {
+        return (digits, String::from("."));
+    };
+    for (i, digit) in digits.iter_mut().enumerate() {
+        if let Some(c) = formatter
+            .format(&Decimal::from(i as u8))
+            .write_to_string()
+            .chars()
+            .next()
+        {
+            *digit = c;
+        }
+    }
+    let half = formatter
+        .format(&{
+            let mut half = Decimal::from(5);
+            half.multiply_pow10(-1);
+            half
+        })
+        .write_to_string()
+        .into_owned();
+    let separator = half
+        .trim_start_matches(|c| c == digits[0])
+        .trim_end_matches(|c| c == digits[5]);
+    (digits, String::from(separator))
+}
+
+#[cfg(all(test, feature = "compiled_data"))]
+mod tests {
+    use super::super::*;
+    use super::DateTimeParseErrorKind as Kind;
+    use crate::fieldsets::{self, enums::CompositeFieldSet};
+    use crate::provider::pattern::PatternItem;
+    use crate::DateTimeFormatter;
+    use icu_calendar::Date;
+    use icu_locale_core::{locale, Locale};
+    use icu_time::zone::UtcOffset;
+    use icu_time::{DateTime, Time, TimeZone};
+    use writeable::Writeable;
+
+    fn names_for(locale: Locale, field_set: fieldsets::YMDT) -> DateTimeNames<CompositeFieldSet> {
+        let prefs = (&locale).into();
+        let formatter = DateTimeFormatter::try_new(prefs, field_set).unwrap();
+        DateTimeNames::from_formatter(prefs, formatter.cast_into_fset())
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let datetimes = [
+            (2025, 1, 3, 9, 5),
+            (1999, 12, 31, 23, 59),
+            (2024, 2, 29, 0, 0),
+            (1970, 7, 14, 12, 30),
+        ];
+        let cases = [
+            "en",
+            "en-u-ca-buddhist",
+            "fr",
+            "de",
+            "ja-u-ca-japanese",
+            "he-u-ca-hebrew",
+            "zh-u-ca-chinese",
+            "ar-EG",
+            "hi-u-nu-deva",
+            "th",
+        ];
+        for locale in cases {
+            let locale: Locale = locale.parse().unwrap();
+            for length in [fieldsets::YMDT::long(), fieldsets::YMDT::short()] {
+                let prefs = (&locale).into();
+                let formatter = DateTimeFormatter::try_new(prefs, length).unwrap();
+                for &(y, m, d, h, min) in &datetimes {
+                    let datetime = DateTime {
+                        date: Date::try_new_iso(y, m, d).unwrap(),
+                        time: Time::try_new(h, min, 0, 0).unwrap(),
+                    };
+                    let formatted = formatter.format(&datetime);
+                    let string = formatted.write_to_string().into_owned();
+                    let pattern = formatted.pattern();
+                    let names = names_for(locale.clone(), length);
+                    let parser = names.parser_with_pattern(&pattern, Default::default());
+                    let parsed = parser
+                        .parse_date_time(&string)
+                        .unwrap_or_else(|e| panic!("{locale} {pattern} {string:?}: {e}"));
+                    assert_eq!(
+                        parsed.date.to_iso(),
+                        datetime.date,
+                        "{locale} {pattern} {string:?}"
+                    );
+                    assert_eq!(parsed.time, datetime.time, "{locale} {pattern} {string:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_strict_and_lenient() {
+        let names = names_for(locale!("en"), fieldsets::YMDT::long());
+        let pattern: DateTimePattern = "MMMM d, y 'at' h:mm a".parse().unwrap();
+        let strict = names.parser_with_pattern(&pattern, Default::default());
+        let lenient =
+            names.parser_with_pattern(&pattern, ParseOptions::with_mode(ParseMode::Lenient));
+        let expected = DateTime {
+            date: Date::try_new_iso(2025, 1, 3).unwrap(),
+            time: Time::try_new(21, 5, 0, 0).unwrap(),
+        };
+
+        let parsed = strict
+            .parse_date_time("January 3, 2025 at 9:05 PM")
+            .unwrap();
+        assert_eq!(parsed.date.to_iso(), expected.date);
+        assert_eq!(parsed.time, expected.time);
+
+        let sloppy = "  january 3 ,2025 AT 9:5 pm ";
+        let parsed = lenient.parse_date_time(sloppy).unwrap();
+        assert_eq!(parsed.date.to_iso(), expected.date);
+        assert_eq!(parsed.time, expected.time);
+        assert!(strict.parse_date_time(sloppy).is_err());
+
+        // Strict requires the minimum number of digits
+        assert_eq!(
+            strict
+                .parse_date_time("January 3, 2025 at 9:5 PM")
+                .unwrap_err()
+                .index,
+            21
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut names = names_for(locale!("en"), fieldsets::YMDT::long());
+        names
+            .as_mut()
+            .include_weekday_names(WeekdayNameLength::Wide)
+            .unwrap();
+        let parse = |pattern: &str, input: &str| {
+            let pattern: DateTimePattern = pattern.parse().unwrap();
+            names
+                .parser_with_pattern(&pattern, Default::default())
+                .parse_date_time(input)
+                .map(|_| ())
+        };
+        let err = |kind, index| Err(DateTimeParseError { kind, index });
+        let field = |s: &str| {
+            let pattern: DateTimePattern = s.parse().unwrap();
+            let item = pattern.iter_items().next();
+            match item {
+                Some(PatternItem::Field(field)) => ErrorField(field),
+                _ => unreachable!(),
+            }
+        };
+
+        assert_eq!(
+            parse("y-MM-dd HH:mm", "2025/01-03 10:00"),
+            err(Kind::ExpectedLiteral('-'), 4)
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm", "2025-0x-03 10:00"),
+            err(Kind::ExpectedNumber(field("MM")), 5)
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm", "2025-01-03 10:00!"),
+            err(Kind::TrailingInput, 16)
+        );
+        assert_eq!(
+            parse("y-MM-dd", "2025-01-03"),
+            err(Kind::MissingInputField(MissingInputFieldKind::Hour), 10)
+        );
+        assert!(matches!(
+            parse("y-MM-dd HH:mm", "2025-02-30 10:00"),
+            Err(DateTimeParseError {
+                kind: Kind::InvalidDate(_),
+                index: 8
+            })
+        ));
+        assert!(matches!(
+            parse("y-MM-dd HH:mm", "2025-02-03 25:00"),
+            Err(DateTimeParseError {
+                kind: Kind::InvalidTime(_),
+                index: 11
+            })
+        ));
+        assert_eq!(
+            parse("MMMM d, y HH:mm", "Janvier 3, 2025 10:00"),
+            err(Kind::UnknownName(field("MMMM")), 0)
+        );
+        assert_eq!(
+            parse("EEEE, y-MM-dd HH:mm", "Monday, 2025-01-03 10:00"),
+            err(Kind::InconsistentField(field("EEEE")), 0)
+        );
+        assert_eq!(
+            parse("EEEE, y-MM-dd HH:mm", "Friday, 2025-01-03 10:00"),
+            Ok(())
+        );
+        assert_eq!(
+            parse("GGGG y-MM-dd HH:mm", "AD 2025-01-03 10:00"),
+            err(Kind::NamesNotLoaded(field("GGGG")), 0)
+        );
+        assert_eq!(
+            parse("D y HH:mm", "3 2025 10:00"),
+            err(Kind::UnsupportedField(field("D")), 0)
+        );
+    }
+
+    #[test]
+    fn test_two_digit_year() {
+        let names = names_for(locale!("en"), fieldsets::YMDT::short());
+        let pattern: DateTimePattern = "M/d/yy".parse().unwrap();
+        let parser = names.parser_with_pattern(&pattern, Default::default());
+        for (input, year) in [("1/3/25", 2025), ("1/3/49", 2049), ("1/3/50", 1950)] {
+            assert_eq!(
+                parser.parse_date(input).unwrap().to_iso(),
+                Date::try_new_iso(year, 1, 3).unwrap()
+            );
+        }
+        // Strict mode requires two digits
+        assert!(parser.parse_date("1/3/2025").is_err());
+
+        let parser = names.parser_with_pattern(
+            &pattern,
+            ParseOptions {
+                two_digit_year_start: Some(1900),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            parser.parse_date("1/3/25").unwrap().to_iso(),
+            Date::try_new_iso(1925, 1, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_abutting_and_fractional() {
+        let names = names_for(locale!("en"), fieldsets::YMDT::short());
+        let pattern: DateTimePattern = "yyyyMMdd'T'HHmmss.SSS".parse().unwrap();
+        let parser = names.parser_with_pattern(&pattern, Default::default());
+        let parsed = parser.parse_date_time("20250103T091502.250").unwrap();
+        assert_eq!(parsed.date.to_iso(), Date::try_new_iso(2025, 1, 3).unwrap());
+        assert_eq!(parsed.time, Time::try_new(9, 15, 2, 250_000_000).unwrap());
+    }
+
+    #[test]
+    fn test_zones() {
+        let mut names = names_for(locale!("en"), fieldsets::YMDT::short());
+        names
+            .as_mut()
+            .include_time_zone_essentials()
+            .unwrap()
+            .include_time_zone_location_names()
+            .unwrap()
+            .include_time_zone_exemplar_city_names()
+            .unwrap();
+        let parse = |pattern: &str, input: &str| {
+            let pattern: DateTimePattern = pattern.parse().unwrap();
+            names
+                .parser_with_pattern(&pattern, Default::default())
+                .parse_zoned_date_time(input)
+                .map(|zdt| (zdt.zone.id(), zdt.zone.offset()))
+        };
+        let id = |s: &str| TimeZone(s.parse().unwrap());
+        let offset = |s: &str| Some(UtcOffset::try_from_str(s).unwrap());
+
+        assert_eq!(
+            parse("y-MM-dd HH:mm OOOO", "2025-01-03 10:00 GMT+05:30"),
+            Ok((TimeZone::UNKNOWN, offset("+0530")))
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm O", "2025-01-03 10:00 GMT"),
+            Ok((TimeZone::UNKNOWN, offset("Z")))
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm XXX", "2025-01-03 10:00 -08:00"),
+            Ok((TimeZone::UNKNOWN, offset("-08")))
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm X", "2025-01-03 10:00 Z"),
+            Ok((TimeZone::UNKNOWN, offset("Z")))
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm V", "2025-01-03 10:00 uslax"),
+            Ok((id("uslax"), None))
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm VVV", "2025-01-03 10:00 Tokyo"),
+            Ok((id("jptyo"), None))
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm VVVV", "2025-01-03 10:00 Germany Time"),
+            Ok((id("deber"), None))
+        );
+        assert_eq!(
+            parse("y-MM-dd HH:mm", "2025-01-03 10:00"),
+            Err(DateTimeParseError {
+                kind: Kind::MissingInputField(MissingInputFieldKind::TimeZoneId),
+                index: 16
+            })
+        );
+    }
+
+    #[test]
+    fn test_native_digits() {
+        let names = names_for(locale!("ar-EG"), fieldsets::YMDT::short());
+        let pattern: DateTimePattern = "d/M/y".parse().unwrap();
+        let parser = names.parser_with_pattern(&pattern, Default::default());
+        assert_eq!(
+            parser.parse_date("٣/١/٢٠٢٥").unwrap().to_iso(),
+            Date::try_new_iso(2025, 1, 3).unwrap()
+        );
+        // ASCII digits are only accepted in lenient mode
+        assert!(parser.parse_date("3/1/2025").is_err());
+        let parser =
+            names.parser_with_pattern(&pattern, ParseOptions::with_mode(ParseMode::Lenient));
+        assert_eq!(
+            parser.parse_date("3/1/2025").unwrap().to_iso(),
+            Date::try_new_iso(2025, 1, 3).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_leap_month() {
+        let prefs = locale!("en-u-ca-chinese").into();
+        let formatter = DateTimeFormatter::try_new(prefs, fieldsets::YMD::long()).unwrap();
+        // 2023-04-01 is in the leap month M02L
+        let date = Date::try_new_iso(2023, 4, 1).unwrap();
+        let formatted = formatter.format(&date);
+        let string = formatted.write_to_string().into_owned();
+        let pattern = formatted.pattern();
+        let names = DateTimeNames::from_formatter(prefs, formatter);
+        let parser = names.parser_with_pattern(&pattern, Default::default());
+        let parsed = parser.parse_date(&string).unwrap();
+        assert_eq!(parsed.month().standard_code.0, "M02L");
+        assert_eq!(parsed.to_iso(), date);
+    }
+}
@@ -595,6 +595,14 @@ impl Hour {
             _ => unreachable!(),
         }
     }
+
+    pub(crate) fn to_hour_cycle(self) -> HourCycle {
+        match self {
+            Self::H11 => HourCycle::H11,
+            Self::H12 => HourCycle::H12,
+            Self::H23 => HourCycle::H23,
+        }
+    }
 }
 
 // NOTE: 'S' Subsecond is represented via DecimalSecond,
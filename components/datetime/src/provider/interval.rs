@@ -0,0 +1,62 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Data provider structs for date/time interval formatting.
+
+use crate::provider::pattern::runtime;
+use alloc::borrow::Cow;
+use icu_pattern::DoublePlaceholderPattern;
+use icu_provider::prelude::*;
+use zerovec::ZeroMap2d;
+
+icu_provider::data_marker!(
+    /// `DatetimePatternsIntervalGregorianV1`
+    DatetimePatternsIntervalGregorianV1,
+    IntervalPatterns<'static>
+);
+icu_provider::data_marker!(
+    /// `DatetimePatternsIntervalGenericV1`
+    ///
+    /// Used for all calendars other than Gregorian, as well as for time-only field sets.
+    DatetimePatternsIntervalGenericV1,
+    IntervalPatterns<'static>
+);
+
+/// The CLDR `intervalFormats` for a calendar.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_datetime::provider::interval))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct IntervalPatterns<'data> {
+    /// The pattern used when no interval pattern is available, such as `{0} – {1}`.
+    ///
+    /// The first placeholder is the formatted start, the second the formatted end.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            borrow,
+            deserialize_with = "icu_pattern::deserialize_borrowed_cow::<icu_pattern::DoublePlaceholder, _>"
+        )
+    )]
+    pub fallback: Cow<'data, DoublePlaceholderPattern>,
+    /// Interval patterns keyed by skeleton (such as `yMMMd`) and by the greatest
+    /// differing field symbol (one of `G`, `y`, `M`, `d`, `a`, `h`, `H`, `m`).
+    ///
+    /// The pattern contains the fields of both the start and the end of the interval;
+    /// the end begins at the first field whose symbol repeats.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub patterns: ZeroMap2d<'data, str, char, runtime::PatternULE>,
+}
+
+icu_provider::data_struct!(
+    IntervalPatterns<'_>,
+    #[cfg(feature = "datagen")]
+);
@@ -16,6 +16,8 @@
 //! Read more about data providers: [`icu_provider`]
 
 pub mod fields;
+#[cfg(feature = "experimental")]
+pub mod interval;
 pub mod neo;
 pub(crate) mod packed_pattern;
 pub mod pattern;
@@ -108,6 +110,11 @@ const _: () = {
     impl_datetime_patterns_date_japanext_v1!(Baked);
     impl_datetime_patterns_date_persian_v1!(Baked);
     impl_datetime_patterns_date_roc_v1!(Baked);
+
+    #[cfg(feature = "experimental")]
+    impl_datetime_patterns_interval_gregorian_v1!(Baked);
+    #[cfg(feature = "experimental")]
+    impl_datetime_patterns_interval_generic_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
@@ -170,4 +177,8 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     DatetimePatternsDateJapanextV1::INFO,
     DatetimePatternsDatePersianV1::INFO,
     DatetimePatternsDateRocV1::INFO,
+    #[cfg(feature = "experimental")]
+    interval::DatetimePatternsIntervalGregorianV1::INFO,
+    #[cfg(feature = "experimental")]
+    interval::DatetimePatternsIntervalGenericV1::INFO,
 ];
@@ -121,6 +121,17 @@ pub(crate) struct DateTimeZonePatternSelectionData {
     time: TimePatternSelectionData,
     zone: Option<ZonePatternSelectionData>,
     glue: Option<DataPayload<DatetimePatternsGlueV1>>,
+    /// The locale of the date or time pattern data, after fallback.
+    locale: DataLocale,
+}
+
+/// The part of a glued date/time/zone pattern that a pattern item belongs to.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum PatternComponent {
+    Glue,
+    Date,
+    Time,
+    Zone,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -142,19 +153,20 @@ impl DatePatternSelectionData {
         provider: &(impl BoundDataProvider<ErasedPackedPatterns> + ?Sized),
         prefs: DateTimeFormatterPreferences,
         attributes: &DataMarkerAttributes,
-    ) -> Result<Self, DataError> {
+    ) -> Result<(Self, DataLocale), DataError> {
         let locale = provider
             .bound_marker()
             .make_locale(prefs.locale_preferences);
-        let payload = provider
-            .load_bound(DataRequest {
-                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
-                ..Default::default()
-            })?
-            .payload;
-        Ok(Self {
-            payload: DataPayloadOr::from_payload(payload),
-        })
+        let response = provider.load_bound(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
+            ..Default::default()
+        })?;
+        Ok((
+            Self {
+                payload: DataPayloadOr::from_payload(response.payload),
+            },
+            response.metadata.locale.unwrap_or(locale),
+        ))
     }
 
     /// Borrows a pattern containing all of the fields that need to be loaded.
@@ -252,16 +264,16 @@ impl TimePatternSelectionData {
         provider: &(impl BoundDataProvider<ErasedPackedPatterns> + ?Sized),
         prefs: DateTimeFormatterPreferences,
         components: TimeFieldSet,
-    ) -> Result<Self, DataError> {
+    ) -> Result<(Self, DataLocale), DataError> {
         let locale = provider
             .bound_marker()
             .make_locale(prefs.locale_preferences);
         let prefs = RawPreferences::from_prefs(prefs);
         // First try to load with the explicit hour cycle. If there is no explicit hour cycle,
         // or if loading the explicit hour cycle fails, then load with the default hour cycle.
-        let mut maybe_payload = None;
+        let mut maybe_response = None;
         if let Some(hour_cycle) = prefs.hour_cycle {
-            maybe_payload = provider
+            maybe_response = provider
                 .load_bound(DataRequest {
                     id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
                         components.id_str_for_hour_cycle(Some(hour_cycle)),
@@ -269,26 +281,24 @@ impl TimePatternSelectionData {
                     ),
                     ..Default::default()
                 })
-                .allow_identifier_not_found()?
-                .map(|r| r.payload);
+                .allow_identifier_not_found()?;
         }
-        let payload = match maybe_payload {
-            Some(payload) => payload,
-            None => {
-                provider
-                    .load_bound(DataRequest {
-                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
-                            components.id_str_for_hour_cycle(None),
-                            &locale,
-                        ),
-                        ..Default::default()
-                    })?
-                    .payload
-            }
+        let response = match maybe_response {
+            Some(response) => response,
+            None => provider.load_bound(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    components.id_str_for_hour_cycle(None),
+                    &locale,
+                ),
+                ..Default::default()
+            })?,
         };
-        Ok(Self {
-            payload: DataPayloadOr::from_payload(payload),
-        })
+        Ok((
+            Self {
+                payload: DataPayloadOr::from_payload(response.payload),
+            },
+            response.metadata.locale.unwrap_or(locale),
+        ))
     }
 
     pub(crate) fn try_new_overlap_with_skeleton(
@@ -296,7 +306,7 @@ impl TimePatternSelectionData {
         prefs: DateTimeFormatterPreferences,
         attributes: &DataMarkerAttributes,
         options: RawOptions,
-    ) -> Result<Self, DataError> {
+    ) -> Result<(Self, DataLocale), DataError> {
         // Currently, none of the overlap patterns have a year field,
         // so we can use the variant to select the time precision.
         //
@@ -307,15 +317,16 @@ impl TimePatternSelectionData {
         let locale = provider
             .bound_marker()
             .make_locale(prefs.locale_preferences);
-        let payload = provider
-            .load_bound(DataRequest {
-                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
-                ..Default::default()
-            })?
-            .payload;
-        Ok(Self {
-            payload: DataPayloadOr::from_payload(payload),
-        })
+        let response = provider.load_bound(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
+            ..Default::default()
+        })?;
+        Ok((
+            Self {
+                payload: DataPayloadOr::from_payload(response.payload),
+            },
+            response.metadata.locale.unwrap_or(locale),
+        ))
     }
 
     /// Borrows a pattern containing all of the fields that need to be loaded.
@@ -410,7 +421,7 @@ impl DateTimeZonePatternSelectionData {
         match skeleton {
             CompositeFieldSet::Date(field_set) => {
                 let options = field_set.to_raw_options();
-                let selection = DatePatternSelectionData::try_new_with_skeleton(
+                let (selection, locale) = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
                     prefs,
                     field_set.id_str(),
@@ -422,11 +433,12 @@ impl DateTimeZonePatternSelectionData {
                     time: TimePatternSelectionData::none(),
                     zone: None,
                     glue: None,
+                    locale,
                 })
             }
             CompositeFieldSet::CalendarPeriod(field_set) => {
                 let options = field_set.to_raw_options();
-                let selection = DatePatternSelectionData::try_new_with_skeleton(
+                let (selection, locale) = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
                     prefs,
                     field_set.id_str(),
@@ -438,11 +450,12 @@ impl DateTimeZonePatternSelectionData {
                     time: TimePatternSelectionData::none(),
                     zone: None,
                     glue: None,
+                    locale,
                 })
             }
             CompositeFieldSet::Time(field_set) => {
                 let options = field_set.to_raw_options();
-                let selection = TimePatternSelectionData::try_new_with_skeleton(
+                let (selection, locale) = TimePatternSelectionData::try_new_with_skeleton(
                     time_provider,
                     prefs,
                     field_set,
@@ -455,10 +468,15 @@ impl DateTimeZonePatternSelectionData {
                     time: selection,
                     zone: None,
                     glue: None,
+                    locale,
                 })
             }
             CompositeFieldSet::Zone(field_set) => {
                 let selection = ZonePatternSelectionData::new_with_skeleton(field_set);
+                // No pattern data is loaded for zones
+                let locale = date_provider
+                    .bound_marker()
+                    .make_locale(prefs.locale_preferences);
                 Ok(Self {
                     options: RawOptions {
                         length: None,
@@ -472,6 +490,7 @@ impl DateTimeZonePatternSelectionData {
                     time: TimePatternSelectionData::none(),
                     zone: Some(selection),
                     glue: None,
+                    locale,
                 })
             }
             CompositeFieldSet::DateTime(field_set) => {
@@ -481,13 +500,14 @@ impl DateTimeZonePatternSelectionData {
                     // Try loading an overlap pattern.
                     // Note: Overlap patterns are loaded from the date skeleton pattern provider
                     // and then stored as a TimePatternSelectionData.
-                    if let Some(overlap) = TimePatternSelectionData::try_new_overlap_with_skeleton(
-                        date_provider,
-                        prefs,
-                        attributes,
-                        options,
-                    )
-                    .allow_identifier_not_found()?
+                    if let Some((overlap, locale)) =
+                        TimePatternSelectionData::try_new_overlap_with_skeleton(
+                            date_provider,
+                            prefs,
+                            attributes,
+                            options,
+                        )
+                        .allow_identifier_not_found()?
                     {
                         let prefs = RawPreferences::from_prefs(prefs);
                         return Ok(Self {
@@ -497,15 +517,16 @@ impl DateTimeZonePatternSelectionData {
                             time: overlap,
                             zone: None,
                             glue: None,
+                            locale,
                         });
                     }
                 }
-                let date = DatePatternSelectionData::try_new_with_skeleton(
+                let (date, locale) = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
                     prefs,
                     field_set.to_date_field_set().id_str(),
                 )?;
-                let time = TimePatternSelectionData::try_new_with_skeleton(
+                let (time, _) = TimePatternSelectionData::try_new_with_skeleton(
                     time_provider,
                     prefs,
                     field_set.to_time_field_set(),
@@ -519,11 +540,12 @@ impl DateTimeZonePatternSelectionData {
                     time,
                     zone: None,
                     glue: Some(glue),
+                    locale,
                 })
             }
             CompositeFieldSet::DateZone(combo) => {
                 let options = combo.dt().to_raw_options();
-                let date = DatePatternSelectionData::try_new_with_skeleton(
+                let (date, locale) = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
                     prefs,
                     combo.dt().id_str(),
@@ -537,11 +559,12 @@ impl DateTimeZonePatternSelectionData {
                     time: TimePatternSelectionData::none(),
                     zone: Some(zone),
                     glue: Some(glue),
+                    locale,
                 })
             }
             CompositeFieldSet::TimeZone(combo) => {
                 let options = combo.dt().to_raw_options();
-                let time = TimePatternSelectionData::try_new_with_skeleton(
+                let (time, locale) = TimePatternSelectionData::try_new_with_skeleton(
                     time_provider,
                     prefs,
                     combo.dt(),
@@ -556,16 +579,17 @@ impl DateTimeZonePatternSelectionData {
                     time,
                     zone: Some(zone),
                     glue: Some(glue),
+                    locale,
                 })
             }
             CompositeFieldSet::DateTimeZone(combo) => {
                 let options = combo.dt().to_raw_options();
-                let date = DatePatternSelectionData::try_new_with_skeleton(
+                let (date, locale) = DatePatternSelectionData::try_new_with_skeleton(
                     date_provider,
                     prefs,
                     combo.dt().to_date_field_set().id_str(),
                 )?;
-                let time = TimePatternSelectionData::try_new_with_skeleton(
+                let (time, _) = TimePatternSelectionData::try_new_with_skeleton(
                     time_provider,
                     prefs,
                     combo.dt().to_time_field_set(),
@@ -580,6 +604,7 @@ impl DateTimeZonePatternSelectionData {
                     time,
                     zone: Some(zone),
                     glue: Some(glue),
+                    locale,
                 })
             }
         }
@@ -632,6 +657,27 @@ impl DateTimeZonePatternSelectionData {
         date_items.chain(time_items).chain(zone_items)
     }
 
+    /// The locale of the pattern data, after fallback.
+    pub(crate) fn resolved_locale(&self) -> DataLocale {
+        self.locale
+    }
+
+    /// The hour cycle used for formatting: the one from the preferences if it overrides the
+    /// pattern, or else the one of the hour field in the time pattern.
+    pub(crate) fn resolved_hour_cycle(&self) -> Option<fields::Hour> {
+        self.prefs.hour_cycle.or_else(|| {
+            self.time
+                .pattern_items_for_data_loading(self.options)?
+                .find_map(|item| match item {
+                    PatternItem::Field(Field {
+                        symbol: FieldSymbol::Hour(hour),
+                        ..
+                    }) => Some(hour),
+                    _ => None,
+                })
+        })
+    }
+
     /// Borrows a resolved pattern based on the given datetime
     pub(crate) fn select(&self, input: &DateTimeInputUnchecked) -> DateTimeZonePatternDataBorrowed {
         DateTimeZonePatternDataBorrowed {
@@ -699,6 +745,14 @@ impl<'a> DateTimeZonePatternDataBorrowed<'a> {
     }
 
     pub(crate) fn iter_items(self) -> impl Iterator<Item = PatternItem> + 'a {
+        self.iter_items_with_component().map(|(_, item)| item)
+    }
+
+    /// Same as [`Self::iter_items`], but also returns which of the glued
+    /// patterns each item comes from.
+    pub(crate) fn iter_items_with_component(
+        self,
+    ) -> impl Iterator<Item = (PatternComponent, PatternItem)> + 'a {
         let glue_pattern_slice = match self.glue_pattern() {
             Some(glue) => glue.as_ule_slice(),
             None => runtime::ZERO_ONE_TWO_SLICE.as_ule_slice(),
@@ -707,26 +761,41 @@ impl<'a> DateTimeZonePatternDataBorrowed<'a> {
             .iter()
             .map(
                 move |generic_item_ule| match generic_item_ule.as_pattern_item_ule() {
-                    Ok(pattern_item_ule) => ItemsAndOptions {
-                        items: ZeroSlice::from_ule_slice(core::slice::from_ref(pattern_item_ule)),
-                        ..Default::default()
-                    },
-                    Err(1) => self
-                        .date_pattern()
-                        .map(|p| p.items_and_options())
-                        .unwrap_or(ItemsAndOptions::new_empty()),
-                    Err(0) => self
-                        .time_pattern()
-                        .map(|p| p.items_and_options())
-                        .unwrap_or(ItemsAndOptions::new_empty()),
-                    Err(2) => self
-                        .zone_pattern()
-                        .map(|p| p.items_and_options())
-                        .unwrap_or(ItemsAndOptions::new_empty()),
-                    _ => ItemsAndOptions::new_empty(),
+                    Ok(pattern_item_ule) => (
+                        PatternComponent::Glue,
+                        ItemsAndOptions {
+                            items: ZeroSlice::from_ule_slice(core::slice::from_ref(
+                                pattern_item_ule,
+                            )),
+                            ..Default::default()
+                        },
+                    ),
+                    Err(1) => (
+                        PatternComponent::Date,
+                        self.date_pattern()
+                            .map(|p| p.items_and_options())
+                            .unwrap_or(ItemsAndOptions::new_empty()),
+                    ),
+                    Err(0) => (
+                        PatternComponent::Time,
+                        self.time_pattern()
+                            .map(|p| p.items_and_options())
+                            .unwrap_or(ItemsAndOptions::new_empty()),
+                    ),
+                    Err(2) => (
+                        PatternComponent::Zone,
+                        self.zone_pattern()
+                            .map(|p| p.items_and_options())
+                            .unwrap_or(ItemsAndOptions::new_empty()),
+                    ),
+                    _ => (PatternComponent::Glue, ItemsAndOptions::new_empty()),
                 },
             )
-            .flat_map(|items_and_options| items_and_options.iter_items())
+            .flat_map(|(component, items_and_options)| {
+                items_and_options
+                    .iter_items()
+                    .map(move |item| (component, item))
+            })
     }
 
     pub(crate) fn to_pattern(self) -> DateTimePattern {
@@ -12,9 +12,11 @@ use icu_calendar::cal::Roc;
 use icu_calendar::cal::{self, Chinese};
 use icu_calendar::cal::{
     Buddhist, Coptic, Dangi, Ethiopian, Gregorian, Hebrew, HijriSimulated, HijriTabular,
-    HijriUmmAlQura, Indian, Japanese, JapaneseExtended, Persian,
+    HijriUmmAlQura, Indian, Japanese, JapaneseExtended, Persian, Vietnamese,
+};
+use icu_calendar::{
+    AnyCalendar, AnyCalendarKind, AsCalendar, Date, IntoAnyCalendar, MonthDay, Ref, YearMonth,
 };
-use icu_calendar::{AnyCalendar, AnyCalendarKind, AsCalendar, Date, IntoAnyCalendar, Ref};
 use icu_provider::marker::NeverMarker;
 use icu_provider::prelude::*;
 use icu_time::{
@@ -72,6 +74,13 @@ impl CldrCalendar for Dangi {
     type SkeletaV1 = DatetimePatternsDateDangiV1;
 }
 
+impl CldrCalendar for Vietnamese {
+    // CLDR has no data for the Vietnamese calendar, which uses the same names as the Chinese one
+    type YearNamesV1 = DatetimeNamesYearChineseV1;
+    type MonthNamesV1 = DatetimeNamesMonthChineseV1;
+    type SkeletaV1 = DatetimePatternsDateChineseV1;
+}
+
 impl CldrCalendar for Ethiopian {
     type YearNamesV1 = DatetimeNamesYearEthiopianV1;
     type MonthNamesV1 = DatetimeNamesMonthEthiopianV1;
@@ -154,6 +163,7 @@ impl UnstableSealed for Japanese {}
 impl UnstableSealed for JapaneseExtended {}
 impl UnstableSealed for Persian {}
 impl UnstableSealed for Roc {}
+impl UnstableSealed for Vietnamese {}
 
 /// A collection of marker types associated with all formattable calendars.
 ///
@@ -231,6 +241,9 @@ where
 /// A calendar type that is supported by [`DateTimeFormatter`](crate::DateTimeFormatter).
 ///
 /// [`FixedCalendarDateTimeFormatter`](crate::FixedCalendarDateTimeFormatter) might support additional calendars.
+///
+/// [`BikramSambat`](icu_calendar::cal::BikramSambat) is not supported by either formatter, since
+/// CLDR has no month or era names for it.
 pub trait IntoFormattableAnyCalendar: CldrCalendar + IntoAnyCalendar {}
 
 // keep in sync with FormattableAnyCalendarKind
@@ -249,6 +262,8 @@ impl IntoFormattableAnyCalendar for Japanese {}
 // _NOT_ JapaneseExtended
 impl IntoFormattableAnyCalendar for Persian {}
 impl IntoFormattableAnyCalendar for Roc {}
+impl IntoFormattableAnyCalendar for Vietnamese {}
+// _NOT_ BikramSambat (no CLDR names)
 
 // keep in sync with IntoFormattableAnyCalendar
 #[derive(Debug, Clone, Copy)]
@@ -270,12 +285,15 @@ pub(crate) enum FormattableAnyCalendarKind {
     // _NOT_ JapaneseExtended
     Persian,
     Roc,
+    Vietnamese,
+    // _NOT_ BikramSambat (no CLDR names)
 }
 
 impl FormattableAnyCalendarKind {
     pub(crate) fn try_from_any_calendar_kind(kind: AnyCalendarKind) -> Option<Self> {
         use AnyCalendarKind::*;
         let res = match kind {
+            BikramSambat => return None,
             Buddhist => Self::Buddhist,
             Chinese => Self::Chinese,
             Coptic => Self::Coptic,
@@ -294,6 +312,7 @@ impl FormattableAnyCalendarKind {
             JapaneseExtended => return None,
             Persian => Self::Persian,
             Roc => Self::Roc,
+            Vietnamese => Self::Vietnamese,
             _ => {
                 debug_assert!(false, "cross-crate exhaustive match");
                 return None;
@@ -311,7 +330,8 @@ impl FormattableAnyCalendarKind {
             }
         }
         // Calendar not specified or not supported by DateTimeFormatter
-        // Currently this is CalendarAlgorithm::Iso8601, CalendarAlgorithm::Hijri(Rgsa)
+        // Currently this is CalendarAlgorithm::Iso8601, CalendarAlgorithm::Hijri(Rgsa),
+        // CalendarAlgorithm::Bikram
         // Let AnyCalendarKind constructor select an appropriate fallback
         prefs.calendar_algorithm = None;
         let kind = AnyCalendarKind::new((&prefs).into());
@@ -354,6 +374,11 @@ impl FormattableAnyCalendar {
         self.kind
     }
 
+    #[cfg(feature = "experimental")]
+    pub(crate) fn any_calendar(&self) -> &AnyCalendar {
+        &self.any_calendar
+    }
+
     #[cfg(feature = "compiled_data")]
     pub(crate) fn try_new(kind: FormattableAnyCalendarKind) -> Result<Self, DataError> {
         use FormattableAnyCalendarKind::*;
@@ -381,6 +406,7 @@ impl FormattableAnyCalendar {
             Japanese => AnyCalendar::Japanese(cal::Japanese::new()),
             Persian => AnyCalendar::Persian(cal::Persian),
             Roc => AnyCalendar::Roc(cal::Roc),
+            Vietnamese => AnyCalendar::Vietnamese(cal::Vietnamese::new()),
         };
         Ok(Self { any_calendar, kind })
     }
@@ -420,6 +446,9 @@ impl FormattableAnyCalendar {
             }
             Persian => AnyCalendar::Persian(cal::Persian),
             Roc => AnyCalendar::Roc(cal::Roc),
+            Vietnamese => {
+                AnyCalendar::Vietnamese(cal::Vietnamese::try_new_with_buffer_provider(provider)?)
+            }
         };
         Ok(Self { any_calendar, kind })
     }
@@ -432,7 +461,8 @@ impl FormattableAnyCalendar {
         P: ?Sized
             + DataProvider<icu_calendar::provider::CalendarJapaneseModernV1>
             + DataProvider<icu_calendar::provider::CalendarChineseV1>
-            + DataProvider<icu_calendar::provider::CalendarDangiV1>,
+            + DataProvider<icu_calendar::provider::CalendarDangiV1>
+            + DataProvider<icu_calendar::provider::CalendarVietnameseV1>,
     {
         use FormattableAnyCalendarKind::*;
         let any_calendar = match kind {
@@ -459,6 +489,7 @@ impl FormattableAnyCalendar {
             Japanese => AnyCalendar::Japanese(cal::Japanese::try_new_unstable(provider)?),
             Persian => AnyCalendar::Persian(cal::Persian),
             Roc => AnyCalendar::Roc(cal::Roc),
+            Vietnamese => AnyCalendar::Vietnamese(cal::Vietnamese::try_new_unstable(provider)?),
         };
         Ok(Self { any_calendar, kind })
     }
@@ -539,7 +570,7 @@ where
         let p = &self.provider;
         match self.kind {
             Buddhist => H::Buddhist::bind(p).load_bound(req),
-            Chinese => H::Chinese::bind(p).load_bound(req),
+            Chinese | Vietnamese => H::Chinese::bind(p).load_bound(req),
             Coptic => H::Coptic::bind(p).load_bound(req),
             Dangi => H::Dangi::bind(p).load_bound(req),
             Ethiopian | EthiopianAmeteAlem => H::Ethiopian::bind(p).load_bound(req),
@@ -558,7 +589,7 @@ where
         use FormattableAnyCalendarKind::*;
         match self.kind {
             Buddhist => H::Buddhist::INFO,
-            Chinese => H::Chinese::INFO,
+            Chinese | Vietnamese => H::Chinese::INFO,
             Coptic => H::Coptic::INFO,
             Dangi => H::Dangi::INFO,
             Ethiopian | EthiopianAmeteAlem => H::Ethiopian::INFO,
@@ -707,6 +738,28 @@ impl<C: IntoAnyCalendar, A: AsCalendar<Calendar = C>> InSameCalendar for Date<A>
     }
 }
 
+impl<C: IntoAnyCalendar, A: AsCalendar<Calendar = C>> InSameCalendar for YearMonth<A> {
+    #[inline]
+    fn check_any_calendar_kind(
+        &self,
+        any_calendar_kind: AnyCalendarKind,
+    ) -> Result<(), MismatchedCalendarError> {
+        self.reference_date()
+            .check_any_calendar_kind(any_calendar_kind)
+    }
+}
+
+impl<C: IntoAnyCalendar, A: AsCalendar<Calendar = C>> InSameCalendar for MonthDay<A> {
+    #[inline]
+    fn check_any_calendar_kind(
+        &self,
+        any_calendar_kind: AnyCalendarKind,
+    ) -> Result<(), MismatchedCalendarError> {
+        self.reference_date()
+            .check_any_calendar_kind(any_calendar_kind)
+    }
+}
+
 impl InSameCalendar for Time {
     #[inline]
     fn check_any_calendar_kind(&self, _: AnyCalendarKind) -> Result<(), MismatchedCalendarError> {
@@ -754,6 +807,10 @@ pub trait InFixedCalendar<C> {}
 
 impl<C: CldrCalendar, A: AsCalendar<Calendar = C>> InFixedCalendar<C> for Date<A> {}
 
+impl<C: CldrCalendar, A: AsCalendar<Calendar = C>> InFixedCalendar<C> for YearMonth<A> {}
+
+impl<C: CldrCalendar, A: AsCalendar<Calendar = C>> InFixedCalendar<C> for MonthDay<A> {}
+
 impl<C> InFixedCalendar<C> for Time {}
 
 impl<C: CldrCalendar, A: AsCalendar<Calendar = C>> InFixedCalendar<C> for DateTime<A> {}
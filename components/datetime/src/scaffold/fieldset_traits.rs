@@ -7,7 +7,9 @@ use crate::{
     scaffold::*,
 };
 use icu_calendar::{
-    provider::{CalendarChineseV1, CalendarDangiV1, CalendarJapaneseModernV1},
+    provider::{
+        CalendarChineseV1, CalendarDangiV1, CalendarJapaneseModernV1, CalendarVietnameseV1,
+    },
     types::{DayOfMonth, DayOfYear, MonthInfo, Weekday, YearInfo},
 };
 use icu_decimal::provider::{DecimalDigitsV1, DecimalSymbolsV1};
@@ -505,6 +507,7 @@ impl<T> AllFixedCalendarExternalDataMarkers for T where
 pub trait AllAnyCalendarExternalDataMarkers:
     DataProvider<CalendarChineseV1>
     + DataProvider<CalendarDangiV1>
+    + DataProvider<CalendarVietnameseV1>
     + DataProvider<CalendarJapaneseModernV1>
     + DataProvider<DecimalSymbolsV1>
     + DataProvider<DecimalDigitsV1>
@@ -515,6 +518,7 @@ impl<T> AllAnyCalendarExternalDataMarkers for T where
     T: ?Sized
         + DataProvider<CalendarChineseV1>
         + DataProvider<CalendarDangiV1>
+        + DataProvider<CalendarVietnameseV1>
         + DataProvider<CalendarJapaneseModernV1>
         + DataProvider<DecimalSymbolsV1>
         + DataProvider<DecimalDigitsV1>
@@ -4,7 +4,7 @@
 
 use icu_calendar::{
     types::{DayOfMonth, DayOfYear, MonthInfo, Weekday, YearInfo},
-    AsCalendar, Calendar, Date,
+    AsCalendar, Calendar, Date, MonthDay, YearMonth,
 };
 use icu_time::{
     zone::{models::TimeZoneModel, TimeZoneVariant, UtcOffset, ZoneNameTimestamp},
@@ -73,6 +73,38 @@ impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<DayOfYear> for Date<A> {
     }
 }
 
+impl<C: Calendar, A: AsCalendar<Calendar = C>> UnstableSealed for YearMonth<A> {}
+
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<YearInfo> for YearMonth<A> {
+    #[inline]
+    fn get_field(&self) -> YearInfo {
+        self.year()
+    }
+}
+
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<MonthInfo> for YearMonth<A> {
+    #[inline]
+    fn get_field(&self) -> MonthInfo {
+        self.month()
+    }
+}
+
+impl<C: Calendar, A: AsCalendar<Calendar = C>> UnstableSealed for MonthDay<A> {}
+
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<MonthInfo> for MonthDay<A> {
+    #[inline]
+    fn get_field(&self) -> MonthInfo {
+        self.month()
+    }
+}
+
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<DayOfMonth> for MonthDay<A> {
+    #[inline]
+    fn get_field(&self) -> DayOfMonth {
+        self.day_of_month()
+    }
+}
+
 impl UnstableSealed for Time {}
 
 impl GetField<Hour> for Time {
@@ -331,6 +363,16 @@ impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<()> for Date<A> {
     fn get_field(&self) {}
 }
 
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<()> for YearMonth<A> {
+    #[inline]
+    fn get_field(&self) {}
+}
+
+impl<C: Calendar, A: AsCalendar<Calendar = C>> GetField<()> for MonthDay<A> {
+    #[inline]
+    fn get_field(&self) {}
+}
+
 impl GetField<()> for Time {
     #[inline]
     fn get_field(&self) {}
@@ -106,3 +106,59 @@ fn test_date_and_time() {
         "en-u-hc-h23".parse::<Locale>().unwrap(),
     );
 }
+
+#[test]
+fn test_resolved_preferences() {
+    use icu_datetime::DateTimeFormatter;
+    use icu_locale_core::preferences::extensions::unicode::keywords::CalendarAlgorithm;
+    use icu_locale_core::subtags::subtag;
+
+    // Dates only
+    let dtf = DateTimeFormatter::try_new(
+        "de-CH-u-ca-hebrew".parse::<Locale>().unwrap().into(),
+        fieldsets::YMD::medium(),
+    )
+    .unwrap();
+    let resolved = dtf.resolved_preferences();
+    assert_eq!(Locale::from(resolved.locale_preferences), locale!("de"));
+    assert_eq!(resolved.calendar_algorithm, Some(CalendarAlgorithm::Hebrew));
+    assert_eq!(resolved.hour_cycle, None);
+    assert_eq!(resolved.numbering_system.as_deref(), Some(&subtag!("latn")));
+
+    // Hour cycle from the locale data
+    let dtf = DateTimeFormatter::try_new(locale!("en-US").into(), fieldsets::T::medium()).unwrap();
+    let resolved = dtf.resolved_preferences();
+    assert_eq!(
+        resolved.calendar_algorithm,
+        Some(CalendarAlgorithm::Gregory)
+    );
+    assert_eq!(resolved.hour_cycle, Some(HourCycle::H12));
+
+    let dtf = DateTimeFormatter::try_new(locale!("ja").into(), fieldsets::YMDT::medium()).unwrap();
+    let resolved = dtf.resolved_preferences();
+    assert_eq!(Locale::from(resolved.locale_preferences), locale!("ja"));
+    assert_eq!(
+        resolved.calendar_algorithm,
+        Some(CalendarAlgorithm::Gregory)
+    );
+    assert_eq!(resolved.hour_cycle, Some(HourCycle::H23));
+
+    // Hour cycle from the preferences
+    let dtf = DateTimeFormatter::try_new(
+        "ja-u-hc-h11".parse::<Locale>().unwrap().into(),
+        fieldsets::T::medium(),
+    )
+    .unwrap();
+    assert_eq!(dtf.resolved_preferences().hour_cycle, Some(HourCycle::H11));
+
+    // Numbering system without data falls back to the default
+    let dtf = DateTimeFormatter::try_new(
+        "ar-EG-u-nu-wxyz".parse::<Locale>().unwrap().into(),
+        fieldsets::YMD::medium(),
+    )
+    .unwrap();
+    assert_eq!(
+        dtf.resolved_preferences().numbering_system.as_deref(),
+        Some(&subtag!("arab"))
+    );
+}
@@ -2,13 +2,15 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_calendar::cal::Hebrew;
+use icu_calendar::cal::{Chinese, Hebrew, Vietnamese};
 use icu_calendar::Date;
 use icu_datetime::fieldsets::enums::{
     CompositeDateTimeFieldSet, DateAndTimeFieldSet, DateFieldSet,
 };
 use icu_datetime::fieldsets::{self, YMD};
-use icu_datetime::{DateTimeFormatterPreferences, FixedCalendarDateTimeFormatter};
+use icu_datetime::{
+    DateTimeFormatter, DateTimeFormatterPreferences, FixedCalendarDateTimeFormatter,
+};
 use icu_locale_core::{locale, Locale};
 use icu_time::{DateTime, Time};
 use writeable::assert_writeable_eq;
@@ -189,6 +191,46 @@ fn hebrew_months() {
     assert_writeable_eq!(formatted_datetime, "28 Adar II 5771");
 }
 
+#[test]
+fn vietnamese_uses_chinese_names() {
+    let vietnamese_formatter =
+        FixedCalendarDateTimeFormatter::<Vietnamese, _>::try_new(locale!("en").into(), YMD::long())
+            .unwrap();
+    let chinese_formatter =
+        FixedCalendarDateTimeFormatter::<Chinese, _>::try_new(locale!("en").into(), YMD::long())
+            .unwrap();
+
+    // Tết and the Chinese New Year of 1985 are a month apart
+    let tet = Date::try_new_iso(1985, 1, 21)
+        .unwrap()
+        .to_calendar(Vietnamese::new());
+    let chinese_new_year = Date::try_new_iso(1985, 2, 20)
+        .unwrap()
+        .to_calendar(Chinese::new());
+    assert_eq!(
+        vietnamese_formatter.format(&tet).to_string(),
+        chinese_formatter.format(&chinese_new_year).to_string()
+    );
+
+    let formatter =
+        DateTimeFormatter::try_new(locale!("vi-u-ca-vietnam").into(), YMD::long()).unwrap();
+    assert_eq!(
+        formatter.calendar().kind(),
+        icu_calendar::AnyCalendarKind::Vietnamese
+    );
+}
+
+#[test]
+fn bikram_sambat_is_not_formattable() {
+    // There is no CLDR data for the Bikram Sambat calendar
+    let formatter =
+        DateTimeFormatter::try_new(locale!("ne-u-ca-bikram").into(), YMD::long()).unwrap();
+    assert_eq!(
+        formatter.calendar().kind(),
+        icu_calendar::AnyCalendarKind::Gregorian
+    );
+}
+
 #[test]
 fn test_5387() {
     let datetime = DateTime {
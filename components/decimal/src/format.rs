@@ -12,6 +12,7 @@ use crate::parts;
 use crate::provider::*;
 use fixed_decimal::Decimal;
 use fixed_decimal::Sign;
+use fixed_decimal::SignDisplay;
 use writeable::Part;
 use writeable::PartsWrite;
 use writeable::Writeable;
@@ -24,6 +25,18 @@ pub struct FormattedDecimal<'l> {
     pub(crate) options: &'l DecimalFormatterOptions,
     pub(crate) symbols: &'l DecimalSymbols<'l>,
     pub(crate) digits: &'l [char; 10],
+    /// Set if the value is displayed in scientific or engineering notation.
+    pub(crate) scientific: Option<Scientific<'l>>,
+}
+
+/// The data needed to display a number in scientific or engineering notation.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub(crate) struct Scientific<'l> {
+    pub(crate) options: &'l ScientificDecimalFormatterOptions,
+    pub(crate) symbols: &'l DecimalExponentSymbols<'l>,
+    /// The exponent of a [`ScientificDecimal`](fixed_decimal::ScientificDecimal), if `value`
+    /// is its significand.
+    pub(crate) exponent: Option<&'l Decimal>,
 }
 
 impl FormattedDecimal<'_> {
@@ -35,18 +48,47 @@ impl FormattedDecimal<'_> {
             Sign::Positive => Some((parts::PLUS_SIGN, self.symbols.plus_sign_affixes())),
         }
     }
-}
 
-impl Writeable for FormattedDecimal<'_> {
-    fn write_to_parts<W>(&self, w: &mut W) -> core::result::Result<(), core::fmt::Error>
+    /// Returns the significand and the exponent to display in scientific or engineering notation.
+    fn to_scientific(&self, scientific: Scientific) -> (Decimal, Decimal) {
+        let mut significand = self.value.clone();
+        let exponent = if self.value.absolute.is_zero() {
+            0
+        } else {
+            let magnitude = self.value.absolute.nonzero_magnitude_start();
+            let exponent = match scientific.options.notation.unwrap_or_default() {
+                Notation::Engineering => {
+                    i16::try_from(i32::from(magnitude).div_euclid(3) * 3).unwrap_or(magnitude)
+                }
+                _ => magnitude,
+            };
+            // Trailing zeros of the integer part are not significant, those of the fraction are.
+            let lower_magnitude = *self.value.absolute.magnitude_range().start();
+            significand.multiply_pow10(exponent.saturating_neg());
+            significand.absolute.trim_start();
+            significand.absolute.pad_end(if lower_magnitude < 0 {
+                lower_magnitude.saturating_sub(exponent)
+            } else {
+                0
+            });
+            exponent
+        };
+
+        let settings = scientific.symbols.settings;
+        let mut exponent = Decimal::from(exponent);
+        exponent.absolute.pad_start(settings.min_digits.into());
+        if settings.plus_sign {
+            exponent.apply_sign_display(SignDisplay::Always);
+        }
+        (significand, exponent)
+    }
+
+    /// Writes the digits of `value`, without its sign.
+    fn write_digits<W>(&self, value: &Decimal, grouping: bool, w: &mut W) -> core::fmt::Result
     where
         W: writeable::PartsWrite + ?Sized,
     {
-        let affixes = self.get_affixes();
-        if let Some((part, affixes)) = affixes {
-            w.with_part(part, |w| w.write_str(affixes.0))?;
-        }
-        let range = self.value.absolute.magnitude_range();
+        let range = value.absolute.magnitude_range();
         let upper_magnitude = *range.end();
         let mut range = range.rev();
         let mut has_fraction = false;
@@ -63,13 +105,15 @@ impl Writeable for FormattedDecimal<'_> {
                     }
                 };
                 #[expect(clippy::indexing_slicing)] // digit_at in 0..=9
-                w.write_char(self.digits[self.value.digit_at(m) as usize])?;
-                if grouper::check(
-                    upper_magnitude,
-                    m,
-                    self.options.grouping_strategy.unwrap_or_default(),
-                    self.symbols.grouping_sizes,
-                ) {
+                w.write_char(self.digits[value.digit_at(m) as usize])?;
+                if grouping
+                    && grouper::check(
+                        upper_magnitude,
+                        m,
+                        self.options.grouping_strategy.unwrap_or_default(),
+                        self.symbols.grouping_sizes,
+                    )
+                {
                     w.with_part(parts::GROUP, |w| {
                         w.write_str(self.symbols.grouping_separator())
                     })?;
@@ -84,7 +128,7 @@ impl Writeable for FormattedDecimal<'_> {
                 let mut m = -1; // read in the previous loop
                 loop {
                     #[expect(clippy::indexing_slicing)] // digit_at in 0..=9
-                    w.write_char(self.digits[self.value.digit_at(m) as usize])?;
+                    w.write_char(self.digits[value.digit_at(m) as usize])?;
                     m = match range.next() {
                         Some(m) => m,
                         None => {
@@ -94,6 +138,100 @@ impl Writeable for FormattedDecimal<'_> {
                 }
             })?;
         }
+        Ok(())
+    }
+
+    /// Writes the exponential symbol and the exponent.
+    fn write_exponent<W>(
+        &self,
+        scientific: Scientific,
+        exponent: &Decimal,
+        w: &mut W,
+    ) -> core::fmt::Result
+    where
+        W: writeable::PartsWrite + ?Sized,
+    {
+        let sign_part = match exponent.sign() {
+            Sign::None => None,
+            Sign::Negative => Some(parts::EXPONENT_MINUS_SIGN),
+            Sign::Positive => Some(parts::EXPONENT_PLUS_SIGN),
+        };
+
+        if scientific.options.exponent_style.unwrap_or_default() == ExponentStyle::Superscript
+            && *self.digits == ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']
+        {
+            w.with_part(parts::EXPONENT_SEPARATOR, |w| {
+                w.write_str(&scientific.symbols.superscripting_exponent)?;
+                w.write_str("10")
+            })?;
+            if let Some(part) = sign_part {
+                w.with_part(part, |w| {
+                    w.write_char(if part == parts::EXPONENT_MINUS_SIGN {
+                        '⁻'
+                    } else {
+                        '⁺'
+                    })
+                })?;
+            }
+            return w.with_part(parts::EXPONENT_INTEGER, |w| {
+                for m in exponent.absolute.magnitude_range().rev() {
+                    #[expect(clippy::indexing_slicing)] // digit_at in 0..=9
+                    w.write_char(SUPERSCRIPT_DIGITS[exponent.digit_at(m) as usize])?;
+                }
+                Ok(())
+            });
+        }
+
+        w.with_part(parts::EXPONENT_SEPARATOR, |w| {
+            w.write_str(&scientific.symbols.exponential)
+        })?;
+        let affixes = match exponent.sign() {
+            Sign::None => None,
+            Sign::Negative => Some(self.symbols.minus_sign_affixes()),
+            Sign::Positive => Some(self.symbols.plus_sign_affixes()),
+        };
+        if let (Some(part), Some(affixes)) = (sign_part, affixes) {
+            w.with_part(part, |w| w.write_str(affixes.0))?;
+        }
+        w.with_part(parts::EXPONENT_INTEGER, |w| {
+            for m in exponent.absolute.magnitude_range().rev() {
+                #[expect(clippy::indexing_slicing)] // digit_at in 0..=9
+                w.write_char(self.digits[exponent.digit_at(m) as usize])?;
+            }
+            Ok(())
+        })?;
+        if let (Some(part), Some(affixes)) = (sign_part, affixes) {
+            w.with_part(part, |w| w.write_str(affixes.1))?;
+        }
+        Ok(())
+    }
+}
+
+const SUPERSCRIPT_DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+impl Writeable for FormattedDecimal<'_> {
+    fn write_to_parts<W>(&self, w: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: writeable::PartsWrite + ?Sized,
+    {
+        let affixes = self.get_affixes();
+        if let Some((part, affixes)) = affixes {
+            w.with_part(part, |w| w.write_str(affixes.0))?;
+        }
+        match self.scientific {
+            None => self.write_digits(self.value, true, w)?,
+            Some(scientific) => match scientific.exponent {
+                Some(exponent) => {
+                    self.write_digits(self.value, false, w)?;
+                    self.write_exponent(scientific, exponent, w)?;
+                }
+                None => {
+                    let (significand, exponent) = self.to_scientific(scientific);
+                    self.write_digits(&significand, false, w)?;
+                    self.write_exponent(scientific, &exponent, w)?;
+                }
+            },
+        }
         if let Some((part, affixes)) = affixes {
             w.with_part(part, |w| w.write_str(affixes.1))?;
         }
@@ -107,8 +245,12 @@ writeable::impl_display_with_writeable!(FormattedDecimal<'_>);
 mod tests {
     use icu_locale_core::locale;
     use writeable::assert_writeable_eq;
+    use writeable::assert_writeable_parts_eq;
 
+    use crate::options::*;
+    use crate::parts;
     use crate::DecimalFormatter;
+    use crate::ScientificDecimalFormatter;
 
     #[test]
     pub fn test_es_mx() {
@@ -117,4 +259,97 @@ mod tests {
         let fd = "12345.67".parse().unwrap();
         assert_writeable_eq!(fmt.format(&fd), "12,345.67");
     }
+
+    #[test]
+    pub fn test_scientific() {
+        let en =
+            ScientificDecimalFormatter::try_new(locale!("en").into(), Notation::Scientific.into())
+                .unwrap();
+        let engineering =
+            ScientificDecimalFormatter::try_new(locale!("en").into(), Notation::Engineering.into())
+                .unwrap();
+        let superscript = ScientificDecimalFormatter::try_new(
+            locale!("en").into(),
+            ExponentStyle::Superscript.into(),
+        )
+        .unwrap();
+
+        for (input, scientific, engineering_expected, superscript_expected) in [
+            ("0", "0E0", "0E0", "0×10⁰"),
+            ("0.00", "0.00E0", "0.00E0", "0.00×10⁰"),
+            ("7", "7E0", "7E0", "7×10⁰"),
+            ("1200", "1.2E3", "1.2E3", "1.2×10³"),
+            ("1200.0", "1.2000E3", "1.2000E3", "1.2000×10³"),
+            ("0012", "1.2E1", "12E0", "1.2×10¹"),
+            ("-123456", "-1.23456E5", "-123.456E3", "-1.23456×10⁵"),
+            ("0.0123", "1.23E-2", "12.3E-3", "1.23×10⁻²"),
+            ("0.001230", "1.230E-3", "1.230E-3", "1.230×10⁻³"),
+            ("+1e30", "+1E30", "+1E30", "+1×10³⁰"),
+        ] {
+            let decimal = input.parse().unwrap();
+            assert_writeable_eq!(en.format(&decimal), scientific, "{input}");
+            assert_writeable_eq!(
+                engineering.format(&decimal),
+                engineering_expected,
+                "{input}"
+            );
+            assert_writeable_eq!(
+                superscript.format(&decimal),
+                superscript_expected,
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn test_scientific_localized() {
+        let mut options = ScientificDecimalFormatterOptions::from(Notation::Scientific);
+        let decimal = "-0.00123".parse().unwrap();
+
+        let ar_eg = ScientificDecimalFormatter::try_new(locale!("ar-EG").into(), options).unwrap();
+        assert_writeable_eq!(ar_eg.format(&decimal), "\u{61c}-١٫٢٣أس\u{61c}-٣");
+
+        // Superscripts only exist for Latin digits
+        options.exponent_style = Some(ExponentStyle::Superscript);
+        let th =
+            ScientificDecimalFormatter::try_new(locale!("th-u-nu-thai").into(), options).unwrap();
+        assert_writeable_eq!(th.format(&decimal), "-๑.๒๓E-๓");
+        let fa =
+            ScientificDecimalFormatter::try_new(locale!("fa-u-nu-latn").into(), options).unwrap();
+        assert_writeable_eq!(fa.format(&decimal), "\u{200e}−1.23×10⁻³");
+    }
+
+    #[test]
+    pub fn test_scientific_parts() {
+        let fmt =
+            ScientificDecimalFormatter::try_new(locale!("en").into(), Default::default()).unwrap();
+        let decimal = "-0.0012".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fmt.format(&decimal),
+            "-1.2E-3",
+            [
+                (0, 1, parts::MINUS_SIGN),
+                (1, 2, parts::INTEGER),
+                (2, 3, parts::DECIMAL),
+                (3, 4, parts::FRACTION),
+                (4, 5, parts::EXPONENT_SEPARATOR),
+                (5, 6, parts::EXPONENT_MINUS_SIGN),
+                (6, 7, parts::EXPONENT_INTEGER),
+            ]
+        );
+
+        let scientific = "1.20e+06".parse().unwrap();
+        assert_writeable_parts_eq!(
+            fmt.format_scientific(&scientific),
+            "1.20E+06",
+            [
+                (0, 1, parts::INTEGER),
+                (1, 2, parts::DECIMAL),
+                (2, 4, parts::FRACTION),
+                (4, 5, parts::EXPONENT_SEPARATOR),
+                (5, 6, parts::EXPONENT_PLUS_SIGN),
+                (6, 8, parts::EXPONENT_INTEGER),
+            ]
+        );
+    }
 }
@@ -96,11 +96,17 @@ extern crate alloc;
 mod format;
 mod grouper;
 pub mod options;
+mod parse;
 pub mod parts;
 pub mod provider;
+mod scientific;
 pub(crate) mod size_test_macro;
 
 pub use format::FormattedDecimal;
+#[doc(hidden)] // unstable
+pub use parse::{DecimalParseError, DecimalParser};
+#[doc(hidden)] // unstable
+pub use scientific::ScientificDecimalFormatter;
 
 use fixed_decimal::Decimal;
 use icu_locale_core::locale;
@@ -108,7 +114,7 @@ use icu_locale_core::preferences::define_preferences;
 use icu_provider::prelude::*;
 use size_test_macro::size_test;
 
-size_test!(DecimalFormatter, decimal_formatter_size, 96);
+size_test!(DecimalFormatter, decimal_formatter_size, 128);
 
 define_preferences!(
     /// The preferences for fixed decimal formatting.
@@ -119,8 +125,8 @@ define_preferences!(
         ///
         /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
         ///
-        /// To get the resolved numbering system, you can inspect the data provider.
-        /// See the [`provider`] module for an example.
+        /// To get the resolved numbering system, see
+        /// [`DecimalFormatter::resolved_preferences`].
         numbering_system: preferences::NumberingSystem
     }
 );
@@ -139,6 +145,7 @@ pub mod input {
     pub use fixed_decimal::Decimal;
     #[cfg(feature = "ryu")]
     pub use fixed_decimal::FloatPrecision;
+    pub use fixed_decimal::ScientificDecimal;
     pub use fixed_decimal::SignDisplay;
 }
 
@@ -150,7 +157,7 @@ pub mod input {
 /// 2. Locale-sensitive grouping separator positions
 /// 3. Locale-sensitive plus and minus signs
 ///
-/// To get the resolved numbering system, see [`provider`].
+/// To get the resolved locale and numbering system, see [`DecimalFormatter::resolved_preferences`].
 ///
 /// See the crate-level documentation for examples.
 #[doc = decimal_formatter_size!()]
@@ -159,6 +166,7 @@ pub struct DecimalFormatter {
     options: options::DecimalFormatterOptions,
     symbols: DataPayload<provider::DecimalSymbolsV1>,
     digits: DataPayload<provider::DecimalDigitsV1>,
+    resolved_preferences: DecimalFormatterPreferences,
 }
 
 impl AsRef<DecimalFormatter> for DecimalFormatter {
@@ -181,105 +189,49 @@ impl DecimalFormatter {
         prefs: DecimalFormatterPreferences,
         options: options::DecimalFormatterOptions,
     ) -> Result<Self, DataError> {
-        let locale = provider::DecimalSymbolsV1::make_locale(prefs.locale_preferences);
-        let provided_nu = prefs.numbering_system.as_ref().map(|s| s.as_str());
-
-        // In case the user explicitly specified a numbering system, use digits from that numbering system. In case of explicitly specified numbering systems,
-        // the resolved one may end up being different due to a lack of data or fallback, e.g. attempting to resolve en-u-nu-thai will likely produce en-u-nu-Latn data.
-        //
-        // This correctly handles the following cases:
-        // - Explicitly specified numbering system that is the same as the resolved numbering system: This code effects no change
-        // - Explicitly specified numbering system that is different from the resolved one: This code overrides it, but the symbols are still correctly loaded for the locale
-        // - No explicitly specified numbering system: The default numbering system for the locale is used.
-        // - Explicitly specified numbering system without data for it: this falls back to the resolved numbering system
-        //
-        // Assuming the provider has symbols for en-u-nu-latn, th-u-nu-thai (default for th), and th-u-nu-latin, this produces the following behavior:
-        //
-        // | Input Locale | Symbols | Digits | Return value of `numbering_system()` |
-        // |--------------|---------|--------|--------------------------------------|
-        // | en           | latn    | latn   | latn                                 |
-        // | en-u-nu-thai | latn    | thai   | thai                                 |
-        // | th           | thai    | thai   | thai                                 |
-        // | th-u-nu-latn | latn    | latn   | latn                                 |
-        // | en-u-nu-wxyz | latn    | latn   | latn                                 |
-        // | th-u-nu-wxyz | thai    | thai   | thai                                 |
-
-        if let Some(provided_nu) = provided_nu {
-            // Load symbols for the locale/numsys pair provided
-            let symbols: DataPayload<provider::DecimalSymbolsV1> = provider
-                .load(DataRequest {
-                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
-                        DataMarkerAttributes::from_str_or_panic(provided_nu),
-                        &locale,
-                    ),
-                    ..Default::default()
-                })
-                // If it doesn't exist, fall back to the locale
-                .or_else(|_err| {
-                    provider.load(DataRequest {
-                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
-                            DataMarkerAttributes::empty(),
-                            &locale,
-                        ),
-                        ..Default::default()
-                    })
-                })?
-                .payload;
-
-            let resolved_nu = symbols.get().numsys();
-
-            // Attempt to load the provided numbering system first
-            let digits = provider
-                .load(DataRequest {
-                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
-                        DataMarkerAttributes::from_str_or_panic(provided_nu),
-                        &locale!("und").into(),
-                    ),
-                    ..Default::default()
-                })
-                .or_else(|_err| {
-                    provider.load(DataRequest {
-                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
-                            DataMarkerAttributes::from_str_or_panic(resolved_nu),
-                            &locale!("und").into(),
-                        ),
-                        ..Default::default()
-                    })
-                })?
-                .payload;
-            Ok(Self {
-                options,
-                symbols,
-                digits,
-            })
-        } else {
-            let symbols: DataPayload<provider::DecimalSymbolsV1> = provider
-                .load(DataRequest {
-                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
-                        DataMarkerAttributes::empty(),
-                        &locale,
-                    ),
-                    ..Default::default()
-                })?
-                .payload;
-
-            let resolved_nu = symbols.get().numsys();
+        let (symbols, digits, resolved_preferences) = load_symbols_and_digits(provider, prefs)?;
+        Ok(Self {
+            options,
+            symbols,
+            digits,
+            resolved_preferences,
+        })
+    }
 
-            let digits = provider
-                .load(DataRequest {
-                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
-                        DataMarkerAttributes::from_str_or_panic(resolved_nu),
-                        &locale!("und").into(),
-                    ),
-                    ..Default::default()
-                })?
-                .payload;
-            Ok(Self {
-                options,
-                symbols,
-                digits,
-            })
-        }
+    /// Returns the preferences that this [`DecimalFormatter`] resolved to.
+    ///
+    /// The locale is the locale of the symbols data that was loaded, after locale fallback,
+    /// as reported by the data provider in [`DataResponseMetadata::locale`], or the requested
+    /// locale if the provider does not report it. Since data providers deduplicate data that
+    /// is inherited from a parent locale, this is the least specific locale with the same
+    /// data, which might be `und`.
+    ///
+    /// [`DataResponseMetadata::locale`]: icu_provider::DataResponseMetadata::locale
+    ///
+    /// The numbering system is the one whose digits are used, which is the requested
+    /// numbering system if there is data for it, and the default numbering system of the
+    /// locale otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::DecimalFormatter;
+    /// use icu::locale::subtags::subtag;
+    /// use icu::locale::{locale, Locale};
+    ///
+    /// // es-MX uses the data for es-419, and there is no data for the numbering system "wxyz"
+    /// let formatter = DecimalFormatter::try_new(
+    ///     locale!("es-MX-u-nu-wxyz").into(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let resolved = formatter.resolved_preferences();
+    /// assert_eq!(Locale::from(resolved.locale_preferences), locale!("es-419"));
+    /// assert_eq!(resolved.numbering_system.as_deref(), Some(&subtag!("latn")));
+    /// ```
+    pub fn resolved_preferences(&self) -> DecimalFormatterPreferences {
+        self.resolved_preferences
     }
 
     /// Formats a [`Decimal`], returning a [`FormattedDecimal`].
@@ -289,6 +241,7 @@ impl DecimalFormatter {
             options: &self.options,
             symbols: self.symbols.get(),
             digits: self.digits.get(),
+            scientific: None,
         }
     }
 
@@ -300,6 +253,135 @@ impl DecimalFormatter {
     }
 }
 
+/// Loads the symbols and digits for the given preferences, shared by [`DecimalFormatter`]
+/// and [`DecimalParser`].
+fn load_symbols_and_digits<
+    D: DataProvider<provider::DecimalSymbolsV1> + DataProvider<provider::DecimalDigitsV1> + ?Sized,
+>(
+    provider: &D,
+    prefs: DecimalFormatterPreferences,
+) -> Result<
+    (
+        DataPayload<provider::DecimalSymbolsV1>,
+        DataPayload<provider::DecimalDigitsV1>,
+        DecimalFormatterPreferences,
+    ),
+    DataError,
+> {
+    let locale = provider::DecimalSymbolsV1::make_locale(prefs.locale_preferences);
+    let provided_nu = prefs.numbering_system.as_ref().map(|s| s.as_str());
+
+    // In case the user explicitly specified a numbering system, use digits from that numbering system. In case of explicitly specified numbering systems,
+    // the resolved one may end up being different due to a lack of data or fallback, e.g. attempting to resolve en-u-nu-thai will likely produce en-u-nu-Latn data.
+    //
+    // This correctly handles the following cases:
+    // - Explicitly specified numbering system that is the same as the resolved numbering system: This code effects no change
+    // - Explicitly specified numbering system that is different from the resolved one: This code overrides it, but the symbols are still correctly loaded for the locale
+    // - No explicitly specified numbering system: The default numbering system for the locale is used.
+    // - Explicitly specified numbering system without data for it: this falls back to the resolved numbering system
+    //
+    // Assuming the provider has symbols for en-u-nu-latn, th-u-nu-thai (default for th), and th-u-nu-latin, this produces the following behavior:
+    //
+    // | Input Locale | Symbols | Digits | Return value of `numbering_system()` |
+    // |--------------|---------|--------|--------------------------------------|
+    // | en           | latn    | latn   | latn                                 |
+    // | en-u-nu-thai | latn    | thai   | thai                                 |
+    // | th           | thai    | thai   | thai                                 |
+    // | th-u-nu-latn | latn    | latn   | latn                                 |
+    // | en-u-nu-wxyz | latn    | latn   | latn                                 |
+    // | th-u-nu-wxyz | thai    | thai   | thai                                 |
+
+    if let Some(provided_nu) = provided_nu {
+        // Load symbols for the locale/numsys pair provided
+        let symbols: DataResponse<provider::DecimalSymbolsV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    DataMarkerAttributes::from_str_or_panic(provided_nu),
+                    &locale,
+                ),
+                ..Default::default()
+            })
+            // If it doesn't exist, fall back to the locale
+            .or_else(|_err| {
+                provider.load(DataRequest {
+                    id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                        DataMarkerAttributes::empty(),
+                        &locale,
+                    ),
+                    ..Default::default()
+                })
+            })?;
+        let resolved_locale = symbols.metadata.locale.unwrap_or(locale);
+        let symbols = symbols.payload;
+
+        let resolved_nu = symbols.get().numsys();
+
+        // Attempt to load the provided numbering system first
+        let (digits, resolved_nu) = match provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                DataMarkerAttributes::from_str_or_panic(provided_nu),
+                &locale!("und").into(),
+            ),
+            ..Default::default()
+        }) {
+            Ok(response) => (response.payload, provided_nu),
+            Err(_) => (
+                provider
+                    .load(DataRequest {
+                        id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                            DataMarkerAttributes::from_str_or_panic(resolved_nu),
+                            &locale!("und").into(),
+                        ),
+                        ..Default::default()
+                    })?
+                    .payload,
+                resolved_nu,
+            ),
+        };
+        let resolved_preferences = resolve_preferences(resolved_locale, resolved_nu);
+        Ok((symbols, digits, resolved_preferences))
+    } else {
+        let symbols: DataResponse<provider::DecimalSymbolsV1> = provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                DataMarkerAttributes::empty(),
+                &locale,
+            ),
+            ..Default::default()
+        })?;
+        let resolved_locale = symbols.metadata.locale.unwrap_or(locale);
+        let symbols = symbols.payload;
+
+        let resolved_nu = symbols.get().numsys();
+
+        let digits = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    DataMarkerAttributes::from_str_or_panic(resolved_nu),
+                    &locale!("und").into(),
+                ),
+                ..Default::default()
+            })?
+            .payload;
+        let resolved_preferences = resolve_preferences(resolved_locale, resolved_nu);
+        Ok((symbols, digits, resolved_preferences))
+    }
+}
+
+/// Builds the preferences reported by [`DecimalFormatter::resolved_preferences`].
+fn resolve_preferences(locale: DataLocale, numbering_system: &str) -> DecimalFormatterPreferences {
+    DecimalFormatterPreferences {
+        locale_preferences: locale.into(),
+        numbering_system: icu_locale_core::subtags::Subtag::try_from_str(numbering_system)
+            .ok()
+            .and_then(|subtag| {
+                preferences::NumberingSystem::try_from(
+                    icu_locale_core::extensions::unicode::Value::from_subtag(Some(subtag)),
+                )
+                .ok()
+            }),
+    }
+}
+
 #[test]
 fn test_numbering_resolution_fallback() {
     fn test_locale(locale: icu_locale_core::Locale, expected_format: &str) {
@@ -327,3 +409,48 @@ fn test_numbering_resolution_fallback() {
     test_locale(locale!("en-u-nu-wxyz"), "1,234");
     test_locale(locale!("ar-EG-u-nu-wxyz"), "١٬٢٣٤");
 }
+
+#[test]
+fn test_resolved_preferences() {
+    use icu_locale_core::subtags::subtag;
+    use icu_locale_core::Locale;
+
+    fn test_locale(
+        locale: Locale,
+        expected_locale: Locale,
+        expected_nu: icu_locale_core::subtags::Subtag,
+    ) {
+        let formatter =
+            DecimalFormatter::try_new((&locale).into(), Default::default()).expect("Must load");
+        let resolved = formatter.resolved_preferences();
+        assert_eq!(
+            Locale::from(resolved.locale_preferences),
+            expected_locale,
+            "Resolved locale for {locale}"
+        );
+        assert_eq!(
+            resolved.numbering_system.as_deref(),
+            Some(&expected_nu),
+            "Resolved numbering system for {locale}"
+        );
+    }
+
+    // en data is the same as root data
+    test_locale(locale!("en"), locale!("und"), subtag!("latn"));
+    test_locale(locale!("en-u-nu-arab"), locale!("und"), subtag!("arab"));
+    test_locale(locale!("de-LU"), locale!("de"), subtag!("latn"));
+    test_locale(locale!("es-MX"), locale!("es-419"), subtag!("latn"));
+    test_locale(locale!("sr-Latn-ME"), locale!("sr-Latn"), subtag!("latn"));
+    test_locale(locale!("ar-EG"), locale!("ar-EG"), subtag!("arab"));
+    test_locale(
+        locale!("ar-EG-u-nu-latn"),
+        locale!("ar-EG"),
+        subtag!("latn"),
+    );
+    test_locale(
+        locale!("ar-EG-u-nu-thai"),
+        locale!("ar-EG"),
+        subtag!("thai"),
+    );
+    test_locale(locale!("de-LU-u-nu-wxyz"), locale!("de"), subtag!("latn"));
+}
@@ -66,3 +66,178 @@ pub enum GroupingStrategy {
     /// grouping separators, but numbers 10,000 and above will.
     Min2,
 }
+
+#[doc(hidden)] // unstable
+/// A bag of options defining how numbers will be formatted by
+/// [`ScientificDecimalFormatter`](crate::ScientificDecimalFormatter).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[non_exhaustive]
+pub struct ScientificDecimalFormatterOptions {
+    /// Whether to display the number in scientific or engineering notation.
+    ///
+    /// Default is [`Notation::Scientific`]
+    pub notation: Option<Notation>,
+
+    /// How to display the exponent.
+    ///
+    /// Default is [`ExponentStyle::Symbol`]
+    pub exponent_style: Option<ExponentStyle>,
+}
+
+impl From<Notation> for ScientificDecimalFormatterOptions {
+    fn from(notation: Notation) -> Self {
+        Self {
+            notation: Some(notation),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ExponentStyle> for ScientificDecimalFormatterOptions {
+    fn from(exponent_style: ExponentStyle) -> Self {
+        Self {
+            exponent_style: Some(exponent_style),
+            ..Default::default()
+        }
+    }
+}
+
+#[doc(hidden)] // unstable
+/// Configuration for how to choose the exponent of a number.
+///
+/// In scientific and engineering notation, the digits of the number are kept as they are,
+/// except that trailing zeros of its integer part are not shown. Round the number before
+/// formatting it to limit the number of significant digits.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::input::Decimal;
+/// use icu::decimal::options::Notation;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let decimal = "-12345.0".parse::<Decimal>().unwrap();
+///
+/// let formatter = ScientificDecimalFormatter::try_new(
+///     locale!("en").into(),
+///     Notation::Scientific.into(),
+/// )
+/// .unwrap();
+/// assert_writeable_eq!(formatter.format(&decimal), "-1.23450E4");
+///
+/// let formatter = ScientificDecimalFormatter::try_new(
+///     locale!("fr").into(),
+///     Notation::Engineering.into(),
+/// )
+/// .unwrap();
+/// assert_writeable_eq!(formatter.format(&decimal), "-12,3450E3");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum Notation {
+    /// Display a single nonzero integer digit and a power of ten, such as `1.2345E4`.
+    #[default]
+    Scientific,
+
+    /// Display one to three integer digits and a power of ten that is a multiple of three,
+    /// such as `12.345E3`.
+    Engineering,
+}
+
+#[doc(hidden)] // unstable
+/// Configuration for how to display the exponent in scientific and engineering notation.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::ExponentStyle;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = ScientificDecimalFormatter::try_new(
+///     locale!("en").into(),
+///     ExponentStyle::Superscript.into(),
+/// )
+/// .unwrap();
+///
+/// let decimal = "0.00123".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&decimal), "1.23×10⁻³");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ExponentStyle {
+    /// Separate the exponent with the locale's exponential symbol, such as `1.23E4`.
+    #[default]
+    Symbol,
+
+    /// Display the power of ten with a superscripted exponent, such as `1.23×10⁴`.
+    ///
+    /// Superscript digits only exist for the Latin numbering system; numbers in other
+    /// numbering systems are displayed with [`ExponentStyle::Symbol`].
+    Superscript,
+}
+
+#[doc(hidden)] // unstable
+/// A bag of options defining how numbers will be parsed by
+/// [`DecimalParser`](crate::DecimalParser).
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Default, Hash)]
+#[non_exhaustive]
+pub struct DecimalParserOptions {
+    /// How closely the input has to match the formatted form of a number.
+    ///
+    /// Default is [`ParseStrictness::Lenient`]
+    pub strictness: Option<ParseStrictness>,
+}
+
+impl From<ParseStrictness> for DecimalParserOptions {
+    fn from(strictness: ParseStrictness) -> Self {
+        Self {
+            strictness: Some(strictness),
+        }
+    }
+}
+
+#[doc(hidden)] // unstable
+/// Configuration for how closely the input of [`DecimalParser`](crate::DecimalParser) has to
+/// match the output of [`DecimalFormatter`](crate::DecimalFormatter).
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::options::ParseStrictness;
+/// use icu::decimal::DecimalParser;
+/// use icu::locale::locale;
+///
+/// let lenient = DecimalParser::try_new(locale!("en").into(), Default::default())
+///     .expect("locale should be present");
+/// let strict =
+///     DecimalParser::try_new(locale!("en").into(), ParseStrictness::Strict.into())
+///         .expect("locale should be present");
+///
+/// assert_eq!(lenient.parse(" 12,34,567 ").unwrap().to_string(), "1234567");
+/// assert!(strict.parse(" 12,34,567 ").is_err());
+/// assert_eq!(strict.parse("1,234,567").unwrap().to_string(), "1234567");
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash, Default)]
+pub enum ParseStrictness {
+    /// Accept common variations of the formatted form:
+    ///
+    /// - surrounding whitespace and bidi marks,
+    /// - grouping separators at any position in the integer part, and whitespace or
+    ///   apostrophes in place of a whitespace or apostrophe grouping separator,
+    /// - ASCII digits in addition to the digits of the numbering system,
+    /// - ASCII signs and `−` in addition to the sign affixes of the locale,
+    /// - accounting-style parentheses for negative numbers,
+    /// - a percent sign, dividing the number by 100,
+    /// - a missing integer or fraction part, such as `.5` or `5.`.
+    #[default]
+    Lenient,
+
+    /// Only accept input in the form produced by [`DecimalFormatter`](crate::DecimalFormatter),
+    /// with grouping separators either absent or at the positions of the locale.
+    Strict,
+}
@@ -0,0 +1,554 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of localized decimal numbers.
+
+use crate::options::{DecimalParserOptions, ParseStrictness};
+use crate::provider::{DecimalDigitsV1, DecimalSymbols, DecimalSymbolsV1};
+use crate::DecimalFormatterPreferences;
+use displaydoc::Display;
+use fixed_decimal::{Decimal, Sign, UnsignedDecimal};
+use icu_provider::prelude::*;
+
+/// Bidi marks that are part of some sign affixes, and that are ignored in lenient mode.
+const BIDI_MARKS: [char; 3] = ['\u{200e}', '\u{200f}', '\u{61c}'];
+
+/// Percent signs accepted in lenient mode.
+const PERCENT_SIGNS: [char; 3] = ['%', '\u{66a}', '\u{ff05}'];
+
+/// The number of digits that are accumulated in a `u64` before being added to the result.
+const CHUNK_DIGITS: u8 = 18;
+
+/// An error returned by [`DecimalParser::parse`].
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DecimalParseError {
+    /// The input is not a number in the format of the locale.
+    #[displaydoc("Failed to parse the input string")]
+    Syntax,
+    /// The grouping separators are not at the positions used by the locale.
+    ///
+    /// This is only returned with [`ParseStrictness::Strict`].
+    #[displaydoc("Grouping separators at unexpected positions")]
+    Grouping,
+    /// The number has too many digits to be represented as a [`Decimal`].
+    #[displaydoc("Magnitude or number of digits exceeded")]
+    Limit,
+}
+
+impl core::error::Error for DecimalParseError {}
+
+/// A parser for localized decimal numbers, producing a [`Decimal`].
+///
+/// [`DecimalParser`] uses the same symbols and digits as [`DecimalFormatter`](crate::DecimalFormatter)
+/// and accepts:
+///
+/// 1. Digits of the local numbering system
+/// 2. The locale's decimal separator, and its grouping separator in the integer part
+/// 3. Locale-sensitive plus and minus signs
+///
+/// By default, common variations of these are accepted as well; see [`ParseStrictness`]
+/// for the details.
+///
+/// The number of digits of the input is preserved, so `"1.50"` parses to a [`Decimal`] with
+/// two fraction digits.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::DecimalParser;
+/// use icu::locale::locale;
+///
+/// let parse = |locale: icu::locale::Locale, input: &str| {
+///     DecimalParser::try_new(locale.into(), Default::default())
+///         .expect("locale should be present")
+///         .parse(input)
+///         .map(|decimal| decimal.to_string())
+/// };
+///
+/// assert_eq!(parse(locale!("de"), "1.234,5"), Ok("1234.5".into()));
+/// assert_eq!(parse(locale!("ar-EG"), "١٢٣"), Ok("123".into()));
+/// assert_eq!(parse(locale!("en"), "(1,234)"), Ok("-1234".into()));
+/// assert_eq!(parse(locale!("fr"), "−12 %"), Ok("-0.12".into()));
+/// assert!(parse(locale!("en"), "1.234,5").is_err());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecimalParser {
+    options: DecimalParserOptions,
+    symbols: DataPayload<DecimalSymbolsV1>,
+    digits: DataPayload<DecimalDigitsV1>,
+}
+
+impl AsRef<DecimalParser> for DecimalParser {
+    fn as_ref(&self) -> &DecimalParser {
+        self
+    }
+}
+
+impl DecimalParser {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DecimalFormatterPreferences, options: DecimalParserOptions) -> error: DataError,
+        /// Creates a new [`DecimalParser`] from compiled data and an options bag.
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<
+        D: DataProvider<DecimalSymbolsV1> + DataProvider<DecimalDigitsV1> + ?Sized,
+    >(
+        provider: &D,
+        prefs: DecimalFormatterPreferences,
+        options: DecimalParserOptions,
+    ) -> Result<Self, DataError> {
+        let (symbols, digits, _) = crate::load_symbols_and_digits(provider, prefs)?;
+        Ok(Self {
+            options,
+            symbols,
+            digits,
+        })
+    }
+
+    /// Parses a localized number into a [`Decimal`].
+    pub fn parse(&self, input: &str) -> Result<Decimal, DecimalParseError> {
+        let symbols = self.symbols.get();
+        if self.options.strictness == Some(ParseStrictness::Strict) {
+            let (sign, body) = strip_sign(symbols, input, true);
+            let absolute = self.parse_unsigned(body, true)?;
+            return Ok(Decimal::new(sign, absolute));
+        }
+
+        let mut input = trim_lenient(input);
+        let parentheses = match input.strip_prefix('(').and_then(|s| s.strip_suffix(')')) {
+            Some(inner) => {
+                input = trim_lenient(inner);
+                true
+            }
+            None => false,
+        };
+        let mut percent = strip_percent(&mut input);
+        let (mut sign, mut body) = strip_sign(symbols, input, false);
+        percent = percent || strip_percent(&mut body);
+        if parentheses {
+            if sign != Sign::None {
+                return Err(DecimalParseError::Syntax);
+            }
+            sign = Sign::Negative;
+        }
+
+        let mut absolute = self.parse_unsigned(body, false)?;
+        if percent {
+            absolute.multiply_pow10(-2);
+        }
+        Ok(Decimal::new(sign, absolute))
+    }
+
+    /// Parses the digits and separators of a number without its sign.
+    fn parse_unsigned(
+        &self,
+        input: &str,
+        strict: bool,
+    ) -> Result<UnsignedDecimal, DecimalParseError> {
+        let symbols = self.symbols.get();
+        let native_digits = self.digits.get();
+        let decimal_separator = symbols.decimal_separator();
+        let grouping_separator = symbols.grouping_separator();
+        let primary = usize::from(symbols.grouping_sizes.primary);
+        let secondary = match symbols.grouping_sizes.secondary {
+            0 => primary,
+            secondary => usize::from(secondary),
+        };
+
+        let mut digits = DigitAccumulator::new();
+        // Whether the digits are those of the numbering system rather than ASCII digits
+        let mut is_native = None;
+        // The number of integer digits, once the decimal separator has been seen
+        let mut integer_digits = None;
+        // The number of digits since the start or since the last grouping separator
+        let mut group_len = 0;
+        let mut separators = 0;
+
+        let mut rest = input;
+        while let Some(c) = rest.chars().next() {
+            let digit = match native_digits.iter().position(|&d| d == c) {
+                Some(d) => Some((d, true)),
+                None if !strict && c.is_ascii_digit() => Some((usize::from(c as u8 - b'0'), false)),
+                None => None,
+            };
+            if let Some((digit, native)) = digit {
+                if *is_native.get_or_insert(native) != native {
+                    return Err(DecimalParseError::Syntax);
+                }
+                digits.push(digit as u64)?;
+                group_len += 1;
+                rest = skip_char(rest);
+                continue;
+            }
+
+            if integer_digits.is_none() {
+                if let Some(r) = strip_separator(rest, decimal_separator) {
+                    if separators > 0 && group_len == 0 {
+                        return Err(DecimalParseError::Syntax);
+                    }
+                    if strict {
+                        if digits.len == 0 {
+                            return Err(DecimalParseError::Syntax);
+                        }
+                        if separators > 0 && group_len != primary {
+                            return Err(DecimalParseError::Grouping);
+                        }
+                    }
+                    integer_digits = Some(digits.len);
+                    rest = r;
+                    continue;
+                }
+
+                if let Some(r) = strip_grouping_separator(rest, grouping_separator, strict) {
+                    if group_len == 0 {
+                        return Err(DecimalParseError::Syntax);
+                    }
+                    // The leftmost group may be shorter than the others
+                    if strict
+                        && (primary == 0
+                            || (separators == 0 && group_len > secondary)
+                            || (separators > 0 && group_len != secondary))
+                    {
+                        return Err(DecimalParseError::Grouping);
+                    }
+                    separators += 1;
+                    group_len = 0;
+                    rest = r;
+                    continue;
+                }
+            }
+
+            if !strict && BIDI_MARKS.contains(&c) {
+                rest = skip_char(rest);
+                continue;
+            }
+            return Err(DecimalParseError::Syntax);
+        }
+
+        if digits.len == 0 {
+            return Err(DecimalParseError::Syntax);
+        }
+        let integer_digits = match integer_digits {
+            Some(integer_digits) => {
+                if strict && integer_digits == digits.len {
+                    return Err(DecimalParseError::Syntax);
+                }
+                integer_digits
+            }
+            None => {
+                if separators > 0 && group_len == 0 {
+                    return Err(DecimalParseError::Syntax);
+                }
+                if strict && separators > 0 && group_len != primary {
+                    return Err(DecimalParseError::Grouping);
+                }
+                digits.len
+            }
+        };
+        Ok(digits.finish(integer_digits))
+    }
+}
+
+/// Trims whitespace and bidi marks.
+fn trim_lenient(s: &str) -> &str {
+    s.trim_matches(|c: char| c.is_whitespace() || BIDI_MARKS.contains(&c))
+}
+
+/// Returns `s` without its first char.
+fn skip_char(s: &str) -> &str {
+    let mut chars = s.chars();
+    chars.next();
+    chars.as_str()
+}
+
+/// Strips a percent sign at the start or end of `s`, returning whether there was one.
+fn strip_percent(s: &mut &str) -> bool {
+    match s
+        .strip_suffix(PERCENT_SIGNS)
+        .or_else(|| s.strip_prefix(PERCENT_SIGNS))
+    {
+        Some(rest) => {
+            *s = trim_lenient(rest);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Strips the sign affixes of the locale from `s`. In lenient mode, whitespace and bidi marks
+/// around the affixes are ignored, and ASCII signs and `−` are accepted as prefixes.
+fn strip_sign<'a>(symbols: &DecimalSymbols, s: &'a str, strict: bool) -> (Sign, &'a str) {
+    for (sign, (prefix, suffix)) in [
+        (Sign::Negative, symbols.minus_sign_affixes()),
+        (Sign::Positive, symbols.plus_sign_affixes()),
+    ] {
+        let (prefix, suffix) = if strict {
+            (prefix, suffix)
+        } else {
+            (trim_lenient(prefix), trim_lenient(suffix))
+        };
+        if prefix.is_empty() && suffix.is_empty() {
+            continue;
+        }
+        if let Some(rest) = s.strip_prefix(prefix).and_then(|s| s.strip_suffix(suffix)) {
+            return (sign, if strict { rest } else { trim_lenient(rest) });
+        }
+    }
+    if !strict {
+        if let Some(rest) = s.strip_prefix(['-', '\u{2212}']) {
+            return (Sign::Negative, trim_lenient(rest));
+        }
+        if let Some(rest) = s.strip_prefix('+') {
+            return (Sign::Positive, trim_lenient(rest));
+        }
+    }
+    (Sign::None, s)
+}
+
+fn strip_separator<'a>(s: &'a str, separator: &str) -> Option<&'a str> {
+    if separator.is_empty() {
+        return None;
+    }
+    s.strip_prefix(separator)
+}
+
+/// Strips a grouping separator from the start of `s`. In lenient mode, any whitespace
+/// separator stands in for a whitespace grouping separator, and likewise for apostrophes.
+fn strip_grouping_separator<'a>(s: &'a str, separator: &str, strict: bool) -> Option<&'a str> {
+    if let Some(rest) = strip_separator(s, separator) {
+        return Some(rest);
+    }
+    if strict {
+        return None;
+    }
+    let mut chars = separator.chars();
+    let equivalents: &[char] = match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_whitespace() => &[' ', '\u{a0}', '\u{202f}'],
+        (Some('\'' | '\u{2019}'), None) => &['\'', '\u{2019}'],
+        _ => return None,
+    };
+    s.strip_prefix(equivalents)
+}
+
+/// Collects digits into an [`UnsignedDecimal`] without allocating, as a number of the form
+/// `0.ddd…` that is shifted into place at the end.
+struct DigitAccumulator {
+    value: UnsignedDecimal,
+    chunk: u64,
+    chunk_len: u8,
+    /// The total number of digits.
+    len: usize,
+}
+
+impl DigitAccumulator {
+    fn new() -> Self {
+        Self {
+            value: UnsignedDecimal::default(),
+            chunk: 0,
+            chunk_len: 0,
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, digit: u64) -> Result<(), DecimalParseError> {
+        // Leave room for the shift applied by a percent sign
+        if self.len >= i16::MAX as usize - 2 {
+            return Err(DecimalParseError::Limit);
+        }
+        self.chunk = self.chunk * 10 + digit;
+        self.chunk_len += 1;
+        self.len += 1;
+        if self.chunk_len == CHUNK_DIGITS {
+            self.flush();
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) {
+        // `len` is bounded by `push`
+        let chunk = UnsignedDecimal::from(self.chunk).multiplied_pow10(-(self.len as i16));
+        let result = self.value.concatenate_end(chunk);
+        // The chunk is to the right of all digits collected so far
+        debug_assert!(result.is_ok());
+        self.chunk = 0;
+        self.chunk_len = 0;
+    }
+
+    /// Returns the collected digits as a number with `integer_digits` integer digits.
+    fn finish(mut self, integer_digits: usize) -> UnsignedDecimal {
+        self.flush();
+        // Both are bounded by `push`
+        let integer_digits = integer_digits as i16;
+        let fraction_digits = self.len as i16 - integer_digits;
+        let mut value = self.value;
+        value.multiply_pow10(integer_digits);
+        value.trim_start();
+        value.pad_start(integer_digits);
+        value.pad_end(-fraction_digits);
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+
+    use crate::options::*;
+    use crate::{DecimalParseError, DecimalParser};
+    use fixed_decimal::Decimal;
+
+    fn parse(
+        locale: icu_locale_core::Locale,
+        strictness: ParseStrictness,
+        input: &str,
+    ) -> Result<Decimal, DecimalParseError> {
+        DecimalParser::try_new(locale.into(), strictness.into())
+            .unwrap()
+            .parse(input)
+    }
+
+    #[test]
+    fn test_digits_preserved() {
+        for input in [
+            "0",
+            "-0",
+            "+7",
+            "007",
+            "1.50",
+            "0.000",
+            "123456789012345678901234567890.5",
+        ] {
+            let expected = Decimal::try_from_str(input).unwrap();
+            for strictness in [ParseStrictness::Lenient, ParseStrictness::Strict] {
+                assert_eq!(
+                    parse(locale!("en"), strictness, input),
+                    Ok(expected.clone())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lenient() {
+        for (locale, input, expected) in [
+            (locale!("en"), "1,234.5", "1234.5"),
+            (locale!("en"), "  1,234,567  ", "1234567"),
+            (locale!("en"), "12,34,567", "1234567"),
+            (locale!("en"), "−5", "-5"),
+            (locale!("en"), "(1,234)", "-1234"),
+            (locale!("en"), "( 12.5 )", "-12.5"),
+            (locale!("en"), "50%", "0.50"),
+            (locale!("en"), "-%5", "-0.05"),
+            (locale!("en"), ".5", "0.5"),
+            (locale!("en"), "5.", "5"),
+            (locale!("de"), "1.234,5", "1234.5"),
+            (locale!("de-CH"), "1'234.5", "1234.5"),
+            (locale!("fr"), "1 234,5", "1234.5"),
+            (locale!("fr"), "1\u{202f}234,5", "1234.5"),
+            (locale!("fr"), "−12 %", "-0.12"),
+            (locale!("ar-EG"), "\u{61c}-١٬٢٣٤٫٥", "-1234.5"),
+            (locale!("ar-EG"), "-١٢٣", "-123"),
+            (locale!("ar-EG"), "1234", "1234"),
+            (locale!("bn"), "১০,০০,০০৭", "1000007"),
+            (locale!("th-u-nu-thai"), "๑,๐๐๐", "1000"),
+        ] {
+            assert_eq!(
+                parse(locale.clone(), ParseStrictness::Lenient, input).map(|d| d.to_string()),
+                Ok(expected.into()),
+                "{locale}: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_lenient_errors() {
+        for (locale, input) in [
+            (locale!("en"), ""),
+            (locale!("en"), "-"),
+            (locale!("en"), "."),
+            (locale!("en"), "1.234,5"),
+            (locale!("en"), ",123"),
+            (locale!("en"), "123,"),
+            (locale!("en"), "1,,234"),
+            (locale!("en"), "1,.5"),
+            (locale!("en"), "1.2,345"),
+            (locale!("en"), "1 234"),
+            (locale!("en"), "(-5)"),
+            (locale!("en"), "12abc"),
+            (locale!("en"), "--5"),
+            (locale!("ar-EG"), "١2"),
+        ] {
+            assert_eq!(
+                parse(locale.clone(), ParseStrictness::Lenient, input),
+                Err(DecimalParseError::Syntax),
+                "{locale}: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_strict() {
+        for (locale, input, expected) in [
+            (locale!("en"), "1,234,567.89", Ok("1234567.89")),
+            (locale!("en"), "1234567.89", Ok("1234567.89")),
+            (locale!("en"), "-1,234", Ok("-1234")),
+            (locale!("en"), "12,34,567", Err(DecimalParseError::Grouping)),
+            (locale!("en"), "1234,567", Err(DecimalParseError::Grouping)),
+            (locale!("en"), "1,23", Err(DecimalParseError::Grouping)),
+            (locale!("en"), " 1", Err(DecimalParseError::Syntax)),
+            (locale!("en"), "(1)", Err(DecimalParseError::Syntax)),
+            (locale!("en"), "5%", Err(DecimalParseError::Syntax)),
+            (locale!("en"), "−5", Err(DecimalParseError::Syntax)),
+            (locale!("en"), ".5", Err(DecimalParseError::Syntax)),
+            (locale!("en"), "5.", Err(DecimalParseError::Syntax)),
+            (locale!("hi-u-nu-latn"), "12,34,567", Ok("1234567")),
+            (
+                locale!("hi-u-nu-latn"),
+                "1,234,567",
+                Err(DecimalParseError::Grouping),
+            ),
+            (locale!("fr"), "1 234", Err(DecimalParseError::Syntax)),
+            (locale!("fr"), "1\u{202f}234", Ok("1234")),
+            (locale!("ar-EG"), "\u{61c}-١٬٢٣٤", Ok("-1234")),
+            (locale!("ar-EG"), "-١٬٢٣٤", Err(DecimalParseError::Syntax)),
+            (locale!("ar-EG"), "1234", Err(DecimalParseError::Syntax)),
+        ] {
+            assert_eq!(
+                parse(locale.clone(), ParseStrictness::Strict, input).map(|d| d.to_string()),
+                expected.map(Into::into),
+                "{locale}: {input}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for locale in [
+            locale!("en"),
+            locale!("ar-EG"),
+            locale!("bn"),
+            locale!("de"),
+            locale!("fr"),
+            locale!("hi"),
+            locale!("sv"),
+        ] {
+            let formatter =
+                crate::DecimalFormatter::try_new((&locale).into(), Default::default()).unwrap();
+            for strictness in [ParseStrictness::Lenient, ParseStrictness::Strict] {
+                let parser = DecimalParser::try_new((&locale).into(), strictness.into()).unwrap();
+                for input in ["-1234567.890", "0.5", "+12", "1000"] {
+                    let decimal = Decimal::try_from_str(input).unwrap();
+                    let formatted = formatter.format(&decimal).to_string();
+                    assert_eq!(
+                        parser.parse(&formatted),
+                        Ok(decimal),
+                        "{locale}: {formatted}"
+                    );
+                }
+            }
+        }
+    }
+}
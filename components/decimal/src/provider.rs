@@ -129,6 +129,7 @@ const _: () = {
     make_provider!(Baked);
     impl_decimal_symbols_v1!(Baked);
     impl_decimal_digits_v1!(Baked);
+    impl_decimal_exponent_symbols_v1!(Baked);
 };
 
 icu_provider::data_marker!(
@@ -148,9 +149,21 @@ icu_provider::data_marker!(
     attributes_domain = "numbering_system"
 );
 
+icu_provider::data_marker!(
+    /// Data marker for the symbols used in scientific and engineering notation. It is
+    /// requested with the same identifiers as [`DecimalSymbolsV1`].
+    DecimalExponentSymbolsV1,
+    "decimal/exponent/symbols/v1",
+    DecimalExponentSymbols<'static>,
+);
+
 #[cfg(feature = "datagen")]
 /// The latest minimum set of markers required by this component.
-pub const MARKERS: &[DataMarkerInfo] = &[DecimalSymbolsV1::INFO, DecimalDigitsV1::INFO];
+pub const MARKERS: &[DataMarkerInfo] = &[
+    DecimalSymbolsV1::INFO,
+    DecimalDigitsV1::INFO,
+    DecimalExponentSymbolsV1::INFO,
+];
 
 /// A collection of settings expressing where to put grouping separators in a decimal number.
 /// For example, `1,000,000` has two grouping separators, positioned along every 3 digits.
@@ -180,6 +193,27 @@ pub struct GroupingSizes {
     pub min_grouping: u8,
 }
 
+/// Settings for the exponent of a number in scientific notation, taken from the locale's
+/// scientific pattern. For example, the pattern `#E0` shows `1.23E4`, while `0.00E+00` shows
+/// `1.23E+04`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, Copy, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_decimal::provider))]
+pub struct ExponentSettings {
+    /// The minimum number of digits of the exponent, padded with zeros.
+    pub min_digits: u8,
+
+    /// Whether a plus sign is shown for positive exponents.
+    pub plus_sign: bool,
+}
+
 /// A stack representation of the strings used in [`DecimalSymbols`], i.e. a builder type
 /// for [`DecimalSymbolsStrs`]. This type can be obtained from a [`DecimalSymbolsStrs`]
 /// the `From`/`Into` traits.
@@ -318,3 +352,32 @@ impl DecimalSymbols<'static> {
         }
     }
 }
+
+/// Symbols and settings required for formatting a number in scientific or engineering notation.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, PartialEq, Clone, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_decimal::provider))]
+pub struct DecimalExponentSymbols<'data> {
+    /// Symbol used to separate the significand and the exponent, such as `E` in `1.23E4`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub exponential: VarZeroCow<'data, str>,
+
+    /// Symbol used before a superscripted power of ten, such as `×` in `1.23×10⁴`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub superscripting_exponent: VarZeroCow<'data, str>,
+
+    /// Settings used to display the exponent.
+    pub settings: ExponentSettings,
+}
+
+icu_provider::data_struct!(
+    DecimalExponentSymbols<'_>,
+    #[cfg(feature = "datagen")]
+);
@@ -0,0 +1,168 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Formatting of numbers in scientific and engineering notation.
+
+use crate::format::{FormattedDecimal, Scientific};
+use crate::options::{DecimalFormatterOptions, ScientificDecimalFormatterOptions};
+use crate::provider::*;
+use crate::size_test_macro::size_test;
+use crate::DecimalFormatterPreferences;
+use fixed_decimal::{Decimal, ScientificDecimal};
+use icu_provider::prelude::*;
+
+size_test!(
+    ScientificDecimalFormatter,
+    scientific_decimal_formatter_size,
+    160
+);
+
+/// The options of the [`DecimalFormatter`](crate::DecimalFormatter) part of the formatting.
+/// Groups are never displayed in scientific notation.
+const DECIMAL_FORMATTER_OPTIONS: DecimalFormatterOptions = DecimalFormatterOptions {
+    grouping_strategy: None,
+};
+
+/// A formatter for [`Decimal`] and [`ScientificDecimal`] in scientific or engineering notation,
+/// rendering decimal digits in an i18n-friendly way.
+///
+/// [`ScientificDecimalFormatter`] supports the features of
+/// [`DecimalFormatter`](crate::DecimalFormatter), except for grouping separators, and
+/// the locale's exponential symbols and exponent pattern.
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::input::Decimal;
+/// use icu::decimal::options::Notation;
+/// use icu::decimal::ScientificDecimalFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = ScientificDecimalFormatter::try_new(
+///     locale!("ar-EG").into(),
+///     Notation::Engineering.into(),
+/// )
+/// .expect("locale should be present");
+///
+/// let decimal = Decimal::from(12300);
+/// assert_writeable_eq!(formatter.format(&decimal), "١٢٫٣أس٣");
+/// ```
+#[doc = scientific_decimal_formatter_size!()]
+#[derive(Debug, Clone)]
+pub struct ScientificDecimalFormatter {
+    options: ScientificDecimalFormatterOptions,
+    symbols: DataPayload<DecimalSymbolsV1>,
+    digits: DataPayload<DecimalDigitsV1>,
+    exponent_symbols: DataPayload<DecimalExponentSymbolsV1>,
+}
+
+impl AsRef<ScientificDecimalFormatter> for ScientificDecimalFormatter {
+    fn as_ref(&self) -> &ScientificDecimalFormatter {
+        self
+    }
+}
+
+impl ScientificDecimalFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: DecimalFormatterPreferences, options: ScientificDecimalFormatterOptions) -> error: DataError,
+        /// Creates a new [`ScientificDecimalFormatter`] from compiled data and an options bag.
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<
+        D: DataProvider<DecimalSymbolsV1>
+            + DataProvider<DecimalDigitsV1>
+            + DataProvider<DecimalExponentSymbolsV1>
+            + ?Sized,
+    >(
+        provider: &D,
+        prefs: DecimalFormatterPreferences,
+        options: ScientificDecimalFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let (symbols, digits, _) = crate::load_symbols_and_digits(provider, prefs)?;
+
+        // The exponent symbols are keyed like the symbols, so this loads the same numbering system
+        let locale = DecimalExponentSymbolsV1::make_locale(prefs.locale_preferences);
+        let load = |attributes| {
+            provider.load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
+                ..Default::default()
+            })
+        };
+        let exponent_symbols: DataResponse<DecimalExponentSymbolsV1> =
+            match prefs.numbering_system.as_ref().map(|s| s.as_str()) {
+                Some(nu) => load(DataMarkerAttributes::from_str_or_panic(nu))
+                    // If it doesn't exist, fall back to the locale
+                    .or_else(|_err| load(DataMarkerAttributes::empty()))?,
+                None => load(DataMarkerAttributes::empty())?,
+            };
+
+        Ok(Self {
+            options,
+            symbols,
+            digits,
+            exponent_symbols: exponent_symbols.payload,
+        })
+    }
+
+    /// Formats a [`Decimal`] in the notation of the options, returning a [`FormattedDecimal`].
+    ///
+    /// The digits of the number are kept as they are, except that trailing zeros of its
+    /// integer part are not shown.
+    pub fn format<'l>(&'l self, value: &'l Decimal) -> FormattedDecimal<'l> {
+        self.format_with_exponent(value, None)
+    }
+
+    /// Formats a [`ScientificDecimal`], returning a [`FormattedDecimal`].
+    ///
+    /// The significand and the exponent are displayed with the digits and signs they have;
+    /// only [`ScientificDecimalFormatterOptions::exponent_style`] is taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::input::ScientificDecimal;
+    /// use icu::decimal::ScientificDecimalFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let formatter = ScientificDecimalFormatter::try_new(
+    ///     locale!("sv").into(),
+    ///     Default::default(),
+    /// )
+    /// .unwrap();
+    ///
+    /// let value = "-1.50e-03".parse::<ScientificDecimal>().unwrap();
+    /// assert_writeable_eq!(formatter.format_scientific(&value), "−1,50×10^−03");
+    /// ```
+    pub fn format_scientific<'l>(&'l self, value: &'l ScientificDecimal) -> FormattedDecimal<'l> {
+        self.format_with_exponent(value.significand(), Some(value.exponent().as_ref()))
+    }
+
+    /// Formats a [`Decimal`] in the notation of the options, returning a [`String`].
+    #[cfg(feature = "alloc")]
+    pub fn format_to_string(&self, value: &Decimal) -> alloc::string::String {
+        use writeable::Writeable;
+        self.format(value).write_to_string().into_owned()
+    }
+
+    fn format_with_exponent<'l>(
+        &'l self,
+        value: &'l Decimal,
+        exponent: Option<&'l Decimal>,
+    ) -> FormattedDecimal<'l> {
+        FormattedDecimal {
+            value,
+            options: &DECIMAL_FORMATTER_OPTIONS,
+            symbols: self.symbols.get(),
+            digits: self.digits.get(),
+            scientific: Some(Scientific {
+                options: &self.options,
+                symbols: self.exponent_symbols.get(),
+                exponent,
+            }),
+        }
+    }
+}
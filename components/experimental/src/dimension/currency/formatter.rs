@@ -125,6 +125,29 @@ impl CurrencyFormatter {
         })
     }
 
+    /// Creates a new [`CurrencyFormatter`] that formats the currency value with the given
+    /// [`DecimalFormatter`].
+    pub(crate) fn try_new_with_decimal_formatter_unstable(
+        provider: &(impl DataProvider<CurrencyEssentialsV1> + ?Sized),
+        prefs: CurrencyFormatterPreferences,
+        decimal_formatter: DecimalFormatter,
+        options: CurrencyFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = CurrencyEssentialsV1::make_locale(prefs.locale_preferences);
+        let essential = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_locale(&locale),
+                ..Default::default()
+            })?
+            .payload;
+
+        Ok(Self {
+            options,
+            essential,
+            decimal_formatter,
+        })
+    }
+
     /// Formats a [`Decimal`] value for the given currency code.
     ///
     /// # Examples
@@ -18,5 +18,5 @@ pub mod long_formatter;
 pub mod options;
 
 /// A currency code, such as "USD" or "EUR".
-#[derive(Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct CurrencyCode(pub TinyAsciiStr<3>);
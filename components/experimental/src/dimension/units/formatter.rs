@@ -170,6 +170,41 @@ impl UnitsFormatter {
         })
     }
 
+    /// Creates a new [`UnitsFormatter`] that formats the value with the given
+    /// [`DecimalFormatter`] and selects the plural form with the given [`PluralRules`].
+    pub(crate) fn try_new_with_formatters_unstable(
+        provider: &(impl DataProvider<UnitsDisplayNameV1> + ?Sized),
+        prefs: UnitsFormatterPreferences,
+        unit: &str,
+        decimal_formatter: DecimalFormatter,
+        plural_rules: PluralRules,
+        options: UnitsFormatterOptions,
+    ) -> Result<Self, DataError> {
+        let locale = UnitsDisplayNameV1::make_locale(prefs.locale_preferences);
+
+        // TODO: Remove this allocation once we have separate markers for different widths.
+        let attribute = Self::attribute(options.width, unit);
+        let unit_attribute = DataMarkerAttributes::try_from_utf8(&attribute[..attribute.len()])
+            .map_err(|_| DataError::custom("Failed to create a data marker"))?;
+
+        let display_name = provider
+            .load(DataRequest {
+                id: DataIdentifierBorrowed::for_marker_attributes_and_locale(
+                    unit_attribute,
+                    &locale,
+                ),
+                ..Default::default()
+            })?
+            .payload;
+
+        Ok(Self {
+            _options: options,
+            display_name,
+            decimal_formatter,
+            plural_rules,
+        })
+    }
+
     /// Formats a [`Decimal`] value for the given unit.
     pub fn format_fixed_decimal<'l>(&'l self, value: &'l Decimal) -> FormattedUnit<'l> {
         FormattedUnit {
@@ -0,0 +1,35 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Processing of internationalized domain names, following
+//! [UTS #46: Unicode IDNA Compatibility Processing](https://www.unicode.org/reports/tr46/).
+//!
+//! See [`IdnaProcessor`] for the conversion of domain names, and [`punycode`] for the
+//! encoding of their labels.
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        clippy::trivially_copy_pass_by_ref,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod processor;
+pub mod punycode;
+
+pub use processor::IdnaError;
+pub use processor::IdnaErrors;
+pub use processor::IdnaOptions;
+pub use processor::IdnaProcessor;
+pub use processor::IdnaProcessorBorrowed;
+pub use processor::IdnaResult;
@@ -0,0 +1,810 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::punycode;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+use icu_normalizer::provider::{
+    NormalizerNfcV1, NormalizerNfdDataV1, NormalizerNfdTablesV1, NormalizerNfkdTablesV1,
+    NormalizerUts46DataV1,
+};
+use icu_normalizer::uts46::{Uts46Mapper, Uts46MapperBorrowed};
+use icu_normalizer::{ComposingNormalizer, ComposingNormalizerBorrowed};
+use icu_properties::props::{
+    BidiClass, CanonicalCombiningClass, GeneralCategory, GeneralCategoryGroup, JoiningType, Script,
+};
+use icu_properties::provider::{
+    PropertyEnumBidiClassV1, PropertyEnumCanonicalCombiningClassV1, PropertyEnumGeneralCategoryV1,
+    PropertyEnumJoiningTypeV1, PropertyEnumScriptV1,
+};
+use icu_properties::{CodePointMapData, CodePointMapDataBorrowed};
+use icu_provider::prelude::*;
+
+/// The prefix of labels that are encoded in Punycode.
+const ACE_PREFIX: &str = "xn--";
+
+/// The maximum length of a domain name, excluding the root label, in [`IdnaProcessorBorrowed::to_ascii`].
+const MAX_DOMAIN_NAME_LENGTH: usize = 253;
+
+/// The maximum length of a label in [`IdnaProcessorBorrowed::to_ascii`].
+const MAX_LABEL_LENGTH: usize = 63;
+
+/// An error found while processing a domain name.
+///
+/// Each variant corresponds to a status code of the UTS #46 conformance tests, which names
+/// the step of [UTS #46](https://www.unicode.org/reports/tr46/) or of IDNA2008 that failed.
+/// See [`IdnaError::code`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum IdnaError {
+    /// A label starting with `xn--` contains non-ASCII code points, is not valid Punycode,
+    /// or decodes to an empty or ASCII-only label (`P4`).
+    Punycode,
+    /// A label is not in Normalization Form C (`V1`).
+    NotNfc,
+    /// A label contains hyphens in both its third and fourth positions (`V2`).
+    HyphensInThirdAndFourthPositions,
+    /// A label begins or ends with a hyphen (`V3`).
+    LeadingOrTrailingHyphen,
+    /// A label decoded from Punycode begins with `xn--` (`V4`).
+    AcePrefix,
+    /// A label decoded from Punycode contains a full stop (`V5`).
+    FullStop,
+    /// A label begins with a combining mark (`V6`).
+    LeadingCombiningMark,
+    /// A label contains a code point that is not valid in domain names (`V7`).
+    InvalidCodePoint,
+    /// A label contains an ASCII code point other than a lowercase letter, a digit,
+    /// or a hyphen (`U1`).
+    Std3AsciiRules,
+    /// A zero width non-joiner does not satisfy the CONTEXTJ rule of IDNA2008 (`C1`).
+    ZeroWidthNonJoiner,
+    /// A zero width joiner does not satisfy the CONTEXTJ rule of IDNA2008 (`C2`).
+    ZeroWidthJoiner,
+    /// A code point does not satisfy its CONTEXTO rule of IDNA2008.
+    ///
+    /// This is not part of UTS #46, and is only checked with [`IdnaOptions::check_context_o`].
+    ContextO,
+    /// A label of a bidi domain name does not begin with a left-to-right or
+    /// right-to-left letter (`B1`).
+    BidiFirstCharacter,
+    /// A right-to-left label contains a code point with a disallowed bidi class (`B2`).
+    BidiRtlCharacter,
+    /// A right-to-left label does not end with a right-to-left letter or a number (`B3`).
+    BidiRtlEnd,
+    /// A right-to-left label contains both European and Arabic-Indic digits (`B4`).
+    BidiMixedNumbers,
+    /// A left-to-right label contains a code point with a disallowed bidi class (`B5`).
+    BidiLtrCharacter,
+    /// A left-to-right label does not end with a left-to-right letter or a European
+    /// digit (`B6`).
+    BidiLtrEnd,
+    /// A label cannot be encoded in Punycode (`A3`).
+    PunycodeOverflow,
+    /// The domain name is empty or longer than 253 bytes (`A4_1`).
+    DomainNameLength,
+    /// A label is empty or longer than 63 bytes (`A4_2`).
+    LabelLength,
+}
+
+impl IdnaError {
+    const ALL: [IdnaError; 21] = [
+        IdnaError::Punycode,
+        IdnaError::NotNfc,
+        IdnaError::HyphensInThirdAndFourthPositions,
+        IdnaError::LeadingOrTrailingHyphen,
+        IdnaError::AcePrefix,
+        IdnaError::FullStop,
+        IdnaError::LeadingCombiningMark,
+        IdnaError::InvalidCodePoint,
+        IdnaError::Std3AsciiRules,
+        IdnaError::ZeroWidthNonJoiner,
+        IdnaError::ZeroWidthJoiner,
+        IdnaError::ContextO,
+        IdnaError::BidiFirstCharacter,
+        IdnaError::BidiRtlCharacter,
+        IdnaError::BidiRtlEnd,
+        IdnaError::BidiMixedNumbers,
+        IdnaError::BidiLtrCharacter,
+        IdnaError::BidiLtrEnd,
+        IdnaError::PunycodeOverflow,
+        IdnaError::DomainNameLength,
+        IdnaError::LabelLength,
+    ];
+
+    /// Returns the status code of this error in the UTS #46 conformance tests, such as `"V6"`.
+    ///
+    /// [`IdnaError::ContextO`], which the conformance tests do not cover, returns `"CO"`.
+    pub fn code(self) -> &'static str {
+        match self {
+            IdnaError::Punycode => "P4",
+            IdnaError::NotNfc => "V1",
+            IdnaError::HyphensInThirdAndFourthPositions => "V2",
+            IdnaError::LeadingOrTrailingHyphen => "V3",
+            IdnaError::AcePrefix => "V4",
+            IdnaError::FullStop => "V5",
+            IdnaError::LeadingCombiningMark => "V6",
+            IdnaError::InvalidCodePoint => "V7",
+            IdnaError::Std3AsciiRules => "U1",
+            IdnaError::ZeroWidthNonJoiner => "C1",
+            IdnaError::ZeroWidthJoiner => "C2",
+            IdnaError::ContextO => "CO",
+            IdnaError::BidiFirstCharacter => "B1",
+            IdnaError::BidiRtlCharacter => "B2",
+            IdnaError::BidiRtlEnd => "B3",
+            IdnaError::BidiMixedNumbers => "B4",
+            IdnaError::BidiLtrCharacter => "B5",
+            IdnaError::BidiLtrEnd => "B6",
+            IdnaError::PunycodeOverflow => "A3",
+            IdnaError::DomainNameLength => "A4_1",
+            IdnaError::LabelLength => "A4_2",
+        }
+    }
+
+    fn bit(self) -> u32 {
+        1 << (self as u32)
+    }
+}
+
+/// The set of [`IdnaError`]s found while processing a domain name.
+#[derive(Copy, Clone, PartialEq, Eq, Default)]
+pub struct IdnaErrors(u32);
+
+impl IdnaErrors {
+    /// Whether no errors were found.
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether the given error was found.
+    pub fn contains(self, error: IdnaError) -> bool {
+        self.0 & error.bit() != 0
+    }
+
+    /// Returns the errors that were found.
+    pub fn iter(self) -> impl Iterator<Item = IdnaError> {
+        IdnaError::ALL
+            .into_iter()
+            .filter(move |&error| self.contains(error))
+    }
+
+    fn insert(&mut self, error: IdnaError) {
+        self.0 |= error.bit();
+    }
+}
+
+impl fmt::Debug for IdnaErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// The result of [`IdnaProcessorBorrowed::to_ascii`] or [`IdnaProcessorBorrowed::to_unicode`].
+///
+/// The domain name is returned even if errors were found, in which case it must not be used
+/// for lookup, but may be displayed. Disallowed code points are replaced by U+FFFD.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IdnaResult {
+    /// The converted domain name.
+    pub domain: String,
+    /// The errors found during the conversion.
+    pub errors: IdnaErrors,
+}
+
+/// Options for [`IdnaProcessor`], corresponding to the flags of UTS #46.
+///
+/// The default options enable all checks of UTS #46, which are the settings of its
+/// conformance tests. Registrars should use the defaults; other settings, such as those of
+/// the [WHATWG URL standard](https://url.spec.whatwg.org/#concept-domain-to-ascii),
+/// are more lenient.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct IdnaOptions {
+    /// Whether to disallow hyphens at the start and end of labels, and in their third and
+    /// fourth positions (`CheckHyphens`).
+    pub check_hyphens: bool,
+    /// Whether to check the bidi rules of IDNA2008 in domain names containing right-to-left
+    /// characters (`CheckBidi`).
+    pub check_bidi: bool,
+    /// Whether to check the CONTEXTJ rules of IDNA2008 for zero width joiners and
+    /// non-joiners (`CheckJoiners`).
+    pub check_joiners: bool,
+    /// Whether to check the CONTEXTO rules of IDNA2008 for the few code points that have them,
+    /// such as U+00B7 MIDDLE DOT and U+30FB KATAKANA MIDDLE DOT.
+    ///
+    /// This is not part of UTS #46, and is disabled by default.
+    pub check_context_o: bool,
+    /// Whether to only allow lowercase letters, digits, and hyphens among the ASCII
+    /// code points (`UseSTD3ASCIIRules`).
+    pub use_std3_ascii_rules: bool,
+    /// Whether [`IdnaProcessorBorrowed::to_ascii`] checks the lengths of the domain name and its
+    /// labels (`VerifyDnsLength`).
+    pub verify_dns_length: bool,
+    /// Whether to ignore labels starting with `xn--` that are not valid Punycode, instead of
+    /// reporting an error (`IgnoreInvalidPunycode`).
+    pub ignore_invalid_punycode: bool,
+}
+
+impl Default for IdnaOptions {
+    fn default() -> Self {
+        Self {
+            check_hyphens: true,
+            check_bidi: true,
+            check_joiners: true,
+            check_context_o: false,
+            use_std3_ascii_rules: true,
+            verify_dns_length: true,
+            ignore_invalid_punycode: false,
+        }
+    }
+}
+
+/// A label of a processed domain name.
+struct Label {
+    text: String,
+    /// The label before disallowed code points were replaced by U+FFFD, if it contained any.
+    ///
+    /// The validity criteria that depend on the properties of code points apply to the
+    /// original code points.
+    original: Option<String>,
+    /// Whether the label was decoded from Punycode.
+    decoded: bool,
+    /// Whether the label must be checked against the validity criteria.
+    validate: bool,
+}
+
+impl Label {
+    /// The label with its original disallowed code points.
+    fn original(&self) -> &str {
+        self.original.as_deref().unwrap_or(&self.text)
+    }
+}
+
+/// An implementation of the processing of internationalized domain names of
+/// [UTS #46: Unicode IDNA Compatibility Processing](https://www.unicode.org/reports/tr46/).
+///
+/// This extends the mapping of [`Uts46Mapper`] with the conversion of labels from and to
+/// Punycode and the validity criteria of UTS #46, including the bidi and CONTEXTJ rules
+/// of IDNA2008. Only nontransitional processing is supported.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::idna::{IdnaError, IdnaOptions, IdnaProcessor};
+///
+/// let idna = IdnaProcessor::new();
+///
+/// let result = idna.to_ascii("Bücher.example", IdnaOptions::default());
+/// assert_eq!(result.domain, "xn--bcher-kva.example");
+/// assert!(result.errors.is_empty());
+///
+/// let result = idna.to_unicode("xn--bcher-kva.example", IdnaOptions::default());
+/// assert_eq!(result.domain, "bücher.example");
+/// assert!(result.errors.is_empty());
+///
+/// // Hyphens are not allowed at the start of labels
+/// let result = idna.to_ascii("-bücher.example", IdnaOptions::default());
+/// assert_eq!(result.domain, "xn---bcher-4ya.example");
+/// assert!(result.errors.contains(IdnaError::LeadingOrTrailingHyphen));
+///
+/// // unless the check is disabled
+/// let mut options = IdnaOptions::default();
+/// options.check_hyphens = false;
+/// let result = idna.to_ascii("-bücher.example", options);
+/// assert!(result.errors.is_empty());
+/// ```
+#[derive(Debug)]
+pub struct IdnaProcessor {
+    mapper: Uts46Mapper,
+    nfc: ComposingNormalizer,
+    bidi_class: CodePointMapData<BidiClass>,
+    canonical_combining_class: CodePointMapData<CanonicalCombiningClass>,
+    general_category: CodePointMapData<GeneralCategory>,
+    joining_type: CodePointMapData<JoiningType>,
+    script: CodePointMapData<Script>,
+}
+
+/// A borrowed version of [`IdnaProcessor`], returned by [`IdnaProcessor::as_borrowed()`].
+#[derive(Debug)]
+pub struct IdnaProcessorBorrowed<'a> {
+    mapper: Uts46MapperBorrowed<'a>,
+    nfc: ComposingNormalizerBorrowed<'a>,
+    bidi_class: CodePointMapDataBorrowed<'a, BidiClass>,
+    canonical_combining_class: CodePointMapDataBorrowed<'a, CanonicalCombiningClass>,
+    general_category: CodePointMapDataBorrowed<'a, GeneralCategory>,
+    joining_type: CodePointMapDataBorrowed<'a, JoiningType>,
+    script: CodePointMapDataBorrowed<'a, Script>,
+}
+
+impl IdnaProcessor {
+    /// Creates a new [`IdnaProcessorBorrowed`] using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[expect(clippy::new_ret_no_self)]
+    pub const fn new() -> IdnaProcessorBorrowed<'static> {
+        IdnaProcessorBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<NormalizerUts46DataV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkdTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + DataProvider<PropertyEnumBidiClassV1>
+            + DataProvider<PropertyEnumCanonicalCombiningClassV1>
+            + DataProvider<PropertyEnumGeneralCategoryV1>
+            + DataProvider<PropertyEnumJoiningTypeV1>
+            + DataProvider<PropertyEnumScriptV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            mapper: Uts46Mapper::try_new(provider)?,
+            nfc: ComposingNormalizer::try_new_nfc_unstable(provider)?,
+            bidi_class: CodePointMapData::try_new_unstable(provider)?,
+            canonical_combining_class: CodePointMapData::try_new_unstable(provider)?,
+            general_category: CodePointMapData::try_new_unstable(provider)?,
+            joining_type: CodePointMapData::try_new_unstable(provider)?,
+            script: CodePointMapData::try_new_unstable(provider)?,
+        })
+    }
+
+    /// Constructs a borrowed version of this type for more efficient querying.
+    pub fn as_borrowed(&self) -> IdnaProcessorBorrowed<'_> {
+        IdnaProcessorBorrowed {
+            mapper: self.mapper.as_borrowed(),
+            nfc: self.nfc.as_borrowed(),
+            bidi_class: self.bidi_class.as_borrowed(),
+            canonical_combining_class: self.canonical_combining_class.as_borrowed(),
+            general_category: self.general_category.as_borrowed(),
+            joining_type: self.joining_type.as_borrowed(),
+            script: self.script.as_borrowed(),
+        }
+    }
+}
+
+impl IdnaProcessorBorrowed<'static> {
+    /// Creates a new [`IdnaProcessorBorrowed`] using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub const fn new() -> Self {
+        Self {
+            mapper: Uts46MapperBorrowed::new(),
+            nfc: ComposingNormalizerBorrowed::new_nfc(),
+            bidi_class: CodePointMapData::new(),
+            canonical_combining_class: CodePointMapData::new(),
+            general_category: CodePointMapData::new(),
+            joining_type: CodePointMapData::new(),
+            script: CodePointMapData::new(),
+        }
+    }
+
+    /// Cheaply converts a [`IdnaProcessorBorrowed<'static>`] into a [`IdnaProcessor`].
+    ///
+    /// Note: Due to branching and indirection, using [`IdnaProcessor`] might inhibit some
+    /// compile-time optimizations that are possible with [`IdnaProcessorBorrowed`].
+    pub const fn static_to_owned(self) -> IdnaProcessor {
+        IdnaProcessor {
+            mapper: self.mapper.static_to_owned(),
+            nfc: self.nfc.static_to_owned(),
+            bidi_class: self.bidi_class.static_to_owned(),
+            canonical_combining_class: self.canonical_combining_class.static_to_owned(),
+            general_category: self.general_category.static_to_owned(),
+            joining_type: self.joining_type.static_to_owned(),
+            script: self.script.static_to_owned(),
+        }
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for IdnaProcessorBorrowed<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IdnaProcessorBorrowed<'_> {
+    /// Converts a domain name to its ASCII form, as used for lookup (UTS #46 `ToASCII`).
+    ///
+    /// Labels containing non-ASCII code points are encoded in Punycode and prefixed with `xn--`.
+    pub fn to_ascii(&self, domain: &str, options: IdnaOptions) -> IdnaResult {
+        let mut errors = IdnaErrors::default();
+        let labels = self.process(domain, options, &mut errors);
+
+        let mut ascii_labels = Vec::with_capacity(labels.len());
+        for label in labels {
+            if label.text.is_ascii() {
+                ascii_labels.push(label.text);
+                continue;
+            }
+            match punycode::encode(&label.text) {
+                Ok(encoded) => {
+                    let mut ascii = String::with_capacity(ACE_PREFIX.len() + encoded.len());
+                    ascii.push_str(ACE_PREFIX);
+                    ascii.push_str(&encoded);
+                    ascii_labels.push(ascii);
+                }
+                Err(_) => {
+                    errors.insert(IdnaError::PunycodeOverflow);
+                    ascii_labels.push(label.text);
+                }
+            }
+        }
+
+        let domain = ascii_labels.join(".");
+        if options.verify_dns_length {
+            // The root label is the empty label after a trailing full stop. It does not count
+            // towards the length of the domain name, but is still an empty label.
+            let domain_length = match ascii_labels.split_last() {
+                Some((root, labels)) if root.is_empty() && !labels.is_empty() => domain.len() - 1,
+                _ => domain.len(),
+            };
+            if domain_length == 0 || domain_length > MAX_DOMAIN_NAME_LENGTH {
+                errors.insert(IdnaError::DomainNameLength);
+            }
+            if ascii_labels
+                .iter()
+                .any(|label| label.is_empty() || label.len() > MAX_LABEL_LENGTH)
+            {
+                errors.insert(IdnaError::LabelLength);
+            }
+        }
+
+        IdnaResult { domain, errors }
+    }
+
+    /// Converts a domain name to its Unicode form, as used for display (UTS #46 `ToUnicode`).
+    ///
+    /// Labels starting with `xn--` are decoded from Punycode.
+    /// [`IdnaOptions::verify_dns_length`] does not apply.
+    pub fn to_unicode(&self, domain: &str, options: IdnaOptions) -> IdnaResult {
+        let mut errors = IdnaErrors::default();
+        let labels = self.process(domain, options, &mut errors);
+        let domain = labels
+            .into_iter()
+            .map(|label| label.text)
+            .collect::<Vec<_>>()
+            .join(".");
+        IdnaResult { domain, errors }
+    }
+
+    /// The processing steps of UTS #46, section 4, returning the labels of the domain name.
+    fn process(&self, domain: &str, options: IdnaOptions, errors: &mut IdnaErrors) -> Vec<Label> {
+        let mapped = self
+            .mapper
+            .map_normalize(domain.chars())
+            .collect::<String>();
+
+        let original = mapped
+            .contains('\u{FFFD}')
+            .then(|| self.restore_disallowed(domain, &mapped));
+        let mut original_labels = original.as_deref().map(|original| original.split('.'));
+
+        let mut labels = Vec::new();
+        for label in mapped.split('.') {
+            let original = original_labels
+                .as_mut()
+                .and_then(Iterator::next)
+                .filter(|&original| original != label)
+                .map(String::from);
+            let Some(encoded) = label.strip_prefix(ACE_PREFIX) else {
+                labels.push(Label {
+                    text: label.into(),
+                    original,
+                    decoded: false,
+                    validate: true,
+                });
+                continue;
+            };
+            let decoded = if encoded.is_ascii() {
+                punycode::decode(encoded).ok()
+            } else {
+                None
+            };
+            match decoded {
+                Some(decoded) => {
+                    if decoded.is_empty() || decoded.is_ascii() {
+                        errors.insert(IdnaError::Punycode);
+                    }
+                    labels.push(Label {
+                        text: decoded,
+                        original: None,
+                        decoded: true,
+                        validate: true,
+                    });
+                }
+                None => {
+                    if !options.ignore_invalid_punycode {
+                        errors.insert(IdnaError::Punycode);
+                    }
+                    labels.push(Label {
+                        text: label.into(),
+                        original,
+                        decoded: false,
+                        validate: options.ignore_invalid_punycode,
+                    });
+                }
+            }
+        }
+
+        // A bidi domain name contains a right-to-left letter or an Arabic-Indic digit
+        let is_bidi_domain_name = options.check_bidi
+            && labels.iter().any(|label| {
+                label.original().chars().any(|c| {
+                    matches!(
+                        self.bidi_class.get(c),
+                        BidiClass::RightToLeft | BidiClass::ArabicLetter | BidiClass::ArabicNumber
+                    )
+                })
+            });
+
+        for label in labels.iter().filter(|label| label.validate) {
+            self.validate(label, options, is_bidi_domain_name, errors);
+        }
+        labels
+    }
+
+    /// Replaces the U+FFFD in the mapped domain name with the disallowed code points of the
+    /// source that they replaced.
+    ///
+    /// U+FFFD is a starter that does not compose, so normalization keeps the replacements in
+    /// the order of the source.
+    fn restore_disallowed(&self, domain: &str, mapped: &str) -> String {
+        let mut disallowed = domain.chars().filter(|&c| {
+            self.mapper
+                .map_normalize(core::iter::once(c))
+                .eq(core::iter::once('\u{FFFD}'))
+        });
+        mapped
+            .chars()
+            .map(|c| match c {
+                '\u{FFFD}' => disallowed.next().unwrap_or(c),
+                _ => c,
+            })
+            .collect()
+    }
+
+    /// The validity criteria of UTS #46, section 4.1.
+    fn validate(
+        &self,
+        label: &Label,
+        options: IdnaOptions,
+        is_bidi_domain_name: bool,
+        errors: &mut IdnaErrors,
+    ) {
+        let text = label.text.as_str();
+
+        if label.decoded && !self.nfc.is_normalized(text) {
+            errors.insert(IdnaError::NotNfc);
+        }
+        if options.check_hyphens {
+            if text.chars().skip(2).take(2).eq(['-', '-']) {
+                errors.insert(IdnaError::HyphensInThirdAndFourthPositions);
+            }
+            if text.starts_with('-') || text.ends_with('-') {
+                errors.insert(IdnaError::LeadingOrTrailingHyphen);
+            }
+        }
+        // Labels decoded from Punycode must not look like Punycode themselves. Other labels
+        // starting with `xn--` already fail the hyphen check.
+        if (label.decoded || !options.check_hyphens) && text.starts_with(ACE_PREFIX) {
+            errors.insert(IdnaError::AcePrefix);
+        }
+        if text.contains('.') {
+            errors.insert(IdnaError::FullStop);
+        }
+        if label
+            .original()
+            .chars()
+            .next()
+            .is_some_and(|c| GeneralCategoryGroup::Mark.contains(self.general_category.get(c)))
+        {
+            errors.insert(IdnaError::LeadingCombiningMark);
+        }
+        // Disallowed code points have been replaced by U+FFFD, which is disallowed itself.
+        // Labels decoded from Punycode have not been mapped yet, so they must be unchanged
+        // by the mapping.
+        if text.contains('\u{FFFD}')
+            || (label.decoded
+                && !errors.contains(IdnaError::NotNfc)
+                && !self
+                    .mapper
+                    .normalize_validate(text.chars())
+                    .eq(text.chars()))
+        {
+            errors.insert(IdnaError::InvalidCodePoint);
+        }
+        if options.use_std3_ascii_rules
+            && text
+                .bytes()
+                .any(|b| b.is_ascii() && !matches!(b, b'a'..=b'z' | b'0'..=b'9' | b'-'))
+        {
+            errors.insert(IdnaError::Std3AsciiRules);
+        }
+
+        let chars = label.original().chars().collect::<Vec<_>>();
+        if options.check_joiners {
+            self.check_joiners(&chars, errors);
+        }
+        if options.check_context_o {
+            self.check_context_o(&chars, errors);
+        }
+        if is_bidi_domain_name {
+            self.check_bidi(&chars, errors);
+        }
+    }
+
+    /// The CONTEXTJ rules of RFC 5892, appendices A.1 and A.2.
+    fn check_joiners(&self, chars: &[char], errors: &mut IdnaErrors) {
+        for (index, &c) in chars.iter().enumerate() {
+            if !matches!(c, '\u{200C}' | '\u{200D}') {
+                continue;
+            }
+            let (before, after) = chars.split_at(index);
+            let after = after.get(1..).unwrap_or_default();
+            if before.last().is_some_and(|&b| {
+                self.canonical_combining_class.get(b) == CanonicalCombiningClass::Virama
+            }) {
+                continue;
+            }
+            if c == '\u{200D}' {
+                errors.insert(IdnaError::ZeroWidthJoiner);
+                continue;
+            }
+            // (Joining_Type:{L,D})(Joining_Type:T)*\u200C(Joining_Type:T)*(Joining_Type:{R,D})
+            if !matches!(
+                self.first_non_transparent(before.iter().rev()),
+                Some(JoiningType::LeftJoining | JoiningType::DualJoining)
+            ) || !matches!(
+                self.first_non_transparent(after.iter()),
+                Some(JoiningType::RightJoining | JoiningType::DualJoining)
+            ) {
+                errors.insert(IdnaError::ZeroWidthNonJoiner);
+            }
+        }
+    }
+
+    /// Returns the first joining type that is not transparent.
+    fn first_non_transparent<'c>(
+        &self,
+        chars: impl Iterator<Item = &'c char>,
+    ) -> Option<JoiningType> {
+        chars
+            .map(|&c| self.joining_type.get(c))
+            .find(|&t| t != JoiningType::Transparent)
+    }
+
+    /// The CONTEXTO rules of RFC 5892, appendices A.3 to A.9.
+    fn check_context_o(&self, chars: &[char], errors: &mut IdnaErrors) {
+        for (index, &c) in chars.iter().enumerate() {
+            let before = index.checked_sub(1).and_then(|i| chars.get(i)).copied();
+            let after = chars.get(index + 1).copied();
+            let valid = match c {
+                // MIDDLE DOT
+                '\u{00B7}' => before == Some('l') && after == Some('l'),
+                // GREEK LOWER NUMERAL SIGN (KERAIA)
+                '\u{0375}' => after.is_some_and(|a| self.script.get(a) == Script::Greek),
+                // HEBREW PUNCTUATION GERESH and GERSHAYIM
+                '\u{05F3}' | '\u{05F4}' => {
+                    before.is_some_and(|b| self.script.get(b) == Script::Hebrew)
+                }
+                // KATAKANA MIDDLE DOT
+                '\u{30FB}' => chars.iter().any(|&c| {
+                    matches!(
+                        self.script.get(c),
+                        Script::Hiragana | Script::Katakana | Script::Han
+                    )
+                }),
+                // ARABIC-INDIC DIGITS
+                '\u{0660}'..='\u{0669}' => {
+                    !chars.iter().any(|c| ('\u{06F0}'..='\u{06F9}').contains(c))
+                }
+                // EXTENDED ARABIC-INDIC DIGITS
+                '\u{06F0}'..='\u{06F9}' => {
+                    !chars.iter().any(|c| ('\u{0660}'..='\u{0669}').contains(c))
+                }
+                _ => true,
+            };
+            if !valid {
+                errors.insert(IdnaError::ContextO);
+            }
+        }
+    }
+
+    /// The bidi rules of RFC 5893, section 2.
+    fn check_bidi(&self, chars: &[char], errors: &mut IdnaErrors) {
+        use BidiClass as B;
+
+        let classes = chars
+            .iter()
+            .map(|&c| self.bidi_class.get(c))
+            .collect::<Vec<_>>();
+        // The class of the last character that is not a nonspacing mark
+        let end = classes
+            .iter()
+            .rev()
+            .copied()
+            .find(|&c| c != B::NonspacingMark);
+
+        let rtl = match classes.first().copied() {
+            // Empty labels, such as the root label, are checked by `VerifyDnsLength` instead
+            None => return,
+            Some(B::RightToLeft | B::ArabicLetter) => true,
+            Some(B::LeftToRight) => false,
+            // The direction is unknown, so the other rules do not apply
+            Some(_) => {
+                errors.insert(IdnaError::BidiFirstCharacter);
+                return;
+            }
+        };
+
+        if rtl {
+            if !classes.iter().all(|c| {
+                matches!(
+                    *c,
+                    B::RightToLeft
+                        | B::ArabicLetter
+                        | B::ArabicNumber
+                        | B::EuropeanNumber
+                        | B::EuropeanSeparator
+                        | B::CommonSeparator
+                        | B::EuropeanTerminator
+                        | B::OtherNeutral
+                        | B::BoundaryNeutral
+                        | B::NonspacingMark
+                )
+            }) {
+                errors.insert(IdnaError::BidiRtlCharacter);
+            }
+            if !matches!(
+                end,
+                Some(B::RightToLeft | B::ArabicLetter | B::EuropeanNumber | B::ArabicNumber)
+            ) {
+                errors.insert(IdnaError::BidiRtlEnd);
+            }
+            if classes.contains(&B::EuropeanNumber) && classes.contains(&B::ArabicNumber) {
+                errors.insert(IdnaError::BidiMixedNumbers);
+            }
+        } else {
+            if !classes.iter().all(|c| {
+                matches!(
+                    *c,
+                    B::LeftToRight
+                        | B::EuropeanNumber
+                        | B::EuropeanSeparator
+                        | B::CommonSeparator
+                        | B::EuropeanTerminator
+                        | B::OtherNeutral
+                        | B::BoundaryNeutral
+                        | B::NonspacingMark
+                )
+            }) {
+                errors.insert(IdnaError::BidiLtrCharacter);
+            }
+            if !matches!(end, Some(B::LeftToRight | B::EuropeanNumber)) {
+                errors.insert(IdnaError::BidiLtrEnd);
+            }
+        }
+    }
+}
@@ -0,0 +1,256 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The [Punycode](https://www.rfc-editor.org/rfc/rfc3492) encoding of Unicode strings
+//! as ASCII strings, used for the labels of internationalized domain names.
+//!
+//! These functions convert single labels without the `xn--` prefix; see
+//! [`IdnaProcessor`](super::IdnaProcessor) for the conversion of domain names.
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::experimental::idna::punycode;
+//!
+//! assert_eq!(punycode::encode("bücher").as_deref(), Ok("bcher-kva"));
+//! assert_eq!(punycode::decode("bcher-kva").as_deref(), Ok("bücher"));
+//! ```
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use displaydoc::Display;
+
+const BASE: u32 = 36;
+const T_MIN: u32 = 1;
+const T_MAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 0x80;
+const DELIMITER: char = '-';
+
+/// An error returned when converting to or from Punycode.
+#[derive(Display, Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PunycodeError {
+    /// The input contains a character that is not a Punycode digit, or a non-ASCII character
+    /// before the last delimiter.
+    #[displaydoc("Invalid Punycode digit")]
+    InvalidDigit,
+    /// The input ends in the middle of an encoded code point.
+    #[displaydoc("Unexpected end of Punycode input")]
+    UnexpectedEnd,
+    /// The input decodes to a value that is not a Unicode scalar value.
+    #[displaydoc("Punycode decodes to an invalid code point")]
+    InvalidCodePoint,
+    /// An intermediate value exceeds the range of the algorithm.
+    #[displaydoc("Punycode overflow")]
+    Overflow,
+}
+
+impl core::error::Error for PunycodeError {}
+
+/// The bias adaptation function of RFC 3492, section 6.1.
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = delta / if first_time { DAMP } else { 2 };
+    delta += delta / num_points;
+    let mut k = 0;
+    while delta > ((BASE - T_MIN) * T_MAX) / 2 {
+        delta /= BASE - T_MIN;
+        k += BASE;
+    }
+    k + (BASE - T_MIN + 1) * delta / (delta + SKEW)
+}
+
+/// The threshold for the digit at position `k` of a variable-length integer.
+fn threshold(k: u32, bias: u32) -> u32 {
+    if k <= bias {
+        T_MIN
+    } else if k >= bias + T_MAX {
+        T_MAX
+    } else {
+        k - bias
+    }
+}
+
+fn digit_value(byte: u8) -> Option<u32> {
+    match byte {
+        b'a'..=b'z' => Some(u32::from(byte - b'a')),
+        b'A'..=b'Z' => Some(u32::from(byte - b'A')),
+        b'0'..=b'9' => Some(u32::from(byte - b'0') + 26),
+        _ => None,
+    }
+}
+
+fn digit_char(digit: u32) -> char {
+    debug_assert!(digit < BASE);
+    #[expect(clippy::cast_possible_truncation)] // digit < 36
+    if digit < 26 {
+        char::from(b'a' + digit as u8)
+    } else {
+        char::from(b'0' + (digit - 26) as u8)
+    }
+}
+
+/// Decodes a Punycode string into a Unicode string.
+///
+/// Uppercase and lowercase digits are treated alike. The case of the basic code points
+/// is preserved.
+pub fn decode(input: &str) -> Result<String, PunycodeError> {
+    // A delimiter only follows basic code points, so a leading one is an invalid digit
+    let (basic, extended) = match input.rfind(DELIMITER) {
+        Some(index) if index > 0 => (
+            input.get(..index).unwrap_or_default(),
+            input.get(index + 1..).unwrap_or_default(),
+        ),
+        _ => ("", input),
+    };
+    if !basic.is_ascii() {
+        return Err(PunycodeError::InvalidDigit);
+    }
+
+    let mut output = basic.chars().collect::<Vec<_>>();
+    let mut n = INITIAL_N;
+    let mut i = 0u32;
+    let mut bias = INITIAL_BIAS;
+    let mut bytes = extended.bytes().peekable();
+    while bytes.peek().is_some() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+        loop {
+            let digit = bytes
+                .next()
+                .ok_or(PunycodeError::UnexpectedEnd)
+                .and_then(|byte| digit_value(byte).ok_or(PunycodeError::InvalidDigit))?;
+            i = digit
+                .checked_mul(w)
+                .and_then(|d| i.checked_add(d))
+                .ok_or(PunycodeError::Overflow)?;
+            let t = threshold(k, bias);
+            if digit < t {
+                break;
+            }
+            w = w.checked_mul(BASE - t).ok_or(PunycodeError::Overflow)?;
+            k += BASE;
+        }
+        let length = u32::try_from(output.len() + 1).map_err(|_| PunycodeError::Overflow)?;
+        bias = adapt(i - old_i, length, old_i == 0);
+        n = n.checked_add(i / length).ok_or(PunycodeError::Overflow)?;
+        i %= length;
+        output.insert(
+            i as usize,
+            char::from_u32(n).ok_or(PunycodeError::InvalidCodePoint)?,
+        );
+        i += 1;
+    }
+    Ok(output.into_iter().collect())
+}
+
+/// Encodes a Unicode string as Punycode.
+///
+/// The basic code points are copied to the output in order, followed by a delimiter
+/// and the lowercase encoding of the other code points.
+pub fn encode(input: &str) -> Result<String, PunycodeError> {
+    let code_points = input.chars().map(u32::from).collect::<Vec<_>>();
+    let mut output = input.chars().filter(char::is_ascii).collect::<String>();
+    let basic_count = u32::try_from(output.len()).map_err(|_| PunycodeError::Overflow)?;
+    let length = u32::try_from(code_points.len()).map_err(|_| PunycodeError::Overflow)?;
+    if basic_count > 0 {
+        output.push(DELIMITER);
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta = 0u32;
+    let mut bias = INITIAL_BIAS;
+    let mut handled = basic_count;
+    while handled < length {
+        // The smallest code point that has not been handled yet
+        let m = code_points
+            .iter()
+            .copied()
+            .filter(|&c| c >= n)
+            .min()
+            .unwrap_or(n);
+        delta = (m - n)
+            .checked_mul(handled + 1)
+            .and_then(|d| delta.checked_add(d))
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+        for &c in &code_points {
+            if c < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            } else if c == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = threshold(k, bias);
+                    if q < t {
+                        break;
+                    }
+                    output.push(digit_char(t + (q - t) % (BASE - t)));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(digit_char(q));
+                bias = adapt(delta, handled + 1, handled == basic_count);
+                delta = 0;
+                handled += 1;
+            }
+        }
+        delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+        n += 1;
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc_3492_samples() {
+        // Samples from RFC 3492, section 7.1
+        for (decoded, encoded) in [
+            // (A) Arabic (Egyptian)
+            (
+                "\u{0644}\u{064A}\u{0647}\u{0645}\u{0627}\u{0628}\u{062A}\u{0643}\u{0644}\u{0645}\u{0648}\u{0634}\u{0639}\u{0631}\u{0628}\u{064A}\u{061F}",
+                "egbpdaj6bu4bxfgehfvwxn",
+            ),
+            // (B) Chinese (simplified)
+            (
+                "\u{4ED6}\u{4EEC}\u{4E3A}\u{4EC0}\u{4E48}\u{4E0D}\u{8BF4}\u{4E2D}\u{6587}",
+                "ihqwcrb4cv8a8dqg056pqjye",
+            ),
+            // (I) Russian (Cyrillic)
+            (
+                "\u{043F}\u{043E}\u{0447}\u{0435}\u{043C}\u{0443}\u{0436}\u{0435}\u{043E}\u{043D}\u{0438}\u{043D}\u{0435}\u{0433}\u{043E}\u{0432}\u{043E}\u{0440}\u{044F}\u{0442}\u{043F}\u{043E}\u{0440}\u{0443}\u{0441}\u{0441}\u{043A}\u{0438}",
+                "b1abfaaepdrnnbgefbadotcwatmq2g4l",
+            ),
+            // (L) 3<nen>B<gumi><kinpachi><sensei>
+            (
+                "3\u{5E74}B\u{7D44}\u{91D1}\u{516B}\u{5148}\u{751F}",
+                "3B-ww4c5e180e575a65lsy2b",
+            ),
+            // (R) <sono><supiido><de>
+            ("\u{305D}\u{306E}\u{30B9}\u{30D4}\u{30FC}\u{30C9}\u{3067}", "d9juau41awczczp"),
+            // (S) -> $1.00 <-
+            ("-> $1.00 <-", "-> $1.00 <--"),
+        ] {
+            assert_eq!(encode(decoded).as_deref(), Ok(encoded), "{decoded}");
+            assert_eq!(decode(encoded).as_deref(), Ok(decoded), "{encoded}");
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(decode("a-b!"), Err(PunycodeError::InvalidDigit));
+        assert_eq!(decode("ü-a"), Err(PunycodeError::InvalidDigit));
+        assert_eq!(decode("a-z"), Err(PunycodeError::UnexpectedEnd));
+        assert_eq!(decode("99999999999"), Err(PunycodeError::Overflow));
+        assert_eq!(decode("a-"), Ok("a".into()));
+        assert_eq!(decode("-"), Err(PunycodeError::InvalidDigit));
+        assert_eq!(decode(""), Ok("".into()));
+    }
+}
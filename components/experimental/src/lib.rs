@@ -21,9 +21,13 @@ pub mod compactdecimal;
 pub mod dimension;
 pub mod displaynames;
 pub mod duration;
+pub mod idna;
 pub mod measure;
+pub mod numberformat;
 pub mod personnames;
+pub mod rbnf;
 pub mod relativetime;
+pub mod spoof;
 pub mod transliterate;
 pub mod unicodeset_parse;
 pub mod units;
@@ -86,6 +90,8 @@ pub mod provider {
         impl_short_second_relative_v1!(Baked);
         impl_short_week_relative_v1!(Baked);
         impl_short_year_relative_v1!(Baked);
+        impl_rbnf_rules_v1!(Baked);
+        impl_spoof_data_v1!(Baked);
         impl_transliterator_rules_v1!(Baked);
         impl_units_info_v1!(Baked);
         impl_unit_ids_v1!(Baked);
@@ -117,6 +123,7 @@ pub mod provider {
         super::displaynames::provider::VariantDisplayNamesV1::INFO,
         super::measure::provider::UnitIdsV1::INFO,
         super::personnames::provider::PersonNamesFormatV1::INFO,
+        super::rbnf::provider::RbnfRulesV1::INFO,
         super::relativetime::provider::LongDayRelativeV1::INFO,
         super::relativetime::provider::LongHourRelativeV1::INFO,
         super::relativetime::provider::LongMinuteRelativeV1::INFO,
@@ -141,6 +148,7 @@ pub mod provider {
         super::relativetime::provider::ShortSecondRelativeV1::INFO,
         super::relativetime::provider::ShortWeekRelativeV1::INFO,
         super::relativetime::provider::ShortYearRelativeV1::INFO,
+        super::spoof::provider::SpoofDataV1::INFO,
         super::transliterate::provider::TransliteratorRulesV1::INFO,
         super::units::provider::UnitsInfoV1::INFO,
     ];
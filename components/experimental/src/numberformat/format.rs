@@ -0,0 +1,148 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::{CompactDecimal, Decimal, ScientificDecimal};
+use writeable::Writeable;
+
+use super::formatter::{Inner, NumberFormatter};
+
+/// The number to format, after applying the options of the [`NumberFormatter`].
+#[derive(Debug)]
+pub(crate) enum FormattedValue {
+    Decimal(Decimal),
+    Scientific(ScientificDecimal),
+    Compact(CompactDecimal),
+}
+
+/// A number formatted with a [`NumberFormatter`].
+#[derive(Debug)]
+pub struct FormattedNumber<'l> {
+    pub(crate) formatter: &'l NumberFormatter,
+    pub(crate) value: FormattedValue,
+}
+
+impl Writeable for FormattedNumber<'_> {
+    fn write_to<W>(&self, sink: &mut W) -> core::result::Result<(), core::fmt::Error>
+    where
+        W: core::fmt::Write + ?Sized,
+    {
+        match (&self.formatter.inner, &self.value) {
+            (Inner::Decimal(formatter), FormattedValue::Decimal(value)) => {
+                formatter.format(value).write_to(sink)
+            }
+            (Inner::Scientific(formatter), FormattedValue::Scientific(value)) => {
+                formatter.format_scientific(value).write_to(sink)
+            }
+            (Inner::Compact(formatter), FormattedValue::Decimal(value)) => {
+                formatter.format_fixed_decimal(value).write_to(sink)
+            }
+            (Inner::Compact(formatter), FormattedValue::Compact(value)) => formatter
+                .format_compact_decimal(value)
+                .map_err(|_| core::fmt::Error)?
+                .write_to(sink),
+            (Inner::Percent(formatter), FormattedValue::Decimal(value)) => {
+                formatter.format(value).write_to(sink)
+            }
+            (Inner::Currency(formatter, currency_code), FormattedValue::Decimal(value)) => {
+                formatter
+                    .format_fixed_decimal(value, *currency_code)
+                    .write_to(sink)
+            }
+            (Inner::Unit(formatter), FormattedValue::Decimal(value)) => {
+                formatter.format_fixed_decimal(value).write_to(sink)
+            }
+            // Not created by `NumberFormatter::format`
+            _ => Err(core::fmt::Error),
+        }
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedNumber<'_>);
+
+#[cfg(test)]
+mod tests {
+    use icu_locale_core::locale;
+    use writeable::assert_writeable_eq;
+
+    use crate::numberformat::options::{Notation, NumberFormatterOptions, UnitWidth};
+    use crate::numberformat::{NumberFormatter, NumberFormatterLoadError};
+
+    fn format(
+        locale: icu_locale_core::Locale,
+        skeleton: &str,
+        value: &str,
+    ) -> alloc::string::String {
+        let options = NumberFormatterOptions::try_from_skeleton(skeleton).unwrap();
+        let formatter = NumberFormatter::try_new(locale.into(), options).unwrap();
+        writeable::Writeable::write_to_string(&formatter.format(&value.parse().unwrap()))
+            .into_owned()
+    }
+
+    #[test]
+    pub fn test_decimal() {
+        assert_eq!(format(locale!("en"), "", "12345.678"), "12,345.678");
+        assert_eq!(format(locale!("en"), ".00", "1.5"), "1.50");
+        assert_eq!(format(locale!("en"), ".0#", "1.234"), "1.23");
+        assert_eq!(format(locale!("en"), "@@", "0.01234"), "0.012");
+        assert_eq!(
+            format(
+                locale!("en"),
+                "precision-integer rounding-mode-floor",
+                "1.9"
+            ),
+            "1"
+        );
+        assert_eq!(
+            format(locale!("en"), "precision-increment/0.05", "1.23"),
+            "1.25"
+        );
+        assert_eq!(format(locale!("en"), "integer-width/000", "7"), "007");
+        assert_eq!(format(locale!("en"), "integer-width/##0", "12345"), "345");
+        assert_eq!(format(locale!("en"), "group-off", "12345"), "12345");
+        assert_eq!(format(locale!("en"), "sign-always", "5"), "+5");
+        assert_eq!(format(locale!("en"), "scale/1000", "1.5"), "1,500");
+        assert_eq!(format(locale!("en-u-nu-arab"), "latin", "12"), "12");
+    }
+
+    #[test]
+    pub fn test_notation() {
+        assert_eq!(format(locale!("en"), "scientific", "12345"), "1.2345E4");
+        assert_eq!(format(locale!("en"), "E0 @@", "0.001234"), "1.2E-3");
+        assert_eq!(format(locale!("en"), "E0 precision-integer", "9.7"), "1E1");
+        assert_eq!(format(locale!("en"), "engineering", "12345"), "12.345E3");
+        assert_eq!(format(locale!("en"), "compact-short", "12345"), "12K");
+        assert_eq!(format(locale!("en"), "K @@", "12345"), "12K");
+        assert_eq!(format(locale!("en"), "K precision-integer", "999999"), "1M");
+        assert_eq!(
+            format(locale!("en"), "compact-long @@", "12345"),
+            "12 thousand"
+        );
+    }
+
+    #[test]
+    pub fn test_dimensions() {
+        let options = NumberFormatterOptions::try_from_skeleton("currency/EUR .00").unwrap();
+        let formatter = NumberFormatter::try_new(locale!("en").into(), options).unwrap();
+        assert_writeable_eq!(formatter.format(&"1234.5".parse().unwrap()), "€1,234.50");
+
+        assert_eq!(format(locale!("en"), "percent", "12.5"), "12.5%");
+        assert_eq!(
+            format(locale!("en"), "%x100 precision-integer", "0.125"),
+            "12%"
+        );
+        assert_eq!(format(locale!("de"), "%x100", "0.5"), "50\u{a0}%");
+
+        let options =
+            NumberFormatterOptions::try_from_skeleton("currency/EUR unit-width-full-name").unwrap();
+        assert_eq!(
+            NumberFormatter::try_new(locale!("en").into(), options).unwrap_err(),
+            NumberFormatterLoadError::UnsupportedCurrencyWidth(UnitWidth::Long)
+        );
+        let options = NumberFormatterOptions::try_from_skeleton("percent scientific").unwrap();
+        assert_eq!(
+            NumberFormatter::try_new(locale!("en").into(), options).unwrap_err(),
+            NumberFormatterLoadError::UnsupportedNotation(Notation::Scientific)
+        );
+    }
+}
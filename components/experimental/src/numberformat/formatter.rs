@@ -0,0 +1,477 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use core::fmt;
+use displaydoc::Display;
+use fixed_decimal::{
+    CompactDecimal, Decimal, FixedInteger, ScientificDecimal, SignedRoundingMode,
+    UnsignedRoundingMode,
+};
+use icu_decimal::options::DecimalFormatterOptions;
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences, ScientificDecimalFormatter};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::{PluralRules, PluralRulesPreferences};
+use icu_provider::prelude::*;
+
+use super::format::{FormattedNumber, FormattedValue};
+use super::options::{Notation, NumberFormatterOptions, Precision, Style, UnitWidth};
+use crate::compactdecimal::{
+    CompactDecimalFormatter, CompactDecimalFormatterOptions, CompactDecimalFormatterPreferences,
+};
+use crate::dimension::currency::formatter::{CurrencyFormatter, CurrencyFormatterPreferences};
+use crate::dimension::currency::options::{CurrencyFormatterOptions, Width as CurrencyWidth};
+use crate::dimension::currency::CurrencyCode;
+use crate::dimension::percent::formatter::{PercentFormatter, PercentFormatterPreferences};
+use crate::dimension::units::formatter::{UnitsFormatter, UnitsFormatterPreferences};
+use crate::dimension::units::options::{UnitsFormatterOptions, Width as UnitsWidth};
+
+define_preferences!(
+    /// The preferences for number formatting.
+    [Copy]
+    NumberFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: super::preferences::NumberingSystem
+    }
+);
+
+prefs_convert!(NumberFormatterPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(
+    NumberFormatterPreferences,
+    CompactDecimalFormatterPreferences,
+    { numbering_system }
+);
+prefs_convert!(NumberFormatterPreferences, CurrencyFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(NumberFormatterPreferences, PercentFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(NumberFormatterPreferences, UnitsFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(NumberFormatterPreferences, PluralRulesPreferences);
+
+/// An error from constructing a [`NumberFormatter`].
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum NumberFormatterLoadError {
+    /// The [`Notation`] is not [`Notation::Standard`] for a [`Style`] other than
+    /// [`Style::Decimal`]; only plain numbers can be formatted in scientific, engineering
+    /// or compact notation.
+    #[displaydoc("Notation {0:?} is only supported for plain numbers")]
+    UnsupportedNotation(Notation),
+    /// The [`UnitWidth`] is [`UnitWidth::Long`] for a [`Style::Currency`]; currencies
+    /// cannot be formatted with their full names.
+    #[displaydoc("Unit width {0:?} is not supported for currencies")]
+    UnsupportedCurrencyWidth(UnitWidth),
+    /// An error while loading data.
+    #[displaydoc("{0}")]
+    Data(DataError),
+}
+
+impl core::error::Error for NumberFormatterLoadError {}
+
+impl From<DataError> for NumberFormatterLoadError {
+    fn from(error: DataError) -> Self {
+        Self::Data(error)
+    }
+}
+
+/// The formatter a [`NumberFormatter`] dispatches to.
+pub(crate) enum Inner {
+    Decimal(DecimalFormatter),
+    Scientific(ScientificDecimalFormatter),
+    Compact(CompactDecimalFormatter),
+    Percent(PercentFormatter<DecimalFormatter>),
+    Currency(CurrencyFormatter, CurrencyCode),
+    Unit(UnitsFormatter),
+}
+
+/// A formatter for numbers, percentages, amounts of money and measurements, configured with
+/// a single set of [`NumberFormatterOptions`].
+///
+/// Depending on the [`Style`] and [`Notation`] of the options, numbers are formatted with
+/// [`DecimalFormatter`], [`CompactDecimalFormatter`], [`PercentFormatter`],
+/// [`CurrencyFormatter`] or [`UnitsFormatter`]. The precision, rounding, integer width,
+/// scale and sign display of the options are applied to the number beforehand.
+///
+/// The options can be parsed from ICU number skeletons with
+/// [`NumberFormatterOptions::try_from_skeleton`], so that the same string configures the
+/// formatting of numbers in ICU4X and ICU.
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::numberformat::NumberFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter = NumberFormatter::try_new(
+///     locale!("en").into(),
+///     "currency/EUR precision-integer group-auto".parse().unwrap(),
+/// )
+/// .unwrap();
+/// let value = "12345.67".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "€12,346");
+///
+/// let formatter =
+///     NumberFormatter::try_new(locale!("en").into(), "K @@".parse().unwrap()).unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "12K");
+///
+/// let formatter =
+///     NumberFormatter::try_new(locale!("fr").into(), "%x100 .0".parse().unwrap()).unwrap();
+/// let value = "0.123".parse().unwrap();
+/// assert_writeable_eq!(formatter.format(&value), "12,3\u{a0}%");
+/// ```
+pub struct NumberFormatter {
+    pub(crate) options: NumberFormatterOptions,
+    pub(crate) inner: Inner,
+}
+
+impl fmt::Debug for NumberFormatter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NumberFormatter")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
+    }
+}
+
+impl NumberFormatter {
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: NumberFormatterPreferences, options: NumberFormatterOptions) -> error: NumberFormatterLoadError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    /// Creates a new [`NumberFormatter`] from compiled data and an options bag.
+    ///
+    /// Returns a [`NumberFormatterLoadError`] if the combination of options is not
+    /// supported, such as compact notation for a currency.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(
+        prefs: NumberFormatterPreferences,
+        options: NumberFormatterOptions,
+    ) -> Result<Self, NumberFormatterLoadError> {
+        let prefs = resolve_prefs(prefs, &options)?;
+        let decimal_formatter =
+            || DecimalFormatter::try_new((&prefs).into(), decimal_formatter_options(&options));
+        let inner = match (&options.style, options.notation) {
+            (Style::Decimal, Notation::CompactShort) => {
+                Inner::Compact(CompactDecimalFormatter::try_new_short(
+                    (&prefs).into(),
+                    compact_decimal_formatter_options(&options),
+                )?)
+            }
+            (Style::Decimal, Notation::CompactLong) => {
+                Inner::Compact(CompactDecimalFormatter::try_new_long(
+                    (&prefs).into(),
+                    compact_decimal_formatter_options(&options),
+                )?)
+            }
+            (Style::Decimal, Notation::Scientific | Notation::Engineering) => Inner::Scientific(
+                ScientificDecimalFormatter::try_new((&prefs).into(), Default::default())?,
+            ),
+            (Style::Decimal, _) => Inner::Decimal(decimal_formatter()?),
+            (Style::Percent, _) => {
+                Inner::Percent(PercentFormatter::try_new_with_decimal_formatter(
+                    (&prefs).into(),
+                    decimal_formatter()?,
+                    Default::default(),
+                )?)
+            }
+            (Style::Currency(currency_code), _) => Inner::Currency(
+                CurrencyFormatter::try_new_with_decimal_formatter_unstable(
+                    &crate::provider::Baked,
+                    (&prefs).into(),
+                    decimal_formatter()?,
+                    currency_formatter_options(&options)?,
+                )?,
+                *currency_code,
+            ),
+            (Style::Unit(unit), _) => {
+                Inner::Unit(UnitsFormatter::try_new_with_formatters_unstable(
+                    &crate::provider::Baked,
+                    (&prefs).into(),
+                    unit,
+                    decimal_formatter()?,
+                    PluralRules::try_new_cardinal((&prefs).into())?,
+                    units_formatter_options(&options),
+                )?)
+            }
+        };
+        Ok(Self { options, inner })
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: NumberFormatterPreferences,
+        options: NumberFormatterOptions,
+    ) -> Result<Self, NumberFormatterLoadError>
+    where
+        D: ?Sized
+            + DataProvider<crate::compactdecimal::provider::ShortCompactDecimalFormatDataV1>
+            + DataProvider<crate::compactdecimal::provider::LongCompactDecimalFormatDataV1>
+            + DataProvider<crate::dimension::provider::currency::essentials::CurrencyEssentialsV1>
+            + DataProvider<crate::dimension::provider::percent::PercentEssentialsV1>
+            + DataProvider<crate::dimension::provider::units::UnitsDisplayNameV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_decimal::provider::DecimalExponentSymbolsV1>
+            + DataProvider<icu_plurals::provider::PluralsCardinalV1>,
+    {
+        let prefs = resolve_prefs(prefs, &options)?;
+        let decimal_formatter = || {
+            DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                decimal_formatter_options(&options),
+            )
+        };
+        let inner = match (&options.style, options.notation) {
+            (Style::Decimal, Notation::CompactShort) => {
+                Inner::Compact(CompactDecimalFormatter::try_new_short_unstable(
+                    provider,
+                    (&prefs).into(),
+                    compact_decimal_formatter_options(&options),
+                )?)
+            }
+            (Style::Decimal, Notation::CompactLong) => {
+                Inner::Compact(CompactDecimalFormatter::try_new_long_unstable(
+                    provider,
+                    (&prefs).into(),
+                    compact_decimal_formatter_options(&options),
+                )?)
+            }
+            (Style::Decimal, Notation::Scientific | Notation::Engineering) => {
+                Inner::Scientific(ScientificDecimalFormatter::try_new_unstable(
+                    provider,
+                    (&prefs).into(),
+                    Default::default(),
+                )?)
+            }
+            (Style::Decimal, _) => Inner::Decimal(decimal_formatter()?),
+            (Style::Percent, _) => {
+                Inner::Percent(PercentFormatter::try_new_with_decimal_formatter_unstable(
+                    provider,
+                    (&prefs).into(),
+                    decimal_formatter()?,
+                    Default::default(),
+                )?)
+            }
+            (Style::Currency(currency_code), _) => Inner::Currency(
+                CurrencyFormatter::try_new_with_decimal_formatter_unstable(
+                    provider,
+                    (&prefs).into(),
+                    decimal_formatter()?,
+                    currency_formatter_options(&options)?,
+                )?,
+                *currency_code,
+            ),
+            (Style::Unit(unit), _) => {
+                Inner::Unit(UnitsFormatter::try_new_with_formatters_unstable(
+                    provider,
+                    (&prefs).into(),
+                    unit,
+                    decimal_formatter()?,
+                    PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?,
+                    units_formatter_options(&options),
+                )?)
+            }
+        };
+        Ok(Self { options, inner })
+    }
+
+    /// Formats a [`Decimal`], returning a [`FormattedNumber`].
+    pub fn format(&self, value: &Decimal) -> FormattedNumber<'_> {
+        let mut value = value.clone();
+        if self.options.scale != 0 {
+            value.multiply_pow10(self.options.scale);
+            // Leading zeros of the unscaled number, such as in 0.12, are not padding
+            value.absolute.trim_start();
+        }
+        let value = match (&self.inner, self.options.notation) {
+            // Without a precision, compact numbers are rounded by the compact formatter
+            (Inner::Compact(_), _) if self.options.precision == Precision::Unlimited => {
+                value.apply_sign_display(self.options.sign_display);
+                FormattedValue::Decimal(value)
+            }
+            (Inner::Compact(formatter), _) => {
+                FormattedValue::Compact(self.round_compact(formatter, value))
+            }
+            (_, Notation::Scientific | Notation::Engineering) => {
+                FormattedValue::Scientific(self.round_scientific(value))
+            }
+            _ => {
+                self.round(&mut value);
+                value.apply_sign_display(self.options.sign_display);
+                FormattedValue::Decimal(value)
+            }
+        };
+        FormattedNumber {
+            formatter: self,
+            value,
+        }
+    }
+
+    /// Applies the precision and the integer width of the options to `value`.
+    fn round(&self, value: &mut Decimal) {
+        let mode = self
+            .options
+            .rounding_mode
+            .unwrap_or(SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven));
+        match self.options.precision {
+            Precision::Unlimited => {}
+            Precision::FractionDigits { min, max } => {
+                if let Some(max) = max {
+                    value.round_with_mode(-i16::from(max), mode);
+                }
+                value.absolute.pad_end(-i16::from(min));
+            }
+            Precision::SignificantDigits { min, max } => {
+                if let Some(max) = max {
+                    let magnitude = value.absolute.nonzero_magnitude_start();
+                    value.round_with_mode(magnitude - i16::from(max) + 1, mode);
+                }
+                // Rounding can increase the magnitude, such as from 9.9 to 10
+                let magnitude = value.absolute.nonzero_magnitude_start();
+                value
+                    .absolute
+                    .pad_end((magnitude - i16::from(min) + 1).min(0));
+            }
+            Precision::Increment {
+                magnitude,
+                increment,
+            } => {
+                value.round_with_mode_and_increment(magnitude, mode, increment);
+                value.absolute.pad_end(magnitude.min(0));
+            }
+        }
+        if let Some(max) = self.options.max_integer_digits {
+            value.absolute.set_max_position(max.into());
+        }
+        if let Some(min) = self.options.min_integer_digits {
+            value.absolute.pad_start(min.into());
+        }
+    }
+
+    /// Splits `value` into a significand and a power of ten for scientific or engineering
+    /// notation, applying the precision to the significand.
+    fn round_scientific(&self, mut value: Decimal) -> ScientificDecimal {
+        let engineering = self.options.notation == Notation::Engineering;
+        let exponent_of = |value: &Decimal| {
+            let magnitude = value.absolute.nonzero_magnitude_start();
+            if engineering {
+                magnitude - magnitude.rem_euclid(3)
+            } else {
+                magnitude
+            }
+        };
+        let mut exponent = exponent_of(&value);
+        value.multiply_pow10(-exponent);
+        // Small numbers such as 0.012 would otherwise keep their leading zeros
+        value.absolute.trim_start();
+        self.round(&mut value);
+        // Rounding can increase the magnitude, such as from 9.99 to 10.0
+        let carry = exponent_of(&value);
+        if carry != 0 {
+            value.multiply_pow10(-carry);
+            exponent += carry;
+            self.round(&mut value);
+        }
+        value.apply_sign_display(self.options.sign_display);
+        ScientificDecimal::from(value, FixedInteger::from(exponent))
+    }
+
+    /// Splits `value` into a significand and the compact exponent of the locale, applying the
+    /// precision to the significand.
+    fn round_compact(
+        &self,
+        formatter: &CompactDecimalFormatter,
+        mut value: Decimal,
+    ) -> CompactDecimal {
+        let mut exponent =
+            formatter.compact_exponent_for_magnitude(value.absolute.nonzero_magnitude_start());
+        value.multiply_pow10(-i16::from(exponent));
+        self.round(&mut value);
+        // Rounding can increase the magnitude, such as from 999.9K to 1000K
+        let rounded_exponent = formatter.compact_exponent_for_magnitude(
+            value.absolute.nonzero_magnitude_start() + i16::from(exponent),
+        );
+        if rounded_exponent != exponent {
+            value.multiply_pow10(i16::from(exponent) - i16::from(rounded_exponent));
+            exponent = rounded_exponent;
+            self.round(&mut value);
+        }
+        value.apply_sign_display(self.options.sign_display);
+        CompactDecimal::from_significand_and_exponent(value, exponent)
+    }
+}
+
+/// Returns the preferences with the numbering system of the options, after checking that
+/// the combination of options is supported.
+fn resolve_prefs(
+    mut prefs: NumberFormatterPreferences,
+    options: &NumberFormatterOptions,
+) -> Result<NumberFormatterPreferences, NumberFormatterLoadError> {
+    if options.style != Style::Decimal && options.notation != Notation::Standard {
+        return Err(NumberFormatterLoadError::UnsupportedNotation(
+            options.notation,
+        ));
+    }
+    if let Some(numbering_system) = options.numbering_system {
+        prefs.numbering_system = Some(numbering_system);
+    }
+    Ok(prefs)
+}
+
+fn decimal_formatter_options(options: &NumberFormatterOptions) -> DecimalFormatterOptions {
+    let mut decimal_formatter_options = DecimalFormatterOptions::default();
+    decimal_formatter_options.grouping_strategy = options.grouping_strategy;
+    decimal_formatter_options
+}
+
+fn compact_decimal_formatter_options(
+    options: &NumberFormatterOptions,
+) -> CompactDecimalFormatterOptions {
+    match options.grouping_strategy {
+        Some(grouping_strategy) => grouping_strategy.into(),
+        None => Default::default(),
+    }
+}
+
+fn currency_formatter_options(
+    options: &NumberFormatterOptions,
+) -> Result<CurrencyFormatterOptions, NumberFormatterLoadError> {
+    Ok(match options.unit_width {
+        UnitWidth::Short => CurrencyWidth::Short,
+        UnitWidth::Narrow => CurrencyWidth::Narrow,
+        width @ UnitWidth::Long => {
+            return Err(NumberFormatterLoadError::UnsupportedCurrencyWidth(width))
+        }
+    }
+    .into())
+}
+
+fn units_formatter_options(options: &NumberFormatterOptions) -> UnitsFormatterOptions {
+    match options.unit_width {
+        UnitWidth::Short => UnitsWidth::Short,
+        UnitWidth::Narrow => UnitsWidth::Narrow,
+        UnitWidth::Long => UnitsWidth::Long,
+    }
+    .into()
+}
@@ -0,0 +1,43 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Number formatting with a single set of options, similar to ECMA-402's `Intl.NumberFormat`,
+//! which can be parsed from ICU number skeletons such as `currency/EUR precision-integer`.
+//!
+//! See [`NumberFormatter`] for details.
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        clippy::trivially_copy_pass_by_ref,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod format;
+mod formatter;
+pub mod options;
+mod skeleton;
+
+pub use format::FormattedNumber;
+pub use formatter::NumberFormatter;
+pub use formatter::NumberFormatterLoadError;
+pub use formatter::NumberFormatterPreferences;
+pub use skeleton::SkeletonError;
+
+/// Locale preferences used by this module
+pub mod preferences {
+    #[doc(inline)]
+    /// **This is a reexport of a type in [`icu::locale`](icu_locale_core::preferences::extensions::unicode::keywords)**.
+    #[doc = "\n"] // prevent autoformatting
+    pub use icu_locale_core::preferences::extensions::unicode::keywords::NumberingSystem;
+}
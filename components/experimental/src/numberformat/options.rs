@@ -0,0 +1,166 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Options for [`NumberFormatter`](super::NumberFormatter).
+
+use alloc::string::String;
+use fixed_decimal::{RoundingIncrement, SignDisplay, SignedRoundingMode};
+use icu_decimal::options::GroupingStrategy;
+
+use super::preferences::NumberingSystem;
+use crate::dimension::currency::CurrencyCode;
+
+/// A bag of options defining how numbers will be formatted by
+/// [`NumberFormatter`](super::NumberFormatter).
+///
+/// The options correspond to those of ECMA-402's `Intl.NumberFormat` and can be parsed from an
+/// [ICU number skeleton](https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html)
+/// with [`NumberFormatterOptions::try_from_skeleton`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct NumberFormatterOptions {
+    /// What the number represents, which determines the formatter it is formatted with.
+    ///
+    /// Default is [`Style::Decimal`]
+    pub style: Style,
+
+    /// Whether to display the number in standard, scientific, engineering or compact notation.
+    ///
+    /// Default is [`Notation::Standard`]
+    pub notation: Notation,
+
+    /// How to display the currency or unit.
+    ///
+    /// Default is [`UnitWidth::Short`]
+    pub unit_width: UnitWidth,
+
+    /// How many digits to display.
+    ///
+    /// Default is [`Precision::Unlimited`]
+    pub precision: Precision,
+
+    /// How to round the number if it has more digits than [`Self::precision`] allows.
+    ///
+    /// Default is [`UnsignedRoundingMode::HalfEven`](fixed_decimal::UnsignedRoundingMode::HalfEven)
+    pub rounding_mode: Option<SignedRoundingMode>,
+
+    /// The minimum number of integer digits, which are padded with leading zeros.
+    ///
+    /// Default is 1.
+    pub min_integer_digits: Option<u8>,
+
+    /// The maximum number of integer digits, which truncates the most significant digits.
+    ///
+    /// Default is no maximum.
+    pub max_integer_digits: Option<u8>,
+
+    /// When to render grouping separators.
+    ///
+    /// Default is the default of the underlying formatter, which is
+    /// [`GroupingStrategy::Min2`] for compact notation and [`GroupingStrategy::Auto`] otherwise.
+    pub grouping_strategy: Option<GroupingStrategy>,
+
+    /// When to display the sign of the number.
+    ///
+    /// Default is [`SignDisplay::Auto`]
+    pub sign_display: SignDisplay,
+
+    /// The power of ten the number is multiplied with before formatting, such as 2 to format
+    /// fractions as percentages.
+    ///
+    /// Default is 0.
+    pub scale: i16,
+
+    /// The numbering system, which takes precedence over the one in the preferences.
+    ///
+    /// Default is the numbering system of the preferences.
+    pub numbering_system: Option<NumberingSystem>,
+}
+
+/// What a number represents.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Style {
+    /// A plain number, formatted with [`DecimalFormatter`](icu_decimal::DecimalFormatter)
+    /// or [`CompactDecimalFormatter`](crate::compactdecimal::CompactDecimalFormatter).
+    #[default]
+    Decimal,
+    /// A percentage, formatted with [`PercentFormatter`](crate::dimension::percent::formatter::PercentFormatter).
+    ///
+    /// The number is not multiplied by 100; use [`NumberFormatterOptions::scale`] for that.
+    Percent,
+    /// An amount of money, formatted with [`CurrencyFormatter`](crate::dimension::currency::formatter::CurrencyFormatter).
+    Currency(CurrencyCode),
+    /// A measurement in a unit such as `meter` or `kilometer-per-hour`, formatted with
+    /// [`UnitsFormatter`](crate::dimension::units::formatter::UnitsFormatter).
+    Unit(String),
+}
+
+/// How to display the magnitude of a number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Notation {
+    /// Display all integer digits, such as `12,345`.
+    #[default]
+    Standard,
+    /// Display a single nonzero integer digit and a power of ten, such as `1.2345E4`.
+    Scientific,
+    /// Display one to three integer digits and a power of ten that is a multiple of three,
+    /// such as `12.345E3`.
+    Engineering,
+    /// Display the number with a short compact pattern, such as `12K`.
+    CompactShort,
+    /// Display the number with a long compact pattern, such as `12 thousand`.
+    CompactLong,
+}
+
+/// How to display a currency or unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum UnitWidth {
+    /// A short form, such as `$` or `m`.
+    #[default]
+    Short,
+    /// A narrow form, such as `$` or `m`, which may be ambiguous.
+    Narrow,
+    /// The full name, such as `meters`.
+    ///
+    /// This is not supported for currencies.
+    Long,
+}
+
+/// How many digits of a number to display.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Precision {
+    /// Display the number with the digits it has.
+    #[default]
+    Unlimited,
+    /// Display at least `min` and at most `max` fraction digits.
+    ///
+    /// Trailing zeros beyond the minimum are removed.
+    FractionDigits {
+        /// The minimum number of fraction digits.
+        min: u8,
+        /// The maximum number of fraction digits, or `None` for no maximum.
+        max: Option<u8>,
+    },
+    /// Display at least `min` and at most `max` significant digits.
+    ///
+    /// Trailing zeros in the fraction part beyond the minimum are removed.
+    SignificantDigits {
+        /// The minimum number of significant digits.
+        min: u8,
+        /// The maximum number of significant digits, or `None` for no maximum.
+        max: Option<u8>,
+    },
+    /// Round the number to a multiple of `increment` at the given magnitude, such as 5 at
+    /// magnitude -2 for multiples of 0.05.
+    Increment {
+        /// The magnitude of the last displayed digit.
+        magnitude: i16,
+        /// The multiples to round to.
+        increment: RoundingIncrement,
+    },
+}
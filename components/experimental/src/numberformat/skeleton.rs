@@ -0,0 +1,558 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Parsing of ICU number skeletons into [`NumberFormatterOptions`].
+
+use alloc::string::{String, ToString};
+use core::str::FromStr;
+use displaydoc::Display;
+use fixed_decimal::{
+    Decimal, RoundingIncrement, Sign, SignDisplay, SignedRoundingMode, UnsignedRoundingMode,
+};
+use icu_decimal::options::GroupingStrategy;
+use icu_locale_core::extensions::unicode::Value;
+use tinystr::TinyAsciiStr;
+
+use super::options::{Notation, NumberFormatterOptions, Precision, Style, UnitWidth};
+use super::preferences::NumberingSystem;
+use crate::dimension::currency::CurrencyCode;
+
+/// An error returned when parsing a number skeleton.
+#[derive(Display, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum SkeletonError {
+    /// A token is not part of the number skeleton syntax, or its options are invalid.
+    #[displaydoc("Invalid skeleton token: {0}")]
+    InvalidToken(String),
+    /// A token is valid, but the option it sets is not supported.
+    #[displaydoc("Unsupported skeleton token: {0}")]
+    UnsupportedToken(String),
+    /// An option is set by more than one token.
+    #[displaydoc("Option set more than once: {0}")]
+    DuplicateOption(String),
+}
+
+impl core::error::Error for SkeletonError {}
+
+/// The option a skeleton token sets, used to detect duplicates.
+#[derive(Debug, Clone, Copy)]
+enum Category {
+    Notation,
+    Unit,
+    UnitWidth,
+    Precision,
+    RoundingMode,
+    IntegerWidth,
+    Scale,
+    Grouping,
+    Sign,
+    NumberingSystem,
+    DecimalSeparator,
+}
+
+impl NumberFormatterOptions {
+    /// Parses an [ICU number skeleton](https://unicode-org.github.io/icu/userguide/format_parse/numbers/skeletons.html),
+    /// such as `currency/EUR precision-integer group-auto`, into options.
+    ///
+    /// Both the long and the concise forms of the tokens are supported, such as
+    /// `compact-short` and `K`. Tokens for options that are not available in ICU4X, such as
+    /// `permille` or `precision-currency-standard`, return
+    /// [`SkeletonError::UnsupportedToken`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::dimension::currency::CurrencyCode;
+    /// use icu::experimental::numberformat::options::{
+    ///     NumberFormatterOptions, Precision, Style,
+    /// };
+    /// use icu::experimental::numberformat::SkeletonError;
+    /// use tinystr::tinystr;
+    ///
+    /// let options =
+    ///     NumberFormatterOptions::try_from_skeleton("currency/EUR precision-integer group-auto")
+    ///         .unwrap();
+    /// assert_eq!(options.style, Style::Currency(CurrencyCode(tinystr!(3, "EUR"))));
+    /// assert_eq!(
+    ///     options.precision,
+    ///     Precision::FractionDigits {
+    ///         min: 0,
+    ///         max: Some(0)
+    ///     }
+    /// );
+    ///
+    /// assert_eq!(
+    ///     NumberFormatterOptions::try_from_skeleton("K .00 K"),
+    ///     Err(SkeletonError::DuplicateOption("K".into()))
+    /// );
+    /// ```
+    pub fn try_from_skeleton(skeleton: &str) -> Result<Self, SkeletonError> {
+        let mut options = Self::default();
+        let mut seen = 0u16;
+        for token in skeleton.split_ascii_whitespace() {
+            let bits = options.apply_token(token)?;
+            if seen & bits != 0 {
+                return Err(SkeletonError::DuplicateOption(token.to_string()));
+            }
+            seen |= bits;
+        }
+        Ok(options)
+    }
+
+    /// Applies a single skeleton token, returning the bits of the [`Category`]s it sets.
+    fn apply_token(&mut self, token: &str) -> Result<u16, SkeletonError> {
+        let invalid = || SkeletonError::InvalidToken(token.to_string());
+        let unsupported = || SkeletonError::UnsupportedToken(token.to_string());
+
+        let (stem, option) = match token.split_once('/') {
+            Some((stem, option)) => (stem, Some(option)),
+            None => (token, None),
+        };
+
+        let category = match (stem, option) {
+            ("notation-simple", None) => self.set_notation(Notation::Standard),
+            ("scientific" | "E0", None) => self.set_notation(Notation::Scientific),
+            ("engineering" | "EE0", None) => self.set_notation(Notation::Engineering),
+            ("compact-short" | "K", None) => self.set_notation(Notation::CompactShort),
+            ("compact-long" | "KK", None) => self.set_notation(Notation::CompactLong),
+            ("scientific" | "engineering", Some(_)) => return Err(unsupported()),
+            // Concise scientific notation with options, such as `E+!00`
+            (stem, _)
+                if stem.starts_with('E')
+                    && stem.trim_start_matches('E').starts_with(['+', '0']) =>
+            {
+                return Err(unsupported())
+            }
+
+            ("base-unit", None) => self.set_style(Style::Decimal),
+            ("percent" | "%", None) => self.set_style(Style::Percent),
+            ("%x100", None) => {
+                self.style = Style::Percent;
+                self.scale = 2;
+                return Ok(1 << Category::Unit as u16 | 1 << Category::Scale as u16);
+            }
+            ("permille" | "‰" | "%x1000", None) => return Err(unsupported()),
+            ("currency", Some(code)) => {
+                let code = TinyAsciiStr::<3>::try_from_str(code)
+                    .ok()
+                    .filter(|code| code.is_ascii_alphabetic())
+                    .ok_or_else(invalid)?;
+                self.set_style(Style::Currency(CurrencyCode(code.to_ascii_uppercase())))
+            }
+            ("measure-unit", Some(unit)) => {
+                // The type of the unit, such as `length` in `length-meter`, is not needed
+                let (_, unit) = unit.split_once('-').ok_or_else(invalid)?;
+                self.set_style(Style::Unit(parse_unit(unit).ok_or_else(invalid)?))
+            }
+            ("unit", Some(unit)) => {
+                self.set_style(Style::Unit(parse_unit(unit).ok_or_else(invalid)?))
+            }
+            ("per-measure-unit", Some(_)) => return Err(unsupported()),
+
+            ("unit-width-short", None) => self.set_unit_width(UnitWidth::Short),
+            ("unit-width-narrow", None) => self.set_unit_width(UnitWidth::Narrow),
+            ("unit-width-full-name", None) => self.set_unit_width(UnitWidth::Long),
+            (
+                "unit-width-iso-code"
+                | "unit-width-formal"
+                | "unit-width-variant"
+                | "unit-width-hidden",
+                None,
+            ) => return Err(unsupported()),
+
+            ("precision-integer", None) => self.set_precision(Precision::FractionDigits {
+                min: 0,
+                max: Some(0),
+            }),
+            ("precision-unlimited", None) => self.set_precision(Precision::Unlimited),
+            ("precision-increment", Some(increment)) => {
+                self.set_precision(parse_increment(increment).ok_or_else(unsupported)?)
+            }
+            ("precision-currency-standard" | "precision-currency-cash", _) => {
+                return Err(unsupported())
+            }
+            (stem, option) if stem.starts_with('.') => {
+                let precision = parse_digits(stem, '.', '0').ok_or_else(invalid)?;
+                if option.is_some() {
+                    return Err(unsupported());
+                }
+                self.set_precision(match precision {
+                    (0, None) => Precision::Unlimited,
+                    (min, max) => Precision::FractionDigits { min, max },
+                })
+            }
+            (stem, option) if stem.starts_with('@') => {
+                let (min, max) = parse_digits(stem, '@', '@').ok_or_else(invalid)?;
+                if min == 0 {
+                    return Err(invalid());
+                }
+                if option.is_some() {
+                    return Err(unsupported());
+                }
+                self.set_precision(Precision::SignificantDigits { min, max })
+            }
+
+            (stem, None) if stem.starts_with("rounding-mode-") => {
+                use SignedRoundingMode::*;
+                use UnsignedRoundingMode::*;
+                let mode = match stem.trim_start_matches("rounding-mode-") {
+                    "ceiling" => Ceil,
+                    "floor" => Floor,
+                    "down" => Unsigned(Trunc),
+                    "up" => Unsigned(Expand),
+                    "half-even" => Unsigned(HalfEven),
+                    "half-down" => Unsigned(HalfTrunc),
+                    "half-up" => Unsigned(HalfExpand),
+                    "half-ceiling" => HalfCeil,
+                    "half-floor" => HalfFloor,
+                    "half-odd" | "unnecessary" => return Err(unsupported()),
+                    _ => return Err(invalid()),
+                };
+                self.rounding_mode = Some(mode);
+                Category::RoundingMode
+            }
+
+            ("integer-width", Some(width)) => {
+                let (min, max) = parse_integer_width(width).ok_or_else(invalid)?;
+                if min == 0 {
+                    return Err(unsupported());
+                }
+                self.min_integer_digits = Some(min);
+                self.max_integer_digits = max;
+                Category::IntegerWidth
+            }
+            ("integer-width-trunc", None) => return Err(unsupported()),
+            // Concise integer width, such as `000`, which has no maximum
+            (stem, None) if stem.trim_start_matches(['+', '*']).starts_with('0') => {
+                let (min, _) = parse_integer_width(stem).ok_or_else(invalid)?;
+                self.min_integer_digits = Some(min);
+                self.max_integer_digits = None;
+                Category::IntegerWidth
+            }
+
+            ("scale", Some(scale)) => {
+                let scale = scale.parse::<Decimal>().map_err(|_| invalid())?;
+                let magnitude = scale.absolute.nonzero_magnitude_start();
+                if scale.sign == Sign::Negative
+                    || magnitude != scale.absolute.nonzero_magnitude_end()
+                    || scale.absolute.digit_at(magnitude) != 1
+                {
+                    return Err(unsupported());
+                }
+                self.scale = magnitude;
+                Category::Scale
+            }
+
+            ("group-off" | ",_", None) => self.set_grouping(GroupingStrategy::Never),
+            ("group-min2" | ",?", None) => self.set_grouping(GroupingStrategy::Min2),
+            ("group-auto", None) => self.set_grouping(GroupingStrategy::Auto),
+            ("group-on-aligned" | ",!", None) => self.set_grouping(GroupingStrategy::Always),
+            ("group-thousands" | ",=", None) => return Err(unsupported()),
+
+            ("latin", None) => self.set_numbering_system("latn").ok_or_else(invalid)?,
+            ("numbering-system", Some(nu)) => self.set_numbering_system(nu).ok_or_else(invalid)?,
+
+            ("sign-auto", None) => self.set_sign_display(SignDisplay::Auto),
+            ("sign-always" | "+!", None) => self.set_sign_display(SignDisplay::Always),
+            ("sign-never" | "+_", None) => self.set_sign_display(SignDisplay::Never),
+            ("sign-except-zero" | "+?", None) => self.set_sign_display(SignDisplay::ExceptZero),
+            ("sign-negative" | "+-", None) => self.set_sign_display(SignDisplay::Negative),
+            (
+                "sign-accounting"
+                | "sign-accounting-always"
+                | "sign-accounting-except-zero"
+                | "sign-accounting-negative"
+                | "()"
+                | "()!"
+                | "()?"
+                | "()-",
+                None,
+            ) => return Err(unsupported()),
+
+            ("decimal-auto", None) => Category::DecimalSeparator,
+            ("decimal-always", None) => return Err(unsupported()),
+
+            _ => return Err(invalid()),
+        };
+
+        Ok(1 << category as u16)
+    }
+
+    fn set_notation(&mut self, notation: Notation) -> Category {
+        self.notation = notation;
+        Category::Notation
+    }
+
+    fn set_style(&mut self, style: Style) -> Category {
+        self.style = style;
+        Category::Unit
+    }
+
+    fn set_unit_width(&mut self, unit_width: UnitWidth) -> Category {
+        self.unit_width = unit_width;
+        Category::UnitWidth
+    }
+
+    fn set_precision(&mut self, precision: Precision) -> Category {
+        self.precision = precision;
+        Category::Precision
+    }
+
+    fn set_grouping(&mut self, grouping_strategy: GroupingStrategy) -> Category {
+        self.grouping_strategy = Some(grouping_strategy);
+        Category::Grouping
+    }
+
+    fn set_sign_display(&mut self, sign_display: SignDisplay) -> Category {
+        self.sign_display = sign_display;
+        Category::Sign
+    }
+
+    fn set_numbering_system(&mut self, numbering_system: &str) -> Option<Category> {
+        let value = Value::try_from_str(numbering_system).ok()?;
+        self.numbering_system = Some(NumberingSystem::try_from(value).ok()?);
+        Some(Category::NumberingSystem)
+    }
+}
+
+impl FromStr for NumberFormatterOptions {
+    type Err = SkeletonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_skeleton(s)
+    }
+}
+
+/// Parses a digit pattern such as `.00##`, `.0+` or `@@#`, returning the minimum and maximum
+/// number of digits, where `required` is the character for a required digit.
+fn parse_digits(stem: &str, prefix: char, required: char) -> Option<(u8, Option<u8>)> {
+    let mut rest = stem.strip_prefix(prefix)?;
+    let mut min = u8::from(prefix == required);
+    while let Some(r) = rest.strip_prefix(required) {
+        min = min.checked_add(1)?;
+        rest = r;
+    }
+    if rest == "+" || rest == "*" {
+        return Some((min, None));
+    }
+    let mut max = min;
+    while let Some(r) = rest.strip_prefix('#') {
+        max = max.checked_add(1)?;
+        rest = r;
+    }
+    rest.is_empty().then_some((min, Some(max)))
+}
+
+/// Parses an integer width such as `##0`, `+00` or `*00`, returning the minimum and maximum
+/// number of integer digits.
+fn parse_integer_width(width: &str) -> Option<(u8, Option<u8>)> {
+    let (unlimited, rest) = match width.strip_prefix(['+', '*']) {
+        Some(rest) => (true, rest),
+        None => (false, width),
+    };
+    let digits = rest.trim_start_matches('#');
+    let optional = rest.len() - digits.len();
+    let rest = digits;
+    if unlimited && optional > 0 || !rest.bytes().all(|b| b == b'0') {
+        return None;
+    }
+    let min = u8::try_from(rest.len()).ok()?;
+    let max = u8::try_from(optional).ok()?.checked_add(min)?;
+    Some((min, (!unlimited).then_some(max)))
+}
+
+/// Parses a unit identifier such as `meter` or `kilometer-per-hour`.
+fn parse_unit(unit: &str) -> Option<String> {
+    (!unit.is_empty() && unit.bytes().all(|b| b.is_ascii_lowercase() || b == b'-'))
+        .then(|| unit.to_string())
+}
+
+/// Parses a rounding increment such as `0.05`.
+fn parse_increment(increment: &str) -> Option<Precision> {
+    let increment = increment.parse::<Decimal>().ok()?;
+    let absolute = &increment.absolute;
+    let magnitude = absolute.nonzero_magnitude_end();
+    if increment.sign == Sign::Negative
+        || absolute.is_zero()
+        || *absolute.magnitude_range().start() < magnitude.min(0)
+    {
+        return None;
+    }
+    let start = absolute.nonzero_magnitude_start();
+    let increment = match (start - magnitude, absolute.digit_at(start)) {
+        (0, 1) => RoundingIncrement::MultiplesOf1,
+        (0, 2) => RoundingIncrement::MultiplesOf2,
+        (0, 5) => RoundingIncrement::MultiplesOf5,
+        (1, 2) if absolute.digit_at(magnitude) == 5 => RoundingIncrement::MultiplesOf25,
+        _ => return None,
+    };
+    Some(Precision::Increment {
+        magnitude,
+        increment,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tinystr::tinystr;
+
+    #[test]
+    fn test_skeleton() {
+        let options = NumberFormatterOptions::try_from_skeleton(
+            "currency/eur precision-integer group-off rounding-mode-floor sign-always scale/100 latin",
+        )
+        .unwrap();
+        assert_eq!(
+            options.style,
+            Style::Currency(CurrencyCode(tinystr!(3, "EUR")))
+        );
+        assert_eq!(
+            options.precision,
+            Precision::FractionDigits {
+                min: 0,
+                max: Some(0)
+            }
+        );
+        assert_eq!(options.grouping_strategy, Some(GroupingStrategy::Never));
+        assert_eq!(options.rounding_mode, Some(SignedRoundingMode::Floor));
+        assert_eq!(options.sign_display, SignDisplay::Always);
+        assert_eq!(options.scale, 2);
+        assert_eq!(
+            options.numbering_system.as_deref().map(|s| s.as_str()),
+            Some("latn")
+        );
+
+        // Concise forms
+        let options = NumberFormatterOptions::try_from_skeleton("%x100 .00## ,? +? 000").unwrap();
+        assert_eq!(options.style, Style::Percent);
+        assert_eq!(options.scale, 2);
+        assert_eq!(
+            options.precision,
+            Precision::FractionDigits {
+                min: 2,
+                max: Some(4)
+            }
+        );
+        assert_eq!(options.grouping_strategy, Some(GroupingStrategy::Min2));
+        assert_eq!(options.sign_display, SignDisplay::ExceptZero);
+        assert_eq!(options.min_integer_digits, Some(3));
+        assert_eq!(options.max_integer_digits, None);
+
+        let options = NumberFormatterOptions::try_from_skeleton(
+            "measure-unit/length-meter unit-width-full-name @@# integer-width/##0",
+        )
+        .unwrap();
+        assert_eq!(options.style, Style::Unit("meter".into()));
+        assert_eq!(options.unit_width, UnitWidth::Long);
+        assert_eq!(
+            options.precision,
+            Precision::SignificantDigits {
+                min: 2,
+                max: Some(3)
+            }
+        );
+        assert_eq!(options.min_integer_digits, Some(1));
+        assert_eq!(options.max_integer_digits, Some(3));
+
+        assert_eq!(
+            NumberFormatterOptions::try_from_skeleton("KK precision-increment/0.25 .0+")
+                .map(|o| (o.notation, o.precision)),
+            Err(SkeletonError::DuplicateOption(".0+".into()))
+        );
+        assert_eq!(
+            NumberFormatterOptions::try_from_skeleton("  ").unwrap(),
+            NumberFormatterOptions::default()
+        );
+    }
+
+    #[test]
+    fn test_precision() {
+        for (token, precision) in [
+            (
+                ".",
+                Precision::FractionDigits {
+                    min: 0,
+                    max: Some(0),
+                },
+            ),
+            (".0*", Precision::FractionDigits { min: 1, max: None }),
+            (
+                ".##",
+                Precision::FractionDigits {
+                    min: 0,
+                    max: Some(2),
+                },
+            ),
+            (".+", Precision::Unlimited),
+            (
+                "@@@",
+                Precision::SignificantDigits {
+                    min: 3,
+                    max: Some(3),
+                },
+            ),
+            ("@+", Precision::SignificantDigits { min: 1, max: None }),
+            (
+                "precision-increment/0.05",
+                Precision::Increment {
+                    magnitude: -2,
+                    increment: RoundingIncrement::MultiplesOf5,
+                },
+            ),
+            (
+                "precision-increment/250",
+                Precision::Increment {
+                    magnitude: 1,
+                    increment: RoundingIncrement::MultiplesOf25,
+                },
+            ),
+        ] {
+            assert_eq!(
+                NumberFormatterOptions::try_from_skeleton(token).map(|o| o.precision),
+                Ok(precision),
+                "{token}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        for token in [
+            "currency/EURO",
+            "measure-unit/meter",
+            "unit/Meter",
+            ".#0",
+            "@0",
+            "rounding-mode-sideways",
+            "integer-width/0#",
+            "scale/x",
+            "numbering-system/",
+            "frobnicate",
+        ] {
+            assert_eq!(
+                NumberFormatterOptions::try_from_skeleton(token),
+                Err(SkeletonError::InvalidToken(token.into())),
+            );
+        }
+        for token in [
+            "permille",
+            "precision-currency-standard",
+            "precision-increment/0.3",
+            "precision-increment/0.50",
+            "scale/0.5",
+            "unit-width-iso-code",
+            "sign-accounting",
+            "group-thousands",
+            "scientific/+ee",
+            "E+!00",
+            ".00/w",
+        ] {
+            assert_eq!(
+                NumberFormatterOptions::try_from_skeleton(token),
+                Err(SkeletonError::UnsupportedToken(token.into())),
+            );
+        }
+    }
+}
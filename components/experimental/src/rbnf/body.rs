@@ -0,0 +1,273 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Tokenization of rule bodies in ICU syntax.
+
+use alloc::vec::Vec;
+use icu_plurals::PluralCategory;
+
+/// A part of a rule body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Token<'a> {
+    /// Literal text.
+    Text(&'a str),
+    /// The start of optional text, `[`.
+    OptionalStart,
+    /// The end of optional text, `]`.
+    OptionalEnd,
+    /// A substitution, such as `<<`, `>%%ordinal>` or `=#,##0=`.
+    Substitution(Substitution<'a>),
+    /// A plural selection, such as `$(ordinal,one{st}other{th})$`.
+    Plural {
+        /// Whether ordinal rather than cardinal plural rules are used.
+        ordinal: bool,
+        /// The cases, such as `one{st}other{th}`.
+        cases: &'a str,
+    },
+}
+
+/// A substitution in a rule body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Substitution<'a> {
+    /// The character the substitution is delimited with: `<`, `>` or `=`.
+    pub(crate) kind: char,
+    /// Whether this is the `>>>` substitution, which bypasses rule selection.
+    pub(crate) triple: bool,
+    /// Whether this is a substitution like `<%spellout-numbering<<`, which writes the
+    /// leading zeros of a numerator in a rule set for fractions.
+    pub(crate) leading_zeros: bool,
+    /// How the substituted number is formatted.
+    pub(crate) target: Target<'a>,
+}
+
+/// How the number of a substitution is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Target<'a> {
+    /// With the rule set of the rule, as in `<<`.
+    SameRuleSet,
+    /// With another rule set, as in `<%spellout-cardinal<`.
+    RuleSet(&'a str),
+    /// With a decimal pattern, as in `=#,##0=`.
+    Pattern(&'a str),
+}
+
+/// An iterator over the [`Token`]s of a rule body, returning `Err` for unterminated
+/// substitutions and plural selections.
+#[derive(Debug)]
+pub(crate) struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    pub(crate) fn new(body: &'a str) -> Self {
+        Self { rest: body }
+    }
+}
+
+/// Returns the delimiter if `s` starts with a substitution: a `<`, `>` or `=` followed by
+/// the same character, a rule set name, or a decimal pattern.
+fn substitution_start(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(kind @ ('<' | '>' | '=')), Some(next))
+            if next == kind || matches!(next, '%' | '#' | '0') =>
+        {
+            Some(kind)
+        }
+        _ => None,
+    }
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Result<Token<'a>, ()>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest;
+        if rest.is_empty() {
+            return None;
+        }
+        if let Some(plural) = rest.strip_prefix("$(") {
+            let Some((plural, after)) = plural.split_once(")$") else {
+                self.rest = "";
+                return Some(Err(()));
+            };
+            self.rest = after;
+            return Some(match plural.split_once(',') {
+                Some(("cardinal", cases)) => Ok(Token::Plural {
+                    ordinal: false,
+                    cases,
+                }),
+                Some(("ordinal", cases)) => Ok(Token::Plural {
+                    ordinal: true,
+                    cases,
+                }),
+                _ => Err(()),
+            });
+        }
+        if let Some(after) = rest.strip_prefix('[') {
+            self.rest = after;
+            return Some(Ok(Token::OptionalStart));
+        }
+        if let Some(after) = rest.strip_prefix(']') {
+            self.rest = after;
+            return Some(Ok(Token::OptionalEnd));
+        }
+        if let Some(kind) = substitution_start(rest) {
+            if let Some(after) = rest.strip_prefix(">>>") {
+                self.rest = after;
+                return Some(Ok(Token::Substitution(Substitution {
+                    kind,
+                    triple: true,
+                    leading_zeros: false,
+                    target: Target::SameRuleSet,
+                })));
+            }
+            // `kind` is ASCII
+            let inner = rest.get(1..).unwrap_or_default();
+            let Some((inner, mut after)) = inner.split_once(kind) else {
+                self.rest = "";
+                return Some(Err(()));
+            };
+            let mut leading_zeros = false;
+            if kind == '<' && !inner.is_empty() {
+                if let Some(rest) = after.strip_prefix('<') {
+                    after = rest;
+                    leading_zeros = true;
+                }
+            }
+            self.rest = after;
+            let target = if inner.is_empty() {
+                Target::SameRuleSet
+            } else if inner.starts_with('%') {
+                Target::RuleSet(inner)
+            } else {
+                Target::Pattern(inner)
+            };
+            return Some(Ok(Token::Substitution(Substitution {
+                kind,
+                triple: false,
+                leading_zeros,
+                target,
+            })));
+        }
+
+        let end = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(i, c)| {
+                let at = rest.get(i..).unwrap_or_default();
+                matches!(c, '[' | ']') || at.starts_with("$(") || substitution_start(at).is_some()
+            })
+            .map_or(rest.len(), |(i, _)| i);
+        let (text, after) = rest.split_at(end);
+        self.rest = after;
+        Some(Ok(Token::Text(text)))
+    }
+}
+
+/// Parses the cases of a plural selection, such as `one{st}two{nd}few{rd}other{th}`,
+/// returning `None` if they are malformed.
+pub(crate) fn plural_cases(cases: &str) -> Option<Vec<(PluralCategory, &str)>> {
+    let mut parsed = Vec::new();
+    let mut rest = cases;
+    while let Some((keyword, after)) = rest.split_once('{') {
+        let (text, after) = after.split_once('}')?;
+        parsed.push((PluralCategory::get_for_cldr_string(keyword.trim())?, text));
+        rest = after;
+    }
+    rest.trim().is_empty().then_some(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens() {
+        let tokens = Tokens::new("<< hundred[ >>]$(ordinal,one{st}other{th})$ =#,##0= >>>")
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let same = |kind| {
+            Token::Substitution(Substitution {
+                kind,
+                triple: false,
+                leading_zeros: false,
+                target: Target::SameRuleSet,
+            })
+        };
+        assert_eq!(
+            tokens,
+            [
+                same('<'),
+                Token::Text(" hundred"),
+                Token::OptionalStart,
+                Token::Text(" "),
+                same('>'),
+                Token::OptionalEnd,
+                Token::Plural {
+                    ordinal: true,
+                    cases: "one{st}other{th}"
+                },
+                Token::Text(" "),
+                Token::Substitution(Substitution {
+                    kind: '=',
+                    triple: false,
+                    leading_zeros: false,
+                    target: Target::Pattern("#,##0"),
+                }),
+                Token::Text(" "),
+                Token::Substitution(Substitution {
+                    kind: '>',
+                    triple: true,
+                    leading_zeros: false,
+                    target: Target::SameRuleSet,
+                }),
+            ]
+        );
+
+        // Arrows that do not start a substitution are text
+        assert_eq!(
+            Tokens::new("a < b").collect::<Vec<_>>(),
+            [Ok(Token::Text("a < b"))]
+        );
+        assert_eq!(
+            Tokens::new("<%spellout-cardinal< ").collect::<Vec<_>>(),
+            [
+                Ok(Token::Substitution(Substitution {
+                    kind: '<',
+                    triple: false,
+                    leading_zeros: false,
+                    target: Target::RuleSet("%spellout-cardinal"),
+                })),
+                Ok(Token::Text(" ")),
+            ]
+        );
+        assert_eq!(
+            Tokens::new("<%spellout-cardinal<<").collect::<Vec<_>>(),
+            [Ok(Token::Substitution(Substitution {
+                kind: '<',
+                triple: false,
+                leading_zeros: true,
+                target: Target::RuleSet("%spellout-cardinal"),
+            }))]
+        );
+        assert_eq!(Tokens::new("<%unterminated").collect::<Vec<_>>(), [Err(())]);
+    }
+
+    #[test]
+    fn test_plural_cases() {
+        assert_eq!(
+            plural_cases("one{st}two{nd}few{rd}other{th}"),
+            Some(vec![
+                (PluralCategory::One, "st"),
+                (PluralCategory::Two, "nd"),
+                (PluralCategory::Few, "rd"),
+                (PluralCategory::Other, "th"),
+            ])
+        );
+        assert_eq!(plural_cases("one{a"), None);
+        assert_eq!(plural_cases("uno{a}"), None);
+        assert_eq!(plural_cases("one{a} b"), None);
+    }
+}
@@ -0,0 +1,506 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Compilation of RBNF rules in ICU syntax into [`RbnfRules`].
+
+use super::body::{plural_cases, Target, Token, Tokens};
+use super::provider::{
+    RbnfDecimalPattern, RbnfPart, RbnfPartKind, RbnfRule, RbnfRuleKind, RbnfRuleULE, RbnfRules,
+};
+use alloc::borrow::Cow;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use displaydoc::Display;
+use icu_plurals::PluralCategory;
+use zerovec::{VarZeroVec, ZeroVec};
+
+/// Rule sets that do not contain formatting rules.
+const IGNORED_RULE_SETS: &[&str] = &["%%lenient-parse", "%%post-process"];
+
+/// An error returned when compiling RBNF rules.
+#[derive(Display, Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum RbnfCompileError {
+    /// A rule appears before the first rule set name.
+    #[displaydoc("Rule outside of a rule set: {0}")]
+    MissingRuleSet(String),
+    /// A rule descriptor could not be parsed, or the base values of a rule set are not
+    /// in ascending order.
+    #[displaydoc("Invalid rule descriptor: {0}")]
+    InvalidDescriptor(String),
+    /// A rule body contains an unterminated or misplaced substitution, bracket or plural
+    /// selection.
+    #[displaydoc("Invalid rule body: {0}")]
+    InvalidBody(String),
+    /// A substitution refers to a rule set that does not exist.
+    #[displaydoc("Unknown rule set: {0}")]
+    UnknownRuleSet(String),
+}
+
+impl core::error::Error for RbnfCompileError {}
+
+impl RbnfRules<'static> {
+    /// Compiles rules in ICU syntax, as found in CLDR, into [`RbnfRules`].
+    ///
+    /// The rules consist of rule sets, each starting with a name such as `%spellout-cardinal:`
+    /// and followed by rules of the form `descriptor: body;`. The arrows `←` and `→` can be
+    /// used in place of `<` and `>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::experimental::rbnf::provider::RbnfRules;
+    ///
+    /// let rules = RbnfRules::try_from_str(
+    ///     "%count:
+    ///         0: none;
+    ///         1: one;
+    ///         2: =#,##0=;
+    ///         10: many;",
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(rules.rule_set("%count"), Some(0));
+    /// assert!(RbnfRules::try_from_str("%a: 0: =%b=;").is_err());
+    /// ```
+    pub fn try_from_str(rules: &str) -> Result<Self, RbnfCompileError> {
+        let rules = rules.replace('←', "<").replace('→', ">");
+
+        let mut rule_sets: Vec<(&str, Vec<(RbnfRule, &str)>)> = Vec::new();
+        let mut ignored = false;
+        for rule in rules.split(';') {
+            let mut rule = rule.trim_start();
+            if rule.starts_with('%') {
+                let (name, rest) = rule
+                    .split_once(':')
+                    .ok_or_else(|| RbnfCompileError::InvalidDescriptor(rule.to_string()))?;
+                let name = name.trim_end();
+                ignored = IGNORED_RULE_SETS.contains(&name);
+                if !ignored {
+                    rule_sets.push((name, Vec::new()));
+                }
+                rule = rest.trim_start();
+            }
+            if rule.is_empty() || ignored {
+                continue;
+            }
+            let Some((_, rules)) = rule_sets.last_mut() else {
+                return Err(RbnfCompileError::MissingRuleSet(rule.to_string()));
+            };
+            let previous = rules
+                .iter()
+                .rev()
+                .find(|(r, _)| r.kind == RbnfRuleKind::Normal)
+                .map(|(r, _)| r.base_value);
+            if let Some(rule) = parse_rule(rule, previous)? {
+                rules.push(rule);
+            }
+        }
+
+        // The bodies are compiled once all rule set names are known
+        let names = rule_sets.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        let rule_sets = rule_sets
+            .into_iter()
+            .enumerate()
+            .map(|(index, (_, rules))| {
+                let rules = rules
+                    .into_iter()
+                    .map(|(mut rule, body)| {
+                        compile_body(&mut rule, body, index, &names)?;
+                        Ok(rule)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(VarZeroVec::<RbnfRuleULE>::from(&rules))
+            })
+            .collect::<Result<Vec<_>, RbnfCompileError>>()?;
+        Ok(Self {
+            names: VarZeroVec::from(&names),
+            rule_sets: VarZeroVec::from(&rule_sets),
+        })
+    }
+}
+
+/// Parses a rule of the form `descriptor: body` into a rule with an empty body and the
+/// body, returning `None` for rules that do not apply to decimals, such as `Inf` and `NaN`.
+fn parse_rule(
+    rule: &str,
+    previous: Option<u64>,
+) -> Result<Option<(RbnfRule<'static>, &str)>, RbnfCompileError> {
+    let invalid = || RbnfCompileError::InvalidDescriptor(rule.to_string());
+    let (descriptor, body) = match rule.split_once(':') {
+        Some((descriptor, body)) => (Some(descriptor.trim()), body.trim_start()),
+        // A rule without a descriptor follows the previous rule
+        None => (None, rule),
+    };
+    // A leading apostrophe preserves the whitespace after it
+    let body = body.strip_prefix('\'').unwrap_or(body);
+
+    let new_rule = |kind| RbnfRule {
+        kind,
+        base_value: 0,
+        radix: 10,
+        exponent: 0,
+        decimal_separator: match descriptor {
+            Some("x,x" | "0,x" | "x,0") => ',',
+            _ => '.',
+        },
+        text: Cow::Borrowed(""),
+        body: ZeroVec::new(),
+    };
+    let kind = match descriptor {
+        Some("Inf" | "NaN") => return Ok(None),
+        Some("-x") => RbnfRuleKind::Negative,
+        Some("x.x" | "x,x") => RbnfRuleKind::ImproperFraction,
+        Some("0.x" | "0,x") => RbnfRuleKind::ProperFraction,
+        Some("x.0" | "x,0") => RbnfRuleKind::Default,
+        None => {
+            let mut rule = new_rule(RbnfRuleKind::Normal);
+            rule.base_value = previous
+                .map_or(Some(0), |p| p.checked_add(1))
+                .ok_or_else(invalid)?;
+            rule.exponent = exponent(rule.base_value, rule.radix);
+            return Ok(Some((rule, body)));
+        }
+        Some(descriptor) => {
+            let mut base_value = 0u64;
+            let mut radix = None::<u32>;
+            let mut decrements = 0u8;
+            for c in descriptor.chars() {
+                match (c, &mut radix) {
+                    ('0'..='9', None) if decrements == 0 => {
+                        base_value = base_value
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(u64::from(c as u8 - b'0')))
+                            .ok_or_else(invalid)?;
+                    }
+                    ('0'..='9', Some(radix)) if decrements == 0 => {
+                        *radix = radix
+                            .checked_mul(10)
+                            .and_then(|v| v.checked_add(u32::from(c as u8 - b'0')))
+                            .ok_or_else(invalid)?;
+                    }
+                    (',' | '.' | ' ', _) => {}
+                    ('/', None) => radix = Some(0),
+                    ('>', _) => decrements = decrements.checked_add(1).ok_or_else(invalid)?,
+                    _ => return Err(invalid()),
+                }
+            }
+            if previous.is_some_and(|p| p > base_value) {
+                return Err(invalid());
+            }
+            let radix = radix.unwrap_or(10);
+            if radix < 2 {
+                return Err(invalid());
+            }
+            let mut rule = new_rule(RbnfRuleKind::Normal);
+            rule.base_value = base_value;
+            rule.radix = radix;
+            rule.exponent = exponent(base_value, radix)
+                .checked_sub(decrements)
+                .ok_or_else(invalid)?;
+            return Ok(Some((rule, body)));
+        }
+    };
+    Ok(Some((new_rule(kind), body)))
+}
+
+/// Returns the highest power of `radix` that is less than or equal to `base_value`.
+fn exponent(base_value: u64, radix: u32) -> u8 {
+    let mut exponent = 0;
+    let mut power = u64::from(radix);
+    while power <= base_value {
+        exponent += 1;
+        match power.checked_mul(radix.into()) {
+            Some(p) => power = p,
+            None => break,
+        }
+    }
+    exponent
+}
+
+/// Compiles `body` into the text and parts of `rule`, which is in the rule set at index
+/// `rule_set` of `names`.
+fn compile_body(
+    rule: &mut RbnfRule,
+    body: &str,
+    rule_set: usize,
+    names: &[&str],
+) -> Result<(), RbnfCompileError> {
+    let invalid = || RbnfCompileError::InvalidBody(body.to_string());
+    let part = |kind| RbnfPart {
+        kind,
+        text_start: 0,
+        text_end: 0,
+        rule_set: 0,
+        pattern: RbnfDecimalPattern::default(),
+        plural_category: PluralCategory::Other,
+    };
+    let text_part = |text: &mut String, kind, s: &str| {
+        let text_start = u16::try_from(text.len()).map_err(|_| invalid())?;
+        text.push_str(s);
+        let text_end = u16::try_from(text.len()).map_err(|_| invalid())?;
+        Ok::<_, RbnfCompileError>(RbnfPart {
+            text_start,
+            text_end,
+            ..part(kind)
+        })
+    };
+    let rule_set_index = |index: usize| {
+        u16::try_from(index)
+            .ok()
+            .filter(|&i| i != RbnfPart::DECIMAL_PATTERN)
+            .ok_or_else(invalid)
+    };
+
+    let mut text = String::new();
+    let mut parts = Vec::new();
+    let mut optional = false;
+    for token in Tokens::new(body) {
+        match token.map_err(|()| invalid())? {
+            Token::Text(s) => parts.push(text_part(&mut text, RbnfPartKind::Text, s)?),
+            Token::OptionalStart if !optional => {
+                optional = true;
+                parts.push(part(RbnfPartKind::OptionalStart));
+            }
+            Token::OptionalEnd if optional => {
+                optional = false;
+                parts.push(part(RbnfPartKind::OptionalEnd));
+            }
+            Token::OptionalStart | Token::OptionalEnd => return Err(invalid()),
+            Token::Plural { ordinal, cases } => {
+                parts.push(part(match ordinal {
+                    true => RbnfPartKind::OrdinalPlural,
+                    false => RbnfPartKind::CardinalPlural,
+                }));
+                for (plural_category, s) in plural_cases(cases).ok_or_else(invalid)? {
+                    parts.push(RbnfPart {
+                        plural_category,
+                        ..text_part(&mut text, RbnfPartKind::PluralCase, s)?
+                    });
+                }
+            }
+            Token::Substitution(substitution) => {
+                let kind = match (substitution.kind, substitution.triple) {
+                    ('<', _) if substitution.leading_zeros => {
+                        RbnfPartKind::QuotientWithLeadingZeros
+                    }
+                    ('<', _) => RbnfPartKind::Quotient,
+                    ('>', false) => RbnfPartKind::Remainder,
+                    ('>', true) => RbnfPartKind::TripleRemainder,
+                    _ => RbnfPartKind::Value,
+                };
+                let mut part = part(kind);
+                match (rule.kind, kind, substitution.target) {
+                    // These would format the same number with the same rules
+                    (_, RbnfPartKind::Value, Target::SameRuleSet) => return Err(invalid()),
+                    (RbnfRuleKind::Negative, RbnfPartKind::Quotient, _)
+                    | (RbnfRuleKind::Negative, RbnfPartKind::QuotientWithLeadingZeros, _) => {
+                        return Err(invalid())
+                    }
+                    (_, _, Target::SameRuleSet) => part.rule_set = rule_set_index(rule_set)?,
+                    (_, _, Target::RuleSet(name)) => {
+                        let index = names
+                            .iter()
+                            .position(|n| *n == name)
+                            .ok_or_else(|| RbnfCompileError::UnknownRuleSet(name.to_string()))?;
+                        part.rule_set = rule_set_index(index)?;
+                    }
+                    (_, _, Target::Pattern(pattern)) => {
+                        part.rule_set = RbnfPart::DECIMAL_PATTERN;
+                        part.pattern = parse_pattern(pattern).ok_or_else(invalid)?;
+                    }
+                }
+                parts.push(part);
+            }
+        }
+    }
+    if optional {
+        return Err(invalid());
+    }
+
+    rule.text = Cow::Owned(text);
+    rule.body = ZeroVec::alloc_from_slice(&parts);
+    Ok(())
+}
+
+/// Parses a decimal pattern such as `#,##0.00`.
+fn parse_pattern(pattern: &str) -> Option<RbnfDecimalPattern> {
+    if !pattern.chars().all(|c| matches!(c, '#' | '0' | ',' | '.')) {
+        return None;
+    }
+    let (integer, fraction) = pattern.split_once('.').unwrap_or((pattern, ""));
+    let count = |s: &str, c| s.chars().filter(|&x| x == c).count();
+    Some(RbnfDecimalPattern {
+        min_integer_digits: count(integer, '0').try_into().ok()?,
+        min_fraction_digits: count(fraction, '0').try_into().ok()?,
+        max_fraction_digits: fraction.len().try_into().ok()?,
+        grouping: pattern.contains(','),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zerofrom::ZeroFrom;
+
+    #[test]
+    fn test_compile() {
+        let rules = RbnfRules::try_from_str(
+            "%%lenient-parse:
+                &[last primary ignorable ] << ' ' << ',';
+            %spellout:
+                -x: minus >>;
+                x.x: << point >>;
+                Inf: infinity;
+                0: zero;
+                1: one;
+                two;
+                20: twenty[-→→];
+                100: ←← hundred[ >>];
+                1,000: << thousand[ >>];
+                1010/100: << >%%2d>;
+                1000000>: =#,##0=;
+            %%2d:
+                0: hundred;
+                1: oh-=%spellout=;",
+        )
+        .unwrap();
+
+        assert_eq!(
+            rules.names.iter().collect::<Vec<_>>(),
+            ["%spellout", "%%2d"]
+        );
+        let spellout = rules
+            .rule_sets
+            .get(0)
+            .unwrap()
+            .iter()
+            .map(RbnfRule::zero_from)
+            .map(|r| {
+                (
+                    r.kind,
+                    r.base_value,
+                    r.radix,
+                    r.exponent,
+                    r.text.into_owned(),
+                )
+            })
+            .collect::<Vec<_>>();
+        use RbnfRuleKind::*;
+        assert_eq!(
+            spellout,
+            [
+                (Negative, 0, 10, 0, "minus ".into()),
+                (ImproperFraction, 0, 10, 0, " point ".into()),
+                (Normal, 0, 10, 0, "zero".into()),
+                (Normal, 1, 10, 0, "one".into()),
+                (Normal, 2, 10, 0, "two".into()),
+                (Normal, 20, 10, 1, "twenty-".into()),
+                (Normal, 100, 10, 2, " hundred ".into()),
+                (Normal, 1000, 10, 3, " thousand ".into()),
+                (Normal, 1010, 100, 1, " ".into()),
+                (Normal, 1000000, 10, 5, "".into()),
+            ]
+        );
+
+        let body = |rule_set, index| {
+            let rule_set = rules.rule_sets.get(rule_set).unwrap();
+            RbnfRule::zero_from(rule_set.get(index).unwrap())
+                .body
+                .iter()
+                .map(|p| (p.kind, p.text_start, p.text_end, p.rule_set))
+                .collect::<Vec<_>>()
+        };
+        use RbnfPartKind::*;
+        // `←← hundred[ >>]`
+        assert_eq!(
+            body(0, 6),
+            [
+                (Quotient, 0, 0, 0),
+                (Text, 0, 8, 0),
+                (OptionalStart, 0, 0, 0),
+                (Text, 8, 9, 0),
+                (Remainder, 0, 0, 0),
+                (OptionalEnd, 0, 0, 0),
+            ]
+        );
+        // `<< >%%2d>`
+        assert_eq!(
+            body(0, 8),
+            [(Quotient, 0, 0, 0), (Text, 0, 1, 0), (Remainder, 0, 0, 1)]
+        );
+        // `oh-=%spellout=`
+        assert_eq!(body(1, 1), [(Text, 0, 3, 0), (Value, 0, 0, 0)]);
+
+        let pattern = RbnfRule::zero_from(rules.rule_sets.get(0).unwrap().get(9).unwrap())
+            .body
+            .get(0)
+            .unwrap();
+        assert_eq!(pattern.rule_set, RbnfPart::DECIMAL_PATTERN);
+        assert_eq!(
+            pattern.pattern,
+            RbnfDecimalPattern {
+                min_integer_digits: 1,
+                min_fraction_digits: 0,
+                max_fraction_digits: 0,
+                grouping: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_compile_plurals() {
+        let rules = RbnfRules::try_from_str("%a: 0: =#,##0=$(ordinal,one{st}other{th})$;").unwrap();
+        let rule = RbnfRule::zero_from(rules.rule_sets.get(0).unwrap().get(0).unwrap());
+        assert_eq!(rule.text, "stth");
+        assert_eq!(
+            rule.body
+                .iter()
+                .skip(1)
+                .map(|p| (p.kind, p.text_start, p.text_end, p.plural_category))
+                .collect::<Vec<_>>(),
+            [
+                (RbnfPartKind::OrdinalPlural, 0, 0, PluralCategory::Other),
+                (RbnfPartKind::PluralCase, 0, 2, PluralCategory::One),
+                (RbnfPartKind::PluralCase, 2, 4, PluralCategory::Other),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compile_errors() {
+        for (rules, error) in [
+            (
+                "0: zero;",
+                RbnfCompileError::MissingRuleSet("0: zero".into()),
+            ),
+            (
+                "%a: 10: ten; 5: five;",
+                RbnfCompileError::InvalidDescriptor("5: five".into()),
+            ),
+            (
+                "%a: 1x: one;",
+                RbnfCompileError::InvalidDescriptor("1x: one".into()),
+            ),
+            (
+                "%a: 0: [zero;",
+                RbnfCompileError::InvalidBody("[zero".into()),
+            ),
+            ("%a: 0: <%b;", RbnfCompileError::InvalidBody("<%b".into())),
+            ("%a: 0: ==;", RbnfCompileError::InvalidBody("==".into())),
+            ("%a: -x: <<;", RbnfCompileError::InvalidBody("<<".into())),
+            (
+                "%a: 0: =%b=;",
+                RbnfCompileError::UnknownRuleSet("%b".into()),
+            ),
+            ("%a: 0: =#x=;", RbnfCompileError::InvalidBody("=#x=".into())),
+            (
+                "%a: 0: $(ordinal,uno{a})$;",
+                RbnfCompileError::InvalidBody("$(ordinal,uno{a})$".into()),
+            ),
+        ] {
+            assert_eq!(RbnfRules::try_from_str(rules), Err(error), "{rules}");
+        }
+    }
+}
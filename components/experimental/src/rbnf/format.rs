@@ -0,0 +1,810 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::provider::{
+    RbnfDecimalPattern, RbnfPart, RbnfPartKind, RbnfRule, RbnfRuleKind, RbnfRuleULE, RbnfRules,
+    RbnfRulesV1,
+};
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use fixed_decimal::{Decimal, Sign, SignedRoundingMode, UnsignedDecimal, UnsignedRoundingMode};
+use icu_decimal::options::{DecimalFormatterOptions, GroupingStrategy};
+use icu_decimal::provider::DecimalSymbolsV1;
+use icu_decimal::{DecimalFormatter, DecimalFormatterPreferences};
+use icu_locale_core::preferences::{define_preferences, prefs_convert};
+use icu_plurals::{PluralCategory, PluralRules, PluralRulesPreferences};
+use icu_provider::prelude::*;
+use writeable::Writeable;
+use zerofrom::ZeroFrom;
+use zerovec::VarZeroSlice;
+
+/// The maximum number of nested rule set invocations while formatting a number.
+///
+/// Rule sets in CLDR nest only a few levels deep, so this is only reached with
+/// malformed data, such as rule sets that refer to each other without making progress.
+const MAX_DEPTH: u8 = 64;
+
+define_preferences!(
+    /// The preferences for rule-based number formatting.
+    [Copy]
+    RbnfFormatterPreferences,
+    {
+        /// The user's preferred numbering system.
+        ///
+        /// Corresponds to the `-u-nu` in Unicode Locale Identifier.
+        numbering_system: super::preferences::NumberingSystem
+    }
+);
+
+prefs_convert!(RbnfFormatterPreferences, DecimalFormatterPreferences, {
+    numbering_system
+});
+prefs_convert!(RbnfFormatterPreferences, PluralRulesPreferences);
+
+/// A formatter that spells out numbers or formats them in non-positional numbering
+/// systems, using the [rule-based number format](https://www.unicode.org/reports/tr35/tr35-numbers.html#Rule-Based_Number_Formatting)
+/// rule sets of CLDR.
+///
+/// Each formatter uses a single public rule set of the locale, such as `%spellout-cardinal`,
+/// `%spellout-ordinal` or `%digits-ordinal`. The rule sets for numbering systems, such as
+/// `%roman-upper`, `%hebrew` or `%armenian-lower`, are available for all locales.
+///
+/// Digits that are not spelled out, for example in very large numbers, are formatted with
+/// a [`DecimalFormatter`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::decimal::input::Decimal;
+/// use icu::experimental::rbnf::RbnfFormatter;
+/// use icu::locale::locale;
+/// use writeable::assert_writeable_eq;
+///
+/// let formatter =
+///     RbnfFormatter::try_new(locale!("en").into(), "%spellout-cardinal").unwrap();
+///
+/// let decimal = Decimal::from(1234);
+/// assert_writeable_eq!(
+///     formatter.format(&decimal),
+///     "one thousand two hundred thirty-four"
+/// );
+///
+/// // Rule sets for numbering systems are available in all locales
+/// let formatter = RbnfFormatter::try_new(locale!("en").into(), "%roman-upper").unwrap();
+///
+/// let decimal = Decimal::from(2025);
+/// assert_writeable_eq!(formatter.format(&decimal), "MMXXV");
+/// ```
+#[derive(Debug)]
+pub struct RbnfFormatter {
+    rules: DataPayload<RbnfRulesV1>,
+    rule_set: usize,
+    decimal_separator: char,
+    decimal_formatter: DecimalFormatter,
+    ungrouped_decimal_formatter: DecimalFormatter,
+    cardinal_rules: PluralRules,
+    ordinal_rules: PluralRules,
+}
+
+impl RbnfFormatter {
+    /// Creates a new [`RbnfFormatter`] for the given rule set from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new(prefs: RbnfFormatterPreferences, rule_set: &str) -> Result<Self, DataError> {
+        let (rules, rule_set) = load_rules(&crate::provider::Baked, prefs, rule_set)?;
+        Self::try_new_with_payload(prefs, rules, rule_set)
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        (prefs: RbnfFormatterPreferences, rule_set: &str) -> error: DataError,
+        functions: [
+            try_new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<D>(
+        provider: &D,
+        prefs: RbnfFormatterPreferences,
+        rule_set: &str,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<RbnfRulesV1>
+            + DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_plurals::provider::PluralsCardinalV1>
+            + DataProvider<icu_plurals::provider::PluralsOrdinalV1>
+            + ?Sized,
+    {
+        let (rules, rule_set) = load_rules(provider, prefs, rule_set)?;
+        Self::try_new_with_payload_unstable(provider, prefs, rules, rule_set)
+    }
+
+    /// Creates a new [`RbnfFormatter`] for a rule set of the given rules, using compiled
+    /// data for the digits and plural rules of the locale.
+    ///
+    /// This can be used for rules that are not part of the data, such as custom rules in
+    /// the syntax of ICU, which can be parsed with [`RbnfRules::try_from_str`].
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::decimal::input::Decimal;
+    /// use icu::experimental::rbnf::provider::RbnfRules;
+    /// use icu::experimental::rbnf::RbnfFormatter;
+    /// use icu::locale::locale;
+    /// use writeable::assert_writeable_eq;
+    ///
+    /// let rules = RbnfRules::try_from_str(
+    ///     "%digits-ordinal:
+    ///         -x: −>>;
+    ///         0: =#,##0=$(ordinal,one{st}two{nd}few{rd}other{th})$;",
+    /// )
+    /// .unwrap();
+    ///
+    /// let formatter =
+    ///     RbnfFormatter::try_new_with_rules(locale!("en").into(), rules, "%digits-ordinal")
+    ///         .unwrap();
+    ///
+    /// for (number, expected) in [(1, "1st"), (12, "12th"), (1023, "1,023rd"), (-2, "−2nd")] {
+    ///     let decimal = Decimal::from(number);
+    ///     assert_writeable_eq!(formatter.format(&decimal), expected);
+    /// }
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_with_rules(
+        prefs: RbnfFormatterPreferences,
+        rules: RbnfRules<'static>,
+        rule_set: &str,
+    ) -> Result<Self, DataError> {
+        let index = public_rule_set(&rules, rule_set).ok_or_else(|| unknown_rule_set(rule_set))?;
+        Self::try_new_with_payload(prefs, DataPayload::from_owned(rules), index)
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_with_rules)]
+    pub fn try_new_with_rules_unstable<D>(
+        provider: &D,
+        prefs: RbnfFormatterPreferences,
+        rules: RbnfRules<'static>,
+        rule_set: &str,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_plurals::provider::PluralsCardinalV1>
+            + DataProvider<icu_plurals::provider::PluralsOrdinalV1>
+            + ?Sized,
+    {
+        let index = public_rule_set(&rules, rule_set).ok_or_else(|| unknown_rule_set(rule_set))?;
+        Self::try_new_with_payload_unstable(provider, prefs, DataPayload::from_owned(rules), index)
+    }
+
+    #[cfg(feature = "compiled_data")]
+    fn try_new_with_payload(
+        prefs: RbnfFormatterPreferences,
+        rules: DataPayload<RbnfRulesV1>,
+        rule_set: usize,
+    ) -> Result<Self, DataError> {
+        let mut ungrouped_options = DecimalFormatterOptions::default();
+        ungrouped_options.grouping_strategy = Some(GroupingStrategy::Never);
+        Ok(Self {
+            rules,
+            rule_set,
+            decimal_separator: load_decimal_separator(&icu_decimal::provider::Baked, prefs)?,
+            decimal_formatter: DecimalFormatter::try_new((&prefs).into(), Default::default())?,
+            ungrouped_decimal_formatter: DecimalFormatter::try_new(
+                (&prefs).into(),
+                ungrouped_options,
+            )?,
+            cardinal_rules: PluralRules::try_new_cardinal((&prefs).into())?,
+            ordinal_rules: PluralRules::try_new_ordinal((&prefs).into())?,
+        })
+    }
+
+    fn try_new_with_payload_unstable<D>(
+        provider: &D,
+        prefs: RbnfFormatterPreferences,
+        rules: DataPayload<RbnfRulesV1>,
+        rule_set: usize,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<icu_decimal::provider::DecimalSymbolsV1>
+            + DataProvider<icu_decimal::provider::DecimalDigitsV1>
+            + DataProvider<icu_plurals::provider::PluralsCardinalV1>
+            + DataProvider<icu_plurals::provider::PluralsOrdinalV1>
+            + ?Sized,
+    {
+        let decimal_formatter =
+            DecimalFormatter::try_new_unstable(provider, (&prefs).into(), Default::default())?;
+        let mut ungrouped_options = DecimalFormatterOptions::default();
+        ungrouped_options.grouping_strategy = Some(GroupingStrategy::Never);
+        Ok(Self {
+            rules,
+            rule_set,
+            decimal_separator: load_decimal_separator(provider, prefs)?,
+            decimal_formatter,
+            ungrouped_decimal_formatter: DecimalFormatter::try_new_unstable(
+                provider,
+                (&prefs).into(),
+                ungrouped_options,
+            )?,
+            cardinal_rules: PluralRules::try_new_cardinal_unstable(provider, (&prefs).into())?,
+            ordinal_rules: PluralRules::try_new_ordinal_unstable(provider, (&prefs).into())?,
+        })
+    }
+
+    /// Formats a [`Decimal`] with the rule set of this formatter.
+    ///
+    /// Numbers that are not integers are rounded to an integer, unless the rule set has rules
+    /// for fractions, as `%spellout-cardinal` usually does.
+    pub fn format<'l>(&'l self, value: &'l Decimal) -> FormattedRbnf<'l> {
+        FormattedRbnf {
+            formatter: self,
+            value,
+        }
+    }
+
+    /// Writes `value` with the rules of the rule set at index `rule_set`.
+    fn write_rule_set<W: Write + ?Sized>(
+        &self,
+        rule_set: usize,
+        value: &Decimal,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let Some(depth) = depth.checked_sub(1) else {
+            debug_assert!(false, "RBNF rule sets nested too deeply");
+            return Ok(());
+        };
+        let Some(rules) = self.rules.get().rule_sets.get(rule_set) else {
+            debug_assert!(false, "RBNF rule set {rule_set} does not exist");
+            return Ok(());
+        };
+        let find_rule = |kind| {
+            let mut found = None;
+            for rule in rules.iter().map(RbnfRule::zero_from) {
+                if rule.kind != kind {
+                    continue;
+                }
+                if rule.decimal_separator == self.decimal_separator {
+                    return Some(rule);
+                }
+                found.get_or_insert(rule);
+            }
+            found
+        };
+
+        let original = value;
+        let mut value = value.clone();
+        if value.sign == Sign::Negative && !value.absolute.is_zero() {
+            if let Some(rule) = find_rule(RbnfRuleKind::Negative) {
+                return self.write_fraction_rule(rule_set, &rule, &value, depth, sink);
+            }
+        }
+        value.sign = Sign::None;
+
+        if !is_integer(&value.absolute) {
+            let rule = match is_less_than_one(&value.absolute) {
+                true => find_rule(RbnfRuleKind::ProperFraction),
+                false => None,
+            }
+            .or_else(|| find_rule(RbnfRuleKind::ImproperFraction));
+            if let Some(rule) = rule {
+                return self.write_fraction_rule(rule_set, &rule, &value, depth, sink);
+            }
+        }
+        if let Some(rule) = find_rule(RbnfRuleKind::Default) {
+            return self.write_fraction_rule(rule_set, &rule, &value, depth, sink);
+        }
+
+        // Only the rule is selected with the rounded absolute value; `=` substitutions
+        // format the original value
+        value.round_with_mode(
+            0,
+            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+        );
+        match to_u64(&value.absolute) {
+            Some(n) => self.write_normal(rules, n, original, depth, sink),
+            // Rule sets end with a rule for large numbers that formats them with digits
+            None => self.decimal_formatter.format(&value).write_to(sink),
+        }
+    }
+
+    /// Writes the integer `n`, which is `value` rounded, with the normal rules of a rule set.
+    fn write_normal<W: Write + ?Sized>(
+        &self,
+        rules: &VarZeroSlice<RbnfRuleULE>,
+        n: u64,
+        value: &Decimal,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let rules = rules
+            .iter()
+            .map(RbnfRule::zero_from)
+            .filter(|rule| rule.kind == RbnfRuleKind::Normal)
+            .collect::<Vec<_>>();
+        let Some(mut index) = rules.iter().rposition(|rule| rule.base_value <= n) else {
+            // A rule set without a rule for `n`, such as a rule set for positive numbers
+            return self.decimal_formatter.format(&n.into()).write_to(sink);
+        };
+
+        // A multiple of the divisor is formatted with the previous rule if this rule would
+        // format a remainder of zero, as in a rule `21: twenty->>;` for 30
+        if let Some(rule) = rules.get(index) {
+            let divisor = rule.divisor();
+            if index > 0 && has_modulus(rule) && n % divisor == 0 && rule.base_value % divisor != 0
+            {
+                index -= 1;
+            }
+        }
+        self.write_normal_rule(&rules, index, n, value, depth, sink)
+    }
+
+    /// Writes the integer `n`, which is `value` rounded, with the normal rule at `index` of
+    /// `rules`.
+    fn write_normal_rule<W: Write + ?Sized>(
+        &self,
+        rules: &[RbnfRule],
+        index: usize,
+        n: u64,
+        value: &Decimal,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let Some(rule) = rules.get(index) else {
+            debug_assert!(false, "RBNF rule {index} does not exist");
+            return Ok(());
+        };
+        let divisor = rule.divisor();
+        let include_optional = if rule.base_value == 0 || rule.base_value % divisor != 0 {
+            true
+        } else if has_modulus(rule) {
+            n % divisor != 0
+        } else {
+            n != rule.base_value
+        };
+
+        let quotient = Decimal::from(n / divisor);
+        let remainder = Decimal::from(n % divisor);
+        self.write_body(
+            rule,
+            include_optional,
+            &quotient,
+            sink,
+            |this, part, sink| match part.kind {
+                RbnfPartKind::Quotient | RbnfPartKind::QuotientWithLeadingZeros => {
+                    this.write_target(Target::of(part), &quotient, depth, sink)
+                }
+                // `>>>` formats the remainder with the previous rule, without selecting a rule
+                RbnfPartKind::TripleRemainder if index > 0 => {
+                    this.write_normal_rule(rules, index - 1, n % divisor, &remainder, depth, sink)
+                }
+                RbnfPartKind::Remainder | RbnfPartKind::TripleRemainder => {
+                    this.write_target(Target::of(part), &remainder, depth, sink)
+                }
+                _ => this.write_target(Target::of(part), value, depth, sink),
+            },
+        )
+    }
+
+    /// Writes `value` with a negative, fraction or default rule of the rule set at index
+    /// `rule_set`.
+    fn write_fraction_rule<W: Write + ?Sized>(
+        &self,
+        rule_set: usize,
+        rule: &RbnfRule,
+        value: &Decimal,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let absolute = Decimal::new(Sign::None, value.absolute.clone());
+        let integer = Decimal::new(Sign::None, value.absolute.clone().trunced(0));
+        let fraction = value.absolute.clone().with_max_position(0);
+        let include_optional = match rule.kind {
+            RbnfRuleKind::ImproperFraction => !integer.absolute.is_zero(),
+            RbnfRuleKind::Default => !is_integer(&value.absolute),
+            _ => true,
+        };
+
+        // Numbers less than 1 select the plural form of their rounded value
+        let plural_value = match is_less_than_one(&value.absolute) {
+            true => absolute.clone().rounded_with_mode(
+                0,
+                SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfExpand),
+            ),
+            false => integer.clone(),
+        };
+
+        self.write_body(
+            rule,
+            include_optional,
+            &plural_value,
+            sink,
+            |this, part, sink| match (rule.kind, part.kind) {
+                (
+                    RbnfRuleKind::Negative,
+                    RbnfPartKind::Remainder | RbnfPartKind::TripleRemainder,
+                ) => this.write_target(Target::of(part), &absolute, depth, sink),
+                (_, RbnfPartKind::Quotient | RbnfPartKind::QuotientWithLeadingZeros) => {
+                    this.write_target(Target::of(part), &integer, depth, sink)
+                }
+                (_, RbnfPartKind::Remainder | RbnfPartKind::TripleRemainder) => {
+                    match Target::of(part) {
+                        Target::RuleSet(target) if target != rule_set => {
+                            this.write_fraction_rule_set(target, &fraction, depth, sink)
+                        }
+                        Target::Pattern(pattern) => this.write_pattern(
+                            pattern,
+                            &Decimal::new(Sign::None, fraction.clone()),
+                            sink,
+                        ),
+                        target => this.write_digits(
+                            target,
+                            &fraction,
+                            part.kind == RbnfPartKind::TripleRemainder,
+                            depth,
+                            sink,
+                        ),
+                    }
+                }
+                _ => this.write_target(Target::of(part), value, depth, sink),
+            },
+        )
+    }
+
+    /// Writes the digits of `fraction` one by one, separated by spaces unless `triple` is set.
+    fn write_digits<W: Write + ?Sized>(
+        &self,
+        target: Target,
+        fraction: &UnsignedDecimal,
+        triple: bool,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let end = fraction.nonzero_magnitude_end().min(-1);
+        for magnitude in (end..=-1).rev() {
+            if magnitude != -1 && !triple {
+                sink.write_char(' ')?;
+            }
+            self.write_target(target, &fraction.digit_at(magnitude).into(), depth, sink)?;
+        }
+        Ok(())
+    }
+
+    /// Writes `fraction` with a rule set whose base values are denominators, using the
+    /// denominator that approximates `fraction` best.
+    fn write_fraction_rule_set<W: Write + ?Sized>(
+        &self,
+        rule_set: usize,
+        fraction: &UnsignedDecimal,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let Some(depth) = depth.checked_sub(1) else {
+            debug_assert!(false, "RBNF rule sets nested too deeply");
+            return Ok(());
+        };
+        let Some(rules) = self.rules.get().rule_sets.get(rule_set) else {
+            debug_assert!(false, "RBNF rule set {rule_set} does not exist");
+            return Ok(());
+        };
+
+        // The fraction as `numerator / 10^digits`, limited to the precision of a `u64`
+        let digits = fraction
+            .nonzero_magnitude_end()
+            .clamp(-18, 0)
+            .unsigned_abs();
+        let scale = 10u64.pow(digits.into());
+        let fraction_numerator =
+            to_u64(&fraction.clone().multiplied_pow10(digits as i16).trunced(0))
+                .unwrap_or_default();
+
+        let rules = rules
+            .iter()
+            .map(RbnfRule::zero_from)
+            .filter(|rule| rule.kind == RbnfRuleKind::Normal && rule.base_value != 0)
+            .collect::<Vec<_>>();
+        let mut best: Option<(usize, u64, u128)> = None;
+        for (index, rule) in rules.iter().enumerate() {
+            let product = u128::from(fraction_numerator) * u128::from(rule.base_value);
+            let scale = u128::from(scale);
+            let numerator = (product + scale / 2) / scale;
+            let difference = product.abs_diff(numerator * scale);
+            if best.is_none_or(|(_, _, d)| difference < d) {
+                best = Some((index, numerator.try_into().unwrap_or(u64::MAX), difference));
+            }
+        }
+        let best = best.and_then(|(mut index, numerator, _)| {
+            // Of two rules with the same denominator, the second one is used for numerators
+            // other than 1, as in `2: a half; 2: halves;`
+            if numerator != 1
+                && rules.get(index + 1).map(|r| r.base_value)
+                    == rules.get(index).map(|r| r.base_value)
+            {
+                index += 1;
+            }
+            Some((rules.get(index)?, numerator))
+        });
+        let Some((rule, numerator)) = best else {
+            return self
+                .decimal_formatter
+                .format(&Decimal::new(Sign::None, fraction.clone()))
+                .write_to(sink);
+        };
+
+        self.write_body(
+            rule,
+            numerator != 1,
+            &numerator.into(),
+            sink,
+            |this, part, sink| {
+                if part.kind == RbnfPartKind::QuotientWithLeadingZeros {
+                    let mut shifted = numerator.saturating_mul(10);
+                    while shifted < rule.base_value {
+                        this.write_target(Target::of(part), &0.into(), depth, sink)?;
+                        sink.write_char(' ')?;
+                        shifted = shifted.saturating_mul(10);
+                    }
+                }
+                this.write_target(Target::of(part), &numerator.into(), depth, sink)
+            },
+        )
+    }
+
+    /// Writes the body of `rule`, delegating substitutions to `substitute`.
+    ///
+    /// Plural selections use the category of `plural_value`.
+    fn write_body<W: Write + ?Sized>(
+        &self,
+        rule: &RbnfRule,
+        include_optional: bool,
+        plural_value: &Decimal,
+        sink: &mut W,
+        mut substitute: impl FnMut(&Self, RbnfPart, &mut W) -> fmt::Result,
+    ) -> fmt::Result {
+        let text = |part: RbnfPart| {
+            rule.text
+                .get(usize::from(part.text_start)..usize::from(part.text_end))
+                .unwrap_or_default()
+        };
+        let mut in_optional = false;
+        let mut parts = rule.body.iter().peekable();
+        while let Some(part) = parts.next() {
+            match part.kind {
+                RbnfPartKind::OptionalStart => in_optional = true,
+                RbnfPartKind::OptionalEnd => in_optional = false,
+                _ if in_optional && !include_optional => {}
+                RbnfPartKind::Text => sink.write_str(text(part))?,
+                RbnfPartKind::CardinalPlural | RbnfPartKind::OrdinalPlural => {
+                    let rules = match part.kind {
+                        RbnfPartKind::OrdinalPlural => &self.ordinal_rules,
+                        _ => &self.cardinal_rules,
+                    };
+                    let category = rules.category_for(plural_value);
+                    // The cases follow the selection, falling back to the `other` case
+                    let mut selected = None;
+                    let mut other = None;
+                    while let Some(case) = parts.next_if(|p| p.kind == RbnfPartKind::PluralCase) {
+                        if case.plural_category == category {
+                            selected.get_or_insert(case);
+                        } else if case.plural_category == PluralCategory::Other {
+                            other.get_or_insert(case);
+                        }
+                    }
+                    if let Some(case) = selected.or(other) {
+                        sink.write_str(text(case))?;
+                    }
+                }
+                // Cases are written with their plural selection
+                RbnfPartKind::PluralCase => {}
+                _ => substitute(self, part, sink)?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes `value` as described by the target of a substitution.
+    fn write_target<W: Write + ?Sized>(
+        &self,
+        target: Target,
+        value: &Decimal,
+        depth: u8,
+        sink: &mut W,
+    ) -> fmt::Result {
+        match target {
+            Target::RuleSet(rule_set) => self.write_rule_set(rule_set, value, depth, sink),
+            Target::Pattern(pattern) => self.write_pattern(pattern, value, sink),
+        }
+    }
+
+    /// Writes `value` with a decimal pattern such as `#,##0.##`.
+    fn write_pattern<W: Write + ?Sized>(
+        &self,
+        pattern: RbnfDecimalPattern,
+        value: &Decimal,
+        sink: &mut W,
+    ) -> fmt::Result {
+        let mut value = value.clone();
+        value.round_with_mode(
+            -i16::from(pattern.max_fraction_digits),
+            SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven),
+        );
+        value.absolute.trim_end();
+        value
+            .absolute
+            .pad_end(-i16::from(pattern.min_fraction_digits));
+        value
+            .absolute
+            .pad_start(i16::from(pattern.min_integer_digits));
+
+        match pattern.grouping {
+            true => self.decimal_formatter.format(&value).write_to(sink),
+            false => self
+                .ungrouped_decimal_formatter
+                .format(&value)
+                .write_to(sink),
+        }
+    }
+}
+
+/// Returns the index of the public rule set with the given name.
+///
+/// Private rule sets, whose names start with `%%`, are only meant to be used by other rule sets.
+fn public_rule_set(rules: &RbnfRules<'_>, rule_set: &str) -> Option<usize> {
+    if rule_set.starts_with("%%") {
+        return None;
+    }
+    rules.rule_set(rule_set)
+}
+
+/// Loads the rules of the locale that contain the given public rule set, falling back to
+/// the root locale, which contains the rule sets for numbering systems.
+fn load_rules<D>(
+    provider: &D,
+    prefs: RbnfFormatterPreferences,
+    rule_set: &str,
+) -> Result<(DataPayload<RbnfRulesV1>, usize), DataError>
+where
+    D: DataProvider<RbnfRulesV1> + ?Sized,
+{
+    let locale = RbnfRulesV1::make_locale(prefs.locale_preferences);
+    let find = |rules: DataPayload<RbnfRulesV1>| {
+        let index = public_rule_set(rules.get(), rule_set)?;
+        Some((rules, index))
+    };
+    let found = match provider.load(DataRequest {
+        id: DataIdentifierBorrowed::for_locale(&locale),
+        ..Default::default()
+    }) {
+        Ok(response) => find(response.payload),
+        Err(DataError {
+            kind: DataErrorKind::IdentifierNotFound | DataErrorKind::MarkerNotFound,
+            ..
+        }) => None,
+        Err(e) => return Err(e),
+    };
+    match found {
+        Some(found) => Ok(found),
+        None => find(provider.load(Default::default())?.payload)
+            .ok_or_else(|| unknown_rule_set(rule_set)),
+    }
+}
+
+fn unknown_rule_set(rule_set: &str) -> DataError {
+    DataError::custom("Unknown RBNF rule set").with_display_context(rule_set)
+}
+
+/// Returns the decimal separator of the locale.
+///
+/// Fraction rules can depend on whether the locale uses a decimal comma. The symbols
+/// are keyed like the ones of the decimal formatter, so this loads the same symbols.
+fn load_decimal_separator<D>(
+    provider: &D,
+    prefs: RbnfFormatterPreferences,
+) -> Result<char, DataError>
+where
+    D: DataProvider<DecimalSymbolsV1> + ?Sized,
+{
+    let locale = DecimalSymbolsV1::make_locale(prefs.locale_preferences);
+    let load = |attributes| {
+        provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_marker_attributes_and_locale(attributes, &locale),
+            ..Default::default()
+        })
+    };
+    let symbols = match prefs.numbering_system.as_ref().map(|s| s.as_str()) {
+        Some(nu) => load(DataMarkerAttributes::from_str_or_panic(nu))
+            // If it doesn't exist, fall back to the locale
+            .or_else(|_err| load(DataMarkerAttributes::empty()))?,
+        None => load(DataMarkerAttributes::empty())?,
+    };
+    Ok(symbols
+        .payload
+        .get()
+        .decimal_separator()
+        .chars()
+        .next()
+        .unwrap_or('.'))
+}
+
+/// What the number of a substitution is formatted with.
+#[derive(Debug, Clone, Copy)]
+enum Target {
+    /// The rule set at the index.
+    RuleSet(usize),
+    /// A decimal pattern.
+    Pattern(RbnfDecimalPattern),
+}
+
+impl Target {
+    fn of(part: RbnfPart) -> Self {
+        match part.rule_set {
+            RbnfPart::DECIMAL_PATTERN => Self::Pattern(part.pattern),
+            rule_set => Self::RuleSet(rule_set.into()),
+        }
+    }
+}
+
+/// Whether `rule` has a `>` substitution, which formats the remainder of the division by
+/// the rule's divisor.
+fn has_modulus(rule: &RbnfRule) -> bool {
+    rule.body.iter().any(|part| {
+        matches!(
+            part.kind,
+            RbnfPartKind::Remainder | RbnfPartKind::TripleRemainder
+        )
+    })
+}
+
+/// Whether `value` has no nonzero fraction digits.
+fn is_integer(value: &UnsignedDecimal) -> bool {
+    value.is_zero() || value.nonzero_magnitude_end() >= 0
+}
+
+/// Whether `value` is less than 1.
+fn is_less_than_one(value: &UnsignedDecimal) -> bool {
+    value.is_zero() || value.nonzero_magnitude_start() < 0
+}
+
+/// Returns the integer part of `value` as a `u64`, or `None` if it does not fit.
+fn to_u64(value: &UnsignedDecimal) -> Option<u64> {
+    if value.is_zero() {
+        return Some(0);
+    }
+    (0..=value.nonzero_magnitude_start())
+        .rev()
+        .try_fold(0u64, |n, magnitude| {
+            n.checked_mul(10)?
+                .checked_add(value.digit_at(magnitude).into())
+        })
+}
+
+/// A number formatted with an [`RbnfFormatter`].
+///
+/// This implements [`Writeable`] and [`Display`](core::fmt::Display).
+#[derive(Debug)]
+pub struct FormattedRbnf<'l> {
+    formatter: &'l RbnfFormatter,
+    value: &'l Decimal,
+}
+
+impl Writeable for FormattedRbnf<'_> {
+    fn write_to<W: Write + ?Sized>(&self, sink: &mut W) -> fmt::Result {
+        self.formatter
+            .write_rule_set(self.formatter.rule_set, self.value, MAX_DEPTH, sink)
+    }
+}
+
+writeable::impl_display_with_writeable!(FormattedRbnf<'_>);
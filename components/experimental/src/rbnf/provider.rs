@@ -0,0 +1,267 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! 🚧 \[Unstable\] Data provider struct definitions for this ICU4X component.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. While the serde representation of data structs is guaranteed
+//! to be stable, their Rust representation might not be. Use with caution.
+//! </div>
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use alloc::borrow::Cow;
+use icu_plurals::PluralCategory;
+use icu_provider::prelude::*;
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroSlice, VarZeroVec, ZeroVec};
+
+#[cfg(feature = "compiled_data")]
+/// Baked data
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. In particular, the `DataProvider` implementations are only
+/// guaranteed to match with this version's `*_unstable` providers. Use with caution.
+/// </div>
+pub use crate::provider::Baked;
+
+icu_provider::data_marker!(
+    /// `RbnfRulesV1`
+    ///
+    /// The rule-based number format rule sets of a locale, such as `%spellout-cardinal`,
+    /// `%spellout-ordinal` and `%digits-ordinal`. Rule sets for numbering systems, such as
+    /// `%roman-upper`, are stored in the root locale.
+    RbnfRulesV1,
+    RbnfRules<'static>
+);
+
+/// The [rule-based number format](https://www.unicode.org/reports/tr35/tr35-numbers.html#Rule-Based_Number_Formatting)
+/// rule sets of a locale.
+///
+/// This can be created from rules in ICU syntax with [`RbnfRules::try_from_str`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::rbnf::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct RbnfRules<'data> {
+    /// The names of the rule sets, such as `%spellout-cardinal`.
+    ///
+    /// Private rule sets, which are only used by other rule sets, start with `%%`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: VarZeroVec<'data, str>,
+    /// The rules of each rule set, in the order of [`Self::names`].
+    ///
+    /// The [`RbnfRuleKind::Normal`] rules of a rule set are sorted by their base value.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rule_sets: VarZeroVec<'data, VarZeroSlice<RbnfRuleULE>, Index32>,
+}
+
+icu_provider::data_struct!(RbnfRules<'_>, #[cfg(feature = "datagen")]);
+
+impl RbnfRules<'_> {
+    /// Returns the index of the rule set with the given name.
+    pub fn rule_set(&self, name: &str) -> Option<usize> {
+        self.names.iter().position(|n| n == name)
+    }
+}
+
+/// The kind of an [`RbnfRule`], which determines the numbers the rule applies to.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(RbnfRuleKindULE)]
+#[zerovec::derive(Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::rbnf::provider))]
+#[repr(u8)]
+pub enum RbnfRuleKind {
+    /// A rule for integers starting at the rule's base value.
+    Normal = 0,
+    /// The rule for negative numbers, `-x`.
+    Negative = 1,
+    /// The rule for numbers with a fraction part that are greater than 1, `x.x`.
+    ImproperFraction = 2,
+    /// The rule for numbers with a fraction part that are less than 1, `0.x`.
+    ProperFraction = 3,
+    /// The rule for all non-negative numbers, `x.0`, which takes precedence over the normal
+    /// rules.
+    Default = 4,
+}
+
+/// A single rule of an RBNF rule set.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq)]
+#[zerovec::make_varule(RbnfRuleULE)]
+#[zerovec::skip_derive(Ord)]
+#[zerovec::derive(Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    zerovec::derive(Deserialize)
+)]
+#[cfg_attr(
+    feature = "datagen",
+    derive(serde::Serialize),
+    zerovec::derive(Serialize)
+)]
+pub struct RbnfRule<'data> {
+    /// The kind of the rule.
+    pub kind: RbnfRuleKind,
+    /// The smallest number this rule applies to, for [`RbnfRuleKind::Normal`] rules.
+    ///
+    /// In rule sets that are used for fraction parts, this is the denominator instead.
+    pub base_value: u64,
+    /// The radix of the rule's divisor, usually 10.
+    pub radix: u32,
+    /// The exponent of the rule's divisor, which is the highest power of the radix that is
+    /// less than or equal to the base value, unless decremented in the rule's descriptor.
+    pub exponent: u8,
+    /// The decimal separator in the descriptor of a fraction or default rule, `.` or `,`.
+    ///
+    /// A rule set can have fraction rules for both separators, such as `x.x: << punto >>;` and
+    /// `x,x: << coma >>;`, in which case the rule that matches the locale's decimal separator
+    /// is used.
+    pub decimal_separator: char,
+    /// The literal text of the rule body, which the [`RbnfPart`]s of [`Self::body`] refer to.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub text: Cow<'data, str>,
+    /// The body of the rule, such as `<< hundred[ >>]`, as a sequence of literal text,
+    /// optional text in brackets, substitutions, and plural selections.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub body: ZeroVec<'data, RbnfPart>,
+}
+
+impl RbnfRule<'_> {
+    /// Returns the divisor of the rule, or `u64::MAX` if it does not fit into a `u64`.
+    pub fn divisor(&self) -> u64 {
+        u64::from(self.radix)
+            .checked_pow(self.exponent.into())
+            .unwrap_or(u64::MAX)
+    }
+}
+
+/// The kind of an [`RbnfPart`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(RbnfPartKindULE)]
+#[zerovec::derive(Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::rbnf::provider))]
+#[repr(u8)]
+pub enum RbnfPartKind {
+    /// Literal text.
+    Text = 0,
+    /// The start of optional text, `[`.
+    OptionalStart = 1,
+    /// The end of optional text, `]`.
+    OptionalEnd = 2,
+    /// A `<<` substitution, which formats the quotient of the division by the rule's divisor,
+    /// or the integer part of a fraction.
+    Quotient = 3,
+    /// A substitution like `<%spellout-numbering<<` in a rule set for fractions, which also
+    /// writes the leading zeros of the numerator.
+    QuotientWithLeadingZeros = 4,
+    /// A `>>` substitution, which formats the remainder of the division by the rule's divisor,
+    /// or the fraction part of a fraction.
+    Remainder = 5,
+    /// The `>>>` substitution, which formats the remainder with the previous rule without
+    /// selecting a rule, or the digits of a fraction part without spaces.
+    TripleRemainder = 6,
+    /// A `==` substitution, which formats the number itself.
+    Value = 7,
+    /// A `$(cardinal,...)$` plural selection, which is followed by its [`Self::PluralCase`]s.
+    CardinalPlural = 8,
+    /// A `$(ordinal,...)$` plural selection, which is followed by its [`Self::PluralCase`]s.
+    OrdinalPlural = 9,
+    /// A case of a plural selection, such as `one{st}`.
+    PluralCase = 10,
+}
+
+/// A part of the body of an [`RbnfRule`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(RbnfPartULE)]
+#[zerovec::derive(Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::rbnf::provider))]
+pub struct RbnfPart {
+    /// The kind of the part.
+    pub kind: RbnfPartKind,
+    /// The start of the text of [`RbnfPartKind::Text`] and [`RbnfPartKind::PluralCase`] parts
+    /// in [`RbnfRule::text`], in bytes.
+    pub text_start: u16,
+    /// The end of the text of [`RbnfPartKind::Text`] and [`RbnfPartKind::PluralCase`] parts
+    /// in [`RbnfRule::text`], in bytes.
+    pub text_end: u16,
+    /// The index of the rule set that formats the number of a substitution, or
+    /// [`Self::DECIMAL_PATTERN`] if it is formatted with [`Self::pattern`].
+    pub rule_set: u16,
+    /// The decimal pattern of a substitution like `=#,##0=`.
+    pub pattern: RbnfDecimalPattern,
+    /// The plural category of a [`RbnfPartKind::PluralCase`].
+    pub plural_category: PluralCategory,
+}
+
+impl RbnfPart {
+    /// The [`Self::rule_set`] of substitutions that format the number with a decimal pattern.
+    pub const DECIMAL_PATTERN: u16 = u16::MAX;
+}
+
+/// A decimal pattern of a substitution, such as `#,##0.00`.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(RbnfDecimalPatternULE)]
+#[zerovec::derive(Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::rbnf::provider))]
+pub struct RbnfDecimalPattern {
+    /// The minimum number of integer digits, the number of `0`s before the decimal point.
+    pub min_integer_digits: u8,
+    /// The minimum number of fraction digits, the number of `0`s after the decimal point.
+    pub min_fraction_digits: u8,
+    /// The maximum number of fraction digits, the number of `0`s and `#`s after the
+    /// decimal point.
+    pub max_fraction_digits: u8,
+    /// Whether the pattern has a grouping separator.
+    pub grouping: bool,
+}
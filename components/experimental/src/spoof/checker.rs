@@ -0,0 +1,430 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::spoof::provider::{SpoofData, SpoofDataV1};
+use alloc::string::String;
+use alloc::vec::Vec;
+use icu_normalizer::provider::{NormalizerNfdDataV1, NormalizerNfdTablesV1};
+use icu_normalizer::DecomposingNormalizer;
+use icu_properties::props::{DefaultIgnorableCodePoint, Script};
+use icu_properties::provider::{
+    PropertyBinaryDefaultIgnorableCodePointV1, PropertyScriptWithExtensionsV1,
+};
+use icu_properties::script::ScriptWithExtensions;
+use icu_properties::CodePointSetData;
+use icu_provider::prelude::*;
+
+// `Script` has no named values for the scripts below, which have no characters of their own.
+// The numbers are those of `USCRIPT_HAN_WITH_BOPOMOFO`, `USCRIPT_JAPANESE` and `USCRIPT_KOREAN`
+// in ICU4C's `UScriptCode` enum (uscript.h), which `Script::from_icu4c_value` mirrors.
+
+/// The `Hanb` script, which only occurs in augmented script sets.
+const HAN_WITH_BOPOMOFO: Script = Script::from_icu4c_value(172);
+/// The `Jpan` script, which only occurs in augmented script sets.
+const JAPANESE: Script = Script::from_icu4c_value(105);
+/// The `Kore` script, which only occurs in augmented script sets.
+const KOREAN: Script = Script::from_icu4c_value(119);
+
+/// The scripts of [Table 7](https://www.unicode.org/reports/tr31/#Table_Recommended_Scripts)
+/// of UAX #31 that are recommended for use in identifiers.
+const RECOMMENDED_SCRIPTS: &[Script] = &[
+    Script::Arabic,
+    Script::Armenian,
+    Script::Bengali,
+    Script::Bopomofo,
+    Script::Cyrillic,
+    Script::Devanagari,
+    Script::Ethiopian,
+    Script::Georgian,
+    Script::Greek,
+    Script::Gujarati,
+    Script::Gurmukhi,
+    Script::Han,
+    Script::Hangul,
+    Script::Hebrew,
+    Script::Hiragana,
+    Script::Kannada,
+    Script::Katakana,
+    Script::Khmer,
+    Script::Lao,
+    Script::Latin,
+    Script::Malayalam,
+    Script::Myanmar,
+    Script::Oriya,
+    Script::Sinhala,
+    Script::Tamil,
+    Script::Telugu,
+    Script::Thaana,
+    Script::Thai,
+    Script::Tibetan,
+];
+
+/// The kind of confusability of two strings, as defined in
+/// [Section 4](https://www.unicode.org/reports/tr39/#Confusable_Detection) of UTS #39.
+///
+/// Returned by [`SpoofChecker::confusable_type`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ConfusableType {
+    /// The strings are confusable and share a script, such as `rn` and `m`.
+    SingleScript,
+    /// The strings are confusable, but they do not share a script, and at least one of them
+    /// mixes scripts, such as `paypal` and `pаypal` with a Cyrillic `а`.
+    MixedScript,
+    /// The strings are confusable, and they are written in different single scripts,
+    /// such as Latin `scope` and Cyrillic `ѕсоре`.
+    WholeScript,
+}
+
+/// The restriction level of a string, as defined in
+/// [Section 5.2](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection) of UTS #39.
+///
+/// Levels are ordered from most to least restrictive, so a string can be checked against a
+/// maximum level by comparison.
+///
+/// Returned by [`SpoofChecker::restriction_level`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum RestrictionLevel {
+    /// All characters are ASCII.
+    AsciiOnly,
+    /// All characters are in a single script, such as `Ελληνικά`.
+    SingleScript,
+    /// The string is in Latin and Han, Hiragana and Katakana, or Latin and Han and Bopomofo,
+    /// or Latin and Han and Hangul, such as `iPhone対応`.
+    HighlyRestrictive,
+    /// The string is in Latin and one other recommended script except Cyrillic and Greek,
+    /// such as `Hello עולם`.
+    ModeratelyRestrictive,
+    /// The string mixes scripts in other ways, such as `Hello мир`.
+    MinimallyRestrictive,
+    /// The string contains characters that are not allowed in identifiers, such as
+    /// punctuation or characters of scripts that are not in common use.
+    Unrestricted,
+}
+
+/// The resolved script set of a string, where `None` contains all scripts.
+type ScriptSet = Option<Vec<Script>>;
+
+/// A checker for strings that can be mistaken for other strings, based on
+/// [UTS #39: Unicode Security Mechanisms](https://www.unicode.org/reports/tr39/).
+///
+/// This can be used to flag lookalike user names and domain names:
+///
+/// * [`SpoofChecker::skeleton`] maps strings to a form in which confusable strings are equal.
+/// * [`SpoofChecker::confusable_type`] detects single-script, mixed-script and whole-script
+///   confusables.
+/// * [`SpoofChecker::restriction_level`] determines how strictly a string keeps to a single
+///   script and to the characters that are allowed in identifiers.
+///
+/// The data for this type is generated from the UTS #39 data files (`--uts39-root` in datagen).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+///
+/// # Example
+///
+/// ```
+/// use icu::experimental::spoof::{ConfusableType, RestrictionLevel, SpoofChecker};
+///
+/// let checker = SpoofChecker::new();
+///
+/// assert_eq!(checker.skeleton("сосоа"), "cocoa");
+/// assert_eq!(
+///     checker.confusable_type("cocoa", "cоcоa"),
+///     Some(ConfusableType::MixedScript)
+/// );
+/// assert_eq!(
+///     checker.confusable_type("cocoa", "сосоа"),
+///     Some(ConfusableType::WholeScript)
+/// );
+/// assert_eq!(checker.confusable_type("cocoa", "coffee"), None);
+///
+/// assert_eq!(checker.restriction_level("cocoa"), RestrictionLevel::AsciiOnly);
+/// assert_eq!(checker.restriction_level("сосоа"), RestrictionLevel::SingleScript);
+/// assert_eq!(
+///     checker.restriction_level("cоcоa"),
+///     RestrictionLevel::MinimallyRestrictive
+/// );
+/// ```
+#[derive(Debug)]
+pub struct SpoofChecker {
+    data: DataPayload<SpoofDataV1>,
+    scripts: ScriptWithExtensions,
+    default_ignorables: CodePointSetData,
+    nfd: DecomposingNormalizer,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for SpoofChecker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpoofChecker {
+    /// Creates a new [`SpoofChecker`] from compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn new() -> Self {
+        Self::new_with_payload(DataPayload::from_static_ref(
+            crate::provider::Baked::SINGLETON_SPOOF_DATA_V1,
+        ))
+    }
+
+    icu_provider::gen_buffer_data_constructors!(() -> error: DataError,
+    functions: [
+        new: skip,
+        try_new_with_buffer_provider,
+        try_new_unstable,
+        Self,
+    ]);
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable<D>(provider: &D) -> Result<Self, DataError>
+    where
+        D: DataProvider<SpoofDataV1>
+            + DataProvider<PropertyScriptWithExtensionsV1>
+            + DataProvider<PropertyBinaryDefaultIgnorableCodePointV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        Self::try_new_with_payload_unstable(provider, provider.load(Default::default())?.payload)
+    }
+
+    /// Creates a new [`SpoofChecker`] with the given confusables and identifier data, using
+    /// compiled data for the script, default ignorable and normalization properties.
+    ///
+    /// This can be used with data that is not part of the provider, such as data from a
+    /// different version of UTS #39.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::experimental::spoof::provider::SpoofData;
+    /// use icu::experimental::spoof::{ConfusableType, RestrictionLevel, SpoofChecker};
+    /// use icu_collections::codepointinvlist::CodePointInversionList;
+    ///
+    /// // A few of the confusables
+    /// let checker = SpoofChecker::new_with_data(SpoofData {
+    ///     prototypes: [('0', "O"), ('а', "a"), ('о', "o"), ('с', "c"), ('р', "p")]
+    ///         .into_iter()
+    ///         .collect(),
+    ///     allowed: CodePointInversionList::try_from_u32_inversion_list_slice(&[
+    ///         0x61, 0x7B, 0x430, 0x450,
+    ///     ])
+    ///     .unwrap(),
+    /// });
+    ///
+    /// assert_eq!(checker.skeleton("сосоа"), "cocoa");
+    /// assert_eq!(
+    ///     checker.confusable_type("cocoa", "cоcоa"),
+    ///     Some(ConfusableType::MixedScript)
+    /// );
+    /// assert_eq!(
+    ///     checker.confusable_type("cocoa", "сосоа"),
+    ///     Some(ConfusableType::WholeScript)
+    /// );
+    /// assert_eq!(checker.confusable_type("cocoa", "coffee"), None);
+    ///
+    /// assert_eq!(checker.restriction_level("cocoa"), RestrictionLevel::AsciiOnly);
+    /// assert_eq!(checker.restriction_level("сосоа"), RestrictionLevel::SingleScript);
+    /// assert_eq!(
+    ///     checker.restriction_level("cоcоa"),
+    ///     RestrictionLevel::MinimallyRestrictive
+    /// );
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn new_with_data(data: SpoofData<'static>) -> Self {
+        Self::new_with_payload(DataPayload::from_owned(data))
+    }
+
+    #[cfg(feature = "compiled_data")]
+    fn new_with_payload(data: DataPayload<SpoofDataV1>) -> Self {
+        Self {
+            data,
+            scripts: ScriptWithExtensions::new().static_to_owned(),
+            default_ignorables: CodePointSetData::new::<DefaultIgnorableCodePoint>()
+                .static_to_owned(),
+            nfd: DecomposingNormalizer::new_nfd().static_to_owned(),
+        }
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new_with_data)]
+    pub fn try_new_with_data_unstable<D>(
+        provider: &D,
+        data: SpoofData<'static>,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<PropertyScriptWithExtensionsV1>
+            + DataProvider<PropertyBinaryDefaultIgnorableCodePointV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        Self::try_new_with_payload_unstable(provider, DataPayload::from_owned(data))
+    }
+
+    fn try_new_with_payload_unstable<D>(
+        provider: &D,
+        data: DataPayload<SpoofDataV1>,
+    ) -> Result<Self, DataError>
+    where
+        D: DataProvider<PropertyScriptWithExtensionsV1>
+            + DataProvider<PropertyBinaryDefaultIgnorableCodePointV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + ?Sized,
+    {
+        Ok(Self {
+            data,
+            scripts: ScriptWithExtensions::try_new_unstable(provider)?,
+            default_ignorables: CodePointSetData::try_new_unstable::<DefaultIgnorableCodePoint>(
+                provider,
+            )?,
+            nfd: DecomposingNormalizer::try_new_nfd_unstable(provider)?,
+        })
+    }
+
+    /// Returns the [skeleton](https://www.unicode.org/reports/tr39/#def-skeleton) of a
+    /// string. Two strings are confusable if and only if their skeletons are equal.
+    ///
+    /// Skeletons are only meant for comparison, they are not suitable for display.
+    pub fn skeleton(&self, s: &str) -> String {
+        let nfd = self.nfd.as_borrowed();
+        let default_ignorables = self.default_ignorables.as_borrowed();
+        let prototypes = &self.data.get().prototypes;
+
+        let mut skeleton = String::with_capacity(s.len());
+        for ch in nfd.normalize(s).chars() {
+            if default_ignorables.contains(ch) {
+                continue;
+            }
+            match prototypes.get(&ch) {
+                Some(prototype) => skeleton.push_str(prototype),
+                None => skeleton.push(ch),
+            }
+        }
+        nfd.normalize(&skeleton).into_owned()
+    }
+
+    /// Returns whether two strings are confusable, that is, whether their
+    /// [skeletons](Self::skeleton) are equal.
+    pub fn are_confusable(&self, a: &str, b: &str) -> bool {
+        self.skeleton(a) == self.skeleton(b)
+    }
+
+    /// Returns the kind of confusability of two strings, or `None` if they are not confusable.
+    pub fn confusable_type(&self, a: &str, b: &str) -> Option<ConfusableType> {
+        if !self.are_confusable(a, b) {
+            return None;
+        }
+        let a = self.resolved_script_set(a.chars());
+        let b = self.resolved_script_set(b.chars());
+        Some(if !is_empty(&intersection(a.clone(), &b)) {
+            ConfusableType::SingleScript
+        } else if !is_empty(&a) && !is_empty(&b) {
+            ConfusableType::WholeScript
+        } else {
+            ConfusableType::MixedScript
+        })
+    }
+
+    /// Returns whether a string mixes scripts, that is, whether its
+    /// [resolved script set](https://www.unicode.org/reports/tr39/#def-resolved-script-set)
+    /// is empty.
+    ///
+    /// Characters that are used with several scripts, such as digits and `ー`, do not make a
+    /// string mixed-script, and Han characters can be mixed with Hiragana and Katakana, Hangul
+    /// or Bopomofo.
+    pub fn is_mixed_script(&self, s: &str) -> bool {
+        is_empty(&self.resolved_script_set(s.chars()))
+    }
+
+    /// Returns the [restriction level](https://www.unicode.org/reports/tr39/#Restriction_Level_Detection)
+    /// of a string.
+    pub fn restriction_level(&self, s: &str) -> RestrictionLevel {
+        let allowed = &self.data.get().allowed;
+        let scripts = self.scripts.as_borrowed();
+
+        if !s.chars().all(|ch| allowed.contains(ch)) {
+            return RestrictionLevel::Unrestricted;
+        }
+        if s.is_ascii() {
+            return RestrictionLevel::AsciiOnly;
+        }
+        if !is_empty(&self.resolved_script_set(s.chars())) {
+            return RestrictionLevel::SingleScript;
+        }
+
+        let without_latin = self.resolved_script_set(
+            s.chars()
+                .filter(|&ch| !scripts.has_script(ch, Script::Latin)),
+        );
+        let contains = |script| match &without_latin {
+            None => true,
+            Some(set) => set.contains(&script),
+        };
+        if contains(JAPANESE) || contains(KOREAN) || contains(HAN_WITH_BOPOMOFO) {
+            RestrictionLevel::HighlyRestrictive
+        } else if RECOMMENDED_SCRIPTS.iter().any(|&script| {
+            script != Script::Cyrillic && script != Script::Greek && contains(script)
+        }) {
+            RestrictionLevel::ModeratelyRestrictive
+        } else {
+            RestrictionLevel::MinimallyRestrictive
+        }
+    }
+
+    /// Returns the intersection of the augmented script sets of the characters.
+    fn resolved_script_set(&self, chars: impl Iterator<Item = char>) -> ScriptSet {
+        let scripts = self.scripts.as_borrowed();
+        let mut resolved = None;
+        for ch in chars {
+            let extensions = scripts.get_script_extensions_val(ch);
+            // Common and Inherited characters are used with all scripts
+            if extensions.contains(&Script::Common) || extensions.contains(&Script::Inherited) {
+                continue;
+            }
+            let mut augmented = Vec::new();
+            for script in extensions.iter() {
+                augmented.push(script);
+                match script {
+                    Script::Han => augmented.extend([HAN_WITH_BOPOMOFO, JAPANESE, KOREAN]),
+                    Script::Hiragana | Script::Katakana => augmented.push(JAPANESE),
+                    Script::Hangul => augmented.push(KOREAN),
+                    Script::Bopomofo => augmented.push(HAN_WITH_BOPOMOFO),
+                    _ => {}
+                }
+            }
+            resolved = intersection(resolved, &Some(augmented));
+        }
+        resolved
+    }
+}
+
+fn intersection(a: ScriptSet, b: &ScriptSet) -> ScriptSet {
+    match (a, b) {
+        (None, b) => b.clone(),
+        (a, None) => a,
+        (Some(mut a), Some(b)) => {
+            a.retain(|script| b.contains(script));
+            Some(a)
+        }
+    }
+}
+
+fn is_empty(set: &ScriptSet) -> bool {
+    set.as_ref().is_some_and(Vec::is_empty)
+}
@@ -0,0 +1,31 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Detection of strings that can be mistaken for other strings, such as lookalike user names
+//! and domain names, following [UTS #39: Unicode Security Mechanisms](https://www.unicode.org/reports/tr39/).
+//!
+//! See [`SpoofChecker`] for details.
+
+// https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::indexing_slicing,
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::exhaustive_structs,
+        clippy::exhaustive_enums,
+        clippy::trivially_copy_pass_by_ref,
+        missing_debug_implementations,
+    )
+)]
+#![warn(missing_docs)]
+
+mod checker;
+pub mod provider;
+
+pub use checker::ConfusableType;
+pub use checker::RestrictionLevel;
+pub use checker::SpoofChecker;
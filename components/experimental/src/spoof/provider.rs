@@ -0,0 +1,69 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+// Provider structs must be stable
+#![allow(clippy::exhaustive_structs, clippy::exhaustive_enums)]
+
+//! 🚧 \[Unstable\] Data provider struct definitions for this ICU4X component.
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. While the serde representation of data structs is guaranteed
+//! to be stable, their Rust representation might not be. Use with caution.
+//! </div>
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_collections::codepointinvlist::CodePointInversionList;
+use icu_provider::prelude::*;
+use zerovec::ZeroMap;
+
+#[cfg(feature = "compiled_data")]
+/// Baked data
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. In particular, the `DataProvider` implementations are only
+/// guaranteed to match with this version's `*_unstable` providers. Use with caution.
+/// </div>
+pub use crate::provider::Baked;
+
+icu_provider::data_marker!(
+    /// `SpoofDataV1`
+    ///
+    /// The confusable mappings and the identifier profile of
+    /// [UTS #39](https://www.unicode.org/reports/tr39/).
+    SpoofDataV1,
+    SpoofData<'static>,
+    is_singleton = true
+);
+
+/// The data used for confusable detection and restriction-level checks, from the
+/// `confusables.txt` and `IdentifierStatus.txt` files of
+/// [UTS #39](https://www.unicode.org/reports/tr39/#Data_Files).
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_experimental::spoof::provider))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct SpoofData<'data> {
+    /// The prototype of each code point that is confusable with a different string, such as
+    /// `a` for `U+0430 CYRILLIC SMALL LETTER A` and `rn` for `m`.
+    ///
+    /// Code points that are not in this map are their own prototype.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub prototypes: ZeroMap<'data, char, str>,
+    /// The code points with `Identifier_Status=Allowed`, which make up the
+    /// [General Security Profile](https://www.unicode.org/reports/tr39/#General_Security_Profile).
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub allowed: CodePointInversionList<'data>,
+}
+
+icu_provider::data_struct!(SpoofData<'_>, #[cfg(feature = "datagen")]);
@@ -121,6 +121,74 @@ impl RuleCollection {
         self.register_aliases(id, aliases)
     }
 
+    /// Add a new transliteration source to the collection, in both directions.
+    ///
+    /// The forward direction is registered under `id` and `aliases`, and the reverse direction
+    /// under `reverse_id` and the inverse of each alias. As in a `::`-rule, an alias can name
+    /// its inverse explicitly, as in `"Any-Strip(Any-Null)"`; otherwise the inverse swaps
+    /// source and target (`"Cyrillic-Latin"` for `"Latin-Cyrillic"`), except for the built-in
+    /// transliterators that have a different inverse (`"Any-NFC"` for `"NFD"`).
+    pub fn register_bidirectional_source<'a>(
+        &mut self,
+        id: &icu_locale_core::Locale,
+        reverse_id: &icu_locale_core::Locale,
+        source: String,
+        aliases: impl IntoIterator<Item = &'a str>,
+        visible: bool,
+    ) {
+        let (aliases, reverse_aliases): (Vec<_>, Vec<_>) =
+            aliases.into_iter().map(split_bidirectional_alias).unzip();
+        self.register_source(id, source.clone(), aliases, false, visible);
+        self.register_source(
+            reverse_id,
+            source,
+            reverse_aliases.iter().map(String::as_str),
+            true,
+            visible,
+        );
+    }
+
+    /// Add a transliterator that is defined by an ICU-style compound ID.
+    ///
+    /// A compound ID is a `;`-separated list of transliterator IDs with optional filters,
+    /// such as `"Any-Latin; NFD; [:Nonspacing Mark:] Remove; NFC"`. A leading UnicodeSet on
+    /// its own is a global filter, and a parenthesized one at the end a global inverse filter.
+    ///
+    /// This is equivalent to registering a source of `::`-rules, one for each element.
+    ///
+    /// # Example
+    /// ```
+    /// use icu::experimental::transliterate::{RuleCollection, Transliterator};
+    ///
+    /// let mut collection = RuleCollection::default();
+    /// collection.register_compound(
+    ///     &"und-t-und-x0-strip".parse().unwrap(),
+    ///     "NFD; [:Nonspacing Mark:] Remove; Lower; NFC",
+    ///     ["Any-Strip"],
+    ///     false,
+    ///     true,
+    /// );
+    ///
+    /// let t = Transliterator::try_new_unstable(&collection.as_provider(), &collection.as_provider(), &collection.as_provider(), &"und-t-und-x0-strip".parse().unwrap()).unwrap();
+    /// assert_eq!(t.transliterate("Crème Brûlée".into()), "creme brulee");
+    /// ```
+    pub fn register_compound<'a>(
+        &mut self,
+        id: &icu_locale_core::Locale,
+        compound_id: &str,
+        aliases: impl IntoIterator<Item = &'a str>,
+        reverse: bool,
+        visible: bool,
+    ) {
+        self.register_source(
+            id,
+            compound_id_to_source(compound_id),
+            aliases,
+            reverse,
+            visible,
+        )
+    }
+
     /// Add transliteration ID aliases without registering a source.
     pub fn register_aliases<'a>(
         &mut self,
@@ -262,6 +330,57 @@ impl RuleCollection {
     }
 }
 
+/// Splits an alias of a bidirectional source into its forward and reverse IDs.
+///
+/// As in `::`-rules, the reverse ID can be given in parentheses, e.g. "Any-Strip(Any-Null)".
+/// Otherwise it is the inverse that a `::`-rule would use, e.g. "Cyrillic-Latin/BGN" for
+/// "Latin-Cyrillic/BGN", or "Any-NFC" for "NFD".
+fn split_bidirectional_alias(alias: &str) -> (&str, String) {
+    match alias
+        .trim_end()
+        .strip_suffix(')')
+        .and_then(|a| a.split_once('('))
+    {
+        Some((forward, reverse)) if reverse.trim().is_empty() => {
+            (forward.trim(), parse::BasicId::default().to_string())
+        }
+        Some((forward, reverse)) => (forward.trim(), reverse.trim().to_string()),
+        None => (alias, parse::BasicId::from_id(alias).reverse().to_string()),
+    }
+}
+
+/// Converts a compound ID into the equivalent transform rules.
+fn compound_id_to_source(compound_id: &str) -> String {
+    let mut source = String::new();
+    let mut push_element = |element: &str| {
+        let element = element.trim();
+        if !element.is_empty() {
+            source.push_str("::");
+            source.push_str(element);
+            source.push_str(";\n");
+        }
+    };
+    // `;` also occurs in UnicodeSets, which can be nested and contain escapes
+    let mut depth = 0usize;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in compound_id.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            ';' if depth == 0 => {
+                push_element(compound_id.get(start..i).unwrap_or_default());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    push_element(compound_id.get(start..).unwrap_or_default());
+    source
+}
+
 /// A provider that is usable by [`Transliterator::try_new_unstable`](crate::transliterate::Transliterator::try_new_unstable).
 #[derive(Debug)]
 pub struct RuleCollectionProvider<'a, PP: ?Sized, NP: ?Sized, NC: ?Sized> {
@@ -274,6 +393,34 @@ pub struct RuleCollectionProvider<'a, PP: ?Sized, NP: ?Sized, NC: ?Sized> {
     pat_ws: CodePointSetData,
 }
 
+impl<PP: ?Sized, NP: ?Sized, NC: ?Sized> RuleCollectionProvider<'_, PP, NP, NC> {
+    /// Registers a compound ID under a fresh private-use ID, which is returned.
+    pub(crate) fn register_anonymous_compound(&self, compound_id: &str, reverse: bool) -> Locale {
+        let mut exclusive_data = self.collection.data.borrow_mut();
+        let mut i = 0usize;
+        let (id, key) = loop {
+            let key = format!("und-x-compound-{i}");
+            if !exclusive_data.0.contains_key(&key) && !exclusive_data.1.contains_key(&key) {
+                #[expect(clippy::unwrap_used)] // valid private-use subtags
+                break (Locale::try_from_str(&key).unwrap(), key);
+            }
+            i += 1;
+        };
+        exclusive_data
+            .0
+            .insert(key, (compound_id_to_source(compound_id), reverse, true));
+        id
+    }
+
+    /// Removes a transliterator registered by [`Self::register_anonymous_compound`].
+    pub(crate) fn remove_anonymous_compound(&self, id: &Locale) {
+        let key = id.to_string().to_ascii_lowercase();
+        let mut exclusive_data = self.collection.data.borrow_mut();
+        exclusive_data.0.remove(&key);
+        exclusive_data.1.remove(&key);
+    }
+}
+
 impl<PP, NP, NC> DataProvider<TransliteratorRulesV1> for RuleCollectionProvider<'_, PP, NP, NC>
 where
     PP: ?Sized
@@ -661,6 +808,28 @@ mod tests {
             .clone()
     }
 
+    #[test]
+    fn test_bidirectional_alias() {
+        for (alias, forward, reverse) in [
+            (
+                "Latin-Cyrillic/BGN",
+                "Latin-Cyrillic/BGN",
+                "cyrillic-latin/bgn",
+            ),
+            ("NFD", "NFD", "any-nfc"),
+            ("Any-Upper", "Any-Upper", "any-lower"),
+            ("Any-Remove", "Any-Remove", "any-remove"),
+            ("Any-Strip(Any-Null)", "Any-Strip", "Any-Null"),
+            ("Any-Strip()", "Any-Strip", "any-null"),
+        ] {
+            assert_eq!(
+                split_bidirectional_alias(alias),
+                (forward, reverse.to_string()),
+                "{alias}"
+            );
+        }
+    }
+
     #[test]
     fn test_source_to_struct() {
         let source = r"
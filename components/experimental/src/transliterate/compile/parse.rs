@@ -104,6 +104,21 @@ pub(crate) struct BasicId {
 }
 
 impl BasicId {
+    /// Splits an ID of the form `source-target/variant`, without validating its identifiers. As
+    /// in `::`-rules, the source defaults to `Any`.
+    pub(crate) fn from_id(id: &str) -> Self {
+        let (basic_id, variant) = match id.split_once('/') {
+            Some((basic_id, variant)) => (basic_id, Some(variant.trim().to_string())),
+            None => (id, None),
+        };
+        let (source, target) = basic_id.split_once('-').unwrap_or(("Any", basic_id));
+        Self {
+            source: source.trim().to_string(),
+            target: target.trim().to_string(),
+            variant,
+        }
+    }
+
     pub(crate) fn reverse(self) -> Self {
         let source = self.source.to_lowercase();
         let target = self.target.to_lowercase();
@@ -29,7 +29,9 @@ mod transliterator;
 
 #[cfg(feature = "compiled_data")]
 pub use transliterator::TransliteratorBuilder;
-pub use transliterator::{CustomTransliterator, Transliterator};
+pub use transliterator::{
+    CustomTransliterator, IncrementalTransliterator, Transliterator, TransliteratorWriter,
+};
 
 pub use compile::RuleCollection;
 pub use compile::RuleCollectionProvider;
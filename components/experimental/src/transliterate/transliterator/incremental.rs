@@ -0,0 +1,200 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Incremental transliteration of text that arrives in chunks.
+//!
+//! Rules can look at the text before and after the part they replace, so the end of the
+//! text seen so far cannot be transliterated until more input arrives. An
+//! [`IncrementalTransliterator`] therefore transliterates everything up to the last
+//! whitespace character, holding back the last (possibly incomplete) word, and keeps the
+//! end of its output around as preceding context for the next chunk.
+
+use super::replaceable::{Replaceable, TransliteratorBuffer};
+use super::Transliterator;
+use alloc::string::String;
+use core::fmt;
+
+/// The maximum number of chars that are held back, and the number of chars of committed
+/// output that are kept as preceding context.
+const MAX_CONTEXT_CHARS: usize = 32;
+
+/// Transliterates text that is fed in chunks, such as a large document or a chat stream.
+///
+/// Output is committed up to the last whitespace character seen so far; the rest of the input
+/// is held back until more input arrives or [`finish`](Self::finish) is called. Words longer
+/// than 32 chars are split, keeping the last 32 chars as pending context.
+///
+/// The output is the same as that of [`Transliterator::transliterate`] for rules whose
+/// contexts do not reach across these boundaries.
+///
+/// This is returned by [`Transliterator::incremental`].
+///
+/// # Examples
+///
+/// ```
+/// use icu::experimental::transliterate::Transliterator;
+///
+/// let t = Transliterator::try_new(&"de-t-de-d0-ascii".parse().unwrap()).unwrap();
+/// let mut incremental = t.incremental();
+///
+/// let mut output = String::new();
+/// output += &incremental.push("Über äl");
+/// assert_eq!(output, "Ueber");
+/// assert_eq!(incremental.pending(), " äl");
+///
+/// output += &incremental.push("tere Lügner");
+/// output += &incremental.finish();
+/// assert_eq!(output, "Ueber aeltere Luegner");
+/// ```
+#[derive(Debug)]
+pub struct IncrementalTransliterator<'a> {
+    transliterator: &'a Transliterator,
+    /// The end of the committed output, visible to rules as preceding context.
+    context: String,
+    /// The input that has not been transliterated yet.
+    pending: String,
+}
+
+impl<'a> IncrementalTransliterator<'a> {
+    pub(super) fn new(transliterator: &'a Transliterator) -> Self {
+        Self {
+            transliterator,
+            context: String::new(),
+            pending: String::new(),
+        }
+    }
+
+    /// Feeds `chunk` and returns the output that has been committed.
+    pub fn push(&mut self, chunk: &str) -> String {
+        let mut output = String::new();
+        let _infallible = self.push_to(chunk, &mut output);
+        output
+    }
+
+    /// Feeds `chunk` and writes the output that has been committed to `sink`.
+    pub fn push_to<W: fmt::Write + ?Sized>(&mut self, chunk: &str, sink: &mut W) -> fmt::Result {
+        self.pending.push_str(chunk);
+        let end = commit_boundary(&self.pending);
+        let output = self.commit(end);
+        sink.write_str(&output)
+    }
+
+    /// Returns the input that has been held back.
+    pub fn pending(&self) -> &str {
+        &self.pending
+    }
+
+    /// Transliterates the held back input and returns the remaining output.
+    pub fn finish(self) -> String {
+        let mut output = String::new();
+        let _infallible = self.finish_to(&mut output);
+        output
+    }
+
+    /// Transliterates the held back input and writes the remaining output to `sink`.
+    pub fn finish_to<W: fmt::Write + ?Sized>(mut self, sink: &mut W) -> fmt::Result {
+        let output = self.commit(self.pending.len());
+        sink.write_str(&output)
+    }
+
+    /// Transliterates `pending[..end]`, with the committed output before it and the rest of
+    /// the pending input after it as context, and returns its transliteration.
+    fn commit(&mut self, end: usize) -> String {
+        if end == 0 {
+            return String::new();
+        }
+        let held_back = self.pending.split_off(end);
+        let context_len = self.context.len();
+
+        let mut text = core::mem::take(&mut self.context);
+        text.push_str(&self.pending);
+        text.push_str(&held_back);
+        let mut buffer = TransliteratorBuffer::from_string(text);
+        let rep = Replaceable::with_context(&mut buffer, context_len, held_back.len());
+        self.transliterator
+            .transliterator
+            .get()
+            .transliterate(rep, &self.transliterator.env);
+        let mut text = buffer.into_string();
+        text.truncate(text.len() - held_back.len());
+
+        let output = String::from(&text[context_len..]);
+        let context_start = text
+            .char_indices()
+            .rev()
+            .nth(MAX_CONTEXT_CHARS - 1)
+            .map_or(0, |(i, _)| i);
+        text.drain(..context_start);
+        self.context = text;
+        self.pending = held_back;
+        output
+    }
+}
+
+/// Returns the end of the part of `pending` that can be committed: everything before the
+/// last whitespace character, but at most all except the last [`MAX_CONTEXT_CHARS`] chars.
+fn commit_boundary(pending: &str) -> usize {
+    let word_start = pending
+        .char_indices()
+        .rev()
+        .find(|&(_, c)| c.is_whitespace())
+        .map_or(0, |(i, _)| i);
+    let limit = pending
+        .char_indices()
+        .rev()
+        .nth(MAX_CONTEXT_CHARS - 1)
+        .map_or(0, |(i, _)| i);
+    word_start.max(limit)
+}
+
+/// A [`fmt::Write`] sink that transliterates everything written to it into another sink.
+///
+/// This allows transliterating the output of [`Writeable`](writeable::Writeable)s and
+/// formatting macros without collecting it into a `String` first.
+///
+/// Like [`IncrementalTransliterator`], this holds back the end of the input until
+/// [`finish`](Self::finish) is called.
+///
+/// This is returned by [`Transliterator::writer`].
+///
+/// # Examples
+///
+/// ```
+/// use core::fmt::Write;
+/// use icu::experimental::transliterate::Transliterator;
+///
+/// let t = Transliterator::try_new(&"de-t-de-d0-ascii".parse().unwrap()).unwrap();
+///
+/// let mut writer = t.writer(String::new());
+/// write!(writer, "{} Lügner", 3).unwrap();
+/// let output = writer.finish().unwrap();
+///
+/// assert_eq!(output, "3 Luegner");
+/// ```
+#[derive(Debug)]
+pub struct TransliteratorWriter<'a, W> {
+    incremental: IncrementalTransliterator<'a>,
+    sink: W,
+}
+
+impl<'a, W: fmt::Write> TransliteratorWriter<'a, W> {
+    pub(super) fn new(transliterator: &'a Transliterator, sink: W) -> Self {
+        Self {
+            incremental: IncrementalTransliterator::new(transliterator),
+            sink,
+        }
+    }
+
+    /// Writes the transliteration of the held back input and returns the sink.
+    pub fn finish(mut self) -> Result<W, fmt::Error> {
+        self.incremental.finish_to(&mut self.sink)?;
+        Ok(self.sink)
+    }
+}
+
+impl<W: fmt::Write> fmt::Write for TransliteratorWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.incremental.push_to(s, &mut self.sink)
+    }
+}
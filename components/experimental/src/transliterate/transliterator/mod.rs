@@ -5,11 +5,13 @@
 #![expect(clippy::indexing_slicing, clippy::unwrap_used)] // TODO(#3958): Remove.
 
 mod hardcoded;
+mod incremental;
 mod replaceable;
 
 use crate::transliterate::provider::{FunctionCall, Rule, RuleULE, SimpleId, VarTable};
 use crate::transliterate::provider::{RuleBasedTransliterator, Segment, TransliteratorRulesV1};
 use crate::transliterate::transliterator::hardcoded::Case;
+use crate::transliterate::RuleCollectionProvider;
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::{String, ToString};
@@ -31,6 +33,8 @@ use zerofrom::ZeroFrom;
 use zerovec::vecs::Index32;
 use zerovec::VarZeroSlice;
 
+pub use incremental::{IncrementalTransliterator, TransliteratorWriter};
+
 type Filter<'a> = CodePointInversionList<'a>;
 
 // Thought: How about a RunTransliterator trait that is implemented for all internal types, is blanket
@@ -479,6 +483,34 @@ impl TransliteratorBuilder {
     }
 }
 
+/// Returns the ID of the inverse of the transliterator with the given ID. This swaps the language
+/// identifier with the `t` extension's language, and the `s0` (source) field with the `d0`
+/// (destination) field.
+fn inverse_id(id: &Locale) -> Locale {
+    use icu_locale_core::extensions::transform::key;
+
+    let mut inverse = id.clone();
+    let transform = &mut inverse.extensions.transform;
+    let target = core::mem::replace(
+        &mut inverse.id,
+        transform.lang.take().unwrap_or(LanguageIdentifier::UNKNOWN),
+    );
+    // An `und` target is implied by an absent `t` language
+    transform.lang = (target != LanguageIdentifier::UNKNOWN).then_some(target);
+    let source = transform.fields.get(&key!("s0")).cloned();
+    let destination = transform.fields.get(&key!("d0")).cloned();
+    transform
+        .fields
+        .retain_by_key(|k| *k != key!("s0") && *k != key!("d0"));
+    if let Some(destination) = destination {
+        transform.fields.set(key!("s0"), destination);
+    }
+    if let Some(source) = source {
+        transform.fields.set(key!("d0"), source);
+    }
+    inverse
+}
+
 impl Transliterator {
     /// Construct a [`Transliterator`] from the given [`Locale`].
     ///
@@ -542,6 +574,71 @@ impl Transliterator {
         )
     }
 
+    /// Construct the inverse of the [`Transliterator`] for the given [`Locale`].
+    ///
+    /// The inverse swaps the source and the target of the ID, e.g. it uses
+    /// `und-Latn-t-s0-ascii` for `und-t-und-Latn-d0-ascii`. This returns an error if there is no
+    /// data for the inverse, as not all transliterators can be reversed.
+    ///
+    /// # Examples
+    /// ```
+    /// use icu::experimental::transliterate::Transliterator;
+    /// // BCP-47-T ID for Any-Publishing, which replaces ASCII sequences by typographic characters
+    /// let locale = "und-t-d0-publish".parse().unwrap();
+    /// let t = Transliterator::try_new_inverse(&locale).unwrap();
+    /// let output = t.transliterate("a ≠ b… ©".to_string());
+    ///
+    /// assert_eq!(output, "a != b... (C)");
+    /// ```
+    #[cfg(feature = "compiled_data")]
+    pub fn try_new_inverse(locale: &Locale) -> Result<Self, DataError> {
+        Self::try_new_inverse_unstable(
+            &crate::provider::Baked,
+            &icu_normalizer::provider::Baked,
+            &icu_casemap::provider::Baked,
+            locale,
+        )
+    }
+
+    #[cfg(feature = "serde")]
+    #[doc = icu_provider::gen_buffer_unstable_docs!(BUFFER, Self::try_new_inverse)]
+    pub fn try_new_inverse_with_buffer_provider(
+        provider: &(impl BufferProvider + ?Sized),
+        locale: &Locale,
+    ) -> Result<Self, DataError> {
+        Self::try_new_inverse_unstable(
+            &provider.as_deserializing(),
+            &provider.as_deserializing(),
+            &provider.as_deserializing(),
+            locale,
+        )
+    }
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new_inverse)]
+    pub fn try_new_inverse_unstable<PT, PN, PC>(
+        transliterator_provider: &PT,
+        normalizer_provider: &PN,
+        casemap_provider: &PC,
+        locale: &Locale,
+    ) -> Result<Self, DataError>
+    where
+        PT: DataProvider<TransliteratorRulesV1> + ?Sized,
+        PC: DataProvider<CaseMapV1> + ?Sized,
+        PN: DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfkdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkdTablesV1>
+            + DataProvider<NormalizerNfcV1>
+            + ?Sized,
+    {
+        Self::try_new_unstable(
+            transliterator_provider,
+            normalizer_provider,
+            casemap_provider,
+            &inverse_id(locale),
+        )
+    }
+
     /// Construct a [`Transliterator`] from the given [`Locale`] using overrides provided
     /// by `lookup`.
     ///
@@ -644,6 +741,51 @@ impl Transliterator {
         )
     }
 
+    /// Construct a [`Transliterator`] from an ICU-style compound ID, such as
+    /// `"Any-Latin; NFD; [:Nonspacing Mark:] Remove; NFC"`, without registering it first.
+    ///
+    /// The elements of the ID refer to the aliases registered in the [`RuleCollection`] of the
+    /// `provider`. See [`RuleCollection::register_compound`] for the syntax, and for `reverse`.
+    ///
+    /// # Example
+    /// ```
+    /// use icu::experimental::transliterate::{RuleCollection, Transliterator};
+    ///
+    /// let collection = RuleCollection::default();
+    /// let t = Transliterator::try_new_from_compound_id(
+    ///     &collection.as_provider(),
+    ///     "NFD; [:Nonspacing Mark:] Remove; Lower; NFC",
+    ///     false,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(t.transliterate("Crème Brûlée".into()), "creme brulee");
+    /// ```
+    ///
+    /// [`RuleCollection`]: crate::transliterate::RuleCollection
+    /// [`RuleCollection::register_compound`]: crate::transliterate::RuleCollection::register_compound
+    pub fn try_new_from_compound_id<'a, PP, NP, NC>(
+        provider: &RuleCollectionProvider<'a, PP, NP, NC>,
+        compound_id: &str,
+        reverse: bool,
+    ) -> Result<Self, DataError>
+    where
+        PP: ?Sized,
+        NP: ?Sized,
+        NC: ?Sized,
+        RuleCollectionProvider<'a, PP, NP, NC>: DataProvider<TransliteratorRulesV1>
+            + DataProvider<CaseMapV1>
+            + DataProvider<NormalizerNfdDataV1>
+            + DataProvider<NormalizerNfkdDataV1>
+            + DataProvider<NormalizerNfdTablesV1>
+            + DataProvider<NormalizerNfkdTablesV1>
+            + DataProvider<NormalizerNfcV1>,
+    {
+        let id = provider.register_anonymous_compound(compound_id, reverse);
+        let result = Self::try_new_unstable(provider, provider, provider, &id);
+        provider.remove_anonymous_compound(&id);
+        result
+    }
+
     fn internal_try_new_with_override_unstable<PN, PT, PC, F>(
         locale: &Locale,
         lookup: Option<&F>,
@@ -811,6 +953,16 @@ impl Transliterator {
         self.transliterator.get().transliterate(rep, &self.env);
         buffer.into_string()
     }
+
+    /// Returns an [`IncrementalTransliterator`] that transliterates text fed to it in chunks.
+    pub fn incremental(&self) -> IncrementalTransliterator<'_> {
+        IncrementalTransliterator::new(self)
+    }
+
+    /// Returns a [`TransliteratorWriter`] that transliterates everything written to it into `sink`.
+    pub fn writer<W: core::fmt::Write>(&self, sink: W) -> TransliteratorWriter<'_, W> {
+        TransliteratorWriter::new(self, sink)
+    }
 }
 
 impl RuleBasedTransliterator<'_> {
@@ -1834,6 +1986,132 @@ mod tests {
         assert_eq!(t.transliterate(input.to_string()), output);
     }
 
+    #[test]
+    fn test_incremental() {
+        let mut collection = RuleCollection::default();
+        collection.register_source(
+            &"und-x-test".parse().unwrap(),
+            "a } b > x; b { c > y; ^ s > S; q } ' ' > Q; ü > ue;".into(),
+            [],
+            false,
+            true,
+        );
+        let t = Transliterator::try_new_unstable(
+            &collection.as_provider(),
+            &icu_normalizer::provider::Baked,
+            &icu_casemap::provider::Baked,
+            &"und-x-test".parse().unwrap(),
+        )
+        .unwrap();
+
+        let input = "sabc abcq bce süabe";
+        let output = "Sxby xbyQ bye suexbe";
+        assert_eq!(t.transliterate(input.to_string()), output);
+
+        for split in (0..=input.len()).filter(|&i| input.is_char_boundary(i)) {
+            let mut incremental = t.incremental();
+            let mut result = incremental.push(&input[..split]);
+            result += &incremental.push(&input[split..]);
+            result += &incremental.finish();
+            assert_eq!(result, output, "split at {split}");
+        }
+
+        let mut writer = t.writer(String::new());
+        for c in input.chars() {
+            core::fmt::Write::write_char(&mut writer, c).unwrap();
+        }
+        assert_eq!(writer.finish().unwrap(), output);
+
+        // Long words are committed up to the held back context
+        let mut incremental = t.incremental();
+        assert_eq!(incremental.push(&"ü".repeat(100)), "ue".repeat(68));
+        assert_eq!(incremental.pending(), "ü".repeat(32));
+        assert_eq!(incremental.finish(), "ue".repeat(32));
+    }
+
+    #[test]
+    fn test_inverse_id() {
+        for (forward, reverse) in [
+            ("und-t-und-latn-d0-ascii", "und-Latn-t-s0-ascii"),
+            ("und-t-d0-publish", "und-t-s0-publish"),
+            ("und-Arab-t-und-beng", "und-Beng-t-und-arab"),
+            ("el-Latn-t-el-m0-bgn", "el-t-el-latn-m0-bgn"),
+        ] {
+            let forward: Locale = forward.parse().unwrap();
+            let reverse: Locale = reverse.parse().unwrap();
+            assert_eq!(inverse_id(&forward), reverse, "{forward}");
+            assert_eq!(inverse_id(&reverse), forward, "{reverse}");
+        }
+    }
+
+    #[test]
+    fn test_compound_and_inverse_ids() {
+        let mut collection = RuleCollection::default();
+        collection.register_bidirectional_source(
+            &"und-t-und-x0-flat".parse().unwrap(),
+            &"und-t-und-x0-umlaut".parse().unwrap(),
+            "ä <> ae; ö <> oe; ü <> ue;".into(),
+            ["Umlaut-Flat"],
+            true,
+        );
+        collection.register_compound(
+            &"und-t-und-x0-chain".parse().unwrap(),
+            "[^q]; [a-zäöü] Flat-Umlaut; Upper",
+            [],
+            false,
+            true,
+        );
+        collection.register_compound(
+            &"und-t-und-x0-chain-rev".parse().unwrap(),
+            "[^q]; [a-zäöü] Flat-Umlaut; Upper",
+            [],
+            true,
+            true,
+        );
+        let provider = collection.as_provider();
+        let t = |id: &str| {
+            Transliterator::try_new_unstable(&provider, &provider, &provider, &id.parse().unwrap())
+                .unwrap()
+        };
+
+        assert_eq!(
+            t("und-t-und-x0-flat").transliterate("Märchen öl".into()),
+            "Maerchen oel"
+        );
+        assert_eq!(
+            t("und-t-und-x0-umlaut").transliterate("Maerchen oel".into()),
+            "Märchen öl"
+        );
+        // the global filter excludes 'q', the filter on Flat-Umlaut excludes 'OE'
+        assert_eq!(
+            t("und-t-und-x0-chain").transliterate("Maerchen OEl quo".into()),
+            "MÄRCHEN OEL qUO"
+        );
+        // the reverse is Lower, then Umlaut-Flat
+        assert_eq!(
+            t("und-t-und-x0-chain-rev").transliterate("MÄRCHEN Öl QUO".into()),
+            "maerchen oel quo"
+        );
+
+        // compound IDs can also be used without registering them
+        let compound = |reverse| {
+            Transliterator::try_new_from_compound_id(
+                &provider,
+                "[^q]; [a-zäöü] Flat-Umlaut; Upper",
+                reverse,
+            )
+            .unwrap()
+        };
+        assert_eq!(
+            compound(false).transliterate("Maerchen OEl quo".into()),
+            "MÄRCHEN OEL qUO"
+        );
+        assert_eq!(
+            compound(true).transliterate("MÄRCHEN Öl QUO".into()),
+            "maerchen oel quo"
+        );
+    }
+
     #[test]
     fn test_katakana_hiragana() {
         let t = Transliterator::try_new(&"und-Hira-t-und-kana".parse().unwrap()).unwrap();
@@ -176,6 +176,28 @@ impl<'a> Replaceable<'a> {
         unsafe { Replaceable::from_hide(Hide::new(&mut buf.0)) }
     }
 
+    /// Creates a `Replaceable` whose modifiable range leaves out the first `pre_len` and the
+    /// last `post_len` bytes of the buffer. These are still visible to rules as context.
+    ///
+    /// Panics if the modifiable range does not lie on UTF-8 boundaries.
+    pub(crate) fn with_context(
+        buf: &'a mut TransliteratorBuffer,
+        pre_len: usize,
+        post_len: usize,
+    ) -> Self {
+        let len = buf.0.len();
+        assert!(pre_len + post_len <= len);
+        // SAFETY: we have exclusive access to the buffer, so it must contain valid UTF-8
+        let mut rep = unsafe { Replaceable::from_hide(Hide::new(&mut buf.0)) };
+        assert!(rep.as_str().is_char_boundary(pre_len));
+        assert!(rep.as_str().is_char_boundary(len - post_len));
+        // SAFETY: we just checked that these are valid UTF-8 indices
+        rep.freeze_pre_len = pre_len;
+        rep.freeze_post_len = post_len;
+        rep.cursor = pre_len;
+        rep
+    }
+
     /// # Safety
     /// The caller must ensure the visible portion of `content` is valid UTF-8.
     unsafe fn from_hide(content: Hide<'a>) -> Self {
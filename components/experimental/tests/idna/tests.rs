@@ -0,0 +1,149 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_experimental::idna::{IdnaError, IdnaOptions, IdnaProcessor, IdnaResult};
+use std::collections::BTreeSet;
+
+/// Replaces the `\uXXXX` and `\x{XXXX}` escapes of the test file, in which `""` denotes
+/// the empty string.
+///
+/// Returns `None` for unpaired surrogates, which cannot be represented in a `String`.
+fn unescape(field: &str) -> Option<String> {
+    if field == "\"\"" {
+        return Some(String::new());
+    }
+    let mut result = String::new();
+    let mut rest = field;
+    while let Some(index) = rest.find('\\') {
+        result.push_str(&rest[..index]);
+        rest = &rest[index..];
+        let (hex, len) = if let Some(braced) = rest.strip_prefix("\\x{") {
+            let end = braced.find('}').unwrap();
+            (&braced[..end], end + 4)
+        } else if rest.starts_with("\\u") {
+            (&rest[2..6], 6)
+        } else {
+            result.push('\\');
+            rest = &rest[1..];
+            continue;
+        };
+        result.push(char::from_u32(u32::from_str_radix(hex, 16).unwrap())?);
+        rest = &rest[len..];
+    }
+    result.push_str(rest);
+    Some(result)
+}
+
+/// Parses a status column of the test file, leaving out the ignored codes.
+fn parse_status<'a>(status: &'a str, ignored: &[&str]) -> BTreeSet<&'a str> {
+    status
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(str::trim)
+        .filter(|code| !code.is_empty() && !ignored.contains(code))
+        .collect()
+}
+
+fn check(
+    result: &IdnaResult,
+    expected: &str,
+    expected_status: &BTreeSet<&str>,
+    operation: &str,
+    line: &str,
+) {
+    let status = result
+        .errors
+        .iter()
+        .map(IdnaError::code)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(&status, expected_status, "{operation}: {line}");
+    // The expected value is not specified if there are errors
+    if expected_status.is_empty() {
+        assert_eq!(result.domain, expected, "{operation}: {line}");
+    }
+}
+
+#[test]
+fn test_idna_conformance() {
+    let idna = IdnaProcessor::new();
+    let options = IdnaOptions::default();
+
+    let data = include_str!("data/IdnaTestV2.txt");
+    let mut count = 0;
+    for line in data.lines() {
+        let line = line.split_once('#').map_or(line, |(data, _)| data).trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+        let [source, to_unicode, to_unicode_status, to_ascii_n, to_ascii_n_status, ..] = fields[..]
+        else {
+            panic!("Invalid line: {line}");
+        };
+
+        let Some(source) = unescape(source) else {
+            // Strings with unpaired surrogates are not tested
+            continue;
+        };
+        // Blank values default to previous columns
+        let to_unicode = match to_unicode {
+            "" => source.clone(),
+            value => unescape(value).unwrap(),
+        };
+        let to_ascii_n = match to_ascii_n {
+            "" => to_unicode.clone(),
+            value => unescape(value).unwrap(),
+        };
+        let to_ascii_n_status = match to_ascii_n_status {
+            "" => to_unicode_status,
+            status => status,
+        };
+
+        check(
+            &idna.to_unicode(&source, options),
+            &to_unicode,
+            // X4_2 marks empty labels, which `ToUnicode` accepts
+            &parse_status(to_unicode_status, &["X4_2"]),
+            "toUnicode",
+            line,
+        );
+        check(
+            &idna.to_ascii(&source, options),
+            &to_ascii_n,
+            &parse_status(to_ascii_n_status, &[]),
+            "toAsciiN",
+            line,
+        );
+        count += 1;
+    }
+    assert!(count > 0);
+}
+
+#[test]
+fn test_context_o() {
+    let idna = IdnaProcessor::new();
+    let mut options = IdnaOptions::default();
+    options.check_context_o = true;
+
+    for (domain, valid) in [
+        ("l·l.example", true),
+        ("a·l.example", false),
+        ("͵α.example", true),
+        ("α͵.example", false),
+        ("א׳.example", true),
+        ("a׳.example", false),
+        ("カ・カ.example", true),
+        ("٠١.example", true),
+        ("٠۱.example", false),
+    ] {
+        let result = idna.to_ascii(domain, options);
+        assert_eq!(
+            !result.errors.contains(IdnaError::ContextO),
+            valid,
+            "{domain}: {:?}",
+            result.errors
+        );
+    }
+}
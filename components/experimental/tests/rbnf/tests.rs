@@ -0,0 +1,231 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use fixed_decimal::Decimal;
+use icu_experimental::rbnf::provider::{RbnfRules, RbnfRulesV1};
+use icu_experimental::rbnf::RbnfFormatter;
+use icu_locale_core::locale;
+use icu_provider::prelude::*;
+use writeable::assert_writeable_eq;
+
+/// Rules of the English locale and the root locale, in the syntax of ICU, and rules for
+/// Spanish that depend on the decimal separator.
+struct TestProvider;
+
+const ES_RULES: &str = "
+%spellout-numbering:
+    x.x: << punto >>;
+    x,x: << coma >%%fraction>;
+    0: cero; 1: uno; 2: dos; 3: tres; 4: cuatro; 5: cinco;
+    6: seis; 7: siete; 8: ocho; 9: nueve; 10: =#,##0=;
+%%fraction:
+    10: <%spellout-numbering<<;
+    100: <%spellout-numbering<<;
+";
+
+impl DataProvider<RbnfRulesV1> for TestProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<RbnfRulesV1>, DataError> {
+        let rules = match req.id.locale.language.as_str() {
+            "en" => include_str!("data/en.txt"),
+            "es" => ES_RULES,
+            "und" => include_str!("data/root.txt"),
+            "fr" => return Err(DataError::custom("Unreadable RBNF rules")),
+            _ => return Err(DataErrorKind::IdentifierNotFound.with_req(RbnfRulesV1::INFO, req)),
+        };
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(RbnfRules::try_from_str(rules).unwrap()),
+        })
+    }
+}
+
+macro_rules! forward_to_baked {
+    ($($marker:ty => $baked:path,)*) => {
+        $(
+            impl DataProvider<$marker> for TestProvider {
+                fn load(&self, req: DataRequest) -> Result<DataResponse<$marker>, DataError> {
+                    $baked.load(req)
+                }
+            }
+        )*
+    };
+}
+
+forward_to_baked!(
+    icu::decimal::provider::DecimalSymbolsV1 => icu::decimal::provider::Baked,
+    icu::decimal::provider::DecimalDigitsV1 => icu::decimal::provider::Baked,
+    icu::plurals::provider::PluralsCardinalV1 => icu::plurals::provider::Baked,
+    icu::plurals::provider::PluralsOrdinalV1 => icu::plurals::provider::Baked,
+);
+
+fn check(rule_set: &str, cases: &[(&str, &str)]) {
+    let formatter =
+        RbnfFormatter::try_new_unstable(&TestProvider, locale!("en").into(), rule_set).unwrap();
+    for (input, expected) in cases {
+        let decimal = input.parse::<Decimal>().unwrap();
+        assert_writeable_eq!(formatter.format(&decimal), *expected, "{rule_set} {input}");
+    }
+}
+
+// The expected values are the output of ICU4C
+
+#[test]
+fn test_spellout_cardinal() {
+    check(
+        "%spellout-cardinal",
+        &[
+            ("0", "zero"),
+            ("7", "seven"),
+            ("20", "twenty"),
+            ("21", "twenty-one"),
+            ("100", "one hundred"),
+            ("123", "one hundred twenty-three"),
+            ("1000", "one thousand"),
+            ("1001", "one thousand one"),
+            ("2025", "two thousand twenty-five"),
+            ("1000000", "one million"),
+            ("-45", "minus forty-five"),
+            ("1.5", "one point five"),
+            ("0.25", "zero point two five"),
+            ("3.0", "three"),
+            ("123456789", "one hundred twenty-three million four hundred fifty-six thousand seven hundred eighty-nine"),
+            ("1000000000000000000", "1,000,000,000,000,000,000"),
+        ],
+    );
+}
+
+#[test]
+fn test_spellout_variants() {
+    check(
+        "%spellout-cardinal-verbose",
+        &[
+            ("101", "one hundred and one"),
+            ("2025", "two thousand and twenty-five"),
+            (
+                "123456",
+                "one hundred and twenty-three thousand, four hundred and fifty-six",
+            ),
+        ],
+    );
+    check(
+        "%spellout-numbering-year",
+        &[
+            ("1999", "nineteen ninety-nine"),
+            ("2000", "two thousand"),
+            ("2005", "two thousand five"),
+            ("2010", "twenty ten"),
+            ("1905", "nineteen oh-five"),
+            ("1900", "nineteen hundred"),
+        ],
+    );
+}
+
+#[test]
+fn test_spellout_ordinal() {
+    check(
+        "%spellout-ordinal",
+        &[
+            ("0", "zeroth"),
+            ("1", "first"),
+            ("12", "twelfth"),
+            ("13", "thirteenth"),
+            ("20", "twentieth"),
+            ("23", "twenty-third"),
+            ("100", "one hundredth"),
+            ("101", "one hundred first"),
+            ("1000", "one thousandth"),
+            ("-2", "minus second"),
+        ],
+    );
+}
+
+#[test]
+fn test_digits_ordinal() {
+    check(
+        "%digits-ordinal",
+        &[
+            ("1", "1st"),
+            ("2", "2nd"),
+            ("3", "3rd"),
+            ("4", "4th"),
+            ("11", "11th"),
+            ("22", "22nd"),
+            ("113", "113th"),
+            ("1001", "1,001st"),
+            ("-3", "−3rd"),
+        ],
+    );
+}
+
+#[test]
+fn test_numbering_systems() {
+    check(
+        "%roman-upper",
+        &[
+            ("1", "I"),
+            ("4", "IV"),
+            ("9", "IX"),
+            ("14", "XIV"),
+            ("1984", "MCMLXXXIV"),
+            ("2025", "MMXXV"),
+        ],
+    );
+    check("%roman-lower", &[("49", "xlix"), ("3999", "mmmcmxcix")]);
+    check(
+        "%hebrew",
+        &[
+            ("1", "א׳"),
+            ("15", "ט״ו"),
+            ("16", "ט״ז"),
+            ("123", "קכ״ג"),
+            ("5785", "ה׳תשפ״ה"),
+        ],
+    );
+    check(
+        "%armenian-lower",
+        &[("1", "ա"), ("12", "ժբ"), ("1999", "ռջղթ")],
+    );
+}
+
+#[test]
+fn test_unknown_rule_set() {
+    for rule_set in ["%spellout-unknown", "%%and", "spellout-cardinal"] {
+        assert!(
+            RbnfFormatter::try_new_unstable(&TestProvider, locale!("en").into(), rule_set).is_err(),
+            "{rule_set}"
+        );
+    }
+}
+
+#[test]
+fn test_data_errors() {
+    // Locales without rules use the rule sets of the root locale
+    let formatter =
+        RbnfFormatter::try_new_unstable(&TestProvider, locale!("de").into(), "%roman-upper")
+            .unwrap();
+    let decimal = Decimal::from(12);
+    assert_writeable_eq!(formatter.format(&decimal), "XII");
+
+    // Other errors are not hidden by the root locale
+    let error =
+        RbnfFormatter::try_new_unstable(&TestProvider, locale!("fr").into(), "%roman-upper")
+            .unwrap_err();
+    assert_eq!(error.kind, DataErrorKind::Custom);
+    assert_eq!(error.str_context, Some("Unreadable RBNF rules"));
+}
+
+#[test]
+fn test_decimal_separator() {
+    let formatter =
+        RbnfFormatter::try_new_unstable(&TestProvider, locale!("es").into(), "%spellout-numbering")
+            .unwrap();
+    for (input, expected) in [
+        ("3.5", "tres coma cinco"),
+        ("0.07", "cero coma cero siete"),
+        ("1.25", "uno coma 25"),
+    ] {
+        let decimal = input.parse::<Decimal>().unwrap();
+        assert_writeable_eq!(formatter.format(&decimal), expected, "{input}");
+    }
+}
@@ -92,6 +92,8 @@ mod directionality;
 pub mod exemplar_chars;
 mod expander;
 pub mod fallback;
+#[cfg(feature = "experimental")]
+pub mod matcher;
 pub mod provider;
 
 pub use icu_locale_core::*;
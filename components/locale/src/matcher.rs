@@ -0,0 +1,398 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Tools for finding the best supported locale for a list of desired locales.
+//!
+//! ✨ *Enabled with the `experimental` Cargo feature.*
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+//!
+//! See [`LocaleMatcher`] for more details.
+
+use crate::provider::matching::*;
+use crate::provider::*;
+use crate::LocaleExpander;
+use alloc::vec::Vec;
+use icu_locale_core::subtags::{Language, Region, Script};
+use icu_locale_core::LanguageIdentifier;
+use icu_provider::prelude::*;
+use zerovec::ZeroSlice;
+
+/// The default value of [`LocaleMatcherOptions::threshold`].
+const DEFAULT_THRESHOLD: u16 = 50;
+
+/// The distance of subtags that are not matched by any rule in the data.
+const UNMATCHED_DISTANCE: u16 = 100;
+
+/// Options for [`LocaleMatcher`].
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct LocaleMatcherOptions {
+    /// The distance at which a supported locale no longer matches a desired locale.
+    ///
+    /// Default is 50, which matches locales that differ in region, or in script for
+    /// some languages, such as `zh-Hant` and `zh-Hans`, but not locales with unrelated
+    /// languages.
+    pub threshold: Option<u16>,
+    /// The locale that is returned if no supported locale matches.
+    ///
+    /// Default is `None`.
+    pub default_locale: Option<LanguageIdentifier>,
+}
+
+/// The result of [`LocaleMatcher::best_match`].
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LocaleMatch<'a> {
+    /// The best supported locale, or the default locale if no supported locale matches.
+    pub locale: &'a LanguageIdentifier,
+    /// The index of [`Self::locale`] in the supported locales, or `None` if it is the
+    /// default locale.
+    pub supported_index: Option<usize>,
+    /// The index of the desired locale that [`Self::locale`] matches, or `None` if it is
+    /// the default locale.
+    pub desired_index: Option<usize>,
+    /// The distance between the desired and the supported locale, or `None` if
+    /// [`Self::locale`] is the default locale.
+    pub distance: Option<u16>,
+}
+
+/// The language, script and region of a maximized locale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Lsr {
+    language: Language,
+    script: Option<Script>,
+    region: Option<Region>,
+}
+
+#[derive(Debug)]
+struct SupportedLocale {
+    locale: LanguageIdentifier,
+    lsr: Lsr,
+    is_paradigm: bool,
+}
+
+/// A struct that finds the supported locale that best matches a list of desired locales,
+/// such as the languages in an HTTP `Accept-Language` header.
+///
+/// Locales are compared by the distance between their maximized language, script and
+/// region subtags, using the likely subtags and the
+/// [`languageMatching`](https://www.unicode.org/reports/tr35/#EnhancedLanguageMatching)
+/// rules from CLDR. For example, `en-GB` is closer to `en-AU` than to `en-US`, and `nb` is
+/// close to `no`. Variants and extensions are ignored.
+///
+/// Earlier desired locales are preferred over later ones: each position in the list of
+/// desired locales adds the distance between two regions of the same language to the
+/// distance that is used for the comparison. Supported locales are preferred in the given
+/// order if their distances are equal, except that a supported locale that is equal to the
+/// desired locale always wins.
+///
+/// The data for this type is generated from CLDR (the `LocaleLanguageMatchingV1` marker
+/// in datagen).
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+///
+/// # Examples
+///
+/// ```
+/// use icu::locale::langid;
+/// use icu::locale::matcher::{LocaleMatcher, LocaleMatcherOptions};
+///
+/// let matcher = LocaleMatcher::try_new(
+///     [langid!("en"), langid!("en-GB"), langid!("fr"), langid!("zh-Hant")],
+///     Default::default(),
+/// )
+/// .unwrap();
+///
+/// // en-AU is closer to en-GB than to en (en-US)
+/// let result = matcher.best_match(&[langid!("en-AU")]).unwrap();
+/// assert_eq!(result.locale, &langid!("en-GB"));
+///
+/// // Traditional Chinese is the best match for Simplified Chinese
+/// let result = matcher.best_match(&[langid!("zh-CN")]).unwrap();
+/// assert_eq!(result.locale, &langid!("zh-Hant"));
+///
+/// // German is not supported, so the second desired locale is used
+/// let result = matcher.best_match(&[langid!("de"), langid!("fr-CH")]).unwrap();
+/// assert_eq!(result.locale, &langid!("fr"));
+/// assert_eq!(result.desired_index, Some(1));
+///
+/// // Without a default locale, there is no result if nothing matches
+/// assert_eq!(matcher.best_match(&[langid!("ja")]), None);
+///
+/// let mut options = LocaleMatcherOptions::default();
+/// options.default_locale = Some(langid!("en"));
+/// let matcher = LocaleMatcher::try_new([langid!("fr")], options).unwrap();
+///
+/// let result = matcher.best_match(&[langid!("ja")]).unwrap();
+/// assert_eq!(result.locale, &langid!("en"));
+/// assert_eq!(result.supported_index, None);
+/// ```
+#[derive(Debug)]
+pub struct LocaleMatcher {
+    data: DataPayload<LocaleLanguageMatchingV1>,
+    expander: LocaleExpander,
+    supported: Vec<SupportedLocale>,
+    paradigm_locales: Vec<Lsr>,
+    threshold: u16,
+    demotion_per_desired_locale: u16,
+    default_locale: Option<LanguageIdentifier>,
+}
+
+impl LocaleMatcher {
+    icu_provider::gen_buffer_data_constructors!(
+        (
+            supported: impl IntoIterator<Item = LanguageIdentifier>,
+            options: LocaleMatcherOptions
+        ) -> error: DataError,
+        /// Creates a new [`LocaleMatcher`] for the given supported locales.
+        functions: [
+            try_new,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::try_new)]
+    pub fn try_new_unstable<P>(
+        provider: &P,
+        supported: impl IntoIterator<Item = LanguageIdentifier>,
+        options: LocaleMatcherOptions,
+    ) -> Result<Self, DataError>
+    where
+        P: DataProvider<LocaleLanguageMatchingV1>
+            + DataProvider<LocaleLikelySubtagsLanguageV1>
+            + DataProvider<LocaleLikelySubtagsScriptRegionV1>
+            + DataProvider<LocaleLikelySubtagsExtendedV1>
+            + ?Sized,
+    {
+        let expander = LocaleExpander::try_new_extended_unstable(provider)?;
+        let data: DataPayload<LocaleLanguageMatchingV1> =
+            provider.load(Default::default())?.payload;
+
+        let paradigm_locales = data
+            .get()
+            .paradigm_locales
+            .iter()
+            .map(|(language, script, region)| {
+                maximize(
+                    &expander,
+                    &LanguageIdentifier {
+                        language,
+                        script,
+                        region,
+                        variants: Default::default(),
+                    },
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // The distance between two regions of the same language
+        let demotion_per_desired_locale = data
+            .get()
+            .regions
+            .iter()
+            .find(|rule| {
+                rule.desired == LanguageMatchPattern::default()
+                    && rule.supported == LanguageMatchPattern::default()
+            })
+            .map(|rule| u16::from(rule.distance))
+            .unwrap_or_default();
+
+        let supported = supported
+            .into_iter()
+            .map(|locale| {
+                let lsr = maximize(&expander, &locale);
+                SupportedLocale {
+                    locale,
+                    lsr,
+                    is_paradigm: paradigm_locales.contains(&lsr),
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            data,
+            expander,
+            supported,
+            paradigm_locales,
+            threshold: options.threshold.unwrap_or(DEFAULT_THRESHOLD),
+            demotion_per_desired_locale,
+            default_locale: options.default_locale,
+        })
+    }
+
+    /// Returns the supported locale that best matches the desired locales, which are
+    /// given in order of preference.
+    ///
+    /// Returns the default locale if no supported locale is closer to a desired locale
+    /// than the threshold, or `None` if there is no default locale.
+    pub fn best_match<'a>(
+        &self,
+        desired: impl IntoIterator<Item = &'a LanguageIdentifier>,
+    ) -> Option<LocaleMatch<'_>> {
+        // (score, exact, desired index, supported index, distance)
+        let mut best: Option<(u16, bool, usize, usize, u16)> = None;
+        for (desired_index, desired) in desired.into_iter().enumerate() {
+            let demotion = u16::try_from(desired_index)
+                .unwrap_or(u16::MAX)
+                .saturating_mul(self.demotion_per_desired_locale);
+            if best.is_some_and(|(score, ..)| score < demotion) {
+                // Later desired locales cannot be better
+                break;
+            }
+            let desired_lsr = maximize(&self.expander, desired);
+            for (supported_index, supported) in self.supported.iter().enumerate() {
+                let distance = self.distance_lsr(desired_lsr, supported.lsr, supported.is_paradigm);
+                if distance >= self.threshold {
+                    continue;
+                }
+                let score = distance.saturating_add(demotion);
+                let exact = supported.locale == *desired;
+                if best.is_none_or(|(best_score, best_exact, ..)| {
+                    (score, !exact) < (best_score, !best_exact)
+                }) {
+                    best = Some((score, exact, desired_index, supported_index, distance));
+                }
+            }
+        }
+
+        match best {
+            Some((_, _, desired_index, supported_index, distance)) => Some(LocaleMatch {
+                locale: &self.supported.get(supported_index)?.locale,
+                supported_index: Some(supported_index),
+                desired_index: Some(desired_index),
+                distance: Some(distance),
+            }),
+            None => self.default_locale.as_ref().map(|locale| LocaleMatch {
+                locale,
+                supported_index: None,
+                desired_index: None,
+                distance: None,
+            }),
+        }
+    }
+
+    /// Returns the distance between a desired and a supported locale.
+    ///
+    /// A distance of 0 means that the maximized locales are equal. Locales with the same
+    /// language typically have a distance below 10, while unrelated languages have a
+    /// distance of 80 or more.
+    pub fn distance(&self, desired: &LanguageIdentifier, supported: &LanguageIdentifier) -> u16 {
+        let supported = maximize(&self.expander, supported);
+        self.distance_lsr(
+            maximize(&self.expander, desired),
+            supported,
+            self.paradigm_locales.contains(&supported),
+        )
+    }
+
+    fn distance_lsr(&self, desired: Lsr, supported: Lsr, is_paradigm: bool) -> u16 {
+        let mut distance = 0u16;
+        if desired.language != supported.language {
+            distance = distance.saturating_add(self.rule_distance(
+                &self.data.get().languages,
+                desired,
+                supported,
+            ));
+        }
+        if desired.script != supported.script {
+            distance = distance.saturating_add(self.rule_distance(
+                &self.data.get().scripts,
+                desired,
+                supported,
+            ));
+        }
+        if desired.region != supported.region {
+            let region_distance = self.rule_distance(&self.data.get().regions, desired, supported);
+            // Paradigm locales are preferred over other locales in the same partition
+            let region_distance = if is_paradigm {
+                region_distance.saturating_sub(1)
+            } else {
+                region_distance
+            };
+            distance = distance.saturating_add(region_distance);
+        }
+        distance
+    }
+
+    /// Returns the distance of the first of the rules that matches the locales.
+    fn rule_distance(
+        &self,
+        rules: &ZeroSlice<LanguageMatchRule>,
+        desired: Lsr,
+        supported: Lsr,
+    ) -> u16 {
+        let data = self.data.get();
+        rules
+            .iter()
+            .find(|rule| {
+                (matches(data, rule.desired, desired) && matches(data, rule.supported, supported))
+                    || (!rule.oneway
+                        && matches(data, rule.desired, supported)
+                        && matches(data, rule.supported, desired))
+            })
+            .map(|rule| u16::from(rule.distance))
+            .unwrap_or(UNMATCHED_DISTANCE)
+    }
+}
+
+fn maximize(expander: &LocaleExpander, locale: &LanguageIdentifier) -> Lsr {
+    let mut maximized = LanguageIdentifier {
+        language: locale.language,
+        script: locale.script,
+        region: locale.region,
+        variants: Default::default(),
+    };
+    expander.maximize(&mut maximized);
+    Lsr {
+        language: maximized.language,
+        script: maximized.script,
+        region: maximized.region,
+    }
+}
+
+/// Returns whether a pattern such as `en_*_$!enUS` matches a locale.
+fn matches(data: &LanguageMatchingData<'_>, pattern: LanguageMatchPattern, lsr: Lsr) -> bool {
+    pattern
+        .language
+        .is_none_or(|language| language == lsr.language)
+        && pattern
+            .script
+            .is_none_or(|script| Some(script) == lsr.script)
+        && pattern
+            .region
+            .is_none_or(|region| Some(region) == lsr.region)
+        && pattern.variable.is_none_or(|variable| {
+            let contained = lsr.region.is_some_and(|region| {
+                data.variables
+                    .get(variable.into())
+                    .is_some_and(|regions| regions.iter().any(|r| r == region))
+            });
+            contained != pattern.negated
+        })
+}
@@ -15,6 +15,9 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+#[cfg(feature = "experimental")]
+pub mod matching;
+
 #[cfg(feature = "compiled_data")]
 #[derive(Debug)]
 /// Baked data
@@ -47,6 +50,9 @@ const _: () = {
     impl_locale_exemplar_characters_main_v1!(Baked);
     impl_locale_exemplar_characters_numbers_v1!(Baked);
     impl_locale_exemplar_characters_punctuation_v1!(Baked);
+
+    #[cfg(feature = "experimental")]
+    impl_locale_language_matching_v1!(Baked);
 };
 
 icu_provider::data_marker!(
@@ -138,6 +144,8 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     LocaleLikelySubtagsScriptRegionV1::INFO,
     LocaleParentsV1::INFO,
     LocaleScriptDirectionV1::INFO,
+    #[cfg(feature = "experimental")]
+    matching::LocaleLanguageMatchingV1::INFO,
 ];
 
 use alloc::borrow::Cow;
@@ -0,0 +1,125 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Unstable\] Language matching data for this component
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. While the serde representation of data structs is guaranteed
+//! to be stable, their Rust representation might not be. Use with caution.
+//! </div>
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_locale_core::subtags::{Language, Region, Script};
+use icu_provider::prelude::*;
+use zerovec::{VarZeroVec, ZeroSlice, ZeroVec};
+
+icu_provider::data_marker!(
+    /// Marker for language matching data.
+    LocaleLanguageMatchingV1,
+    "locale/language/matching/v1",
+    LanguageMatchingData<'static>,
+    is_singleton = true
+);
+
+/// The distances between languages, scripts and regions, from the CLDR
+/// [`languageMatching`](https://www.unicode.org/reports/tr35/#EnhancedLanguageMatching) data.
+///
+/// The rules are stored in the CLDR order, which is also the order in which they are
+/// evaluated. CLDR rules with one (language), two (script) or three (region) subtags,
+/// such as `nb`, `zh_Hant` or `en_*_$!enUS`, are stored in [`Self::languages`],
+/// [`Self::scripts`] and [`Self::regions`] respectively.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider::matching))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct LanguageMatchingData<'data> {
+    /// The rules that apply to locales with different languages.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub languages: ZeroVec<'data, LanguageMatchRule>,
+    /// The rules that apply to locales with different scripts.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub scripts: ZeroVec<'data, LanguageMatchRule>,
+    /// The rules that apply to locales with different regions.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub regions: ZeroVec<'data, LanguageMatchRule>,
+    /// The regions in each match variable, indexed by [`LanguageMatchPattern::variable`].
+    ///
+    /// Macroregions are listed together with the regions they contain.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub variables: VarZeroVec<'data, ZeroSlice<Region>>,
+    /// The language, script and region of the paradigm locales, which are preferred over
+    /// other supported locales in the same region partition.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub paradigm_locales: ZeroVec<'data, (Language, Option<Script>, Option<Region>)>,
+}
+
+/// A rule in [`LanguageMatchingData`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(LanguageMatchRuleULE)]
+#[zerovec::derive(Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider::matching))]
+pub struct LanguageMatchRule {
+    /// The pattern for the desired locale.
+    pub desired: LanguageMatchPattern,
+    /// The pattern for the supported locale.
+    pub supported: LanguageMatchPattern,
+    /// The distance between locales that match the rule.
+    pub distance: u8,
+    /// Whether the rule only applies in the given direction.
+    ///
+    /// Other rules also apply with the desired and supported patterns swapped.
+    pub oneway: bool,
+}
+
+/// A pattern of a [`LanguageMatchRule`], such as `en_*_$!enUS`.
+///
+/// `None` subtags match any subtag, like `*` in CLDR.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(LanguageMatchPatternULE)]
+#[zerovec::derive(Debug)]
+#[derive(Copy, Clone, PartialOrd, Ord, PartialEq, Eq, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_locale::provider::matching))]
+pub struct LanguageMatchPattern {
+    /// The language.
+    pub language: Option<Language>,
+    /// The script.
+    pub script: Option<Script>,
+    /// The region.
+    pub region: Option<Region>,
+    /// The index of the match variable in [`LanguageMatchingData::variables`] that the
+    /// region has to be in, like `$name` in CLDR.
+    pub variable: Option<u8>,
+    /// Whether the region has to be outside of [`Self::variable`] instead, like `$!name`
+    /// in CLDR.
+    pub negated: bool,
+}
+
+icu_provider::data_struct!(
+    LanguageMatchingData<'_>,
+    #[cfg(feature = "datagen")]
+);
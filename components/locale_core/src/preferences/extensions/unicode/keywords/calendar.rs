@@ -33,6 +33,10 @@ enum_keyword!(
     ///
     /// The valid values are listed in [LDML](https://unicode.org/reports/tr35/#UnicodeCalendarIdentifier).
     CalendarAlgorithm {
+        /// Bikram Sambat calendar, the official calendar of Nepal
+        ///
+        /// This is not a CLDR calendar identifier.
+        ("bikram" => Bikram),
         /// Thai Buddhist calendar (same as Gregorian except for the year)
         ("buddhist" => Buddhist),
         /// Traditional Chinese calendar
@@ -65,5 +69,9 @@ enum_keyword!(
         /// Persian calendar
         ("persian" => Persian),
         /// Republic of China calendar
-        ("roc" => Roc)
+        ("roc" => Roc),
+        /// Traditional Vietnamese calendar
+        ///
+        /// This is not a CLDR calendar identifier.
+        ("vietnam" => Vietnam)
 }, "ca", s, if *s == value!("islamicc") { return Ok(Self::Hijri(Some(HijriCalendarAlgorithm::Civil))); });
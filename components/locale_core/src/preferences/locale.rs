@@ -99,6 +99,19 @@ impl From<&crate::LanguageIdentifier> for LocalePreferences {
     }
 }
 
+impl From<DataLocale> for LocalePreferences {
+    fn from(locale: DataLocale) -> Self {
+        Self {
+            language: locale.language,
+            script: locale.script,
+            region: locale.region,
+            variant: locale.variant,
+            subdivision: locale.subdivision,
+            ue_region: None,
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl From<LocalePreferences> for crate::Locale {
     fn from(prefs: LocalePreferences) -> Self {
@@ -266,7 +266,11 @@ define_preferences!(
 /// [`Plural Type`]: PluralRuleType
 /// [`Plural Category`]: PluralCategory
 #[derive(Debug)]
-pub struct PluralRules(DataPayload<ErasedMarker<PluralRulesData<'static>>>);
+pub struct PluralRules(
+    DataPayload<ErasedMarker<PluralRulesData<'static>>>,
+    /// The locale of the loaded data
+    DataLocale,
+);
 
 impl AsRef<PluralRules> for PluralRules {
     fn as_ref(&self) -> &PluralRules {
@@ -345,14 +349,13 @@ impl PluralRules {
         prefs: PluralRulesPreferences,
     ) -> Result<Self, DataError> {
         let locale = PluralsCardinalV1::make_locale(prefs.locale_preferences);
+        let response = provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&locale),
+            ..Default::default()
+        })?;
         Ok(Self(
-            provider
-                .load(DataRequest {
-                    id: DataIdentifierBorrowed::for_locale(&locale),
-                    ..Default::default()
-                })?
-                .payload
-                .cast(),
+            response.payload.cast(),
+            response.metadata.locale.unwrap_or(locale),
         ))
     }
 
@@ -401,14 +404,13 @@ impl PluralRules {
         prefs: PluralRulesPreferences,
     ) -> Result<Self, DataError> {
         let locale = PluralsOrdinalV1::make_locale(prefs.locale_preferences);
+        let response = provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&locale),
+            ..Default::default()
+        })?;
         Ok(Self(
-            provider
-                .load(DataRequest {
-                    id: DataIdentifierBorrowed::for_locale(&locale),
-                    ..Default::default()
-                })?
-                .payload
-                .cast(),
+            response.payload.cast(),
+            response.metadata.locale.unwrap_or(locale),
         ))
     }
 
@@ -521,6 +523,36 @@ impl PluralRules {
             .chain(test_rule!(many, Many))
             .chain(Some(PluralCategory::Other))
     }
+
+    /// Returns the preferences that this [`PluralRules`] resolved to.
+    ///
+    /// The locale is the locale of the plural rules data that was loaded, after locale
+    /// fallback, as reported by the data provider in [`DataResponseMetadata::locale`], or
+    /// the requested locale if the provider does not report it. Since data providers
+    /// deduplicate data that is inherited from a parent locale, this is the least specific
+    /// locale with the same data, which might be `und`.
+    ///
+    /// [`DataResponseMetadata::locale`]: icu_provider::DataResponseMetadata::locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::{locale, Locale};
+    /// use icu::plurals::PluralRules;
+    ///
+    /// let pr = PluralRules::try_new(locale!("fr-CA").into(), Default::default())
+    ///     .expect("locale should be present");
+    ///
+    /// assert_eq!(
+    ///     Locale::from(pr.resolved_preferences().locale_preferences),
+    ///     locale!("fr")
+    /// );
+    /// ```
+    pub fn resolved_preferences(&self) -> PluralRulesPreferences {
+        PluralRulesPreferences {
+            locale_preferences: self.1.into(),
+        }
+    }
 }
 
 /// A [`PluralRules`] that also has the ability to retrieve an appropriate [`Plural Category`] for a
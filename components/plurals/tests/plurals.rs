@@ -2,8 +2,10 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
-use icu_locale_core::{langid, locale};
-use icu_plurals::{provider::PluralsCardinalV1, PluralCategory, PluralRules};
+use icu_locale_core::{langid, locale, Locale};
+use icu_plurals::{
+    provider::PluralsCardinalV1, PluralCategory, PluralRuleType, PluralRules, PluralRulesOptions,
+};
 use icu_provider::prelude::*;
 
 #[test]
@@ -16,6 +18,30 @@ fn test_plural_rules() {
     );
 }
 
+#[test]
+fn test_resolved_preferences() {
+    for (locale, rule_type, expected) in [
+        (locale!("en"), PluralRuleType::Cardinal, locale!("en")),
+        (locale!("en-GB"), PluralRuleType::Cardinal, locale!("en")),
+        (locale!("en-GB"), PluralRuleType::Ordinal, locale!("en")),
+        (locale!("fr-CA"), PluralRuleType::Cardinal, locale!("fr")),
+        (locale!("pt-PT"), PluralRuleType::Cardinal, locale!("pt-PT")),
+        // Japanese has the same rules as root
+        (locale!("ja"), PluralRuleType::Cardinal, locale!("und")),
+    ] {
+        let rules = PluralRules::try_new(
+            (&locale).into(),
+            PluralRulesOptions::default().with_type(rule_type),
+        )
+        .unwrap();
+        assert_eq!(
+            Locale::from(rules.resolved_preferences().locale_preferences),
+            expected,
+            "{locale} {rule_type:?}"
+        );
+    }
+}
+
 #[test]
 fn test_static_load_works() {
     DataProvider::<PluralsCardinalV1>::load(
@@ -2,6 +2,50 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+//! Bidirectional text.
+//!
+//! This module contains the [`BidiMirroringGlyph`] and [`BidiPairedBracketType`] properties,
+//! and (with the `alloc` Cargo feature) an implementation of the
+//! [Unicode Bidirectional Algorithm (UAX #9)](https://www.unicode.org/reports/tr9/) in
+//! [`BidiResolver`], which resolves embedding levels and paragraph directions of UTF-8
+//! and UTF-16 text, and reorders lines into visual order.
+//!
+//! ✨ *Enabled with the `experimental` Cargo feature.*
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+//!
+//! # Examples
+//!
+//! ```
+//! use icu::properties::bidi::{BaseDirection, BidiLevel, BidiResolver};
+//!
+//! let bidi = BidiResolver::new();
+//!
+//! // "abc ABC" where "ABC" is Hebrew
+//! let text = "abc \u{5D0}\u{5D1}\u{5D2}";
+//! let info = bidi.resolve_str(text, BaseDirection::Auto);
+//!
+//! assert_eq!(info.paragraphs().len(), 1);
+//! assert_eq!(info.paragraphs()[0].level(), BidiLevel::LTR);
+//! assert_eq!(
+//!     bidi.reorder_str(&info, text, 0..text.len()),
+//!     "abc \u{5D2}\u{5D1}\u{5D0}"
+//! );
+//!
+//! // In right-to-left text, brackets are mirrored when reordering.
+//! let text = "\u{5D0}(\u{5D1})\u{5D2}";
+//! let info = bidi.resolve_str(text, BaseDirection::Auto);
+//!
+//! assert_eq!(info.paragraphs()[0].level(), BidiLevel::RTL);
+//! assert_eq!(
+//!     bidi.reorder_str(&info, text, 0..text.len()),
+//!     "\u{5D2}(\u{5D1})\u{5D0}"
+//! );
+//! ```
+
 use crate::{props::EnumeratedProperty, provider::PropertyEnumBidiMirroringGlyphV1};
 use icu_collections::codepointtrie::TrieValue;
 use zerovec::ule::{AsULE, RawBytesULE};
@@ -151,3 +195,521 @@ impl unicode_bidi::data_source::BidiDataSource
         }
     }
 }
+
+#[cfg(all(feature = "alloc", feature = "experimental"))]
+mod algorithm;
+
+#[cfg(all(feature = "alloc", feature = "experimental"))]
+pub use resolver::*;
+
+#[cfg(all(feature = "alloc", feature = "experimental"))]
+mod resolver {
+    use super::algorithm::{self, Bracket};
+    use super::{BidiMirroringGlyph, BidiPairedBracketType};
+    use crate::props::BidiClass;
+    use crate::provider::{PropertyEnumBidiClassV1, PropertyEnumBidiMirroringGlyphV1};
+    use crate::{CodePointMapData, CodePointMapDataBorrowed};
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::ops::Range;
+    use icu_provider::prelude::*;
+
+    /// An embedding level (BD2) of the Unicode Bidirectional Algorithm.
+    ///
+    /// Even levels are left-to-right, odd levels are right-to-left.
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+    pub struct BidiLevel(u8);
+
+    impl BidiLevel {
+        /// The level of left-to-right paragraphs.
+        pub const LTR: Self = Self(0);
+        /// The level of right-to-left paragraphs.
+        pub const RTL: Self = Self(1);
+        /// The highest level that text can be resolved to.
+        pub const MAX: Self = Self(algorithm::MAX_DEPTH + 1);
+
+        /// Returns the level with the given number, if it is not higher than [`Self::MAX`].
+        pub const fn new(level: u8) -> Option<Self> {
+            if level <= Self::MAX.0 {
+                Some(Self(level))
+            } else {
+                None
+            }
+        }
+
+        /// Returns the number of this level.
+        pub const fn get(self) -> u8 {
+            self.0
+        }
+
+        /// Whether this is a right-to-left level.
+        pub const fn is_rtl(self) -> bool {
+            self.0 % 2 == 1
+        }
+    }
+
+    /// The direction of a paragraph, or how to determine it.
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+    #[non_exhaustive]
+    pub enum BaseDirection {
+        /// Left-to-right.
+        LeftToRight,
+        /// Right-to-left.
+        RightToLeft,
+        /// The direction of the first strong character in the paragraph (rules P2 and P3),
+        /// or left-to-right if there is none.
+        #[default]
+        Auto,
+    }
+
+    /// A paragraph of the text resolved by a [`BidiResolver`].
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BidiParagraph {
+        range: Range<usize>,
+        level: BidiLevel,
+    }
+
+    impl BidiParagraph {
+        /// The range of code units of the paragraph, including its paragraph separator.
+        pub fn range(&self) -> Range<usize> {
+            self.range.clone()
+        }
+
+        /// The paragraph embedding level.
+        pub fn level(&self) -> BidiLevel {
+            self.level
+        }
+    }
+
+    /// The resolved embedding levels of a text.
+    ///
+    /// All indices are code unit indices into the text this was resolved from, i.e. byte
+    /// indices for UTF-8 and `u16` indices for UTF-16.
+    ///
+    /// This is returned by [`BidiResolverBorrowed::resolve_str`] and
+    /// [`BidiResolverBorrowed::resolve_utf16`].
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BidiInfo {
+        paragraphs: Vec<BidiParagraph>,
+        levels: Vec<BidiLevel>,
+        classes: Vec<BidiClass>,
+    }
+
+    impl BidiInfo {
+        /// The paragraphs of the text.
+        pub fn paragraphs(&self) -> &[BidiParagraph] {
+            &self.paragraphs
+        }
+
+        /// The embedding level of each code unit, before any line breaking.
+        ///
+        /// Characters that are removed by rule X9, such as explicit embeddings, have the
+        /// level of the preceding character.
+        pub fn levels(&self) -> &[BidiLevel] {
+            &self.levels
+        }
+
+        /// The embedding level of each code unit of a line, with rule L1 applied, which
+        /// resets separators and trailing whitespace to the paragraph level.
+        ///
+        /// Returns an empty vector if `line` is out of bounds.
+        pub fn line_levels(&self, line: Range<usize>) -> Vec<BidiLevel> {
+            let (Some(levels), Some(classes)) = (
+                self.levels.get(line.clone()),
+                self.classes.get(line.clone()),
+            ) else {
+                return Vec::new();
+            };
+            let mut line_levels = levels.to_vec();
+            let mut trailing = true;
+            for (i, (&class, level)) in line
+                .clone()
+                .zip(classes.iter().zip(line_levels.iter_mut()))
+                .rev()
+            {
+                match class {
+                    BidiClass::SegmentSeparator | BidiClass::ParagraphSeparator => {
+                        *level = self.paragraph_level_at(i);
+                        trailing = true;
+                    }
+                    BidiClass::WhiteSpace
+                    | BidiClass::RightToLeftIsolate
+                    | BidiClass::LeftToRightIsolate
+                    | BidiClass::FirstStrongIsolate
+                    | BidiClass::PopDirectionalIsolate
+                        if trailing =>
+                    {
+                        *level = self.paragraph_level_at(i);
+                    }
+                    class if trailing && algorithm::is_removed(class) => {
+                        *level = self.paragraph_level_at(i);
+                    }
+                    _ => trailing = false,
+                }
+            }
+            line_levels
+        }
+
+        /// The runs of a line in visual order, with their embedding levels (rule L2).
+        ///
+        /// Runs with a right-to-left level have to be displayed in reverse.
+        pub fn visual_runs(&self, line: Range<usize>) -> Vec<(Range<usize>, BidiLevel)> {
+            let mut runs: Vec<(Range<usize>, BidiLevel)> = Vec::new();
+            for (i, level) in (line.start..).zip(self.line_levels(line)) {
+                match runs.last_mut() {
+                    Some((run, run_level)) if *run_level == level => run.end = i + 1,
+                    _ => runs.push((i..i + 1, level)),
+                }
+            }
+            reverse_by_level(&mut runs, |(_, level)| *level);
+            runs
+        }
+
+        fn paragraph_level_at(&self, index: usize) -> BidiLevel {
+            let paragraph = self
+                .paragraphs
+                .partition_point(|paragraph| paragraph.range.end <= index);
+            self.paragraphs
+                .get(paragraph)
+                .map_or(BidiLevel::LTR, |paragraph| paragraph.level)
+        }
+    }
+
+    /// Returns the visual order of items with the given levels, as indices into `levels`
+    /// (rule L2).
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::properties::bidi::{reorder_visual, BidiLevel};
+    ///
+    /// let levels = [0, 0, 1, 1, 2, 1].map(|l| BidiLevel::new(l).unwrap());
+    /// assert_eq!(reorder_visual(&levels), [0, 1, 5, 4, 3, 2]);
+    /// ```
+    pub fn reorder_visual(levels: &[BidiLevel]) -> Vec<usize> {
+        let mut indices = levels.iter().copied().enumerate().collect::<Vec<_>>();
+        reverse_by_level(&mut indices, |(_, level)| *level);
+        indices.into_iter().map(|(i, _)| i).collect()
+    }
+
+    /// Reverses any sequence of items at the highest level and up, then at the next lower
+    /// level and up, down to the lowest odd level.
+    fn reverse_by_level<T>(items: &mut [T], level: impl Fn(&T) -> BidiLevel) {
+        let (Some(min), Some(max)) = (
+            items.iter().map(&level).min(),
+            items.iter().map(&level).max(),
+        ) else {
+            return;
+        };
+        for reversed_level in ((min.0 | 1)..=max.0).rev() {
+            let mut i = 0;
+            while i < items.len() {
+                let start = i;
+                while items
+                    .get(i)
+                    .is_some_and(|item| level(item).0 >= reversed_level)
+                {
+                    i += 1;
+                }
+                if let Some(sequence) = items.get_mut(start..i) {
+                    sequence.reverse();
+                }
+                i += 1;
+            }
+        }
+    }
+
+    /// An implementation of the [Unicode Bidirectional Algorithm (UAX #9)](https://www.unicode.org/reports/tr9/).
+    ///
+    /// See the [module-level docs](super) for an example.
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[derive(Debug)]
+    pub struct BidiResolver {
+        classes: CodePointMapData<BidiClass>,
+        mirroring: CodePointMapData<BidiMirroringGlyph>,
+    }
+
+    /// A borrowed version of [`BidiResolver`], returned by [`BidiResolver::as_borrowed()`].
+    ///
+    /// ✨ *Enabled with the `experimental` Cargo feature.*
+    ///
+    /// <div class="stab unstable">
+    /// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+    /// including in SemVer minor releases. Use with caution.
+    /// </div>
+    #[derive(Debug, Copy, Clone)]
+    pub struct BidiResolverBorrowed<'a> {
+        classes: CodePointMapDataBorrowed<'a, BidiClass>,
+        mirroring: CodePointMapDataBorrowed<'a, BidiMirroringGlyph>,
+    }
+
+    impl BidiResolver {
+        /// Creates a new [`BidiResolverBorrowed`] using compiled data.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        #[cfg(feature = "compiled_data")]
+        #[expect(clippy::new_ret_no_self)]
+        pub const fn new() -> BidiResolverBorrowed<'static> {
+            BidiResolverBorrowed::new()
+        }
+
+        icu_provider::gen_buffer_data_constructors!(
+            () -> result: Result<BidiResolver, DataError>,
+            functions: [
+                new: skip,
+                try_new_with_buffer_provider,
+                try_new_unstable,
+                Self,
+            ]
+        );
+
+        #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+        pub fn try_new_unstable(
+            provider: &(impl DataProvider<PropertyEnumBidiClassV1>
+                  + DataProvider<PropertyEnumBidiMirroringGlyphV1>
+                  + ?Sized),
+        ) -> Result<Self, DataError> {
+            Ok(Self {
+                classes: CodePointMapData::try_new_unstable(provider)?,
+                mirroring: CodePointMapData::try_new_unstable(provider)?,
+            })
+        }
+
+        /// Construct a borrowed version of this type that can be queried.
+        pub fn as_borrowed(&self) -> BidiResolverBorrowed<'_> {
+            BidiResolverBorrowed {
+                classes: self.classes.as_borrowed(),
+                mirroring: self.mirroring.as_borrowed(),
+            }
+        }
+    }
+
+    impl BidiResolverBorrowed<'static> {
+        /// Creates a new [`BidiResolverBorrowed`] using compiled data.
+        ///
+        /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+        ///
+        /// [📚 Help choosing a constructor](icu_provider::constructors)
+        #[cfg(feature = "compiled_data")]
+        pub const fn new() -> Self {
+            Self {
+                classes: CodePointMapData::new(),
+                mirroring: CodePointMapData::new(),
+            }
+        }
+
+        /// Cheaply converts a [`BidiResolverBorrowed<'static>`] into a [`BidiResolver`].
+        pub const fn static_to_owned(self) -> BidiResolver {
+            BidiResolver {
+                classes: self.classes.static_to_owned(),
+                mirroring: self.mirroring.static_to_owned(),
+            }
+        }
+    }
+
+    #[cfg(feature = "compiled_data")]
+    impl Default for BidiResolverBorrowed<'static> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl BidiResolverBorrowed<'_> {
+        /// Resolves the embedding levels of UTF-8 text.
+        pub fn resolve_str(self, text: &str, direction: BaseDirection) -> BidiInfo {
+            self.resolve(text.char_indices(), text.len(), direction)
+        }
+
+        /// Resolves the embedding levels of UTF-16 text.
+        ///
+        /// Unpaired surrogates are treated like U+FFFD REPLACEMENT CHARACTER.
+        pub fn resolve_utf16(self, text: &[u16], direction: BaseDirection) -> BidiInfo {
+            let mut offset = 0;
+            let chars = char::decode_utf16(text.iter().copied()).map(|c| {
+                let start = offset;
+                offset += c.as_ref().map_or(1, |c| c.len_utf16());
+                (start, c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            });
+            self.resolve(chars, text.len(), direction)
+        }
+
+        fn resolve(
+            self,
+            chars: impl Iterator<Item = (usize, char)>,
+            len: usize,
+            direction: BaseDirection,
+        ) -> BidiInfo {
+            let chars = chars.collect::<Vec<_>>();
+            let mut info = BidiInfo {
+                paragraphs: Vec::new(),
+                levels: Vec::with_capacity(len),
+                classes: Vec::with_capacity(len),
+            };
+
+            let mut rest = chars.as_slice();
+            while !rest.is_empty() {
+                // P1: a paragraph separator ends a paragraph, keeping CR LF together.
+                let mut end = rest
+                    .iter()
+                    .position(|&(_, c)| self.classes.get(c) == BidiClass::ParagraphSeparator)
+                    .map_or(rest.len(), |i| i + 1);
+                if let [.., (_, '\r')] = rest.get(..end).unwrap_or_default() {
+                    if let Some((_, '\n')) = rest.get(end) {
+                        end += 1;
+                    }
+                }
+                let (paragraph, next) = rest.split_at(end);
+                rest = next;
+
+                let classes = paragraph
+                    .iter()
+                    .map(|&(_, c)| self.classes.get(c))
+                    .collect::<Vec<_>>();
+                let brackets = paragraph
+                    .iter()
+                    .map(|&(_, c)| self.bracket(c))
+                    .collect::<Vec<_>>();
+                let level = match direction {
+                    BaseDirection::LeftToRight => 0,
+                    BaseDirection::RightToLeft => 1,
+                    BaseDirection::Auto => {
+                        u8::from(algorithm::first_strong_is_rtl(&classes, false) == Some(true))
+                    }
+                };
+                let levels = algorithm::resolve_paragraph(&classes, &brackets, level);
+
+                let start = paragraph.first().map_or(len, |&(i, _)| i);
+                let end = rest.first().map_or(len, |&(i, _)| i);
+                let ends = paragraph.iter().skip(1).map(|&(i, _)| i).chain([end]);
+                for (((&(start, _), end), class), level) in
+                    paragraph.iter().zip(ends).zip(classes).zip(levels)
+                {
+                    for _ in start..end {
+                        info.classes.push(class);
+                        info.levels.push(BidiLevel(level));
+                    }
+                }
+                info.paragraphs.push(BidiParagraph {
+                    range: start..end,
+                    level: BidiLevel(level),
+                });
+            }
+            info
+        }
+
+        /// Reorders a line of UTF-8 text into visual order, mirroring characters in
+        /// right-to-left runs (rules L1, L2, and L4).
+        ///
+        /// `info` must have been resolved from `text`.
+        pub fn reorder_str(self, info: &BidiInfo, text: &str, line: Range<usize>) -> String {
+            let mut visual = String::with_capacity(line.len());
+            for (run, level) in info.visual_runs(line) {
+                let Some(run) = text.get(run) else {
+                    continue;
+                };
+                if level.is_rtl() {
+                    visual.extend(run.chars().rev().map(|c| self.mirror(c)));
+                } else {
+                    visual.push_str(run);
+                }
+            }
+            visual
+        }
+
+        /// Reorders a line of UTF-16 text into visual order, mirroring characters in
+        /// right-to-left runs (rules L1, L2, and L4).
+        ///
+        /// `info` must have been resolved from `text`.
+        pub fn reorder_utf16(self, info: &BidiInfo, text: &[u16], line: Range<usize>) -> Vec<u16> {
+            let mut visual = Vec::with_capacity(line.len());
+            for (run, level) in info.visual_runs(line) {
+                let Some(run) = text.get(run) else {
+                    continue;
+                };
+                if level.is_rtl() {
+                    let chars = char::decode_utf16(run.iter().copied()).collect::<Vec<_>>();
+                    for c in chars.into_iter().rev() {
+                        match c {
+                            Ok(c) => {
+                                visual.extend_from_slice(self.mirror(c).encode_utf16(&mut [0; 2]))
+                            }
+                            Err(e) => visual.push(e.unpaired_surrogate()),
+                        }
+                    }
+                } else {
+                    visual.extend_from_slice(run);
+                }
+            }
+            visual
+        }
+
+        /// Returns the character to display for `c` in right-to-left text (rule L4).
+        fn mirror(self, c: char) -> char {
+            let mirroring = self.mirroring.get(c);
+            match mirroring.mirroring_glyph {
+                Some(glyph) if mirroring.mirrored => glyph,
+                _ => c,
+            }
+        }
+
+        fn bracket(self, c: char) -> Bracket {
+            // BD16: brackets match their canonical equivalents
+            let canonical = |c| match c {
+                '\u{2329}' => '\u{3008}',
+                '\u{232A}' => '\u{3009}',
+                c => c,
+            };
+            let mirroring = self.mirroring.get(c);
+            match mirroring.paired_bracket_type {
+                BidiPairedBracketType::Open => {
+                    Bracket::Open(canonical(mirroring.mirroring_glyph.unwrap_or(c)))
+                }
+                BidiPairedBracketType::Close => Bracket::Close(canonical(c)),
+                BidiPairedBracketType::None => Bracket::None,
+            }
+        }
+    }
+}
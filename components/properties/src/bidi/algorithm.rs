@@ -0,0 +1,579 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! The resolution of embedding levels for a single paragraph, following
+//! [UAX #9](https://www.unicode.org/reports/tr9/) rules X1 to I2.
+//!
+//! All indices in this module are indices into the characters of the paragraph.
+
+// All indices are obtained from iterating over the paragraph or an isolating run sequence
+// within it.
+#![expect(clippy::indexing_slicing)]
+
+use crate::props::BidiClass;
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The maximum explicit embedding level (BD2).
+pub(super) const MAX_DEPTH: u8 = 125;
+
+/// The maximum nesting depth of bracket pairs (BD16).
+const MAX_BRACKET_DEPTH: usize = 63;
+
+/// The paired bracket properties of a character (BD14, BD15).
+///
+/// Both kinds of brackets are identified by their closing bracket, so that an opening
+/// and a closing bracket form a pair if they carry the same character.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum Bracket {
+    None,
+    Open(char),
+    Close(char),
+}
+
+/// Whether characters of this class are removed by rule X9.
+pub(super) fn is_removed(class: BidiClass) -> bool {
+    matches!(
+        class,
+        BidiClass::RightToLeftEmbedding
+            | BidiClass::LeftToRightEmbedding
+            | BidiClass::RightToLeftOverride
+            | BidiClass::LeftToRightOverride
+            | BidiClass::PopDirectionalFormat
+            | BidiClass::BoundaryNeutral
+    )
+}
+
+fn is_isolate_initiator(class: BidiClass) -> bool {
+    matches!(
+        class,
+        BidiClass::RightToLeftIsolate
+            | BidiClass::LeftToRightIsolate
+            | BidiClass::FirstStrongIsolate
+    )
+}
+
+/// Finds the first strong character according to rule P2, skipping isolates, and returns
+/// whether it is right-to-left.
+///
+/// If `in_isolate` is set, the search ends at a PDI that closes the enclosing isolate.
+pub(super) fn first_strong_is_rtl(classes: &[BidiClass], in_isolate: bool) -> Option<bool> {
+    let mut depth = 0usize;
+    for &class in classes {
+        match class {
+            BidiClass::LeftToRight if depth == 0 => return Some(false),
+            BidiClass::RightToLeft | BidiClass::ArabicLetter if depth == 0 => return Some(true),
+            BidiClass::ParagraphSeparator => return None,
+            BidiClass::PopDirectionalIsolate => {
+                if depth > 0 {
+                    depth -= 1;
+                } else if in_isolate {
+                    return None;
+                }
+            }
+            class if is_isolate_initiator(class) => depth += 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The direction of a strong type, treating numbers as right-to-left as in rules N0 and N1.
+fn strong_direction(class: BidiClass) -> Option<BidiClass> {
+    match class {
+        BidiClass::LeftToRight => Some(BidiClass::LeftToRight),
+        BidiClass::RightToLeft | BidiClass::EuropeanNumber | BidiClass::ArabicNumber => {
+            Some(BidiClass::RightToLeft)
+        }
+        _ => None,
+    }
+}
+
+fn direction_of_level(level: u8) -> BidiClass {
+    if level % 2 == 0 {
+        BidiClass::LeftToRight
+    } else {
+        BidiClass::RightToLeft
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct DirectionalStatus {
+    level: u8,
+    override_class: Option<BidiClass>,
+    isolate: bool,
+}
+
+/// Resolves the embedding levels of a paragraph with the given paragraph embedding level.
+///
+/// Characters removed by rule X9 get the level of the preceding character.
+pub(super) fn resolve_paragraph(
+    classes: &[BidiClass],
+    brackets: &[Bracket],
+    paragraph_level: u8,
+) -> Vec<u8> {
+    let len = classes.len();
+
+    // BD9: matching PDIs
+    let mut matching_pdi = vec![None; len];
+    let mut open_isolates = Vec::new();
+    for (i, &class) in classes.iter().enumerate() {
+        if is_isolate_initiator(class) {
+            open_isolates.push(i);
+        } else if class == BidiClass::PopDirectionalIsolate {
+            if let Some(initiator) = open_isolates.pop() {
+                matching_pdi[initiator] = Some(i);
+            }
+        }
+    }
+
+    let (mut levels, mut types) = resolve_explicit(classes, paragraph_level);
+
+    // X10: level runs and isolating run sequences
+    let mut level_runs: Vec<Vec<usize>> = Vec::new();
+    let mut run_starting_at = vec![None; len];
+    let mut previous_level = None;
+    for i in (0..len).filter(|&i| !is_removed(classes[i])) {
+        match level_runs.last_mut() {
+            Some(run) if previous_level == Some(levels[i]) => run.push(i),
+            _ => {
+                run_starting_at[i] = Some(level_runs.len());
+                level_runs.push(vec![i]);
+            }
+        }
+        previous_level = Some(levels[i]);
+    }
+
+    // The sequences are all determined from the explicit levels before resolving any of them.
+    let mut sequences = Vec::new();
+    let mut consumed = vec![false; level_runs.len()];
+    for run_index in 0..level_runs.len() {
+        if consumed[run_index] {
+            continue;
+        }
+        consumed[run_index] = true;
+        let mut sequence = level_runs[run_index].clone();
+        while let Some(&last) = sequence.last() {
+            let Some(next_run) = matching_pdi[last]
+                .filter(|_| is_isolate_initiator(classes[last]))
+                .and_then(|pdi| run_starting_at[pdi])
+            else {
+                break;
+            };
+            consumed[next_run] = true;
+            sequence.extend_from_slice(&level_runs[next_run]);
+        }
+
+        let (Some(&first), Some(&last)) = (sequence.first(), sequence.last()) else {
+            continue;
+        };
+        let level = levels[first];
+        let before = (0..first)
+            .rev()
+            .find(|&i| !is_removed(classes[i]))
+            .map_or(paragraph_level, |i| levels[i]);
+        let after = if is_isolate_initiator(classes[last]) {
+            paragraph_level
+        } else {
+            (last + 1..len)
+                .find(|&i| !is_removed(classes[i]))
+                .map_or(paragraph_level, |i| levels[i])
+        };
+        sequences.push(IsolatingRunSequence {
+            sos: direction_of_level(level.max(before)),
+            eos: direction_of_level(level.max(after)),
+            level,
+            indices: sequence,
+        });
+    }
+    for sequence in sequences {
+        sequence.resolve(classes, brackets, &mut types, &mut levels);
+    }
+
+    // Removed characters get the level of the preceding character
+    for i in 0..len {
+        if is_removed(classes[i]) {
+            levels[i] = if i == 0 {
+                paragraph_level
+            } else {
+                levels[i - 1]
+            };
+        }
+    }
+
+    levels
+}
+
+/// Applies rules X1 to X8, returning the explicit embedding levels and the character
+/// types after directional overrides.
+fn resolve_explicit(classes: &[BidiClass], paragraph_level: u8) -> (Vec<u8>, Vec<BidiClass>) {
+    let mut levels = vec![paragraph_level; classes.len()];
+    let mut types = classes.to_vec();
+
+    let mut stack = vec![DirectionalStatus {
+        level: paragraph_level,
+        override_class: None,
+        isolate: false,
+    }];
+    let mut overflow_isolates = 0usize;
+    let mut overflow_embeddings = 0usize;
+    let mut valid_isolates = 0usize;
+
+    for (i, &class) in classes.iter().enumerate() {
+        let Some(&top) = stack.last() else {
+            // The stack always holds the paragraph level entry.
+            break;
+        };
+        let next_level = |rtl: bool| {
+            if rtl {
+                (top.level + 1) | 1
+            } else {
+                (top.level + 2) & !1
+            }
+        };
+        match class {
+            // X2 - X5
+            BidiClass::RightToLeftEmbedding
+            | BidiClass::LeftToRightEmbedding
+            | BidiClass::RightToLeftOverride
+            | BidiClass::LeftToRightOverride => {
+                levels[i] = top.level;
+                let level = next_level(matches!(
+                    class,
+                    BidiClass::RightToLeftEmbedding | BidiClass::RightToLeftOverride
+                ));
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    stack.push(DirectionalStatus {
+                        level,
+                        override_class: match class {
+                            BidiClass::RightToLeftOverride => Some(BidiClass::RightToLeft),
+                            BidiClass::LeftToRightOverride => Some(BidiClass::LeftToRight),
+                            _ => None,
+                        },
+                        isolate: false,
+                    });
+                } else if overflow_isolates == 0 {
+                    overflow_embeddings += 1;
+                }
+            }
+            // X5a - X5c
+            BidiClass::RightToLeftIsolate
+            | BidiClass::LeftToRightIsolate
+            | BidiClass::FirstStrongIsolate => {
+                levels[i] = top.level;
+                if let Some(override_class) = top.override_class {
+                    types[i] = override_class;
+                }
+                let rtl = match class {
+                    BidiClass::RightToLeftIsolate => true,
+                    BidiClass::LeftToRightIsolate => false,
+                    _ => first_strong_is_rtl(&classes[i + 1..], true).unwrap_or(false),
+                };
+                let level = next_level(rtl);
+                if level <= MAX_DEPTH && overflow_isolates == 0 && overflow_embeddings == 0 {
+                    valid_isolates += 1;
+                    stack.push(DirectionalStatus {
+                        level,
+                        override_class: None,
+                        isolate: true,
+                    });
+                } else {
+                    overflow_isolates += 1;
+                }
+            }
+            // X6a
+            BidiClass::PopDirectionalIsolate => {
+                if overflow_isolates > 0 {
+                    overflow_isolates -= 1;
+                } else if valid_isolates > 0 {
+                    overflow_embeddings = 0;
+                    while let Some(status) = stack.pop() {
+                        if status.isolate {
+                            break;
+                        }
+                    }
+                    valid_isolates -= 1;
+                }
+                let top = stack.last().copied().unwrap_or(top);
+                levels[i] = top.level;
+                if let Some(override_class) = top.override_class {
+                    types[i] = override_class;
+                }
+            }
+            // X7
+            BidiClass::PopDirectionalFormat => {
+                levels[i] = top.level;
+                if overflow_isolates > 0 {
+                } else if overflow_embeddings > 0 {
+                    overflow_embeddings -= 1;
+                } else if !top.isolate && stack.len() >= 2 {
+                    stack.pop();
+                }
+            }
+            // X8
+            BidiClass::ParagraphSeparator => levels[i] = paragraph_level,
+            // X9
+            BidiClass::BoundaryNeutral => levels[i] = top.level,
+            // X6
+            _ => {
+                levels[i] = top.level;
+                if let Some(override_class) = top.override_class {
+                    types[i] = override_class;
+                }
+            }
+        }
+    }
+
+    (levels, types)
+}
+
+/// An isolating run sequence (BD13).
+#[derive(Debug)]
+struct IsolatingRunSequence {
+    indices: Vec<usize>,
+    level: u8,
+    sos: BidiClass,
+    eos: BidiClass,
+}
+
+impl IsolatingRunSequence {
+    /// Applies rules W1 to I2 to the sequence.
+    fn resolve(
+        &self,
+        classes: &[BidiClass],
+        brackets: &[Bracket],
+        types: &mut [BidiClass],
+        levels: &mut [u8],
+    ) {
+        let mut ts = self.indices.iter().map(|&i| types[i]).collect::<Vec<_>>();
+        self.resolve_weak(&mut ts);
+        self.resolve_brackets(classes, brackets, &mut ts);
+        self.resolve_neutral(&mut ts);
+
+        // I1, I2
+        for (&i, &t) in self.indices.iter().zip(&ts) {
+            let level = &mut levels[i];
+            if *level % 2 == 0 {
+                match t {
+                    BidiClass::RightToLeft => *level += 1,
+                    BidiClass::ArabicNumber | BidiClass::EuropeanNumber => *level += 2,
+                    _ => {}
+                }
+            } else if matches!(
+                t,
+                BidiClass::LeftToRight | BidiClass::ArabicNumber | BidiClass::EuropeanNumber
+            ) {
+                *level += 1;
+            }
+            types[i] = t;
+        }
+    }
+
+    /// Rules W1 to W7.
+    fn resolve_weak(&self, ts: &mut [BidiClass]) {
+        let len = ts.len();
+
+        // W1
+        let mut previous = self.sos;
+        for t in ts.iter_mut() {
+            if *t == BidiClass::NonspacingMark {
+                *t = if is_isolate_initiator(previous)
+                    || previous == BidiClass::PopDirectionalIsolate
+                {
+                    BidiClass::OtherNeutral
+                } else {
+                    previous
+                };
+            }
+            previous = *t;
+        }
+
+        // W2, W3
+        let mut last_strong = self.sos;
+        for t in ts.iter_mut() {
+            match *t {
+                BidiClass::LeftToRight | BidiClass::RightToLeft => last_strong = *t,
+                BidiClass::ArabicLetter => {
+                    last_strong = *t;
+                    *t = BidiClass::RightToLeft;
+                }
+                BidiClass::EuropeanNumber if last_strong == BidiClass::ArabicLetter => {
+                    *t = BidiClass::ArabicNumber;
+                }
+                _ => {}
+            }
+        }
+
+        // W4
+        for k in 1..len.saturating_sub(1) {
+            let (before, after) = (ts[k - 1], ts[k + 1]);
+            match ts[k] {
+                BidiClass::EuropeanSeparator | BidiClass::CommonSeparator
+                    if before == BidiClass::EuropeanNumber
+                        && after == BidiClass::EuropeanNumber =>
+                {
+                    ts[k] = BidiClass::EuropeanNumber;
+                }
+                BidiClass::CommonSeparator
+                    if before == BidiClass::ArabicNumber && after == BidiClass::ArabicNumber =>
+                {
+                    ts[k] = BidiClass::ArabicNumber;
+                }
+                _ => {}
+            }
+        }
+
+        // W5
+        let mut k = 0;
+        while k < len {
+            if ts[k] != BidiClass::EuropeanTerminator {
+                k += 1;
+                continue;
+            }
+            let start = k;
+            while k < len && ts[k] == BidiClass::EuropeanTerminator {
+                k += 1;
+            }
+            if (start > 0 && ts[start - 1] == BidiClass::EuropeanNumber)
+                || (k < len && ts[k] == BidiClass::EuropeanNumber)
+            {
+                ts[start..k].fill(BidiClass::EuropeanNumber);
+            }
+        }
+
+        // W6
+        for t in ts.iter_mut() {
+            if matches!(
+                *t,
+                BidiClass::EuropeanSeparator
+                    | BidiClass::EuropeanTerminator
+                    | BidiClass::CommonSeparator
+            ) {
+                *t = BidiClass::OtherNeutral;
+            }
+        }
+
+        // W7
+        let mut last_strong = self.sos;
+        for t in ts.iter_mut() {
+            match *t {
+                BidiClass::LeftToRight | BidiClass::RightToLeft => last_strong = *t,
+                BidiClass::EuropeanNumber if last_strong == BidiClass::LeftToRight => {
+                    *t = BidiClass::LeftToRight;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Rule N0.
+    fn resolve_brackets(&self, classes: &[BidiClass], brackets: &[Bracket], ts: &mut [BidiClass]) {
+        // BD16
+        let mut openers: Vec<(char, usize)> = Vec::new();
+        let mut pairs = Vec::new();
+        for (k, &i) in self.indices.iter().enumerate() {
+            if ts[k] != BidiClass::OtherNeutral {
+                continue;
+            }
+            match brackets[i] {
+                Bracket::Open(closing) => {
+                    if openers.len() == MAX_BRACKET_DEPTH {
+                        break;
+                    }
+                    openers.push((closing, k));
+                }
+                Bracket::Close(closing) => {
+                    if let Some(position) = openers.iter().rposition(|&(c, _)| c == closing) {
+                        pairs.push((openers[position].1, k));
+                        openers.truncate(position);
+                    }
+                }
+                Bracket::None => {}
+            }
+        }
+        pairs.sort_unstable();
+
+        let embedding_direction = direction_of_level(self.level);
+        for (open, close) in pairs {
+            let mut found_opposite = false;
+            let mut found_embedding = false;
+            for &t in &ts[open + 1..close] {
+                match strong_direction(t) {
+                    Some(direction) if direction == embedding_direction => {
+                        found_embedding = true;
+                        break;
+                    }
+                    Some(_) => found_opposite = true,
+                    None => {}
+                }
+            }
+            let direction = if found_embedding {
+                embedding_direction
+            } else if found_opposite {
+                ts[..open]
+                    .iter()
+                    .rev()
+                    .find_map(|&t| strong_direction(t))
+                    .unwrap_or(self.sos)
+            } else {
+                continue;
+            };
+            for bracket in [open, close] {
+                ts[bracket] = direction;
+                // Marks following a bracket take its direction
+                for k in bracket + 1..ts.len() {
+                    if classes[self.indices[k]] != BidiClass::NonspacingMark {
+                        break;
+                    }
+                    ts[k] = direction;
+                }
+            }
+        }
+    }
+
+    /// Rules N1 and N2.
+    fn resolve_neutral(&self, ts: &mut [BidiClass]) {
+        let is_neutral_or_isolate = |t: BidiClass| {
+            matches!(
+                t,
+                BidiClass::ParagraphSeparator
+                    | BidiClass::SegmentSeparator
+                    | BidiClass::WhiteSpace
+                    | BidiClass::OtherNeutral
+                    | BidiClass::RightToLeftIsolate
+                    | BidiClass::LeftToRightIsolate
+                    | BidiClass::FirstStrongIsolate
+                    | BidiClass::PopDirectionalIsolate
+            )
+        };
+        let embedding_direction = direction_of_level(self.level);
+        let len = ts.len();
+        let mut k = 0;
+        while k < len {
+            if !is_neutral_or_isolate(ts[k]) {
+                k += 1;
+                continue;
+            }
+            let start = k;
+            while k < len && is_neutral_or_isolate(ts[k]) {
+                k += 1;
+            }
+            let before = if start == 0 {
+                Some(self.sos)
+            } else {
+                strong_direction(ts[start - 1])
+            };
+            let after = if k == len {
+                Some(self.eos)
+            } else {
+                strong_direction(ts[k])
+            };
+            let direction = match (before, after) {
+                (Some(before), Some(after)) if before == after => before,
+                _ => embedding_direction,
+            };
+            ts[start..k].fill(direction);
+        }
+    }
+}
@@ -0,0 +1,574 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::provider::character_names::*;
+use core::fmt;
+use icu_provider::prelude::*;
+use zerovec::vecs::{Index32, VarZeroSlice};
+use zerovec::ZeroSlice;
+
+/// The first Hangul syllable, `U+AC00 HANGUL SYLLABLE GA`.
+const HANGUL_S_BASE: u32 = 0xAC00;
+/// The number of Hangul syllables.
+const HANGUL_S_COUNT: u32 = 11172;
+/// The number of Hangul syllables with the same leading consonant and vowel.
+const HANGUL_T_COUNT: u32 = 28;
+/// The number of Hangul syllables with the same leading consonant.
+const HANGUL_N_COUNT: u32 = 21 * HANGUL_T_COUNT;
+
+const HANGUL_PREFIX: &str = "HANGUL SYLLABLE ";
+
+/// The `Jamo_Short_Name` values of the leading consonants.
+const JAMO_L: [&str; 19] = [
+    "G", "GG", "N", "D", "DD", "R", "M", "B", "BB", "S", "SS", "", "J", "JJ", "C", "K", "T", "P",
+    "H",
+];
+/// The `Jamo_Short_Name` values of the vowels.
+const JAMO_V: [&str; 21] = [
+    "A", "AE", "YA", "YAE", "EO", "E", "YEO", "YE", "O", "WA", "WAE", "OE", "YO", "U", "WEO", "WE",
+    "WI", "YU", "EU", "YI", "I",
+];
+/// The `Jamo_Short_Name` values of the trailing consonants.
+const JAMO_T: [&str; 28] = [
+    "", "G", "GG", "GS", "N", "NJ", "NH", "D", "L", "LG", "LM", "LB", "LS", "LT", "LP", "LH", "M",
+    "B", "BS", "S", "SS", "NG", "J", "C", "K", "T", "P", "H",
+];
+
+/// `U+1180 HANGUL JUNGSEONG O-E`, whose hyphen is significant in loose matching.
+const HANGUL_JUNGSEONG_O_E: char = '\u{1180}';
+
+/// The maximum length of a name that can be matched loosely.
+const MAX_NAME_LEN: usize = 128;
+/// The maximum number of words of a name that can be matched strictly.
+const MAX_NAME_WORDS: usize = 32;
+
+/// A struct capable of looking up the name of a code point and the code point of a name.
+/// Access its data by calling [`Self::as_borrowed()`] and using the methods on
+/// [`CharacterNamesBorrowed`].
+///
+/// This covers the `Name` property, including the names of Hangul syllables and CJK
+/// ideographs that are derived from the code point, as well as the
+/// [name aliases](https://www.unicode.org/reports/tr44/#NameAliases.txt).
+///
+/// The data for this type is generated from the Unicode Character Database (`--ucd-root` in
+/// datagen).
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+///
+/// The compiled data covers all code points and takes about 800 kB, so it should only be
+/// used where all names are needed. [`CharacterNames::from_table`] can be used with a
+/// subset of the names.
+///
+/// # Example
+///
+/// ```
+/// use icu::properties::CharacterNames;
+///
+/// let names = CharacterNames::new();
+///
+/// assert_eq!(names.get('a').unwrap().to_string(), "LATIN SMALL LETTER A");
+/// assert_eq!(names.get('木').unwrap().to_string(), "CJK UNIFIED IDEOGRAPH-6728");
+/// assert_eq!(names.get('한').unwrap().to_string(), "HANGUL SYLLABLE HAN");
+///
+/// assert_eq!(names.get_char_strict("LATIN SMALL LETTER A"), Some('a'));
+/// assert_eq!(names.get_char_strict("latin small letter a"), None);
+/// assert_eq!(names.get_char_loose("latin small letter a"), Some('a'));
+/// assert_eq!(names.get_char_strict("CJK UNIFIED IDEOGRAPH-6728"), Some('木'));
+/// assert_eq!(names.get_char_strict("HANGUL SYLLABLE HAN"), Some('한'));
+/// ```
+#[derive(Debug)]
+pub struct CharacterNames {
+    data: DataPayload<PropertyCharacterNamesV1>,
+}
+
+/// A borrowed wrapper around character name data, returned by
+/// [`CharacterNames::as_borrowed()`]. More efficient to query.
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Copy)]
+pub struct CharacterNamesBorrowed<'a> {
+    data: &'a CharacterNameTable<'a>,
+}
+
+impl CharacterNames {
+    /// Creates a new instance of `CharacterNamesBorrowed` using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[expect(clippy::new_ret_no_self)]
+    pub fn new() -> CharacterNamesBorrowed<'static> {
+        CharacterNamesBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(
+        () -> result: Result<CharacterNames, DataError>,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<PropertyCharacterNamesV1> + ?Sized),
+    ) -> Result<Self, DataError> {
+        Ok(Self {
+            data: provider.load(Default::default())?.payload,
+        })
+    }
+
+    /// Creates a new instance of [`CharacterNames`] from a [`CharacterNameTable`], such as
+    /// one built with [`CharacterNameTable::try_from_names`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::provider::character_names::{CharacterNameKind, CharacterNameTable};
+    /// use icu::properties::CharacterNames;
+    ///
+    /// // Only the names that are needed
+    /// let table = CharacterNameTable::try_from_names(
+    ///     &[('a', CharacterNameKind::Name, "LATIN SMALL LETTER A")],
+    ///     &[('\u{4E00}', '\u{9FFF}', "CJK UNIFIED IDEOGRAPH-")],
+    /// )
+    /// .unwrap();
+    ///
+    /// let names = CharacterNames::from_table(table);
+    /// let names = names.as_borrowed();
+    ///
+    /// assert_eq!(names.get('a').unwrap().to_string(), "LATIN SMALL LETTER A");
+    /// assert_eq!(names.get('木').unwrap().to_string(), "CJK UNIFIED IDEOGRAPH-6728");
+    /// assert!(names.get('b').is_none());
+    /// ```
+    pub fn from_table(table: CharacterNameTable<'static>) -> Self {
+        Self {
+            data: DataPayload::from_owned(table),
+        }
+    }
+
+    /// Construct a borrowed version of this type that can be queried.
+    ///
+    /// This avoids a potential small underlying cost per API call (like `get()`) by consolidating it
+    /// up front.
+    #[inline]
+    pub fn as_borrowed(&self) -> CharacterNamesBorrowed<'_> {
+        CharacterNamesBorrowed {
+            data: self.data.get(),
+        }
+    }
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for CharacterNamesBorrowed<'static> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CharacterNamesBorrowed<'static> {
+    /// Creates a new instance of `CharacterNamesBorrowed` using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    pub fn new() -> Self {
+        Self {
+            data: crate::provider::Baked::SINGLETON_PROPERTY_CHARACTER_NAMES_V1,
+        }
+    }
+
+    /// Cheaply converts a [`CharacterNamesBorrowed<'static>`] into a [`CharacterNames`].
+    pub const fn static_to_owned(self) -> CharacterNames {
+        CharacterNames {
+            data: DataPayload::from_static_ref(self.data),
+        }
+    }
+}
+
+impl<'a> CharacterNamesBorrowed<'a> {
+    /// Returns the `Name` property value of a code point, or `None` if it has no name,
+    /// such as control characters, private use characters and unassigned code points.
+    ///
+    /// The returned value implements [`Display`](fmt::Display).
+    pub fn get(self, ch: char) -> Option<CharacterName<'a>> {
+        let code_point = u32::from(ch);
+        if (HANGUL_S_BASE..HANGUL_S_BASE + HANGUL_S_COUNT).contains(&code_point) {
+            return Some(CharacterName(CharacterNameInner::Hangul(code_point)));
+        }
+        if let Some(prefix) = self
+            .data
+            .algorithmic_ranges
+            .iter()
+            .zip(self.data.algorithmic_prefixes.iter())
+            .find_map(|((start, end), prefix)| (start..=end).contains(&ch).then_some(prefix))
+        {
+            return Some(CharacterName(CharacterNameInner::Algorithmic {
+                prefix,
+                code_point,
+            }));
+        }
+        self.stored(ch)
+            .find(|&(kind, _)| kind == CharacterNameKind::Name)
+            .map(|(_, name)| name)
+    }
+
+    /// Returns the [name aliases](https://www.unicode.org/reports/tr44/#NameAliases.txt) of a
+    /// code point with their types, such as the `control` alias `LINE FEED` of `U+000A`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use icu::properties::provider::character_names::{CharacterNameKind, CharacterNameTable};
+    /// use icu::properties::{CharacterNames, NameAliasType};
+    ///
+    /// let table = CharacterNameTable::try_from_names(
+    ///     &[
+    ///         ('\n', CharacterNameKind::Control, "LINE FEED"),
+    ///         ('\n', CharacterNameKind::Control, "NEW LINE"),
+    ///         ('\n', CharacterNameKind::Abbreviation, "LF"),
+    ///     ],
+    ///     &[],
+    /// )
+    /// .unwrap();
+    ///
+    /// let names = CharacterNames::from_table(table);
+    /// let names = names.as_borrowed();
+    ///
+    /// assert!(names.get('\n').is_none());
+    ///
+    /// let aliases = names
+    ///     .aliases('\n')
+    ///     .map(|(alias_type, alias)| (alias_type, alias.to_string()))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     aliases,
+    ///     [
+    ///         (NameAliasType::Control, "LINE FEED".into()),
+    ///         (NameAliasType::Control, "NEW LINE".into()),
+    ///         (NameAliasType::Abbreviation, "LF".into()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn aliases(
+        self,
+        ch: char,
+    ) -> impl Iterator<Item = (NameAliasType, CharacterName<'a>)> + 'a {
+        self.stored(ch).filter_map(|(kind, name)| {
+            let alias_type = match kind {
+                CharacterNameKind::Name => return None,
+                CharacterNameKind::Correction => NameAliasType::Correction,
+                CharacterNameKind::Control => NameAliasType::Control,
+                CharacterNameKind::Alternate => NameAliasType::Alternate,
+                CharacterNameKind::Figment => NameAliasType::Figment,
+                CharacterNameKind::Abbreviation => NameAliasType::Abbreviation,
+            };
+            Some((alias_type, name))
+        })
+    }
+
+    /// Returns the code point with the given name or name alias, using strict matching.
+    ///
+    /// The name has to match exactly, such as `LATIN SMALL LETTER A`.
+    pub fn get_char_strict(self, name: &str) -> Option<char> {
+        if let Some(jamo) = name.strip_prefix(HANGUL_PREFIX) {
+            return hangul_from_jamo(jamo.as_bytes());
+        }
+        for (i, prefix) in self.data.algorithmic_prefixes.iter().enumerate() {
+            if let Some(ch) = name
+                .strip_prefix(prefix)
+                .and_then(|hex| self.algorithmic_from_hex(i, hex.as_bytes()))
+            {
+                return Some(ch);
+            }
+        }
+
+        let mut words = [0u16; MAX_NAME_WORDS];
+        let mut len = 0;
+        for word in name.split(' ') {
+            *words.get_mut(len)? = self.data.words.binary_search(word).ok()? as u16;
+            len += 1;
+        }
+        let words = words.get(..len)?;
+        let index = self
+            .data
+            .names_index
+            .binary_search_by(|index| {
+                self.data
+                    .names
+                    .get(index as usize)
+                    .map(|name| name.iter().cmp(words.iter().copied()))
+                    .unwrap_or(core::cmp::Ordering::Less)
+            })
+            .ok()?;
+        self.data
+            .code_points
+            .get(self.data.names_index.get(index)? as usize)
+    }
+
+    /// Returns the code point with the given name or name alias, using loose matching.
+    ///
+    /// This follows [UAX44-LM2](https://www.unicode.org/reports/tr44/#UAX44-LM2): case,
+    /// whitespace, underscores and hyphens between letters or digits are ignored, such as
+    /// in `latin_small_letter_a`.
+    ///
+    /// Unlike strict matching, this compares against every stored name, so it is
+    /// considerably slower.
+    pub fn get_char_loose(self, name: &str) -> Option<char> {
+        let mut buffer = [0u8; MAX_NAME_LEN];
+        let key = normalize_loose(name.bytes(), &mut buffer)?;
+
+        // The only name whose medial hyphen is significant
+        if name
+            .bytes()
+            .filter(|b| !b.is_ascii_whitespace() && *b != b'_')
+            .map(|b| b.to_ascii_uppercase())
+            .eq(*b"HANGULJUNGSEONGO-E")
+        {
+            return Some(HANGUL_JUNGSEONG_O_E);
+        }
+
+        let mut hangul_buffer = [0u8; MAX_NAME_LEN];
+        let hangul_prefix = normalize_loose(HANGUL_PREFIX.bytes(), &mut hangul_buffer)?;
+        if let Some(jamo) = key.strip_prefix(hangul_prefix) {
+            return hangul_from_jamo(jamo);
+        }
+        for (i, prefix) in self.data.algorithmic_prefixes.iter().enumerate() {
+            let mut prefix_buffer = [0u8; MAX_NAME_LEN];
+            // The hyphen at the end of the prefix precedes a digit
+            let Some(prefix) = normalize_loose(prefix.bytes().chain([b'0']), &mut prefix_buffer)
+                .and_then(|p| p.split_last())
+                .map(|(_, p)| p)
+            else {
+                continue;
+            };
+            if let Some(ch) = key
+                .strip_prefix(prefix)
+                .and_then(|hex| self.algorithmic_from_hex(i, hex))
+            {
+                return Some(ch);
+            }
+        }
+
+        let mut name_buffer = [0u8; MAX_NAME_LEN];
+        self.data
+            .names
+            .iter()
+            .zip(self.data.code_points.iter())
+            .find(|&(name, ch)| {
+                ch != HANGUL_JUNGSEONG_O_E
+                    && normalize_loose(StoredName::bytes(&self.data.words, name), &mut name_buffer)
+                        == Some(key)
+            })
+            .map(|(_, ch)| ch)
+    }
+
+    /// Returns the stored names of a code point, with the name preceding the aliases.
+    fn stored(self, ch: char) -> impl Iterator<Item = (CharacterNameKind, CharacterName<'a>)> + 'a {
+        let code_points = &self.data.code_points;
+        let start = code_points
+            .binary_search(&ch)
+            .map(|mut i| {
+                while i > 0 && code_points.get(i - 1) == Some(ch) {
+                    i -= 1;
+                }
+                i
+            })
+            .unwrap_or(code_points.len());
+        let data = self.data;
+        (start..code_points.len())
+            .take_while(move |&i| data.code_points.get(i) == Some(ch))
+            .filter_map(move |i| {
+                Some((
+                    data.kinds.get(i)?,
+                    CharacterName(CharacterNameInner::Stored(StoredName {
+                        words: &data.words,
+                        indices: data.names.get(i)?,
+                    })),
+                ))
+            })
+    }
+
+    /// Returns the code point of a name in the algorithmic range with the given index from
+    /// its hexadecimal part, which has to use four to six uppercase digits without
+    /// unnecessary leading zeros.
+    fn algorithmic_from_hex(self, range: usize, hex: &[u8]) -> Option<char> {
+        if !(4..=6).contains(&hex.len())
+            || (hex.len() > 4 && hex.first() == Some(&b'0'))
+            || !hex.iter().all(|b| matches!(b, b'0'..=b'9' | b'A'..=b'F'))
+        {
+            return None;
+        }
+        let code_point = u32::from_str_radix(core::str::from_utf8(hex).ok()?, 16).ok()?;
+        let (start, end) = self.data.algorithmic_ranges.get(range)?;
+        char::from_u32(code_point).filter(|ch| (start..=end).contains(ch))
+    }
+}
+
+/// Returns the Hangul syllable with the given `Jamo_Short_Name` values.
+fn hangul_from_jamo(jamo: &[u8]) -> Option<char> {
+    for (l, l_name) in JAMO_L.iter().enumerate() {
+        let Some(rest) = jamo.strip_prefix(l_name.as_bytes()) else {
+            continue;
+        };
+        for (v, v_name) in JAMO_V.iter().enumerate() {
+            let Some(rest) = rest.strip_prefix(v_name.as_bytes()) else {
+                continue;
+            };
+            if let Some(t) = JAMO_T.iter().position(|t_name| t_name.as_bytes() == rest) {
+                return char::from_u32(
+                    HANGUL_S_BASE
+                        + l as u32 * HANGUL_N_COUNT
+                        + v as u32 * HANGUL_T_COUNT
+                        + t as u32,
+                );
+            }
+        }
+    }
+    None
+}
+
+/// Writes the loosely matchable form of a name into `buffer`: uppercase, without
+/// whitespace, underscores and hyphens between letters or digits.
+///
+/// Returns `None` if the name is not ASCII or too long.
+fn normalize_loose(
+    name: impl Iterator<Item = u8>,
+    buffer: &mut [u8; MAX_NAME_LEN],
+) -> Option<&[u8]> {
+    let mut len = 0;
+    let mut previous = None;
+    let mut name = name.peekable();
+    while let Some(b) = name.next() {
+        if !b.is_ascii() {
+            return None;
+        }
+        let skip = b.is_ascii_whitespace()
+            || b == b'_'
+            || (b == b'-'
+                && previous.is_some_and(|p: u8| p.is_ascii_alphanumeric())
+                && name.peek().is_some_and(u8::is_ascii_alphanumeric));
+        previous = Some(b);
+        if !skip {
+            *buffer.get_mut(len)? = b.to_ascii_uppercase();
+            len += 1;
+        }
+    }
+    buffer.get(..len)
+}
+
+/// A name stored as indices into a list of words.
+#[derive(Debug, Clone, Copy)]
+struct StoredName<'a> {
+    words: &'a VarZeroSlice<str, Index32>,
+    indices: &'a ZeroSlice<u16>,
+}
+
+impl<'a> StoredName<'a> {
+    /// Returns the words of the name.
+    fn words(self) -> impl Iterator<Item = &'a str> {
+        self.indices
+            .iter()
+            .filter_map(move |i| self.words.get(usize::from(i)))
+    }
+
+    /// Returns the bytes of the name, with the words separated by spaces.
+    fn bytes(
+        words: &'a VarZeroSlice<str, Index32>,
+        indices: &'a ZeroSlice<u16>,
+    ) -> impl Iterator<Item = u8> + 'a {
+        Self { words, indices }
+            .words()
+            .enumerate()
+            .flat_map(|(i, word)| (i > 0).then_some(b' ').into_iter().chain(word.bytes()))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CharacterNameInner<'a> {
+    Stored(StoredName<'a>),
+    Algorithmic { prefix: &'a str, code_point: u32 },
+    Hangul(u32),
+}
+
+/// The name or a name alias of a code point, returned by [`CharacterNamesBorrowed`].
+///
+/// This implements [`Display`](fmt::Display).
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Copy)]
+pub struct CharacterName<'a>(CharacterNameInner<'a>);
+
+impl fmt::Display for CharacterName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            CharacterNameInner::Stored(name) => {
+                for (i, word) in name.words().enumerate() {
+                    if i > 0 {
+                        f.write_str(" ")?;
+                    }
+                    f.write_str(word)?;
+                }
+                Ok(())
+            }
+            CharacterNameInner::Algorithmic { prefix, code_point } => {
+                write!(f, "{prefix}{code_point:04X}")
+            }
+            CharacterNameInner::Hangul(code_point) => {
+                let index = code_point - HANGUL_S_BASE;
+                let jamo = |names: &[&'static str], i: u32| {
+                    names.get(i as usize).copied().unwrap_or_default()
+                };
+                f.write_str(HANGUL_PREFIX)?;
+                f.write_str(jamo(&JAMO_L, index / HANGUL_N_COUNT))?;
+                f.write_str(jamo(&JAMO_V, index % HANGUL_N_COUNT / HANGUL_T_COUNT))?;
+                f.write_str(jamo(&JAMO_T, index % HANGUL_T_COUNT))
+            }
+        }
+    }
+}
+
+/// The type of a [name alias](https://www.unicode.org/reports/tr44/#NameAliases.txt).
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NameAliasType {
+    /// A correction of a seriously defective name, such as
+    /// `PRESENTATION FORM FOR VERTICAL RIGHT WHITE LENTICULAR BRACKET` for `U+FE18`.
+    Correction,
+    /// The ISO 6429 name of a control character, such as `LINE FEED`.
+    Control,
+    /// A widely used alternate name, such as `BYTE ORDER MARK` for `U+FEFF`.
+    Alternate,
+    /// A name of a character that was documented but never standardized, such as
+    /// `PADDING CHARACTER` for `U+0080`.
+    Figment,
+    /// An abbreviation, such as `LF` for `U+000A`.
+    Abbreviation,
+}
@@ -71,6 +71,10 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "experimental")]
+mod character_names;
+#[cfg(feature = "experimental")]
+pub use character_names::{CharacterName, CharacterNames, CharacterNamesBorrowed, NameAliasType};
 mod code_point_set;
 pub use code_point_set::{CodePointSetData, CodePointSetDataBorrowed};
 mod code_point_map;
@@ -92,6 +96,9 @@ pub mod props;
 pub mod provider;
 pub mod script;
 
+#[cfg(feature = "experimental")]
+pub mod bidi;
+#[cfg(not(feature = "experimental"))]
 mod bidi;
 mod trievalue;
 
@@ -15,6 +15,8 @@
 //!
 //! Read more about data providers: [`icu_provider`]
 
+#[cfg(feature = "experimental")]
+pub mod character_names;
 pub mod names;
 
 pub use names::{
@@ -192,6 +194,8 @@ const _: () = {
     impl_property_name_short_vertical_orientation_v1!(Baked);
     impl_property_name_short_word_break_v1!(Baked);
     impl_property_script_with_extensions_v1!(Baked);
+    #[cfg(feature = "experimental")]
+    impl_property_character_names_v1!(Baked);
 };
 
 icu_provider::data_marker!(
@@ -811,6 +815,8 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     PropertyEnumBidiMirroringGlyphV1::INFO,
     PropertyBinaryBasicEmojiV1::INFO,
     PropertyScriptWithExtensionsV1::INFO,
+    #[cfg(feature = "experimental")]
+    character_names::PropertyCharacterNamesV1::INFO,
 ];
 
 /// A set of characters which share a particular property value.
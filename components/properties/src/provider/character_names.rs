@@ -0,0 +1,173 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Unstable\] Character name data for this component
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. While the serde representation of data structs is guaranteed
+//! to be stable, their Rust representation might not be. Use with caution.
+//! </div>
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use icu_provider::prelude::*;
+use zerovec::vecs::Index32;
+use zerovec::{VarZeroVec, ZeroSlice, ZeroVec};
+
+icu_provider::data_marker!(
+    /// `PropertyCharacterNamesV1`
+    PropertyCharacterNamesV1,
+    CharacterNameTable<'static>,
+    is_singleton = true
+);
+
+/// The values of the `Name` property and the name aliases of code points, from the
+/// `UnicodeData.txt` and `NameAliases.txt` files of the Unicode Character Database.
+///
+/// Names are stored as sequences of indices into a list of the words that occur in them,
+/// such as `LATIN`, `SMALL` and `LETTER`. Names that are derived from the code point by
+/// rule NR1 (Hangul syllables) or NR2 (such as `CJK UNIFIED IDEOGRAPH-4E00`) of
+/// [Section 4.8](https://www.unicode.org/versions/latest/core-spec/chapter-4/#G135669)
+/// of the Unicode Standard are not stored.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, yoke::Yokeable, zerofrom::ZeroFrom)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties::provider::character_names))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct CharacterNameTable<'data> {
+    /// The words that occur in the stored names, sorted and without duplicates.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub words: VarZeroVec<'data, str, Index32>,
+    /// The code point of each stored name, sorted.
+    ///
+    /// A code point occurs once for its name and once for each of its aliases.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub code_points: ZeroVec<'data, char>,
+    /// Whether each stored name is the name or an alias of its code point, in the order of
+    /// [`Self::code_points`].
+    ///
+    /// The name of a code point precedes its aliases.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub kinds: ZeroVec<'data, CharacterNameKind>,
+    /// The words of each stored name as indices into [`Self::words`], in the order of
+    /// [`Self::code_points`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names: VarZeroVec<'data, ZeroSlice<u16>, Index32>,
+    /// The indices of [`Self::names`], sorted by the word indices of the names.
+    ///
+    /// This allows finding the code point of a name with a binary search.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub names_index: ZeroVec<'data, u32>,
+    /// The ranges of code points whose names are derived by rule NR2, consisting of a prefix
+    /// and the hexadecimal code point.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub algorithmic_ranges: ZeroVec<'data, (char, char)>,
+    /// The prefix of the names of each range in [`Self::algorithmic_ranges`], such as
+    /// `CJK UNIFIED IDEOGRAPH-`.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub algorithmic_prefixes: VarZeroVec<'data, str>,
+}
+
+#[cfg(feature = "alloc")]
+impl CharacterNameTable<'static> {
+    /// Builds a table from the stored names and the ranges of names derived by rule NR2.
+    ///
+    /// `names` contains the code point, kind and name of each name and alias, where the name
+    /// of a code point precedes its aliases. `algorithmic` contains the first and last code
+    /// point and the prefix of each range, such as `CJK UNIFIED IDEOGRAPH-`.
+    pub fn try_from_names(
+        names: &[(char, CharacterNameKind, &str)],
+        algorithmic: &[(char, char, &str)],
+    ) -> Result<Self, DataError> {
+        use alloc::collections::BTreeSet;
+        use alloc::vec::Vec;
+
+        let mut names = names.to_vec();
+        // Stable, so the aliases of a code point keep their order
+        names.sort_by_key(|&(ch, _, _)| ch);
+        let mut algorithmic = algorithmic.to_vec();
+        algorithmic.sort();
+
+        let words = names
+            .iter()
+            .flat_map(|(_, _, name)| name.split(' '))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        let indices = names
+            .iter()
+            .map(|(_, _, name)| {
+                name.split(' ')
+                    .map(|word| {
+                        words
+                            .binary_search(&word)
+                            .ok()
+                            .and_then(|i| u16::try_from(i).ok())
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| DataError::custom("Too many distinct words in character names"))?;
+        let mut names_index = (0..indices.len() as u32).collect::<Vec<_>>();
+        names_index.sort_by_key(|&i| indices.get(i as usize));
+
+        Ok(Self {
+            words: VarZeroVec::from(&words),
+            code_points: names.iter().map(|&(ch, _, _)| ch).collect(),
+            kinds: names.iter().map(|&(_, kind, _)| kind).collect(),
+            names: VarZeroVec::from(&indices),
+            names_index: ZeroVec::alloc_from_slice(&names_index),
+            algorithmic_ranges: algorithmic
+                .iter()
+                .map(|&(start, end, _)| (start, end))
+                .collect(),
+            algorithmic_prefixes: VarZeroVec::from(
+                &algorithmic
+                    .iter()
+                    .map(|&(_, _, prefix)| prefix)
+                    .collect::<Vec<_>>(),
+            ),
+        })
+    }
+}
+
+icu_provider::data_struct!(
+    CharacterNameTable<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+/// Whether a name in [`CharacterNameTable`] is the `Name` property value of its code point or an
+/// alias of a certain type.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(CharacterNameKindULE)]
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_properties::provider::character_names))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[repr(u8)]
+pub enum CharacterNameKind {
+    /// The `Name` property value.
+    Name = 0,
+    /// An alias of type `correction`.
+    Correction = 1,
+    /// An alias of type `control`.
+    Control = 2,
+    /// An alias of type `alternate`.
+    Alternate = 3,
+    /// An alias of type `figment`.
+    Figment = 4,
+    /// An alias of type `abbreviation`.
+    Abbreviation = 5,
+}
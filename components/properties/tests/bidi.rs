@@ -0,0 +1,196 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use icu_properties::bidi::{reorder_visual, BaseDirection, BidiLevel, BidiResolver};
+use icu_properties::props::BidiClass;
+use icu_properties::CodePointMapData;
+
+/// The expected results of one test case: the levels (`None` for removed characters) and the
+/// visual order of the characters that are not removed.
+struct Expected {
+    levels: Vec<Option<u8>>,
+    order: Vec<usize>,
+}
+
+fn parse_levels(s: &str) -> Vec<Option<u8>> {
+    s.split_whitespace()
+        .map(|l| {
+            if l == "x" {
+                None
+            } else {
+                Some(l.parse().unwrap())
+            }
+        })
+        .collect()
+}
+
+fn parse_order(s: &str) -> Vec<usize> {
+    s.split_whitespace().map(|i| i.parse().unwrap()).collect()
+}
+
+/// A character of each bidi class, as named in BidiTest.txt.
+fn representative(class: &str) -> char {
+    match class {
+        "L" => 'a',
+        "R" => '\u{05D0}',
+        "AL" => '\u{0627}',
+        "EN" => '0',
+        "ES" => '+',
+        "ET" => '#',
+        "AN" => '\u{0660}',
+        "CS" => ',',
+        "NSM" => '\u{0300}',
+        "BN" => '\u{00AD}',
+        "B" => '\u{2029}',
+        "S" => '\t',
+        "WS" => ' ',
+        "ON" => '!',
+        "LRE" => '\u{202A}',
+        "RLE" => '\u{202B}',
+        "PDF" => '\u{202C}',
+        "LRO" => '\u{202D}',
+        "RLO" => '\u{202E}',
+        "LRI" => '\u{2066}',
+        "RLI" => '\u{2067}',
+        "FSI" => '\u{2068}',
+        "PDI" => '\u{2069}',
+        _ => panic!("unknown bidi class {class}"),
+    }
+}
+
+/// Whether rule X9 removes `c`, which the test files mark with level x.
+fn is_removed(c: char) -> bool {
+    matches!(
+        CodePointMapData::<BidiClass>::new().get(c),
+        BidiClass::BoundaryNeutral
+            | BidiClass::LeftToRightEmbedding
+            | BidiClass::RightToLeftEmbedding
+            | BidiClass::PopDirectionalFormat
+            | BidiClass::LeftToRightOverride
+            | BidiClass::RightToLeftOverride
+    )
+}
+
+/// Resolves `text` and returns the levels of its chars after rule L1, or `None` for removed
+/// characters, checking that the UTF-8 and UTF-16 entry points agree.
+fn resolve(text: &[char], direction: BaseDirection) -> Vec<Option<u8>> {
+    let resolver = BidiResolver::new();
+
+    let utf8: String = text.iter().collect();
+    let info = resolver.resolve_str(&utf8, direction);
+    let mut byte_levels = Vec::new();
+    for paragraph in info.paragraphs() {
+        byte_levels.extend(info.line_levels(paragraph.range()));
+    }
+    let levels: Vec<BidiLevel> = utf8.char_indices().map(|(i, _)| byte_levels[i]).collect();
+
+    let utf16: Vec<u16> = utf8.encode_utf16().collect();
+    let info = resolver.resolve_utf16(&utf16, direction);
+    let mut unit_levels = Vec::new();
+    for paragraph in info.paragraphs() {
+        unit_levels.extend(info.line_levels(paragraph.range()));
+    }
+    let mut utf16_levels = Vec::new();
+    let mut i = 0;
+    for c in text {
+        utf16_levels.push(unit_levels[i]);
+        i += c.len_utf16();
+    }
+    assert_eq!(
+        levels, utf16_levels,
+        "UTF-8 and UTF-16 disagree on {text:?}"
+    );
+
+    text.iter()
+        .zip(levels)
+        .map(|(&c, level)| (!is_removed(c)).then_some(level.get()))
+        .collect()
+}
+
+fn check(text: &[char], direction: BaseDirection, expected: &Expected, line: &str) {
+    let levels = resolve(text, direction);
+    assert_eq!(levels, expected.levels, "levels of {line} ({direction:?})");
+
+    let (indices, visible): (Vec<usize>, Vec<BidiLevel>) = levels
+        .iter()
+        .enumerate()
+        .filter_map(|(i, level)| Some((i, BidiLevel::new((*level)?).unwrap())))
+        .unzip();
+    let order: Vec<usize> = reorder_visual(&visible)
+        .into_iter()
+        .map(|i| indices[i])
+        .collect();
+    assert_eq!(
+        order, expected.order,
+        "visual order of {line} ({direction:?})"
+    );
+}
+
+#[test]
+fn bidi_class_test() {
+    let mut expected = Expected {
+        levels: Vec::new(),
+        order: Vec::new(),
+    };
+    for line in include_str!("data/BidiTest.txt").lines() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(levels) = line.strip_prefix("@Levels:") {
+            expected.levels = parse_levels(levels);
+            continue;
+        }
+        if let Some(order) = line.strip_prefix("@Reorder:") {
+            expected.order = parse_order(order);
+            continue;
+        }
+
+        let (classes, bitset) = line.split_once(';').unwrap();
+        let text: Vec<char> = classes.split_whitespace().map(representative).collect();
+        let bitset = u8::from_str_radix(bitset.trim(), 16).unwrap();
+        for (bit, direction) in [
+            (1, BaseDirection::Auto),
+            (2, BaseDirection::LeftToRight),
+            (4, BaseDirection::RightToLeft),
+        ] {
+            if bitset & bit != 0 {
+                check(&text, direction, &expected, line);
+            }
+        }
+    }
+}
+
+#[test]
+fn bidi_character_test() {
+    for line in include_str!("data/BidiCharacterTest.txt").lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').collect();
+        let text: Vec<char> = fields[0]
+            .split_whitespace()
+            .map(|c| char::from_u32(u32::from_str_radix(c, 16).unwrap()).unwrap())
+            .collect();
+        let direction = match fields[1] {
+            "0" => BaseDirection::LeftToRight,
+            "1" => BaseDirection::RightToLeft,
+            _ => BaseDirection::Auto,
+        };
+
+        let info = BidiResolver::new().resolve_str(&text.iter().collect::<String>(), direction);
+        let paragraph_level: u8 = fields[2].parse().unwrap();
+        assert_eq!(
+            info.paragraphs()[0].level().get(),
+            paragraph_level,
+            "paragraph level of {line}"
+        );
+
+        let expected = Expected {
+            levels: parse_levels(fields[3]),
+            order: parse_order(fields[4]),
+        };
+        check(&text, direction, &expected, line);
+    }
+}
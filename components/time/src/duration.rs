@@ -0,0 +1,538 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::zone::UtcOffset;
+use crate::{DateTime, Time, ZonedDateTime};
+use icu_calendar::options::{DateAddOptions, DateDifferenceOptions};
+use icu_calendar::types::RataDie;
+use icu_calendar::{AsCalendar, Date, DateDuration, DateError, Ref};
+
+const NANOSECONDS_PER_SECOND: i128 = 1_000_000_000;
+const NANOSECONDS_PER_MINUTE: i128 = 60 * NANOSECONDS_PER_SECOND;
+const NANOSECONDS_PER_HOUR: i128 = 60 * NANOSECONDS_PER_MINUTE;
+const NANOSECONDS_PER_DAY: i128 = 24 * NANOSECONDS_PER_HOUR;
+
+/// A duration of hours, minutes, seconds, and nanoseconds.
+///
+/// Days are always 24 hours long in a [`TimeDuration`]. Calendar days, as well as weeks,
+/// months, and years, are represented by a [`DateDuration`], which is added to a [`DateTime`]
+/// together with a [`TimeDuration`].
+///
+/// ```rust
+/// use icu::calendar::{Date, DateDuration};
+/// use icu::time::{DateTime, Time, TimeDuration};
+///
+/// let mut date_time = DateTime {
+///     date: Date::try_new_iso(2024, 1, 31).unwrap(),
+///     time: Time::try_new(18, 30, 0, 0).unwrap(),
+/// };
+///
+/// // One month and eight hours later
+/// date_time
+///     .try_add_with_options(
+///         DateDuration::new(0, 1, 0, 0),
+///         TimeDuration::new(8, 0, 0, 0),
+///         Default::default(),
+///     )
+///     .unwrap();
+///
+/// assert_eq!(date_time.date, Date::try_new_iso(2024, 3, 1).unwrap());
+/// assert_eq!(date_time.time, Time::try_new(2, 30, 0, 0).unwrap());
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(clippy::exhaustive_structs)] // this type should be stable (and is intended to be constructed manually)
+#[doc(hidden)] // unstable
+pub struct TimeDuration {
+    /// The number of hours
+    pub hours: i64,
+    /// The number of minutes
+    pub minutes: i64,
+    /// The number of seconds
+    pub seconds: i64,
+    /// The number of nanoseconds
+    pub nanoseconds: i64,
+}
+
+impl TimeDuration {
+    /// Construct a [`TimeDuration`]
+    ///
+    /// ```rust
+    /// # use icu::time::TimeDuration;
+    /// // two hours, thirty minutes, and one nanosecond
+    /// let duration = TimeDuration::new(2, 30, 0, 1);
+    /// ```
+    #[inline]
+    pub const fn new(hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+        }
+    }
+
+    /// Construct a [`TimeDuration`] from a number of nanoseconds, balanced into hours,
+    /// minutes, seconds, and nanoseconds with the same sign.
+    ///
+    /// The number of hours saturates at the bounds of `i64`.
+    ///
+    /// ```rust
+    /// # use icu::time::TimeDuration;
+    /// assert_eq!(
+    ///     TimeDuration::from_nanoseconds(-5_400_000_000_001),
+    ///     TimeDuration::new(-1, -30, 0, -1)
+    /// );
+    /// assert_eq!(
+    ///     TimeDuration::from_nanoseconds(i128::MAX).hours,
+    ///     i64::MAX
+    /// );
+    /// ```
+    pub fn from_nanoseconds(nanoseconds: i128) -> Self {
+        // Truncating division keeps all fields at the sign of `nanoseconds`, and
+        // all fields except for the hours are smaller than an hour.
+        let hours = nanoseconds / NANOSECONDS_PER_HOUR;
+        Self {
+            hours: i64::try_from(hours).unwrap_or(if hours < 0 { i64::MIN } else { i64::MAX }),
+            minutes: (nanoseconds % NANOSECONDS_PER_HOUR / NANOSECONDS_PER_MINUTE) as i64,
+            seconds: (nanoseconds % NANOSECONDS_PER_MINUTE / NANOSECONDS_PER_SECOND) as i64,
+            nanoseconds: (nanoseconds % NANOSECONDS_PER_SECOND) as i64,
+        }
+    }
+
+    /// Returns the total number of nanoseconds in this duration
+    pub fn to_nanoseconds(self) -> i128 {
+        i128::from(self.hours) * NANOSECONDS_PER_HOUR
+            + i128::from(self.minutes) * NANOSECONDS_PER_MINUTE
+            + i128::from(self.seconds) * NANOSECONDS_PER_SECOND
+            + i128::from(self.nanoseconds)
+    }
+}
+
+impl Time {
+    /// Add a `duration` to this time, mutating it, and returning the number of days by which
+    /// the time rolled over
+    ///
+    /// A leap second is treated as the last second of its minute.
+    ///
+    /// ```rust
+    /// use icu::time::{Time, TimeDuration};
+    ///
+    /// let mut time = Time::try_new(22, 0, 0, 0).unwrap();
+    /// assert_eq!(time.add(TimeDuration::new(3, 0, 0, 0)), 1);
+    /// assert_eq!(time, Time::try_new(1, 0, 0, 0).unwrap());
+    ///
+    /// assert_eq!(time.add(TimeDuration::new(-49, 0, 0, 0)), -2);
+    /// assert_eq!(time, Time::try_new(0, 0, 0, 0).unwrap());
+    /// ```
+    #[doc(hidden)] // unstable
+    pub fn add(&mut self, duration: TimeDuration) -> i64 {
+        let nanoseconds =
+            i128::from(self.to_nanoseconds_since_start_of_day()) + duration.to_nanoseconds();
+        *self = Self::from_nanoseconds_since_start_of_day(
+            nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as u64,
+        );
+        // A `TimeDuration` is shorter than `i64::MAX` days
+        nanoseconds.div_euclid(NANOSECONDS_PER_DAY) as i64
+    }
+
+    /// Calculating the duration between `other - self`
+    ///
+    /// A leap second is treated as the last second of its minute.
+    #[doc(hidden)] // unstable
+    pub fn until(&self, other: &Time) -> TimeDuration {
+        TimeDuration::from_nanoseconds(
+            i128::from(other.to_nanoseconds_since_start_of_day())
+                - i128::from(self.to_nanoseconds_since_start_of_day()),
+        )
+    }
+}
+
+impl<A: AsCalendar> DateTime<A> {
+    /// Add a date and a time duration to this date and time, mutating it
+    ///
+    /// The time duration is added first, and the days by which the time rolled over are
+    /// added to the days of the date duration, which is then added to the date according
+    /// to `options`. This follows the Temporal
+    /// [`AddDateTime`](https://tc39.es/proposal-temporal/#sec-temporal-adddatetime) operation.
+    ///
+    /// If this returns an error, the date and time are left unchanged.
+    #[doc(hidden)] // unstable
+    pub fn try_add_with_options(
+        &mut self,
+        date_duration: DateDuration<A::Calendar>,
+        time_duration: TimeDuration,
+        options: DateAddOptions,
+    ) -> Result<(), DateError> {
+        add(
+            &mut self.date,
+            &mut self.time,
+            date_duration,
+            time_duration,
+            options,
+        )
+    }
+
+    /// Add a date and a time duration to this date and time, returning the new one
+    ///
+    /// See [`Self::try_add_with_options()`].
+    #[doc(hidden)] // unstable
+    pub fn try_added_with_options(
+        mut self,
+        date_duration: DateDuration<A::Calendar>,
+        time_duration: TimeDuration,
+        options: DateAddOptions,
+    ) -> Result<Self, DateError> {
+        self.try_add_with_options(date_duration, time_duration, options)?;
+        Ok(self)
+    }
+
+    /// Calculating the duration between `other - self`, such that adding it to `self`
+    /// results in `other`
+    ///
+    /// The date duration uses no units larger than [`DateDifferenceOptions::largest_unit`],
+    /// and has the same sign as the time duration, which is shorter than a day. This follows
+    /// the Temporal
+    /// [`DifferenceISODateTime`](https://tc39.es/proposal-temporal/#sec-temporal-differenceisodatetime)
+    /// operation.
+    ///
+    /// ```rust
+    /// use icu::calendar::options::DateDifferenceOptions;
+    /// use icu::calendar::{Date, DateDuration};
+    /// use icu::time::{DateTime, Time, TimeDuration};
+    ///
+    /// let start = DateTime {
+    ///     date: Date::try_new_iso(2024, 1, 1).unwrap(),
+    ///     time: Time::try_new(12, 0, 0, 0).unwrap(),
+    /// };
+    /// let end = DateTime {
+    ///     date: Date::try_new_iso(2024, 1, 3).unwrap(),
+    ///     time: Time::try_new(6, 0, 0, 0).unwrap(),
+    /// };
+    ///
+    /// let (date_duration, time_duration) =
+    ///     start.until_with_options(&end, DateDifferenceOptions::default());
+    /// assert_eq!(date_duration, DateDuration::new(0, 0, 0, 1));
+    /// assert_eq!(time_duration, TimeDuration::new(18, 0, 0, 0));
+    /// ```
+    #[doc(hidden)] // unstable
+    pub fn until_with_options<B: AsCalendar<Calendar = A::Calendar>>(
+        &self,
+        other: &DateTime<B>,
+        options: DateDifferenceOptions,
+    ) -> (DateDuration<A::Calendar>, TimeDuration) {
+        until(
+            &self.date,
+            self.time,
+            other.date.to_rata_die(),
+            other.time,
+            options,
+        )
+    }
+}
+
+impl<A: AsCalendar> ZonedDateTime<A, UtcOffset> {
+    /// Add a date and a time duration to this date and time, mutating it
+    ///
+    /// The date and time are local to the UTC offset, which stays the same. See
+    /// [`DateTime::try_add_with_options()`].
+    #[doc(hidden)] // unstable
+    pub fn try_add_with_options(
+        &mut self,
+        date_duration: DateDuration<A::Calendar>,
+        time_duration: TimeDuration,
+        options: DateAddOptions,
+    ) -> Result<(), DateError> {
+        add(
+            &mut self.date,
+            &mut self.time,
+            date_duration,
+            time_duration,
+            options,
+        )
+    }
+
+    /// Add a date and a time duration to this date and time, returning the new one
+    ///
+    /// See [`Self::try_add_with_options()`].
+    #[doc(hidden)] // unstable
+    pub fn try_added_with_options(
+        mut self,
+        date_duration: DateDuration<A::Calendar>,
+        time_duration: TimeDuration,
+        options: DateAddOptions,
+    ) -> Result<Self, DateError> {
+        self.try_add_with_options(date_duration, time_duration, options)?;
+        Ok(self)
+    }
+
+    /// Calculating the duration between `other - self`, such that adding it to `self`
+    /// results in `other`
+    ///
+    /// `other` is first converted to the UTC offset of `self`. See
+    /// [`DateTime::until_with_options()`], and [`Instant::until()`](crate::Instant::until)
+    /// for the exact time between two instants.
+    ///
+    /// ```rust
+    /// use icu::calendar::options::DateDifferenceOptions;
+    /// use icu::calendar::{Date, DateDuration};
+    /// use icu::time::zone::UtcOffset;
+    /// use icu::time::{TimeDuration, ZonedDateTime};
+    ///
+    /// let start = ZonedDateTime::try_offset_only_from_str(
+    ///     "2024-03-30T23:00+01:00",
+    ///     icu::calendar::Iso,
+    /// )
+    /// .unwrap();
+    /// let end = ZonedDateTime::try_offset_only_from_str(
+    ///     "2024-03-31T23:00+02:00",
+    ///     icu::calendar::Iso,
+    /// )
+    /// .unwrap();
+    ///
+    /// let (date_duration, time_duration) =
+    ///     start.until_with_options(&end, DateDifferenceOptions::default());
+    /// assert_eq!(date_duration, DateDuration::new(0, 0, 0, 0));
+    /// assert_eq!(time_duration, TimeDuration::new(23, 0, 0, 0));
+    /// ```
+    #[doc(hidden)] // unstable
+    pub fn until_with_options<B: AsCalendar<Calendar = A::Calendar>>(
+        &self,
+        other: &ZonedDateTime<B, UtcOffset>,
+        options: DateDifferenceOptions,
+    ) -> (DateDuration<A::Calendar>, TimeDuration) {
+        let mut other_time = other.time;
+        let days = other_time.add(TimeDuration::new(
+            0,
+            0,
+            i64::from(self.zone.to_seconds() - other.zone.to_seconds()),
+            0,
+        ));
+        until(
+            &self.date,
+            self.time,
+            other.date.to_rata_die() + days,
+            other_time,
+            options,
+        )
+    }
+}
+
+/// Implementation of [`DateTime::try_add_with_options()`].
+fn add<A: AsCalendar>(
+    date: &mut Date<A>,
+    time: &mut Time,
+    mut date_duration: DateDuration<A::Calendar>,
+    time_duration: TimeDuration,
+    options: DateAddOptions,
+) -> Result<(), DateError> {
+    let mut new_time = *time;
+    let days = i64::from(date_duration.days) + new_time.add(time_duration);
+    date_duration.days = i32::try_from(days).map_err(|_| DateError::Range {
+        field: "days",
+        value: if days < 0 { i32::MIN } else { i32::MAX },
+        min: i32::MIN,
+        max: i32::MAX,
+    })?;
+    date.try_add_with_options(date_duration, options)?;
+    *time = new_time;
+    Ok(())
+}
+
+/// Implementation of [`DateTime::until_with_options()`].
+fn until<A: AsCalendar>(
+    date: &Date<A>,
+    time: Time,
+    mut other_date: RataDie,
+    other_time: Time,
+    options: DateDifferenceOptions,
+) -> (DateDuration<A::Calendar>, TimeDuration) {
+    let mut nanoseconds = time.until(&other_time).to_nanoseconds();
+    // Balance the time duration into the date duration if their signs differ
+    let time_sign = nanoseconds.signum();
+    if time_sign == date.to_rata_die().cmp(&other_date) as i128 {
+        other_date += time_sign as i64;
+        nanoseconds -= time_sign * NANOSECONDS_PER_DAY;
+    }
+    (
+        date.until_with_options(
+            &Date::from_rata_die(other_date, Ref(date.calendar())),
+            options,
+        ),
+        TimeDuration::from_nanoseconds(nanoseconds),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_calendar::cal::Hebrew;
+    use icu_calendar::options::Overflow;
+    use icu_calendar::{DateDurationUnit, Iso};
+
+    fn date_time(year: i32, month: u8, day: u8, hour: u8, minute: u8) -> DateTime<Iso> {
+        DateTime {
+            date: Date::try_new_iso(year, month, day).unwrap(),
+            time: Time::try_new(hour, minute, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_time_add() {
+        let mut time = Time::try_new(23, 59, 59, 999_999_999).unwrap();
+        assert_eq!(time.add(TimeDuration::new(0, 0, 0, 1)), 1);
+        assert_eq!(time, Time::start_of_day());
+        assert_eq!(time.add(TimeDuration::new(0, 0, 0, -1)), -1);
+        assert_eq!(time, Time::try_new(23, 59, 59, 999_999_999).unwrap());
+        assert_eq!(time.add(TimeDuration::new(0, 0, 0, 0)), 0);
+
+        let mut leap_second = Time::try_new(23, 59, 60, 500_000_000).unwrap();
+        assert_eq!(leap_second.add(TimeDuration::new(0, 0, 0, 500_000_000)), 1);
+        assert_eq!(leap_second, Time::start_of_day());
+
+        assert_eq!(
+            Time::try_new(18, 0, 0, 0)
+                .unwrap()
+                .until(&Time::try_new(6, 30, 0, 0).unwrap()),
+            TimeDuration::new(-11, -30, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_date_time_add() {
+        let reject = {
+            let mut options = DateAddOptions::default();
+            options.overflow = Overflow::Reject;
+            options
+        };
+
+        // The rolled over day is added after the month is constrained
+        let start = date_time(2024, 1, 31, 12, 0);
+        assert_eq!(
+            start.try_added_with_options(
+                DateDuration::new(0, 1, 0, 0),
+                TimeDuration::new(12, 0, 0, 0),
+                Default::default()
+            ),
+            Ok(date_time(2024, 3, 1, 0, 0))
+        );
+        assert!(start
+            .try_added_with_options(
+                DateDuration::new(0, 1, 0, 0),
+                TimeDuration::new(12, 0, 0, 0),
+                reject
+            )
+            .is_err());
+        assert_eq!(
+            start.try_added_with_options(
+                DateDuration::new(0, 0, 0, 1),
+                TimeDuration::new(-36, 0, 0, 0),
+                reject
+            ),
+            Ok(date_time(2024, 1, 31, 0, 0))
+        );
+
+        let mut date_time = start;
+        assert!(matches!(
+            date_time.try_add_with_options(
+                DateDuration::new(0, 0, 0, i32::MAX),
+                TimeDuration::new(24, 0, 0, 0),
+                Default::default()
+            ),
+            Err(DateError::Range { field: "days", .. })
+        ));
+        assert_eq!(date_time, start);
+    }
+
+    #[test]
+    fn test_date_time_until() {
+        let date_times = [
+            date_time(2020, 2, 29, 0, 0),
+            date_time(2020, 2, 29, 23, 59),
+            date_time(2021, 2, 28, 12, 0),
+            date_time(2021, 3, 1, 6, 0),
+            date_time(2024, 1, 31, 18, 30),
+            date_time(2024, 3, 1, 0, 0),
+        ];
+        for largest_unit in [
+            DateDurationUnit::Years,
+            DateDurationUnit::Months,
+            DateDurationUnit::Weeks,
+            DateDurationUnit::Days,
+        ] {
+            let mut options = DateDifferenceOptions::default();
+            options.largest_unit = Some(largest_unit);
+            for start in date_times {
+                for end in date_times {
+                    let (date_duration, time_duration) = start.until_with_options(&end, options);
+                    assert!(time_duration.to_nanoseconds().abs() < NANOSECONDS_PER_DAY);
+                    assert_eq!(
+                        start.try_added_with_options(
+                            date_duration,
+                            time_duration,
+                            Default::default()
+                        ),
+                        Ok(end),
+                        "{start:?} + {date_duration:?} + {time_duration:?}"
+                    );
+                }
+            }
+        }
+
+        // In a non-ISO calendar
+        let start = DateTime {
+            date: Date::try_new_iso(2024, 2, 10).unwrap().to_calendar(Hebrew),
+            time: Time::try_new(20, 0, 0, 0).unwrap(),
+        };
+        let end = DateTime {
+            date: Date::try_new_iso(2025, 3, 12).unwrap().to_calendar(Hebrew),
+            time: Time::try_new(8, 0, 0, 0).unwrap(),
+        };
+        let mut options = DateDifferenceOptions::default();
+        options.largest_unit = Some(DateDurationUnit::Months);
+        assert_eq!(
+            start.until_with_options(&end, options),
+            (
+                DateDuration::new(0, 13, 0, 10),
+                TimeDuration::new(12, 0, 0, 0)
+            )
+        );
+    }
+
+    #[test]
+    fn test_zoned_date_time() {
+        let start = ZonedDateTime {
+            date: Date::try_new_iso(2024, 12, 31).unwrap(),
+            time: Time::try_new(23, 0, 0, 0).unwrap(),
+            zone: UtcOffset::try_from_seconds(-5 * 3600).unwrap(),
+        };
+        let end = ZonedDateTime {
+            date: Date::try_new_iso(2025, 1, 1).unwrap(),
+            time: Time::try_new(6, 0, 0, 0).unwrap(),
+            zone: UtcOffset::try_from_seconds(9 * 3600).unwrap(),
+        };
+
+        // 06:00+09:00 is 16:00-05:00 on the previous day
+        let (date_duration, time_duration) = start.until_with_options(&end, Default::default());
+        assert_eq!(date_duration, DateDuration::new(0, 0, 0, 0));
+        assert_eq!(time_duration, TimeDuration::new(-7, 0, 0, 0));
+        assert_eq!(
+            start.to_instant().unwrap().until(end.to_instant().unwrap()),
+            time_duration
+        );
+
+        let added = start
+            .try_added_with_options(date_duration, time_duration, Default::default())
+            .unwrap();
+        assert_eq!(added.zone, start.zone);
+        assert_eq!(added.to_instant(), end.to_instant());
+        assert_eq!(
+            start
+                .to_instant()
+                .unwrap()
+                .try_added(time_duration)
+                .unwrap(),
+            end.to_instant().unwrap()
+        );
+    }
+}
@@ -0,0 +1,215 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::duration::TimeDuration;
+use crate::zone::UtcOffset;
+use crate::{Time, ZonedDateTime};
+use calendrical_calculations::rata_die::RataDie;
+use displaydoc::Display;
+use icu_calendar::{AsCalendar, Date, Iso};
+
+const UNIX_EPOCH: RataDie = calendrical_calculations::iso::const_fixed_from_iso(1970, 1, 1);
+const NANOSECONDS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// The largest number of days before or after the UNIX epoch that an [`Instant`] can represent.
+const MAX_EPOCH_DAYS: i128 = 100_000_000;
+
+/// The instant was out of range. Must be within ±100,000,000 days of the UNIX epoch.
+#[derive(Display, Debug, Copy, Clone, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct InvalidInstantError;
+
+impl core::error::Error for InvalidInstantError {}
+
+/// An exact point in time, independent of any calendar or time zone.
+///
+/// This is represented as the number of nanoseconds since the UNIX epoch,
+/// 1970-01-01T00:00:00Z, ignoring leap seconds. Like the Temporal
+/// [`Instant`](https://tc39.es/proposal-temporal/#sec-temporal-instant-objects),
+/// it supports ±100,000,000 days around the epoch.
+///
+/// # Examples
+///
+/// ```
+/// use icu::calendar::Date;
+/// use icu::time::zone::UtcOffset;
+/// use icu::time::{Instant, Time, ZonedDateTime};
+///
+/// let instant = Instant::try_from_epoch_milliseconds(1746060300000).unwrap();
+///
+/// let offset = UtcOffset::try_from_seconds(-7 * 3600).unwrap();
+/// let zoned_date_time =
+///     ZonedDateTime::from_instant_and_utc_offset(instant, offset);
+/// assert_eq!(zoned_date_time.date, Date::try_new_iso(2025, 4, 30).unwrap());
+/// assert_eq!(zoned_date_time.time, Time::try_new(17, 45, 0, 0).unwrap());
+///
+/// assert_eq!(zoned_date_time.to_instant(), Ok(instant));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant(i128);
+
+impl Instant {
+    /// The UNIX epoch, 1970-01-01T00:00:00Z.
+    pub const UNIX_EPOCH: Self = Self(0);
+
+    /// Creates an [`Instant`] from a number of nanoseconds since the UNIX epoch.
+    ///
+    /// Returns [`InvalidInstantError`] if the instant is out of range.
+    pub fn try_from_epoch_nanoseconds(
+        epoch_nanoseconds: i128,
+    ) -> Result<Self, InvalidInstantError> {
+        if epoch_nanoseconds.unsigned_abs() > (MAX_EPOCH_DAYS * NANOSECONDS_PER_DAY) as u128 {
+            Err(InvalidInstantError)
+        } else {
+            Ok(Self(epoch_nanoseconds))
+        }
+    }
+
+    /// Creates an [`Instant`] from a number of milliseconds since the UNIX epoch.
+    ///
+    /// Returns [`InvalidInstantError`] if the instant is out of range.
+    pub fn try_from_epoch_milliseconds(
+        epoch_milliseconds: i64,
+    ) -> Result<Self, InvalidInstantError> {
+        Self::try_from_epoch_nanoseconds(i128::from(epoch_milliseconds) * 1_000_000)
+    }
+
+    /// Returns the number of nanoseconds since the UNIX epoch.
+    #[inline]
+    pub fn epoch_nanoseconds(self) -> i128 {
+        self.0
+    }
+
+    /// Returns the number of whole milliseconds since the UNIX epoch, rounding towards
+    /// negative infinity.
+    #[inline]
+    pub fn epoch_milliseconds(self) -> i64 {
+        // The range of `Instant` fits into an `i64` of milliseconds
+        self.0.div_euclid(1_000_000) as i64
+    }
+
+    /// Adds a [`TimeDuration`] to this instant, returning the new one.
+    ///
+    /// Returns [`InvalidInstantError`] if the result is out of range.
+    #[doc(hidden)] // unstable
+    pub fn try_added(self, duration: TimeDuration) -> Result<Self, InvalidInstantError> {
+        Self::try_from_epoch_nanoseconds(self.0 + duration.to_nanoseconds())
+    }
+
+    /// Calculating the duration between `other - self`
+    #[doc(hidden)] // unstable
+    pub fn until(self, other: Self) -> TimeDuration {
+        TimeDuration::from_nanoseconds(other.0 - self.0)
+    }
+}
+
+impl ZonedDateTime<Iso, UtcOffset> {
+    /// Creates a [`ZonedDateTime`] from an [`Instant`] and a UTC offset.
+    ///
+    /// This constructor returns a [`ZonedDateTime`] that supports only the localized offset
+    /// time zone style. See also [`Self::from_epoch_milliseconds_and_utc_offset()`].
+    #[doc(hidden)] // unstable
+    pub fn from_instant_and_utc_offset(instant: Instant, utc_offset: UtcOffset) -> Self {
+        let local_nanoseconds = instant.0 + i128::from(utc_offset.to_seconds()) * 1_000_000_000;
+        // The range of `Instant` fits into an `i64` of days
+        let epoch_days = local_nanoseconds.div_euclid(NANOSECONDS_PER_DAY) as i64;
+        let time = Time::from_nanoseconds_since_start_of_day(
+            local_nanoseconds.rem_euclid(NANOSECONDS_PER_DAY) as u64,
+        );
+        ZonedDateTime {
+            date: Date::from_rata_die(UNIX_EPOCH + epoch_days, Iso),
+            time,
+            zone: utc_offset,
+        }
+    }
+}
+
+impl<A: AsCalendar> ZonedDateTime<A, UtcOffset> {
+    /// Returns the [`Instant`] of this date and time in its UTC offset.
+    ///
+    /// For a [`ZonedDateTime`] with a [`TimeZoneInfo`](crate::TimeZoneInfo), construct a
+    /// [`ZonedDateTime`] with its [`offset`](crate::TimeZoneInfo::offset) first.
+    ///
+    /// Returns [`InvalidInstantError`] if the instant is out of range.
+    #[doc(hidden)] // unstable
+    pub fn to_instant(&self) -> Result<Instant, InvalidInstantError> {
+        let epoch_days = i128::from(self.date.to_rata_die() - UNIX_EPOCH);
+        Instant::try_from_epoch_nanoseconds(
+            epoch_days * NANOSECONDS_PER_DAY
+                + i128::from(self.time.to_nanoseconds_since_start_of_day())
+                - i128::from(self.zone.to_seconds()) * 1_000_000_000,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let max = MAX_EPOCH_DAYS * NANOSECONDS_PER_DAY;
+        assert!(Instant::try_from_epoch_nanoseconds(max).is_ok());
+        assert!(Instant::try_from_epoch_nanoseconds(-max).is_ok());
+        assert_eq!(
+            Instant::try_from_epoch_nanoseconds(max + 1),
+            Err(InvalidInstantError)
+        );
+        assert_eq!(
+            Instant::try_from_epoch_nanoseconds(-max - 1),
+            Err(InvalidInstantError)
+        );
+        assert_eq!(
+            Instant::try_from_epoch_milliseconds(i64::MIN),
+            Err(InvalidInstantError)
+        );
+
+        // -271821-04-20T00:00:00Z and +275760-09-13T00:00:00Z
+        let utc = UtcOffset::zero();
+        let min = ZonedDateTime::from_instant_and_utc_offset(Instant(-max), utc);
+        assert_eq!(min.date, Date::try_new_iso(-271821, 4, 20).unwrap());
+        assert_eq!(min.to_instant(), Ok(Instant(-max)));
+        let max = ZonedDateTime::from_instant_and_utc_offset(Instant(max), utc);
+        assert_eq!(max.date, Date::try_new_iso(275760, 9, 13).unwrap());
+        assert_eq!(max.time, Time::start_of_day());
+
+        let mut after_max = max;
+        after_max.time.subsecond = 1u32.try_into().unwrap();
+        assert_eq!(after_max.to_instant(), Err(InvalidInstantError));
+    }
+
+    #[test]
+    fn test_epoch_milliseconds() {
+        for epoch_milliseconds in [0, 1, -1, 1746060300000, -1577847354750] {
+            let instant = Instant::try_from_epoch_milliseconds(epoch_milliseconds).unwrap();
+            assert_eq!(instant.epoch_milliseconds(), epoch_milliseconds);
+
+            for offset_seconds in [0, 3600, -25200, 20700] {
+                let offset = UtcOffset::try_from_seconds(offset_seconds).unwrap();
+                let zoned_date_time = ZonedDateTime::from_instant_and_utc_offset(instant, offset);
+                assert_eq!(
+                    zoned_date_time,
+                    ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+                        epoch_milliseconds,
+                        offset
+                    )
+                );
+                assert_eq!(zoned_date_time.to_instant(), Ok(instant));
+            }
+        }
+
+        let instant = Instant::try_from_epoch_nanoseconds(-1).unwrap();
+        assert_eq!(instant.epoch_milliseconds(), -1);
+        let zoned_date_time =
+            ZonedDateTime::from_instant_and_utc_offset(instant, UtcOffset::zero());
+        assert_eq!(
+            zoned_date_time.date,
+            Date::try_new_iso(1969, 12, 31).unwrap()
+        );
+        assert_eq!(
+            zoned_date_time.time,
+            Time::try_new(23, 59, 59, 999_999_999).unwrap()
+        );
+    }
+}
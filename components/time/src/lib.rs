@@ -39,5 +39,12 @@ pub mod zone;
 #[doc(no_inline)]
 pub use zone::{TimeZone, TimeZoneInfo};
 
+mod duration;
+pub use duration::TimeDuration;
+
+mod instant;
+#[doc(hidden)] // unstable
+pub use instant::{Instant, InvalidInstantError};
+
 mod types;
 pub use types::{DateTime, Hour, Minute, Nanosecond, Second, Time, ZonedDateTime};
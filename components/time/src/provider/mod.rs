@@ -25,6 +25,8 @@ use zerovec::{ZeroMap2d, ZeroSlice, ZeroVec};
 pub use crate::zone::ule::TimeZoneVariantULE;
 pub use crate::zone::TimeZone;
 pub mod iana;
+#[cfg(feature = "experimental")]
+pub mod transitions;
 pub mod windows;
 
 #[cfg(feature = "compiled_data")]
@@ -50,6 +52,8 @@ const _: () = {
     impl_timezone_identifiers_iana_core_v1!(Baked);
     impl_timezone_identifiers_windows_v1!(Baked);
     impl_timezone_variants_offsets_v1!(Baked);
+    #[cfg(feature = "experimental")]
+    impl_timezone_transitions_v1!(Baked);
 };
 
 #[cfg(feature = "datagen")]
@@ -59,6 +63,8 @@ pub const MARKERS: &[DataMarkerInfo] = &[
     iana::TimezoneIdentifiersIanaCoreV1::INFO,
     windows::TimezoneIdentifiersWindowsV1::INFO,
     TimezoneVariantsOffsetsV1::INFO,
+    #[cfg(feature = "experimental")]
+    transitions::TimezoneTransitionsV1::INFO,
 ];
 
 const SECONDS_TO_EIGHTS_OF_HOURS: i32 = 60 * 60 / 8;
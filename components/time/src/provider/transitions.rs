@@ -0,0 +1,156 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! 🚧 \[Unstable\] Time zone transition data for this component
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. While the serde representation of data structs is guaranteed
+//! to be stable, their Rust representation might not be. Use with caution.
+//! </div>
+//!
+//! Read more about data providers: [`icu_provider`]
+
+use crate::zone::TimeZoneVariant;
+use crate::TimeZone;
+use icu_provider::prelude::*;
+use zerovec::{ZeroMap, ZeroVec};
+
+icu_provider::data_marker!(
+    /// See [`TimeZoneTransitions`]
+    TimezoneTransitionsV1,
+    "timezone/transitions/v1",
+    TimeZoneTransitions<'static>,
+    is_singleton = true
+);
+
+/// The UTC offset transitions of each time zone, as compiled from the time zone database.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[derive(Debug, Clone, PartialEq, zerofrom::ZeroFrom, yoke::Yokeable)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider::transitions))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[yoke(prove_covariance_manually)]
+pub struct TimeZoneTransitions<'data> {
+    /// The explicit transitions of all time zones, as pairs of the instant, in seconds since
+    /// the UNIX epoch, from which an offset applies, and that offset.
+    ///
+    /// The transitions of a time zone are contiguous and sorted by instant; the first one is
+    /// at [`i64::MIN`], giving the offset that applies before any recorded transition.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transitions: ZeroVec<'data, (i64, TransitionOffset)>,
+    /// The start and end indices of the transitions of each time zone in [`Self::transitions`].
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub transition_ranges: ZeroMap<'data, TimeZone, (u32, u32)>,
+    /// The recurring rule of time zones that observe daylight saving time indefinitely.
+    ///
+    /// The rule applies from [`ZoneRule::start_year`] on; the last explicit transition
+    /// of a time zone precedes the first transition produced by its rule.
+    #[cfg_attr(feature = "serde", serde(borrow))]
+    pub rules: ZeroMap<'data, TimeZone, ZoneRule>,
+}
+
+icu_provider::data_struct!(
+    TimeZoneTransitions<'_>,
+    #[cfg(feature = "datagen")]
+);
+
+/// The offset in effect after a transition.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(TransitionOffsetULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider::transitions))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct TransitionOffset {
+    /// The total UTC offset, in seconds.
+    pub offset_seconds: i32,
+    /// Whether the offset is standard or daylight time.
+    pub variant: TimeZoneVariant,
+}
+
+/// A recurring pair of yearly transitions into and out of daylight saving time.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(ZoneRuleULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider::transitions))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct ZoneRule {
+    /// The first year in which the rule produces transitions.
+    pub start_year: i32,
+    /// The standard UTC offset, in seconds.
+    pub standard_offset_seconds: i32,
+    /// The amount added to the standard offset during daylight time, in seconds.
+    pub daylight_savings_seconds: i32,
+    /// The transition into daylight time.
+    pub start: TransitionRule,
+    /// The transition back into standard time.
+    pub end: TransitionRule,
+}
+
+/// The date and time of a yearly transition, in the style of tzdb `Rule` lines.
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(TransitionRuleULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider::transitions))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct TransitionRule {
+    /// The month, 1-based.
+    pub month: u8,
+    /// The day of the month, or 0 for the last day of the month.
+    pub day: u8,
+    /// The ISO weekday (1 = Monday, 7 = Sunday) to search for, starting at `day`,
+    /// or 0 if the transition happens on `day` itself.
+    pub weekday: u8,
+    /// Whether the weekday search goes forward from `day`, instead of backward.
+    pub on_or_after: bool,
+    /// The time of the transition, in seconds since midnight.
+    pub time_seconds: i32,
+    /// How `time_seconds` is to be interpreted.
+    pub time_kind: TransitionTimeKind,
+}
+
+/// The reference of the time of a [`TransitionRule`].
+///
+/// <div class="stab unstable">
+/// 🚧 This code is considered unstable; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. While the serde representation of data structs is guaranteed
+/// to be stable, their Rust representation might not be. Use with caution.
+/// </div>
+#[zerovec::make_ule(TransitionTimeKindULE)]
+#[derive(Copy, Clone, PartialEq, PartialOrd, Eq, Ord, Hash, Debug)]
+#[cfg_attr(feature = "datagen", derive(serde::Serialize, databake::Bake))]
+#[cfg_attr(feature = "datagen", databake(path = icu_time::provider::transitions))]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[repr(u8)]
+pub enum TransitionTimeKind {
+    /// Local wall-clock time, i.e. in the offset in effect before the transition.
+    Wall = 0,
+    /// Local standard time.
+    Standard = 1,
+    /// UTC.
+    Utc = 2,
+}
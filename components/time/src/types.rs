@@ -179,6 +179,27 @@ impl Time {
             subsecond: nanosecond.try_into()?,
         })
     }
+
+    /// Returns the number of nanoseconds since the start of the day, treating a leap second
+    /// as the last second of its minute.
+    pub(crate) fn to_nanoseconds_since_start_of_day(self) -> u64 {
+        ((u64::from(self.hour.0) * 60 + u64::from(self.minute.0)) * 60
+            + u64::from(self.second.0.min(59)))
+            * 1_000_000_000
+            + u64::from(self.subsecond.0)
+    }
+
+    /// Construct a new [`Time`] from a number of nanoseconds since the start of the day,
+    /// which must be less than a day.
+    pub(crate) fn from_nanoseconds_since_start_of_day(nanoseconds: u64) -> Self {
+        debug_assert!(nanoseconds < 86_400_000_000_000);
+        Self {
+            hour: Hour((nanoseconds / 3_600_000_000_000) as u8),
+            minute: Minute((nanoseconds / 60_000_000_000 % 60) as u8),
+            second: Second((nanoseconds / 1_000_000_000 % 60) as u8),
+            subsecond: Nanosecond((nanoseconds % 1_000_000_000) as u32),
+        }
+    }
 }
 
 /// A date and time for a given calendar.
@@ -59,9 +59,14 @@
 //!
 //! This crate does not ship time zone offset information. Other Rust crates such as [`chrono_tz`](https://docs.rs/chrono-tz) or [`jiff`](https://docs.rs/jiff)
 //! are available for this purpose. See our [`example`](https://github.com/unicode-org/icu4x/blob/main/components/icu/examples/chrono_jiff.rs).
+//!
+//! Alternatively, the experimental `rules` module (✨ *enabled with the `experimental` Cargo feature*) resolves
+//! offsets from time zone database transition data, which can be generated with `--tzdb-root` in datagen.
 
 pub mod iana;
 mod offset;
+#[cfg(feature = "experimental")]
+pub mod rules;
 pub mod windows;
 mod zone_name_timestamp;
 
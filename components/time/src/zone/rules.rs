@@ -0,0 +1,793 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Conversion between instants and local times using time zone transition data.
+//!
+//! ✨ *Enabled with the `experimental` Cargo feature.*
+//!
+//! <div class="stab unstable">
+//! 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+//! including in SemVer minor releases. Use with caution.
+//! </div>
+
+use crate::provider::transitions::{
+    TimeZoneTransitions, TimezoneTransitionsV1, TransitionOffset, TransitionRule,
+    TransitionTimeKind, ZoneRule,
+};
+use crate::zone::{models, TimeZoneInfo, TimeZoneVariant, UtcOffset};
+use crate::{DateTime, Time, TimeZone, ZonedDateTime};
+use calendrical_calculations::iso;
+use calendrical_calculations::rata_die::RataDie;
+use icu_calendar::{AsCalendar, Iso};
+use icu_provider::prelude::*;
+
+const UNIX_EPOCH: RataDie = iso::const_fixed_from_iso(1970, 1, 1);
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// How to resolve a local time that does not correspond to exactly one instant.
+///
+/// Around a transition, local times either repeat (when the offset decreases, such as
+/// at the end of daylight saving time) or are skipped (when the offset increases).
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Disambiguation {
+    /// Use the earlier of the two instants of a repeated local time.
+    ///
+    /// A skipped local time is interpreted in the offset in effect after the transition,
+    /// which results in an instant before the transition.
+    Earlier,
+    /// Use the later of the two instants of a repeated local time.
+    ///
+    /// A skipped local time is interpreted in the offset in effect before the transition,
+    /// which results in an instant after the transition.
+    Later,
+    /// Return an error for repeated and skipped local times.
+    Reject,
+}
+
+/// The error type for resolving local times with [`TimeZoneRulesBorrowed`].
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Copy, PartialEq, Eq, displaydoc::Display)]
+#[non_exhaustive]
+pub enum LocalTimeError {
+    /// There is no transition data for the time zone.
+    #[displaydoc("No transition data for time zone {0:?}")]
+    UnknownTimeZone(TimeZone),
+    /// The local time is skipped by a transition, and [`Disambiguation::Reject`] was requested.
+    #[displaydoc("The local time does not exist in the time zone")]
+    Skipped,
+    /// The local time is repeated by a transition, and [`Disambiguation::Reject`] was requested.
+    #[displaydoc("The local time is ambiguous in the time zone")]
+    Ambiguous,
+}
+
+impl core::error::Error for LocalTimeError {}
+
+/// The UTC offset in effect in a time zone at a given instant.
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ZoneOffset {
+    /// The UTC offset.
+    pub offset: UtcOffset,
+    /// Whether the offset is standard or daylight time.
+    pub variant: TimeZoneVariant,
+}
+
+impl From<TransitionOffset> for ZoneOffset {
+    fn from(other: TransitionOffset) -> Self {
+        Self {
+            offset: UtcOffset::from_seconds_unchecked(other.offset_seconds),
+            variant: other.variant,
+        }
+    }
+}
+
+/// [`TimeZoneRules`] converts between instants and local times using the transitions
+/// of the time zone database.
+///
+/// The data for this type is generated from the time zone database (`--tzdb-root` in datagen).
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug)]
+pub struct TimeZoneRules {
+    transitions: DataPayload<TimezoneTransitionsV1>,
+}
+
+/// The borrowed version of a [`TimeZoneRules`].
+///
+/// ✨ *Enabled with the `experimental` Cargo feature.*
+///
+/// <div class="stab unstable">
+/// 🚧 This code is experimental; it may change at any time, in breaking or non-breaking ways,
+/// including in SemVer minor releases. Use with caution.
+/// </div>
+#[derive(Debug)]
+pub struct TimeZoneRulesBorrowed<'a> {
+    transitions: &'a TimeZoneTransitions<'a>,
+}
+
+#[cfg(feature = "compiled_data")]
+impl Default for TimeZoneRulesBorrowed<'static> {
+    fn default() -> Self {
+        TimeZoneRules::new()
+    }
+}
+
+impl TimeZoneRules {
+    /// Constructs a `TimeZoneRules` using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    #[expect(clippy::new_ret_no_self)]
+    pub const fn new() -> TimeZoneRulesBorrowed<'static> {
+        TimeZoneRulesBorrowed::new()
+    }
+
+    icu_provider::gen_buffer_data_constructors!(() -> error: DataError,
+        functions: [
+            new: skip,
+            try_new_with_buffer_provider,
+            try_new_unstable,
+            Self,
+        ]
+    );
+
+    #[doc = icu_provider::gen_buffer_unstable_docs!(UNSTABLE, Self::new)]
+    pub fn try_new_unstable(
+        provider: &(impl DataProvider<TimezoneTransitionsV1> + ?Sized),
+    ) -> Result<Self, DataError> {
+        let transitions = provider.load(Default::default())?.payload;
+        Ok(Self { transitions })
+    }
+
+    /// Returns a borrowed version of the rules that can be queried.
+    ///
+    /// This avoids a small potential indirection cost when querying.
+    pub fn as_borrowed(&self) -> TimeZoneRulesBorrowed {
+        TimeZoneRulesBorrowed {
+            transitions: self.transitions.get(),
+        }
+    }
+}
+
+impl TimeZoneRulesBorrowed<'static> {
+    /// Constructs a `TimeZoneRulesBorrowed` using compiled data.
+    ///
+    /// ✨ *Enabled with the `compiled_data` Cargo feature.*
+    ///
+    /// [📚 Help choosing a constructor](icu_provider::constructors)
+    #[cfg(feature = "compiled_data")]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            transitions: crate::provider::Baked::SINGLETON_TIMEZONE_TRANSITIONS_V1,
+        }
+    }
+
+    /// Cheaply converts a [`TimeZoneRulesBorrowed<'static>`] into a [`TimeZoneRules`].
+    ///
+    /// Note: Due to branching and indirection, using [`TimeZoneRules`] might inhibit some
+    /// compile-time optimizations that are possible with [`TimeZoneRulesBorrowed`].
+    pub fn static_to_owned(&self) -> TimeZoneRules {
+        TimeZoneRules {
+            transitions: DataPayload::from_static_ref(self.transitions),
+        }
+    }
+}
+
+impl TimeZoneRulesBorrowed<'_> {
+    /// Returns the UTC offset in effect in the time zone at the given instant, in
+    /// milliseconds since the UNIX epoch.
+    ///
+    /// Returns `None` if there is no transition data for the time zone.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::locale::subtags::subtag;
+    /// use icu::time::zone::rules::TimeZoneRules;
+    /// use icu::time::zone::TimeZoneVariant;
+    /// use icu::time::TimeZone;
+    ///
+    /// let rules = TimeZoneRules::new();
+    ///
+    /// // 2025-01-15T12:00:00Z
+    /// let offset = rules
+    ///     .offset_for_epoch_milliseconds(TimeZone(subtag!("usnyc")), 1736942400000)
+    ///     .unwrap();
+    /// assert_eq!(offset.offset, "-05:00".parse().unwrap());
+    /// assert_eq!(offset.variant, TimeZoneVariant::Standard);
+    ///
+    /// // 2025-07-15T12:00:00Z
+    /// let offset = rules
+    ///     .offset_for_epoch_milliseconds(TimeZone(subtag!("usnyc")), 1752580800000)
+    ///     .unwrap();
+    /// assert_eq!(offset.offset, "-04:00".parse().unwrap());
+    /// assert_eq!(offset.variant, TimeZoneVariant::Daylight);
+    /// ```
+    pub fn offset_for_epoch_milliseconds(
+        &self,
+        time_zone: TimeZone,
+        epoch_milliseconds: i64,
+    ) -> Option<ZoneOffset> {
+        self.transition_offset(time_zone, epoch_milliseconds.div_euclid(1000))
+            .map(Into::into)
+    }
+
+    /// Creates a [`ZonedDateTime`] in the given time zone from an absolute time, in
+    /// milliseconds since the UNIX epoch.
+    ///
+    /// Returns `None` if there is no transition data for the time zone.
+    pub fn zoned_date_time_from_epoch_milliseconds(
+        &self,
+        time_zone: TimeZone,
+        epoch_milliseconds: i64,
+    ) -> Option<ZonedDateTime<Iso, TimeZoneInfo<models::Full>>> {
+        let ZoneOffset { offset, variant } =
+            self.offset_for_epoch_milliseconds(time_zone, epoch_milliseconds)?;
+        let ZonedDateTime { date, time, .. } =
+            ZonedDateTime::from_epoch_milliseconds_and_utc_offset(epoch_milliseconds, offset);
+        Some(ZonedDateTime {
+            date,
+            time,
+            zone: time_zone
+                .with_offset(Some(offset))
+                .at_date_time_iso(DateTime { date, time })
+                .with_variant(variant),
+        })
+    }
+
+    /// Returns the instant, in milliseconds since the UNIX epoch, of a local date and time
+    /// in the given time zone.
+    ///
+    /// Local times that are repeated or skipped by a transition are resolved according to
+    /// `disambiguation`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use icu::calendar::Date;
+    /// use icu::locale::subtags::subtag;
+    /// use icu::time::zone::rules::{
+    ///     Disambiguation, LocalTimeError, TimeZoneRules,
+    /// };
+    /// use icu::time::{DateTime, Time, TimeZone};
+    ///
+    /// let rules = TimeZoneRules::new();
+    /// let new_york = TimeZone(subtag!("usnyc"));
+    ///
+    /// // 01:30 happens twice on 2025-11-02
+    /// let date_time = DateTime {
+    ///     date: Date::try_new_iso(2025, 11, 2).unwrap(),
+    ///     time: Time::try_new(1, 30, 0, 0).unwrap(),
+    /// };
+    /// assert_eq!(
+    ///     rules.epoch_milliseconds_from_local(
+    ///         new_york,
+    ///         date_time,
+    ///         Disambiguation::Earlier
+    ///     ),
+    ///     Ok(1762061400000) // 05:30Z
+    /// );
+    /// assert_eq!(
+    ///     rules.epoch_milliseconds_from_local(
+    ///         new_york,
+    ///         date_time,
+    ///         Disambiguation::Later
+    ///     ),
+    ///     Ok(1762065000000) // 06:30Z
+    /// );
+    /// assert_eq!(
+    ///     rules.epoch_milliseconds_from_local(
+    ///         new_york,
+    ///         date_time,
+    ///         Disambiguation::Reject
+    ///     ),
+    ///     Err(LocalTimeError::Ambiguous)
+    /// );
+    ///
+    /// // 02:30 does not happen on 2025-03-09
+    /// let date_time = DateTime {
+    ///     date: Date::try_new_iso(2025, 3, 9).unwrap(),
+    ///     time: Time::try_new(2, 30, 0, 0).unwrap(),
+    /// };
+    /// assert_eq!(
+    ///     rules.epoch_milliseconds_from_local(
+    ///         new_york,
+    ///         date_time,
+    ///         Disambiguation::Earlier
+    ///     ),
+    ///     Ok(1741501800000) // 06:30Z, which is 01:30 local time
+    /// );
+    /// assert_eq!(
+    ///     rules.epoch_milliseconds_from_local(
+    ///         new_york,
+    ///         date_time,
+    ///         Disambiguation::Later
+    ///     ),
+    ///     Ok(1741505400000) // 07:30Z, which is 03:30 local time
+    /// );
+    /// assert_eq!(
+    ///     rules.epoch_milliseconds_from_local(
+    ///         new_york,
+    ///         date_time,
+    ///         Disambiguation::Reject
+    ///     ),
+    ///     Err(LocalTimeError::Skipped)
+    /// );
+    /// ```
+    pub fn epoch_milliseconds_from_local<A: AsCalendar>(
+        &self,
+        time_zone: TimeZone,
+        date_time: DateTime<A>,
+        disambiguation: Disambiguation,
+    ) -> Result<i64, LocalTimeError> {
+        self.resolve_local(time_zone, date_time, None, disambiguation)
+    }
+
+    /// Returns the instant, in milliseconds since the UNIX epoch, of a [`ZonedDateTime`].
+    ///
+    /// If the zoned date time has a UTC offset that is valid for its local time, that
+    /// offset selects the instant. Otherwise, the local time is resolved according to
+    /// `disambiguation`, as in [`Self::epoch_milliseconds_from_local`].
+    pub fn epoch_milliseconds_from_zoned_date_time<A: AsCalendar, Model: models::TimeZoneModel>(
+        &self,
+        zoned_date_time: &ZonedDateTime<A, TimeZoneInfo<Model>>,
+        disambiguation: Disambiguation,
+    ) -> Result<i64, LocalTimeError> {
+        self.resolve_local(
+            zoned_date_time.zone.id(),
+            DateTime {
+                date: zoned_date_time.date.to_iso(),
+                time: zoned_date_time.time,
+            },
+            zoned_date_time.zone.offset(),
+            disambiguation,
+        )
+    }
+
+    /// Resolves a local date and time in the given time zone into a [`ZonedDateTime`] with
+    /// the UTC offset and zone variant in effect.
+    ///
+    /// Local times that are repeated or skipped by a transition are resolved according to
+    /// `disambiguation`. A skipped local time is moved by the length of the transition.
+    pub fn zoned_date_time_from_local<A: AsCalendar>(
+        &self,
+        time_zone: TimeZone,
+        date_time: DateTime<A>,
+        disambiguation: Disambiguation,
+    ) -> Result<ZonedDateTime<Iso, TimeZoneInfo<models::Full>>, LocalTimeError> {
+        let epoch_milliseconds =
+            self.epoch_milliseconds_from_local(time_zone, date_time, disambiguation)?;
+        self.zoned_date_time_from_epoch_milliseconds(time_zone, epoch_milliseconds)
+            .ok_or(LocalTimeError::UnknownTimeZone(time_zone))
+    }
+
+    fn resolve_local<A: AsCalendar>(
+        &self,
+        time_zone: TimeZone,
+        date_time: DateTime<A>,
+        offset_hint: Option<UtcOffset>,
+        disambiguation: Disambiguation,
+    ) -> Result<i64, LocalTimeError> {
+        let Time {
+            hour,
+            minute,
+            second,
+            subsecond,
+        } = date_time.time;
+        let local_seconds = (date_time.date.to_rata_die() - UNIX_EPOCH) * SECONDS_PER_DAY
+            + i64::from(hour.number()) * 3600
+            + i64::from(minute.number()) * 60
+            + i64::from(second.number());
+        let millisecond = i64::from(subsecond.number() / 1_000_000);
+
+        let offset_at = |seconds| {
+            self.transition_offset(time_zone, seconds)
+                .map(|o| i64::from(o.offset_seconds))
+                .ok_or(LocalTimeError::UnknownTimeZone(time_zone))
+        };
+
+        // No time zone changes its offset more than once within a day, so the offsets
+        // a day before and after are the only candidates.
+        let offset_before = offset_at(local_seconds - SECONDS_PER_DAY)?;
+        let offset_after = offset_at(local_seconds + SECONDS_PER_DAY)?;
+
+        let mut candidates = [offset_before, offset_after]
+            .map(|offset| {
+                let instant = local_seconds - offset;
+                (offset_at(instant) == Ok(offset)).then_some((instant, offset))
+            })
+            .into_iter()
+            .flatten();
+        let first = candidates.next();
+        let second = candidates.next().filter(|&c| Some(c) != first);
+
+        let instant = match (first, second) {
+            (Some((a, a_offset)), Some((b, b_offset))) => {
+                match offset_hint.map(|o| i64::from(o.to_seconds())) {
+                    Some(o) if o == a_offset => a,
+                    Some(o) if o == b_offset => b,
+                    _ => match disambiguation {
+                        Disambiguation::Earlier => a.min(b),
+                        Disambiguation::Later => a.max(b),
+                        Disambiguation::Reject => return Err(LocalTimeError::Ambiguous),
+                    },
+                }
+            }
+            (Some((instant, _)), None) => instant,
+            (None, _) => match disambiguation {
+                Disambiguation::Earlier => local_seconds - offset_after,
+                Disambiguation::Later => local_seconds - offset_before,
+                Disambiguation::Reject => return Err(LocalTimeError::Skipped),
+            },
+        };
+        Ok(instant * 1000 + millisecond)
+    }
+
+    fn transition_offset(&self, time_zone: TimeZone, seconds: i64) -> Option<TransitionOffset> {
+        use zerovec::ule::AsULE;
+        let (start, end) = self.transitions.transition_ranges.get_copied(&time_zone)?;
+        let transitions = self
+            .transitions
+            .transitions
+            .get_subslice(start as usize..end as usize)?;
+        let index = transitions
+            .as_ule_slice()
+            .partition_point(|t| i64::from_unaligned(t.0) <= seconds);
+        let (last_start, last_offset) = transitions.get(index.checked_sub(1)?)?;
+        if index < transitions.len() {
+            return Some(last_offset);
+        }
+
+        let Some(rule) = self.transitions.rules.get_copied(&time_zone) else {
+            return Some(last_offset);
+        };
+        let rd = UNIX_EPOCH
+            + (seconds + i64::from(rule.standard_offset_seconds)).div_euclid(SECONDS_PER_DAY);
+        let Ok((year, _, _)) = iso::iso_from_fixed(rd) else {
+            return Some(last_offset);
+        };
+        let mut result = (last_start, last_offset);
+        for year in [year - 1, year] {
+            if year < rule.start_year {
+                continue;
+            }
+            for (transition, offset) in rule_transitions(rule, year) {
+                if transition > result.0 && transition <= seconds {
+                    result = (transition, offset);
+                }
+            }
+        }
+        Some(result.1)
+    }
+}
+
+/// Returns the two transitions of a rule in the given year, with the offsets they introduce.
+fn rule_transitions(rule: ZoneRule, year: i32) -> [(i64, TransitionOffset); 2] {
+    let standard = rule.standard_offset_seconds;
+    let daylight = standard + rule.daylight_savings_seconds;
+    [
+        (
+            transition_instant(rule.start, year, standard, standard),
+            TransitionOffset {
+                offset_seconds: daylight,
+                variant: TimeZoneVariant::Daylight,
+            },
+        ),
+        (
+            transition_instant(rule.end, year, standard, daylight),
+            TransitionOffset {
+                offset_seconds: standard,
+                variant: TimeZoneVariant::Standard,
+            },
+        ),
+    ]
+}
+
+/// Returns the instant, in seconds since the UNIX epoch, at which a rule transitions in the
+/// given year, given the standard offset and the offset in effect before the transition.
+fn transition_instant(transition: TransitionRule, year: i32, standard: i32, before: i32) -> i64 {
+    let month_start = iso::fixed_from_iso(year, transition.month, 1);
+    let next_month_start = if transition.month == 12 {
+        iso::fixed_from_iso(year + 1, 1, 1)
+    } else {
+        iso::fixed_from_iso(year, transition.month + 1, 1)
+    };
+    let mut day = if transition.day == 0 {
+        next_month_start - 1
+    } else {
+        month_start + i64::from(transition.day) - 1
+    };
+    if transition.weekday != 0 {
+        // RD 1 is a Monday
+        let weekday = (day.to_i64_date() - 1).rem_euclid(7) + 1;
+        let target = i64::from(transition.weekday);
+        if transition.on_or_after {
+            day += (target - weekday).rem_euclid(7);
+        } else {
+            day -= (weekday - target).rem_euclid(7);
+        }
+    }
+    let offset = match transition.time_kind {
+        TransitionTimeKind::Wall => before,
+        TransitionTimeKind::Standard => standard,
+        TransitionTimeKind::Utc => 0,
+    };
+    (day - UNIX_EPOCH) * SECONDS_PER_DAY + i64::from(transition.time_seconds) - i64::from(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu_calendar::Date;
+    use icu_locale_core::subtags::subtag;
+    use zerovec::ZeroVec;
+
+    fn rule(
+        month: u8,
+        day: u8,
+        weekday: u8,
+        on_or_after: bool,
+        time_seconds: i32,
+    ) -> TransitionRule {
+        TransitionRule {
+            month,
+            day,
+            weekday,
+            on_or_after,
+            time_seconds,
+            time_kind: TransitionTimeKind::Wall,
+        }
+    }
+
+    fn offset(offset_seconds: i32, variant: TimeZoneVariant) -> TransitionOffset {
+        TransitionOffset {
+            offset_seconds,
+            variant,
+        }
+    }
+
+    const BERLIN: TimeZone = TimeZone(subtag!("deber"));
+    const SYDNEY: TimeZone = TimeZone(subtag!("ausyd"));
+    const TOKYO: TimeZone = TimeZone(subtag!("jptyo"));
+
+    fn rules() -> TimeZoneRules {
+        use TimeZoneVariant::*;
+        let mut data = TimeZoneTransitions {
+            transitions: Default::default(),
+            transition_ranges: Default::default(),
+            rules: Default::default(),
+        };
+
+        let insert =
+            |data: &mut TimeZoneTransitions, tz, transitions: &[(i64, TransitionOffset)]| {
+                let start = data.transitions.len() as u32;
+                let mut all = data.transitions.to_vec();
+                all.extend_from_slice(transitions);
+                data.transitions = ZeroVec::alloc_from_slice(&all);
+                data.transition_ranges
+                    .insert(&tz, &(start, all.len() as u32));
+            };
+
+        insert(
+            &mut data,
+            TOKYO,
+            &[
+                (i64::MIN, offset(33539, Standard)),
+                (-2587712400, offset(9 * 3600, Standard)),
+            ],
+        );
+
+        // Last Sunday of March/October at 01:00 UTC
+        insert(
+            &mut data,
+            BERLIN,
+            &[
+                (i64::MIN, offset(3208, Standard)),
+                (846118800, offset(3600, Standard)),
+            ],
+        );
+        data.rules.insert(
+            &BERLIN,
+            &ZoneRule {
+                start_year: 1997,
+                standard_offset_seconds: 3600,
+                daylight_savings_seconds: 3600,
+                start: TransitionRule {
+                    time_kind: TransitionTimeKind::Utc,
+                    ..rule(3, 0, 7, false, 3600)
+                },
+                end: TransitionRule {
+                    time_kind: TransitionTimeKind::Utc,
+                    ..rule(10, 0, 7, false, 3600)
+                },
+            },
+        );
+
+        // Southern hemisphere: first Sunday of October to first Sunday of April, 02:00 standard time
+        insert(
+            &mut data,
+            SYDNEY,
+            &[
+                (i64::MIN, offset(36292, Standard)),
+                (1207411200, offset(10 * 3600, Standard)),
+            ],
+        );
+        data.rules.insert(
+            &SYDNEY,
+            &ZoneRule {
+                start_year: 2008,
+                standard_offset_seconds: 10 * 3600,
+                daylight_savings_seconds: 3600,
+                start: TransitionRule {
+                    time_kind: TransitionTimeKind::Standard,
+                    ..rule(10, 1, 7, true, 7200)
+                },
+                end: TransitionRule {
+                    time_kind: TransitionTimeKind::Standard,
+                    ..rule(4, 1, 7, true, 7200)
+                },
+            },
+        );
+
+        TimeZoneRules {
+            transitions: DataPayload::from_owned(data),
+        }
+    }
+
+    #[test]
+    fn test_offsets() {
+        let rules = rules();
+        let rules = rules.as_borrowed();
+        let at = |tz, seconds: i64| {
+            rules
+                .offset_for_epoch_milliseconds(tz, seconds * 1000)
+                .map(|o| (o.offset.to_seconds(), o.variant))
+        };
+        use TimeZoneVariant::*;
+
+        // Local mean time
+        assert_eq!(at(TOKYO, -2587712401), Some((33539, Standard)));
+        assert_eq!(at(TOKYO, -2587712400), Some((9 * 3600, Standard)));
+        assert_eq!(at(TOKYO, 1_700_000_000), Some((9 * 3600, Standard)));
+
+        // 2025-03-30T01:00Z and 2025-10-26T01:00Z
+        assert_eq!(at(BERLIN, 1743296399), Some((3600, Standard)));
+        assert_eq!(at(BERLIN, 1743296400), Some((7200, Daylight)));
+        assert_eq!(at(BERLIN, 1761440399), Some((7200, Daylight)));
+        assert_eq!(at(BERLIN, 1761440400), Some((3600, Standard)));
+        // Before the first rule transition, the last explicit transition applies
+        assert_eq!(at(BERLIN, 846118800), Some((3600, Standard)));
+
+        // 2025-04-05T16:00Z and 2025-10-04T16:00Z
+        assert_eq!(at(SYDNEY, 1743868799), Some((11 * 3600, Daylight)));
+        assert_eq!(at(SYDNEY, 1743868800), Some((10 * 3600, Standard)));
+        assert_eq!(at(SYDNEY, 1759593599), Some((10 * 3600, Standard)));
+        assert_eq!(at(SYDNEY, 1759593600), Some((11 * 3600, Daylight)));
+        // Across the new year, daylight time from the previous year's rule applies
+        assert_eq!(at(SYDNEY, 1767225600), Some((11 * 3600, Daylight)));
+
+        assert_eq!(at(TimeZone::UNKNOWN, 0), None);
+    }
+
+    #[test]
+    fn test_local_round_trip() {
+        let rules = rules();
+        let rules = rules.as_borrowed();
+
+        for tz in [BERLIN, SYDNEY, TOKYO] {
+            // Every 15 minutes across 2025
+            for seconds in (1735689600..1767225600).step_by(900) {
+                let zdt = rules
+                    .zoned_date_time_from_epoch_milliseconds(tz, seconds * 1000)
+                    .unwrap();
+                // Repeated local times are resolved through the offset
+                assert_eq!(
+                    rules.epoch_milliseconds_from_zoned_date_time(&zdt, Disambiguation::Reject),
+                    Ok(seconds * 1000),
+                    "{tz:?} {zdt:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_disambiguation() {
+        let rules = rules();
+        let rules = rules.as_borrowed();
+        let local = |y, m, d, h, min| DateTime {
+            date: Date::try_new_iso(y, m, d).unwrap(),
+            time: Time::try_new(h, min, 0, 0).unwrap(),
+        };
+
+        // Berlin skips 02:00-03:00 on 2025-03-30
+        let skipped = local(2025, 3, 30, 2, 30);
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(BERLIN, skipped, Disambiguation::Earlier),
+            Ok(1743294600000)
+        );
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(BERLIN, skipped, Disambiguation::Later),
+            Ok(1743298200000)
+        );
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(BERLIN, skipped, Disambiguation::Reject),
+            Err(LocalTimeError::Skipped)
+        );
+        let zdt = rules
+            .zoned_date_time_from_local(BERLIN, skipped, Disambiguation::Later)
+            .unwrap();
+        assert_eq!(zdt.time, Time::try_new(3, 30, 0, 0).unwrap());
+        assert_eq!(zdt.zone.variant(), TimeZoneVariant::Daylight);
+
+        // Berlin repeats 02:00-03:00 on 2025-10-26
+        let repeated = local(2025, 10, 26, 2, 30);
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(BERLIN, repeated, Disambiguation::Earlier),
+            Ok(1761438600000)
+        );
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(BERLIN, repeated, Disambiguation::Later),
+            Ok(1761442200000)
+        );
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(BERLIN, repeated, Disambiguation::Reject),
+            Err(LocalTimeError::Ambiguous)
+        );
+
+        // Unambiguous times are not affected by the disambiguation
+        for disambiguation in [
+            Disambiguation::Earlier,
+            Disambiguation::Later,
+            Disambiguation::Reject,
+        ] {
+            assert_eq!(
+                rules.epoch_milliseconds_from_local(
+                    BERLIN,
+                    local(2025, 7, 1, 12, 0),
+                    disambiguation
+                ),
+                Ok(1751364000000)
+            );
+        }
+
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(
+                TimeZone::UNKNOWN,
+                repeated,
+                Disambiguation::Earlier
+            ),
+            Err(LocalTimeError::UnknownTimeZone(TimeZone::UNKNOWN))
+        );
+    }
+}
@@ -30,6 +30,8 @@ typedef enum CalendarKind {
   CalendarKind_HijriUmmAlQura = 15,
   CalendarKind_Persian = 16,
   CalendarKind_Roc = 17,
+  CalendarKind_Vietnamese = 19,
+  CalendarKind_BikramSambat = 20,
 } CalendarKind;
 
 typedef struct CalendarKind_option {union { CalendarKind ok; }; bool is_ok; } CalendarKind_option;
@@ -39,6 +39,8 @@ namespace capi {
       CalendarKind_HijriUmmAlQura = 15,
       CalendarKind_Persian = 16,
       CalendarKind_Roc = 17,
+      CalendarKind_Vietnamese = 19,
+      CalendarKind_BikramSambat = 20,
     };
 
     typedef struct CalendarKind_option {union { CalendarKind ok; }; bool is_ok; } CalendarKind_option;
@@ -72,6 +74,8 @@ public:
     HijriUmmAlQura = 15,
     Persian = 16,
     Roc = 17,
+    Vietnamese = 19,
+    BikramSambat = 20,
   };
 
   CalendarKind(): value(Value::Iso) {}
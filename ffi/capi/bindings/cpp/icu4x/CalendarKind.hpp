@@ -49,6 +49,8 @@ inline icu4x::CalendarKind icu4x::CalendarKind::FromFFI(icu4x::capi::CalendarKin
     case icu4x::capi::CalendarKind_HijriUmmAlQura:
     case icu4x::capi::CalendarKind_Persian:
     case icu4x::capi::CalendarKind_Roc:
+    case icu4x::capi::CalendarKind_Vietnamese:
+    case icu4x::capi::CalendarKind_BikramSambat:
       return static_cast<icu4x::CalendarKind::Value>(c_enum);
     default:
       std::abort();
@@ -42,7 +42,11 @@ enum CalendarKind {
   /// The kind of a Persian calendar
   persian,
   /// The kind of a Roc calendar
-  roc;
+  roc,
+  /// The kind of a Vietnamese calendar
+  vietnamese,
+  /// The kind of a Bikram Sambat calendar
+  bikramSambat;
 
   int get _ffi {
     switch (this) {
@@ -82,6 +86,10 @@ enum CalendarKind {
         return 16;
       case roc:
         return 17;
+      case vietnamese:
+        return 19;
+      case bikramSambat:
+        return 20;
     }
   }
 
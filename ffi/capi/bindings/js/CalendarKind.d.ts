@@ -37,6 +37,8 @@ export class CalendarKind {
     static HijriUmmAlQura : CalendarKind;
     static Persian : CalendarKind;
     static Roc : CalendarKind;
+    static Vietnamese : CalendarKind;
+    static BikramSambat : CalendarKind;
 
 
     /**
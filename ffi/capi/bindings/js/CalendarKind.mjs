@@ -31,7 +31,9 @@ export class CalendarKind {
         ["HijriTabularTypeIiThursday", 14],
         ["HijriUmmAlQura", 15],
         ["Persian", 16],
-        ["Roc", 17]
+        ["Roc", 17],
+        ["Vietnamese", 19],
+        ["BikramSambat", 20]
     ]);
 
     static getAllEntries() {
@@ -99,6 +101,8 @@ export class CalendarKind {
         [15]: new CalendarKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 15),
         [16]: new CalendarKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 16),
         [17]: new CalendarKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 17),
+        [19]: new CalendarKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 19),
+        [20]: new CalendarKind(diplomatRuntime.internalConstructor, diplomatRuntime.internalConstructor, 20),
     };
 
     static Iso = CalendarKind.#objectValues[0];
@@ -119,6 +123,8 @@ export class CalendarKind {
     static HijriUmmAlQura = CalendarKind.#objectValues[15];
     static Persian = CalendarKind.#objectValues[16];
     static Roc = CalendarKind.#objectValues[17];
+    static Vietnamese = CalendarKind.#objectValues[19];
+    static BikramSambat = CalendarKind.#objectValues[20];
 
 
     /**
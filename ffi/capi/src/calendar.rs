@@ -59,6 +59,10 @@ pub mod ffi {
         Persian = 16,
         /// The kind of a Roc calendar
         Roc = 17,
+        /// The kind of a Vietnamese calendar
+        Vietnamese = 19,
+        /// The kind of a Bikram Sambat calendar
+        BikramSambat = 20,
     }
 
     impl CalendarKind {
@@ -0,0 +1,467 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! A data provider wrapper that caches responses.
+//!
+//! This is useful in front of providers that do work on every load, such as deserializing
+//! a payload from a [`BufferProvider`], when many formatters are constructed from the
+//! same data.
+//!
+//! ✨ *Enabled with the `std` Cargo feature.*
+//!
+//! [`BufferProvider`]: icu_provider::buf::BufferProvider
+
+use alloc::boxed::Box;
+use alloc::collections::BTreeMap;
+use core::any::{Any, TypeId};
+use core::fmt;
+use icu_provider::marker::DataMarkerId;
+use icu_provider::prelude::*;
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use yoke::Yokeable;
+
+/// Statistics about the use of a [`CachingProvider`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// The number of loads that were served from the cache.
+    pub hits: u64,
+    /// The number of loads that were forwarded to the inner provider, including failed ones.
+    pub misses: u64,
+    /// The number of responses that were removed from the cache, either to stay within
+    /// the capacity or because their data changed.
+    pub evictions: u64,
+    /// The number of responses currently in the cache.
+    pub len: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct CacheKey {
+    marker: DataMarkerId,
+    // The type of the cached response, as the same marker can be loaded through
+    // different traits, such as `DataProvider` and `BufferProvider`.
+    response_type: TypeId,
+    id: DataIdentifierCow<'static>,
+    attributes_prefix_match: bool,
+}
+
+struct CacheEntry {
+    last_used: u64,
+    checksum: Option<u64>,
+    response: Box<dyn Any + Send>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    entries: HashMap<CacheKey, CacheEntry>,
+    // The keys of the entries by the time they were last used, for LRU eviction
+    recency: BTreeMap<u64, CacheKey>,
+    // The checksum of the most recently loaded data for each marker
+    checksums: HashMap<DataMarkerId, u64>,
+    clock: u64,
+    stats: CacheStats,
+}
+
+impl CacheState {
+    fn tick(&mut self) -> u64 {
+        self.clock += 1;
+        self.clock
+    }
+
+    fn get<R: Clone + 'static>(&mut self, key: &CacheKey) -> Option<R> {
+        let now = self.tick();
+        let entry = self.entries.get_mut(key)?;
+        let response = entry.response.downcast_ref::<R>()?.clone();
+        self.recency.remove(&entry.last_used);
+        entry.last_used = now;
+        self.recency.insert(now, key.clone());
+        self.stats.hits += 1;
+        Some(response)
+    }
+
+    fn insert(
+        &mut self,
+        key: CacheKey,
+        checksum: Option<u64>,
+        response: Box<dyn Any + Send>,
+        capacity: usize,
+    ) {
+        if let Some(checksum) = checksum {
+            if self.checksums.insert(key.marker, checksum) != Some(checksum) {
+                // The data for this marker changed, so previously cached responses are stale
+                self.remove_where(|k, entry| {
+                    k.marker == key.marker && entry.checksum.is_some_and(|c| c != checksum)
+                });
+            }
+        }
+        if capacity == 0 {
+            return;
+        }
+        while self.entries.len() >= capacity {
+            let Some((_, lru)) = self.recency.pop_first() else {
+                break;
+            };
+            self.entries.remove(&lru);
+            self.stats.evictions += 1;
+        }
+        let now = self.tick();
+        self.recency.insert(now, key.clone());
+        if let Some(old) = self.entries.insert(
+            key,
+            CacheEntry {
+                last_used: now,
+                checksum,
+                response,
+            },
+        ) {
+            self.recency.remove(&old.last_used);
+        }
+    }
+
+    fn remove_where(&mut self, mut predicate: impl FnMut(&CacheKey, &CacheEntry) -> bool) {
+        let recency = &mut self.recency;
+        let stats = &mut self.stats;
+        self.entries.retain(|key, entry| {
+            let remove = predicate(key, entry);
+            if remove {
+                recency.remove(&entry.last_used);
+                stats.evictions += 1;
+            }
+            !remove
+        });
+    }
+}
+
+/// A data provider wrapper that caches the responses of the inner provider.
+///
+/// Responses are cached by data marker and [`DataIdentifierBorrowed`], including their
+/// [`DataResponseMetadata`], so a cached response is indistinguishable from a fresh one.
+/// Errors are not cached.
+///
+/// The cache holds at most `capacity` responses. When it is full, the least recently used
+/// response is evicted. If the inner provider reports a [checksum] for a marker that differs
+/// from the one of the cached responses, those responses are evicted, as their data changed.
+///
+/// The cache is shared between threads. Since payloads are cloned out of the cache, this
+/// requires them to be [`Send`]; for buffer-backed data, enable the `sync` Cargo feature
+/// of `icu_provider`.
+///
+/// [checksum]: DataResponseMetadata::checksum
+///
+/// # Examples
+///
+/// ```
+/// use icu_locale::langid;
+/// use icu_provider::hello_world::*;
+/// use icu_provider::prelude::*;
+/// use icu_provider_adapters::cache::CachingProvider;
+///
+/// let provider = CachingProvider::new(HelloWorldProvider, 100);
+///
+/// let locale = langid!("de").into();
+/// let req = DataRequest {
+///     id: DataIdentifierBorrowed::for_locale(&locale),
+///     ..Default::default()
+/// };
+///
+/// // The first load is forwarded to the inner provider...
+/// let response: DataResponse<HelloWorldV1> = provider.load(req).unwrap();
+/// assert_eq!(response.payload.get().message, "Hallo Welt");
+///
+/// // ...and the second load is served from the cache
+/// let response: DataResponse<HelloWorldV1> = provider.load(req).unwrap();
+/// assert_eq!(response.payload.get().message, "Hallo Welt");
+///
+/// let stats = provider.stats();
+/// assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+/// ```
+pub struct CachingProvider<P> {
+    inner: P,
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl<P: fmt::Debug> fmt::Debug for CachingProvider<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachingProvider")
+            .field("inner", &self.inner)
+            .field("capacity", &self.capacity)
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
+impl<P> CachingProvider<P> {
+    /// Wraps a provider with a cache holding at most `capacity` responses.
+    pub fn new(provider: P, capacity: usize) -> Self {
+        Self {
+            inner: provider,
+            capacity,
+            state: Default::default(),
+        }
+    }
+
+    /// Returns a reference to the inner provider, bypassing the cache.
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    /// Returns ownership of the inner provider to the caller.
+    pub fn into_inner(self) -> P {
+        self.inner
+    }
+
+    /// Returns the maximum number of responses in the cache.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns statistics about the use of the cache.
+    pub fn stats(&self) -> CacheStats {
+        let state = self.lock();
+        CacheStats {
+            len: state.entries.len(),
+            ..state.stats
+        }
+    }
+
+    /// Removes all responses from the cache. This does not reset the statistics.
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.entries.clear();
+        state.recency.clear();
+        state.checksums.clear();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheState> {
+        // The state is consistent between operations, so it can be used after a panic
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn load_cached<M>(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+        load: impl FnOnce(DataRequest) -> Result<DataResponse<M>, DataError>,
+    ) -> Result<DataResponse<M>, DataError>
+    where
+        M: DynamicDataMarker,
+        for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+        DataResponse<M>: Send,
+    {
+        let key = CacheKey {
+            marker: marker.id,
+            response_type: TypeId::of::<DataResponse<M>>(),
+            id: if req.id.marker_attributes.is_empty() {
+                // Avoids an allocation
+                DataIdentifierCow::from_locale(*req.id.locale)
+            } else {
+                req.id.into_owned()
+            },
+            attributes_prefix_match: req.metadata.attributes_prefix_match,
+        };
+        if let Some(response) = self.lock().get(&key) {
+            return Ok(response);
+        }
+        // Don't hold the lock while loading, as the inner provider might be slow
+        let response = load(req);
+        let mut state = self.lock();
+        state.stats.misses += 1;
+        let response = response?;
+        state.insert(
+            key,
+            response.metadata.checksum,
+            Box::new(response.clone()),
+            self.capacity,
+        );
+        Ok(response)
+    }
+}
+
+impl<P, M> DynamicDataProvider<M> for CachingProvider<P>
+where
+    P: DynamicDataProvider<M>,
+    M: DynamicDataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send,
+{
+    fn load_data(
+        &self,
+        marker: DataMarkerInfo,
+        req: DataRequest,
+    ) -> Result<DataResponse<M>, DataError> {
+        self.load_cached(marker, req, |req| self.inner.load_data(marker, req))
+    }
+}
+
+impl<P, M> DataProvider<M> for CachingProvider<P>
+where
+    P: DataProvider<M>,
+    M: DataMarker,
+    for<'a> <M::DataStruct as Yokeable<'a>>::Output: Clone,
+    DataResponse<M>: Send,
+{
+    fn load(&self, req: DataRequest) -> Result<DataResponse<M>, DataError> {
+        self.load_cached(M::INFO, req, |req| self.inner.load(req))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use icu_locale::langid;
+    use icu_provider::hello_world::*;
+
+    /// A provider that counts its loads and can change its checksum.
+    struct CountingProvider {
+        loads: Cell<usize>,
+        checksum: Cell<Option<u64>>,
+    }
+
+    impl CountingProvider {
+        fn new() -> Self {
+            Self {
+                loads: Cell::new(0),
+                checksum: Cell::new(None),
+            }
+        }
+    }
+
+    impl DataProvider<HelloWorldV1> for CountingProvider {
+        fn load(&self, req: DataRequest) -> Result<DataResponse<HelloWorldV1>, DataError> {
+            self.loads.set(self.loads.get() + 1);
+            let mut response = HelloWorldProvider.load(req)?;
+            response.metadata.checksum = self.checksum.get();
+            Ok(response)
+        }
+    }
+
+    fn load(
+        provider: &impl DataProvider<HelloWorldV1>,
+        locale: &str,
+    ) -> Result<DataResponse<HelloWorldV1>, DataError> {
+        provider.load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&locale.parse().unwrap()),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_lru_eviction() {
+        let provider = CachingProvider::new(CountingProvider::new(), 2);
+
+        load(&provider, "de").unwrap();
+        load(&provider, "ja").unwrap();
+        // "de" is now more recently used than "ja"
+        load(&provider, "de").unwrap();
+        assert_eq!(provider.inner().loads.get(), 2);
+
+        // Evicts "ja"
+        load(&provider, "ru").unwrap();
+        load(&provider, "de").unwrap();
+        assert_eq!(provider.inner().loads.get(), 3);
+        load(&provider, "ja").unwrap();
+        assert_eq!(provider.inner().loads.get(), 4);
+
+        assert_eq!(
+            provider.stats(),
+            CacheStats {
+                hits: 2,
+                misses: 4,
+                evictions: 2,
+                len: 2,
+            }
+        );
+
+        provider.clear();
+        assert_eq!(provider.stats().len, 0);
+        load(&provider, "de").unwrap();
+        assert_eq!(provider.inner().loads.get(), 5);
+    }
+
+    #[test]
+    fn test_metadata_and_errors() {
+        let provider = CachingProvider::new(
+            crate::fallback::LocaleFallbackProvider::new(
+                HelloWorldProvider,
+                icu_locale::LocaleFallbacker::new().static_to_owned(),
+            ),
+            10,
+        );
+
+        for _ in 0..2 {
+            let response = load(&provider, "de-CH").unwrap();
+            assert_eq!(response.payload.get().message, "Hallo Welt");
+            assert_eq!(response.metadata.locale, Some(langid!("de").into()));
+        }
+
+        let provider = CachingProvider::new(CountingProvider::new(), 10);
+        for _ in 0..2 {
+            load(&provider, "xx").unwrap_err();
+        }
+        assert_eq!(provider.inner().loads.get(), 2);
+        assert_eq!(provider.stats().misses, 2);
+        assert_eq!(provider.stats().len, 0);
+
+        // A capacity of zero disables caching
+        let provider = CachingProvider::new(CountingProvider::new(), 0);
+        for _ in 0..2 {
+            load(&provider, "de").unwrap();
+        }
+        assert_eq!(provider.inner().loads.get(), 2);
+    }
+
+    #[test]
+    fn test_checksum_change() {
+        let provider = CachingProvider::new(CountingProvider::new(), 10);
+        provider.inner().checksum.set(Some(1));
+        load(&provider, "de").unwrap();
+        load(&provider, "ja").unwrap();
+
+        // New data for "ru" invalidates the other responses
+        provider.inner().checksum.set(Some(2));
+        load(&provider, "ru").unwrap();
+        assert_eq!(provider.stats().len, 1);
+        assert_eq!(provider.stats().evictions, 2);
+
+        load(&provider, "de").unwrap();
+        assert_eq!(provider.inner().loads.get(), 4);
+        assert_eq!(load(&provider, "de").unwrap().metadata.checksum, Some(2));
+        assert_eq!(provider.inner().loads.get(), 4);
+    }
+
+    #[test]
+    fn test_dynamic_and_typed_loads() {
+        let provider = CachingProvider::new(HelloWorldProvider.into_json_provider(), 10);
+        let locale = langid!("de").into();
+        let req = DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&locale),
+            ..Default::default()
+        };
+
+        // Deserializing on top of the cache loads the cached buffer
+        provider.load_data(HelloWorldV1::INFO, req).unwrap();
+        let response: DataResponse<HelloWorldV1> = provider.as_deserializing().load(req).unwrap();
+        assert_eq!(response.payload.get().message, "Hallo Welt");
+        assert_eq!(provider.stats().len, 1);
+        assert_eq!(provider.stats().hits, 1);
+
+        let json_provider = provider.into_inner();
+        let provider = CachingProvider::new(json_provider.as_deserializing(), 10);
+        let _: DataResponse<HelloWorldV1> = provider.load(req).unwrap();
+        let response: DataResponse<HelloWorldV1> = provider.load(req).unwrap();
+        assert_eq!(response.payload.get().message, "Hallo Welt");
+        assert_eq!(provider.stats().hits, 1);
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CachingProvider<HelloWorldProvider>>();
+    }
+}
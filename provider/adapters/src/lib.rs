@@ -8,9 +8,10 @@
 //! - Use the [`either`] module to choose between multiple provider types at runtime.
 //! - Use the [`filter`] module to programmatically reject certain data requests.
 //! - Use the [`fallback`] module to automatically resolve arbitrary locales for data loading.
+//! - Use the `cache` module to avoid repeatedly loading the same data (requires the `std` Cargo feature).
 
 // https://github.com/unicode-org/icu4x/blob/main/documents/process/boilerplate.md#library-annotations
-#![cfg_attr(not(any(test, doc)), no_std)]
+#![cfg_attr(not(any(test, doc, feature = "std")), no_std)]
 #![cfg_attr(
     not(test),
     deny(
@@ -28,6 +29,8 @@
 
 extern crate alloc;
 
+#[cfg(feature = "std")]
+pub mod cache;
 pub mod either;
 pub mod empty;
 pub mod fallback;
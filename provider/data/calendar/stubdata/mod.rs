@@ -5,6 +5,7 @@ include!("calendar_week_v1.rs.data");
 include!("calendar_dangi_v1.rs.data");
 include!("calendar_hijri_simulated_mecca_v1.rs.data");
 include!("calendar_chinese_v1.rs.data");
+include!("calendar_vietnamese_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -44,5 +45,6 @@ macro_rules! impl_data_provider {
         impl_calendar_dangi_v1!($provider);
         impl_calendar_hijri_simulated_mecca_v1!($provider);
         impl_calendar_chinese_v1!($provider);
+        impl_calendar_vietnamese_v1!($provider);
     };
 }
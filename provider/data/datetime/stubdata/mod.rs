@@ -53,6 +53,8 @@ include!("datetime_names_year_japanext_v1.rs.data");
 include!("datetime_patterns_date_dangi_v1.rs.data");
 include!("datetime_names_month_roc_v1.rs.data");
 include!("datetime_names_year_japanese_v1.rs.data");
+include!("datetime_patterns_interval_generic_v1.rs.data");
+include!("datetime_patterns_interval_gregorian_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -140,5 +142,7 @@ macro_rules! impl_data_provider {
         impl_datetime_patterns_date_dangi_v1!($provider);
         impl_datetime_names_month_roc_v1!($provider);
         impl_datetime_names_year_japanese_v1!($provider);
+        impl_datetime_patterns_interval_generic_v1!($provider);
+        impl_datetime_patterns_interval_gregorian_v1!($provider);
     };
 }
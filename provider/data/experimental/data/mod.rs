@@ -43,6 +43,8 @@ include!("narrow_minute_relative_v1.rs.data");
 include!("digital_duration_data_v1.rs.data");
 include!("units_info_v1.rs.data");
 include!("narrow_year_relative_v1.rs.data");
+include!("rbnf_rules_v1.rs.data");
+include!("spoof_data_v1.rs.data");
 /// Marks a type as a data provider. You can then use macros like
 /// `impl_core_helloworld_v1` to add implementations.
 ///
@@ -123,5 +125,7 @@ macro_rules! impl_data_provider {
         impl_digital_duration_data_v1!($provider);
         impl_units_info_v1!($provider);
         impl_narrow_year_relative_v1!($provider);
+        impl_rbnf_rules_v1!($provider);
+        impl_spoof_data_v1!($provider);
     };
 }
@@ -1,4 +1,5 @@
 // @generated
+include!("timezone_transitions_v1.rs.data");
 include!("timezone_identifiers_iana_extended_v1.rs.data");
 include!("timezone_identifiers_windows_v1.rs.data");
 include!("timezone_variants_offsets_v1.rs.data");
@@ -37,6 +38,7 @@ pub use __make_provider as make_provider;
 macro_rules! impl_data_provider {
     ($ provider : ty) => {
         make_provider!($provider);
+        impl_timezone_transitions_v1!($provider);
         impl_timezone_identifiers_iana_extended_v1!($provider);
         impl_timezone_identifiers_windows_v1!($provider);
         impl_timezone_variants_offsets_v1!($provider);
@@ -136,6 +136,29 @@ struct Cli {
     #[cfg(feature = "provider")]
     tzdb_root: Option<PathBuf>,
 
+    #[arg(long, value_name = "TAG", default_value = "latest")]
+    #[arg(
+        help = "Download the Unicode Character Database and the UTS #39 security data for this Unicode version \
+                  (https://www.unicode.org/Public/, https://www.unicode.org/Public/security/)\n\
+                  Use 'latest' for the latest version verified to work with this version of the binary.\n\
+                  Ignored for the data whose '--ucd-root' or '--uts39-root' is present. Requires binary to be built with `networking` Cargo feature (enabled by default)."
+    )]
+    #[cfg_attr(not(feature = "networking"), arg(hide = true))]
+    #[cfg(feature = "provider")]
+    unicode_tag: String,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(help = "Path to a local Unicode Character Database directory \
+                (see UCD.zip in any version directory of https://www.unicode.org/Public/).")]
+    #[cfg(feature = "provider")]
+    ucd_root: Option<PathBuf>,
+
+    #[arg(long, value_name = "PATH")]
+    #[arg(help = "Path to a local UTS #39 data directory \
+                (see uts39-data-*.zip in any version directory of https://www.unicode.org/Public/security/).")]
+    #[cfg(feature = "provider")]
+    uts39_root: Option<PathBuf>,
+
     #[arg(long, value_enum, default_value_t = TrieType::Small)]
     #[arg(
         help = "Whether to optimize CodePointTrie data structures for size (\"small\") or speed (\"fast\").\n\
@@ -433,6 +456,30 @@ fn main() -> eyre::Result<()> {
                 }
             };
 
+            p = match (cli.ucd_root, cli.uts39_root, cli.unicode_tag.as_str()) {
+                (Some(ucd), Some(uts39), _) => p.with_ucd(&ucd)?.with_uts39(&uts39)?,
+                #[cfg(feature = "networking")]
+                (ucd, uts39, tag) => {
+                    p = p.with_unicode_for_tag(match tag {
+                        "latest" => SourceDataProvider::TESTED_UNICODE_TAG,
+                        tag => tag,
+                    });
+                    if let Some(path) = ucd {
+                        p = p.with_ucd(&path)?;
+                    }
+                    if let Some(path) = uts39 {
+                        p = p.with_uts39(&path)?;
+                    }
+                    p
+                }
+                #[cfg(not(feature = "networking"))]
+                _ => {
+                    eyre::bail!(
+                        "Please set --ucd-root and --uts39-root or enable the `networking` Cargo feature"
+                    )
+                }
+            };
+
             if cli.locales.as_slice() == ["recommended"] {
                 preprocessed_locales = Some(PreprocessedLocales::Locales(
                     p.locales_for_coverage_levels([
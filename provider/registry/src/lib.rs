@@ -27,6 +27,7 @@ macro_rules! registry(
             icu::calendar::provider::CalendarHijriSimulatedMeccaV1: CalendarHijriSimulatedMeccaV1,
             icu::calendar::provider::CalendarJapaneseExtendedV1: CalendarJapaneseExtendedV1,
             icu::calendar::provider::CalendarWeekV1: CalendarWeekV1,
+            icu::calendar::provider::CalendarVietnameseV1: CalendarVietnameseV1,
             icu::casemap::provider::CaseMapV1: CaseMapV1,
             icu::casemap::provider::CaseMapUnfoldV1: CaseMapUnfoldV1,
             icu::collator::provider::CollationRootV1: CollationRootV1,
@@ -293,9 +294,17 @@ macro_rules! registry(
             icu::experimental::relativetime::provider::ShortYearRelativeV1: ShortYearRelativeV1,
             icu::experimental::relativetime::provider::NarrowYearRelativeV1: NarrowYearRelativeV1,
             icu::experimental::personnames::provider::PersonNamesFormatV1: PersonNamesFormatV1,
+            icu::experimental::rbnf::provider::RbnfRulesV1: RbnfRulesV1,
+            icu::experimental::spoof::provider::SpoofDataV1: SpoofDataV1,
             icu::experimental::transliterate::provider::TransliteratorRulesV1: TransliteratorRulesV1,
             icu::experimental::units::provider::UnitsInfoV1: UnitsInfoV1,
+            icu::decimal::provider::DecimalExponentSymbolsV1: DecimalExponentSymbolsV1,
+            icu::locale::provider::matching::LocaleLanguageMatchingV1: LocaleLanguageMatchingV1,
             icu::plurals::provider::PluralsRangesV1: PluralsRangesV1,
+            icu::properties::provider::character_names::PropertyCharacterNamesV1: PropertyCharacterNamesV1,
+            icu::datetime::provider::interval::DatetimePatternsIntervalGregorianV1: DatetimePatternsIntervalGregorianV1,
+            icu::datetime::provider::interval::DatetimePatternsIntervalGenericV1: DatetimePatternsIntervalGenericV1,
+            icu::time::provider::transitions::TimezoneTransitionsV1: TimezoneTransitionsV1,
         );
     }
 );
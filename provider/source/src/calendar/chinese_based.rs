@@ -5,7 +5,7 @@
 use std::collections::HashSet;
 
 use crate::SourceDataProvider;
-use calendrical_calculations::chinese_based::{Chinese, Dangi};
+use calendrical_calculations::chinese_based::{Chinese, Dangi, Vietnamese};
 use icu::calendar::provider::chinese_based::*;
 use icu_provider::prelude::*;
 
@@ -36,6 +36,18 @@ impl DataProvider<CalendarDangiV1> for SourceDataProvider {
     }
 }
 
+impl DataProvider<CalendarVietnameseV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<CalendarVietnameseV1>, DataError> {
+        self.check_req::<CalendarVietnameseV1>(req)?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(ChineseBasedCache::compute_for::<Vietnamese>(
+                ISO_START..(ISO_START + YEARS),
+            )),
+        })
+    }
+}
+
 impl crate::IterableDataProviderCached<CalendarChineseV1> for SourceDataProvider {
     fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
         Ok(HashSet::from_iter([Default::default()]))
@@ -47,3 +59,9 @@ impl crate::IterableDataProviderCached<CalendarDangiV1> for SourceDataProvider {
         Ok(HashSet::from_iter([Default::default()]))
     }
 }
+
+impl crate::IterableDataProviderCached<CalendarVietnameseV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
@@ -66,6 +66,11 @@ impl CldrCache {
         CldrDirNoLang(self, "cldr-bcp47/bcp47".to_string())
     }
 
+    #[cfg(feature = "experimental")]
+    pub(crate) fn rbnf(&self) -> CldrDirNoLang<'_> {
+        CldrDirNoLang(self, "cldr-rbnf/rbnf".to_owned())
+    }
+
     pub(crate) fn personnames(&self) -> CldrDirLang<'_> {
         CldrDirLang(self, "cldr-person-names".to_owned())
     }
@@ -235,6 +240,11 @@ impl<'a> CldrDirNoLang<'a> {
             .serde_cache
             .read_and_parse_json(&format!("{}/{}", self.1, file_name))
     }
+
+    #[cfg(feature = "experimental")]
+    pub(crate) fn list(&self) -> Result<impl Iterator<Item = String> + '_, DataError> {
+        self.0.serde_cache.list(&self.1)
+    }
 }
 
 pub(crate) struct CldrDirLang<'a>(&'a CldrCache, String);
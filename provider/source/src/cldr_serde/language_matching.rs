@@ -0,0 +1,55 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON languageMatching.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/languageMatching.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct ParadigmLocales {
+    #[serde(rename = "_locales")]
+    pub(crate) locales: String,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct LanguageMatch {
+    #[serde(rename = "_desired")]
+    pub(crate) desired: String,
+    #[serde(rename = "_supported")]
+    pub(crate) supported: String,
+    #[serde(rename = "_distance")]
+    pub(crate) distance: String,
+    #[serde(rename = "_oneway")]
+    pub(crate) oneway: Option<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum LanguageMatchingEntry {
+    ParadigmLocales(ParadigmLocales),
+    /// Variable names (including the `$`) to `+`-separated regions
+    MatchVariable(BTreeMap<String, String>),
+    LanguageMatch(LanguageMatch),
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct LanguageMatching {
+    #[serde(rename = "written-new")]
+    pub(crate) written_new: Vec<LanguageMatchingEntry>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Supplemental {
+    #[serde(rename = "languageMatching")]
+    pub(crate) language_matching: LanguageMatching,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) supplemental: Supplemental,
+}
@@ -19,6 +19,8 @@ pub(crate) mod directionality;
 pub(crate) mod displaynames;
 pub(crate) mod eras;
 pub(crate) mod exemplar_chars;
+#[cfg(feature = "experimental")]
+pub(crate) mod language_matching;
 pub(crate) mod likely_subtags;
 pub(crate) mod list_patterns;
 pub(crate) mod locale_resource;
@@ -30,6 +32,10 @@ pub(crate) mod personnames;
 #[cfg(feature = "experimental")]
 pub(crate) mod plural_ranges;
 pub(crate) mod plurals;
+#[cfg(feature = "experimental")]
+pub(crate) mod rbnf;
+#[cfg(feature = "experimental")]
+pub(crate) mod territory_containment;
 pub(crate) mod time_zones;
 #[cfg(feature = "experimental")]
 pub(crate) mod transforms;
@@ -26,6 +26,9 @@ pub(crate) struct Symbols {
     pub(crate) plus_sign: String,
     #[serde(rename = "percentSign")]
     pub(crate) percent_sign: String,
+    pub(crate) exponential: String,
+    #[serde(rename = "superscriptingExponent")]
+    pub(crate) superscripting_exponent: String,
 }
 
 #[derive(PartialEq, Debug, Deserialize)]
@@ -152,6 +155,12 @@ pub(crate) struct PercentFormattingPatterns {
     pub(crate) standard: String,
 }
 
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct ScientificFormattingPatterns {
+    /// Standard pattern
+    pub(crate) standard: String,
+}
+
 #[derive(PartialEq, Debug, Default)]
 pub(crate) struct NumberingSystemData {
     /// Map from numbering system to symbols
@@ -162,6 +171,8 @@ pub(crate) struct NumberingSystemData {
     pub(crate) currency_patterns: HashMap<String, CurrencyFormattingPatterns>,
     /// Map from numbering system to percent patterns
     pub(crate) percent_patterns: HashMap<String, PercentFormattingPatterns>,
+    /// Map from numbering system to scientific patterns
+    pub(crate) scientific_patterns: HashMap<String, ScientificFormattingPatterns>,
 }
 
 pub(crate) struct NumberingSystemDataVisitor;
@@ -202,8 +213,12 @@ impl<'de> Visitor<'de> for NumberingSystemDataVisitor {
                     let value: PercentFormattingPatterns = access.next_value()?;
                     result.percent_patterns.insert(numsys.to_string(), value);
                 }
+                "scientificFormats" => {
+                    let value: ScientificFormattingPatterns = access.next_value()?;
+                    result.scientific_patterns.insert(numsys.to_string(), value);
+                }
                 _ => {
-                    // When needed, consume "scientificFormats", "percentFormats", ...
+                    // When needed, consume "miscPatterns", ...
                     // For now, ignore them.
                 }
             }
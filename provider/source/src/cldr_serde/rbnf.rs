@@ -0,0 +1,46 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON rbnf/<locale>.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-rbnf/rbnf/en.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+/// The rules of a rule set, usually as pairs of descriptor and body, such as
+/// `["100", "←← hundred[ →→];"]`.
+pub(crate) type RuleSet = Vec<Vec<String>>;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Rbnf {
+    /// The rule sets of each group, such as `SpelloutRules` or `OrdinalRules`.
+    pub(crate) rbnf: BTreeMap<String, BTreeMap<String, RuleSet>>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) rbnf: Rbnf,
+}
+
+impl Resource {
+    /// Returns the rules of all rule sets in ICU syntax.
+    pub(crate) fn to_icu_syntax(&self) -> String {
+        let mut rules = String::new();
+        for (name, rule_set) in self.rbnf.rbnf.values().flatten() {
+            rules.push_str(name);
+            rules.push_str(":\n");
+            for rule in rule_set {
+                let rule = rule.join(": ");
+                rules.push_str(&rule);
+                if !rule.ends_with(';') {
+                    rules.push(';');
+                }
+                rules.push('\n');
+            }
+        }
+        rules
+    }
+}
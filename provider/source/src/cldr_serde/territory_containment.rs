@@ -0,0 +1,29 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+//! Serde structs representing CLDR JSON territoryContainment.json files.
+//!
+//! Sample file:
+//! <https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-core/supplemental/territoryContainment.json>
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Containment {
+    #[serde(rename = "_contains")]
+    pub(crate) contains: Vec<String>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Supplemental {
+    /// Keys are regions, or regions with a status suffix such as `EU-status-grouping`
+    #[serde(rename = "territoryContainment")]
+    pub(crate) territory_containment: BTreeMap<String, Containment>,
+}
+
+#[derive(PartialEq, Debug, Deserialize)]
+pub(crate) struct Resource {
+    pub(crate) supplemental: Supplemental,
+}
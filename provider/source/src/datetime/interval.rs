@@ -0,0 +1,174 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use super::DatagenCalendar;
+use crate::cldr_serde::ca;
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use icu::datetime::provider::interval::*;
+use icu::datetime::provider::pattern::runtime;
+use icu_pattern::DoublePlaceholderPattern;
+use icu_provider::prelude::*;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use zerovec::ZeroMap2d;
+
+impl SourceDataProvider {
+    fn load_interval_patterns<M: DataMarker<DataStruct = IntervalPatterns<'static>>>(
+        &self,
+        req: DataRequest,
+        calendar: Option<DatagenCalendar>,
+    ) -> Result<DataResponse<M>, DataError>
+    where
+        Self: IterableDataProviderCached<M>,
+    {
+        self.check_req::<M>(req)?;
+        let data = self.get_datetime_resources(req.id.locale, calendar)?;
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(interval_convert(&data)?),
+        })
+    }
+
+    fn iter_interval_ids(
+        &self,
+        calendar: Option<DatagenCalendar>,
+    ) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .cldr()?
+            .dates(
+                calendar
+                    .map(DatagenCalendar::cldr_name)
+                    .unwrap_or("generic"),
+            )
+            .list_locales()?
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
+fn interval_convert(data: &ca::Dates) -> Result<IntervalPatterns<'static>, DataError> {
+    let interval_formats = data
+        .datetime_formats
+        .interval_formats
+        .as_ref()
+        .ok_or_else(|| DataError::custom("Missing intervalFormats"))?;
+
+    let fallback =
+        DoublePlaceholderPattern::try_from_str(&interval_formats.fallback, Default::default())
+            .map_err(|e| DataError::custom("Invalid interval fallback").with_display_context(&e))?;
+
+    let mut patterns = ZeroMap2d::new();
+    for (skeleton, differences) in &interval_formats.skeletons {
+        // Flexible day periods (`B`) are not supported by runtime patterns. Their skeletons
+        // are never selected at runtime, which uses the `a` skeletons instead.
+        if skeleton.contains('B') {
+            log::debug!("Skipping interval skeleton with flexible day period: {skeleton:?}");
+            continue;
+        }
+        for (difference, pattern) in differences {
+            let mut chars = difference.chars();
+            let (Some(difference), None) = (chars.next(), chars.next()) else {
+                return Err(DataError::custom("Invalid greatest difference")
+                    .with_display_context(difference));
+            };
+            let pattern = pattern.parse::<runtime::Pattern>().map_err(|e| {
+                DataError::custom("Invalid interval pattern")
+                    .with_display_context(pattern)
+                    .with_display_context(&e)
+            })?;
+            patterns.insert(
+                skeleton.as_str(),
+                &difference,
+                &*zerovec::ule::encode_varule_to_box(&pattern),
+            );
+        }
+    }
+
+    Ok(IntervalPatterns {
+        fallback: Cow::Owned(fallback),
+        patterns,
+    })
+}
+
+impl DataProvider<DatetimePatternsIntervalGregorianV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<DatetimePatternsIntervalGregorianV1>, DataError> {
+        self.load_interval_patterns(req, Some(DatagenCalendar::Gregorian))
+    }
+}
+
+impl IterableDataProviderCached<DatetimePatternsIntervalGregorianV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        self.iter_interval_ids(Some(DatagenCalendar::Gregorian))
+    }
+}
+
+impl DataProvider<DatetimePatternsIntervalGenericV1> for SourceDataProvider {
+    fn load(
+        &self,
+        req: DataRequest,
+    ) -> Result<DataResponse<DatetimePatternsIntervalGenericV1>, DataError> {
+        self.load_interval_patterns(req, None)
+    }
+}
+
+impl IterableDataProviderCached<DatetimePatternsIntervalGenericV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        self.iter_interval_ids(None)
+    }
+}
+
+#[test]
+fn test_en_interval_patterns() {
+    use icu::locale::langid;
+
+    let provider = SourceDataProvider::new_testing();
+    let data: DataResponse<DatetimePatternsIntervalGregorianV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierBorrowed::for_locale(&langid!("en").into()),
+            ..Default::default()
+        })
+        .unwrap();
+
+    assert_eq!(
+        data.payload
+            .get()
+            .patterns
+            .get_2d("yMMMd", &'d')
+            .map(runtime::Pattern::from)
+            .map(|p| p.to_string()),
+        Some("MMM d\u{2009}–\u{2009}d, y".to_string())
+    );
+    assert_eq!(
+        data.payload
+            .get()
+            .fallback
+            .interpolate(("A", "B"))
+            .to_string(),
+        "A\u{2009}–\u{2009}B"
+    );
+}
+
+#[test]
+fn test_all_interval_patterns() {
+    let provider = SourceDataProvider::new_testing();
+
+    for id in IterableDataProviderCached::<DatetimePatternsIntervalGregorianV1>::iter_ids_cached(
+        &provider,
+    )
+    .unwrap()
+    {
+        let data: DataResponse<DatetimePatternsIntervalGregorianV1> = provider
+            .load(DataRequest {
+                id: id.as_borrowed(),
+                ..Default::default()
+            })
+            .unwrap();
+        // The flexible day period skeletons are skipped
+        assert!(data.payload.get().patterns.get0("Bh").is_none());
+    }
+}
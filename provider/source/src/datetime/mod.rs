@@ -7,6 +7,8 @@ use crate::SourceDataProvider;
 use icu::calendar::AnyCalendarKind;
 use icu_provider::prelude::*;
 
+#[cfg(feature = "experimental")]
+mod interval;
 mod legacy;
 mod names;
 mod neo;
@@ -48,6 +48,43 @@ impl IterableDataProviderCached<DecimalSymbolsV1> for SourceDataProvider {
     }
 }
 
+impl DataProvider<DecimalExponentSymbolsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<DecimalExponentSymbolsV1>, DataError> {
+        self.check_req::<DecimalExponentSymbolsV1>(req)?;
+
+        let resource: &cldr_serde::numbers::Resource = self
+            .cldr()?
+            .numbers()
+            .read_and_parse(req.id.locale, "numbers.json")?;
+
+        let numbers = &resource.main.value.numbers;
+
+        let nsname = if !req.id.marker_attributes.is_empty() {
+            req.id.marker_attributes.as_str()
+        } else {
+            &numbers.default_numbering_system
+        };
+
+        let result =
+            DecimalExponentSymbols::try_from(NumbersWithNumsys(numbers, nsname)).map_err(|s| {
+                DataError::custom("Could not create decimal exponent symbols")
+                    .with_display_context(&s)
+                    .with_display_context(nsname)
+            })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(result),
+        })
+    }
+}
+
+impl IterableDataProviderCached<DecimalExponentSymbolsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        self.iter_ids_for_numbers_with_locales()
+    }
+}
+
 #[derive(Debug)]
 struct NumbersWithNumsys<'a>(
     pub(crate) &'a cldr_serde::numbers::Numbers,
@@ -103,6 +140,52 @@ impl TryFrom<NumbersWithNumsys<'_>> for DecimalSymbols<'static> {
     }
 }
 
+impl TryFrom<NumbersWithNumsys<'_>> for DecimalExponentSymbols<'static> {
+    type Error = Cow<'static, str>;
+
+    fn try_from(other: NumbersWithNumsys<'_>) -> Result<Self, Self::Error> {
+        let NumbersWithNumsys(numbers, nsname) = other;
+        let symbols = numbers
+            .numsys_data
+            .symbols
+            .get(nsname)
+            .ok_or("Could not find symbols for numbering system")?;
+        let settings = numbers
+            .numsys_data
+            .scientific_patterns
+            .get(nsname)
+            .ok_or("Could not find scientific patterns for numbering system")
+            .and_then(|patterns| {
+                parse_exponent_settings(&patterns.standard).ok_or("Invalid scientific pattern")
+            })?;
+
+        Ok(Self {
+            exponential: VarZeroCow::new_owned(symbols.exponential.clone().into_boxed_str()),
+            superscripting_exponent: VarZeroCow::new_owned(
+                symbols.superscripting_exponent.clone().into_boxed_str(),
+            ),
+            settings,
+        })
+    }
+}
+
+/// Reads the exponent of a scientific pattern such as `#E0` or `0.00E+00`.
+fn parse_exponent_settings(pattern: &str) -> Option<ExponentSettings> {
+    let (_, exponent) = pattern.split_once('E')?;
+    let (plus_sign, exponent) = match exponent.strip_prefix('+') {
+        Some(exponent) => (true, exponent),
+        None => (false, exponent),
+    };
+    let min_digits = exponent.chars().take_while(|&c| c == '0').count();
+    if min_digits == 0 {
+        return None;
+    }
+    Some(ExponentSettings {
+        min_digits: u8::try_from(min_digits).ok()?,
+        plus_sign,
+    })
+}
+
 #[test]
 fn test_basic() {
     use icu::locale::langid;
@@ -118,3 +201,52 @@ fn test_basic() {
     assert_eq!(ar_decimal.payload.get().decimal_separator(), "٫");
     assert_eq!(ar_decimal.payload.get().numsys(), "arab");
 }
+
+#[test]
+fn test_exponent_symbols() {
+    use icu::locale::langid;
+
+    let provider = SourceDataProvider::new_testing();
+
+    let ar_exponent: DataResponse<DecimalExponentSymbolsV1> = provider
+        .load(DataRequest {
+            id: DataIdentifierCow::from_locale(langid!("ar-EG").into()).as_borrowed(),
+            ..Default::default()
+        })
+        .unwrap();
+    assert_eq!(&*ar_exponent.payload.get().exponential, "أس");
+    assert_eq!(&*ar_exponent.payload.get().superscripting_exponent, "×");
+    assert_eq!(
+        ar_exponent.payload.get().settings,
+        ExponentSettings {
+            min_digits: 1,
+            plus_sign: false
+        }
+    );
+}
+
+#[test]
+fn test_parse_exponent_settings() {
+    assert_eq!(
+        parse_exponent_settings("#E0"),
+        Some(ExponentSettings {
+            min_digits: 1,
+            plus_sign: false
+        })
+    );
+    assert_eq!(
+        parse_exponent_settings("[#E0]"),
+        Some(ExponentSettings {
+            min_digits: 1,
+            plus_sign: false
+        })
+    );
+    assert_eq!(
+        parse_exponent_settings("0.00E+00"),
+        Some(ExponentSettings {
+            min_digits: 2,
+            plus_sign: true
+        })
+    );
+    assert_eq!(parse_exponent_settings("#,##0.###"), None);
+}
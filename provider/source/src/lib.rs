@@ -49,8 +49,12 @@ mod personnames;
 mod plurals;
 mod properties;
 #[cfg(feature = "experimental")]
+mod rbnf;
+#[cfg(feature = "experimental")]
 mod relativetime;
 mod segmenter;
+#[cfg(feature = "experimental")]
+mod spoof;
 mod time_zones;
 #[cfg(feature = "experimental")]
 mod transforms;
@@ -74,6 +78,9 @@ mod tests;
 /// * [`is_missing_cldr_error`](Self::is_missing_cldr_error)
 /// * [`is_missing_icuexport_error`](Self::is_missing_icuexport_error)
 /// * [`is_missing_segmenter_lstm_error`](Self::is_missing_segmenter_lstm_error)
+/// * [`is_missing_tzdb_error`](Self::is_missing_tzdb_error)
+/// * [`is_missing_ucd_error`](Self::is_missing_ucd_error)
+/// * [`is_missing_uts39_error`](Self::is_missing_uts39_error)
 #[allow(clippy::exhaustive_structs)] // any information will be added to SourceData
 #[derive(Debug, Clone)]
 pub struct SourceDataProvider {
@@ -81,6 +88,10 @@ pub struct SourceDataProvider {
     icuexport_paths: Option<Arc<SerdeCache>>,
     segmenter_lstm_paths: Option<Arc<SerdeCache>>,
     tzdb_paths: Option<Arc<TzdbCache>>,
+    #[cfg_attr(not(feature = "experimental"), allow(dead_code))]
+    ucd_paths: Option<Arc<AbstractFs>>,
+    #[cfg_attr(not(feature = "experimental"), allow(dead_code))]
+    uts39_paths: Option<Arc<AbstractFs>>,
     trie_type: TrieType,
     collation_root_han: CollationRootHan,
     pub(crate) timezone_horizon: Date<Iso>,
@@ -119,12 +130,17 @@ impl SourceDataProvider {
     /// The TZDB tag that has been verified to work with this version of `SourceDataProvider`.
     pub const TESTED_TZDB_TAG: &'static str = "2025b";
 
+    /// The Unicode version of the Unicode Character Database and UTS #39 data that has been
+    /// verified to work with this version of `SourceDataProvider`.
+    pub const TESTED_UNICODE_TAG: &'static str = "16.0.0";
+
     /// A provider using the data that has been verified to work with this version of `SourceDataProvider`.
     ///
     /// See [`TESTED_CLDR_TAG`](Self::TESTED_CLDR_TAG),
     /// [`TESTED_ICUEXPORT_TAG`](Self::TESTED_ICUEXPORT_TAG),
     /// [`TESTED_SEGMENTER_LSTM_TAG`](Self::TESTED_SEGMENTER_LSTM_TAG),
-    /// [`TESTED_TZDB_TAG`](Self::TESTED_TZDB_TAG).
+    /// [`TESTED_TZDB_TAG`](Self::TESTED_TZDB_TAG),
+    /// [`TESTED_UNICODE_TAG`](Self::TESTED_UNICODE_TAG).
     ///
     /// ✨ *Enabled with the `networking` Cargo feature.*
     #[cfg(feature = "networking")]
@@ -139,6 +155,7 @@ impl SourceDataProvider {
                     .with_icuexport_for_tag(Self::TESTED_ICUEXPORT_TAG)
                     .with_segmenter_lstm_for_tag(Self::TESTED_SEGMENTER_LSTM_TAG)
                     .with_tzdb_for_tag(Self::TESTED_TZDB_TAG)
+                    .with_unicode_for_tag(Self::TESTED_UNICODE_TAG)
             })
             .clone()
     }
@@ -154,6 +171,8 @@ impl SourceDataProvider {
             icuexport_paths: None,
             segmenter_lstm_paths: None,
             tzdb_paths: None,
+            ucd_paths: None,
+            uts39_paths: None,
             trie_type: Default::default(),
             timezone_horizon: Date::try_new_iso(2015, 1, 1).unwrap(),
             collation_root_han: Default::default(),
@@ -205,6 +224,25 @@ impl SourceDataProvider {
         })
     }
 
+    /// Adds Unicode Character Database source data to the provider. The path should point to a
+    /// local `ucd` directory or `UCD.zip` file (see [unicode.org](https://www.unicode.org/Public/)).
+    pub fn with_ucd(self, root: &Path) -> Result<Self, DataError> {
+        Ok(Self {
+            ucd_paths: Some(Arc::new(AbstractFs::new(root)?)),
+            ..self
+        })
+    }
+
+    /// Adds Unicode security mechanisms (UTS #39) source data to the provider. The path should
+    /// point to a local directory or ZIP file with `confusables.txt` and `IdentifierStatus.txt`
+    /// (see [unicode.org](https://www.unicode.org/Public/security/)).
+    pub fn with_uts39(self, root: &Path) -> Result<Self, DataError> {
+        Ok(Self {
+            uts39_paths: Some(Arc::new(AbstractFs::new(root)?)),
+            ..self
+        })
+    }
+
     /// Adds CLDR source data to the provider. The data will be downloaded from GitHub
     /// using the given tag (see [GitHub releases](https://github.com/unicode-org/cldr-json/releases)).
     ///
@@ -276,6 +314,26 @@ impl SourceDataProvider {
         }
     }
 
+    /// Adds Unicode Character Database and Unicode security mechanisms (UTS #39) source data to
+    /// the provider. The data will be downloaded from unicode.org using the given Unicode version
+    /// (see [unicode.org](https://www.unicode.org/Public/)).
+    ///
+    /// Also see: [`TESTED_UNICODE_TAG`](Self::TESTED_UNICODE_TAG)
+    ///
+    /// ✨ *Enabled with the `networking` Cargo feature.*
+    #[cfg(feature = "networking")]
+    pub fn with_unicode_for_tag(self, tag: &str) -> Self {
+        Self {
+            ucd_paths: Some(Arc::new(AbstractFs::new_from_url(format!(
+                "https://www.unicode.org/Public/{tag}/ucd/UCD.zip",
+            )))),
+            uts39_paths: Some(Arc::new(AbstractFs::new_from_url(format!(
+                "https://www.unicode.org/Public/security/{tag}/uts39-data-{tag}.zip",
+            )))),
+            ..self
+        }
+    }
+
     const MISSING_CLDR_ERROR: DataError =
         DataError::custom("Missing CLDR data. Use `.with_cldr[_for_tag]` to set CLDR data.");
 
@@ -289,6 +347,14 @@ impl SourceDataProvider {
     const MISSING_TZDB_ERROR: DataError =
         DataError::custom("Missing tzdb data. Use `.with_tzdb[_for_tag]` to set tzdb data.");
 
+    const MISSING_UCD_ERROR: DataError = DataError::custom(
+        "Missing UCD data. Use `.with_ucd` or `.with_unicode_for_tag` to set UCD data.",
+    );
+
+    const MISSING_UTS39_ERROR: DataError = DataError::custom(
+        "Missing UTS #39 data. Use `.with_uts39` or `.with_unicode_for_tag` to set UTS #39 data.",
+    );
+
     /// Identifies errors that are due to missing CLDR data.
     pub fn is_missing_cldr_error(mut e: DataError) -> bool {
         e.marker = None;
@@ -313,6 +379,18 @@ impl SourceDataProvider {
         e == Self::MISSING_TZDB_ERROR
     }
 
+    /// Identifies errors that are due to missing Unicode Character Database data.
+    pub fn is_missing_ucd_error(mut e: DataError) -> bool {
+        e.marker = None;
+        e == Self::MISSING_UCD_ERROR
+    }
+
+    /// Identifies errors that are due to missing UTS #39 data.
+    pub fn is_missing_uts39_error(mut e: DataError) -> bool {
+        e.marker = None;
+        e == Self::MISSING_UTS39_ERROR
+    }
+
     fn cldr(&self) -> Result<&CldrCache, DataError> {
         self.cldr_paths.as_deref().ok_or(Self::MISSING_CLDR_ERROR)
     }
@@ -333,6 +411,16 @@ impl SourceDataProvider {
         self.tzdb_paths.as_deref().ok_or(Self::MISSING_TZDB_ERROR)
     }
 
+    #[cfg(feature = "experimental")]
+    fn ucd(&self) -> Result<&AbstractFs, DataError> {
+        self.ucd_paths.as_deref().ok_or(Self::MISSING_UCD_ERROR)
+    }
+
+    #[cfg(feature = "experimental")]
+    fn uts39(&self) -> Result<&AbstractFs, DataError> {
+        self.uts39_paths.as_deref().ok_or(Self::MISSING_UTS39_ERROR)
+    }
+
     /// Set this to use tries optimized for speed instead of data size
     pub fn with_fast_tries(self) -> Self {
         Self {
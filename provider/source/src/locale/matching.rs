@@ -0,0 +1,317 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_serde;
+use crate::cldr_serde::language_matching::LanguageMatchingEntry;
+use crate::SourceDataProvider;
+
+use icu::locale::provider::matching::*;
+use icu::locale::subtags::{Language, Region, Script};
+use icu::locale::LanguageIdentifier;
+use icu_provider::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use zerovec::{VarZeroVec, ZeroVec};
+
+impl DataProvider<LocaleLanguageMatchingV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<LocaleLanguageMatchingV1>, DataError> {
+        self.check_req::<LocaleLanguageMatchingV1>(req)?;
+        let matching: &cldr_serde::language_matching::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/languageMatching.json")?;
+        let containment: &cldr_serde::territory_containment::Resource = self
+            .cldr()?
+            .core()
+            .read_and_parse("supplemental/territoryContainment.json")?;
+
+        let containment = containment
+            .supplemental
+            .territory_containment
+            .iter()
+            // Skip alternative groupings such as `EU-status-grouping`
+            .filter(|(region, _)| !region.contains('-'))
+            .map(|(region, containment)| {
+                Ok((
+                    parse_region(region)?,
+                    containment
+                        .contains
+                        .iter()
+                        .map(|r| parse_region(r))
+                        .collect::<Result<Vec<_>, _>>()?,
+                ))
+            })
+            .collect::<Result<BTreeMap<_, _>, DataError>>()?;
+
+        let mut variables = Vec::new();
+        for entry in &matching.supplemental.language_matching.written_new {
+            if let LanguageMatchingEntry::MatchVariable(vars) = entry {
+                for (name, value) in vars {
+                    let name = name.strip_prefix('$').ok_or_else(|| {
+                        DataError::custom("Invalid match variable").with_display_context(name)
+                    })?;
+                    let regions = value
+                        .split('+')
+                        .map(parse_region)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    variables.push((name, expand_regions(&regions, &containment)));
+                }
+            }
+        }
+
+        let mut rules = [Vec::new(), Vec::new(), Vec::new()];
+        let mut paradigm_locales = Vec::new();
+
+        for entry in &matching.supplemental.language_matching.written_new {
+            match entry {
+                LanguageMatchingEntry::ParadigmLocales(locales) => {
+                    for locale in locales.locales.split_whitespace() {
+                        let langid = LanguageIdentifier::try_from_str(&locale.replace('_', "-"))
+                            .ok()
+                            .filter(|langid| langid.variants.is_empty())
+                            .ok_or_else(|| {
+                                DataError::custom("Invalid paradigm locale")
+                                    .with_display_context(locale)
+                            })?;
+                        paradigm_locales.push((langid.language, langid.script, langid.region));
+                    }
+                }
+                LanguageMatchingEntry::MatchVariable(_) => {}
+                LanguageMatchingEntry::LanguageMatch(rule) => {
+                    let (subtags, desired) = parse_pattern(&rule.desired, &variables)?;
+                    let (supported_subtags, supported) =
+                        parse_pattern(&rule.supported, &variables)?;
+                    if subtags != supported_subtags {
+                        return Err(DataError::custom("Mismatched language match patterns")
+                            .with_display_context(&rule.desired)
+                            .with_display_context(&rule.supported));
+                    }
+                    rules[subtags - 1].push(LanguageMatchRule {
+                        desired,
+                        supported,
+                        distance: rule.distance.parse::<u8>().map_err(|_| {
+                            DataError::custom("Invalid distance")
+                                .with_display_context(&rule.distance)
+                        })?,
+                        oneway: rule.oneway.as_deref() == Some("true"),
+                    });
+                }
+            }
+        }
+
+        let [languages, scripts, regions] = rules;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(LanguageMatchingData {
+                languages: ZeroVec::alloc_from_slice(&languages),
+                scripts: ZeroVec::alloc_from_slice(&scripts),
+                regions: ZeroVec::alloc_from_slice(&regions),
+                variables: VarZeroVec::from(
+                    &variables
+                        .iter()
+                        .map(|(_, regions)| regions.as_slice())
+                        .collect::<Vec<_>>(),
+                ),
+                paradigm_locales: ZeroVec::alloc_from_slice(&paradigm_locales),
+            }),
+        })
+    }
+}
+
+impl crate::IterableDataProviderCached<LocaleLanguageMatchingV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+/// Parses a pattern such as `en_*_$!enUS`, returning its number of subtags.
+fn parse_pattern(
+    pattern: &str,
+    variables: &[(&str, Vec<Region>)],
+) -> Result<(usize, LanguageMatchPattern), DataError> {
+    let invalid =
+        || DataError::custom("Invalid language match pattern").with_display_context(pattern);
+    let mut parsed = LanguageMatchPattern::default();
+    let parts = pattern.split('_').collect::<Vec<_>>();
+    let [language, rest @ ..] = parts.as_slice() else {
+        return Err(invalid());
+    };
+    if *language != "*" {
+        parsed.language = Some(Language::try_from_str(language).map_err(|_| invalid())?);
+    }
+    if let [script, rest @ ..] = rest {
+        if *script != "*" {
+            parsed.script = Some(Script::try_from_str(script).map_err(|_| invalid())?);
+        }
+        if let [region, rest @ ..] = rest {
+            if !rest.is_empty() {
+                return Err(invalid());
+            }
+            if let Some(variable) = region.strip_prefix('$') {
+                let (negated, variable) = match variable.strip_prefix('!') {
+                    Some(variable) => (true, variable),
+                    None => (false, variable),
+                };
+                let index = variables
+                    .iter()
+                    .position(|(name, _)| *name == variable)
+                    .and_then(|index| u8::try_from(index).ok())
+                    .ok_or_else(invalid)?;
+                parsed.variable = Some(index);
+                parsed.negated = negated;
+            } else if *region != "*" {
+                parsed.region = Some(parse_region(region)?);
+            }
+        }
+    }
+    Ok((parts.len(), parsed))
+}
+
+fn parse_region(region: &str) -> Result<Region, DataError> {
+    Region::try_from_str(region)
+        .map_err(|_| DataError::custom("Invalid region").with_display_context(region))
+}
+
+/// Adds the regions contained in the given regions, as well as the macroregions whose
+/// regions are all contained.
+fn expand_regions(regions: &[Region], containment: &BTreeMap<Region, Vec<Region>>) -> Vec<Region> {
+    let mut expanded = BTreeSet::new();
+    let mut queue = regions.to_vec();
+    while let Some(region) = queue.pop() {
+        if expanded.insert(region) {
+            queue.extend(containment.get(&region).into_iter().flatten());
+        }
+    }
+    // For example, `419` (Latin America) is part of `019` (Americas), but not contained in it
+    loop {
+        let len = expanded.len();
+        for (region, contained) in containment {
+            if contained.iter().all(|r| expanded.contains(r)) {
+                expanded.insert(*region);
+            }
+        }
+        if expanded.len() == len {
+            break;
+        }
+    }
+    expanded.into_iter().collect()
+}
+
+#[test]
+fn test_basic() {
+    use icu::locale::subtags::{language, region};
+
+    let provider = SourceDataProvider::new_testing();
+
+    let data: DataResponse<LocaleLanguageMatchingV1> = provider.load(Default::default()).unwrap();
+    let data = data.payload.get();
+
+    let nb = data.languages.get(0).unwrap();
+    assert_eq!(nb.desired.language, Some(language!("nb")));
+    assert_eq!(nb.supported.language, Some(language!("no")));
+    assert_eq!(nb.distance, 1);
+    assert_eq!(
+        data.paradigm_locales.get(1),
+        Some((language!("en"), None, Some(region!("GB"))))
+    );
+
+    // es_*_$americas
+    let es = data
+        .regions
+        .iter()
+        .find(|rule| rule.desired.language == Some(language!("es")))
+        .unwrap();
+    assert_eq!(es.desired.script, None);
+    assert!(!es.desired.negated);
+    let americas = data
+        .variables
+        .get(es.desired.variable.unwrap().into())
+        .unwrap();
+    assert!(americas.iter().any(|r| r == region!("419")));
+    assert!(americas.iter().any(|r| r == region!("MX")));
+    assert!(!americas.iter().any(|r| r == region!("ES")));
+}
+
+#[test]
+fn test_locale_matcher() {
+    use icu::locale::langid;
+    use icu::locale::matcher::{LocaleMatcher, LocaleMatcherOptions};
+
+    let provider = SourceDataProvider::new_testing();
+
+    let matcher = LocaleMatcher::try_new_unstable(
+        &provider,
+        [
+            langid!("en"),
+            langid!("en-GB"),
+            langid!("es"),
+            langid!("es-419"),
+            langid!("pt-BR"),
+            langid!("pt-PT"),
+            langid!("zh-Hant"),
+            langid!("nb"),
+            langid!("de"),
+        ],
+        Default::default(),
+    )
+    .unwrap();
+
+    let best = |desired: &[LanguageIdentifier]| matcher.best_match(desired).map(|m| m.locale);
+
+    assert_eq!(best(&[langid!("en-US")]), Some(&langid!("en")));
+    assert_eq!(best(&[langid!("en-AU")]), Some(&langid!("en-GB")));
+    assert_eq!(best(&[langid!("en-CA")]), Some(&langid!("en")));
+    assert_eq!(best(&[langid!("es-MX")]), Some(&langid!("es-419")));
+    assert_eq!(best(&[langid!("es-AR")]), Some(&langid!("es-419")));
+    assert_eq!(best(&[langid!("es-GQ")]), Some(&langid!("es")));
+    assert_eq!(best(&[langid!("pt")]), Some(&langid!("pt-BR")));
+    assert_eq!(best(&[langid!("pt-AO")]), Some(&langid!("pt-PT")));
+    assert_eq!(best(&[langid!("zh-HK")]), Some(&langid!("zh-Hant")));
+    assert_eq!(best(&[langid!("zh")]), Some(&langid!("zh-Hant")));
+    assert_eq!(best(&[langid!("no")]), Some(&langid!("nb")));
+    assert_eq!(best(&[langid!("gsw")]), Some(&langid!("de")));
+    assert_eq!(best(&[langid!("ja")]), None);
+
+    // The first desired locale is preferred over a closer match for the second
+    assert_eq!(
+        best(&[langid!("de-AT"), langid!("en-AU")]),
+        Some(&langid!("de"))
+    );
+    // The second desired locale is used if the first one does not match
+    let result = matcher
+        .best_match(&[langid!("fr"), langid!("en-GB")])
+        .unwrap();
+    assert_eq!(result.locale, &langid!("en-GB"));
+    assert_eq!(result.desired_index, Some(1));
+    assert_eq!(result.supported_index, Some(1));
+    assert_eq!(result.distance, Some(0));
+
+    assert_eq!(matcher.distance(&langid!("en"), &langid!("en-Latn-US")), 0);
+    assert_eq!(matcher.distance(&langid!("gsw"), &langid!("de")), 9);
+    assert_eq!(matcher.distance(&langid!("de"), &langid!("gsw")), 85);
+    assert_eq!(
+        matcher.distance(&langid!("zh-Hans"), &langid!("zh-Hant")),
+        20
+    );
+    assert_eq!(
+        matcher.distance(&langid!("sr-Latn"), &langid!("sr-Cyrl")),
+        50
+    );
+
+    let mut options = LocaleMatcherOptions::default();
+    options.threshold = Some(60);
+    options.default_locale = Some(langid!("en"));
+    let matcher =
+        LocaleMatcher::try_new_unstable(&provider, [langid!("sr-Cyrl"), langid!("fr")], options)
+            .unwrap();
+
+    assert_eq!(
+        matcher.best_match(&[langid!("sr-Latn")]).map(|m| m.locale),
+        Some(&langid!("sr-Cyrl"))
+    );
+    let result = matcher.best_match(&[langid!("ja")]).unwrap();
+    assert_eq!(result.locale, &langid!("en"));
+    assert_eq!(result.desired_index, None);
+    assert_eq!(result.distance, None);
+}
@@ -5,4 +5,6 @@
 pub(crate) mod aliases;
 pub(crate) mod directionality;
 pub(crate) mod likely_subtags;
+#[cfg(feature = "experimental")]
+pub(crate) mod matching;
 pub(crate) mod parents;
@@ -0,0 +1,204 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use icu::properties::provider::character_names::*;
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+
+/// The prefixes of the names of the ranges in `UnicodeData.txt` that are derived by rule NR2,
+/// by the label of the range.
+///
+/// Ranges of Hangul syllables (rule NR1), surrogates and private use characters are omitted.
+const RANGE_PREFIXES: &[(&str, &str)] = &[
+    ("CJK Ideograph", "CJK UNIFIED IDEOGRAPH-"),
+    ("Tangut Ideograph", "TANGUT IDEOGRAPH-"),
+];
+
+impl DataProvider<PropertyCharacterNamesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<PropertyCharacterNamesV1>, DataError> {
+        self.check_req::<PropertyCharacterNamesV1>(req)?;
+
+        // (code point, kind, name)
+        let mut names = Vec::<(char, CharacterNameKind, &str)>::new();
+        // (start, end, prefix)
+        let mut algorithmic = Vec::<(char, char, &str)>::new();
+
+        let unicode_data = self.ucd()?.read_to_string("UnicodeData.txt")?;
+        let mut range_start = None;
+        for fields in lines(&unicode_data) {
+            let [code_point, name, ..] = fields.as_slice() else {
+                return Err(DataError::custom("Invalid UnicodeData.txt line")
+                    .with_display_context(&fields.join(";")));
+            };
+            if let Some(label) = name.strip_prefix('<') {
+                // Ranges include surrogates, so their bounds are not necessarily `char`s
+                let ch = parse_code_point(code_point)?;
+                if let Some(label) = label.strip_suffix(", First>") {
+                    range_start = Some((ch, label));
+                } else if let Some(label) = label.strip_suffix(", Last>") {
+                    let Some((start, start_label)) = range_start.take() else {
+                        return Err(DataError::custom("Unmatched range in UnicodeData.txt")
+                            .with_display_context(label));
+                    };
+                    if start_label != label {
+                        return Err(DataError::custom("Unmatched range in UnicodeData.txt")
+                            .with_display_context(label));
+                    }
+                    if let Some(&(_, prefix)) = RANGE_PREFIXES
+                        .iter()
+                        .find(|(range_label, _)| label.starts_with(range_label))
+                    {
+                        algorithmic.push((to_char(start)?, to_char(ch)?, prefix));
+                    }
+                }
+                // Other labels, such as `<control>`, are not names
+                continue;
+            }
+            let ch = to_char(parse_code_point(code_point)?)?;
+            if let Some(prefix) = name.strip_suffix(code_point) {
+                if prefix.ends_with('-') {
+                    // Names such as `CJK COMPATIBILITY IDEOGRAPH-F900` are derived by rule NR2
+                    match algorithmic.last_mut() {
+                        Some((_, end, last_prefix))
+                            if *last_prefix == prefix && u32::from(*end) + 1 == u32::from(ch) =>
+                        {
+                            *end = ch
+                        }
+                        _ => algorithmic.push((ch, ch, prefix)),
+                    }
+                    continue;
+                }
+            }
+            names.push((ch, CharacterNameKind::Name, name));
+        }
+
+        let name_aliases = self.ucd()?.read_to_string("NameAliases.txt")?;
+        for fields in lines(&name_aliases) {
+            let [code_point, alias, kind] = fields.as_slice() else {
+                return Err(DataError::custom("Invalid NameAliases.txt line")
+                    .with_display_context(&fields.join(";")));
+            };
+            let kind = match *kind {
+                "correction" => CharacterNameKind::Correction,
+                "control" => CharacterNameKind::Control,
+                "alternate" => CharacterNameKind::Alternate,
+                "figment" => CharacterNameKind::Figment,
+                "abbreviation" => CharacterNameKind::Abbreviation,
+                _ => {
+                    return Err(
+                        DataError::custom("Unknown name alias type").with_display_context(kind)
+                    )
+                }
+            };
+            names.push((to_char(parse_code_point(code_point)?)?, kind, alias));
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(CharacterNameTable::try_from_names(
+                &names,
+                &algorithmic,
+            )?),
+        })
+    }
+}
+
+impl IterableDataProviderCached<PropertyCharacterNamesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+/// Returns the fields of the lines of a UCD file, skipping comments and empty lines.
+fn lines(file: &str) -> impl Iterator<Item = Vec<&str>> {
+    file.lines()
+        .map(|line| line.split_once('#').map_or(line, |(data, _)| data).trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split(';').map(str::trim).collect())
+}
+
+fn parse_code_point(code_point: &str) -> Result<u32, DataError> {
+    u32::from_str_radix(code_point, 16)
+        .map_err(|_| DataError::custom("Invalid code point").with_display_context(code_point))
+}
+
+fn to_char(code_point: u32) -> Result<char, DataError> {
+    char::from_u32(code_point)
+        .ok_or_else(|| DataError::custom("Invalid code point").with_debug_context(&code_point))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::properties::{CharacterNames, NameAliasType};
+
+    #[test]
+    fn test_character_names() {
+        let provider = SourceDataProvider::new_testing();
+        let names = CharacterNames::try_new_unstable(&provider).unwrap();
+        let names = names.as_borrowed();
+
+        for (ch, name) in [
+            ('A', "LATIN CAPITAL LETTER A"),
+            ('ÿ', "LATIN SMALL LETTER Y WITH DIAERESIS"),
+            ('\u{1180}', "HANGUL JUNGSEONG O-E"),
+            ('\u{116C}', "HANGUL JUNGSEONG OE"),
+            ('一', "CJK UNIFIED IDEOGRAPH-4E00"),
+            ('\u{F900}', "CJK COMPATIBILITY IDEOGRAPH-F900"),
+            ('\u{1B170}', "NUSHU CHARACTER-1B170"),
+            ('각', "HANGUL SYLLABLE GAG"),
+            ('힣', "HANGUL SYLLABLE HIH"),
+            ('아', "HANGUL SYLLABLE A"),
+        ] {
+            assert_eq!(names.get(ch).unwrap().to_string(), name);
+            assert_eq!(names.get_char_strict(name), Some(ch), "{name}");
+            assert_eq!(names.get_char_loose(name), Some(ch), "{name}");
+        }
+
+        assert!(names.get('\0').is_none());
+        assert!(names.get('\u{E000}').is_none());
+        assert_eq!(names.get_char_strict("NULL"), Some('\0'));
+        assert_eq!(names.get_char_strict("NUL"), Some('\0'));
+        assert_eq!(
+            names
+                .aliases('\u{FEFF}')
+                .map(|(alias_type, alias)| (alias_type, alias.to_string()))
+                .collect::<Vec<_>>(),
+            [
+                (NameAliasType::Alternate, "BYTE ORDER MARK".into()),
+                (NameAliasType::Abbreviation, "BOM".into()),
+                (NameAliasType::Abbreviation, "ZWNBSP".into()),
+            ]
+        );
+
+        // Loose matching
+        assert_eq!(names.get_char_loose("latin_capital_letter_a"), Some('A'));
+        assert_eq!(names.get_char_loose("Latin capital letter-A"), Some('A'));
+        assert_eq!(
+            names.get_char_loose("hangul jungseong o-e"),
+            Some('\u{1180}')
+        );
+        assert_eq!(
+            names.get_char_loose("hangul jungseong oe"),
+            Some('\u{116C}')
+        );
+        assert_eq!(
+            names.get_char_loose("cjk unified ideograph-4e00"),
+            Some('一')
+        );
+        assert_eq!(names.get_char_loose("hangul syllable gag"), Some('각'));
+        assert_eq!(names.get_char_loose("line feed"), Some('\n'));
+        assert_eq!(names.get_char_loose("LATIN CAPITAL LETTER -A"), None);
+
+        // Strict matching
+        assert_eq!(names.get_char_strict("LATIN CAPITAL  LETTER A"), None);
+        assert_eq!(names.get_char_strict("CJK UNIFIED IDEOGRAPH-4e00"), None);
+        assert_eq!(names.get_char_strict("CJK UNIFIED IDEOGRAPH-04E00"), None);
+        assert_eq!(names.get_char_strict("CJK UNIFIED IDEOGRAPH-0041"), None);
+        assert_eq!(names.get_char_strict("HANGUL SYLLABLE GX"), None);
+        assert_eq!(names.get_char_strict(""), None);
+    }
+}
@@ -7,6 +7,8 @@
 
 mod bidi;
 mod bin_cp_set;
+#[cfg(feature = "experimental")]
+mod character_names;
 mod emoji_set;
 mod enum_codepointtrie;
 mod script;
@@ -0,0 +1,121 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::cldr_serde::rbnf::Resource;
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use icu::experimental::rbnf::provider::*;
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+impl DataProvider<RbnfRulesV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<RbnfRulesV1>, DataError> {
+        self.check_req::<RbnfRulesV1>(req)?;
+
+        let file_name = if req.id.locale.is_unknown() {
+            "root.json".to_owned()
+        } else {
+            format!("{}.json", req.id.locale)
+        };
+        let resource: &Resource = self.cldr()?.rbnf().read_and_parse(&file_name)?;
+
+        let rules = RbnfRules::try_from_str(&resource.to_icu_syntax()).map_err(|e| {
+            DataError::custom("RBNF rules")
+                .with_display_context(&e)
+                .with_display_context(&req.id.locale)
+        })?;
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(rules),
+        })
+    }
+}
+
+impl IterableDataProviderCached<RbnfRulesV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(self
+            .cldr()?
+            .rbnf()
+            .list()?
+            .filter_map(|file| {
+                let locale = file.strip_suffix(".json")?;
+                if locale == "root" {
+                    Some(DataLocale::default())
+                } else {
+                    DataLocale::from_str(locale).ok()
+                }
+            })
+            .map(DataIdentifierCow::from_locale)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_icu_syntax() {
+        let resource: Resource = serde_json::from_str(
+            r#"{
+                "rbnf": {
+                    "identity": { "language": "en" },
+                    "rbnf": {
+                        "OrdinalRules": {
+                            "%digits-ordinal": [
+                                ["-x", "−→→;"],
+                                ["0", "=#,##0=$(ordinal,one{st}two{nd}few{rd}other{th})$;"]
+                            ]
+                        },
+                        "SpelloutRules": {
+                            "%%lenient-parse": [
+                                ["&[last primary ignorable ] ", "<< ' ' << ',' << '-';"]
+                            ],
+                            "%spellout-numbering": [
+                                ["0", "zero;"],
+                                ["1", "one;"],
+                                ["2", "=#,##0="]
+                            ]
+                        }
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let rules = RbnfRules::try_from_str(&resource.to_icu_syntax()).unwrap();
+        assert_eq!(
+            rules.names.iter().collect::<Vec<_>>(),
+            ["%digits-ordinal", "%spellout-numbering"]
+        );
+    }
+
+    #[test]
+    fn test_load() {
+        use icu::locale::langid;
+
+        let provider = SourceDataProvider::new_testing();
+
+        let ids = IterableDataProviderCached::<RbnfRulesV1>::iter_ids_cached(&provider).unwrap();
+        assert!(ids.contains(&DataIdentifierCow::from_locale(langid!("en").into())));
+        assert!(ids.contains(&DataIdentifierCow::default()));
+
+        let en: DataResponse<RbnfRulesV1> = provider
+            .load(DataRequest {
+                id: DataIdentifierCow::from_locale(langid!("en").into()).as_borrowed(),
+                ..Default::default()
+            })
+            .unwrap();
+        let en = en.payload.get();
+        assert!(en.rule_set("%spellout-cardinal").is_some());
+        assert!(en.rule_set("%digits-ordinal").is_some());
+        // Rule sets that are only used for parsing are not included
+        assert!(en.rule_set("%%lenient-parse").is_none());
+
+        let root: DataResponse<RbnfRulesV1> = provider.load(Default::default()).unwrap();
+        assert!(root.payload.get().rule_set("%roman-upper").is_some());
+    }
+}
@@ -0,0 +1,147 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use icu::collections::codepointinvlist::CodePointInversionListBuilder;
+use icu::experimental::spoof::provider::*;
+use icu_provider::prelude::*;
+use std::collections::HashSet;
+use zerovec::ZeroMap;
+
+impl DataProvider<SpoofDataV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<SpoofDataV1>, DataError> {
+        self.check_req::<SpoofDataV1>(req)?;
+
+        let mut prototypes = ZeroMap::new();
+        for fields in lines(&self.uts39()?.read_to_string("confusables.txt")?) {
+            let [source, prototype, ..] = fields.as_slice() else {
+                return Err(DataError::custom("Invalid confusables.txt line")
+                    .with_display_context(&fields.join(";")));
+            };
+            let prototype = prototype
+                .split_whitespace()
+                .map(parse_code_point)
+                .collect::<Result<String, _>>()?;
+            prototypes.insert(&parse_code_point(source)?, prototype.as_str());
+        }
+
+        let mut allowed = CodePointInversionListBuilder::new();
+        for fields in lines(&self.uts39()?.read_to_string("IdentifierStatus.txt")?) {
+            let [range, status] = fields.as_slice() else {
+                return Err(DataError::custom("Invalid IdentifierStatus.txt line")
+                    .with_display_context(&fields.join(";")));
+            };
+            if *status != "Allowed" {
+                continue;
+            }
+            match range.split_once("..") {
+                Some((start, end)) => {
+                    allowed.add_range(parse_code_point(start)?..=parse_code_point(end)?)
+                }
+                None => allowed.add_char(parse_code_point(range)?),
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(SpoofData {
+                prototypes,
+                allowed: allowed.build(),
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<SpoofDataV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+/// Returns the fields of the lines of a UTS #39 data file, skipping comments and empty lines.
+fn lines(file: &str) -> impl Iterator<Item = Vec<&str>> {
+    file.lines()
+        .map(|line| line.split_once('#').map_or(line, |(data, _)| data).trim())
+        // confusables.txt starts with a byte order mark
+        .map(|line| line.trim_start_matches('\u{FEFF}'))
+        .filter(|line| !line.is_empty())
+        .map(|line| line.split(';').map(str::trim).collect())
+}
+
+fn parse_code_point(code_point: &str) -> Result<char, DataError> {
+    u32::from_str_radix(code_point, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| DataError::custom("Invalid code point").with_display_context(code_point))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::experimental::spoof::{ConfusableType, RestrictionLevel, SpoofChecker};
+
+    #[test]
+    fn test_confusables() {
+        let provider = SourceDataProvider::new_testing();
+        let checker = SpoofChecker::try_new_unstable(&provider).unwrap();
+
+        assert_eq!(checker.skeleton("paypal"), "paypal");
+        assert_eq!(checker.skeleton("pаypаl"), "paypal");
+        assert_eq!(checker.skeleton("payp\u{AD}al"), "paypal");
+        assert_eq!(checker.skeleton("m"), "rn");
+        assert_eq!(checker.skeleton("I1l"), "lll");
+
+        assert_eq!(
+            checker.confusable_type("paypal", "pаypal"),
+            Some(ConfusableType::MixedScript)
+        );
+        assert_eq!(
+            checker.confusable_type("scope", "ѕсоре"),
+            Some(ConfusableType::WholeScript)
+        );
+        assert_eq!(
+            checker.confusable_type("corn", "com"),
+            Some(ConfusableType::SingleScript)
+        );
+        assert_eq!(
+            checker.confusable_type("l0l", "lOl"),
+            Some(ConfusableType::SingleScript)
+        );
+        assert_eq!(
+            checker.confusable_type("paypal", "paypa1"),
+            Some(ConfusableType::SingleScript)
+        );
+        assert_eq!(checker.confusable_type("paypal", "pay pal"), None);
+        assert!(checker.are_confusable("ѕсоре", "ѕсoре"));
+
+        assert!(!checker.is_mixed_script("東京タワー"));
+        assert!(!checker.is_mixed_script("scope123"));
+        assert!(checker.is_mixed_script("ѕcope"));
+    }
+
+    #[test]
+    fn test_restriction_level() {
+        let provider = SourceDataProvider::new_testing();
+        let checker = SpoofChecker::try_new_unstable(&provider).unwrap();
+
+        for (s, level) in [
+            ("user_name", RestrictionLevel::AsciiOnly),
+            ("café", RestrictionLevel::SingleScript),
+            ("ελληνικά", RestrictionLevel::SingleScript),
+            ("東京タワー", RestrictionLevel::SingleScript),
+            ("iPhone対応", RestrictionLevel::HighlyRestrictive),
+            ("hello한국", RestrictionLevel::HighlyRestrictive),
+            ("hello مرحبا", RestrictionLevel::Unrestricted),
+            ("helloمرحبا", RestrictionLevel::ModeratelyRestrictive),
+            ("helloмир", RestrictionLevel::MinimallyRestrictive),
+            ("helloαβγ", RestrictionLevel::MinimallyRestrictive),
+            ("helloмирمرحبا", RestrictionLevel::MinimallyRestrictive),
+            ("user@name", RestrictionLevel::Unrestricted),
+        ] {
+            assert_eq!(checker.restriction_level(s), level, "{s}");
+        }
+        assert!(checker.restriction_level("café") <= RestrictionLevel::HighlyRestrictive);
+    }
+}
@@ -238,6 +238,7 @@ impl SourceDataProvider {
                         ("cldr-core/supplemental/calendarData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/calendarData.json").as_slice()),
                         ("cldr-core/supplemental/currencyData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/currencyData.json").as_slice()),
                         ("cldr-core/supplemental/units.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/units.json").as_slice()),
+                        ("cldr-core/supplemental/languageMatching.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/languageMatching.json").as_slice()),
                         ("cldr-core/supplemental/likelySubtags.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/likelySubtags.json").as_slice()),
                         ("cldr-core/supplemental/metaZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/metaZones.json").as_slice()),
                         ("cldr-core/supplemental/primaryZones.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/primaryZones.json").as_slice()),
@@ -247,6 +248,7 @@ impl SourceDataProvider {
                         ("cldr-core/supplemental/parentLocales.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/parentLocales.json").as_slice()),
                         ("cldr-core/supplemental/pluralRanges.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/pluralRanges.json").as_slice()),
                         ("cldr-core/supplemental/plurals.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/plurals.json").as_slice()),
+                        ("cldr-core/supplemental/territoryContainment.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/territoryContainment.json").as_slice()),
                         ("cldr-core/supplemental/weekData.json", include_bytes!("../../tests/data/cldr/cldr-core/supplemental/weekData.json").as_slice()),
                         ("cldr-dates-full/main/ar/ca-generic.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar/ca-generic.json").as_slice()),
                         ("cldr-dates-full/main/ar-EG/ca-generic.json", include_bytes!("../../tests/data/cldr/cldr-dates-full/main/ar-EG/ca-generic.json").as_slice()),
@@ -498,6 +500,8 @@ impl SourceDataProvider {
                         ("cldr-person-names-full/main/th/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/th/personNames.json").as_slice()),
                         ("cldr-person-names-full/main/tr/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/tr/personNames.json").as_slice()),
                         ("cldr-person-names-full/main/und/personNames.json", include_bytes!("../../tests/data/cldr/cldr-person-names-full/main/und/personNames.json").as_slice()),
+                        ("cldr-rbnf/rbnf/en.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/en.json").as_slice()),
+                        ("cldr-rbnf/rbnf/root.json", include_bytes!("../../tests/data/cldr/cldr-rbnf/rbnf/root.json").as_slice()),
                         ("cldr-transforms/transforms/Any-Publishing.json", include_bytes!("../../tests/data/cldr/cldr-transforms/transforms/Any-Publishing.json").as_slice()),
                         ("cldr-transforms/transforms/Any-Publishing.txt", include_bytes!("../../tests/data/cldr/cldr-transforms/transforms/Any-Publishing.txt").as_slice()),
                         ("cldr-transforms/transforms/Bengali-Arabic.json", include_bytes!("../../tests/data/cldr/cldr-transforms/transforms/Bengali-Arabic.json").as_slice()),
@@ -692,6 +696,18 @@ impl SourceDataProvider {
                         ("southamerica", include_bytes!("../../tests/data/tzdb/southamerica").as_slice())
                     ].into_iter().collect(),
                 ), transitions: Default::default() })),
+                ucd_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        ("NameAliases.txt", include_bytes!("../../tests/data/ucd/NameAliases.txt").as_slice()),
+                        ("UnicodeData.txt", include_bytes!("../../tests/data/ucd/UnicodeData.txt").as_slice())
+                    ].into_iter().collect(),
+                ))),
+                uts39_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        ("IdentifierStatus.txt", include_bytes!("../../tests/data/uts39/IdentifierStatus.txt").as_slice()),
+                        ("confusables.txt", include_bytes!("../../tests/data/uts39/confusables.txt").as_slice())
+                    ].into_iter().collect(),
+                ))),
                 ..SourceDataProvider::new_custom()
             })
             .clone()
@@ -24,6 +24,8 @@ use twox_hash::XxHash64;
 
 mod convert;
 mod names;
+#[cfg(feature = "experimental")]
+mod transitions;
 mod windows;
 
 type Cache<T> = OnceLock<Result<T, DataError>>;
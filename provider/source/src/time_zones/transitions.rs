@@ -0,0 +1,320 @@
+// This file is part of ICU4X. For terms of use, please see the file
+// called LICENSE at the top level of the ICU4X source tree
+// (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
+
+use crate::IterableDataProviderCached;
+use crate::SourceDataProvider;
+use icu::time::provider::transitions::*;
+use icu::time::zone::TimeZoneVariant;
+use icu_provider::prelude::*;
+use parse_zoneinfo::line::{DaySpec, TimeType, Weekday, Year};
+use parse_zoneinfo::table::{RuleInfo, Saving, Table, ZoneInfo};
+use parse_zoneinfo::transitions::{FixedTimespan, TableTransitions};
+use std::collections::HashSet;
+use zerovec::{ZeroMap, ZeroVec};
+
+impl DataProvider<TimezoneTransitionsV1> for SourceDataProvider {
+    fn load(&self, req: DataRequest) -> Result<DataResponse<TimezoneTransitionsV1>, DataError> {
+        self.check_req::<TimezoneTransitionsV1>(req)?;
+        let tzdb = self.tzdb()?.transitions()?;
+
+        let mut transitions = Vec::new();
+        let mut transition_ranges = ZeroMap::new();
+        let mut rules = ZeroMap::new();
+
+        for (bcp47, iana) in self.bcp47_to_canonical_iana_map()? {
+            let (Some(zoneset), Some(timespans)) = (tzdb.get_zoneset(iana), tzdb.timespans(iana))
+            else {
+                continue;
+            };
+
+            let rule = final_rule(tzdb, zoneset);
+            // Transitions from the first year of the rule on are computed at runtime
+            let cutoff = rule
+                .map(|r| Year::Number(r.start_year.into()))
+                .map(|y| parse_zoneinfo::line::ChangeTime::UntilYear(y).to_timestamp())
+                .unwrap_or(i64::MAX);
+
+            let start = transitions.len() as u32;
+            transitions.push((i64::MIN, transition_offset(&timespans.first)));
+            transitions.extend(
+                timespans
+                    .rest
+                    .iter()
+                    .filter(|&&(start, _)| start < cutoff)
+                    .map(|(start, timespan)| (*start, transition_offset(timespan))),
+            );
+            transition_ranges.insert(bcp47, &(start, transitions.len() as u32));
+            if let Some(rule) = rule {
+                rules.insert(bcp47, &rule);
+            }
+        }
+
+        Ok(DataResponse {
+            metadata: Default::default(),
+            payload: DataPayload::from_owned(TimeZoneTransitions {
+                transitions: ZeroVec::alloc_from_slice(&transitions),
+                transition_ranges,
+                rules,
+            }),
+        })
+    }
+}
+
+impl IterableDataProviderCached<TimezoneTransitionsV1> for SourceDataProvider {
+    fn iter_ids_cached(&self) -> Result<HashSet<DataIdentifierCow<'static>>, DataError> {
+        Ok(HashSet::from_iter([Default::default()]))
+    }
+}
+
+fn transition_offset(timespan: &FixedTimespan) -> TransitionOffset {
+    TransitionOffset {
+        offset_seconds: timespan.total_offset() as i32,
+        variant: TimeZoneVariant::from_rearguard_isdst(timespan.dst_offset != 0),
+    }
+}
+
+/// Returns the rule that a zone follows indefinitely, if its last zone line uses a pair
+/// of rules that applies until the end of time.
+fn final_rule(tzdb: &Table, zoneset: &[ZoneInfo]) -> Option<ZoneRule> {
+    let [.., previous, last] = zoneset else {
+        return None;
+    };
+    let Saving::Multiple(ref ruleset) = last.saving else {
+        return None;
+    };
+    let ruleset = tzdb.rulesets.get(ruleset)?;
+
+    let (unbounded, bounded): (Vec<&RuleInfo>, Vec<&RuleInfo>) = ruleset
+        .iter()
+        .partition(|r| r.to_year == Some(Year::Maximum));
+    let (start, end) = match unbounded.as_slice() {
+        [a, b] if a.time_to_add != 0 && b.time_to_add == 0 => (a, b),
+        [a, b] if a.time_to_add == 0 && b.time_to_add != 0 => (b, a),
+        _ => return None,
+    };
+
+    let year = |y: Year| match y {
+        Year::Number(y) => Some(y),
+        _ => None,
+    };
+    let start_year = [
+        year(start.from_year)?,
+        year(end.from_year)?,
+        previous.end_time?.year() + 1,
+    ]
+    .into_iter()
+    .chain(
+        bounded
+            .iter()
+            .filter_map(|r| year(r.to_year.unwrap_or(r.from_year)).map(|y| y + 1)),
+    )
+    .max()?;
+
+    Some(ZoneRule {
+        start_year: start_year.try_into().ok()?,
+        standard_offset_seconds: last.offset.try_into().ok()?,
+        daylight_savings_seconds: start.time_to_add.try_into().ok()?,
+        start: transition_rule(start)?,
+        end: transition_rule(end)?,
+    })
+}
+
+fn transition_rule(rule: &RuleInfo) -> Option<TransitionRule> {
+    fn iso_weekday(weekday: Weekday) -> u8 {
+        match weekday {
+            Weekday::Monday => 1,
+            Weekday::Tuesday => 2,
+            Weekday::Wednesday => 3,
+            Weekday::Thursday => 4,
+            Weekday::Friday => 5,
+            Weekday::Saturday => 6,
+            Weekday::Sunday => 7,
+        }
+    }
+
+    let (day, weekday, on_or_after) = match rule.day {
+        DaySpec::Ordinal(day) => (day, 0, false),
+        DaySpec::Last(weekday) => (0, iso_weekday(weekday), false),
+        DaySpec::LastOnOrBefore(weekday, day) => (day, iso_weekday(weekday), false),
+        DaySpec::FirstOnOrAfter(weekday, day) => (day, iso_weekday(weekday), true),
+    };
+
+    Some(TransitionRule {
+        month: rule.month as u8,
+        day: day.try_into().ok()?,
+        weekday,
+        on_or_after,
+        time_seconds: rule.time.try_into().ok()?,
+        time_kind: match rule.time_type {
+            TimeType::Wall => TransitionTimeKind::Wall,
+            TimeType::Standard => TransitionTimeKind::Standard,
+            TimeType::UTC => TransitionTimeKind::Utc,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use icu::calendar::Date;
+    use icu::locale::subtags::subtag;
+    use icu::time::zone::rules::{Disambiguation, LocalTimeError, TimeZoneRules};
+    use icu::time::zone::IanaParser;
+    use icu::time::{DateTime, Time, TimeZone};
+
+    #[test]
+    fn test_transitions() {
+        let provider = SourceDataProvider::new_testing();
+        let rules = TimeZoneRules::try_new_unstable(&provider).unwrap();
+        let rules = rules.as_borrowed();
+        let parser = IanaParser::try_new_unstable(&provider).unwrap();
+
+        let offset = |iana: &str, epoch_seconds: i64| {
+            let offset = rules
+                .offset_for_epoch_milliseconds(
+                    parser.as_borrowed().parse(iana),
+                    epoch_seconds * 1000,
+                )
+                .unwrap();
+            (offset.offset.to_seconds(), offset.variant)
+        };
+
+        // 2025-03-09T07:00Z, start of daylight time in New York
+        assert_eq!(
+            offset("America/New_York", 1741503599),
+            (-5 * 3600, TimeZoneVariant::Standard)
+        );
+        assert_eq!(
+            offset("America/New_York", 1741503600),
+            (-4 * 3600, TimeZoneVariant::Daylight)
+        );
+        // 2060-11-07T06:00Z, end of daylight time in New York
+        assert_eq!(
+            offset("America/New_York", 2867032800 - 1),
+            (-4 * 3600, TimeZoneVariant::Daylight)
+        );
+        assert_eq!(
+            offset("America/New_York", 2867032800),
+            (-5 * 3600, TimeZoneVariant::Standard)
+        );
+        // 1945-08-14T23:00Z, "Peace Time" in New York
+        assert_eq!(
+            offset("America/New_York", -769395600),
+            (-4 * 3600, TimeZoneVariant::Daylight)
+        );
+        // Local mean time
+        assert_eq!(
+            offset("America/New_York", -3000000000),
+            (-17762, TimeZoneVariant::Standard)
+        );
+        // Southern hemisphere
+        assert_eq!(
+            offset("Australia/Sydney", 1767225600),
+            (11 * 3600, TimeZoneVariant::Daylight)
+        );
+        // No daylight time
+        assert_eq!(
+            offset("Asia/Kolkata", 1767225600),
+            (19800, TimeZoneVariant::Standard)
+        );
+
+        let data: DataResponse<TimezoneTransitionsV1> = provider.load(Default::default()).unwrap();
+        let rule = data
+            .payload
+            .get()
+            .rules
+            .get_copied(&TimeZone(subtag!("usnyc")))
+            .unwrap();
+        assert_eq!(rule.start_year, 2007);
+        assert_eq!(rule.standard_offset_seconds, -5 * 3600);
+
+        // The rules reproduce the transitions computed by the tzdb crate, which go up to 2100
+        let tzdb = provider.tzdb().unwrap().transitions().unwrap();
+        for (time_zone, iana) in provider.bcp47_to_canonical_iana_map().unwrap() {
+            let Some(timespans) = tzdb.timespans(iana) else {
+                continue;
+            };
+            let mut previous = &timespans.first;
+            for (start, timespan) in &timespans.rest {
+                assert_eq!(
+                    rules
+                        .offset_for_epoch_milliseconds(*time_zone, start * 1000 - 1)
+                        .map(|o| o.offset.to_seconds() as i64),
+                    Some(previous.total_offset()),
+                    "{iana} {start}"
+                );
+                assert_eq!(
+                    rules
+                        .offset_for_epoch_milliseconds(*time_zone, start * 1000)
+                        .map(|o| o.offset.to_seconds() as i64),
+                    Some(timespan.total_offset()),
+                    "{iana} {start}"
+                );
+                previous = timespan;
+            }
+        }
+    }
+
+    #[test]
+    fn test_local_times() {
+        let provider = SourceDataProvider::new_testing();
+        let rules = TimeZoneRules::try_new_unstable(&provider).unwrap();
+        let rules = rules.as_borrowed();
+        let los_angeles = IanaParser::try_new_unstable(&provider)
+            .unwrap()
+            .as_borrowed()
+            .parse("America/Los_Angeles");
+
+        let local = |y, m, d, h, min| DateTime {
+            date: Date::try_new_iso(y, m, d).unwrap(),
+            time: Time::try_new(h, min, 0, 0).unwrap(),
+        };
+
+        // Skipped
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(
+                los_angeles,
+                local(2025, 3, 9, 2, 30),
+                Disambiguation::Later
+            ),
+            Ok(1741516200000)
+        );
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(
+                los_angeles,
+                local(2025, 3, 9, 2, 30),
+                Disambiguation::Reject
+            ),
+            Err(LocalTimeError::Skipped)
+        );
+
+        // Repeated
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(
+                los_angeles,
+                local(2025, 11, 2, 1, 30),
+                Disambiguation::Earlier
+            ),
+            Ok(1762072200000)
+        );
+        assert_eq!(
+            rules.epoch_milliseconds_from_local(
+                los_angeles,
+                local(2025, 11, 2, 1, 30),
+                Disambiguation::Later
+            ),
+            Ok(1762075800000)
+        );
+
+        let zdt = rules
+            .zoned_date_time_from_epoch_milliseconds(los_angeles, 1762075800000)
+            .unwrap();
+        assert_eq!(zdt.time, Time::try_new(1, 30, 0, 0).unwrap());
+        assert_eq!(zdt.zone.offset(), Some("-08:00".parse().unwrap()));
+        assert_eq!(
+            rules.epoch_milliseconds_from_zoned_date_time(&zdt, Disambiguation::Reject),
+            Ok(1762075800000)
+        );
+    }
+}
@@ -210,6 +210,7 @@ impl crate::IterableDataProviderCached<TransliteratorRulesV1> for SourceDataProv
 #[cfg(test)]
 mod tests {
     use super::*;
+    use icu::experimental::transliterate::Transliterator;
 
     #[test]
     fn test_de_ascii_forward() {
@@ -242,4 +243,18 @@ mod tests {
             .unwrap()
             .payload;
     }
+
+    #[test]
+    fn test_publishing_inverse() {
+        let provider = SourceDataProvider::new_testing();
+
+        let t = Transliterator::try_new_inverse_unstable(
+            &provider,
+            &provider,
+            &provider,
+            &"und-t-d0-publish".parse().unwrap(),
+        )
+        .unwrap();
+        assert_eq!(t.transliterate("a ≠ b… ©".into()), "a != b... (C)");
+    }
 }
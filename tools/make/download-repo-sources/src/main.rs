@@ -11,7 +11,7 @@ use simple_logger::SimpleLogger;
 use std::collections::BTreeSet;
 use std::fs::{self, File};
 use std::io::{self, BufWriter, Cursor, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use zip::ZipArchive;
 
 include!("../globs.rs.data");
@@ -107,6 +107,44 @@ fn main() -> eyre::Result<()> {
         Ok(())
     }
 
+    /// Reduces a semicolon-separated Unicode data file to the lines whose code point or
+    /// range intersects `UNICODE_TEST_RANGES`, removing comments and the spaces around fields.
+    fn filter_unicode_data(path: &Path) -> eyre::Result<()> {
+        let data =
+            fs::read_to_string(path).with_context(|| format!("Failed to read file {:?}", &path))?;
+        let name = path.file_name().unwrap().to_string_lossy();
+
+        let mut out = crlify::BufWriterWithLineEndingFix::new(
+            File::create(path).with_context(|| format!("Failed to create file {:?}", &path))?,
+        );
+        writeln!(
+            out,
+            "# {name} (Unicode {}), filtered to UNICODE_TEST_RANGES by download-repo-sources",
+            SourceDataProvider::TESTED_UNICODE_TAG
+        )?;
+
+        for line in data.lines() {
+            let line = line.split('#').next().unwrap();
+            let line = line.trim_start_matches('\u{FEFF}').trim();
+            if line.is_empty() {
+                continue;
+            }
+            let fields = line.split(';').map(str::trim).collect::<Vec<_>>();
+            let (start, end) = fields[0].split_once("..").unwrap_or((fields[0], fields[0]));
+            let start = u32::from_str_radix(start, 16)
+                .with_context(|| format!("Invalid code point in {name}: {line}"))?;
+            let end = u32::from_str_radix(end, 16)
+                .with_context(|| format!("Invalid code point in {name}: {line}"))?;
+            if UNICODE_TEST_RANGES
+                .iter()
+                .any(|&(lo, hi)| start <= hi && lo <= end)
+            {
+                writeln!(out, "{}", fields.join(";"))?;
+            }
+        }
+        Ok(())
+    }
+
     fn extract_tar(
         tar: PathBuf,
         paths: BTreeSet<String>,
@@ -212,6 +250,47 @@ fn main() -> eyre::Result<()> {
         &mut Default::default(),
     )?;
 
+    std::fs::remove_dir_all(out_root.join("tests/data/ucd"))?;
+    extract_zip(
+        cached(&format!(
+            "https://www.unicode.org/Public/{}/ucd/UCD.zip",
+            SourceDataProvider::TESTED_UNICODE_TAG,
+        ))
+        .with_context(|| "Failed to download UCD ZIP".to_owned())?,
+        UCD_GLOB.iter().copied().map(String::from).collect(),
+        out_root.join("tests/data/ucd"),
+        &mut Default::default(),
+    )?;
+    for path in UCD_GLOB {
+        filter_unicode_data(&out_root.join("tests/data/ucd").join(path))?;
+    }
+
+    extract_zip(
+        cached(&format!(
+            "https://www.unicode.org/Public/{}/ucd/UCD.zip",
+            SourceDataProvider::TESTED_UNICODE_TAG,
+        ))
+        .with_context(|| "Failed to download UCD ZIP".to_owned())?,
+        BIDI_TEST_GLOB.iter().copied().map(String::from).collect(),
+        out_root.join("../../components/properties/tests/data"),
+        &mut Default::default(),
+    )?;
+
+    std::fs::remove_dir_all(out_root.join("tests/data/uts39"))?;
+    extract_zip(
+        cached(&format!(
+            "https://www.unicode.org/Public/security/{0}/uts39-data-{0}.zip",
+            SourceDataProvider::TESTED_UNICODE_TAG,
+        ))
+        .with_context(|| "Failed to download UTS #39 ZIP".to_owned())?,
+        UTS39_GLOB.iter().copied().map(String::from).collect(),
+        out_root.join("tests/data/uts39"),
+        &mut Default::default(),
+    )?;
+    for path in UTS39_GLOB {
+        filter_unicode_data(&out_root.join("tests/data/uts39").join(path))?;
+    }
+
     let cldr_data = cldr_data
         .iter()
         .map(|path| {
@@ -244,6 +323,20 @@ fn main() -> eyre::Result<()> {
         })
         .collect::<Vec<_>>()
         .join(",\n                        ");
+    let ucd_data: String = UCD_GLOB
+        .iter()
+        .map(|path| {
+            format!(r#"("{path}", include_bytes!("../../tests/data/ucd/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                        ");
+    let uts39_data: String = UTS39_GLOB
+        .iter()
+        .map(|path| {
+            format!(r#"("{path}", include_bytes!("../../tests/data/uts39/{path}").as_slice())"#)
+        })
+        .collect::<Vec<_>>()
+        .join(",\n                        ");
 
     write!(&mut crlify::BufWriterWithLineEndingFix::new(File::create(out_root.join("src/tests/data.rs")).unwrap()), "\
 // This file is part of ICU4X. For terms of use, please see the file
@@ -281,6 +374,16 @@ impl SourceDataProvider {{
                         {tzdb_data}
                     ].into_iter().collect(),
                 ), transitions: Default::default() }})),
+                ucd_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        {ucd_data}
+                    ].into_iter().collect(),
+                ))),
+                uts39_paths: Some(Arc::new(AbstractFs::Memory(
+                    [
+                        {uts39_data}
+                    ].into_iter().collect(),
+                ))),
                 ..SourceDataProvider::new_custom()
             }})
             .clone()
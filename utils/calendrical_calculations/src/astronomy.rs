@@ -1528,7 +1528,7 @@ impl Astronomical {
     ///
     /// Based on functions from _Calendrical Calculations_ by Reingold & Dershowitz.
     /// Reference code: <https://github.com/EdReingold/calendar-code2/blob/9afc1f3/calendar.l#L4037-L4047>
-    fn nutation(julian_centuries: f64) -> f64 {
+    pub(crate) fn nutation(julian_centuries: f64) -> f64 {
         // This polynomial is written out manually instead of using a fn like pow for optimization, see #3743
         let c = julian_centuries;
         let a = 124.90 - 1934.134 * c + 0.002063 * c * c;
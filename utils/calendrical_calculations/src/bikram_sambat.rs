@@ -0,0 +1,380 @@
+// This file is part of ICU4X.
+//
+// The contents of this file implement algorithms from Calendrical Calculations
+// by Reingold & Dershowitz, Cambridge University Press, 4th edition (2018),
+// which have been released as Lisp code at <https://github.com/EdReingold/calendar-code2/>
+// under the Apache-2.0 license. Accordingly, this file is released under
+// the Apache License, Version 2.0 which can be found at the calendrical_calculations
+// package root or at http://www.apache.org/licenses/LICENSE-2.0.
+
+//! The Bikram Sambat calendar is the official calendar of Nepal. It is a sidereal solar
+//! calendar: each month starts on the day (in Nepal) on which the sun enters the next sign
+//! of the sidereal zodiac (a *sankranti*), with the year starting at Mesha Sankranti, in
+//! mid-April. This gives months of 29 to 32 days, and years of 365 or 366 days.
+//!
+//! The sidereal longitude of the sun is computed using the Lahiri (Chitrapaksha) ayanamsa,
+//! which is the ayanamsa adopted by the Indian and Nepali calendar reform committees.
+//!
+//! The published calendar is based on traditional almanac calculations, and when a sankranti
+//! falls late in the evening it may start its month one day later than an astronomical
+//! computation would. Therefore, for the years covered by the published calendars, the month
+//! lengths are taken from a table, and the astronomical computation is only used outside of it.
+
+use crate::astronomy::Astronomical;
+use crate::rata_die::{Moment, RataDie};
+#[allow(unused_imports)]
+use core_maths::*;
+
+/// The number of years the Bikram Sambat calendar is ahead of the ISO calendar after Mesha Sankranti
+const YEAR_OFFSET: i64 = 57;
+
+/// The mean length of the sidereal year in days
+const SIDEREAL_YEAR: f64 = 365.256363;
+
+/// Mesha Sankranti of 2057 BS (2000 CE) was on April 13, 2000; this is used as the
+/// starting point for finding sankrantis.
+const MESHA_SANKRANTI_2057: RataDie = crate::iso::const_fixed_from_iso(2000, 4, 13);
+
+/// The first year in [`PUBLISHED_MONTH_LENGTHS`]
+const FIRST_PUBLISHED_YEAR: i32 = 2000;
+
+/// Baisakh 1 of [`FIRST_PUBLISHED_YEAR`], which was April 14, 1943
+const FIRST_PUBLISHED_NEW_YEAR: RataDie = crate::iso::const_fixed_from_iso(1943, 4, 14);
+
+/// The lengths of the months of the years 2000 to 2081 BS, as published in the official
+/// calendars of Nepal.
+#[rustfmt::skip]
+const PUBLISHED_MONTH_LENGTHS: [[u8; 12]; 82] = [
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2000
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2001
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2002
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2003
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2004
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2005
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2006
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2007
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], // 2008
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2009
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2010
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2011
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2012
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2013
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2014
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2015
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2016
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2017
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2018
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2019
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2020
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2021
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2022
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2023
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2024
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2025
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2026
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2027
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2028
+    [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30], // 2029
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2030
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2031
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2032
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2033
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2034
+    [30, 32, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], // 2035
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2036
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2037
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2038
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2039
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2040
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2041
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2042
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2043
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2044
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2045
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2046
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2047
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2048
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2049
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2050
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2051
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2052
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2053
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2054
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2055
+    [31, 31, 32, 31, 32, 30, 30, 29, 30, 29, 30, 30], // 2056
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2057
+    [30, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2058
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2059
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2060
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2061
+    [30, 32, 31, 32, 31, 31, 29, 30, 29, 30, 29, 31], // 2062
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2063
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2064
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2065
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 29, 31], // 2066
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2067
+    [31, 31, 32, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2068
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2069
+    [31, 31, 31, 32, 31, 31, 29, 30, 30, 29, 30, 30], // 2070
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2071
+    [31, 32, 31, 32, 31, 30, 30, 29, 30, 29, 30, 30], // 2072
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 31], // 2073
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2074
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2075
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2076
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 30, 29, 31], // 2077
+    [31, 31, 31, 32, 31, 31, 30, 29, 30, 29, 30, 30], // 2078
+    [31, 31, 32, 31, 31, 31, 30, 29, 30, 29, 30, 30], // 2079
+    [31, 32, 31, 32, 31, 30, 30, 30, 29, 29, 30, 30], // 2080
+    [31, 31, 32, 32, 31, 30, 30, 30, 29, 30, 30, 30], // 2081
+];
+
+/// The number of days between [`FIRST_PUBLISHED_NEW_YEAR`] and Baisakh 1 of each year in
+/// [`PUBLISHED_MONTH_LENGTHS`], followed by the one for the year after the table.
+#[expect(clippy::indexing_slicing)] // const-evaluated, indices are within the table
+const PUBLISHED_NEW_YEAR_OFFSETS: [u16; 83] = {
+    let mut offsets = [0; 83];
+    let mut i = 0;
+    while i < PUBLISHED_MONTH_LENGTHS.len() {
+        let mut length = 0;
+        let mut m = 0;
+        while m < 12 {
+            length += PUBLISHED_MONTH_LENGTHS[i][m] as u16;
+            m += 1;
+        }
+        offsets[i + 1] = offsets[i] + length;
+        i += 1;
+    }
+    offsets
+};
+
+/// The number of Newton iterations after which we give up on improving a sankranti
+const MAX_ITERS_FOR_SANKRANTI: u8 = 10;
+
+/// The Lahiri ayanamsa in degrees, which is the difference between the tropical and the mean
+/// sidereal longitude, at a time given in Julian centuries.
+fn lahiri_ayanamsa(julian_centuries: f64) -> f64 {
+    let c = julian_centuries;
+    23.857092 + (5028.796195 * c + 1.1054348 * c * c) / 3600.0
+}
+
+/// The sidereal longitude of the sun in degrees at a given Moment, measured from the start
+/// of the sign of Mesha (Aries).
+fn sidereal_solar_longitude(moment: Moment) -> f64 {
+    let c = Astronomical::julian_centuries(moment);
+    (Astronomical::solar_longitude(c) - Astronomical::nutation(c) - lahiri_ayanamsa(c))
+        .rem_euclid(360.0)
+}
+
+/// The UTC offset used in Nepal at a given Moment, as a fraction of a day.
+fn nepal_utc_offset(moment: Moment) -> f64 {
+    use crate::iso::const_fixed_from_iso as iso;
+    // Before 1920, the local mean time of Kathmandu (85.32 degrees east) was used. Between
+    // 1920 and 1986 Nepal used UTC+5:30, and since then it has used UTC+5:45.
+    if moment < const { iso(1920, 1, 1) }.as_moment() {
+        85.32 / 360.0
+    } else if moment < const { iso(1986, 1, 1) }.as_moment() {
+        5.5 / 24.0
+    } else {
+        5.75 / 24.0
+    }
+}
+
+/// The Moment (in universal time) at which the sun enters the sign starting month `month` of
+/// `year`, with month 0 being Baisakh. Months past the end of the year continue into the next year.
+fn sankranti(year: i32, month: u8) -> Moment {
+    let angle = 30.0 * f64::from(month);
+    let mut moment = MESHA_SANKRANTI_2057.as_moment()
+        + 0.5
+        + (f64::from(year) - 2057.0 + angle / 360.0) * SIDEREAL_YEAR;
+    for _ in 0..MAX_ITERS_FOR_SANKRANTI {
+        let delta = (angle - sidereal_solar_longitude(moment) + 180.0).rem_euclid(360.0) - 180.0;
+        moment += delta * (SIDEREAL_YEAR / 360.0);
+        if delta.abs() < 1e-7 {
+            break;
+        }
+    }
+    moment
+}
+
+/// The first day of the month `month` (1-indexed) of the Bikram Sambat year `year`. Month 13
+/// is the first month of the next year.
+pub fn fixed_from_bikram_sambat_month(year: i32, month: u8) -> RataDie {
+    if let Some(start) = published_month_start(year, month) {
+        return start;
+    }
+    let sankranti = sankranti(year, month.saturating_sub(1));
+    (sankranti + nepal_utc_offset(sankranti)).as_rata_die()
+}
+
+/// The first day of the month `month` of the Bikram Sambat year `year` according to
+/// [`PUBLISHED_MONTH_LENGTHS`], or `None` if the month is not covered by the table.
+fn published_month_start(year: i32, month: u8) -> Option<RataDie> {
+    // Month 13 is Baisakh of the next year, which is covered for the year after the table
+    let (year, month) = if month == 13 {
+        (year.checked_add(1)?, 1)
+    } else {
+        (year, month)
+    };
+    let index = usize::try_from(year.checked_sub(FIRST_PUBLISHED_YEAR)?).ok()?;
+    let days_before: u16 = if month == 1 {
+        0
+    } else {
+        PUBLISHED_MONTH_LENGTHS
+            .get(index)?
+            .get(..usize::from(month.checked_sub(1)?))?
+            .iter()
+            .copied()
+            .map(u16::from)
+            .sum()
+    };
+    Some(
+        FIRST_PUBLISHED_NEW_YEAR + i64::from(*PUBLISHED_NEW_YEAR_OFFSETS.get(index)? + days_before),
+    )
+}
+
+/// The first days of the twelve months of the Bikram Sambat year `year`, followed by the first
+/// day of the next year.
+pub fn month_starts(year: i32) -> [RataDie; 13] {
+    core::array::from_fn(|month| fixed_from_bikram_sambat_month(year, month as u8 + 1))
+}
+
+/// The Bikram Sambat year containing the given date
+pub fn bikram_sambat_year_from_fixed(date: RataDie) -> i32 {
+    let year =
+        crate::helpers::i64_to_saturated_i32(crate::iso::iso_year_from_fixed(date) + YEAR_OFFSET);
+    if date < fixed_from_bikram_sambat_month(year, 1) {
+        year - 1
+    } else {
+        year
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::iso::fixed_from_iso;
+
+    #[test]
+    fn test_new_year() {
+        // Baisakh 1 as published by the Nepali government
+        let cases = [
+            (2075, (2018, 4, 14)),
+            (2076, (2019, 4, 14)),
+            (2077, (2020, 4, 13)),
+            (2078, (2021, 4, 14)),
+            (2079, (2022, 4, 14)),
+            (2080, (2023, 4, 14)),
+            (2081, (2024, 4, 13)),
+            (2082, (2025, 4, 14)),
+        ];
+        for (year, (y, m, d)) in cases {
+            let new_year = fixed_from_iso(y, m, d);
+            assert_eq!(fixed_from_bikram_sambat_month(year, 1), new_year, "{year}");
+            assert_eq!(bikram_sambat_year_from_fixed(new_year), year);
+            assert_eq!(bikram_sambat_year_from_fixed(new_year - 1), year - 1);
+        }
+    }
+
+    #[test]
+    fn test_month_starts() {
+        for ((year, month), (y, m, d)) in [
+            // Jestha 1, 2081
+            ((2081, 2), (2024, 5, 14)),
+            // Saune Sankranti, the first of Shrawan
+            ((2081, 4), (2024, 7, 16)),
+            // Maghe Sankranti, the first of Magh
+            ((2080, 10), (2024, 1, 15)),
+            ((2081, 10), (2025, 1, 14)),
+        ] {
+            assert_eq!(
+                fixed_from_bikram_sambat_month(year, month),
+                fixed_from_iso(y, m, d),
+                "{year}-{month}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_whole_years() {
+        // The first days of all months, as published by the Nepali government
+        let cases = [
+            (
+                2080,
+                [
+                    (2023, 4, 14),
+                    (2023, 5, 15),
+                    (2023, 6, 16),
+                    (2023, 7, 17),
+                    (2023, 8, 18),
+                    (2023, 9, 18),
+                    (2023, 10, 18),
+                    (2023, 11, 17),
+                    (2023, 12, 17),
+                    (2024, 1, 15),
+                    (2024, 2, 13),
+                    (2024, 3, 14),
+                    (2024, 4, 13),
+                ],
+            ),
+            (
+                2081,
+                [
+                    (2024, 4, 13),
+                    (2024, 5, 14),
+                    (2024, 6, 14),
+                    (2024, 7, 16),
+                    (2024, 8, 17),
+                    (2024, 9, 17),
+                    (2024, 10, 17),
+                    (2024, 11, 16),
+                    (2024, 12, 16),
+                    (2025, 1, 14),
+                    (2025, 2, 13),
+                    (2025, 3, 15),
+                    (2025, 4, 14),
+                ],
+            ),
+        ];
+        for (year, dates) in cases {
+            assert_eq!(
+                month_starts(year),
+                dates.map(|(y, m, d)| fixed_from_iso(y, m, d)),
+                "{year}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_published_close_to_astronomical() {
+        // The published calendar starts a month at most one day away from the sankranti
+        for year in
+            FIRST_PUBLISHED_YEAR..FIRST_PUBLISHED_YEAR + PUBLISHED_MONTH_LENGTHS.len() as i32
+        {
+            for month in 1..=13 {
+                let sankranti = sankranti(year, month - 1);
+                let astronomical = (sankranti + nepal_utc_offset(sankranti)).as_rata_die();
+                let published = fixed_from_bikram_sambat_month(year, month);
+                assert!((published - astronomical).abs() <= 1, "{year}-{month}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_month_lengths() {
+        for year in 1900..2200 {
+            let starts = month_starts(year);
+            for window in starts.windows(2) {
+                let [start, next] = window else {
+                    unreachable!()
+                };
+                assert!((29..=32).contains(&(*next - *start)), "{year}: {window:?}");
+            }
+            let length = starts[12] - starts[0];
+            assert!((365..=366).contains(&length), "{year}: {length}");
+            assert_eq!(month_starts(year + 1)[0], starts[12]);
+            assert_eq!(bikram_sambat_year_from_fixed(starts[0]), year);
+            assert_eq!(bikram_sambat_year_from_fixed(starts[12] - 1), year);
+        }
+    }
+}
@@ -50,6 +50,11 @@ pub struct Chinese;
 #[allow(clippy::exhaustive_structs)] // newtype
 pub struct Dangi;
 
+/// A type implementing [`ChineseBased`] for the Vietnamese calendar
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[allow(clippy::exhaustive_structs)] // newtype
+pub struct Vietnamese;
+
 impl ChineseBased for Chinese {
     fn utc_offset(fixed: RataDie) -> f64 {
         use crate::iso::const_fixed_from_iso as iso;
@@ -92,6 +97,23 @@ impl ChineseBased for Dangi {
     const DEBUG_NAME: &'static str = "dangi";
 }
 
+impl ChineseBased for Vietnamese {
+    fn utc_offset(fixed: RataDie) -> f64 {
+        use crate::iso::const_fixed_from_iso as iso;
+        // Until 1967, Vietnam used the Chinese calendar. From 1968 onward, new moon
+        // calculations are based on UTC+7h, the standard time of (North) Vietnam.
+        if fixed < const { iso(1968, 1, 1) } {
+            Chinese::utc_offset(fixed)
+        } else {
+            7.0 / 24.0
+        }
+    }
+
+    /// The Vietnamese calendar uses the same epoch as the Chinese calendar
+    const EPOCH: RataDie = Chinese::EPOCH;
+    const DEBUG_NAME: &'static str = "vietnamese";
+}
+
 /// Marks the bounds of a lunar year
 #[derive(Debug, Copy, Clone)]
 #[allow(clippy::exhaustive_structs)] // we're comfortable making frequent breaking changes to this crate
@@ -568,6 +590,31 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_vietnamese_new_year() {
+        // The Vietnamese and Chinese calendars diverged in 1968, and again in 1985
+        // when they had different leap months.
+        for (year, vietnamese, chinese) in [
+            (1967, (1967, 2, 9), (1967, 2, 9)),
+            (1968, (1968, 1, 29), (1968, 1, 30)),
+            (1985, (1985, 1, 21), (1985, 2, 20)),
+            (2024, (2024, 2, 10), (2024, 2, 10)),
+        ] {
+            let fixed = crate::iso::fixed_from_iso(year, 6, 1);
+            let new_year = |(y, m, d)| crate::iso::fixed_from_iso(y, m, d);
+            assert_eq!(
+                YearBounds::compute::<Vietnamese>(fixed).new_year,
+                new_year(vietnamese),
+                "{year}"
+            );
+            assert_eq!(
+                YearBounds::compute::<Chinese>(fixed).new_year,
+                new_year(chinese),
+                "{year}"
+            );
+        }
+    }
+
     #[test]
     fn test_chinese_new_moon_directionality() {
         for i in (-1000..1000).step_by(31) {
@@ -36,7 +36,9 @@
 #![warn(missing_docs)]
 
 mod astronomy;
-/// Chinese-like lunar calendars (Chinese, Dangi)
+/// The Bikram Sambat calendar
+pub mod bikram_sambat;
+/// Chinese-like lunar calendars (Chinese, Dangi, Vietnamese)
 pub mod chinese_based;
 /// The Coptic calendar
 pub mod coptic;
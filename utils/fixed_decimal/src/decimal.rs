@@ -83,7 +83,25 @@ compile_error!("The fixed_decimal crate only works if usizes are at least the si
 ///
 /// dec.multiply_pow10(-2);
 /// assert_eq!("2.50", dec.to_string());
-/// ```````
+/// ```
+///
+/// # Arithmetic
+///
+/// Numbers can be added and multiplied without loss of precision, and subtracted and divided
+/// with [`UnsignedDecimal::checked_sub`] and [`UnsignedDecimal::checked_div_with_mode`].
+///
+/// NOTE: if the result of an addition or multiplication overflows the range of magnitudes,
+/// the number will be set to zero, as with [`UnsignedDecimal::multiply_pow10`].
+///
+/// ```
+/// use fixed_decimal::UnsignedDecimal;
+///
+/// let ten = UnsignedDecimal::from(10u32);
+/// assert_eq!("100", (&ten * &ten).to_string());
+///
+/// let huge = UnsignedDecimal::from(1u32).multiplied_pow10(i16::MAX);
+/// assert_eq!("0", (huge * ten).to_string());
+/// ```
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnsignedDecimal {
     /// List of digits; digits\[0\] is the most significant.
@@ -1853,6 +1871,355 @@ impl UnsignedDecimal {
     }
 }
 
+/// The digits of a nonnegative integer, least significant first, used for arithmetic.
+type AscendingDigits = SmallVec<[u8; 8]>;
+
+/// Compares two integers given as digits in descending order of magnitude without leading zeros.
+fn cmp_descending(a: &[u8], b: &[u8]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Subtracts `b` from `a`, which must not be smaller, and removes leading zeros from `a`.
+fn sub_ascending(a: &mut AscendingDigits, b: &[u8]) {
+    let mut borrow = 0;
+    for (i, digit) in a.iter_mut().enumerate() {
+        let subtrahend = b.get(i).copied().unwrap_or(0) + borrow;
+        if *digit >= subtrahend {
+            *digit -= subtrahend;
+            borrow = 0;
+        } else {
+            *digit = *digit + 10 - subtrahend;
+            borrow = 1;
+        }
+    }
+    debug_assert_eq!(borrow, 0, "Subtrahend too large");
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+/// Subtracts `b` from `a`, which must not be smaller, both given as digits in descending order
+/// of magnitude.
+fn sub_descending(a: &mut [u8], b: &[u8]) {
+    let mut borrow = 0;
+    let mut b = b.iter().rev();
+    for digit in a.iter_mut().rev() {
+        let subtrahend = b.next().copied().unwrap_or(0) + borrow;
+        if *digit >= subtrahend {
+            *digit -= subtrahend;
+            borrow = 0;
+        } else {
+            *digit = *digit + 10 - subtrahend;
+            borrow = 1;
+        }
+    }
+    debug_assert_eq!(borrow, 0, "Subtrahend too large");
+}
+
+impl UnsignedDecimal {
+    /// Returns the digits of this number from magnitude `end` up to its largest nonzero digit,
+    /// least significant first.
+    ///
+    /// `end` must not be greater than [`Self::nonzero_magnitude_end`].
+    fn ascending_digits_from(&self, end: i32) -> AscendingDigits {
+        let mut digits = AscendingDigits::new();
+        if !self.is_zero() {
+            let trailing_zeros = i32::from(self.nonzero_magnitude_end()) - end;
+            debug_assert!(trailing_zeros >= 0);
+            digits.resize(trailing_zeros as usize, 0);
+            digits.extend(self.digits.iter().rev().copied());
+        }
+        digits
+    }
+
+    /// Creates a number from digits in ascending order of magnitude starting at magnitude `end`,
+    /// which may include leading and trailing zeros, and the lowest magnitude to display.
+    ///
+    /// NOTE: if a nonzero digit is out of the range of magnitudes, the number will be zero.
+    fn from_ascending_digits(digits: &[u8], end: i32, lower_magnitude: i32) -> Self {
+        let (Some(first), Some(last)) = (
+            digits.iter().position(|&d| d != 0),
+            digits.iter().rposition(|&d| d != 0),
+        ) else {
+            return Self {
+                lower_magnitude: lower_magnitude.clamp(i16::MIN.into(), 0) as i16,
+                ..Default::default()
+            };
+        };
+        let end = end + first as i32;
+        let magnitude = end + (last - first) as i32;
+        if end < i16::MIN.into() || magnitude > i16::MAX.into() {
+            return Self::default();
+        }
+        let result = Self {
+            digits: digits
+                .iter()
+                .take(last + 1)
+                .skip(first)
+                .rev()
+                .copied()
+                .collect(),
+            magnitude: magnitude as i16,
+            upper_magnitude: cmp::max(magnitude, 0) as i16,
+            lower_magnitude: lower_magnitude.clamp(i16::MIN.into(), cmp::min(end, 0)) as i16,
+        };
+        #[cfg(debug_assertions)]
+        result.check_invariants();
+        result
+    }
+
+    /// Compares the numeric values of two numbers, ignoring leading and trailing zeros.
+    pub(crate) fn cmp_value(&self, other: &Self) -> Ordering {
+        match (self.is_zero(), other.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .magnitude
+                .cmp(&other.magnitude)
+                .then_with(|| self.digits.iter().cmp(other.digits.iter())),
+        }
+    }
+
+    /// Returns the sum of two numbers, displaying the fraction digits
+    /// displayed by either of them.
+    ///
+    /// NOTE: if the sum overflows, the number will be set to zero.
+    pub(crate) fn sum(&self, other: &Self) -> Self {
+        let end = i32::from(cmp::min(
+            self.nonzero_magnitude_end(),
+            other.nonzero_magnitude_end(),
+        ));
+        let a = self.ascending_digits_from(end);
+        let b = other.ascending_digits_from(end);
+        let mut sum = AscendingDigits::new();
+        let mut carry = 0;
+        for i in 0..cmp::max(a.len(), b.len()) {
+            let digit = a.get(i).copied().unwrap_or(0) + b.get(i).copied().unwrap_or(0) + carry;
+            sum.push(digit % 10);
+            carry = digit / 10;
+        }
+        sum.push(carry);
+        Self::from_ascending_digits(
+            &sum,
+            end,
+            cmp::min(self.lower_magnitude, other.lower_magnitude).into(),
+        )
+    }
+
+    /// Returns the difference of two numbers, displaying the fraction digits
+    /// displayed by either of them.
+    ///
+    /// `other` must not be greater than `self`.
+    pub(crate) fn difference(&self, other: &Self) -> Self {
+        debug_assert_ne!(self.cmp_value(other), Ordering::Less);
+        let end = i32::from(cmp::min(
+            self.nonzero_magnitude_end(),
+            other.nonzero_magnitude_end(),
+        ));
+        let mut difference = self.ascending_digits_from(end);
+        sub_ascending(&mut difference, &other.ascending_digits_from(end));
+        Self::from_ascending_digits(
+            &difference,
+            end,
+            cmp::min(self.lower_magnitude, other.lower_magnitude).into(),
+        )
+    }
+
+    /// Returns the product of two numbers, displaying as many fraction digits as both of them
+    /// together.
+    ///
+    /// NOTE: if the product overflows, the number will be set to zero.
+    pub(crate) fn product(&self, other: &Self) -> Self {
+        let a_end = i32::from(self.nonzero_magnitude_end());
+        let b_end = i32::from(other.nonzero_magnitude_end());
+        let a = self.ascending_digits_from(a_end);
+        let b = other.ascending_digits_from(b_end);
+        // Each column sums at most u16::MAX products of two digits, which fits into a u32
+        let mut columns: SmallVec<[u32; 16]> = SmallVec::new();
+        columns.resize(a.len() + b.len(), 0);
+        for (i, x) in a.iter().enumerate() {
+            for (column, y) in columns.iter_mut().skip(i).zip(b.iter()) {
+                *column += u32::from(x * y);
+            }
+        }
+        let mut product = AscendingDigits::new();
+        let mut carry = 0;
+        for column in columns {
+            let value = column + carry;
+            product.push((value % 10) as u8);
+            carry = value / 10;
+        }
+        while carry > 0 {
+            product.push((carry % 10) as u8);
+            carry /= 10;
+        }
+        Self::from_ascending_digits(
+            &product,
+            a_end + b_end,
+            i32::from(self.lower_magnitude) + i32::from(other.lower_magnitude),
+        )
+    }
+
+    /// Returns the quotient of two numbers, truncated after the magnitude below `position`,
+    /// followed by a nonzero digit if the truncated digits are not all zero.
+    ///
+    /// Rounding the result at `position` in any mode and increment yields the same as
+    /// rounding the exact quotient. Returns `None` if `divisor` is zero.
+    pub(crate) fn quotient_for_rounding(&self, divisor: &Self, position: i16) -> Option<Self> {
+        if divisor.is_zero() {
+            return None;
+        }
+        if self.is_zero() {
+            return Some(Self::default());
+        }
+        // Leave room for the nonzero digit below the truncation
+        let truncation = cmp::max(i32::from(position) - 1, i32::from(i16::MIN) + 1);
+        let dividend_end = i32::from(self.nonzero_magnitude_end());
+        let divisor_end = i32::from(divisor.nonzero_magnitude_end());
+        // Scale both to integers such that their quotient is the result at magnitude `truncation`
+        let shift = dividend_end - divisor_end - truncation;
+        let dividend = self.ascending_digits_from(dividend_end - cmp::max(shift, 0));
+        let mut divisor = divisor.ascending_digits_from(divisor_end - cmp::max(-shift, 0));
+        divisor.reverse();
+
+        let mut quotient = AscendingDigits::new();
+        // The remainder is kept most significant digit first, so that bringing down the next
+        // digit of the dividend appends to it. Its leading zeros are skipped by `start`.
+        let mut remainder = AscendingDigits::new();
+        let mut start = 0;
+        for &digit in dividend.iter().rev() {
+            remainder.push(digit);
+            let mut quotient_digit = 0;
+            loop {
+                while remainder.get(start) == Some(&0) {
+                    start += 1;
+                }
+                let significant = remainder.get_mut(start..).unwrap_or_default();
+                if cmp_descending(significant, &divisor) == Ordering::Less {
+                    break;
+                }
+                sub_descending(significant, &divisor);
+                quotient_digit += 1;
+            }
+            quotient.push(quotient_digit);
+        }
+        quotient.reverse();
+        let mut end = truncation;
+        if start < remainder.len() {
+            quotient.insert(0, 1);
+            end -= 1;
+        }
+        Some(Self::from_ascending_digits(&quotient, end, 0))
+    }
+
+    /// Returns this number subtracted by another one, or `None` if the result would be
+    /// negative.
+    ///
+    /// The result displays the fraction digits displayed by either of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::UnsignedDecimal;
+    ///
+    /// let a: UnsignedDecimal = "12.5".parse().unwrap();
+    /// let b: UnsignedDecimal = "0.25".parse().unwrap();
+    ///
+    /// assert_eq!("12.25", a.checked_sub(&b).unwrap().to_string());
+    /// assert_eq!(None, b.checked_sub(&a));
+    /// ```
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        match self.cmp_value(other) {
+            Ordering::Less => None,
+            _ => Some(self.difference(other)),
+        }
+    }
+
+    /// Returns this number divided by another one and rounded at a particular digit position,
+    /// using the specified rounding mode, or `None` if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{UnsignedDecimal, UnsignedRoundingMode};
+    ///
+    /// let one = UnsignedDecimal::from(1u32);
+    /// let three = UnsignedDecimal::from(3u32);
+    ///
+    /// assert_eq!(
+    ///     "0.33",
+    ///     one.checked_div_with_mode(&three, -2, UnsignedRoundingMode::HalfEven)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "0.34",
+    ///     one.checked_div_with_mode(&three, -2, UnsignedRoundingMode::Expand)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     one.checked_div_with_mode(
+    ///         &UnsignedDecimal::from(0u32),
+    ///         -2,
+    ///         UnsignedRoundingMode::HalfEven
+    ///     )
+    /// );
+    /// ```
+    pub fn checked_div_with_mode(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: UnsignedRoundingMode,
+    ) -> Option<Self> {
+        let mut quotient = self.quotient_for_rounding(divisor, position)?;
+        quotient.round_with_mode(position, mode);
+        Some(quotient)
+    }
+
+    /// Returns this number divided by another one and rounded at a particular digit position
+    /// and increment, using the specified rounding mode, or `None` if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{
+    ///     RoundingIncrement, UnsignedDecimal, UnsignedRoundingMode,
+    /// };
+    ///
+    /// let ten = UnsignedDecimal::from(10u32);
+    /// let three = UnsignedDecimal::from(3u32);
+    ///
+    /// assert_eq!(
+    ///     "3.35",
+    ///     ten.checked_div_with_mode_and_increment(
+    ///         &three,
+    ///         -2,
+    ///         UnsignedRoundingMode::Expand,
+    ///         RoundingIncrement::MultiplesOf5
+    ///     )
+    ///     .unwrap()
+    ///     .to_string()
+    /// );
+    /// ```
+    pub fn checked_div_with_mode_and_increment(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: UnsignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Option<Self> {
+        let mut quotient = self.quotient_for_rounding(divisor, position)?;
+        quotient.round_with_mode_and_increment(position, mode, increment);
+        Some(quotient)
+    }
+}
+
+crate::ops::impl_binary_op!(UnsignedDecimal, Add, add, AddAssign, add_assign, sum);
+crate::ops::impl_binary_op!(UnsignedDecimal, Mul, mul, MulAssign, mul_assign, product);
+
 /// Render the `FixedDecimal` as a string of ASCII digits with a possible decimal point.
 ///
 /// # Examples
@@ -4174,3 +4541,234 @@ fn test_rounding_increment() {
     );
     assert_eq!("2.50", dec.to_string());
 }
+
+#[test]
+fn test_arithmetic() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input_1: &'static str,
+        pub input_2: &'static str,
+        pub sum: &'static str,
+        pub difference: Option<&'static str>,
+        pub product: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input_1: "0",
+            input_2: "0",
+            sum: "0",
+            difference: Some("0"),
+            product: "0",
+        },
+        TestCase {
+            input_1: "1.50",
+            input_2: "2",
+            sum: "3.50",
+            difference: None,
+            product: "3.00",
+        },
+        TestCase {
+            input_1: "99.99",
+            input_2: "0.01",
+            sum: "100.00",
+            difference: Some("99.98"),
+            product: "0.9999",
+        },
+        TestCase {
+            input_1: "1000",
+            input_2: "0.001",
+            sum: "1000.001",
+            difference: Some("999.999"),
+            product: "1.000",
+        },
+        TestCase {
+            input_1: "012.5",
+            input_2: "12.5",
+            sum: "25.0",
+            difference: Some("0.0"),
+            product: "156.25",
+        },
+        TestCase {
+            input_1: "123456789.123456789",
+            input_2: "987654321.987654321",
+            sum: "1111111111.111111110",
+            difference: None,
+            product: "121932631356500531.347203169112635269",
+        },
+        TestCase {
+            input_1: "1200",
+            input_2: "0",
+            sum: "1200",
+            difference: Some("1200"),
+            product: "0",
+        },
+    ];
+    for cas in &cases {
+        let input_1 = UnsignedDecimal::from_str(cas.input_1).unwrap();
+        let input_2 = UnsignedDecimal::from_str(cas.input_2).unwrap();
+        assert_eq!(cas.sum, (&input_1 + &input_2).to_string(), "{cas:?}");
+        assert_eq!(cas.sum, (&input_2 + &input_1).to_string(), "{cas:?}");
+        assert_eq!(
+            cas.difference,
+            input_1
+                .checked_sub(&input_2)
+                .map(|d| d.to_string())
+                .as_deref(),
+            "{cas:?}"
+        );
+        assert_eq!(cas.product, (&input_1 * &input_2).to_string(), "{cas:?}");
+        assert_eq!(cas.product, (input_2 * input_1).to_string(), "{cas:?}");
+    }
+
+    // Overflow
+    let mut dec = UnsignedDecimal::from(9u32).multiplied_pow10(i16::MAX);
+    dec += UnsignedDecimal::from(1u32).multiplied_pow10(i16::MAX);
+    assert!(dec.is_zero());
+}
+
+#[test]
+fn test_division() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub dividend: &'static str,
+        pub divisor: &'static str,
+        pub position: i16,
+        pub mode: UnsignedRoundingMode,
+        pub increment: RoundingIncrement,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            dividend: "1",
+            divisor: "3",
+            position: -5,
+            mode: UnsignedRoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.33333",
+        },
+        TestCase {
+            dividend: "2",
+            divisor: "3",
+            position: -5,
+            mode: UnsignedRoundingMode::Trunc,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.66666",
+        },
+        TestCase {
+            dividend: "2",
+            divisor: "3",
+            position: -5,
+            mode: UnsignedRoundingMode::HalfTrunc,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.66667",
+        },
+        TestCase {
+            dividend: "1",
+            divisor: "4",
+            position: -5,
+            mode: UnsignedRoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.25000",
+        },
+        // Exactly half
+        TestCase {
+            dividend: "1",
+            divisor: "8",
+            position: -2,
+            mode: UnsignedRoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.12",
+        },
+        TestCase {
+            dividend: "1",
+            divisor: "8",
+            position: -2,
+            mode: UnsignedRoundingMode::HalfExpand,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.13",
+        },
+        // Slightly more than half
+        TestCase {
+            dividend: "1.000001",
+            divisor: "8",
+            position: -2,
+            mode: UnsignedRoundingMode::HalfTrunc,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.13",
+        },
+        // Slightly more than an integer
+        TestCase {
+            dividend: "8.000001",
+            divisor: "8",
+            position: 0,
+            mode: UnsignedRoundingMode::Expand,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "2",
+        },
+        TestCase {
+            dividend: "8",
+            divisor: "8",
+            position: 0,
+            mode: UnsignedRoundingMode::Expand,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "1",
+        },
+        TestCase {
+            dividend: "1200",
+            divisor: "0.03",
+            position: 2,
+            mode: UnsignedRoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "40000",
+        },
+        TestCase {
+            dividend: "0.001",
+            divisor: "7",
+            position: -3,
+            mode: UnsignedRoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.000",
+        },
+        TestCase {
+            dividend: "0.001",
+            divisor: "7",
+            position: -3,
+            mode: UnsignedRoundingMode::Expand,
+            increment: RoundingIncrement::MultiplesOf1,
+            expected: "0.001",
+        },
+        TestCase {
+            dividend: "10",
+            divisor: "7",
+            position: -2,
+            mode: UnsignedRoundingMode::HalfEven,
+            increment: RoundingIncrement::MultiplesOf25,
+            expected: "1.50",
+        },
+        TestCase {
+            dividend: "10",
+            divisor: "7",
+            position: -2,
+            mode: UnsignedRoundingMode::Trunc,
+            increment: RoundingIncrement::MultiplesOf5,
+            expected: "1.40",
+        },
+    ];
+    for cas in &cases {
+        let dividend = UnsignedDecimal::from_str(cas.dividend).unwrap();
+        let divisor = UnsignedDecimal::from_str(cas.divisor).unwrap();
+        let quotient = dividend
+            .checked_div_with_mode_and_increment(&divisor, cas.position, cas.mode, cas.increment)
+            .unwrap();
+        assert_eq!(cas.expected, quotient.to_string(), "{cas:?}");
+    }
+
+    assert_eq!(
+        None,
+        UnsignedDecimal::from(1u32).checked_div_with_mode(
+            &UnsignedDecimal::from_str("0.00").unwrap(),
+            0,
+            UnsignedRoundingMode::HalfEven
+        )
+    );
+}
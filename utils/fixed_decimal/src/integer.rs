@@ -45,6 +45,12 @@ impl From<FixedInteger> for Decimal {
     }
 }
 
+impl AsRef<Decimal> for FixedInteger {
+    fn as_ref(&self) -> &Decimal {
+        &self.0
+    }
+}
+
 macro_rules! impl_fixed_integer_from_integer_type {
     ($type:ident) => {
         impl From<$type> for FixedInteger {
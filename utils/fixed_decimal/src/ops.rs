@@ -68,3 +68,44 @@ fn test_i16_abs_sub() {
     assert_eq!(i16_abs_sub(i16::MAX, i16::MAX - 1), 1);
     assert_eq!(i16_abs_sub(i16::MAX, i16::MAX), 0);
 }
+
+/// Implements an arithmetic operator and its assigning variant for owned and borrowed operands
+/// of a type, using an inherent method that takes both operands by reference.
+macro_rules! impl_binary_op {
+    ($type:ty, $trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident, $method:ident) => {
+        impl core::ops::$trait<&$type> for &$type {
+            type Output = $type;
+            fn $fn(self, rhs: &$type) -> $type {
+                self.$method(rhs)
+            }
+        }
+
+        impl core::ops::$trait<&$type> for $type {
+            type Output = $type;
+            fn $fn(self, rhs: &$type) -> $type {
+                self.$method(rhs)
+            }
+        }
+
+        impl core::ops::$trait for $type {
+            type Output = $type;
+            fn $fn(self, rhs: $type) -> $type {
+                self.$method(&rhs)
+            }
+        }
+
+        impl core::ops::$assign_trait<&$type> for $type {
+            fn $assign_fn(&mut self, rhs: &$type) {
+                *self = self.$method(rhs);
+            }
+        }
+
+        impl core::ops::$assign_trait for $type {
+            fn $assign_fn(&mut self, rhs: $type) {
+                *self = self.$method(&rhs);
+            }
+        }
+    };
+}
+
+pub(crate) use impl_binary_op;
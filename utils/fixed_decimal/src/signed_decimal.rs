@@ -2,8 +2,9 @@
 // called LICENSE at the top level of the ICU4X source tree
 // (online at: https://github.com/unicode-org/icu4x/blob/main/LICENSE ).
 
+use core::cmp::Ordering;
 use core::fmt;
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, Neg};
 use core::str::FromStr;
 
 use crate::uint_iterator::IntIterator;
@@ -46,6 +47,24 @@ use crate::{
 /// dec.multiply_pow10(-2);
 /// assert_eq!("2.50", dec.to_string());
 /// ```
+///
+/// # Arithmetic
+///
+/// Numbers can be added, subtracted and multiplied without loss of precision, and divided with
+/// [`Decimal::checked_div_with_mode`].
+///
+/// NOTE: if the result of an addition, subtraction or multiplication overflows the range of
+/// magnitudes, the number will be set to zero, as with [`UnsignedDecimal::multiply_pow10`].
+///
+/// ```
+/// use fixed_decimal::Decimal;
+///
+/// let price: Decimal = "19.99".parse().unwrap();
+/// let discount: Decimal = "2.50".parse().unwrap();
+///
+/// let total = (&price - &discount) * Decimal::from(3);
+/// assert_eq!("52.47", total.to_string());
+/// ```
 pub type Decimal = Signed<UnsignedDecimal>;
 
 impl Decimal {
@@ -855,6 +874,169 @@ impl Decimal {
     }
 }
 
+impl Decimal {
+    /// Returns a number with the given absolute value, which is negative if `negative` is true
+    /// and the value is nonzero.
+    fn with_negative(absolute: UnsignedDecimal, negative: bool) -> Self {
+        let sign = if negative && !absolute.is_zero() {
+            Sign::Negative
+        } else {
+            Sign::None
+        };
+        Self::new(sign, absolute)
+    }
+
+    /// Returns the sum of two numbers, displaying the fraction digits
+    /// displayed by either of them.
+    pub(crate) fn sum(&self, other: &Self) -> Self {
+        let negative = self.sign == Sign::Negative;
+        if negative == (other.sign == Sign::Negative) {
+            return Self::with_negative(self.absolute.sum(&other.absolute), negative);
+        }
+        match self.absolute.cmp_value(&other.absolute) {
+            Ordering::Less => {
+                Self::with_negative(other.absolute.difference(&self.absolute), !negative)
+            }
+            _ => Self::with_negative(self.absolute.difference(&other.absolute), negative),
+        }
+    }
+
+    /// Returns the difference of two numbers, displaying the fraction digits
+    /// displayed by either of them.
+    pub(crate) fn difference(&self, other: &Self) -> Self {
+        self.sum(&-other.clone())
+    }
+
+    /// Returns the product of two numbers, displaying as many fraction digits as both of them
+    /// together.
+    pub(crate) fn product(&self, other: &Self) -> Self {
+        Self::with_negative(
+            self.absolute.product(&other.absolute),
+            (self.sign == Sign::Negative) != (other.sign == Sign::Negative),
+        )
+    }
+
+    /// Returns this number divided by another one and rounded at a particular digit position,
+    /// using the specified rounding mode, or `None` if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{Decimal, SignedRoundingMode, UnsignedRoundingMode};
+    ///
+    /// let dividend = Decimal::from(-10);
+    /// let divisor = Decimal::from(3);
+    ///
+    /// assert_eq!(
+    ///     "-3.33",
+    ///     dividend
+    ///         .checked_div_with_mode(
+    ///             &divisor,
+    ///             -2,
+    ///             SignedRoundingMode::Unsigned(UnsignedRoundingMode::HalfEven)
+    ///         )
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     "-3.34",
+    ///     dividend
+    ///         .checked_div_with_mode(&divisor, -2, SignedRoundingMode::Floor)
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// assert_eq!(
+    ///     None,
+    ///     dividend.checked_div_with_mode(
+    ///         &Decimal::from(0),
+    ///         -2,
+    ///         SignedRoundingMode::Floor
+    ///     )
+    /// );
+    /// ```
+    pub fn checked_div_with_mode(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: SignedRoundingMode,
+    ) -> Option<Self> {
+        let mut quotient = self.quotient_for_rounding(divisor, position)?;
+        quotient.round_with_mode(position, mode);
+        Some(quotient)
+    }
+
+    /// Returns this number divided by another one and rounded at a particular digit position
+    /// and increment, using the specified rounding mode, or `None` if the divisor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_decimal::{Decimal, RoundingIncrement, SignedRoundingMode};
+    ///
+    /// let dividend = Decimal::from(-10);
+    /// let divisor = Decimal::from(3);
+    ///
+    /// assert_eq!(
+    ///     "-3.30",
+    ///     dividend
+    ///         .checked_div_with_mode_and_increment(
+    ///             &divisor,
+    ///             -2,
+    ///             SignedRoundingMode::Ceil,
+    ///             RoundingIncrement::MultiplesOf5
+    ///         )
+    ///         .unwrap()
+    ///         .to_string()
+    /// );
+    /// ```
+    pub fn checked_div_with_mode_and_increment(
+        &self,
+        divisor: &Self,
+        position: i16,
+        mode: SignedRoundingMode,
+        increment: RoundingIncrement,
+    ) -> Option<Self> {
+        let mut quotient = self.quotient_for_rounding(divisor, position)?;
+        quotient.round_with_mode_and_increment(position, mode, increment);
+        Some(quotient)
+    }
+
+    /// Returns the quotient of two numbers with the sign applied, such that rounding it at
+    /// `position` yields the same as rounding the exact quotient.
+    fn quotient_for_rounding(&self, divisor: &Self, position: i16) -> Option<Self> {
+        Some(Self::with_negative(
+            self.absolute
+                .quotient_for_rounding(&divisor.absolute, position)?,
+            (self.sign == Sign::Negative) != (divisor.sign == Sign::Negative),
+        ))
+    }
+}
+
+/// Negates the [`Decimal`], turning an explicit positive sign into a negative sign.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_decimal::Decimal;
+///
+/// assert_eq!("-1.5", (-"1.5".parse::<Decimal>().unwrap()).to_string());
+/// assert_eq!("1.5", (-"-1.5".parse::<Decimal>().unwrap()).to_string());
+/// ```
+impl Neg for Decimal {
+    type Output = Self;
+    fn neg(mut self) -> Self {
+        self.sign = match self.sign {
+            Sign::Negative => Sign::None,
+            Sign::None | Sign::Positive => Sign::Negative,
+        };
+        self
+    }
+}
+
+crate::ops::impl_binary_op!(Decimal, Add, add, AddAssign, add_assign, sum);
+crate::ops::impl_binary_op!(Decimal, Sub, sub, SubAssign, sub_assign, difference);
+crate::ops::impl_binary_op!(Decimal, Mul, mul, MulAssign, mul_assign, product);
+
 /// Render the [`Decimal`] as a string of ASCII digits with a possible decimal point.
 ///
 /// # Examples
@@ -3995,3 +4177,128 @@ fn test_rounding_increment() {
     );
     assert_eq!("2.50", dec.to_string());
 }
+
+#[test]
+fn test_arithmetic() {
+    #[derive(Debug)]
+    struct TestCase {
+        pub input_1: &'static str,
+        pub input_2: &'static str,
+        pub sum: &'static str,
+        pub difference: &'static str,
+        pub product: &'static str,
+    }
+    let cases = [
+        TestCase {
+            input_1: "1.50",
+            input_2: "2",
+            sum: "3.50",
+            difference: "-0.50",
+            product: "3.00",
+        },
+        TestCase {
+            input_1: "-1.50",
+            input_2: "2",
+            sum: "0.50",
+            difference: "-3.50",
+            product: "-3.00",
+        },
+        TestCase {
+            input_1: "-1.50",
+            input_2: "-2",
+            sum: "-3.50",
+            difference: "0.50",
+            product: "3.00",
+        },
+        TestCase {
+            input_1: "+2",
+            input_2: "-2",
+            sum: "0",
+            difference: "4",
+            product: "-4",
+        },
+        TestCase {
+            input_1: "-0.5",
+            input_2: "0",
+            sum: "-0.5",
+            difference: "-0.5",
+            product: "0.0",
+        },
+    ];
+    for cas in &cases {
+        let input_1 = Decimal::from_str(cas.input_1).unwrap();
+        let input_2 = Decimal::from_str(cas.input_2).unwrap();
+        assert_eq!(cas.sum, (&input_1 + &input_2).to_string(), "{cas:?}");
+        assert_eq!(cas.difference, (&input_1 - &input_2).to_string(), "{cas:?}");
+        assert_eq!(cas.product, (&input_1 * &input_2).to_string(), "{cas:?}");
+
+        let mut dec = input_1.clone();
+        dec -= input_2.clone();
+        dec += input_2;
+        assert_eq!(
+            Ordering::Equal,
+            dec.absolute.cmp_value(&input_1.absolute),
+            "{cas:?}"
+        );
+        assert_eq!(dec.sign == Sign::Negative, input_1.sign == Sign::Negative);
+    }
+}
+
+#[test]
+fn test_division() {
+    use SignedRoundingMode::*;
+    use UnsignedRoundingMode::*;
+    #[derive(Debug)]
+    struct TestCase {
+        pub dividend: &'static str,
+        pub divisor: &'static str,
+        pub mode: SignedRoundingMode,
+        pub expected: &'static str,
+    }
+    let cases = [
+        TestCase {
+            dividend: "-10",
+            divisor: "3",
+            mode: Ceil,
+            expected: "-3.33",
+        },
+        TestCase {
+            dividend: "-10",
+            divisor: "3",
+            mode: Floor,
+            expected: "-3.34",
+        },
+        TestCase {
+            dividend: "10",
+            divisor: "-3",
+            mode: Unsigned(Expand),
+            expected: "-3.34",
+        },
+        TestCase {
+            dividend: "-10",
+            divisor: "-3",
+            mode: Floor,
+            expected: "3.33",
+        },
+        TestCase {
+            dividend: "-1",
+            divisor: "8",
+            mode: HalfCeil,
+            expected: "-0.12",
+        },
+        TestCase {
+            dividend: "-1",
+            divisor: "8",
+            mode: HalfFloor,
+            expected: "-0.13",
+        },
+    ];
+    for cas in &cases {
+        let dividend = Decimal::from_str(cas.dividend).unwrap();
+        let divisor = Decimal::from_str(cas.divisor).unwrap();
+        let quotient = dividend
+            .checked_div_with_mode(&divisor, -2, cas.mode)
+            .unwrap();
+        assert_eq!(cas.expected, quotient.to_string(), "{cas:?}");
+    }
+}
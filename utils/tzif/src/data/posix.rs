@@ -68,3 +68,107 @@ pub struct PosixTzString {
     /// The variant info of the DST time-zone variant if present.
     pub dst_info: Option<DstTransitionInfo>,
 }
+
+impl TransitionDate {
+    /// Returns the local time of this transition in the given year, in seconds since
+    /// 1970-01-01T00:00:00 local time.
+    fn local_seconds_in_year(self, year: i64) -> i64 {
+        let day = match self.day {
+            TransitionDay::NoLeap(n) => {
+                let n = i64::from(n);
+                // February 29 is never counted
+                days_from_civil(year, 1, 1) + n - 1 + i64::from(is_leap(year) && n >= 60)
+            }
+            TransitionDay::WithLeap(n) => days_from_civil(year, 1, 1) + i64::from(n),
+            TransitionDay::Mwd(month, week, weekday) => {
+                let first = days_from_civil(year, month.into(), 1);
+                let month_length = days_from_civil(year, i64::from(month) + 1, 1) - first;
+                // 1970-01-01 was a Thursday
+                let first_weekday = (first + 4).rem_euclid(7);
+                let mut offset =
+                    (i64::from(weekday) - first_weekday).rem_euclid(7) + 7 * (i64::from(week) - 1);
+                // Week 5 means the last such weekday of the month
+                while offset >= month_length {
+                    offset -= 7;
+                }
+                first + offset
+            }
+        };
+        day * SECONDS_PER_DAY + self.time.0
+    }
+}
+
+impl PosixTzString {
+    /// Returns the variant info in effect at the given UNIX timestamp, and whether it is
+    /// the DST variant.
+    pub fn variant_info_at(&self, timestamp: Seconds) -> (&TimeZoneVariantInfo, bool) {
+        let Some(dst_info) = &self.dst_info else {
+            return (&self.std_info, false);
+        };
+
+        // The offsets are the amounts that must be added to local time to reach UTC.
+        let std_offset = self.std_info.offset.0;
+        let dst_offset = dst_info.variant_info.offset.0;
+
+        let year = civil_year_from_days((timestamp.0 - std_offset).div_euclid(SECONDS_PER_DAY));
+
+        // Consecutive years are considered so that transitions around the new year and
+        // rules in which DST spans the new year are handled.
+        let mut is_dst = None;
+        let mut last_transition = i64::MIN;
+        for year in year - 1..=year + 1 {
+            let start = dst_info.start_date.local_seconds_in_year(year) + std_offset;
+            let end = dst_info.end_date.local_seconds_in_year(year) + dst_offset;
+            let mut transitions = [(start, true), (end, false)];
+            transitions.sort_by_key(|&(t, _)| t);
+            for (transition, dst) in transitions {
+                if transition <= timestamp.0 && transition >= last_transition {
+                    last_transition = transition;
+                    is_dst = Some(dst);
+                }
+            }
+        }
+
+        if is_dst.unwrap_or_default() {
+            (&dst_info.variant_info, true)
+        } else {
+            (&self.std_info, false)
+        }
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+fn is_leap(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns the number of days since 1970-01-01 of the given proleptic Gregorian date.
+///
+/// Months past December roll over into the following year.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let (year, month) = (
+        year + (month - 1).div_euclid(12),
+        (month - 1).rem_euclid(12) + 1,
+    );
+    // See <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Returns the proleptic Gregorian year of the given number of days since 1970-01-01.
+fn civil_year_from_days(days: i64) -> i64 {
+    // See <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    year_of_era + era * 400 + i64::from(month >= 10)
+}
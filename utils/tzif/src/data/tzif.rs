@@ -150,6 +150,53 @@ impl TzifData {
             _ => self.header2.as_ref().map(TzifHeader::block_size::<V>),
         }
     }
+
+    /// Returns the data block with the highest precision, i.e. the version-2+ data block
+    /// if present, otherwise the version-1 data block.
+    pub fn data_block(&self) -> &DataBlock {
+        self.data_block2.as_ref().unwrap_or(&self.data_block1)
+    }
+
+    /// Returns the [`LocalTimeTypeRecord`] in effect at the given UNIX timestamp.
+    ///
+    /// Timestamps before the first transition use the first local time type. Timestamps
+    /// on or after the last transition are evaluated using the POSIX time-zone string in
+    /// the footer, if present, in which case the record of the data block matching the
+    /// footer's offset, DST flag, and designation is returned.
+    ///
+    /// Returns [`None`] if the data block does not contain the required local time type.
+    ///
+    /// Leap seconds are not taken into account, i.e. the timestamp is in POSIX time.
+    pub fn local_time_type_at(&self, timestamp: Seconds) -> Option<LocalTimeTypeRecord> {
+        let block = self.data_block();
+        let index = block
+            .transition_times
+            .partition_point(|&transition| transition <= timestamp);
+
+        if index == block.transition_times.len() {
+            if let Some(footer) = &self.footer {
+                let (variant_info, is_dst) = footer.variant_info_at(timestamp);
+                let utoff = Seconds(-variant_info.offset.0);
+                let mut candidates = block
+                    .local_time_type_records
+                    .iter()
+                    .filter(|r| r.utoff == utoff && r.is_dst == is_dst);
+                let first = candidates.clone().next().copied();
+                return candidates
+                    .find(|r| {
+                        block.time_zone_designation(r.idx) == Some(variant_info.name.as_str())
+                    })
+                    .copied()
+                    .or(first);
+            }
+        }
+
+        let local_time_type = match index.checked_sub(1) {
+            Some(i) => *block.transition_types.get(i)?,
+            None => 0,
+        };
+        block.local_time_type_records.get(local_time_type).copied()
+    }
 }
 
 /// A record specifying a local time type.
@@ -313,3 +360,85 @@ impl DataBlock {
         })
     }
 }
+
+#[cfg(feature = "icu_time")]
+impl LocalTimeTypeRecord {
+    /// Returns the UTC offset of this local time type as an ICU4X [`UtcOffset`](icu_time::zone::UtcOffset).
+    ///
+    /// Returns [`None`] if the offset is outside of the range supported by ICU4X.
+    ///
+    /// ✨ *Enabled with the `icu_time` Cargo feature.*
+    pub fn utc_offset(&self) -> Option<icu_time::zone::UtcOffset> {
+        icu_time::zone::UtcOffset::try_from_seconds(self.utoff.0.try_into().ok()?).ok()
+    }
+
+    /// Returns the ICU4X [`TimeZoneVariant`](icu_time::zone::TimeZoneVariant) of this local time type.
+    ///
+    /// This uses [`TimeZoneVariant::from_rearguard_isdst`](icu_time::zone::TimeZoneVariant::from_rearguard_isdst),
+    /// see its documentation for the caveats of `TZif` files that were not built with
+    /// `DATAFORM=rearguard`.
+    ///
+    /// ✨ *Enabled with the `icu_time` Cargo feature.*
+    pub fn time_zone_variant(&self) -> icu_time::zone::TimeZoneVariant {
+        icu_time::zone::TimeZoneVariant::from_rearguard_isdst(self.is_dst)
+    }
+}
+
+#[cfg(feature = "icu_time")]
+impl TzifData {
+    /// Creates an ICU4X [`ZonedDateTime`](icu_time::ZonedDateTime) for the given time zone
+    /// from an absolute time, in milliseconds since the UNIX epoch, using the offset and
+    /// zone variant in effect in this `TZif` data.
+    ///
+    /// Returns [`None`] if the local time type cannot be determined or its offset is not
+    /// supported by ICU4X.
+    ///
+    /// ✨ *Enabled with the `icu_time` Cargo feature.*
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use icu_time::zone::IanaParser;
+    /// use std::path::Path;
+    ///
+    /// let iana = "America/New_York";
+    /// let data =
+    ///     tzif::parse_tzif_file(&Path::new("/usr/share/zoneinfo").join(iana))
+    ///         .unwrap();
+    ///
+    /// let zdt = data
+    ///     .zoned_date_time_from_epoch_milliseconds(
+    ///         IanaParser::new().parse(iana),
+    ///         1_700_000_000_000,
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(zdt.zone.offset(), Some("-05:00".parse().unwrap()));
+    /// ```
+    pub fn zoned_date_time_from_epoch_milliseconds(
+        &self,
+        time_zone: icu_time::TimeZone,
+        epoch_milliseconds: i64,
+    ) -> Option<
+        icu_time::ZonedDateTime<
+            icu_calendar::Iso,
+            icu_time::TimeZoneInfo<icu_time::zone::models::Full>,
+        >,
+    > {
+        let record = self.local_time_type_at(Seconds(epoch_milliseconds.div_euclid(1000)))?;
+        let offset = record.utc_offset()?;
+        let icu_time::ZonedDateTime { date, time, .. } =
+            icu_time::ZonedDateTime::from_epoch_milliseconds_and_utc_offset(
+                epoch_milliseconds,
+                offset,
+            );
+        Some(icu_time::ZonedDateTime {
+            date,
+            time,
+            zone: time_zone
+                .with_offset(Some(offset))
+                .at_date_time_iso(icu_time::DateTime { date, time })
+                .with_variant(record.time_zone_variant()),
+        })
+    }
+}
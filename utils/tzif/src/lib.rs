@@ -23,6 +23,22 @@
 //! let data =
 //!     tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").unwrap();
 //! ```
+//!
+//! ### Evaluate the local time type at a timestamp
+//! ```no_run
+//! # use std::path::Path;
+//! use tzif::data::time::Seconds;
+//!
+//! let data =
+//!     tzif::parse_tzif_file(Path::new("/usr/share/zoneinfo/America/New_York"))
+//!         .unwrap();
+//! let record = data.local_time_type_at(Seconds(1_700_000_000)).unwrap();
+//! assert_eq!(record.utoff, Seconds(-5 * 60 * 60));
+//! assert!(!record.is_dst);
+//! ```
+//!
+//! With the `icu_time` Cargo feature, local time types can be converted into ICU4X
+//! `UtcOffset`s and `TimeZoneVariant`s.
 
 #![warn(missing_docs)]
 
@@ -27,3 +27,116 @@ fn parse_tzif_testdata() -> Result<(), tzif::error::Error> {
 fn parse_posix_tz_string() {
     assert!(tzif::parse_posix_tz_string(b"WGT3WGST,M3.5.0/-2,M10.5.0/-1").is_ok());
 }
+
+fn utoff_at(path: &str, timestamp: i64) -> (i64, bool) {
+    let data = tzif::parse_tzif_file(Path::new(path)).unwrap();
+    let record = data
+        .local_time_type_at(tzif::data::time::Seconds(timestamp))
+        .unwrap();
+    (record.utoff.0, record.is_dst)
+}
+
+#[test]
+fn local_time_type_at() {
+    const HOUR: i64 = 60 * 60;
+
+    // Before the first transition
+    assert_eq!(
+        utoff_at("testdata/America/Los_Angeles", -3_000_000_000),
+        (-28378, false)
+    );
+    // From the transitions, 2015-03-08T10:00:00Z
+    assert_eq!(
+        utoff_at("testdata/America/Los_Angeles", 1425808799),
+        (-8 * HOUR, false)
+    );
+    assert_eq!(
+        utoff_at("testdata/America/Los_Angeles", 1425808800),
+        (-7 * HOUR, true)
+    );
+    // From the footer, 2200-11-02T09:00:00Z
+    assert_eq!(
+        utoff_at("testdata/America/Los_Angeles", 7284502799),
+        (-7 * HOUR, true)
+    );
+    assert_eq!(
+        utoff_at("testdata/America/Los_Angeles", 7284502800),
+        (-8 * HOUR, false)
+    );
+
+    // Southern hemisphere, DST spans the new year
+    assert_eq!(
+        utoff_at("testdata/Chile/EasterIsland", 4102444800),
+        (-5 * HOUR, true)
+    );
+    assert_eq!(
+        utoff_at("testdata/Chile/EasterIsland", 4118000000),
+        (-6 * HOUR, false)
+    );
+
+    // Two hour DST, 2100-03-28T01:00:00Z
+    assert_eq!(
+        utoff_at("testdata/Antarctica/Troll", 4109878799),
+        (0, false)
+    );
+    assert_eq!(
+        utoff_at("testdata/Antarctica/Troll", 4109878800),
+        (2 * HOUR, true)
+    );
+
+    // No DST
+    assert_eq!(
+        utoff_at("testdata/Asia/Tokyo", 4102444800),
+        (9 * HOUR, false)
+    );
+}
+
+#[test]
+fn posix_variant_info_at() {
+    use tzif::data::time::Seconds;
+
+    let posix = tzif::parse_posix_tz_string(b"EST5EDT,M3.2.0,M11.1.0").unwrap();
+    // 2025-03-09T07:00:00Z
+    assert_eq!(posix.variant_info_at(Seconds(1741503599)).0.name, "EST");
+    assert_eq!(posix.variant_info_at(Seconds(1741503600)).0.name, "EDT");
+    // 2025-11-02T06:00:00Z
+    assert!(posix.variant_info_at(Seconds(1762063199)).1);
+    assert!(!posix.variant_info_at(Seconds(1762063200)).1);
+
+    // DST all year
+    let posix = tzif::parse_posix_tz_string(b"EST5EDT,0/0,J365/25").unwrap();
+    for timestamp in [0, 1735689600, 1751328000, 1767225599] {
+        assert!(posix.variant_info_at(Seconds(timestamp)).1);
+    }
+
+    let posix = tzif::parse_posix_tz_string(b"JST-9").unwrap();
+    assert_eq!(
+        posix.variant_info_at(Seconds(0)).0.offset,
+        Seconds(-9 * 60 * 60)
+    );
+}
+
+#[cfg(feature = "icu_time")]
+#[test]
+fn zoned_date_time_from_epoch_milliseconds() {
+    use icu_time::zone::{IanaParser, TimeZoneVariant};
+    use icu_time::Time;
+
+    let data = tzif::parse_tzif_file(Path::new("testdata/America/Los_Angeles")).unwrap();
+    let time_zone = IanaParser::new().parse("America/Los_Angeles");
+
+    // 2025-07-01T19:30:00Z
+    let zdt = data
+        .zoned_date_time_from_epoch_milliseconds(time_zone, 1751398200000)
+        .unwrap();
+    assert_eq!(zdt.time, Time::try_new(12, 30, 0, 0).unwrap());
+    assert_eq!(zdt.zone.id(), time_zone);
+    assert_eq!(zdt.zone.offset(), Some("-07:00".parse().unwrap()));
+    assert_eq!(zdt.zone.variant(), TimeZoneVariant::Daylight);
+
+    let zdt = data
+        .zoned_date_time_from_epoch_milliseconds(time_zone, 1735689600000)
+        .unwrap();
+    assert_eq!(zdt.zone.offset(), Some("-08:00".parse().unwrap()));
+    assert_eq!(zdt.zone.variant(), TimeZoneVariant::Standard);
+}